@@ -0,0 +1,64 @@
+//! Application context and window registry
+//!
+//! [`AppContext`] carries the shared state that menu, hotkey and automation
+//! callbacks act on, so each callback is handed what it needs instead of
+//! reaching for globals. AppKit windows can't leave the main thread, so they
+//! live in a main-thread [`Windows`] registry rather than in `Send` statics.
+//! Objective-C selectors and main-queue blocks can't capture a context and
+//! reach the registry through [`windows`].
+
+use objc2_foundation::MainThreadMarker;
+use once_cell::unsync::OnceCell;
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+use crate::language_hud::HudState;
+use crate::menubar::{AppState, MenuBarInner};
+use crate::polish_compare::CompareWindow;
+use crate::recording::RecordingSession;
+use crate::recording_banner::BannerState;
+use crate::region_selection::RegionSelectionState;
+use crate::screenshot_flash::FlashState;
+use crate::settings_window::SettingsWindowInner;
+use crate::transcription_window::TranscriptionWindowInner;
+use crate::ui_sink::SharedUi;
+
+/// State shared by the app's callbacks
+#[derive(Clone)]
+pub(crate) struct AppContext {
+    /// The active recording session, if any
+    pub(crate) recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    /// Recording, processing and credential flags shown in the menu bar
+    pub(crate) app_state: Arc<AppState>,
+    /// Where recording and polishing progress is shown
+    pub(crate) ui: SharedUi,
+}
+
+/// Windows and menus owned by the main thread
+///
+/// Each slot is filled when its window is first created. Borrows are short
+/// and taken with `try_borrow`, so a re-entrant AppKit callback skips its
+/// update instead of panicking.
+#[derive(Default)]
+pub(crate) struct Windows {
+    pub(crate) menu_bar: OnceCell<RefCell<MenuBarInner>>,
+    pub(crate) transcription: OnceCell<RefCell<TranscriptionWindowInner>>,
+    pub(crate) settings: OnceCell<RefCell<SettingsWindowInner>>,
+    pub(crate) region_selection: RefCell<Option<RegionSelectionState>>,
+    pub(crate) screenshot_flash: RefCell<Option<FlashState>>,
+    pub(crate) polish_compare: RefCell<Option<CompareWindow>>,
+    pub(crate) recording_banner: RefCell<Option<BannerState>>,
+    pub(crate) language_hud: RefCell<Option<HudState>>,
+}
+
+thread_local! {
+    // Leaked so borrows can outlive a `with` closure; only the main thread
+    // ever touches it, and it lives as long as the app.
+    static WINDOWS: &'static Windows = Box::leak(Box::default());
+}
+
+/// The main thread's window registry, or `None` off the main thread.
+pub(crate) fn windows() -> Option<&'static Windows> {
+    MainThreadMarker::new()?;
+    Some(WINDOWS.with(|windows| *windows))
+}
@@ -0,0 +1,152 @@
+//! Attendees of the current session
+//!
+//! While recording, the attendees button in the overlay's header opens a
+//! field for the names of the people in the meeting. The meeting notes and
+//! follow-up email prompts are given the names, so action items and
+//! decisions are attributed to the right people and the notes list everyone
+//! who took part, and Notion exports put them in the Attendees column. Saved
+//! and printed transcripts start with an attendees line.
+//!
+//! The list belongs to one recording: it is kept while that recording's
+//! outputs are generated and exported, and cleared when the next one starts.
+//! Names are personal data and are never logged.
+
+use std::sync::Mutex;
+
+use tracing::info;
+
+use crate::issues;
+use crate::response::PolishConfig;
+use crate::transcription_window::TabType;
+
+/// Heading of the section meeting notes list the attendees under
+const ATTENDEES_HEADING: &str = "Attendees";
+
+/// Names of the current session's attendees, in the order typed
+static ATTENDEES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Forget the previous recording's attendees when a new recording starts
+pub(crate) fn recording_started() {
+    if let Ok(mut attendees) = ATTENDEES.lock() {
+        attendees.clear();
+    }
+}
+
+/// Replace the attendees with the names in `text`
+pub(crate) fn set(text: &str) {
+    let names = parse(text);
+    info!(count = names.len(), "Attendees updated");
+    if let Ok(mut attendees) = ATTENDEES.lock() {
+        *attendees = names;
+    }
+}
+
+/// Names of the current session's attendees
+pub(crate) fn current() -> Vec<String> {
+    ATTENDEES
+        .lock()
+        .map(|attendees| attendees.clone())
+        .unwrap_or_default()
+}
+
+/// The attendees as shown in the overlay's field
+pub(crate) fn field_text() -> String {
+    current().join(", ")
+}
+
+/// `prompt` with the attendees added, for meeting notes and follow-up emails
+pub(crate) fn add_to_prompt(prompt: String, config: &PolishConfig) -> String {
+    if !config.is_meeting_notes() && !config.is_follow_up_email() {
+        return prompt;
+    }
+    match prompt_instructions(&current()) {
+        Some(instructions) => format!("{}\n\n{}", prompt, instructions),
+        None => prompt,
+    }
+}
+
+/// Content of `tab` as saved or printed, starting with the attendees
+pub(crate) fn add_to_export(tab: TabType, content: &str) -> String {
+    with_attendees(tab, content, &current())
+}
+
+/// `content` with an attendees line before it, unless there are no attendees,
+/// it is a follow-up email (which starts with its subject and greets them
+/// already) or meeting notes that list them in their own section
+fn with_attendees(tab: TabType, content: &str, attendees: &[String]) -> String {
+    let listed = tab == TabType::MeetingNotes
+        && !issues::parse_section_items(content, ATTENDEES_HEADING).is_empty();
+    if attendees.is_empty() || tab == TabType::FollowUpEmail || listed {
+        return content.to_string();
+    }
+    format!("Attendees: {}\n\n{}", attendees.join(", "), content)
+}
+
+/// Names separated by commas, semicolons or line breaks, without blanks and
+/// repeats
+fn parse(text: &str) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    text.split([',', ';', '\n'])
+        .map(|name| name.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|name| !name.is_empty() && seen.insert(name.to_lowercase()))
+        .collect()
+}
+
+/// Instructions naming the attendees, None when there are none
+fn prompt_instructions(attendees: &[String]) -> Option<String> {
+    if attendees.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "ATTENDEES: The people in this meeting were: {}. Spell their names as given here. \
+         Use these names to attribute action items, decisions and remarks to the right person, \
+         and list all of them as attendees. Only attribute something to a person when the \
+         transcript supports it.",
+        attendees.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_splits_and_trims_names() {
+        assert_eq!(
+            parse(" Anna Berg,Bob  Olsen;\nCarla ,, "),
+            vec!["Anna Berg", "Bob Olsen", "Carla"]
+        );
+    }
+
+    #[test]
+    fn test_parse_drops_repeated_names() {
+        assert_eq!(parse("Anna, Bob, anna"), vec!["Anna", "Bob"]);
+    }
+
+    #[test]
+    fn test_exports_start_with_the_attendees() {
+        let names = ["Anna".to_string(), "Bob".to_string()];
+        assert_eq!(
+            with_attendees(TabType::Live, "Hello.", &names),
+            "Attendees: Anna, Bob\n\nHello."
+        );
+        assert_eq!(with_attendees(TabType::Live, "Hello.", &[]), "Hello.");
+
+        let email = "Subject: Recap\n\nHi all,";
+        assert_eq!(with_attendees(TabType::FollowUpEmail, email, &names), email);
+
+        let notes = "## Attendees\n- Anna\n- Bob\n\n## Summary\n- Done";
+        assert_eq!(with_attendees(TabType::MeetingNotes, notes, &names), notes);
+        assert!(
+            with_attendees(TabType::MeetingNotes, "## Summary\n- Done", &names)
+                .starts_with("Attendees: Anna, Bob\n\n")
+        );
+    }
+
+    #[test]
+    fn test_prompt_instructions_name_every_attendee() {
+        assert_eq!(prompt_instructions(&[]), None);
+        let instructions = prompt_instructions(&["Anna".to_string(), "Bob".to_string()]).unwrap();
+        assert!(instructions.contains("were: Anna, Bob."));
+    }
+}
@@ -1,22 +1,33 @@
 //! Audio capture module using cpal for cross-platform microphone access
 //!
 //! Captures audio from the default input device at the specified sample rate
-//! in mono PCM format, optimal for realtime transcription services.
+//! in mono PCM format, optimal for realtime transcription services. A drop in
+//! the device's sample rate mid-stream, typical of a Bluetooth headset
+//! switching to its hands-free profile, is reported (see `profile`), and so is
+//! an input device that is muted or at zero volume (see `mute`).
 
+mod mute;
+mod profile;
 mod resampler;
+mod stream;
+mod tuning;
 mod types;
 
+pub(crate) use mute::{
+    input_silence, stop_watching_input_silence, watch_input_silence, InputSilence,
+};
+pub(crate) use profile::{alternative_input_device, switch_input_device, CALL_QUALITY_RATE};
+pub(crate) use resampler::convert_chunk_rate;
+pub(crate) use tuning::{AudioTuning, BUFFER_SECS_PRESETS, CHUNK_MS_PRESETS};
+pub(crate) use tuning::{DEFAULT_BUFFER_SECS, DEFAULT_CHUNK_MS};
 pub use types::{AudioCaptureError, AudioCaptureHandle, AudioChunk};
 
-use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::SampleFormat;
-use resampler::{process_samples, CHUNK_SIZE};
-use rubato::{SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
+use cpal::traits::{DeviceTrait, HostTrait};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::thread;
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{error, info};
 
 /// Target sample rate for Azure OpenAI STT (16kHz)
 pub const AZURE_SAMPLE_RATE: u32 = 16000;
@@ -52,7 +63,7 @@ pub const TARGET_SAMPLE_RATE: u32 = AZURE_SAMPLE_RATE;
 #[allow(dead_code)]
 pub(crate) fn start_capture(
 ) -> Result<(AudioCaptureHandle, mpsc::Receiver<AudioChunk>), AudioCaptureError> {
-    start_capture_with_sample_rate(TARGET_SAMPLE_RATE)
+    start_capture_with_sample_rate(TARGET_SAMPLE_RATE, AudioTuning::default())
 }
 
 /// Start audio capture on a dedicated thread with specified sample rate
@@ -62,6 +73,7 @@ pub(crate) fn start_capture(
 ///
 /// # Arguments
 /// - `target_sample_rate` - Target sample rate in Hz (e.g., 16000 for Azure, 24000 for OpenAI)
+/// - `tuning` - Chunk duration and how many chunks are buffered
 ///
 /// # Returns
 /// A tuple containing:
@@ -69,15 +81,22 @@ pub(crate) fn start_capture(
 /// - `mpsc::Receiver<AudioChunk>` - Receives audio chunks for streaming to STT
 pub(crate) fn start_capture_with_sample_rate(
     target_sample_rate: u32,
+    tuning: AudioTuning,
 ) -> Result<(AudioCaptureHandle, mpsc::Receiver<AudioChunk>), AudioCaptureError> {
     let is_capturing = Arc::new(AtomicBool::new(true));
     let is_capturing_clone = is_capturing.clone();
 
     // Create async channel for audio chunks
-    let (chunk_tx, chunk_rx) = mpsc::channel(600);
+    info!(
+        "Audio chunks of {} ms, buffering up to {} chunks",
+        tuning.chunk_ms,
+        tuning.buffer_chunks()
+    );
+    let (chunk_tx, chunk_rx) = mpsc::channel(tuning.buffer_chunks());
+    let chunk_size = tuning.chunk_samples(target_sample_rate);
 
     let thread_handle = thread::spawn(move || {
-        if let Err(e) = run_capture(is_capturing_clone, chunk_tx, target_sample_rate) {
+        if let Err(e) = run_capture(is_capturing_clone, chunk_tx, target_sample_rate, chunk_size) {
             error!("Audio capture error: {}", e);
         }
     });
@@ -90,190 +109,69 @@ pub(crate) fn start_capture_with_sample_rate(
     Ok((handle, chunk_rx))
 }
 
+/// Start a capture that produces no audio and needs no input device
+///
+/// Used when replaying a mock transcript or the demo session. The channel
+/// stays open until the handle is stopped, just like a real capture.
+pub(crate) fn start_silent_capture() -> (AudioCaptureHandle, mpsc::Receiver<AudioChunk>) {
+    let is_capturing = Arc::new(AtomicBool::new(true));
+    let is_capturing_clone = is_capturing.clone();
+    let (chunk_tx, chunk_rx) = mpsc::channel(1);
+
+    let thread_handle = thread::spawn(move || {
+        while is_capturing_clone.load(Ordering::SeqCst) {
+            thread::sleep(std::time::Duration::from_millis(50));
+        }
+        drop(chunk_tx);
+    });
+
+    let handle = AudioCaptureHandle {
+        is_capturing,
+        thread_handle: Some(thread_handle),
+    };
+
+    (handle, chunk_rx)
+}
+
 /// Run audio capture on the current thread (blocking)
+///
+/// The stream is reopened when the device's sample rate changes under it,
+/// e.g. when a Bluetooth headset switches to its hands-free profile, and on
+/// another device when one is picked with [`switch_input_device`].
 fn run_capture(
     is_capturing: Arc<AtomicBool>,
     chunk_tx: mpsc::Sender<AudioChunk>,
     target_sample_rate: u32,
+    chunk_size: usize,
 ) -> Result<(), AudioCaptureError> {
     let host = cpal::default_host();
 
-    let device = host
+    let mut device = host
         .default_input_device()
         .ok_or(AudioCaptureError::NoInputDevice)?;
+    profile::take_switch_request();
 
-    let device_name = device.name().unwrap_or_else(|_| "Unknown".to_string());
-    info!("Using audio input device: {}", device_name);
-
-    // Get supported configs and find one closest to our target
-    let supported_configs = device
-        .supported_input_configs()
-        .map_err(|e| AudioCaptureError::ConfigError(e.to_string()))?;
-
-    // Try to find a config with target rate, or fall back to any supported rate
-    let mut best_config = None;
-    let mut found_target_rate = false;
-
-    for config in supported_configs {
-        let channels = config.channels();
-        if channels > 0 {
-            if config.min_sample_rate().0 <= target_sample_rate
-                && config.max_sample_rate().0 >= target_sample_rate
-            {
-                best_config = Some(config.with_sample_rate(cpal::SampleRate(target_sample_rate)));
-                found_target_rate = true;
-                break;
-            } else if best_config.is_none() {
-                best_config = Some(config.with_max_sample_rate());
-            }
-        }
-    }
-
-    let supported_config = best_config.ok_or(AudioCaptureError::NoSupportedConfig)?;
+    loop {
+        let device_name = device.name().unwrap_or_else(|_| "Unknown".to_string());
+        info!("Using audio input device: {}", device_name);
 
-    if !found_target_rate {
-        warn!(
-            "{}Hz not supported, using {}Hz instead",
+        let (stream, sample_rate) = stream::open(
+            &device,
+            &is_capturing,
+            &chunk_tx,
             target_sample_rate,
-            supported_config.sample_rate().0
-        );
-    }
-
-    let config: cpal::StreamConfig = supported_config.into();
-    let sample_rate = config.sample_rate.0;
-    let channels = config.channels as usize;
-
-    info!("Audio config: {} channels, {} Hz", channels, sample_rate);
-
-    // Create resampler if sample rate doesn't match target
-    let (resampler, input_chunk_size): (Option<Arc<Mutex<SincFixedIn<f32>>>>, usize) =
-        if sample_rate != target_sample_rate {
-            info!(
-                "Creating resampler: {} Hz -> {} Hz",
-                sample_rate, target_sample_rate
-            );
-            let params = SincInterpolationParameters {
-                sinc_len: 256,
-                f_cutoff: 0.95,
-                interpolation: SincInterpolationType::Linear,
-                oversampling_factor: 256,
-                window: WindowFunction::BlackmanHarris2,
-            };
-            // Calculate chunk size that will produce target sample rate chunks
-            let input_frames = (CHUNK_SIZE as f64 * sample_rate as f64 / target_sample_rate as f64)
-                .ceil() as usize;
-            match SincFixedIn::<f32>::new(
-                target_sample_rate as f64 / sample_rate as f64,
-                2.0,
-                params,
-                input_frames,
-                1, // mono
-            ) {
-                Ok(resampler) => {
-                    info!(
-                        "Resampler configured: input {} samples -> output {} samples",
-                        input_frames, CHUNK_SIZE
-                    );
-                    (Some(Arc::new(Mutex::new(resampler))), input_frames)
-                }
-                Err(e) => {
-                    error!("Failed to create resampler: {}", e);
-                    (None, CHUNK_SIZE)
-                }
-            }
-        } else {
-            (None, CHUNK_SIZE)
-        };
-
-    // Buffer for accumulating resampled output samples (after resampling)
-    let output_buffer: Arc<Mutex<Vec<i16>>> =
-        Arc::new(Mutex::new(Vec::with_capacity(CHUNK_SIZE * 2)));
-    let output_buffer_clone = output_buffer.clone();
-
-    // Buffer for accumulating input samples (before resampling)
-    let input_buffer: Arc<Mutex<Vec<i16>>> =
-        Arc::new(Mutex::new(Vec::with_capacity(input_chunk_size * 2)));
-    let input_buffer_clone = input_buffer.clone();
-
-    let resampler_clone = resampler.clone();
-
-    let is_capturing_stream = is_capturing.clone();
-    let chunk_tx_clone = chunk_tx.clone();
-
-    let err_callback = |err| {
-        error!("Audio stream error: {}", err);
-    };
-
-    // Build the input stream based on sample format
-    let stream = match device.default_input_config()?.sample_format() {
-        SampleFormat::I16 => device.build_input_stream(
-            &config,
-            move |data: &[i16], _| {
-                if !is_capturing_stream.load(Ordering::SeqCst) {
-                    return;
-                }
-                process_samples(
-                    data,
-                    channels,
-                    &input_buffer_clone,
-                    input_chunk_size,
-                    &output_buffer_clone,
-                    &chunk_tx_clone,
-                    &resampler_clone,
-                );
-            },
-            err_callback,
-            None,
-        )?,
-        SampleFormat::F32 => {
-            let is_capturing_f32 = is_capturing.clone();
-            let input_buffer_f32 = input_buffer.clone();
-            let output_buffer_f32 = output_buffer.clone();
-            let chunk_tx_f32 = chunk_tx.clone();
-            let resampler_f32 = resampler.clone();
-            device.build_input_stream(
-                &config,
-                move |data: &[f32], _| {
-                    if !is_capturing_f32.load(Ordering::SeqCst) {
-                        return;
-                    }
-                    // Convert f32 to i16
-                    let samples: Vec<i16> = data
-                        .iter()
-                        .map(|&s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
-                        .collect();
-                    process_samples(
-                        &samples,
-                        channels,
-                        &input_buffer_f32,
-                        input_chunk_size,
-                        &output_buffer_f32,
-                        &chunk_tx_f32,
-                        &resampler_f32,
-                    );
-                },
-                err_callback,
-                None,
-            )?
+            chunk_size,
+        )?;
+        info!("Audio capture started");
+
+        // Keep the stream alive until capture is stopped or has to reopen
+        let next = profile::watch(&host, &device, sample_rate, &is_capturing);
+        drop(stream);
+        match next {
+            Some(next) => device = next,
+            None => return Ok(()),
         }
-        sample_format => {
-            return Err(AudioCaptureError::UnsupportedFormat(format!(
-                "{:?}",
-                sample_format
-            )));
-        }
-    };
-
-    stream.play()?;
-    info!("Audio capture started");
-
-    // Keep the stream alive until capture is stopped
-    while is_capturing.load(Ordering::SeqCst) {
-        thread::sleep(std::time::Duration::from_millis(100));
     }
-
-    drop(stream);
-    Ok(())
 }
 
 #[cfg(test)]
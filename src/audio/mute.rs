@@ -0,0 +1,287 @@
+//! Muted or silent input device detection
+//!
+//! macOS keeps delivering audio from a muted microphone, or one whose input
+//! volume is at zero; it is just silence. Recording would then run the whole
+//! meeting and produce an empty transcript. The default input device's mute
+//! and volume properties are read through CoreAudio and watched with property
+//! listeners, so the user can be warned as soon as recording starts and
+//! whenever the setting changes while recording.
+
+use std::ffi::c_void;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+/// Why the input device records only silence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InputSilence {
+    /// The device is muted
+    Muted,
+    /// The device's input volume is at zero
+    VolumeZero,
+}
+
+/// Called with the current state whenever mute or volume changes
+type SilenceHandler = Arc<dyn Fn(Option<InputSilence>) + Send + Sync>;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct PropertyAddress {
+    selector: u32,
+    scope: u32,
+    element: u32,
+}
+
+type ListenerProc = extern "C" fn(u32, u32, *const PropertyAddress, *mut c_void) -> i32;
+
+#[link(name = "CoreAudio", kind = "framework")]
+extern "C" {
+    fn AudioObjectHasProperty(object: u32, address: *const PropertyAddress) -> u8;
+    fn AudioObjectGetPropertyData(
+        object: u32,
+        address: *const PropertyAddress,
+        qualifier_size: u32,
+        qualifier: *const c_void,
+        data_size: *mut u32,
+        data: *mut c_void,
+    ) -> i32;
+    fn AudioObjectAddPropertyListener(
+        object: u32,
+        address: *const PropertyAddress,
+        listener: ListenerProc,
+        client_data: *mut c_void,
+    ) -> i32;
+    fn AudioObjectRemovePropertyListener(
+        object: u32,
+        address: *const PropertyAddress,
+        listener: ListenerProc,
+        client_data: *mut c_void,
+    ) -> i32;
+}
+
+/// kAudioObjectSystemObject
+const SYSTEM_OBJECT: u32 = 1;
+/// kAudioObjectUnknown
+const UNKNOWN_OBJECT: u32 = 0;
+/// kAudioObjectPropertyElementMain
+const ELEMENT_MAIN: u32 = 0;
+/// Channels checked when the device has no main volume control
+const STEREO_CHANNELS: [u32; 2] = [1, 2];
+
+const SCOPE_GLOBAL: u32 = u32::from_be_bytes(*b"glob");
+const SCOPE_INPUT: u32 = u32::from_be_bytes(*b"inpt");
+const DEFAULT_INPUT_DEVICE: u32 = u32::from_be_bytes(*b"dIn ");
+const MUTE: u32 = u32::from_be_bytes(*b"mute");
+const VOLUME: u32 = u32::from_be_bytes(*b"volm");
+
+/// Handler of the running watch
+static HANDLER: Mutex<Option<SilenceHandler>> = Mutex::new(None);
+
+/// Device whose mute and volume listeners are registered
+static WATCHED_DEVICE: Mutex<Option<u32>> = Mutex::new(None);
+
+/// Whether the default input device records only silence, and why.
+///
+/// None when it doesn't, or when the device can't be queried.
+pub(crate) fn input_silence() -> Option<InputSilence> {
+    let device = default_input_device()?;
+    let muted = read_u32(device, input_address(MUTE, ELEMENT_MAIN)).map(|mute| mute != 0);
+    let volumes: Vec<f32> = volume_elements(device)
+        .into_iter()
+        .filter_map(|element| read_f32(device, input_address(VOLUME, element)))
+        .collect();
+    classify(muted, &volumes)
+}
+
+/// Call `handler` whenever the default input device is muted, unmuted or its
+/// volume changes, including after switching to another input device.
+///
+/// Replaces the handler of a previous watch.
+pub(crate) fn watch_input_silence(handler: impl Fn(Option<InputSilence>) + Send + Sync + 'static) {
+    stop_watching_input_silence();
+    if let Ok(mut current) = HANDLER.lock() {
+        *current = Some(Arc::new(handler));
+    }
+
+    let address = system_address(DEFAULT_INPUT_DEVICE);
+    // SAFETY: the address is valid for the call and the listener is a plain fn
+    let status = unsafe {
+        AudioObjectAddPropertyListener(SYSTEM_OBJECT, &address, on_change, std::ptr::null_mut())
+    };
+    if status != 0 {
+        warn!("Could not watch for input device changes: {}", status);
+    }
+    watch_default_device();
+}
+
+/// Stop calling the handler given to [`watch_input_silence`]
+pub(crate) fn stop_watching_input_silence() {
+    let handler = HANDLER.lock().ok().and_then(|mut current| current.take());
+    if handler.is_none() {
+        return;
+    }
+
+    let address = system_address(DEFAULT_INPUT_DEVICE);
+    // SAFETY: removes the listener added in watch_input_silence
+    unsafe {
+        AudioObjectRemovePropertyListener(SYSTEM_OBJECT, &address, on_change, std::ptr::null_mut());
+    }
+    unwatch_device();
+}
+
+/// Register mute and volume listeners on the current default input device
+fn watch_default_device() {
+    let Some(device) = default_input_device() else {
+        return;
+    };
+    for address in device_addresses(device) {
+        // SAFETY: the address is valid for the call and the listener is a plain fn
+        unsafe {
+            AudioObjectAddPropertyListener(device, &address, on_change, std::ptr::null_mut());
+        }
+    }
+    if let Ok(mut watched) = WATCHED_DEVICE.lock() {
+        *watched = Some(device);
+    }
+}
+
+/// Remove the listeners registered by [`watch_default_device`]
+fn unwatch_device() {
+    let device = WATCHED_DEVICE
+        .lock()
+        .ok()
+        .and_then(|mut watched| watched.take());
+    let Some(device) = device else {
+        return;
+    };
+    for address in device_addresses(device) {
+        // SAFETY: removes a listener added in watch_default_device
+        unsafe {
+            AudioObjectRemovePropertyListener(device, &address, on_change, std::ptr::null_mut());
+        }
+    }
+}
+
+/// CoreAudio listener for the default device, mute and volume properties
+extern "C" fn on_change(
+    object: u32,
+    _count: u32,
+    _addresses: *const PropertyAddress,
+    _client_data: *mut c_void,
+) -> i32 {
+    if object == SYSTEM_OBJECT {
+        info!("Default input device changed");
+        unwatch_device();
+        watch_default_device();
+    }
+
+    let handler = HANDLER.lock().ok().and_then(|current| current.clone());
+    if let Some(handler) = handler {
+        handler(input_silence());
+    }
+    0
+}
+
+/// Decide from the device's mute flag and channel volumes
+fn classify(muted: Option<bool>, volumes: &[f32]) -> Option<InputSilence> {
+    if muted == Some(true) {
+        return Some(InputSilence::Muted);
+    }
+    if !volumes.is_empty() && volumes.iter().all(|volume| *volume <= 0.0) {
+        return Some(InputSilence::VolumeZero);
+    }
+    None
+}
+
+/// Mute and volume properties of a device that exist on it
+fn device_addresses(device: u32) -> Vec<PropertyAddress> {
+    std::iter::once(input_address(MUTE, ELEMENT_MAIN))
+        .chain(
+            volume_elements(device)
+                .into_iter()
+                .map(|element| input_address(VOLUME, element)),
+        )
+        .filter(|address| has_property(device, address))
+        .collect()
+}
+
+/// The main volume control, or the channel controls when there is none
+fn volume_elements(device: u32) -> Vec<u32> {
+    if has_property(device, &input_address(VOLUME, ELEMENT_MAIN)) {
+        vec![ELEMENT_MAIN]
+    } else {
+        STEREO_CHANNELS
+            .into_iter()
+            .filter(|channel| has_property(device, &input_address(VOLUME, *channel)))
+            .collect()
+    }
+}
+
+fn default_input_device() -> Option<u32> {
+    read_u32(SYSTEM_OBJECT, system_address(DEFAULT_INPUT_DEVICE))
+        .filter(|device| *device != UNKNOWN_OBJECT)
+}
+
+fn system_address(selector: u32) -> PropertyAddress {
+    PropertyAddress {
+        selector,
+        scope: SCOPE_GLOBAL,
+        element: ELEMENT_MAIN,
+    }
+}
+
+fn input_address(selector: u32, element: u32) -> PropertyAddress {
+    PropertyAddress {
+        selector,
+        scope: SCOPE_INPUT,
+        element,
+    }
+}
+
+fn has_property(object: u32, address: &PropertyAddress) -> bool {
+    // SAFETY: the address is valid for the call
+    unsafe { AudioObjectHasProperty(object, address) != 0 }
+}
+
+fn read_u32(object: u32, address: PropertyAddress) -> Option<u32> {
+    let mut value: u32 = 0;
+    read(object, &address, &mut value as *mut u32 as *mut c_void).then_some(value)
+}
+
+fn read_f32(object: u32, address: PropertyAddress) -> Option<f32> {
+    let mut value: f32 = 0.0;
+    read(object, &address, &mut value as *mut f32 as *mut c_void).then_some(value)
+}
+
+/// Read a 4-byte property into `data`
+fn read(object: u32, address: &PropertyAddress, data: *mut c_void) -> bool {
+    if !has_property(object, address) {
+        return false;
+    }
+    let mut size: u32 = 4;
+    // SAFETY: `data` points to 4 writable bytes, as `size` says
+    let status = unsafe {
+        AudioObjectGetPropertyData(object, address, 0, std::ptr::null(), &mut size, data)
+    };
+    status == 0 && size == 4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_prefers_mute_over_volume() {
+        assert_eq!(classify(Some(true), &[0.0]), Some(InputSilence::Muted));
+        assert_eq!(
+            classify(Some(false), &[0.0, 0.0]),
+            Some(InputSilence::VolumeZero)
+        );
+        assert_eq!(classify(Some(false), &[0.0, 0.4]), None);
+    }
+
+    #[test]
+    fn test_classify_without_controls_is_not_silent() {
+        assert_eq!(classify(None, &[]), None);
+        assert_eq!(classify(Some(false), &[]), None);
+    }
+}
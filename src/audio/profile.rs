@@ -0,0 +1,155 @@
+//! Sample rate changes of the input device mid-stream
+//!
+//! When a Bluetooth headset starts using its microphone it switches from its
+//! high-quality profile to the hands-free one, and macOS drops the input's
+//! sample rate to 8, 16 or 24 kHz. Transcription then gets worse without any
+//! error. The capture thread checks the device's rate every second; when it
+//! changes, the stream is reopened at the new rate so the audio stays
+//! correctly resampled, and `AppEvent::InputRateChanged` is published so the
+//! user can be warned and offered another microphone.
+
+use cpal::traits::{DeviceTrait, HostTrait};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::event_bus::{self, AppEvent};
+
+/// Highest input rate of a Bluetooth headset's hands-free profile
+pub(crate) const CALL_QUALITY_RATE: u32 = 24_000;
+
+/// How often the capture thread checks for a stop or a switch
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Polls between checks of the device's sample rate
+const RATE_CHECK_POLLS: u32 = 10;
+
+/// Name of the device capture is on
+static CURRENT_DEVICE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Name of the device picked to continue capturing on
+static SWITCH_REQUEST: Mutex<Option<String>> = Mutex::new(None);
+
+/// Wait while `is_capturing` is set and return the device to reopen on.
+///
+/// That is `device` again when its rate moved away from `sample_rate`, the
+/// device picked with [`switch_input_device`], or None once capture stops.
+pub(super) fn watch(
+    host: &cpal::Host,
+    device: &cpal::Device,
+    sample_rate: u32,
+    is_capturing: &AtomicBool,
+) -> Option<cpal::Device> {
+    if let Ok(mut current) = CURRENT_DEVICE.lock() {
+        *current = device.name().ok();
+    }
+
+    let mut polls: u32 = 0;
+    while is_capturing.load(Ordering::SeqCst) {
+        thread::sleep(POLL_INTERVAL);
+
+        if let Some(name) = take_switch_request() {
+            match find_input_device(host, &name) {
+                Some(next) => {
+                    info!("Switching to the picked input device");
+                    return Some(next);
+                }
+                None => warn!("The picked input device is no longer available"),
+            }
+        }
+
+        polls = polls.wrapping_add(1);
+        if polls % RATE_CHECK_POLLS != 0 {
+            continue;
+        }
+        let Ok(config) = device.default_input_config() else {
+            continue;
+        };
+        let rate = config.sample_rate().0;
+        if rate != sample_rate {
+            warn!(
+                "Input sample rate changed from {} Hz to {} Hz, reopening the stream",
+                sample_rate, rate
+            );
+            event_bus::publish(AppEvent::InputRateChanged {
+                from: sample_rate,
+                to: rate,
+            });
+            return Some(device.clone());
+        }
+    }
+    None
+}
+
+/// Continue the running capture on the input device named `name`.
+pub(crate) fn switch_input_device(name: String) {
+    if let Ok(mut request) = SWITCH_REQUEST.lock() {
+        *request = Some(name);
+    }
+}
+
+/// Take the pending switch, so a new capture starts on the default device
+pub(super) fn take_switch_request() -> Option<String> {
+    SWITCH_REQUEST
+        .lock()
+        .ok()
+        .and_then(|mut request| request.take())
+}
+
+/// Name of the input device, other than the one in use, that records at the
+/// highest rate above call quality
+pub(crate) fn alternative_input_device() -> Option<String> {
+    let current = CURRENT_DEVICE
+        .lock()
+        .ok()
+        .and_then(|current| current.clone());
+    let devices: Vec<(String, u32)> = cpal::default_host()
+        .input_devices()
+        .ok()?
+        .filter_map(|device| {
+            let name = device.name().ok()?;
+            let max_rate = device
+                .supported_input_configs()
+                .ok()?
+                .map(|config| config.max_sample_rate().0)
+                .max()?;
+            Some((name, max_rate))
+        })
+        .collect();
+    best_alternative(&devices, current.as_deref())
+}
+
+fn best_alternative(devices: &[(String, u32)], current: Option<&str>) -> Option<String> {
+    devices
+        .iter()
+        .filter(|(name, rate)| Some(name.as_str()) != current && *rate > CALL_QUALITY_RATE)
+        .max_by_key(|(_, rate)| *rate)
+        .map(|(name, _)| name.clone())
+}
+
+fn find_input_device(host: &cpal::Host, name: &str) -> Option<cpal::Device> {
+    host.input_devices()
+        .ok()?
+        .find(|device| device.name().ok().as_deref() == Some(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_alternative_skips_current_and_call_quality_devices() {
+        let devices = [
+            ("Headset".to_string(), 16_000),
+            ("MacBook Pro Microphone".to_string(), 48_000),
+            ("USB Microphone".to_string(), 96_000),
+        ];
+        assert_eq!(
+            best_alternative(&devices, Some("USB Microphone")).as_deref(),
+            Some("MacBook Pro Microphone")
+        );
+        assert_eq!(best_alternative(&devices[..1], None), None);
+    }
+}
@@ -1,21 +1,29 @@
 //! Audio resampling and sample processing
 
 use super::types::AudioChunk;
-use super::TARGET_SAMPLE_RATE;
 use rubato::{Resampler, SincFixedIn};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tokio::sync::mpsc;
 use tracing::{error, warn};
 
-/// Chunk size in samples (0.1 seconds of audio at 16kHz = 1600 samples)
-pub(crate) const CHUNK_SIZE: usize = 1600;
+/// Samples per chunk before and after resampling
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChunkSizes {
+    /// Input samples passed to the resampler at a time
+    pub(crate) input: usize,
+    /// Samples per chunk sent to the speech service
+    pub(crate) output: usize,
+    /// Sample rate of the chunks sent
+    pub(crate) output_rate: u32,
+}
 
 /// Process incoming audio samples: convert to mono, optionally resample, buffer, and send chunks
 pub(crate) fn process_samples(
     data: &[i16],
     channels: usize,
     input_buffer: &Arc<Mutex<Vec<i16>>>,
-    input_chunk_size: usize,
+    sizes: ChunkSizes,
     output_buffer: &Arc<Mutex<Vec<i16>>>,
     sender: &mpsc::Sender<AudioChunk>,
     resampler: &Option<Arc<Mutex<SincFixedIn<f32>>>>,
@@ -37,14 +45,14 @@ pub(crate) fn process_samples(
         process_with_resampler(
             &mono_samples,
             input_buffer,
-            input_chunk_size,
+            sizes,
             output_buffer,
             sender,
             resampler_arc,
         );
     } else {
         // No resampling needed - direct buffering
-        process_direct(&mono_samples, output_buffer, sender);
+        process_direct(&mono_samples, output_buffer, sizes, sender);
     }
 }
 
@@ -52,7 +60,7 @@ pub(crate) fn process_samples(
 fn process_with_resampler(
     mono_samples: &[i16],
     input_buffer: &Arc<Mutex<Vec<i16>>>,
-    input_chunk_size: usize,
+    sizes: ChunkSizes,
     output_buffer: &Arc<Mutex<Vec<i16>>>,
     sender: &mpsc::Sender<AudioChunk>,
     resampler_arc: &Arc<Mutex<SincFixedIn<f32>>>,
@@ -62,8 +70,8 @@ fn process_with_resampler(
         input_buf.extend(mono_samples);
 
         // Process complete chunks through the resampler
-        while input_buf.len() >= input_chunk_size {
-            let input_chunk: Vec<i16> = input_buf.drain(..input_chunk_size).collect();
+        while input_buf.len() >= sizes.input {
+            let input_chunk: Vec<i16> = input_buf.drain(..sizes.input).collect();
 
             // Convert i16 to f32 for resampling
             let input_f32: Vec<f32> = input_chunk.iter().map(|&s| s as f32 / 32768.0).collect();
@@ -92,23 +100,25 @@ fn process_with_resampler(
     }
 
     // Send chunks from output buffer
-    send_chunks(output_buffer, sender);
+    send_chunks(output_buffer, sizes, sender);
 }
 
 /// Process samples directly without resampling
 fn process_direct(
     mono_samples: &[i16],
     output_buffer: &Arc<Mutex<Vec<i16>>>,
+    sizes: ChunkSizes,
     sender: &mpsc::Sender<AudioChunk>,
 ) {
     if let Ok(mut output_buf) = output_buffer.lock() {
         output_buf.extend(mono_samples);
 
-        while output_buf.len() >= CHUNK_SIZE {
-            let chunk: Vec<i16> = output_buf.drain(..CHUNK_SIZE).collect();
+        while output_buf.len() >= sizes.output {
+            let chunk: Vec<i16> = output_buf.drain(..sizes.output).collect();
             let audio_chunk = AudioChunk {
                 samples: chunk,
-                sample_rate: TARGET_SAMPLE_RATE, // Should already be 16kHz
+                sample_rate: sizes.output_rate,
+                captured_at: Instant::now(),
             };
             // Use try_send to avoid blocking the audio callback
             match sender.try_send(audio_chunk) {
@@ -123,13 +133,18 @@ fn process_direct(
 }
 
 /// Send complete chunks from the output buffer
-fn send_chunks(output_buffer: &Arc<Mutex<Vec<i16>>>, sender: &mpsc::Sender<AudioChunk>) {
+fn send_chunks(
+    output_buffer: &Arc<Mutex<Vec<i16>>>,
+    sizes: ChunkSizes,
+    sender: &mpsc::Sender<AudioChunk>,
+) {
     if let Ok(mut output_buf) = output_buffer.lock() {
-        while output_buf.len() >= CHUNK_SIZE {
-            let chunk: Vec<i16> = output_buf.drain(..CHUNK_SIZE).collect();
+        while output_buf.len() >= sizes.output {
+            let chunk: Vec<i16> = output_buf.drain(..sizes.output).collect();
             let audio_chunk = AudioChunk {
                 samples: chunk,
-                sample_rate: TARGET_SAMPLE_RATE,
+                sample_rate: sizes.output_rate,
+                captured_at: Instant::now(),
             };
             // Use try_send to avoid blocking the audio callback
             match sender.try_send(audio_chunk) {
@@ -142,3 +157,66 @@ fn send_chunks(output_buffer: &Arc<Mutex<Vec<i16>>>, sender: &mpsc::Sender<Audio
         }
     }
 }
+
+/// Convert a chunk to another sample rate by linear interpolation
+///
+/// For audio that has to be sent again to a provider expecting a different
+/// rate, where a few seconds of speech don't warrant a sinc resampler.
+pub(crate) fn convert_chunk_rate(chunk: &AudioChunk, sample_rate: u32) -> AudioChunk {
+    if chunk.sample_rate == sample_rate || chunk.samples.is_empty() {
+        return AudioChunk {
+            sample_rate,
+            ..chunk.clone()
+        };
+    }
+
+    let ratio = chunk.sample_rate as f64 / sample_rate as f64;
+    let len = (chunk.samples.len() as f64 / ratio).round().max(1.0) as usize;
+    let last = chunk.samples.len() - 1;
+    let samples = (0..len)
+        .map(|i| {
+            let position = i as f64 * ratio;
+            let index = (position as usize).min(last);
+            let next = (index + 1).min(last);
+            let fraction = position - index as f64;
+            let a = chunk.samples[index] as f64;
+            let b = chunk.samples[next] as f64;
+            (a + (b - a) * fraction).round() as i16
+        })
+        .collect();
+
+    AudioChunk {
+        samples,
+        sample_rate,
+        captured_at: chunk.captured_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(samples: Vec<i16>, sample_rate: u32) -> AudioChunk {
+        AudioChunk {
+            samples,
+            sample_rate,
+            captured_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_convert_keeps_duration() {
+        let up = convert_chunk_rate(&chunk(vec![0; 1600], 16000), 24000);
+        assert_eq!(up.sample_rate, 24000);
+        assert_eq!(up.samples.len(), 2400);
+
+        let down = convert_chunk_rate(&chunk(vec![0; 2400], 24000), 16000);
+        assert_eq!(down.samples.len(), 1600);
+    }
+
+    #[test]
+    fn test_convert_interpolates_between_samples() {
+        let up = convert_chunk_rate(&chunk(vec![0, 300, 600, 900], 16000), 32000);
+        assert_eq!(up.samples, vec![0, 150, 300, 450, 600, 750, 900, 900]);
+    }
+}
@@ -0,0 +1,195 @@
+//! Input stream setup
+//!
+//! Opens a cpal input stream on a device at the rate closest to the target,
+//! resampling to the target rate and mixing down to mono as it goes.
+
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::SampleFormat;
+use rubato::{SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use super::resampler::{process_samples, ChunkSizes};
+use super::types::{AudioCaptureError, AudioChunk};
+
+/// Open and start an input stream on `device`, returning it with the rate
+/// the device captures at
+///
+/// Chunks of `chunk_size` samples at `target_sample_rate` are sent to
+/// `chunk_tx` while `is_capturing` is set.
+pub(super) fn open(
+    device: &cpal::Device,
+    is_capturing: &Arc<AtomicBool>,
+    chunk_tx: &mpsc::Sender<AudioChunk>,
+    target_sample_rate: u32,
+    chunk_size: usize,
+) -> Result<(cpal::Stream, u32), AudioCaptureError> {
+    // Get supported configs and find one closest to our target
+    let supported_configs = device
+        .supported_input_configs()
+        .map_err(|e| AudioCaptureError::ConfigError(e.to_string()))?;
+
+    // Try to find a config with target rate, or fall back to any supported rate
+    let mut best_config = None;
+    let mut found_target_rate = false;
+
+    for config in supported_configs {
+        let channels = config.channels();
+        if channels > 0 {
+            if config.min_sample_rate().0 <= target_sample_rate
+                && config.max_sample_rate().0 >= target_sample_rate
+            {
+                best_config = Some(config.with_sample_rate(cpal::SampleRate(target_sample_rate)));
+                found_target_rate = true;
+                break;
+            } else if best_config.is_none() {
+                best_config = Some(config.with_max_sample_rate());
+            }
+        }
+    }
+
+    let supported_config = best_config.ok_or(AudioCaptureError::NoSupportedConfig)?;
+
+    if !found_target_rate {
+        warn!(
+            "{}Hz not supported, using {}Hz instead",
+            target_sample_rate,
+            supported_config.sample_rate().0
+        );
+    }
+
+    let config: cpal::StreamConfig = supported_config.into();
+    let sample_rate = config.sample_rate.0;
+    let channels = config.channels as usize;
+
+    info!("Audio config: {} channels, {} Hz", channels, sample_rate);
+
+    // Create resampler if sample rate doesn't match target
+    let (resampler, input_chunk_size): (Option<Arc<Mutex<SincFixedIn<f32>>>>, usize) =
+        if sample_rate != target_sample_rate {
+            info!(
+                "Creating resampler: {} Hz -> {} Hz",
+                sample_rate, target_sample_rate
+            );
+            let params = SincInterpolationParameters {
+                sinc_len: 256,
+                f_cutoff: 0.95,
+                interpolation: SincInterpolationType::Linear,
+                oversampling_factor: 256,
+                window: WindowFunction::BlackmanHarris2,
+            };
+            // Calculate chunk size that will produce target sample rate chunks
+            let input_frames = (chunk_size as f64 * sample_rate as f64 / target_sample_rate as f64)
+                .ceil() as usize;
+            match SincFixedIn::<f32>::new(
+                target_sample_rate as f64 / sample_rate as f64,
+                2.0,
+                params,
+                input_frames,
+                1, // mono
+            ) {
+                Ok(resampler) => {
+                    info!(
+                        "Resampler configured: input {} samples -> output {} samples",
+                        input_frames, chunk_size
+                    );
+                    (Some(Arc::new(Mutex::new(resampler))), input_frames)
+                }
+                Err(e) => {
+                    error!("Failed to create resampler: {}", e);
+                    (None, chunk_size)
+                }
+            }
+        } else {
+            (None, chunk_size)
+        };
+
+    // Buffer for accumulating resampled output samples (after resampling)
+    let output_buffer: Arc<Mutex<Vec<i16>>> =
+        Arc::new(Mutex::new(Vec::with_capacity(chunk_size * 2)));
+    let output_buffer_clone = output_buffer.clone();
+
+    // Buffer for accumulating input samples (before resampling)
+    let input_buffer: Arc<Mutex<Vec<i16>>> =
+        Arc::new(Mutex::new(Vec::with_capacity(input_chunk_size * 2)));
+    let input_buffer_clone = input_buffer.clone();
+
+    let sizes = ChunkSizes {
+        input: input_chunk_size,
+        output: chunk_size,
+        output_rate: target_sample_rate,
+    };
+    let resampler_clone = resampler.clone();
+
+    let is_capturing_stream = is_capturing.clone();
+    let chunk_tx_clone = chunk_tx.clone();
+
+    let err_callback = |err| {
+        error!("Audio stream error: {}", err);
+    };
+
+    // Build the input stream based on sample format
+    let stream = match device.default_input_config()?.sample_format() {
+        SampleFormat::I16 => device.build_input_stream(
+            &config,
+            move |data: &[i16], _| {
+                if !is_capturing_stream.load(Ordering::SeqCst) {
+                    return;
+                }
+                process_samples(
+                    data,
+                    channels,
+                    &input_buffer_clone,
+                    sizes,
+                    &output_buffer_clone,
+                    &chunk_tx_clone,
+                    &resampler_clone,
+                );
+            },
+            err_callback,
+            None,
+        )?,
+        SampleFormat::F32 => {
+            let is_capturing_f32 = is_capturing.clone();
+            let input_buffer_f32 = input_buffer.clone();
+            let output_buffer_f32 = output_buffer.clone();
+            let chunk_tx_f32 = chunk_tx.clone();
+            let resampler_f32 = resampler.clone();
+            device.build_input_stream(
+                &config,
+                move |data: &[f32], _| {
+                    if !is_capturing_f32.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    // Convert f32 to i16
+                    let samples: Vec<i16> = data
+                        .iter()
+                        .map(|&s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
+                        .collect();
+                    process_samples(
+                        &samples,
+                        channels,
+                        &input_buffer_f32,
+                        sizes,
+                        &output_buffer_f32,
+                        &chunk_tx_f32,
+                        &resampler_f32,
+                    );
+                },
+                err_callback,
+                None,
+            )?
+        }
+        sample_format => {
+            return Err(AudioCaptureError::UnsupportedFormat(format!(
+                "{:?}",
+                sample_format
+            )));
+        }
+    };
+
+    stream.play()?;
+    Ok((stream, sample_rate))
+}
@@ -0,0 +1,137 @@
+//! Chunk duration and buffer depth for audio capture
+//!
+//! Shorter chunks reach the speech service sooner but mean more WebSocket
+//! messages and resampler passes per second. The buffer holds chunks while
+//! the connection stalls; once it is full, new audio is dropped.
+//!
+//! Low-bandwidth mode sends at least 300 ms per chunk, since every message
+//! carries its own JSON, WebSocket and TLS overhead.
+
+use crate::preferences;
+
+/// Chunk durations offered in Settings, in milliseconds
+pub(crate) const CHUNK_MS_PRESETS: [u32; 5] = [50, 100, 200, 300, 500];
+
+/// Buffer depths offered in Settings, in seconds of audio
+pub(crate) const BUFFER_SECS_PRESETS: [u32; 4] = [15, 30, 60, 120];
+
+/// Chunk duration used unless changed in Settings
+pub(crate) const DEFAULT_CHUNK_MS: u32 = 100;
+
+/// Buffer depth used unless changed in Settings
+pub(crate) const DEFAULT_BUFFER_SECS: u32 = 60;
+
+const MIN_CHUNK_MS: u32 = 50;
+const MAX_CHUNK_MS: u32 = 500;
+
+/// Shortest chunk duration in low-bandwidth mode
+const LOW_BANDWIDTH_MIN_CHUNK_MS: u32 = 300;
+
+/// How audio is split into chunks and how much is buffered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AudioTuning {
+    /// Audio per chunk in milliseconds (50-500)
+    pub(crate) chunk_ms: u32,
+    /// Seconds of audio held while the connection is slow
+    pub(crate) buffer_secs: u32,
+}
+
+impl Default for AudioTuning {
+    fn default() -> Self {
+        Self {
+            chunk_ms: DEFAULT_CHUNK_MS,
+            buffer_secs: DEFAULT_BUFFER_SECS,
+        }
+    }
+}
+
+impl AudioTuning {
+    /// Tuning from the saved preferences, clamped to the supported range
+    pub(crate) fn from_preferences() -> Self {
+        let tuning = Self {
+            chunk_ms: preferences::get_audio_chunk_ms().clamp(MIN_CHUNK_MS, MAX_CHUNK_MS),
+            buffer_secs: preferences::get_audio_buffer_secs().max(1),
+        };
+        if preferences::get_low_bandwidth_mode() {
+            tuning.for_low_bandwidth()
+        } else {
+            tuning
+        }
+    }
+
+    /// Fewer, longer chunks for low-bandwidth mode
+    fn for_low_bandwidth(self) -> Self {
+        Self {
+            chunk_ms: self.chunk_ms.max(LOW_BANDWIDTH_MIN_CHUNK_MS),
+            ..self
+        }
+    }
+
+    /// Samples in one chunk at `sample_rate`
+    pub(crate) fn chunk_samples(&self, sample_rate: u32) -> usize {
+        (sample_rate as usize * self.chunk_ms as usize / 1000).max(1)
+    }
+
+    /// Chunks the capture channel can hold
+    pub(crate) fn buffer_chunks(&self) -> usize {
+        (self.buffer_secs as usize * 1000 / self.chunk_ms as usize).max(1)
+    }
+
+    /// Plain-language effect of these settings, shown in Settings
+    pub(crate) fn latency_summary(&self) -> String {
+        format!(
+            "Speech reaches the service up to {} ms after it is spoken, in {} messages \
+             per second. Up to {} s of audio ({} chunks) is held while the connection \
+             is slow before audio is dropped. Changes apply to the next recording.",
+            self.chunk_ms,
+            1000 / self.chunk_ms,
+            self.buffer_secs,
+            self.buffer_chunks()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_previous_fixed_values() {
+        let tuning = AudioTuning::default();
+        assert_eq!(tuning.chunk_samples(16000), 1600);
+        assert_eq!(tuning.buffer_chunks(), 600);
+    }
+
+    #[test]
+    fn test_chunk_samples_follow_sample_rate() {
+        let tuning = AudioTuning {
+            chunk_ms: 50,
+            buffer_secs: 30,
+        };
+        assert_eq!(tuning.chunk_samples(16000), 800);
+        assert_eq!(tuning.chunk_samples(24000), 1200);
+        assert_eq!(tuning.buffer_chunks(), 600);
+    }
+
+    #[test]
+    fn test_low_bandwidth_keeps_longer_chunks() {
+        let short = AudioTuning::default().for_low_bandwidth();
+        assert_eq!(short.chunk_ms, LOW_BANDWIDTH_MIN_CHUNK_MS);
+        assert_eq!(short.buffer_secs, DEFAULT_BUFFER_SECS);
+
+        let long = AudioTuning {
+            chunk_ms: 500,
+            buffer_secs: 30,
+        };
+        assert_eq!(long.for_low_bandwidth(), long);
+    }
+
+    #[test]
+    fn test_buffer_holds_at_least_one_chunk() {
+        let tuning = AudioTuning {
+            chunk_ms: 500,
+            buffer_secs: 0,
+        };
+        assert_eq!(tuning.buffer_chunks(), 1);
+    }
+}
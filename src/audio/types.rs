@@ -3,6 +3,7 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread::JoinHandle;
+use std::time::Instant;
 use tracing::info;
 
 /// Audio chunk ready to be sent over WebSocket
@@ -15,6 +16,8 @@ pub struct AudioChunk {
     pub samples: Vec<i16>,
     /// Sample rate in Hz (typically 16000)
     pub sample_rate: u32,
+    /// When the chunk's last sample was captured, for measuring latency
+    pub captured_at: Instant,
 }
 
 /// Handle for controlling audio capture from outside the capture thread
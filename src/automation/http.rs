@@ -0,0 +1,187 @@
+//! Local HTTP API for stream overlays and dashboards
+//!
+//! Listens on 127.0.0.1 only. Every request must carry the token shown in
+//! Settings, either as `Authorization: Bearer <token>` or as a `?token=`
+//! query parameter (OBS browser sources cannot set headers).
+//!
+//! - `GET /status` - session status
+//! - `GET /transcript` - current transcript
+//! - `POST /polish` - polish the current transcript, optional body `{"mode": "meeting_notes"}`
+//! - `GET /events` - app events as a server-sent event stream
+
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response as HttpResponse};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::info;
+
+use super::protocol::{Command, PolishMode, Response};
+use crate::event_bus;
+
+/// Body of `POST /polish`
+#[derive(Debug, Default, Deserialize)]
+struct PolishRequest {
+    #[serde(default)]
+    mode: PolishMode,
+}
+
+/// Serve the API on 127.0.0.1:`port` until the task is aborted.
+pub(super) async fn serve(port: u16, token: String) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+    info!("HTTP API listening on http://127.0.0.1:{}", port);
+    axum::serve(listener, router(token)).await
+}
+
+fn router(token: String) -> Router {
+    Router::new()
+        .route("/status", get(status))
+        .route("/transcript", get(transcript))
+        .route("/polish", post(polish))
+        .route("/events", get(events))
+        .layer(middleware::from_fn_with_state(Arc::new(token), authorize))
+}
+
+/// Reject requests without the API token and allow cross-origin access
+/// (browser sources and dashboards are served from other origins).
+async fn authorize(State(token): State<Arc<String>>, request: Request, next: Next) -> HttpResponse {
+    if request.method() == Method::OPTIONS {
+        return with_cors(StatusCode::NO_CONTENT.into_response());
+    }
+
+    let response = if is_authorized(request.headers(), request.uri().query(), &token) {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            Json(Response::error("Missing or invalid token")),
+        )
+            .into_response()
+    };
+    with_cors(response)
+}
+
+async fn status() -> HttpResponse {
+    reply(super::execute(Command::Status).await)
+}
+
+async fn transcript() -> HttpResponse {
+    reply(super::execute(Command::Transcript).await)
+}
+
+async fn polish(body: Option<Json<PolishRequest>>) -> HttpResponse {
+    let Json(request) = body.unwrap_or_default();
+    reply(super::execute(Command::Polish { mode: request.mode }).await)
+}
+
+/// Stream app events as JSON until the client disconnects
+async fn events() -> HttpResponse {
+    let stream = futures_util::stream::unfold(event_bus::subscribe(), |mut events| async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => return Some((SseEvent::default().json_data(&event), events)),
+                // A slow client misses events rather than holding them up
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Map a command response to HTTP (failures are state conflicts like "Not recording")
+fn reply(response: Response) -> HttpResponse {
+    let status = if response.ok {
+        StatusCode::OK
+    } else {
+        StatusCode::CONFLICT
+    };
+    (status, Json(response)).into_response()
+}
+
+fn with_cors(mut response: HttpResponse) -> HttpResponse {
+    let headers = response.headers_mut();
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+        HeaderValue::from_static("*"),
+    );
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("authorization, content-type"),
+    );
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("GET, POST, OPTIONS"),
+    );
+    response
+}
+
+/// Whether a request carries `token`, in a header or the query
+pub(crate) fn is_authorized(headers: &HeaderMap, query: Option<&str>, token: &str) -> bool {
+    request_token(headers, query).is_some_and(|provided| tokens_match(provided, token))
+}
+
+/// Token from the `Authorization: Bearer` header, falling back to `?token=`
+fn request_token<'a>(headers: &'a HeaderMap, query: Option<&'a str>) -> Option<&'a str> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if let Some(token) = bearer {
+        return Some(token.trim());
+    }
+
+    query?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+}
+
+/// Compare tokens without exiting early on the first mismatch
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_token_sources() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(request_token(&headers, None), None);
+        assert_eq!(
+            request_token(&headers, Some("format=plain&token=abc123")),
+            Some("abc123")
+        );
+
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer def456"),
+        );
+        assert_eq!(
+            request_token(&headers, Some("token=abc123")),
+            Some("def456")
+        );
+    }
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("abc123", "abc123"));
+        assert!(!tokens_match("abc124", "abc123"));
+        assert!(!tokens_match("abc", "abc123"));
+        assert!(!tokens_match("", "abc123"));
+    }
+}
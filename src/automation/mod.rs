@@ -0,0 +1,384 @@
+//! Scriptable control over a local Unix socket
+//!
+//! When enabled in the Integrations tab of Settings, Vissper accepts
+//! newline-delimited JSON commands on a Unix socket in its config directory,
+//! so launchers like Raycast or Alfred and Shortcuts can start and stop
+//! recording, take screenshots and query the session state:
+//!
+//! ```text
+//! echo '{"command":"status"}' | nc -U ~/Library/Application\ Support/Vissper/vissper.sock
+//! ```
+//!
+//! The socket is only accessible to the current user (mode 0600).
+//!
+//! A token-protected HTTP API on localhost (see [`http`]) exposes the same
+//! status and transcript for stream overlays and dashboards.
+
+mod http;
+mod protocol;
+
+pub(crate) use http::is_authorized;
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::app_context::AppContext;
+use crate::callbacks;
+use crate::error::KeychainError;
+use crate::keychain;
+use crate::managed;
+use crate::preferences;
+use crate::private_mode;
+use crate::recording::{self, RecordingSession};
+use crate::transcription_window::{TabType, TranscriptionWindow};
+use protocol::{Command, PolishMode, Response, SessionStatus, StopMode};
+
+/// Shared state the command handlers act on
+struct AutomationContext {
+    app: AppContext,
+    runtime: tokio::runtime::Handle,
+}
+
+static CONTEXT: OnceCell<AutomationContext> = OnceCell::new();
+
+/// The running socket server, if enabled
+static SOCKET_SERVER: Lazy<Mutex<Option<JoinHandle<()>>>> = Lazy::new(|| Mutex::new(None));
+
+/// The running HTTP API server, if enabled
+static HTTP_SERVER: Lazy<Mutex<Option<JoinHandle<()>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Location of the automation socket
+pub(crate) fn socket_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("Vissper").join("vissper.sock"))
+}
+
+/// Store the shared state and start whichever servers are enabled.
+pub(crate) fn init(app: AppContext, runtime: tokio::runtime::Handle) {
+    let context = AutomationContext { app, runtime };
+    if CONTEXT.set(context).is_err() {
+        warn!("Automation already initialized");
+        return;
+    }
+
+    if managed::policy().disable_automation {
+        info!("Automation socket and HTTP API disabled by managed policy");
+        return;
+    }
+
+    if preferences::get_automation_socket_enabled() {
+        start_socket_server();
+    }
+    if preferences::get_http_api_enabled() {
+        start_http_server();
+    }
+}
+
+/// Enable or disable the automation socket and persist the choice.
+pub(crate) fn set_enabled(enabled: bool) -> Result<(), preferences::PreferencesError> {
+    preferences::set_automation_socket_enabled(enabled)?;
+    if enabled {
+        start_socket_server();
+    } else if stop_server(&SOCKET_SERVER) {
+        if let Some(path) = socket_path() {
+            let _ = std::fs::remove_file(path);
+        }
+        info!("Automation socket stopped");
+    }
+    Ok(())
+}
+
+/// Enable or disable the local HTTP API and persist the choice.
+pub(crate) fn set_http_api_enabled(enabled: bool) -> Result<(), preferences::PreferencesError> {
+    preferences::set_http_api_enabled(enabled)?;
+    if enabled {
+        start_http_server();
+    } else if stop_server(&HTTP_SERVER) {
+        info!("HTTP API stopped");
+    }
+    Ok(())
+}
+
+/// The token required by the HTTP API, generated on first use and kept in
+/// the keychain.
+pub(crate) fn http_api_token() -> Result<String, KeychainError> {
+    match keychain::get_http_api_token()? {
+        Some(token) if !token.is_empty() => Ok(token),
+        _ => regenerate_http_api_token(),
+    }
+}
+
+/// Replace the HTTP API token, restarting the server so the old one stops working.
+pub(crate) fn regenerate_http_api_token() -> Result<String, KeychainError> {
+    let token: String = rand::random::<[u8; 16]>()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    keychain::store_http_api_token(&token)?;
+
+    if stop_server(&HTTP_SERVER) {
+        start_http_server();
+    }
+    Ok(token)
+}
+
+fn start_socket_server() {
+    let Some(path) = socket_path() else {
+        error!("Could not determine automation socket path");
+        return;
+    };
+    spawn_server(&SOCKET_SERVER, async move {
+        if let Err(e) = serve(&path).await {
+            error!("Automation socket stopped: {}", e);
+        }
+    });
+}
+
+fn start_http_server() {
+    let token = match http_api_token() {
+        Ok(token) => token,
+        Err(e) => {
+            error!("Cannot start HTTP API without a token: {}", e);
+            return;
+        }
+    };
+    let port = preferences::get_http_api_port();
+    spawn_server(&HTTP_SERVER, async move {
+        if let Err(e) = http::serve(port, token).await {
+            error!("HTTP API stopped: {}", e);
+        }
+    });
+}
+
+/// Spawn `server` into `slot` unless a server is already running there.
+fn spawn_server(
+    slot: &Mutex<Option<JoinHandle<()>>>,
+    server: impl std::future::Future<Output = ()> + Send + 'static,
+) {
+    let Some(context) = CONTEXT.get() else {
+        warn!("Automation server requested before initialization");
+        return;
+    };
+    if managed::policy().disable_automation {
+        warn!("Automation server refused: disabled by managed policy");
+        return;
+    }
+    let Ok(mut slot) = slot.lock() else {
+        return;
+    };
+    if slot.as_ref().is_some_and(|handle| !handle.is_finished()) {
+        return;
+    }
+    *slot = Some(context.runtime.spawn(server));
+}
+
+/// Abort the server in `slot`. Returns whether one was running.
+fn stop_server(slot: &Mutex<Option<JoinHandle<()>>>) -> bool {
+    let handle = slot.lock().ok().and_then(|mut slot| slot.take());
+    match handle {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Bind the socket and accept connections until the task is aborted.
+async fn serve(path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    // Remove a socket left behind by a previous run
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    info!("Automation socket listening at {:?}", path);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(handle_connection(stream));
+    }
+}
+
+/// Answer each command line on a connection until the client disconnects.
+async fn handle_connection(stream: UnixStream) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match protocol::parse_command(&line) {
+            Ok(command) => {
+                debug!(?command, "Automation command");
+                execute(command).await
+            }
+            Err(e) => Response::error(e),
+        };
+
+        let mut reply = response.to_line();
+        reply.push('\n');
+        if writer.write_all(reply.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn execute(command: Command) -> Response {
+    let Some(context) = CONTEXT.get() else {
+        return Response::error("Automation not initialized");
+    };
+    let app = &context.app;
+    let recording_state = &app.recording_state;
+    let is_recording = app.app_state.is_recording.load(Ordering::SeqCst);
+
+    match command {
+        Command::StartRecording => {
+            if is_recording {
+                return Response::error("Already recording");
+            }
+            info!("Automation: Starting recording");
+            recording::start_recording(recording_state.clone(), app.ui.clone(), true);
+            Response::ok()
+        }
+        Command::StopRecording { mode } => {
+            if !is_recording {
+                return Response::error("Not recording");
+            }
+            info!(?mode, "Automation: Stopping recording");
+            match mode {
+                StopMode::None => {
+                    recording::stop_recording_no_polish(recording_state.clone(), app.ui.clone())
+                }
+                StopMode::Basic => {
+                    recording::stop_recording(recording_state.clone(), app.ui.clone())
+                }
+                StopMode::MeetingNotes => {
+                    recording::stop_live_meeting_recording(recording_state.clone(), app.ui.clone())
+                }
+                StopMode::FollowUpEmail => recording::stop_follow_up_email_recording(
+                    recording_state.clone(),
+                    app.ui.clone(),
+                ),
+            }
+            Response::ok()
+        }
+        Command::Screenshot => {
+            let recording_state = recording_state.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                callbacks::capture_screenshot_into_session(&recording_state)
+            })
+            .await;
+            match result {
+                Ok(Ok(_)) => Response::ok(),
+                Ok(Err(e)) => Response::error(e.to_string()),
+                Err(e) => Response::error(format!("Screenshot task failed: {}", e)),
+            }
+        }
+        Command::RegionScreenshot => {
+            crate::region_selection::RegionSelection::start(recording_state.clone());
+            Response::ok()
+        }
+        Command::LastRegionScreenshot => {
+            let recording_state = recording_state.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                callbacks::capture_last_region_into_session(&recording_state)
+            })
+            .await;
+            match result {
+                Ok(Ok(_)) => Response::ok(),
+                Ok(Err(e)) => Response::error(e.to_string()),
+                Err(e) => Response::error(format!("Screenshot task failed: {}", e)),
+            }
+        }
+        Command::Marker => {
+            if !is_recording {
+                return Response::error("Not recording");
+            }
+            recording::insert_marker(recording_state);
+            Response::ok()
+        }
+        Command::Note { text } => {
+            if !is_recording {
+                return Response::error("Not recording");
+            }
+            if text.trim().is_empty() {
+                return Response::error("Note text is empty");
+            }
+            recording::insert_note(recording_state, &text);
+            Response::ok()
+        }
+        Command::ShowWindow => {
+            TranscriptionWindow::show();
+            Response::ok()
+        }
+        Command::Polish { mode } => {
+            if app.app_state.is_processing.load(Ordering::SeqCst) {
+                return Response::error("Already processing");
+            }
+            if managed::policy().disable_polishing {
+                return Response::error("Polishing is turned off by your organization");
+            }
+            if private_mode::skips_polishing() {
+                return Response::error("Polishing is turned off during private sessions");
+            }
+            let transcript = current_transcript(recording_state).await;
+            if transcript.trim().is_empty() {
+                return Response::error("No transcript to polish");
+            }
+            let tab = match mode {
+                PolishMode::Basic => TabType::BasicPolish,
+                PolishMode::MeetingNotes => TabType::MeetingNotes,
+                PolishMode::FollowUpEmail => TabType::FollowUpEmail,
+            };
+            info!(?mode, "Automation: Polishing transcript");
+            tokio::spawn(recording::polish_transcript_on_demand(
+                transcript,
+                tab,
+                app.ui.clone(),
+            ));
+            Response::ok()
+        }
+        Command::Status => Response::with_status(SessionStatus {
+            recording: is_recording,
+            processing: app.app_state.is_processing.load(Ordering::SeqCst),
+            provider: preferences::get_ai_provider().to_string(),
+            language: preferences::get_language_code(),
+            transcript_chars: current_transcript(recording_state).await.chars().count(),
+        }),
+        Command::Transcript => Response::with_transcript(current_transcript(recording_state).await),
+    }
+}
+
+/// Transcript of the active session, or the one still shown in the window
+/// after recording has finished
+///
+/// Commands run on the runtime, and the window can only be read on the main
+/// thread, so the window's transcript is fetched there.
+async fn current_transcript(recording_state: &Arc<Mutex<Option<RecordingSession>>>) -> String {
+    let transcript = recording::get_full_transcript(recording_state);
+    if !transcript.is_empty() {
+        return transcript;
+    }
+    let (sender, receiver) = oneshot::channel();
+    dispatch::Queue::main().exec_async(move || {
+        let _ = sender.send(TranscriptionWindow::get_live_transcript());
+    });
+    receiver.await.ok().flatten().unwrap_or_default()
+}
@@ -0,0 +1,189 @@
+//! Automation socket wire format
+//!
+//! One JSON object per line in each direction, e.g.
+//! `{"command": "stop_recording", "mode": "meeting_notes"}` answered by
+//! `{"ok": true}`.
+
+use serde::{Deserialize, Serialize};
+
+/// How to finish a recording stopped through automation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StopMode {
+    /// Raw transcript, no polishing
+    #[default]
+    None,
+    /// Basic polishing
+    Basic,
+    /// Meeting notes
+    MeetingNotes,
+    /// Follow-up email
+    FollowUpEmail,
+}
+
+/// Which polished version to produce from the current transcript
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PolishMode {
+    #[default]
+    Basic,
+    MeetingNotes,
+    FollowUpEmail,
+}
+
+/// A command sent by an automation client
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub(crate) enum Command {
+    StartRecording,
+    StopRecording {
+        #[serde(default)]
+        mode: StopMode,
+    },
+    Screenshot,
+    RegionScreenshot,
+    LastRegionScreenshot,
+    Marker,
+    Note {
+        text: String,
+    },
+    ShowWindow,
+    Polish {
+        #[serde(default)]
+        mode: PolishMode,
+    },
+    Status,
+    Transcript,
+}
+
+/// Snapshot of the app's session state
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct SessionStatus {
+    pub recording: bool,
+    pub processing: bool,
+    pub provider: String,
+    pub language: String,
+    pub transcript_chars: usize,
+}
+
+/// Reply to a single command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Response {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SessionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+}
+
+impl Response {
+    pub(crate) fn ok() -> Self {
+        Self {
+            ok: true,
+            error: None,
+            status: None,
+            transcript: None,
+        }
+    }
+
+    pub(crate) fn error(message: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            error: Some(message.into()),
+            ..Self::ok()
+        }
+    }
+
+    pub(crate) fn with_status(status: SessionStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Self::ok()
+        }
+    }
+
+    pub(crate) fn with_transcript(transcript: String) -> Self {
+        Self {
+            transcript: Some(transcript),
+            ..Self::ok()
+        }
+    }
+
+    /// Serialize as a single line (without the trailing newline)
+    pub(crate) fn to_line(&self) -> String {
+        serde_json::to_string(self)
+            .unwrap_or_else(|_| r#"{"ok":false,"error":"serialization failed"}"#.to_string())
+    }
+}
+
+/// Parse one line from a client.
+pub(crate) fn parse_command(line: &str) -> Result<Command, String> {
+    serde_json::from_str(line.trim()).map_err(|e| format!("Invalid command: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse_command(r#"{"command": "start_recording"}"#),
+            Ok(Command::StartRecording)
+        );
+        assert_eq!(
+            parse_command(r#"{"command": "stop_recording"}"#),
+            Ok(Command::StopRecording {
+                mode: StopMode::None
+            })
+        );
+        assert_eq!(
+            parse_command(r#"{"command": "stop_recording", "mode": "meeting_notes"}"#),
+            Ok(Command::StopRecording {
+                mode: StopMode::MeetingNotes
+            })
+        );
+        assert_eq!(
+            parse_command(r#"{"command": "polish", "mode": "meeting_notes"}"#),
+            Ok(Command::Polish {
+                mode: PolishMode::MeetingNotes
+            })
+        );
+        assert_eq!(
+            parse_command(r#"{"command": "stop_recording", "mode": "follow_up_email"}"#),
+            Ok(Command::StopRecording {
+                mode: StopMode::FollowUpEmail
+            })
+        );
+        assert_eq!(
+            parse_command(r#"{"command": "last_region_screenshot"}"#),
+            Ok(Command::LastRegionScreenshot)
+        );
+        assert_eq!(
+            parse_command(r#" {"command":"note","text":"Bob joined late"} "#),
+            Ok(Command::Note {
+                text: "Bob joined late".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_command() {
+        assert!(parse_command(r#"{"command": "self_destruct"}"#).is_err());
+        assert!(parse_command("status").is_err());
+    }
+
+    #[test]
+    fn test_response_lines() {
+        assert_eq!(Response::ok().to_line(), r#"{"ok":true}"#);
+        assert_eq!(
+            Response::error("Not recording").to_line(),
+            r#"{"ok":false,"error":"Not recording"}"#
+        );
+        assert_eq!(
+            Response::with_transcript("hi".to_string()).to_line(),
+            r#"{"ok":true,"transcript":"hi"}"#
+        );
+    }
+}
@@ -4,9 +4,20 @@
 //! bypassing the VIPS AI Gateway or Supabase edge functions. Users provide
 //! their own Azure OpenAI credentials.
 
+mod routes;
+
+pub(crate) use routes::AzurePolishRoute;
+use routes::{ChatRequest, ChatResponse};
+
+use crate::attendees;
 use crate::error::ResponseError;
 use crate::keychain::AzureCredentials;
-use crate::response::{language_code_to_name, PolishConfig};
+use crate::language_sections;
+use crate::output_modes;
+use crate::response::{language_code_to_name, PolishConfig, FOLLOW_UP_EMAIL_PROMPT_TEMPLATE};
+use crate::retry::{self, RetryNotice};
+use crate::topics;
+use crate::workspaces;
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -24,7 +35,12 @@ pub(crate) struct AzureOpenAIClient {
     endpoint_url: String,
     api_key: String,
     polish_deployment: String,
+    /// Responses API version, e.g. "2025-04-01-preview"
+    api_version: String,
+    /// Route set in the credentials (None = detect)
+    route: Option<AzurePolishRoute>,
     client: reqwest::Client,
+    retry_notice: Option<RetryNotice>,
 }
 
 /// Request body for Azure OpenAI Responses API.
@@ -36,6 +52,14 @@ struct AzurePolishRequest {
     reasoning: Option<Reasoning>,
 }
 
+/// Request body for the route being used.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum PolishBody {
+    Responses(AzurePolishRequest),
+    Chat(ChatRequest),
+}
+
 /// Message in the Azure OpenAI request.
 #[derive(Debug, Serialize)]
 struct Message {
@@ -79,6 +103,8 @@ const POLISH_PROMPT_TEMPLATE: &str = r#"You are an expert copy editor. Your task
 
 IMPORTANT: The transcript may contain screenshot references in markdown image format like `![Screenshot](screenshots/filename.png)`. These must be preserved exactly as they appear, in their original positions within the transcript. Do not modify, remove, or relocate these screenshot references.
 
+The transcript may also contain timestamped markers like `=== MARKER 14:32 ===` that the user added to flag important moments, and typed notes like `> Note: Bob joined late` that add context not in the audio. Preserve each marker and note exactly as it appears, on its own line, at its original position in the transcript. Lines like `[connection lost 14:02–14:03, audio may be missing]` mark where the recording lost its connection; keep them the same way and do not fill in what might have been said.
+
 Return only the polished transcript without any additional commentary."#;
 
 /// System prompt template for live meeting recording.
@@ -90,6 +116,9 @@ Extract and organize the key information into the following sections:
 ## Summary
 Provide a concise overview of what the meeting was about and its main outcomes. Use as many sentences as needed to capture the essence, proportional to the meeting length and complexity.
 
+## Attendees
+List the people who took part in the meeting, one name per bullet point, if they can be identified from the transcript.
+
 ## Main Items
 List the most important topics or points discussed in the meeting. Include only the most significant items, maximum 7 bullet points.
 
@@ -109,18 +138,40 @@ Condense and polish the transcript for readability. Remove filler words, meaning
 
 IMPORTANT: The transcript may contain screenshot references in markdown image format like `![Screenshot](screenshots/filename.png)`. These must be preserved exactly as they appear, in their original positions within the transcript. Do not modify, remove, or relocate these screenshot references.
 
+The transcript may also contain timestamped markers like `=== MARKER 14:32 ===` that the user added to flag important moments, and typed notes like `> Note: Bob joined late` that add context not in the audio. Preserve each marker and note exactly as it appears, on its own line, at its original position in the transcript. Lines like `[connection lost 14:02–14:03, audio may be missing]` mark where the recording lost its connection; keep them the same way and do not fill in what might have been said.
+
 If a section has no relevant content from the transcript, write "None identified" for that section.
 
 Return the output in the format above with the section headers as shown."#;
 
-/// Select the appropriate prompt based on config, with language injected
+/// Select the appropriate prompt based on config, with language and the
+/// session's attendees injected
 fn select_prompt(config: &PolishConfig) -> String {
     let language = language_code_to_name(&config.language_code);
+    attendees::add_to_prompt(
+        prompt_template(config).replace("{language}", language),
+        config,
+    )
+}
+
+/// Prompt template for config, with the active workspace's instructions,
+/// before the language is injected
+pub(crate) fn prompt_template(config: &PolishConfig) -> String {
     let template = match config.prompt_type.as_deref() {
-        Some("live_meeting") => LIVE_MEETING_PROMPT_TEMPLATE,
-        _ => POLISH_PROMPT_TEMPLATE,
+        Some("live_meeting") => LIVE_MEETING_PROMPT_TEMPLATE.to_string(),
+        Some("meeting_topics") => format!(
+            "{}\n\n{}",
+            LIVE_MEETING_PROMPT_TEMPLATE,
+            topics::PROMPT_INSTRUCTIONS
+        ),
+        Some("follow_up_email") => FOLLOW_UP_EMAIL_PROMPT_TEMPLATE.to_string(),
+        Some("custom") => match config.custom_mode.and_then(output_modes::custom_mode) {
+            Some(mode) => mode.prompt_template(),
+            None => POLISH_PROMPT_TEMPLATE.to_string(),
+        },
+        _ => POLISH_PROMPT_TEMPLATE.to_string(),
     };
-    template.replace("{language}", language)
+    workspaces::add_to_prompt(template)
 }
 
 impl AzureOpenAIClient {
@@ -136,31 +187,86 @@ impl AzureOpenAIClient {
             endpoint_url: creds.endpoint_url.clone(),
             api_key: creds.api_key.clone(),
             polish_deployment: creds.polish_deployment.clone(),
+            api_version: creds.responses_api_version().to_string(),
+            route: creds.polish_route,
             client,
+            retry_notice: None,
         })
     }
 
-    /// Polish a transcript using Azure OpenAI Responses API.
+    /// Set a callback that is told when the client waits on a rate limit and when it resumes.
+    pub(crate) fn with_retry_notice(mut self, notice: RetryNotice) -> Self {
+        self.retry_notice = Some(notice);
+        self
+    }
+
+    /// Polish a transcript using Azure OpenAI.
     ///
-    /// Sends the raw transcript to Azure OpenAI for copyediting and polishing.
-    /// Includes retry logic for transient network failures.
+    /// Sends the raw transcript to Azure OpenAI for copyediting and polishing,
+    /// on the route set in the credentials or the first one the resource has.
+    /// Includes retry logic for transient network failures and rate limits.
     #[instrument(skip(self, transcript, config), fields(transcript_len = transcript.len()))]
     pub(crate) async fn polish_transcript(
         &self,
         transcript: &str,
         config: &PolishConfig,
     ) -> Result<String, ResponseError> {
-        // For Azure, always use the configured deployment name
-        // (config.model is for proxy backends that can route to different models)
-        let model = self.polish_deployment.clone();
+        let routes = routes::candidates(&self.endpoint_url, self.route);
+        let detecting = routes.len() > 1;
+
+        let mut last_error = None;
+        for route in routes {
+            let body = self.request_body(route, transcript, config);
+            match self.send(route, &body).await {
+                Ok(text) => {
+                    if detecting {
+                        info!(%route, "Detected Azure polish route");
+                    }
+                    routes::remember(&self.endpoint_url, route);
+                    return Ok(text);
+                }
+                Err(ResponseError::ServerError { status, message })
+                    if detecting && routes::is_route_missing(status, &message) =>
+                {
+                    info!(%route, status, "Azure resource lacks polish route, trying the next");
+                    last_error = Some(ResponseError::ServerError { status, message });
+                }
+                Err(e) => return Err(e),
+            }
+        }
 
-        let reasoning = config.reasoning_effort.as_ref().map(|effort| Reasoning {
-            effort: effort.clone(),
-        });
+        Err(last_error
+            .unwrap_or_else(|| ResponseError::InvalidResponse("No Azure polish route".into())))
+    }
 
-        let prompt = select_prompt(config);
-        let request_body = AzurePolishRequest {
-            model,
+    /// Build the request body in the shape `route` expects.
+    fn request_body(
+        &self,
+        route: AzurePolishRoute,
+        transcript: &str,
+        config: &PolishConfig,
+    ) -> PolishBody {
+        let prompt = language_sections::add_to_prompt(select_prompt(config), transcript);
+        if !route.is_responses() {
+            return PolishBody::Chat(ChatRequest {
+                messages: vec![
+                    Message {
+                        role: "system".to_string(),
+                        content: prompt,
+                    },
+                    Message {
+                        role: "user".to_string(),
+                        content: transcript.to_string(),
+                    },
+                ],
+                reasoning_effort: config.reasoning_effort.clone(),
+            });
+        }
+
+        // For Azure, always use the configured deployment name
+        // (config.model is for proxy backends that can route to different models)
+        PolishBody::Responses(AzurePolishRequest {
+            model: self.polish_deployment.clone(),
             input: vec![
                 Message {
                     role: "developer".to_string(),
@@ -171,27 +277,43 @@ impl AzureOpenAIClient {
                     content: transcript.to_string(),
                 },
             ],
-            reasoning,
-        };
+            reasoning: config.reasoning_effort.as_ref().map(|effort| Reasoning {
+                effort: effort.clone(),
+            }),
+        })
+    }
 
-        // Azure OpenAI Responses API
-        // Try non-v1 format with api-version for Data Zone Standard deployments
-        let endpoint = self.endpoint_url.trim_end_matches('/');
-        let url = format!("{endpoint}/openai/responses?api-version=2025-04-01-preview");
+    /// Send a polish request on `route`, retrying transient failures.
+    async fn send(
+        &self,
+        route: AzurePolishRoute,
+        body: &PolishBody,
+    ) -> Result<String, ResponseError> {
+        let url = route.url(
+            &self.endpoint_url,
+            &self.polish_deployment,
+            &self.api_version,
+        );
 
         let mut last_error: Option<ResponseError> = None;
         let mut retry_delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS);
+        let mut rate_limit_wait: Option<Duration> = None;
+        let mut rate_limited_for = Duration::ZERO;
 
         for attempt in 0..=MAX_RETRIES {
             if attempt > 0 {
+                let delay = rate_limit_wait.take().unwrap_or(retry_delay);
                 warn!(
                     attempt = attempt,
                     max_retries = MAX_RETRIES,
-                    delay_ms = retry_delay.as_millis(),
+                    delay_ms = delay.as_millis(),
                     "Retrying Azure polish request after transient failure"
                 );
-                tokio::time::sleep(retry_delay).await;
+                tokio::time::sleep(delay).await;
                 retry_delay *= 2;
+                if let Some(notice) = &self.retry_notice {
+                    notice(None);
+                }
             }
 
             let result = self
@@ -199,23 +321,30 @@ impl AzureOpenAIClient {
                 .post(&url)
                 .header("api-key", &self.api_key)
                 .header("Content-Type", "application/json")
-                .json(&request_body)
+                .json(body)
                 .send()
                 .await;
 
             match result {
                 Ok(response) => {
                     if response.status().is_success() {
-                        let azure_response: AzurePolishResponse =
-                            response.json().await.map_err(|e| {
-                                ResponseError::InvalidResponse(format!(
-                                    "Failed to parse Azure response: {}",
-                                    e
-                                ))
-                            })?;
+                        let parse_error = |e: reqwest::Error| {
+                            ResponseError::InvalidResponse(format!(
+                                "Failed to parse Azure response: {}",
+                                e
+                            ))
+                        };
 
                         // Extract text from response
-                        let polished_text = Self::extract_text(&azure_response)?;
+                        let polished_text = if route.is_responses() {
+                            let azure_response: AzurePolishResponse =
+                                response.json().await.map_err(parse_error)?;
+                            Self::extract_text(&azure_response)?
+                        } else {
+                            let chat_response: ChatResponse =
+                                response.json().await.map_err(parse_error)?;
+                            routes::extract_chat_text(&chat_response)?
+                        };
 
                         if attempt > 0 {
                             info!(
@@ -228,10 +357,37 @@ impl AzureOpenAIClient {
                     }
 
                     let status = response.status().as_u16();
+                    let server_hint = Self::retry_after_hint(response.headers());
                     let message = response.text().await.unwrap_or_default();
+                    let quota_exhausted = retry::is_quota_exhausted(&message);
 
                     let error = ResponseError::ServerError { status, message };
 
+                    // Retry on rate limits, honoring the server's Retry-After hint
+                    // as long as the waits fit in the rate-limit budget
+                    let rate_limit_delay = if status == retry::TOO_MANY_REQUESTS
+                        && !quota_exhausted
+                        && attempt < MAX_RETRIES
+                    {
+                        retry::next_rate_limit_delay(server_hint, retry_delay, rate_limited_for)
+                    } else {
+                        None
+                    };
+                    if let Some(delay) = rate_limit_delay {
+                        warn!(
+                            attempt = attempt,
+                            delay_ms = delay.as_millis(),
+                            "Rate limited, will retry"
+                        );
+                        if let Some(notice) = &self.retry_notice {
+                            notice(Some(delay));
+                        }
+                        rate_limited_for += delay;
+                        rate_limit_wait = Some(delay);
+                        last_error = Some(error);
+                        continue;
+                    }
+
                     // Retry on 5xx server errors
                     if (500..600).contains(&status) && attempt < MAX_RETRIES {
                         warn!(
@@ -279,6 +435,16 @@ impl AzureOpenAIClient {
         ))
     }
 
+    /// Read the server's retry hint from rate-limit response headers.
+    fn retry_after_hint(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        retry::parse_retry_after(
+            header("retry-after"),
+            header("retry-after-ms"),
+            chrono::Utc::now(),
+        )
+    }
+
     /// Check if a reqwest error is retryable (transient).
     fn is_retryable_error(error: &reqwest::Error) -> bool {
         error.is_timeout() || error.is_connect() || error.is_request()
@@ -366,6 +532,7 @@ mod tests {
             reasoning_effort: None,
             prompt_type: None,
             language_code: "en".to_string(),
+            custom_mode: None,
         };
         let prompt = select_prompt(&config);
         assert!(prompt.contains("The output MUST be in English"));
@@ -378,9 +545,83 @@ mod tests {
             reasoning_effort: None,
             prompt_type: Some("live_meeting".to_string()),
             language_code: "no".to_string(),
+            custom_mode: None,
         };
         let prompt = select_prompt(&config);
         assert!(prompt.contains("The output MUST be in Norwegian"));
         assert!(prompt.contains("## Summary"));
+        assert!(!prompt.contains("TOPIC SECTIONS"));
+    }
+
+    #[test]
+    fn test_select_prompt_meeting_topics_adds_contents() {
+        let config = PolishConfig {
+            reasoning_effort: None,
+            prompt_type: Some("meeting_topics".to_string()),
+            language_code: "en".to_string(),
+            custom_mode: None,
+        };
+        let prompt = select_prompt(&config);
+        assert!(prompt.contains("## Summary"));
+        assert!(prompt.contains("## Contents"));
+        assert!(prompt.contains("[@12:34]"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_completes_within_budget() {
+        use axum::http::{HeaderValue, StatusCode};
+        use axum::response::IntoResponse;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        // Throttle the first request with a Retry-After hint, then answer
+        let requests = Arc::new(AtomicUsize::new(0));
+        let seen = requests.clone();
+        let app = axum::Router::new().route(
+            "/openai/responses",
+            axum::routing::post(move || {
+                let seen = seen.clone();
+                async move {
+                    if seen.fetch_add(1, Ordering::SeqCst) == 0 {
+                        let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+                        response
+                            .headers_mut()
+                            .insert("retry-after", HeaderValue::from_static("1"));
+                        return response;
+                    }
+                    axum::Json(serde_json::json!({
+                        "output": [{
+                            "type": "message",
+                            "content": [{"type": "output_text", "text": "Polished"}]
+                        }]
+                    }))
+                    .into_response()
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let client = AzureOpenAIClient::new(&AzureCredentials {
+            api_key: "test-key".to_string(),
+            endpoint_url: format!("http://{}", addr),
+            stt_deployment: "stt".to_string(),
+            polish_deployment: "polish".to_string(),
+            realtime_api_version: None,
+            responses_api_version: None,
+            polish_route: Some(AzurePolishRoute::Responses),
+        })
+        .unwrap();
+
+        let polished = tokio::time::timeout(
+            retry::MAX_TOTAL_RATE_LIMIT_DELAY,
+            client.polish_transcript("hello", &PolishConfig::default()),
+        )
+        .await
+        .expect("rate-limited request should finish within the budget")
+        .unwrap();
+        assert_eq!(polished, "Polished");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
     }
 }
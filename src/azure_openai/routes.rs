@@ -0,0 +1,196 @@
+//! Routes an Azure OpenAI resource may expose for polishing
+//!
+//! Resources differ: some have the Responses API under `/openai/responses`
+//! with an api-version, some only the newer `/openai/v1/responses`, and some
+//! only the classic chat completions route per deployment. Unless the
+//! credentials pin a route, they are tried in that order and the first one
+//! the resource has is remembered for the rest of the run.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use super::Message;
+use crate::error::ResponseError;
+
+/// How polish requests reach an Azure OpenAI resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AzurePolishRoute {
+    /// `/openai/responses?api-version=...`
+    Responses,
+    /// `/openai/v1/responses`
+    ResponsesV1,
+    /// `/openai/deployments/{deployment}/chat/completions?api-version=...`
+    ChatCompletions,
+}
+
+impl fmt::Display for AzurePolishRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AzurePolishRoute::Responses => write!(f, "Responses API"),
+            AzurePolishRoute::ResponsesV1 => write!(f, "Responses API (v1)"),
+            AzurePolishRoute::ChatCompletions => write!(f, "Chat Completions"),
+        }
+    }
+}
+
+impl AzurePolishRoute {
+    /// Every route, in the order they are tried
+    pub(crate) const ALL: [AzurePolishRoute; 3] = [
+        AzurePolishRoute::Responses,
+        AzurePolishRoute::ResponsesV1,
+        AzurePolishRoute::ChatCompletions,
+    ];
+
+    /// Request URL for this route
+    pub(super) fn url(self, endpoint_url: &str, deployment: &str, api_version: &str) -> String {
+        let endpoint = endpoint_url.trim_end_matches('/');
+        match self {
+            AzurePolishRoute::Responses => {
+                format!("{endpoint}/openai/responses?api-version={api_version}")
+            }
+            AzurePolishRoute::ResponsesV1 => format!("{endpoint}/openai/v1/responses"),
+            AzurePolishRoute::ChatCompletions => format!(
+                "{endpoint}/openai/deployments/{deployment}/chat/completions?api-version={api_version}"
+            ),
+        }
+    }
+
+    /// Whether requests and responses have the Responses API shape
+    pub(super) fn is_responses(self) -> bool {
+        !matches!(self, AzurePolishRoute::ChatCompletions)
+    }
+}
+
+/// Route found to work for each endpoint during this run
+static DETECTED: Lazy<Mutex<HashMap<String, AzurePolishRoute>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Routes to try for `endpoint_url`: the pinned one, the one found earlier, or all
+pub(super) fn candidates(
+    endpoint_url: &str,
+    pinned: Option<AzurePolishRoute>,
+) -> Vec<AzurePolishRoute> {
+    if let Some(route) = pinned {
+        return vec![route];
+    }
+    let detected = DETECTED
+        .lock()
+        .ok()
+        .and_then(|detected| detected.get(endpoint_url).copied());
+    match detected {
+        Some(route) => vec![route],
+        None => AzurePolishRoute::ALL.to_vec(),
+    }
+}
+
+/// Remember that `route` works for `endpoint_url`
+pub(super) fn remember(endpoint_url: &str, route: AzurePolishRoute) {
+    if let Ok(mut detected) = DETECTED.lock() {
+        detected.insert(endpoint_url.to_string(), route);
+    }
+}
+
+/// Whether a failed request means the resource doesn't have the route
+pub(super) fn is_route_missing(status: u16, message: &str) -> bool {
+    status == 404 || (status == 400 && message.contains("OperationNotSupported"))
+}
+
+/// Request body for the chat completions route.
+#[derive(Debug, Serialize)]
+pub(super) struct ChatRequest {
+    pub(super) messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) reasoning_effort: Option<String>,
+}
+
+/// Response from the chat completions route.
+#[derive(Debug, Deserialize)]
+pub(super) struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Extract text from a chat completions response.
+pub(super) fn extract_chat_text(response: &ChatResponse) -> Result<String, ResponseError> {
+    response
+        .choices
+        .iter()
+        .filter_map(|choice| choice.message.content.as_deref())
+        .find(|text| !text.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| ResponseError::InvalidResponse("No text content in Azure response".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_urls() {
+        let endpoint = "https://res.openai.azure.com/";
+        assert_eq!(
+            AzurePolishRoute::Responses.url(endpoint, "gpt", "2025-04-01-preview"),
+            "https://res.openai.azure.com/openai/responses?api-version=2025-04-01-preview"
+        );
+        assert_eq!(
+            AzurePolishRoute::ResponsesV1.url(endpoint, "gpt", "2025-04-01-preview"),
+            "https://res.openai.azure.com/openai/v1/responses"
+        );
+        assert_eq!(
+            AzurePolishRoute::ChatCompletions.url(endpoint, "gpt", "2024-10-21"),
+            "https://res.openai.azure.com/openai/deployments/gpt/chat/completions?api-version=2024-10-21"
+        );
+    }
+
+    #[test]
+    fn test_pinned_and_detected_routes_are_tried_alone() {
+        let endpoint = "https://detect-test.openai.azure.com";
+        assert_eq!(candidates(endpoint, None), AzurePolishRoute::ALL.to_vec());
+        assert_eq!(
+            candidates(endpoint, Some(AzurePolishRoute::ChatCompletions)),
+            vec![AzurePolishRoute::ChatCompletions]
+        );
+
+        remember(endpoint, AzurePolishRoute::ResponsesV1);
+        assert_eq!(
+            candidates(endpoint, None),
+            vec![AzurePolishRoute::ResponsesV1]
+        );
+    }
+
+    #[test]
+    fn test_missing_route_detection() {
+        assert!(is_route_missing(404, "Resource not found"));
+        assert!(is_route_missing(
+            400,
+            r#"{"error":{"code":"OperationNotSupported"}}"#
+        ));
+        assert!(!is_route_missing(400, "Invalid value for 'input'"));
+        assert!(!is_route_missing(401, "Access denied"));
+    }
+
+    #[test]
+    fn test_chat_response_text() {
+        let json = r#"{"choices":[{"message":{"role":"assistant","content":"Polished"}}]}"#;
+        let response: ChatResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(extract_chat_text(&response).unwrap(), "Polished");
+
+        let empty: ChatResponse = serde_json::from_str(r#"{"choices":[]}"#).unwrap();
+        assert!(extract_chat_text(&empty).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Review dialog for reporting a problem
+
+use std::path::Path;
+use std::process::Command;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSFont, NSScrollView, NSTextView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use tracing::{error, info};
+
+use super::BugReport;
+
+/// NSAlertFirstButtonReturn, and the buttons after it
+const ALERT_FIRST_BUTTON: isize = 1000;
+const ALERT_SECOND_BUTTON: isize = 1001;
+
+/// Size of the report contents shown for review
+const REVIEW_WIDTH: CGFloat = 520.0;
+const REVIEW_HEIGHT: CGFloat = 300.0;
+
+/// Where the report is sent once it is saved
+enum Destination {
+    GitHubIssue,
+    Email,
+}
+
+/// Show what a bug report includes and, if the user agrees, save it and
+/// open a GitHub issue or email draft to send it with.
+///
+/// Must be called on the main thread.
+pub(crate) fn report_problem() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Not on main thread, cannot report a problem");
+        return;
+    };
+
+    let report = BugReport::collect();
+    let Some(destination) = review(mtm, &report) else {
+        info!("Problem report cancelled");
+        return;
+    };
+
+    let dir = dirs::download_dir().unwrap_or_else(std::env::temp_dir);
+    let zip = match report.save_zip(&dir) {
+        Ok(zip) => zip,
+        Err(e) => {
+            error!("Failed to save bug report: {}", e);
+            show_message(mtm, "Could not save the report", &e.to_string());
+            return;
+        }
+    };
+    reveal(&zip);
+
+    let url = match destination {
+        Destination::GitHubIssue => report.issue_url().map(String::from),
+        Destination::Email => Some(report.email_url()),
+    };
+    if let Err(e) = url.map_or(Ok(()), open::that) {
+        error!("Failed to open the report draft: {}", e);
+        show_message(
+            mtm,
+            "Could not open the draft",
+            "The report is saved in your Downloads folder. Attach it to an issue or email.",
+        );
+    }
+}
+
+/// Ask the user to review the report and choose how to send it
+fn review(mtm: MainThreadMarker, report: &BugReport) -> Option<Destination> {
+    let contents = create_review_view(mtm, &report.review_text());
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str("Report a Problem"));
+        alert.setInformativeText(&NSString::from_str(
+            "The report below includes recent logs, diagnostics and your settings without \
+             keys, names or prompts. It is saved as a zip in your Downloads folder for you \
+             to attach. Nothing leaves this Mac until you send the issue or email yourself.",
+        ));
+        alert.setAccessoryView(Some(&contents));
+        alert.addButtonWithTitle(&NSString::from_str("Open GitHub Issue"));
+        alert.addButtonWithTitle(&NSString::from_str("Email Draft"));
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+
+    match response {
+        ALERT_FIRST_BUTTON => Some(Destination::GitHubIssue),
+        ALERT_SECOND_BUTTON => Some(Destination::Email),
+        _ => None,
+    }
+}
+
+/// A read-only, scrollable view of the report contents
+fn create_review_view(mtm: MainThreadMarker, text: &str) -> Retained<NSScrollView> {
+    let frame = NSRect::new(
+        NSPoint::new(0.0, 0.0),
+        NSSize::new(REVIEW_WIDTH, REVIEW_HEIGHT),
+    );
+
+    // SAFETY: view allocation and configuration on the main thread with valid frames
+    unsafe {
+        let scroll_view: Retained<NSScrollView> =
+            msg_send_id![mtm.alloc::<NSScrollView>(), initWithFrame: frame];
+        scroll_view.setHasVerticalScroller(true);
+        scroll_view.setHasHorizontalScroller(false);
+        let _: () = msg_send![&scroll_view, setBorderType: 2u64]; // NSBezelBorder
+
+        let text_view: Retained<NSTextView> =
+            msg_send_id![mtm.alloc::<NSTextView>(), initWithFrame: frame];
+        text_view.setEditable(false);
+        text_view.setSelectable(true);
+        let _: () = msg_send![&text_view, setRichText: false];
+
+        let font: Retained<NSFont> = msg_send_id![
+            NSFont::class(),
+            monospacedSystemFontOfSize: 11.0,
+            weight: 0.0
+        ];
+        text_view.setFont(Some(&font));
+
+        // Wrap to the scroll view width
+        let text_container: *mut AnyObject = msg_send![&text_view, textContainer];
+        if !text_container.is_null() {
+            let _: () = msg_send![text_container, setWidthTracksTextView: true];
+        }
+        let _: () = msg_send![&text_view, setVerticallyResizable: true];
+        let _: () = msg_send![&text_view, setHorizontallyResizable: false];
+        let _: () = msg_send![&text_view, setAutoresizingMask: 2u64]; // NSViewWidthSizable
+
+        let _: () = msg_send![&text_view, setString: &*NSString::from_str(text)];
+        scroll_view.setDocumentView(Some(&text_view));
+        scroll_view
+    }
+}
+
+/// Select the saved zip in Finder, ready to attach
+fn reveal(zip: &Path) {
+    if let Err(e) = Command::new("/usr/bin/open").arg("-R").arg(zip).status() {
+        error!("Failed to reveal the bug report in Finder: {}", e);
+    }
+}
+
+fn show_message(mtm: MainThreadMarker, title: &str, message: &str) {
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(title));
+        alert.setInformativeText(&NSString::from_str(message));
+        alert.runModal();
+    }
+}
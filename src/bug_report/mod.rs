@@ -0,0 +1,279 @@
+//! Reporting a problem
+//!
+//! Report a Problem in the menu bar collects what helps to track down a bug:
+//! recent log lines, diagnostics such as versions, provider settings and
+//! measured latency, anonymized preferences and the Debug tab's events. All
+//! of it is shown for review first. Only once the user agrees is it saved as
+//! a zip in Downloads and a pre-filled GitHub issue or email draft opened;
+//! the user attaches the zip and sends the report themselves, so nothing
+//! leaves the Mac on its own.
+//!
+//! Preferences holding the user's own words, names or addresses (keywords,
+//! custom prompts, workspaces, templates, the git remote and so on) only say
+//! whether they are set, and the home folder is shortened to `~` everywhere.
+
+mod dialogs;
+
+pub(crate) use dialogs::report_problem;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{DateTime, Local};
+use serde_json::Value;
+use tracing::info;
+use url::Url;
+
+use crate::preferences::{self, Preferences};
+use crate::{managed, settings_transfer, transcription};
+
+/// Preferences replaced by whether they are set, as they hold the user's own content
+const PRIVATE_FIELDS: [&str; 9] = [
+    "active_workspace",
+    "consent_notice",
+    "custom_output_modes",
+    "git_archive_remote",
+    "keyword_rules",
+    "screenshot_blocklist",
+    "templates",
+    "wake_word_phrase",
+    "workspaces",
+];
+
+/// Bug report errors
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum BugReportError {
+    #[error("Could not write the report: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not create the zip file")]
+    Zip,
+}
+
+/// One file of the report
+pub(crate) struct ReportFile {
+    pub(crate) name: &'static str,
+    pub(crate) contents: String,
+}
+
+/// Everything a bug report includes, as reviewed before it is saved
+pub(crate) struct BugReport {
+    created: DateTime<Local>,
+    pub(crate) files: Vec<ReportFile>,
+}
+
+impl BugReport {
+    /// Collect the report's files from the running app
+    pub(crate) fn collect() -> Self {
+        let home = dirs::home_dir().map(|home| home.to_string_lossy().into_owned());
+        let home = home.as_deref();
+
+        let mut files = vec![
+            ReportFile {
+                name: "diagnostics.txt",
+                contents: diagnostics(),
+            },
+            ReportFile {
+                name: "preferences.json",
+                contents: anonymized_preferences(preferences::snapshot(), home),
+            },
+            ReportFile {
+                name: "recent.log",
+                contents: without_home(&crate::recent_logs::recent_lines(), home),
+            },
+        ];
+        let events = transcription::debug_log_text();
+        if !events.is_empty() {
+            files.push(ReportFile {
+                name: "debug-events.log",
+                contents: events,
+            });
+        }
+
+        Self {
+            created: Local::now(),
+            files,
+        }
+    }
+
+    /// Name of the zip file, and of the folder inside it
+    pub(crate) fn name(&self) -> String {
+        format!(
+            "Vissper Bug Report {}",
+            self.created.format("%Y-%m-%d %H.%M.%S")
+        )
+    }
+
+    /// All files one after another, for review
+    pub(crate) fn review_text(&self) -> String {
+        self.files
+            .iter()
+            .map(|file| format!("==== {} ====\n\n{}\n", file.name, file.contents.trim_end()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Save the files as a zip in `dir`, returning its path
+    pub(crate) fn save_zip(&self, dir: &Path) -> Result<PathBuf, BugReportError> {
+        let staging = std::env::temp_dir().join(self.name());
+        fs::create_dir_all(&staging)?;
+        for file in &self.files {
+            fs::write(staging.join(file.name), &file.contents)?;
+        }
+
+        let zip = dir.join(format!("{}.zip", self.name()));
+        let zipped = Command::new("/usr/bin/ditto")
+            .args(["-c", "-k", "--keepParent"])
+            .arg(&staging)
+            .arg(&zip)
+            .status();
+        let _ = fs::remove_dir_all(&staging);
+        if !zipped.is_ok_and(|status| status.success()) {
+            return Err(BugReportError::Zip);
+        }
+
+        info!("Saved bug report");
+        Ok(zip)
+    }
+
+    /// A new GitHub issue with the diagnostics filled in
+    pub(crate) fn issue_url(&self) -> Option<Url> {
+        Url::parse_with_params(
+            &format!("{}/issues/new", env!("CARGO_PKG_REPOSITORY")),
+            &[("body", self.message_body())],
+        )
+        .ok()
+    }
+
+    /// An email draft with the diagnostics filled in, for the user to address
+    pub(crate) fn email_url(&self) -> String {
+        format!(
+            "mailto:?subject={}&body={}",
+            mailto_encode("Vissper bug report"),
+            mailto_encode(&self.message_body())
+        )
+    }
+
+    fn message_body(&self) -> String {
+        let diagnostics = self
+            .files
+            .iter()
+            .find(|file| file.name == "diagnostics.txt")
+            .map(|file| file.contents.as_str())
+            .unwrap_or_default();
+        format!(
+            "What happened?\n\n\nWhat did you expect to happen?\n\n\nSteps to reproduce:\n\n\n\
+             ---\n{}\n\nPlease attach “{}.zip” from your Downloads folder for the logs \
+             and settings.\n",
+            diagnostics.trim_end(),
+            self.name()
+        )
+    }
+}
+
+/// Versions and the settings that matter most for transcription problems
+fn diagnostics() -> String {
+    let macos = Command::new("/usr/bin/sw_vers")
+        .arg("-productVersion")
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default();
+
+    [
+        format!("Vissper {}", env!("CARGO_PKG_VERSION")),
+        format!("macOS {} ({})", macos, std::env::consts::ARCH),
+        format!("Managed: {}", managed::is_managed()),
+        format!("Interface language: {}", preferences::get_ui_language()),
+        format!("Transcription provider: {}", preferences::get_ai_provider()),
+        format!("Polish provider: {}", preferences::get_polish_provider()),
+        format!(
+            "OpenAI model: {}",
+            preferences::get_openai_transcribe_model().label()
+        ),
+        format!("Language: {}", preferences::get_language_code()),
+        format!(
+            "Audio: {} ms chunks, {} s buffer, low bandwidth {}, noise reduction {}",
+            preferences::get_audio_chunk_ms(),
+            preferences::get_audio_buffer_secs(),
+            preferences::get_low_bandwidth_mode(),
+            preferences::get_noise_reduction().label()
+        ),
+        format!(
+            "Stall timeout: {} s, workspace active: {}",
+            preferences::get_stall_timeout_secs(),
+            preferences::get_active_workspace().is_some()
+        ),
+        format!(
+            "Data usage of the last recording: {}",
+            transcription::data_usage().summary()
+        ),
+        transcription::latency_summary(),
+    ]
+    .join("\n")
+}
+
+/// Preferences as JSON, without this Mac's token and the user's own content
+fn anonymized_preferences(prefs: Preferences, home: Option<&str>) -> String {
+    let mut value =
+        serde_json::to_value(settings_transfer::shareable(prefs)).unwrap_or(Value::Null);
+    if let Value::Object(fields) = &mut value {
+        fields.retain(|_, field| !field.is_null());
+        for (name, field) in fields.iter_mut() {
+            if PRIVATE_FIELDS.contains(&name.as_str()) {
+                *field = Value::String(match field {
+                    Value::Array(items) => format!("<{} items>", items.len()),
+                    _ => "<set>".to_string(),
+                });
+            } else if let Value::String(text) = field {
+                *text = without_home(text, home);
+            }
+        }
+    }
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+/// `text` with the home folder shortened to `~`, hiding the user name
+fn without_home(text: &str, home: Option<&str>) -> String {
+    match home.filter(|home| home.len() > 1) {
+        Some(home) => text.replace(home, "~"),
+        None => text.to_string(),
+    }
+}
+
+/// Percent-encode for a mailto URL, where `+` is not a space
+fn mailto_encode(text: &str) -> String {
+    url::form_urlencoded::byte_serialize(text.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_preferences_leave_out_private_content() {
+        let prefs = Preferences {
+            transcript_location: Some(PathBuf::from("/Users/jane/Documents/Notes")),
+            wake_word_phrase: Some("Hey Jane".to_string()),
+            git_archive_remote: Some("git@example.com:jane/notes.git".to_string()),
+            audio_chunk_ms: Some(200),
+            ..Preferences::default()
+        };
+
+        let json = anonymized_preferences(prefs, Some("/Users/jane"));
+        assert!(json.contains(r#""transcript_location": "~/Documents/Notes""#));
+        assert!(json.contains(r#""wake_word_phrase": "<set>""#));
+        assert!(json.contains(r#""audio_chunk_ms": 200"#));
+        assert!(!json.contains("jane"));
+        assert!(!json.contains("null"));
+    }
+
+    #[test]
+    fn test_mailto_encode_keeps_spaces_and_plus_signs() {
+        assert_eq!(mailto_encode("C++ & more"), "C%2B%2B%20%26%20more");
+    }
+}
@@ -2,84 +2,93 @@
 //!
 //! Configures global hotkey handlers for recording control.
 
-use crate::menubar::AppState;
-use crate::recording;
-use crate::screenshot;
+use crate::app_context::AppContext;
+use crate::menubar::MenuBar;
+use crate::recording::{self, RecordingPhase};
+use crate::transcription_window::TabType;
+use crate::{language_hud, languages, preferences, templates};
 use std::sync::Arc;
 use tracing::{error, info};
 
-use super::CallbackConfig;
-
 /// Setup hotkey callbacks
-pub(crate) fn setup_hotkey_callbacks(
-    config: &CallbackConfig,
-    app_state: Arc<AppState>,
-    runtime_handle: tokio::runtime::Handle,
-) {
-    let recording_state = config.recording_state.clone();
+pub(crate) fn setup_hotkey_callbacks(context: &AppContext, runtime_handle: tokio::runtime::Handle) {
+    let recording_state = context.recording_state.clone();
 
     // Clone for each hotkey
     let recording_state_no_polish = recording_state.clone();
-    let app_state_no_polish = app_state.clone();
+    let ui_no_polish = context.ui.clone();
 
     let recording_state_basic = recording_state.clone();
-    let app_state_basic = app_state.clone();
+    let ui_basic = context.ui.clone();
 
     let recording_state_meeting = recording_state.clone();
-    let app_state_meeting = app_state.clone();
+    let ui_meeting = context.ui.clone();
 
     let recording_state_screenshot = recording_state.clone();
     let recording_state_region = recording_state.clone();
+    let recording_state_last_region = recording_state.clone();
+    let recording_state_marker = recording_state.clone();
+    let recording_state_prepare = recording_state.clone();
+    let recording_state_stop = recording_state.clone();
+    let ui_stop = context.ui.clone();
 
     let runtime_basic = runtime_handle.clone();
     let runtime_meeting = runtime_handle.clone();
+    let runtime_quick_polish = runtime_handle.clone();
+    let runtime_stop = runtime_handle.clone();
 
     crate::hotkeys::start_hotkey_listener(
-        // No polishing callback (Control + Space)
+        // No polishing callback (Control + Space), or the recording template's mode
         Arc::new(move || {
-            use std::sync::atomic::Ordering;
-
-            let is_recording = app_state_no_polish.is_recording.load(Ordering::SeqCst);
+            let phase = recording::current_phase();
             let recording_state = recording_state_no_polish.clone();
+            let ui = ui_no_polish.clone();
 
             runtime_handle.spawn(async move {
-                if is_recording {
-                    info!("Hotkey: Stopping recording (no polishing)");
-                    recording::stop_recording_no_polish(recording_state);
-                } else {
-                    info!("Hotkey: Starting recording");
-                    recording::start_recording(recording_state, true);
+                match phase {
+                    RecordingPhase::Recording => {
+                        let tab = templates::stop_mode();
+                        info!(?tab, "Hotkey: Stopping recording");
+                        recording::stop_recording_with_mode(recording_state, ui, tab);
+                    }
+                    RecordingPhase::Idle => {
+                        info!("Hotkey: Starting recording");
+                        recording::start_recording(recording_state, ui, true);
+                    }
+                    _ => info!("Hotkey ignored: recording is {:?}", phase),
                 }
             });
         }),
         // Basic polishing callback (Control + Shift + 1)
         Arc::new(move || {
-            use std::sync::atomic::Ordering;
-
-            let is_recording = app_state_basic.is_recording.load(Ordering::SeqCst);
+            let phase = recording::current_phase();
             let recording_state = recording_state_basic.clone();
+            let ui = ui_basic.clone();
 
             runtime_basic.spawn(async move {
-                if is_recording {
-                    info!("Hotkey: Stopping recording (basic polishing)");
-                    recording::stop_recording(recording_state);
-                } else {
-                    info!("Hotkey: Starting recording");
-                    recording::start_recording(recording_state, true);
+                match phase {
+                    RecordingPhase::Recording => {
+                        info!("Hotkey: Stopping recording (basic polishing)");
+                        recording::stop_recording(recording_state, ui);
+                    }
+                    RecordingPhase::Idle => {
+                        info!("Hotkey: Starting recording");
+                        recording::start_recording(recording_state, ui, true);
+                    }
+                    _ => info!("Hotkey ignored: recording is {:?}", phase),
                 }
             });
         }),
         // Meeting notes callback (Control + Shift + 2)
         Arc::new(move || {
-            use std::sync::atomic::Ordering;
-
-            let is_recording = app_state_meeting.is_recording.load(Ordering::SeqCst);
+            let phase = recording::current_phase();
             let recording_state = recording_state_meeting.clone();
+            let ui = ui_meeting.clone();
 
             runtime_meeting.spawn(async move {
-                if is_recording {
+                if phase == RecordingPhase::Recording {
                     info!("Hotkey: Stopping recording (meeting notes)");
-                    recording::stop_live_meeting_recording(recording_state);
+                    recording::stop_live_meeting_recording(recording_state, ui);
                 } else {
                     info!(
                         "Hotkey: Control+Shift+2 only stops with meeting notes (use Control+Space or Control+Shift+1 to start)"
@@ -90,24 +99,8 @@ pub(crate) fn setup_hotkey_callbacks(
         // Screenshot callback (Control + Shift + 0)
         Arc::new(move || {
             info!("Hotkey: Taking screenshot");
-            match screenshot::capture_screenshot() {
-                Ok(filename) => {
-                    info!("Screenshot captured: {}", filename);
-                    crate::screenshot_flash::ScreenshotFlash::show();
-
-                    if let Ok(state) = recording_state_screenshot.lock() {
-                        if let Some(ref session) = *state {
-                            if let Ok(mut session_data) = session.session_data.lock() {
-                                let relative_path = format!("screenshots/{}", filename);
-                                session_data.insert_screenshot(&relative_path);
-                                info!("Screenshot reference inserted into transcript");
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to capture screenshot: {}", e);
-                }
+            if let Err(e) = super::capture_screenshot_into_session(&recording_state_screenshot) {
+                error!("Failed to capture screenshot: {}", e);
             }
         }),
         // Region screenshot callback (Control + Shift + 9)
@@ -115,5 +108,53 @@ pub(crate) fn setup_hotkey_callbacks(
             info!("Hotkey: Region screenshot selection");
             crate::region_selection::RegionSelection::start(recording_state_region.clone());
         }),
+        // Last region screenshot callback (Control + Shift + 8)
+        Arc::new(move || {
+            info!("Hotkey: Capturing last region again");
+            if let Err(e) = super::capture_last_region_into_session(&recording_state_last_region) {
+                error!("Failed to capture last region: {}", e);
+                super::notify_if_no_last_region(&e);
+            }
+        }),
+        // Marker callback (Control + Shift + M)
+        Arc::new(move || {
+            info!("Hotkey: Inserting marker");
+            recording::insert_marker(&recording_state_marker);
+        }),
+        // Note callback (Control + Shift + N)
+        Arc::new(|| {
+            info!("Hotkey: Opening note popover");
+            crate::transcription_window::TranscriptionWindow::show_note_popover();
+        }),
+        // Quick polish callback (Control + Shift + P)
+        Arc::new(move || {
+            info!("Hotkey: Quick polish");
+            runtime_quick_polish.spawn(crate::quick_polish::run());
+        }),
+        // Prepare callback (Control + Shift + R)
+        Arc::new(move || {
+            info!("Hotkey: Preparing the connection");
+            recording::prepare_connection(&recording_state_prepare);
+        }),
+        // Cycle language callback (Control + Shift + L, or as set in Settings)
+        Arc::new(|| {
+            let current = preferences::get_language_code();
+            let Some(next) = languages::next_favorite(&current) else {
+                return;
+            };
+            info!("Hotkey: Switching language to {}", next.name);
+            MenuBar::set_language(next.code);
+            language_hud::show(next.native_name);
+        }),
+        // Stop-with-mode callback (Control + Option + 1, 2 and 3, or as set in Settings)
+        Arc::new(move |tab: TabType| {
+            let recording_state = recording_state_stop.clone();
+            let ui = ui_stop.clone();
+
+            runtime_stop.spawn(async move {
+                info!(?tab, "Hotkey: Stopping recording");
+                recording::stop_recording_with_mode(recording_state, ui, tab);
+            });
+        }),
     );
 }
@@ -4,48 +4,50 @@
 
 mod hotkeys;
 
+use crate::app_context::AppContext;
+use crate::event_bus::{self, AppEvent};
+use crate::feedback;
+use crate::localization::tr;
 use crate::menubar::MenuCallbacks;
 use crate::recording::{self, RecordingSession};
+use crate::screenshot::{self, ScreenshotError};
 use crate::settings_window;
+use crate::shutdown;
+use crate::transcription::SessionCommand;
 use crate::transcription_window;
 use std::sync::{Arc, Mutex};
 use tracing::info;
 
 pub(crate) use hotkeys::setup_hotkey_callbacks;
 
-/// Configuration for creating callbacks
-pub(crate) struct CallbackConfig {
-    pub(crate) recording_state: Arc<Mutex<Option<RecordingSession>>>,
-}
-
 /// Create menu bar callbacks
-pub(crate) fn create_menu_callbacks(config: &CallbackConfig) -> MenuCallbacks {
-    let recording_state_start = config.recording_state.clone();
-    let recording_state_no_polish = config.recording_state.clone();
-    let recording_state_basic_polish = config.recording_state.clone();
-    let recording_state_meeting_notes = config.recording_state.clone();
-    let recording_state_screenshot = config.recording_state.clone();
-    let recording_state_region_screenshot = config.recording_state.clone();
+pub(crate) fn create_menu_callbacks(context: &AppContext) -> MenuCallbacks {
+    let recording_state_start = context.recording_state.clone();
+    let recording_state_demo = context.recording_state.clone();
+    let recording_state_stop = context.recording_state.clone();
+    let recording_state_screenshot = context.recording_state.clone();
+    let recording_state_region_screenshot = context.recording_state.clone();
+    let recording_state_last_region = context.recording_state.clone();
+    let recording_state_menu_opened = context.recording_state.clone();
+    let ui_start = context.ui.clone();
+    let ui_demo = context.ui.clone();
+    let ui_stop = context.ui.clone();
+    let context_quit = context.clone();
 
     MenuCallbacks {
         on_start_recording: Box::new(move || {
             info!("Starting recording...");
-            recording::start_recording(recording_state_start.clone(), true);
-        }),
-
-        on_stop_no_polish: Box::new(move || {
-            info!("Stopping recording (no polishing)...");
-            recording::stop_recording_no_polish(recording_state_no_polish.clone());
+            recording::start_recording(recording_state_start.clone(), ui_start.clone(), true);
         }),
 
-        on_stop_basic_polish: Box::new(move || {
-            info!("Stopping recording (basic polishing)...");
-            recording::stop_recording(recording_state_basic_polish.clone());
+        on_start_demo: Box::new(move || {
+            info!("Starting demo session...");
+            recording::start_demo(recording_state_demo.clone(), ui_demo.clone());
         }),
 
-        on_stop_meeting_notes: Box::new(move || {
-            info!("Stopping recording (meeting notes)...");
-            recording::stop_live_meeting_recording(recording_state_meeting_notes.clone());
+        on_stop_with_mode: Box::new(move |tab| {
+            info!(?tab, "Stopping recording...");
+            recording::stop_recording_with_mode(recording_state_stop.clone(), ui_stop.clone(), tab);
         }),
 
         on_show_window: Box::new(|| {
@@ -55,26 +57,8 @@ pub(crate) fn create_menu_callbacks(config: &CallbackConfig) -> MenuCallbacks {
 
         on_screenshot: Box::new(move || {
             info!("Taking screenshot...");
-            match crate::screenshot::capture_screenshot() {
-                Ok(filename) => {
-                    info!("Screenshot captured: {}", filename);
-                    crate::screenshot_flash::ScreenshotFlash::show();
-
-                    if let Ok(state) = recording_state_screenshot.lock() {
-                        if let Some(ref session) = *state {
-                            if let Ok(mut session_data) = session.session_data.lock() {
-                                let relative_path = format!("screenshots/{}", filename);
-                                session_data.insert_screenshot(&relative_path);
-                                info!("Screenshot reference inserted into transcript");
-                            }
-                        } else {
-                            info!("Screenshot saved but no active recording session");
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::error!("Failed to capture screenshot: {}", e);
-                }
+            if let Err(e) = capture_screenshot_into_session(&recording_state_screenshot) {
+                tracing::error!("Failed to capture screenshot: {}", e);
             }
         }),
 
@@ -85,14 +69,22 @@ pub(crate) fn create_menu_callbacks(config: &CallbackConfig) -> MenuCallbacks {
             );
         }),
 
+        on_last_region_screenshot: Box::new(move || {
+            info!("Capturing last region again...");
+            if let Err(e) = capture_last_region_into_session(&recording_state_last_region) {
+                tracing::error!("Failed to capture last region: {}", e);
+                notify_if_no_last_region(&e);
+            }
+        }),
+
         on_settings: Box::new(|| {
             info!("Settings clicked");
             settings_window::SettingsWindow::show();
         }),
 
-        on_quit: Box::new(|| {
+        on_quit: Box::new(move || {
             info!("Quitting application...");
-            std::process::exit(0);
+            shutdown::begin(&context_quit);
         }),
 
         on_update_available: Box::new(move || {
@@ -111,5 +103,60 @@ pub(crate) fn create_menu_callbacks(config: &CallbackConfig) -> MenuCallbacks {
                 }
             }
         }),
+
+        on_menu_opened: Box::new(move || {
+            recording::prepare_connection_on_menu_open(&recording_state_menu_opened);
+        }),
+    }
+}
+
+/// Capture a full-screen screenshot and reference it in the active transcript
+///
+/// The screenshot is always saved; the transcript reference is only inserted
+/// while a recording session exists. Returns the screenshot filename.
+pub(crate) fn capture_screenshot_into_session(
+    recording_state: &Arc<Mutex<Option<RecordingSession>>>,
+) -> Result<String, ScreenshotError> {
+    let filename = screenshot::capture_screenshot()?;
+    insert_into_session(recording_state, &filename);
+    Ok(filename)
+}
+
+/// Capture the last selected region again and reference it in the active transcript
+///
+/// Like [`capture_screenshot_into_session`], but for the area last picked
+/// with region selection, without showing the overlay.
+pub(crate) fn capture_last_region_into_session(
+    recording_state: &Arc<Mutex<Option<RecordingSession>>>,
+) -> Result<String, ScreenshotError> {
+    let filename = screenshot::capture_last_region()?;
+    insert_into_session(recording_state, &filename);
+    Ok(filename)
+}
+
+/// Announce a saved screenshot and insert its reference into the transcript
+fn insert_into_session(recording_state: &Arc<Mutex<Option<RecordingSession>>>, filename: &str) {
+    info!("Screenshot captured: {}", filename);
+    event_bus::publish(AppEvent::ScreenshotCaptured {
+        filename: filename.to_string(),
+    });
+
+    if let Ok(state) = recording_state.lock() {
+        if let Some(ref session) = *state {
+            let relative_path = format!("screenshots/{}", filename);
+            session
+                .session_data
+                .send(SessionCommand::InsertScreenshot(relative_path));
+            info!("Screenshot reference inserted into transcript");
+        } else {
+            info!("Screenshot saved but no active recording session");
+        }
+    }
+}
+
+/// Tell the user a repeat capture needs a region selected first
+fn notify_if_no_last_region(error: &ScreenshotError) {
+    if matches!(error, ScreenshotError::NoLastRegion) {
+        feedback::notify_now(tr("menu.capture_last_area"), &error.to_string());
     }
 }
@@ -0,0 +1,394 @@
+//! Live caption output for streams and webinars
+//!
+//! Publishes the latest spoken line either to a text file that OBS text
+//! sources can read ("Read from file"), or to a WebSocket feed on localhost
+//! for browser sources. Configured in the Captions tab of Settings.
+//!
+//! WebSocket clients must pass the local API token as `?token=`, so other
+//! web pages open in a browser can't read along.
+
+use futures_util::SinkExt;
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tracing::{debug, error, info, warn};
+
+use crate::event_bus::AppEvent;
+use crate::{automation, managed, preferences, private_mode};
+
+/// Longest caption shown, in characters (about two lines in a text source)
+const MAX_CAPTION_CHARS: usize = 120;
+
+/// Where captions are published
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CaptionOutput {
+    #[default]
+    Off,
+    File,
+    WebSocket,
+}
+
+impl CaptionOutput {
+    /// All outputs, in the order shown in Settings
+    pub(crate) const ALL: [CaptionOutput; 3] = [
+        CaptionOutput::Off,
+        CaptionOutput::File,
+        CaptionOutput::WebSocket,
+    ];
+
+    /// Segment label in Settings
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            CaptionOutput::Off => "Off",
+            CaptionOutput::File => "Text File",
+            CaptionOutput::WebSocket => "WebSocket",
+        }
+    }
+
+    /// Look up an output by its index in `ALL` (the selected segment)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    /// Index in `ALL`
+    pub(crate) fn to_index(self) -> isize {
+        Self::ALL.iter().position(|o| *o == self).unwrap_or(0) as isize
+    }
+}
+
+/// Latest caption; the running output task subscribes to changes
+static LATEST: Lazy<watch::Sender<String>> = Lazy::new(|| watch::channel(String::new()).0);
+
+static RUNTIME: OnceCell<tokio::runtime::Handle> = OnceCell::new();
+
+/// The running file writer or WebSocket server, if any
+static OUTPUT_TASK: Lazy<Mutex<Option<JoinHandle<()>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Location of the caption text file
+pub(crate) fn file_path() -> Option<PathBuf> {
+    dirs::document_dir().map(|d| d.join("Vissper").join("captions.txt"))
+}
+
+/// Address of the caption WebSocket feed
+pub(crate) fn websocket_url() -> String {
+    format!(
+        "ws://127.0.0.1:{}",
+        preferences::get_caption_websocket_port()
+    )
+}
+
+/// Start the configured caption output.
+pub(crate) fn init(runtime: tokio::runtime::Handle) {
+    if RUNTIME.set(runtime).is_err() {
+        warn!("Captions already initialized");
+        return;
+    }
+    start_output(preferences::get_caption_output());
+}
+
+/// Switch the caption output and persist the choice.
+pub(crate) fn set_output(output: CaptionOutput) -> Result<(), preferences::PreferencesError> {
+    preferences::set_caption_output(output)?;
+    start_output(output);
+    Ok(())
+}
+
+/// Publish the caption for the current transcript.
+///
+/// Cheap when captions are off: nothing is computed without a running output.
+/// Nothing is published during a private session.
+pub(crate) fn publish(committed: &str, partial: Option<&str>) {
+    if LATEST.receiver_count() == 0 || private_mode::is_active() {
+        return;
+    }
+    let caption = caption_text(committed, partial);
+    LATEST.send_if_modified(|current| {
+        if *current == caption {
+            return false;
+        }
+        *current = caption;
+        true
+    });
+}
+
+/// Clear the caption when recording stops.
+pub(crate) fn handle_event(event: &AppEvent) {
+    if *event == AppEvent::RecordingStopped {
+        clear();
+    }
+}
+
+/// Clear the caption (e.g. when recording stops).
+///
+/// Always applied, so a client connecting later doesn't see an old caption.
+pub(crate) fn clear() {
+    LATEST.send_if_modified(|current| {
+        let changed = !current.is_empty();
+        current.clear();
+        changed
+    });
+}
+
+fn start_output(output: CaptionOutput) {
+    let Some(runtime) = RUNTIME.get() else {
+        warn!("Caption output requested before initialization");
+        return;
+    };
+    let Ok(mut task) = OUTPUT_TASK.lock() else {
+        return;
+    };
+    if let Some(previous) = task.take() {
+        previous.abort();
+    }
+
+    if output != CaptionOutput::Off && managed::policy().disable_captions {
+        info!("Caption output disabled by managed policy");
+        return;
+    }
+
+    *task = match output {
+        CaptionOutput::Off => None,
+        CaptionOutput::File => {
+            let Some(path) = file_path() else {
+                error!("Could not determine caption file path");
+                return;
+            };
+            let captions = LATEST.subscribe();
+            Some(runtime.spawn(async move {
+                if let Err(e) = write_file(path, captions).await {
+                    error!("Caption file output stopped: {}", e);
+                }
+            }))
+        }
+        CaptionOutput::WebSocket => {
+            let port = preferences::get_caption_websocket_port();
+            Some(runtime.spawn(async move {
+                if let Err(e) = serve_websocket(port).await {
+                    error!("Caption WebSocket stopped: {}", e);
+                }
+            }))
+        }
+    };
+    info!(?output, "Caption output started");
+}
+
+/// Rewrite the caption file whenever the caption changes.
+///
+/// Writes go through a temporary file so readers never see a partial line.
+async fn write_file(path: PathBuf, mut captions: watch::Receiver<String>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let temp_path = path.with_extension("txt.tmp");
+    info!("Writing captions to {:?}", path);
+
+    loop {
+        let caption = captions.borrow_and_update().clone();
+        tokio::fs::write(&temp_path, caption.as_bytes()).await?;
+        tokio::fs::rename(&temp_path, &path).await?;
+
+        if captions.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+/// Accept WebSocket clients on 127.0.0.1:`port` until the task is aborted.
+///
+/// Clients are owned by the server task so stopping it disconnects them.
+async fn serve_websocket(port: u16) -> std::io::Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+    info!("Caption WebSocket listening on ws://127.0.0.1:{}", port);
+
+    let mut clients = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, _) = accepted?;
+                clients.spawn(async move {
+                    if let Err(e) = stream_captions(stream, LATEST.subscribe()).await {
+                        debug!("Caption client disconnected: {}", e);
+                    }
+                });
+            }
+            Some(_) = clients.join_next() => {}
+        }
+    }
+}
+
+/// Send the current caption, then every change, as text frames.
+///
+/// The handshake is refused unless it carries the local API token.
+async fn stream_captions(
+    stream: TcpStream,
+    mut captions: watch::Receiver<String>,
+) -> Result<(), tungstenite::Error> {
+    let token = match automation::http_api_token() {
+        Ok(token) => Some(token),
+        Err(e) => {
+            error!("Refusing caption client without an API token: {}", e);
+            None
+        }
+    };
+    let mut socket = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response| {
+        authorize_handshake(request, response, token.as_deref())
+    })
+    .await?;
+
+    loop {
+        let caption = captions.borrow_and_update().clone();
+        socket.send(Message::Text(caption)).await?;
+
+        if captions.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+/// Accept a handshake carrying `token`, or answer 401 Unauthorized
+fn authorize_handshake(
+    request: &Request,
+    response: Response,
+    token: Option<&str>,
+) -> Result<Response, ErrorResponse> {
+    let authorized = token.is_some_and(|token| {
+        automation::is_authorized(request.headers(), request.uri().query(), token)
+    });
+    if authorized {
+        return Ok(response);
+    }
+    debug!("Caption client refused: missing or invalid token");
+    let mut refusal = ErrorResponse::new(Some("Missing or invalid token".to_string()));
+    *refusal.status_mut() = StatusCode::UNAUTHORIZED;
+    Err(refusal)
+}
+
+/// Caption for the latest speech: the last spoken paragraph followed by the
+/// partial text, cut to the last `MAX_CAPTION_CHARS` characters.
+///
+/// Markers, notes and screenshot references are not speech and are skipped.
+fn caption_text(committed: &str, partial: Option<&str>) -> String {
+    let last_spoken = committed
+        .rsplit("\n\n")
+        .map(str::trim)
+        .find(|paragraph| !paragraph.is_empty() && is_speech(paragraph))
+        .unwrap_or("");
+    let partial = partial.map(str::trim).unwrap_or("");
+
+    let text = match (last_spoken.is_empty(), partial.is_empty()) {
+        (_, true) => last_spoken.to_string(),
+        (true, false) => partial.to_string(),
+        (false, false) => format!("{} {}", last_spoken, partial),
+    };
+    tail_at_word_boundary(&text, MAX_CAPTION_CHARS)
+}
+
+fn is_speech(paragraph: &str) -> bool {
+    !(paragraph.starts_with("=== MARKER")
+        || paragraph.starts_with("> Note:")
+        || paragraph.starts_with("[connection lost")
+        || paragraph.starts_with("![Screenshot]"))
+}
+
+/// The last `max_chars` characters of `text`, without a cut-off first word
+fn tail_at_word_boundary(text: &str, max_chars: usize) -> String {
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text.to_string();
+    }
+
+    let start = text
+        .char_indices()
+        .nth(char_count - max_chars)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let tail = &text[start..];
+    if text[..start].ends_with(char::is_whitespace) {
+        return tail.trim_start().to_string();
+    }
+    match tail.find(char::is_whitespace) {
+        Some(i) => tail[i..].trim_start().to_string(),
+        None => tail.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caption_combines_last_paragraph_and_partial() {
+        assert_eq!(caption_text("", None), "");
+        assert_eq!(caption_text("Hello there.", None), "Hello there.");
+        assert_eq!(
+            caption_text("Hello there.", Some(" How are")),
+            "Hello there. How are"
+        );
+        assert_eq!(caption_text("", Some("How are")), "How are");
+    }
+
+    #[test]
+    fn test_caption_skips_markers_notes_and_screenshots() {
+        let committed = "First point. \n\n=== MARKER 10:15 ===\n\n \n\n> Note: Bob joined\n\n \n\n![Screenshot](screenshots/a.png)\n\n";
+        assert_eq!(caption_text(committed, None), "First point.");
+
+        let committed = "Old topic. \n\n=== MARKER 10:15 ===\n\n New topic.";
+        assert_eq!(caption_text(committed, None), "New topic.");
+    }
+
+    #[test]
+    fn test_caption_is_cut_at_word_boundary() {
+        let long = "word ".repeat(40);
+        let caption = caption_text(&long, Some("end"));
+        assert!(caption.chars().count() <= MAX_CAPTION_CHARS);
+        assert!(caption.starts_with("word"));
+        assert!(caption.ends_with("word end"));
+
+        assert_eq!(tail_at_word_boundary("alpha beta gamma", 10), "beta gamma");
+        assert_eq!(tail_at_word_boundary("alpha beta gamma", 9), "gamma");
+        assert_eq!(tail_at_word_boundary("ærlig talt", 4), "talt");
+    }
+
+    #[test]
+    fn test_handshake_requires_token() {
+        let request = |uri: &str| Request::builder().uri(uri).body(()).unwrap();
+
+        let refused = authorize_handshake(&request("/"), Response::default(), Some("abc123"));
+        assert_eq!(refused.unwrap_err().status(), StatusCode::UNAUTHORIZED);
+        let refused = authorize_handshake(
+            &request("/?token=wrong1"),
+            Response::default(),
+            Some("abc123"),
+        );
+        assert!(refused.is_err());
+        assert!(
+            authorize_handshake(&request("/?token=abc123"), Response::default(), None).is_err()
+        );
+
+        assert!(authorize_handshake(
+            &request("/?token=abc123"),
+            Response::default(),
+            Some("abc123")
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_output_index_round_trips() {
+        for output in CaptionOutput::ALL {
+            assert_eq!(CaptionOutput::from_index(output.to_index()), Some(output));
+        }
+        assert_eq!(CaptionOutput::from_index(3), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! Consent notice at the top of exported meeting notes
+//!
+//! Some organizations require the notes of a transcribed meeting to say so.
+//! With the notice turned on in the Notes tab of Settings, meeting notes
+//! saved as Markdown or PDF, printed, or exported to Notion start with a
+//! configurable line such as "This meeting was transcribed by Vissper on
+//! 2026-03-14.", where `{date}` in the text is replaced with the date.
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::preferences;
+use crate::transcription_window::TabType;
+
+/// Notice used until the user writes their own
+pub(crate) const DEFAULT_TEXT: &str = "This meeting was transcribed by Vissper on {date}.";
+
+/// Replaced with the meeting's date in the notice
+const DATE_PLACEHOLDER: &str = "{date}";
+
+/// Whether exported meeting notes start with a notice, and its text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ConsentNotice {
+    pub(crate) enabled: bool,
+    pub(crate) text: String,
+}
+
+impl Default for ConsentNotice {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            text: DEFAULT_TEXT.to_string(),
+        }
+    }
+}
+
+impl ConsentNotice {
+    /// The notice for a meeting held on `date`, None when turned off or empty
+    fn line(&self, date: NaiveDate) -> Option<String> {
+        let text = self.text.trim();
+        if !self.enabled || text.is_empty() {
+            return None;
+        }
+        Some(text.replace(DATE_PLACEHOLDER, &date.format("%Y-%m-%d").to_string()))
+    }
+
+    /// `notes` with the notice as their first paragraph
+    fn prepend(&self, notes: &str, date: NaiveDate) -> String {
+        match self.line(date) {
+            Some(line) => format!("{}\n\n{}", line, notes),
+            None => notes.to_string(),
+        }
+    }
+}
+
+/// Content of `tab` as exported, starting with the consent notice if the
+/// tab holds meeting notes and the notice is turned on
+pub(crate) fn add_to_export(tab: TabType, content: &str, date: DateTime<Local>) -> String {
+    if tab != TabType::MeetingNotes {
+        return content.to_string();
+    }
+    preferences::get_consent_notice().prepend(content, date.date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()
+    }
+
+    #[test]
+    fn test_notice_is_off_by_default() {
+        let notes = "## Summary\n- Budget approved";
+        assert_eq!(ConsentNotice::default().prepend(notes, date()), notes);
+    }
+
+    #[test]
+    fn test_notice_fills_in_the_date() {
+        let notice = ConsentNotice {
+            enabled: true,
+            ..ConsentNotice::default()
+        };
+        assert_eq!(
+            notice.prepend("## Summary", date()),
+            "This meeting was transcribed by Vissper on 2026-03-14.\n\n## Summary"
+        );
+    }
+
+    #[test]
+    fn test_empty_notice_adds_nothing() {
+        let notice = ConsentNotice {
+            enabled: true,
+            text: "  ".to_string(),
+        };
+        assert_eq!(notice.prepend("## Summary", date()), "## Summary");
+    }
+}
@@ -0,0 +1,313 @@
+//! Encryption at rest for saved transcripts and screenshots
+//!
+//! When enabled in the Privacy tab of Settings, files are sealed with
+//! AES-256-GCM and written with an extra `.enc` extension. The key is
+//! generated on first use and kept in the macOS Keychain; it never touches
+//! disk or the preferences file.
+//!
+//! # File format
+//! `MAGIC` (8 bytes) | nonce (12 bytes) | ciphertext with 16-byte tag
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use once_cell::sync::OnceCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+use crate::error::KeychainError;
+use crate::{keychain, managed, preferences, storage};
+
+/// Header identifying files written by this module (format version 1)
+const MAGIC: &[u8; 8] = b"VSPRENC1";
+
+/// AES-GCM nonce length in bytes
+const NONCE_LEN: usize = 12;
+
+/// AES-256 key length in bytes
+const KEY_LEN: usize = 32;
+
+/// Extension appended to encrypted files (e.g. `transcript.md.enc`)
+pub(crate) const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Key loaded from the keychain, kept for the life of the process
+static KEY: OnceCell<Zeroizing<[u8; KEY_LEN]>> = OnceCell::new();
+
+/// Encryption errors
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum EncryptionError {
+    #[error("Keychain error: {0}")]
+    Keychain(#[from] KeychainError),
+
+    #[error("Failed to save setting: {0}")]
+    Preferences(#[from] preferences::PreferencesError),
+
+    #[error("Stored encryption key has the wrong length")]
+    InvalidKey,
+
+    #[error("Encryption failed")]
+    Encrypt,
+
+    #[error("File could not be decrypted (wrong key or damaged file)")]
+    Decrypt,
+
+    #[error("{0}")]
+    Storage(#[from] storage::StorageError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Whether new transcripts and screenshots should be encrypted
+///
+/// A managed policy overrides the user's choice.
+pub(crate) fn is_enabled() -> bool {
+    managed::policy()
+        .encrypt_at_rest
+        .unwrap_or_else(preferences::get_encrypt_at_rest)
+}
+
+/// Turn encryption at rest on or off.
+///
+/// Turning it on creates the keychain key right away, so a keychain problem
+/// is reported here rather than on the first save.
+pub(crate) fn set_enabled(enabled: bool) -> Result<(), EncryptionError> {
+    if enabled {
+        key()?;
+    }
+    preferences::set_encrypt_at_rest(enabled)?;
+    Ok(())
+}
+
+/// Path an encrypted copy of `path` is written to
+pub(crate) fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(ENCRYPTED_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Whether `path` has the encrypted file extension
+pub(crate) fn is_encrypted_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(ENCRYPTED_EXTENSION))
+}
+
+/// `path` without the encrypted file extension, if it has one
+pub(crate) fn plaintext_path(path: &Path) -> PathBuf {
+    if is_encrypted_path(path) {
+        path.with_extension("")
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Write `contents` to `path`, encrypting it when encryption is enabled.
+///
+/// Returns the path actually written, which has `.enc` appended when encrypted
+/// and may be renamed to avoid an iCloud conflict.
+pub(crate) fn write_file(path: &Path, contents: &[u8]) -> Result<PathBuf, EncryptionError> {
+    if !is_enabled() {
+        return Ok(storage::write_file(path, contents)?);
+    }
+
+    let target = encrypted_path(path);
+    Ok(storage::write_file(&target, &seal(&*key()?, contents)?)?)
+}
+
+/// Write `contents` to a new file named `name` in `dir`, encrypting it when
+/// encryption is enabled.
+///
+/// Never replaces an existing file, see [`storage::write_new_file`]. Returns
+/// the path actually written.
+pub(crate) fn write_new_file(
+    dir: &Path,
+    name: &str,
+    contents: &[u8],
+) -> Result<PathBuf, EncryptionError> {
+    if !is_enabled() {
+        return Ok(storage::write_new_file(dir, name, contents)?);
+    }
+
+    let name = format!("{}.{}", name, ENCRYPTED_EXTENSION);
+    Ok(storage::write_new_file(
+        dir,
+        &name,
+        &seal(&*key()?, contents)?,
+    )?)
+}
+
+/// Replace a plaintext file with its encrypted copy.
+///
+/// The plaintext is only removed once the encrypted copy has been written.
+pub(crate) fn encrypt_file_in_place(path: &Path) -> Result<PathBuf, EncryptionError> {
+    let contents = Zeroizing::new(fs::read(path)?);
+    let target = storage::write_file(&encrypted_path(path), &seal(&*key()?, &contents)?)?;
+    fs::remove_file(path)?;
+    info!("Encrypted {:?}", target);
+    Ok(target)
+}
+
+/// Read a file, decrypting it if it was written encrypted.
+///
+/// Plaintext files are returned unchanged, so callers can read either kind.
+pub(crate) fn read_file(path: &Path) -> Result<Vec<u8>, EncryptionError> {
+    let contents = fs::read(path)?;
+    if !is_sealed(&contents) {
+        return Ok(contents);
+    }
+    open(&*key()?, &contents)
+}
+
+/// Decrypt `path` into a private temporary folder and return the copy's path.
+///
+/// Used to hand encrypted files to other apps. The folder is only readable by
+/// the current user and is emptied each time Vissper starts.
+pub(crate) fn decrypt_to_temp(path: &Path) -> Result<PathBuf, EncryptionError> {
+    let contents = Zeroizing::new(read_file(path)?);
+
+    let dir = temp_dir();
+    fs::create_dir_all(&dir)?;
+    restrict_to_owner(&dir)?;
+
+    let file_name = if is_encrypted_path(path) {
+        path.file_stem()
+    } else {
+        path.file_name()
+    };
+    let target = dir.join(file_name.unwrap_or_else(|| "decrypted".as_ref()));
+    fs::write(&target, contents.as_slice())?;
+    Ok(target)
+}
+
+/// Remove decrypted copies left over from the previous run.
+pub(crate) fn clear_temp_dir() {
+    let dir = temp_dir();
+    if dir.exists() {
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            warn!("Failed to remove decrypted files in {:?}: {}", dir, e);
+        }
+    }
+}
+
+fn temp_dir() -> PathBuf {
+    std::env::temp_dir().join("Vissper-decrypted")
+}
+
+#[cfg(unix)]
+fn restrict_to_owner(dir: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(dir, fs::Permissions::from_mode(0o700))
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_dir: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// The storage key, created in the keychain on first use
+fn key() -> Result<Zeroizing<[u8; KEY_LEN]>, EncryptionError> {
+    KEY.get_or_try_init(|| {
+        let stored = keychain::get_storage_key()?.map(Zeroizing::new);
+        let key = match stored {
+            Some(stored) => {
+                let key: [u8; KEY_LEN] = stored
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| EncryptionError::InvalidKey)?;
+                Zeroizing::new(key)
+            }
+            None => {
+                let key: Zeroizing<[u8; KEY_LEN]> =
+                    Zeroizing::new(Aes256Gcm::generate_key(OsRng).into());
+                keychain::store_storage_key(key.as_slice())?;
+                info!("Created storage encryption key in keychain");
+                key
+            }
+        };
+        Ok(key)
+    })
+    .cloned()
+}
+
+/// Whether `contents` starts with the encrypted file header
+fn is_sealed(contents: &[u8]) -> bool {
+    contents.starts_with(MAGIC)
+}
+
+/// Encrypt `plaintext` into the file format described in the module docs
+fn seal(key: &[u8; KEY_LEN], plaintext: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| EncryptionError::Encrypt)?;
+
+    let mut sealed = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(MAGIC);
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt data produced by `seal`
+fn open(key: &[u8; KEY_LEN], sealed: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+    let body = sealed
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or(EncryptionError::Decrypt)?;
+    if body.len() < NONCE_LEN {
+        return Err(EncryptionError::Decrypt);
+    }
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| EncryptionError::Decrypt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_KEY: [u8; KEY_LEN] = [7; KEY_LEN];
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let plaintext = b"# Board meeting\n\nConfidential.";
+        let sealed = seal(&TEST_KEY, plaintext).unwrap();
+
+        assert!(is_sealed(&sealed));
+        assert_ne!(&sealed[MAGIC.len() + NONCE_LEN..], plaintext.as_slice());
+        assert_eq!(open(&TEST_KEY, &sealed).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_open_rejects_tampering_and_wrong_key() {
+        let mut sealed = seal(&TEST_KEY, b"secret").unwrap();
+
+        assert!(open(&[8; KEY_LEN], &sealed).is_err());
+        assert!(open(&TEST_KEY, &sealed[..MAGIC.len() + 4]).is_err());
+
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(open(&TEST_KEY, &sealed).is_err());
+    }
+
+    #[test]
+    fn test_plaintext_is_not_sealed() {
+        assert!(!is_sealed(b"# Transcript"));
+        assert!(!is_sealed(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn test_encrypted_paths() {
+        let path = Path::new("/tmp/transcript.md");
+        let encrypted = encrypted_path(path);
+
+        assert_eq!(encrypted, Path::new("/tmp/transcript.md.enc"));
+        assert!(is_encrypted_path(&encrypted));
+        assert!(!is_encrypted_path(path));
+    }
+}
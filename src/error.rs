@@ -41,6 +41,94 @@ pub enum KeychainError {
     #[error("Invalid credential data: {0}")]
     InvalidData(String),
 
+    #[error("Wrong passphrase, or the credentials file is damaged")]
+    WrongPassphrase,
+
     #[error("Credential storage not implemented for this platform")]
     NotImplemented,
 }
+
+/// Problems with Azure connection settings typed into Settings
+///
+/// The messages are shown next to the field they concern.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AzureInputError {
+    #[error("Required")]
+    Missing,
+
+    #[error("Not a valid URL")]
+    InvalidUrl,
+
+    #[error("Must start with https://")]
+    NotHttps,
+
+    #[error("Only letters, digits, '-', '_' and '.' are allowed")]
+    InvalidDeploymentName,
+
+    #[error("At most 64 characters")]
+    DeploymentNameTooLong,
+
+    #[error("This is an OpenAI key; enter it in the OpenAI tab")]
+    OpenAIKey,
+
+    #[error("Azure keys are at least 32 letters and digits")]
+    InvalidApiKey,
+}
+
+/// Issue tracker (Jira / Linear) errors
+#[derive(Debug, Error)]
+pub enum IssueError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("Tracker error ({status}): {message}")]
+    ServerError { status: u16, message: String },
+
+    #[error("Invalid response from tracker: {0}")]
+    InvalidResponse(String),
+
+    #[error("No Linear team with key {0}")]
+    TeamNotFound(String),
+}
+
+/// Notion export errors
+#[derive(Debug, Error)]
+pub enum NotionError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("Notion error ({status}): {message}")]
+    ServerError { status: u16, message: String },
+
+    #[error("Invalid response from Notion: {0}")]
+    InvalidResponse(String),
+}
+
+/// Git transcript archive errors
+#[derive(Debug, Error)]
+pub enum GitArchiveError {
+    #[error("Could not run git: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("git {command} failed with exit code {code:?}")]
+    CommandFailed { command: String, code: Option<i32> },
+}
+
+/// Google Drive sign-in and upload errors
+#[derive(Debug, Error)]
+pub enum GoogleDriveError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("Google error ({status}): {message}")]
+    ServerError { status: u16, message: String },
+
+    #[error("Invalid response from Google: {0}")]
+    InvalidResponse(String),
+
+    #[error("Sign-in failed: {0}")]
+    SignIn(String),
+
+    #[error("Sign-in callback failed: {0}")]
+    Callback(#[from] std::io::Error),
+}
@@ -0,0 +1,176 @@
+//! App-wide event bus
+//!
+//! Recording, polishing, screenshots and the update checker publish an
+//! [`AppEvent`] instead of calling each module that cares. Feedback, usage
+//! statistics, captions, the menu bar, the screenshot flash, the transcription
+//! window's screenshot strip, the Notion export and the HTTP API subscribe, so
+//! new integrations hook in without touching the publishers.
+//!
+//! Preferences publish [`AppEvent::SettingChanged`] when a setting that
+//! affects recording or the overlay is saved, so the overlay can apply it
+//! right away or tell the user it applies to the next recording.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::warn;
+
+use crate::transcription_window::TabType;
+
+/// Events buffered per subscriber before the slowest one starts missing events
+const CAPACITY: usize = 256;
+
+/// Something that happened in the app
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum AppEvent {
+    RecordingStarted,
+    RecordingStopped,
+    /// The speech service finalized a segment of the transcript
+    SegmentCommitted {
+        text: String,
+    },
+    TranscriptionError,
+    Reconnected,
+    ReconnectFailed,
+    /// The microphone's sample rate changed mid-stream, in Hz
+    InputRateChanged {
+        from: u32,
+        to: u32,
+    },
+    /// Polished text or meeting notes are ready in the given tab
+    PolishCompleted {
+        tab: TabType,
+    },
+    PolishFailed,
+    ScreenshotCaptured {
+        filename: String,
+    },
+    UpdateAvailable {
+        version: String,
+    },
+    /// A setting was saved in Settings or the menu bar
+    SettingChanged {
+        setting: Setting,
+    },
+}
+
+/// Settings whose changes other parts of the app react to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Setting {
+    Transparency,
+    DarkMode,
+    /// Transcription provider, also changed by switching workspace
+    Provider,
+    PolishProvider,
+    Language,
+    TranscribeModel,
+    /// Chunk size, buffer depth or low-bandwidth mode
+    Audio,
+    NoiseReduction,
+    StallTimeout,
+    /// An API key or endpoint saved to the keychain
+    Credentials,
+    /// Whether the transcription window shows the Debug tab
+    DebugTab,
+}
+
+impl Setting {
+    /// Whether a running recording keeps the old value until it stops
+    ///
+    /// Recording reads these when it connects, and changing them under a
+    /// live connection would mix providers or audio formats within one
+    /// transcript. Polishing reads its provider when it runs, and a running
+    /// recording switches to a new language, tagging the segments after it.
+    pub(crate) fn applies_to_next_recording(self) -> bool {
+        !matches!(
+            self,
+            Setting::Transparency
+                | Setting::DarkMode
+                | Setting::PolishProvider
+                | Setting::Language
+                | Setting::DebugTab
+        )
+    }
+}
+
+static BUS: Lazy<broadcast::Sender<AppEvent>> = Lazy::new(|| broadcast::channel(CAPACITY).0);
+
+/// Send an event to every current subscriber.
+pub(crate) fn publish(event: AppEvent) {
+    // Having no subscribers yet is fine
+    let _ = BUS.send(event);
+}
+
+/// Receive events published from now on.
+pub(crate) fn subscribe() -> broadcast::Receiver<AppEvent> {
+    BUS.subscribe()
+}
+
+/// Call `handler` for every event on its own task until the app exits.
+///
+/// Subscribes immediately, so nothing published after this returns is missed.
+pub(crate) fn listen(runtime: &tokio::runtime::Handle, handler: fn(&AppEvent)) {
+    let mut events = subscribe();
+    runtime.spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => handler(&event),
+                Err(RecvError::Lagged(missed)) => {
+                    warn!("Event listener fell behind and missed {} events", missed);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribers_receive_published_events() {
+        let mut first = subscribe();
+        let mut second = subscribe();
+
+        let event = AppEvent::ScreenshotCaptured {
+            filename: "screenshot_test.png".to_string(),
+        };
+        publish(event.clone());
+
+        // Other tests publish on the same bus, so skip unrelated events
+        for receiver in [&mut first, &mut second] {
+            loop {
+                if receiver.recv().await.unwrap() == event {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_events_serialize_with_tag() {
+        let json = serde_json::to_value(AppEvent::PolishCompleted {
+            tab: TabType::MeetingNotes,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"event": "polish_completed", "tab": "meeting_notes"})
+        );
+
+        let json = serde_json::to_value(AppEvent::RecordingStarted).unwrap();
+        assert_eq!(json, serde_json::json!({"event": "recording_started"}));
+
+        let json = serde_json::to_value(AppEvent::SettingChanged {
+            setting: Setting::TranscribeModel,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"event": "setting_changed", "setting": "transcribe_model"})
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Sound and notification feedback for recording state changes
+//!
+//! The menu bar icon change is easy to miss, so recording start/stop can play
+//! a short system sound, and key events can post a macOS user notification.
+//! Both are opt-in and configured in the Notifications tab of Settings.
+
+mod notifications;
+mod sound;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::event_bus::AppEvent;
+use crate::preferences;
+use crate::transcription_window::{TabType, TranscriptionWindow};
+
+/// Events that can produce a sound cue or notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FeedbackEvent {
+    RecordingStarted,
+    RecordingStopped,
+    /// Polished text or meeting notes are ready in the given tab
+    PolishingComplete(TabType),
+    ReconnectFailed,
+}
+
+/// User-configurable feedback options, in the order shown in Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FeedbackOption {
+    SoundCues,
+    NotifyRecordingStarted,
+    NotifyPolishingComplete,
+    NotifyPolishingCompleteWhileHidden,
+    NotifyReconnectFailed,
+}
+
+impl FeedbackOption {
+    pub(crate) const ALL: [FeedbackOption; 5] = [
+        FeedbackOption::SoundCues,
+        FeedbackOption::NotifyRecordingStarted,
+        FeedbackOption::NotifyPolishingComplete,
+        FeedbackOption::NotifyPolishingCompleteWhileHidden,
+        FeedbackOption::NotifyReconnectFailed,
+    ];
+
+    /// Checkbox title in Settings
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            FeedbackOption::SoundCues => "Play a sound when recording starts and stops",
+            FeedbackOption::NotifyRecordingStarted => "Notify when recording starts",
+            FeedbackOption::NotifyPolishingComplete => "Notify when polishing completes",
+            FeedbackOption::NotifyPolishingCompleteWhileHidden => {
+                "Notify when polishing completes while the window is hidden"
+            }
+            FeedbackOption::NotifyReconnectFailed => {
+                "Notify when the connection cannot be restored"
+            }
+        }
+    }
+
+    /// Look up an option by its index in `ALL` (used as the control tag)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    /// Whether this option posts user notifications
+    pub(crate) fn is_notification(self) -> bool {
+        self != FeedbackOption::SoundCues
+    }
+}
+
+/// Persisted feedback settings
+///
+/// Everything is off by default, so notification permission is only asked
+/// for once an option is turned on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct FeedbackSettings {
+    pub sound_cues: bool,
+    pub notify_recording_started: bool,
+    pub notify_polishing_complete: bool,
+    pub notify_polishing_complete_while_hidden: bool,
+    pub notify_reconnect_failed: bool,
+}
+
+impl Default for FeedbackSettings {
+    fn default() -> Self {
+        Self {
+            sound_cues: false,
+            notify_recording_started: false,
+            notify_polishing_complete: false,
+            notify_polishing_complete_while_hidden: false,
+            notify_reconnect_failed: false,
+        }
+    }
+}
+
+impl FeedbackSettings {
+    pub(crate) fn is_enabled(&self, option: FeedbackOption) -> bool {
+        match option {
+            FeedbackOption::SoundCues => self.sound_cues,
+            FeedbackOption::NotifyRecordingStarted => self.notify_recording_started,
+            FeedbackOption::NotifyPolishingComplete => self.notify_polishing_complete,
+            FeedbackOption::NotifyPolishingCompleteWhileHidden => {
+                self.notify_polishing_complete_while_hidden
+            }
+            FeedbackOption::NotifyReconnectFailed => self.notify_reconnect_failed,
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, option: FeedbackOption, enabled: bool) {
+        let field = match option {
+            FeedbackOption::SoundCues => &mut self.sound_cues,
+            FeedbackOption::NotifyRecordingStarted => &mut self.notify_recording_started,
+            FeedbackOption::NotifyPolishingComplete => &mut self.notify_polishing_complete,
+            FeedbackOption::NotifyPolishingCompleteWhileHidden => {
+                &mut self.notify_polishing_complete_while_hidden
+            }
+            FeedbackOption::NotifyReconnectFailed => &mut self.notify_reconnect_failed,
+        };
+        *field = enabled;
+    }
+
+    /// Whether any notification option is enabled
+    pub(crate) fn any_notifications(&self) -> bool {
+        FeedbackOption::ALL
+            .into_iter()
+            .any(|option| option.is_notification() && self.is_enabled(option))
+    }
+}
+
+impl FeedbackEvent {
+    /// System sound to play, if this event has a sound cue
+    fn sound_name(self) -> Option<&'static str> {
+        match self {
+            FeedbackEvent::RecordingStarted => Some("Tink"),
+            FeedbackEvent::RecordingStopped => Some("Pop"),
+            FeedbackEvent::PolishingComplete(_) | FeedbackEvent::ReconnectFailed => None,
+        }
+    }
+
+    /// Whether this event's notification is enabled.
+    ///
+    /// `window_visible` is only consulted for polishing, which can also
+    /// notify just when the transcription window is hidden.
+    fn should_notify(
+        self,
+        settings: &FeedbackSettings,
+        window_visible: impl FnOnce() -> bool,
+    ) -> bool {
+        match self {
+            FeedbackEvent::RecordingStarted => settings.notify_recording_started,
+            FeedbackEvent::RecordingStopped => false,
+            FeedbackEvent::PolishingComplete(_) => {
+                settings.notify_polishing_complete
+                    || (settings.notify_polishing_complete_while_hidden && !window_visible())
+            }
+            FeedbackEvent::ReconnectFailed => settings.notify_reconnect_failed,
+        }
+    }
+
+    /// Notification title and body
+    fn notification_text(self) -> (&'static str, &'static str) {
+        match self {
+            FeedbackEvent::RecordingStarted => ("Recording started", "Vissper is transcribing."),
+            FeedbackEvent::RecordingStopped => ("Recording stopped", ""),
+            FeedbackEvent::PolishingComplete(TabType::MeetingNotes) => (
+                "Meeting notes ready",
+                "Your meeting notes are ready and copied to the clipboard.",
+            ),
+            FeedbackEvent::PolishingComplete(TabType::FollowUpEmail) => (
+                "Follow-up email ready",
+                "Your follow-up email is ready and copied to the clipboard.",
+            ),
+            FeedbackEvent::PolishingComplete(_) => (
+                "Polishing complete",
+                "Your transcript is ready and copied to the clipboard.",
+            ),
+            FeedbackEvent::ReconnectFailed => (
+                "Reconnect failed",
+                "Lost connection to the transcription service. Recording has stopped transcribing.",
+            ),
+        }
+    }
+
+    /// Tab opened by the notification's "Show" action, if it has one
+    fn show_tab(self) -> Option<TabType> {
+        match self {
+            FeedbackEvent::PolishingComplete(tab) => Some(tab),
+            _ => None,
+        }
+    }
+}
+
+/// Request notification permission at startup if any notification is enabled.
+pub(crate) fn init() {
+    if preferences::get_feedback_settings().any_notifications() {
+        notifications::request_authorization();
+    }
+}
+
+/// Update a feedback option and persist it.
+///
+/// Enabling a notification option asks for notification permission.
+pub(crate) fn set_option(
+    option: FeedbackOption,
+    enabled: bool,
+) -> Result<(), preferences::PreferencesError> {
+    let mut settings = preferences::get_feedback_settings();
+    settings.set_enabled(option, enabled);
+    preferences::set_feedback_settings(settings)?;
+
+    if enabled && option.is_notification() {
+        notifications::request_authorization();
+    }
+    Ok(())
+}
+
+/// Give feedback for the app events that have a sound cue or notification.
+pub(crate) fn handle_event(event: &AppEvent) {
+    let feedback = match event {
+        AppEvent::RecordingStarted => FeedbackEvent::RecordingStarted,
+        AppEvent::RecordingStopped => FeedbackEvent::RecordingStopped,
+        AppEvent::PolishCompleted { tab } => FeedbackEvent::PolishingComplete(*tab),
+        AppEvent::ReconnectFailed => FeedbackEvent::ReconnectFailed,
+        _ => return,
+    };
+    notify(feedback);
+}
+
+/// Play the sound cue and post the notification for `event`, as configured.
+///
+/// Safe to call from any thread.
+pub(crate) fn notify(event: FeedbackEvent) {
+    let settings = preferences::get_feedback_settings();
+
+    debug!(?event, "Recording feedback");
+    dispatch::Queue::main().exec_async(move || {
+        if settings.sound_cues {
+            if let Some(name) = event.sound_name() {
+                sound::play(name);
+            }
+        }
+        if event.should_notify(&settings, TranscriptionWindow::is_visible) {
+            let (title, body) = event.notification_text();
+            notifications::post(title, body, event.show_tab());
+        }
+    });
+}
+
+/// Post a notification right away, regardless of the notification options.
+///
+/// For the outcome of an action the user just triggered, such as quick
+/// polish. Safe to call from any thread.
+pub(crate) fn notify_now(title: &str, body: &str) {
+    let (title, body) = (title.to_string(), body.to_string());
+    dispatch::Queue::main().exec_async(move || {
+        // Only prompts the first time; afterwards the stored decision applies
+        notifications::request_authorization();
+        notifications::post(&title, &body, None);
+    });
+}
+
+/// Ask whether to keep recording, with "Keep Recording" and "Stop Recording"
+/// actions, regardless of the notification options.
+///
+/// Safe to call from any thread.
+pub(crate) fn remind_recording(title: &str, body: &str) {
+    let (title, body) = (title.to_string(), body.to_string());
+    dispatch::Queue::main().exec_async(move || {
+        notifications::request_authorization();
+        notifications::post_recording_reminder(&title, &body);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_defaults() {
+        let settings: FeedbackSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, FeedbackSettings::default());
+        for option in FeedbackOption::ALL {
+            assert!(!settings.is_enabled(option));
+        }
+        assert!(!settings.any_notifications());
+    }
+
+    #[test]
+    fn test_set_enabled_round_trips() {
+        for option in FeedbackOption::ALL {
+            let mut settings = FeedbackSettings::default();
+            settings.set_enabled(option, false);
+            assert!(!settings.is_enabled(option));
+            settings.set_enabled(option, true);
+            assert!(settings.is_enabled(option));
+        }
+    }
+
+    #[test]
+    fn test_polishing_notifies_when_hidden() {
+        let event = FeedbackEvent::PolishingComplete(TabType::MeetingNotes);
+        assert!(!event.should_notify(&FeedbackSettings::default(), || false));
+
+        let settings = FeedbackSettings {
+            notify_polishing_complete_while_hidden: true,
+            ..FeedbackSettings::default()
+        };
+        assert!(event.should_notify(&settings, || false));
+        assert!(!event.should_notify(&settings, || true));
+
+        let always = FeedbackSettings {
+            notify_polishing_complete: true,
+            ..FeedbackSettings::default()
+        };
+        assert!(event.should_notify(&always, || true));
+    }
+
+    #[test]
+    fn test_option_from_index() {
+        assert_eq!(
+            FeedbackOption::from_index(0),
+            Some(FeedbackOption::SoundCues)
+        );
+        assert_eq!(
+            FeedbackOption::from_index(4),
+            Some(FeedbackOption::NotifyReconnectFailed)
+        );
+        assert_eq!(FeedbackOption::from_index(5), None);
+        assert_eq!(FeedbackOption::from_index(-1), None);
+    }
+}
@@ -0,0 +1,414 @@
+//! macOS user notifications (UNUserNotificationCenter)
+//!
+//! UNUserNotificationCenter requires a bundled app; when running unbundled
+//! (e.g. `cargo run`) notifications are skipped with a warning.
+//!
+//! Notifications for finished polishing carry a "Show" action that reopens
+//! the transcription window on the tab holding the result. The reminder about
+//! a recording that has gone quiet offers "Keep Recording" and "Stop Recording".
+
+use block2::{Block, RcBlock};
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject, Bool};
+use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType, DeclaredClass};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSString};
+use std::cell::RefCell;
+use tracing::{error, info, warn};
+
+use crate::menubar::MenuBar;
+use crate::transcription_window::{TabType, TranscriptionWindow};
+
+#[link(name = "UserNotifications", kind = "framework")]
+extern "C" {}
+
+/// UNAuthorizationOptionAlert
+const AUTHORIZATION_OPTION_ALERT: usize = 1 << 2;
+
+/// UNNotificationPresentationOptionList | UNNotificationPresentationOptionBanner
+const PRESENTATION_OPTIONS_BANNER: usize = (1 << 3) | (1 << 4);
+
+/// UNNotificationActionOptionForeground: launch the app when the action is chosen
+const ACTION_OPTION_FOREGROUND: usize = 1 << 2;
+
+/// Category for notifications with a "Show" action
+const SHOW_CATEGORY_ID: &str = "VissperShowTranscript";
+
+/// Identifier of the "Show" action
+const SHOW_ACTION_ID: &str = "VissperShow";
+
+/// Category for the reminder with "Keep Recording" and "Stop Recording" actions
+const RECORDING_CATEGORY_ID: &str = "VissperRecordingReminder";
+
+/// Identifier of the "Keep Recording" action
+const KEEP_RECORDING_ACTION_ID: &str = "VissperKeepRecording";
+
+/// Identifier of the "Stop Recording" action
+const STOP_RECORDING_ACTION_ID: &str = "VissperStopRecording";
+
+/// UNNotificationDefaultActionIdentifier (the user clicked the notification itself)
+const DEFAULT_ACTION_ID: &str = "com.apple.UNNotificationDefaultActionIdentifier";
+
+/// userInfo key holding the tab index to show
+const TAB_USER_INFO_KEY: &str = "tab";
+
+declare_class!(
+    /// Notification center delegate so notifications are shown even while
+    /// the app (or its overlay window) is active.
+    struct NotificationDelegate;
+
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - The delegate is created and installed on the main thread.
+    // - `NotificationDelegate` does not implement `Drop`.
+    unsafe impl ClassType for NotificationDelegate {
+        type Super = NSObject;
+        type Mutability = mutability::MainThreadOnly;
+        const NAME: &'static str = "VissperNotificationDelegate";
+    }
+
+    impl DeclaredClass for NotificationDelegate {}
+
+    unsafe impl NotificationDelegate {
+        #[method(userNotificationCenter:willPresentNotification:withCompletionHandler:)]
+        fn will_present_notification(
+            &self,
+            _center: *mut AnyObject,
+            _notification: *mut AnyObject,
+            completion_handler: &Block<dyn Fn(usize)>,
+        ) {
+            completion_handler.call((PRESENTATION_OPTIONS_BANNER,));
+        }
+
+        #[method(userNotificationCenter:didReceiveNotificationResponse:withCompletionHandler:)]
+        fn did_receive_notification_response(
+            &self,
+            _center: *mut AnyObject,
+            response: *mut AnyObject,
+            completion_handler: &Block<dyn Fn()>,
+        ) {
+            // SAFETY: AppKit passes a valid UNNotificationResponse
+            let (action_id, tab) = unsafe { (action_identifier(response), tab_to_show(response)) };
+            if action_id.as_deref() == Some(STOP_RECORDING_ACTION_ID) {
+                info!("Stopping recording from notification");
+                MenuBar::stop_recording(TabType::Live);
+            } else if let Some(tab) = tab {
+                info!(?tab, "Showing transcription window from notification");
+                TranscriptionWindow::show();
+                TranscriptionWindow::switch_to_tab(tab);
+            }
+            completion_handler.call(());
+        }
+    }
+
+    unsafe impl NSObjectProtocol for NotificationDelegate {}
+);
+
+impl NotificationDelegate {
+    fn new(mtm: MainThreadMarker) -> Retained<Self> {
+        unsafe { msg_send_id![mtm.alloc::<Self>(), init] }
+    }
+}
+
+thread_local! {
+    /// The installed delegate (the notification center only holds a weak reference).
+    /// Only touched on the main thread.
+    static DELEGATE: RefCell<Option<Retained<NotificationDelegate>>> = const { RefCell::new(None) };
+}
+
+/// Get the current notification center, installing our delegate on first use.
+///
+/// Returns `None` when running without a bundle identifier or if the
+/// UserNotifications classes are unavailable. Must be called on the main thread.
+fn notification_center() -> Option<*mut AnyObject> {
+    let mtm = MainThreadMarker::new()?;
+
+    if !has_bundle_identifier() {
+        warn!("Notifications unavailable: app is not running from a bundle");
+        return None;
+    }
+
+    let center_class = AnyClass::get("UNUserNotificationCenter")?;
+    // SAFETY: currentNotificationCenter is safe to call once a bundle identifier exists
+    let center: *mut AnyObject = unsafe { msg_send![center_class, currentNotificationCenter] };
+    if center.is_null() {
+        return None;
+    }
+
+    DELEGATE.with(|cell| {
+        let mut delegate = cell.borrow_mut();
+        if delegate.is_none() {
+            let new_delegate = NotificationDelegate::new(mtm);
+            // SAFETY: setDelegate: on a valid notification center; we keep the delegate alive
+            unsafe {
+                let _: () = msg_send![center, setDelegate: &*new_delegate];
+                register_categories(center);
+            }
+            *delegate = Some(new_delegate);
+        }
+    });
+
+    Some(center)
+}
+
+/// Register the notification categories carrying the "Show" action and the
+/// recording reminder's actions.
+///
+/// # Safety
+/// `center` must be a valid UNUserNotificationCenter.
+unsafe fn register_categories(center: *mut AnyObject) {
+    let (Some(set_class), Some(array_class)) = (AnyClass::get("NSSet"), AnyClass::get("NSArray"))
+    else {
+        warn!("Notification category classes not available");
+        return;
+    };
+
+    let show = category(
+        SHOW_CATEGORY_ID,
+        &[(SHOW_ACTION_ID, "Show", ACTION_OPTION_FOREGROUND)],
+    );
+    let recording = category(
+        RECORDING_CATEGORY_ID,
+        &[
+            (KEEP_RECORDING_ACTION_ID, "Keep Recording", 0),
+            (STOP_RECORDING_ACTION_ID, "Stop Recording", 0),
+        ],
+    );
+    let (Some(show), Some(recording)) = (show, recording) else {
+        return;
+    };
+
+    let categories = [show, recording];
+    let categories: *mut AnyObject = msg_send![
+        array_class,
+        arrayWithObjects: categories.as_ptr(),
+        count: categories.len()
+    ];
+    let categories: *mut AnyObject = msg_send![set_class, setWithArray: categories];
+    let _: () = msg_send![center, setNotificationCategories: categories];
+}
+
+/// Notification category `id` with the given `(identifier, title, options)`
+/// actions.
+///
+/// # Safety
+/// Must be called where the UserNotifications classes may be used.
+unsafe fn category(id: &str, actions: &[(&str, &str, usize)]) -> Option<*mut AnyObject> {
+    let (Some(action_class), Some(category_class), Some(array_class)) = (
+        AnyClass::get("UNNotificationAction"),
+        AnyClass::get("UNNotificationCategory"),
+        AnyClass::get("NSArray"),
+    ) else {
+        warn!("Notification category classes not available");
+        return None;
+    };
+
+    let mut created = Vec::with_capacity(actions.len());
+    for (identifier, title, options) in actions {
+        let action: *mut AnyObject = msg_send![
+            action_class,
+            actionWithIdentifier: &*NSString::from_str(identifier),
+            title: &*NSString::from_str(title),
+            options: *options
+        ];
+        if action.is_null() {
+            return None;
+        }
+        created.push(action);
+    }
+
+    let actions: *mut AnyObject = msg_send![
+        array_class,
+        arrayWithObjects: created.as_ptr(),
+        count: created.len()
+    ];
+    let no_intents: *mut AnyObject = msg_send![array_class, array];
+    let category: *mut AnyObject = msg_send![
+        category_class,
+        categoryWithIdentifier: &*NSString::from_str(id),
+        actions: actions,
+        intentIdentifiers: no_intents,
+        options: 0usize
+    ];
+    (!category.is_null()).then_some(category)
+}
+
+/// Identifier of the action the user chose in a notification response.
+///
+/// # Safety
+/// `response` must be a valid UNNotificationResponse or null.
+unsafe fn action_identifier(response: *mut AnyObject) -> Option<String> {
+    if response.is_null() {
+        return None;
+    }
+    let action_id: *mut NSString = msg_send![response, actionIdentifier];
+    Some(action_id.as_ref()?.to_string())
+}
+
+/// Tab to show for a notification response, if the user chose "Show"
+/// (or clicked a notification that has a tab attached).
+///
+/// # Safety
+/// `response` must be a valid UNNotificationResponse.
+unsafe fn tab_to_show(response: *mut AnyObject) -> Option<TabType> {
+    let action_id = action_identifier(response)?;
+    if action_id != SHOW_ACTION_ID && action_id != DEFAULT_ACTION_ID {
+        return None;
+    }
+
+    let notification: *mut AnyObject = msg_send![response, notification];
+    let request: *mut AnyObject = msg_send![notification.as_ref()?, request];
+    let content: *mut AnyObject = msg_send![request.as_ref()?, content];
+    let user_info: *mut AnyObject = msg_send![content.as_ref()?, userInfo];
+    let tab: *mut AnyObject = msg_send![
+        user_info.as_ref()?,
+        objectForKey: &*NSString::from_str(TAB_USER_INFO_KEY)
+    ];
+    let tab_index: isize = msg_send![tab.as_ref()?, integerValue];
+    Some(TabType::from_index(tab_index))
+}
+
+/// Attach the "Show" category and the tab to open to notification content.
+///
+/// # Safety
+/// `content` must be a valid UNMutableNotificationContent.
+unsafe fn set_show_tab(content: &AnyObject, tab: TabType) {
+    let (Some(number_class), Some(dictionary_class)) =
+        (AnyClass::get("NSNumber"), AnyClass::get("NSDictionary"))
+    else {
+        return;
+    };
+
+    let tab_number: *mut AnyObject = msg_send![number_class, numberWithInteger: tab.to_index()];
+    let user_info: *mut AnyObject = msg_send![
+        dictionary_class,
+        dictionaryWithObject: tab_number,
+        forKey: &*NSString::from_str(TAB_USER_INFO_KEY)
+    ];
+    let _: () = msg_send![content, setCategoryIdentifier: &*NSString::from_str(SHOW_CATEGORY_ID)];
+    let _: () = msg_send![content, setUserInfo: user_info];
+}
+
+/// Whether the main bundle has an identifier (required by UNUserNotificationCenter)
+fn has_bundle_identifier() -> bool {
+    let Some(bundle_class) = AnyClass::get("NSBundle") else {
+        return false;
+    };
+    // SAFETY: mainBundle and bundleIdentifier are safe to call; both may return nil
+    unsafe {
+        let bundle: *mut AnyObject = msg_send![bundle_class, mainBundle];
+        if bundle.is_null() {
+            return false;
+        }
+        let identifier: *mut NSString = msg_send![bundle, bundleIdentifier];
+        !identifier.is_null()
+    }
+}
+
+/// Ask the user for permission to show notifications.
+///
+/// macOS only prompts once; later calls return the stored decision.
+pub(super) fn request_authorization() {
+    let Some(center) = notification_center() else {
+        return;
+    };
+
+    let handler = RcBlock::new(|granted: Bool, _error: *mut AnyObject| {
+        if granted.as_bool() {
+            info!("Notification permission granted");
+        } else {
+            warn!("Notification permission denied");
+        }
+    });
+
+    // SAFETY: valid notification center and completion block
+    unsafe {
+        let _: () = msg_send![
+            center,
+            requestAuthorizationWithOptions: AUTHORIZATION_OPTION_ALERT,
+            completionHandler: &*handler
+        ];
+    }
+}
+
+/// Post a notification immediately.
+///
+/// With `show_tab`, the notification gets a "Show" action that opens the
+/// transcription window on that tab. Must be called on the main thread.
+pub(super) fn post(title: &str, body: &str, show_tab: Option<TabType>) {
+    post_with(title, body, |content| {
+        if let Some(tab) = show_tab {
+            // SAFETY: `post_with` passes valid notification content
+            unsafe { set_show_tab(content, tab) };
+        }
+    });
+}
+
+/// Post a reminder with "Keep Recording" and "Stop Recording" actions.
+///
+/// Stopping ends the recording without polishing. Must be called on the
+/// main thread.
+pub(super) fn post_recording_reminder(title: &str, body: &str) {
+    post_with(title, body, |content| {
+        // SAFETY: `post_with` passes valid notification content
+        unsafe {
+            let _: () = msg_send![
+                content,
+                setCategoryIdentifier: &*NSString::from_str(RECORDING_CATEGORY_ID)
+            ];
+        }
+    });
+}
+
+/// Post a notification, letting `configure` add to its content first.
+fn post_with(title: &str, body: &str, configure: impl FnOnce(&AnyObject)) {
+    let Some(center) = notification_center() else {
+        return;
+    };
+    let (Some(content_class), Some(request_class)) = (
+        AnyClass::get("UNMutableNotificationContent"),
+        AnyClass::get("UNNotificationRequest"),
+    ) else {
+        error!("UserNotifications classes not available");
+        return;
+    };
+
+    let identifier = format!(
+        "vissper-{}",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    );
+
+    // SAFETY: messages to valid UserNotifications objects; `new` returns a +1 object
+    unsafe {
+        let content: *mut AnyObject = msg_send![content_class, new];
+        let Some(content) = Retained::from_raw(content) else {
+            error!("Failed to create notification content");
+            return;
+        };
+        let _: () = msg_send![&content, setTitle: &*NSString::from_str(title)];
+        if !body.is_empty() {
+            let _: () = msg_send![&content, setBody: &*NSString::from_str(body)];
+        }
+        configure(&content);
+
+        let no_trigger: *mut AnyObject = std::ptr::null_mut();
+        let request: *mut AnyObject = msg_send![
+            request_class,
+            requestWithIdentifier: &*NSString::from_str(&identifier),
+            content: &*content,
+            trigger: no_trigger
+        ];
+        if request.is_null() {
+            error!("Failed to create notification request");
+            return;
+        }
+
+        let no_handler: *const Block<dyn Fn(*mut AnyObject)> = std::ptr::null();
+        let _: () = msg_send![
+            center,
+            addNotificationRequest: request,
+            withCompletionHandler: no_handler
+        ];
+    }
+
+    info!("Posted notification: {}", title);
+}
@@ -0,0 +1,26 @@
+//! System sound cues
+
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2_foundation::NSString;
+use tracing::warn;
+
+/// Play a named system sound (e.g. "Tink" from /System/Library/Sounds).
+///
+/// Must be called on the main thread.
+pub(super) fn play(name: &str) {
+    let Some(sound_class) = AnyClass::get("NSSound") else {
+        warn!("NSSound not available, skipping sound cue");
+        return;
+    };
+
+    // SAFETY: soundNamed: returns a shared, autoreleased NSSound or nil
+    unsafe {
+        let sound: *mut AnyObject = msg_send![sound_class, soundNamed: &*NSString::from_str(name)];
+        if sound.is_null() {
+            warn!("System sound '{}' not found", name);
+            return;
+        }
+        let _: bool = msg_send![sound, play];
+    }
+}
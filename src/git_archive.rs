@@ -0,0 +1,248 @@
+//! Versioned transcript archive backed by git
+//!
+//! When turned on in the Storage tab of Settings, the transcripts folder is
+//! kept as a git repository and every transcript saved into it is committed,
+//! with details about the session in the commit message. With pushing turned
+//! on, each commit is also pushed to the configured remote using the user's
+//! own git credentials.
+//!
+//! The `git` command-line tool does the work, so it must be installed
+//! (it comes with the Xcode Command Line Tools). Transcripts saved during
+//! private sessions or outside the transcripts folder are never committed.
+
+use chrono::{DateTime, Local};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{error, info, warn};
+
+use crate::error::GitArchiveError;
+use crate::provenance::Provenance;
+use crate::{preferences, private_mode, storage};
+
+/// Remote the configured URL is stored under
+const REMOTE_NAME: &str = "origin";
+
+/// Identity used for commits when git has none configured
+const FALLBACK_NAME: &str = "Vissper";
+const FALLBACK_EMAIL: &str = "vissper@localhost";
+
+/// Details about a saved transcript for its commit message
+pub(crate) struct CommitDetails {
+    pub(crate) saved_at: DateTime<Local>,
+    /// Output mode the transcript was saved from
+    pub(crate) mode: String,
+    pub(crate) words: usize,
+    /// Providers, models and prompt that produced it
+    pub(crate) provenance: Provenance,
+}
+
+/// Commit a saved transcript in the background, then push if set up.
+pub(crate) fn archive_transcript(path: PathBuf, details: CommitDetails) {
+    if !should_archive() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let Some(dir) = archive_dir(&path) else {
+            return;
+        };
+        if let Err(e) = commit(&dir, &path, &details) {
+            error!("Failed to commit transcript to the git archive: {}", e);
+            return;
+        }
+        if preferences::get_git_archive_push() {
+            if let Err(e) = push(&dir) {
+                error!("Failed to push the git archive: {}", e);
+            }
+        }
+    });
+}
+
+/// Commit a transcript saved while quitting.
+///
+/// Doesn't push, so quitting isn't held up by the network; the next
+/// archived transcript pushes this commit too.
+pub(crate) fn archive_transcript_on_quit(path: &Path, details: &CommitDetails) {
+    if !should_archive() {
+        return;
+    }
+    let Some(dir) = archive_dir(path) else {
+        return;
+    };
+    if let Err(e) = commit(&dir, path, details) {
+        error!("Failed to commit transcript to the git archive: {}", e);
+    }
+}
+
+fn should_archive() -> bool {
+    preferences::get_git_archive() && !private_mode::is_active()
+}
+
+/// The transcripts folder, if `path` was saved inside it
+fn archive_dir(path: &Path) -> Option<PathBuf> {
+    let dir = storage::transcripts_dir()?.canonicalize().ok()?;
+    let inside = path.canonicalize().is_ok_and(|path| path.starts_with(&dir));
+    if !inside {
+        info!("Transcript saved outside the transcripts folder, not archived");
+        return None;
+    }
+    Some(dir)
+}
+
+/// Stage and commit one transcript, creating the repository if needed.
+fn commit(dir: &Path, path: &Path, details: &CommitDetails) -> Result<(), GitArchiveError> {
+    if !dir.join(".git").exists() {
+        git(dir, &["init", "--quiet"])?;
+        info!("Created git archive in the transcripts folder");
+    }
+
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    let relative = relative.to_string_lossy();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let message = commit_message(&file_name, details);
+
+    git(dir, &["add", "--", &relative])?;
+
+    let mut args = Vec::new();
+    if git(dir, &["config", "user.email"]).is_err() {
+        warn!(
+            "No git identity configured, committing as {}",
+            FALLBACK_NAME
+        );
+        args.extend([
+            "-c".to_string(),
+            format!("user.name={}", FALLBACK_NAME),
+            "-c".to_string(),
+            format!("user.email={}", FALLBACK_EMAIL),
+        ]);
+    }
+    args.extend(["commit", "--quiet", "-m", message.as_str(), "--"].map(String::from));
+    args.push(relative.into_owned());
+    git(dir, &args.iter().map(String::as_str).collect::<Vec<_>>())?;
+
+    info!("Committed transcript to the git archive");
+    Ok(())
+}
+
+/// Push the archive to the configured remote.
+fn push(dir: &Path) -> Result<(), GitArchiveError> {
+    let Some(url) = preferences::get_git_archive_remote() else {
+        warn!("Git archive push is on but no remote is configured");
+        return Ok(());
+    };
+
+    if git(dir, &["remote", "get-url", REMOTE_NAME]).is_ok() {
+        git(dir, &["remote", "set-url", REMOTE_NAME, &url])?;
+    } else {
+        git(dir, &["remote", "add", REMOTE_NAME, &url])?;
+    }
+    git(
+        dir,
+        &["push", "--quiet", "--set-upstream", REMOTE_NAME, "HEAD"],
+    )?;
+
+    info!("Pushed the git archive");
+    Ok(())
+}
+
+/// Commit message with the file name as subject and session details as body
+fn commit_message(file_name: &str, details: &CommitDetails) -> String {
+    let format = if file_name.contains(".pdf") {
+        "PDF"
+    } else {
+        "Markdown"
+    };
+    let encrypted = if file_name.ends_with(".enc") {
+        "\nEncrypted: yes"
+    } else {
+        ""
+    };
+
+    format!(
+        "Add {}\n\nSaved: {}\nMode: {}\nFormat: {}\nWords: {}{}\n{}",
+        file_name,
+        details.saved_at.format("%Y-%m-%d %H:%M"),
+        details.mode,
+        format,
+        details.words,
+        encrypted,
+        details.provenance.summary()
+    )
+}
+
+/// Run git in the archive folder.
+///
+/// Output isn't logged, since it can include the remote URL and its credentials.
+fn git(dir: &Path, args: &[&str]) -> Result<(), GitArchiveError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        // Fail instead of waiting for a password nobody can type
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(GitArchiveError::CommandFailed {
+            // The subcommand, skipping `-c name=value` options
+            command: args
+                .iter()
+                .find(|arg| !arg.starts_with('-') && !arg.contains('='))
+                .copied()
+                .unwrap_or("git")
+                .to_string(),
+            code: output.status.code(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn details() -> CommitDetails {
+        CommitDetails {
+            saved_at: Local.with_ymd_and_hms(2026, 3, 4, 9, 30, 0).unwrap(),
+            mode: "Meeting Notes".to_string(),
+            words: 1234,
+            provenance: Provenance::default(),
+        }
+    }
+
+    #[test]
+    fn test_commit_message() {
+        assert_eq!(
+            commit_message("transcript-2026-03-04-09-30-00.md", &details()),
+            "Add transcript-2026-03-04-09-30-00.md\n\nSaved: 2026-03-04 09:30\nMode: Meeting Notes\nFormat: Markdown\nWords: 1234\n"
+        );
+    }
+
+    #[test]
+    fn test_commit_message_encrypted_pdf() {
+        let message = commit_message("notes.pdf.enc", &details());
+        assert!(message.starts_with("Add notes.pdf.enc\n\n"));
+        assert!(message.contains("Format: PDF\n"));
+        assert!(message.ends_with("Encrypted: yes\n"));
+    }
+
+    #[test]
+    fn test_commit_message_provenance() {
+        let details = CommitDetails {
+            provenance: Provenance {
+                transcription: vec![crate::provenance::Transcription {
+                    provider: "OpenAI".to_string(),
+                    model: "whisper-1".to_string(),
+                }],
+                polish: None,
+            },
+            ..details()
+        };
+        assert!(commit_message("notes.md", &details)
+            .ends_with("Words: 1234\nTranscribed with: OpenAI (whisper-1)\n"));
+    }
+}
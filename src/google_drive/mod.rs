@@ -0,0 +1,106 @@
+//! Upload saved transcripts to a Google Drive folder
+//!
+//! When turned on in the Export tab of Settings, a transcript saved from the
+//! transcription window is uploaded to the configured folder as well:
+//! Markdown is converted to a Google Doc and PDF stays a PDF. Shared drives
+//! work too, so notes can land in a team drive.
+//!
+//! Sign-in uses an OAuth client the user creates for a desktop app in Google
+//! Cloud. The client and the refresh token are kept in the keychain, and
+//! nothing is uploaded during private sessions.
+
+mod oauth;
+mod upload;
+
+use std::path::Path;
+use tracing::{error, info, warn};
+
+use crate::error::GoogleDriveError;
+use crate::output_modes::ExportFormat;
+use crate::{feedback, keychain, preferences, private_mode};
+
+pub(crate) use oauth::authorize;
+
+/// Folder ID from a pasted ID or folder link.
+///
+/// Folder links look like `https://drive.google.com/drive/folders/<id>`,
+/// optionally followed by a query.
+pub(crate) fn parse_folder_id(input: &str) -> Option<String> {
+    let path = input.trim().split(['?', '#']).next().unwrap_or_default();
+    let id = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    (!id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    .then(|| id.to_string())
+}
+
+/// Upload a transcript that was just saved, if turned on in Settings.
+///
+/// `contents` are the bytes written to `path` before any encryption at rest;
+/// the file name without extension becomes the name in Drive.
+pub(crate) fn upload_saved_transcript(path: &Path, contents: Vec<u8>) {
+    if !preferences::get_google_drive_upload() || private_mode::is_active() {
+        return;
+    }
+
+    let is_pdf = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("pdf"));
+    let format = if is_pdf {
+        ExportFormat::Pdf
+    } else {
+        ExportFormat::Markdown
+    };
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "transcript".to_string());
+
+    tokio::spawn(async move {
+        let creds = match keychain::get_google_drive_credentials() {
+            Ok(creds) => creds,
+            Err(e) => {
+                warn!(
+                    "Google Drive upload is on but no connection is saved: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        match upload::upload_file(&creds, &name, &contents, format).await {
+            Ok(()) => info!(?format, "Uploaded transcript to Google Drive"),
+            Err(e) => {
+                error!("Failed to upload transcript to Google Drive: {}", e);
+                feedback::notify_now(
+                    "Google Drive upload failed",
+                    "The transcript was saved but not uploaded. Check the connection in the Export tab of Settings.",
+                );
+            }
+        }
+    });
+}
+
+/// Send a request and parse the JSON response.
+async fn send<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, GoogleDriveError> {
+    let response = request.send().await?;
+
+    if !response.status().is_success() {
+        return Err(GoogleDriveError::ServerError {
+            status: response.status().as_u16(),
+            message: response.text().await.unwrap_or_default(),
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| GoogleDriveError::InvalidResponse(format!("Failed to parse response: {}", e)))
+}
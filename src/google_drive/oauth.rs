@@ -0,0 +1,210 @@
+//! Google sign-in with the loopback redirect used by desktop apps
+//!
+//! The browser is sent to Google's consent page and redirected back to a
+//! one-off listener on 127.0.0.1, which receives the authorization code.
+
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::info;
+use url::Url;
+
+use super::send;
+use crate::error::GoogleDriveError;
+use crate::keychain::GoogleDriveCredentials;
+
+const AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Full Drive access, needed to add files to a folder Vissper didn't create
+const DRIVE_SCOPE: &str = "https://www.googleapis.com/auth/drive";
+
+/// How long to wait for the user to finish signing in
+const SIGN_IN_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Timeout for each request to Google
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Largest redirect request read from the browser
+const MAX_REQUEST_BYTES: usize = 8192;
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// Only returned when the user gives consent
+    refresh_token: Option<String>,
+}
+
+/// Sign in with Google in the browser and return a refresh token.
+pub(crate) async fn authorize(
+    client_id: &str,
+    client_secret: &str,
+) -> Result<String, GoogleDriveError> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+    let redirect_uri = format!("http://127.0.0.1:{}", listener.local_addr()?.port());
+    let state = random_string(32);
+    // PKCE verifier, sent as the challenge itself ("plain" method)
+    let verifier = random_string(64);
+
+    let auth_url = Url::parse_with_params(
+        AUTH_URL,
+        &[
+            ("client_id", client_id),
+            ("redirect_uri", &redirect_uri),
+            ("response_type", "code"),
+            ("scope", DRIVE_SCOPE),
+            ("access_type", "offline"),
+            // Ask for consent every time so a refresh token is always returned
+            ("prompt", "consent"),
+            ("state", &state),
+            ("code_challenge", &verifier),
+            ("code_challenge_method", "plain"),
+        ],
+    )
+    .map_err(|e| GoogleDriveError::SignIn(e.to_string()))?;
+
+    open::that(auth_url.as_str())
+        .map_err(|e| GoogleDriveError::SignIn(format!("Could not open the browser: {}", e)))?;
+    info!("Waiting for Google sign-in in the browser");
+
+    let code = tokio::time::timeout(SIGN_IN_TIMEOUT, receive_code(&listener, &state))
+        .await
+        .map_err(|_| GoogleDriveError::SignIn("Timed out waiting for the browser".to_string()))??;
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    let response: TokenResponse = send(client.post(TOKEN_URL).form(&[
+        ("code", code.as_str()),
+        ("client_id", client_id),
+        ("client_secret", client_secret),
+        ("redirect_uri", &redirect_uri),
+        ("grant_type", "authorization_code"),
+        ("code_verifier", &verifier),
+    ]))
+    .await?;
+
+    response
+        .refresh_token
+        .ok_or_else(|| GoogleDriveError::InvalidResponse("No refresh token returned".to_string()))
+}
+
+/// Exchange the stored refresh token for a short-lived access token.
+pub(super) async fn access_token(
+    client: &reqwest::Client,
+    creds: &GoogleDriveCredentials,
+) -> Result<String, GoogleDriveError> {
+    let response: TokenResponse = send(client.post(TOKEN_URL).form(&[
+        ("client_id", creds.client_id.as_str()),
+        ("client_secret", &creds.client_secret),
+        ("refresh_token", &creds.refresh_token),
+        ("grant_type", "refresh_token"),
+    ]))
+    .await?;
+
+    Ok(response.access_token)
+}
+
+/// Wait for the browser's redirect and return the authorization code.
+async fn receive_code(listener: &TcpListener, state: &str) -> Result<String, GoogleDriveError> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let mut buffer = vec![0u8; MAX_REQUEST_BYTES];
+        let read = stream.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+
+        // Browsers may also ask for a favicon; only the redirect has a query
+        let Some(result) = code_from_redirect(&request, state) else {
+            let _ = stream
+                .write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await;
+            continue;
+        };
+
+        let body = if result.is_ok() {
+            "Vissper is connected to Google Drive. You can close this tab."
+        } else {
+            "Google Drive sign-in failed. Close this tab and try again in Vissper's settings."
+        };
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        return result;
+    }
+}
+
+/// Authorization code from the redirect request, or `None` for other requests.
+fn code_from_redirect(request: &str, state: &str) -> Option<Result<String, GoogleDriveError>> {
+    let target = request
+        .lines()
+        .next()?
+        .strip_prefix("GET ")?
+        .split(' ')
+        .next()?;
+    let url = Url::parse(&format!("http://127.0.0.1{}", target)).ok()?;
+    url.query()?;
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+    if params.get("state").map(String::as_str) != Some(state) {
+        return Some(Err(GoogleDriveError::SignIn(
+            "The sign-in response didn't match the request".to_string(),
+        )));
+    }
+    if let Some(error) = params.get("error") {
+        return Some(Err(GoogleDriveError::SignIn(error.clone())));
+    }
+    Some(
+        params
+            .get("code")
+            .cloned()
+            .ok_or_else(|| GoogleDriveError::SignIn("No authorization code returned".to_string())),
+    )
+}
+
+fn random_string(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_from_redirect() {
+        let request =
+            "GET /?state=abc&code=4%2F0Ab&scope=drive HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n";
+        assert_eq!(
+            code_from_redirect(request, "abc").unwrap().unwrap(),
+            "4/0Ab"
+        );
+    }
+
+    #[test]
+    fn test_code_from_redirect_rejects_other_state_and_errors() {
+        let request = "GET /?state=other&code=x HTTP/1.1\r\n\r\n";
+        assert!(code_from_redirect(request, "abc").unwrap().is_err());
+
+        let request = "GET /?state=abc&error=access_denied HTTP/1.1\r\n\r\n";
+        assert!(code_from_redirect(request, "abc").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_code_from_redirect_ignores_other_requests() {
+        let request = "GET /favicon.ico HTTP/1.1\r\n\r\n";
+        assert!(code_from_redirect(request, "abc").is_none());
+    }
+}
@@ -0,0 +1,81 @@
+//! Drive API upload of one transcript file
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use super::{oauth, send};
+use crate::error::GoogleDriveError;
+use crate::keychain::GoogleDriveCredentials;
+use crate::output_modes::ExportFormat;
+
+const UPLOAD_URL: &str = "https://www.googleapis.com/upload/drive/v3/files";
+
+/// Drive converts uploads with this target type to a Google Doc
+const GOOGLE_DOC_MIME: &str = "application/vnd.google-apps.document";
+
+/// Timeout for each request to Google, allowing for large PDFs
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Upload a transcript to the configured folder.
+///
+/// Markdown is converted to a Google Doc named `name`; a PDF is kept as is.
+pub(super) async fn upload_file(
+    creds: &GoogleDriveCredentials,
+    name: &str,
+    contents: &[u8],
+    format: ExportFormat,
+) -> Result<(), GoogleDriveError> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    let access_token = oauth::access_token(&client, creds).await?;
+
+    let (metadata, media_type) = match format {
+        ExportFormat::Markdown => (
+            json!({
+                "name": name,
+                "parents": [creds.folder_id],
+                "mimeType": GOOGLE_DOC_MIME,
+            }),
+            "text/markdown",
+        ),
+        ExportFormat::Pdf => (
+            json!({
+                "name": format!("{}.pdf", name),
+                "parents": [creds.folder_id],
+            }),
+            "application/pdf",
+        ),
+    };
+
+    let boundary = format!("vissper-{:016x}", rand::random::<u64>());
+    let body = multipart_body(&boundary, &metadata, media_type, contents);
+
+    let _: Value = send(
+        client
+            .post(UPLOAD_URL)
+            .query(&[("uploadType", "multipart"), ("supportsAllDrives", "true")])
+            .bearer_auth(access_token)
+            .header(
+                CONTENT_TYPE,
+                format!("multipart/related; boundary={}", boundary),
+            )
+            .body(body),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// `multipart/related` body with the file's metadata followed by its contents
+fn multipart_body(boundary: &str, metadata: &Value, media_type: &str, contents: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{0}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{1}\r\n--{0}\r\nContent-Type: {2}\r\n\r\n",
+        boundary, metadata, media_type
+    )
+    .into_bytes();
+    body.extend_from_slice(contents);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
@@ -0,0 +1,113 @@
+//! Hotkey cycling through the favorite transcription languages
+//!
+//! Unlike the other hotkeys it can be changed in Settings, picked from a few
+//! presets or turned off, since any fixed combination is bound to clash with
+//! some other app. A change is registered right away.
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use global_hotkey::GlobalHotKeyManager;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, PoisonError};
+use tracing::{info, warn};
+
+/// The registered language hotkey, None while it is off
+static REGISTERED: Mutex<Option<HotKey>> = Mutex::new(None);
+
+/// Key combinations offered for cycling the transcription language
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LanguageHotkey {
+    Off,
+    /// Control + Shift + L
+    #[default]
+    ControlShiftL,
+    /// Control + Option + L
+    ControlOptionL,
+    /// Control + Option + Space
+    ControlOptionSpace,
+}
+
+impl LanguageHotkey {
+    /// Presets in the order Settings lists them
+    pub(crate) const ALL: [LanguageHotkey; 4] = [
+        LanguageHotkey::Off,
+        LanguageHotkey::ControlShiftL,
+        LanguageHotkey::ControlOptionL,
+        LanguageHotkey::ControlOptionSpace,
+    ];
+
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|preset| *preset == self)
+            .unwrap_or_default() as isize
+    }
+
+    /// Short label for Settings, in macOS modifier symbols
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            LanguageHotkey::Off => "Off",
+            LanguageHotkey::ControlShiftL => "⌃⇧L",
+            LanguageHotkey::ControlOptionL => "⌃⌥L",
+            LanguageHotkey::ControlOptionSpace => "⌃⌥Space",
+        }
+    }
+
+    fn hotkey(self) -> Option<HotKey> {
+        let (modifiers, code) = match self {
+            LanguageHotkey::Off => return None,
+            LanguageHotkey::ControlShiftL => (Modifiers::CONTROL | Modifiers::SHIFT, Code::KeyL),
+            LanguageHotkey::ControlOptionL => (Modifiers::CONTROL | Modifiers::ALT, Code::KeyL),
+            LanguageHotkey::ControlOptionSpace => {
+                (Modifiers::CONTROL | Modifiers::ALT, Code::Space)
+            }
+        };
+        Some(HotKey::new(Some(modifiers), code))
+    }
+}
+
+/// Whether `id` is the language hotkey's
+pub(super) fn is_language_hotkey(id: u32) -> bool {
+    registered().is_some_and(|hotkey| hotkey.id() == id)
+}
+
+/// The registered language hotkey, if any
+pub(super) fn registered() -> Option<HotKey> {
+    *REGISTERED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register `preset` in place of the language hotkey registered before
+pub(super) fn register(manager: &GlobalHotKeyManager, preset: LanguageHotkey) {
+    let mut registered = REGISTERED.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(hotkey) = registered.take() {
+        if let Err(e) = manager.unregister(hotkey) {
+            warn!("Failed to unregister language hotkey: {}", e);
+        }
+    }
+
+    let Some(hotkey) = preset.hotkey() else {
+        info!("Language hotkey turned off");
+        return;
+    };
+    match manager.register(hotkey) {
+        Ok(()) => {
+            *registered = Some(hotkey);
+            info!(
+                "Registered global hotkey: {} (cycle language)",
+                preset.label()
+            );
+        }
+        // Another app may own the combination; the other hotkeys still work
+        Err(e) => warn!(
+            "Failed to register language hotkey {}: {}",
+            preset.label(),
+            e
+        ),
+    }
+}
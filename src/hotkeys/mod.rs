@@ -0,0 +1,395 @@
+//! Global hotkey management
+//!
+//! Provides global keyboard shortcuts for quick actions.
+//! Hotkeys work even when the app is in the background.
+
+mod language;
+mod stop;
+
+pub(crate) use language::LanguageHotkey;
+pub(crate) use stop::{StopHotkey, StopHotkeys};
+
+use global_hotkey::{
+    hotkey::{Code, HotKey, Modifiers},
+    GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
+};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::preferences;
+use crate::transcription_window::TabType;
+
+/// Set once the hotkeys are unregistered so the listener thread exits
+static LISTENER_STOPPED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    // Hotkeys are registered and unregistered on the main thread that
+    // created the manager
+    static MANAGER: RefCell<Option<GlobalHotKeyManager>> = const { RefCell::new(None) };
+}
+
+/// Initialize global hotkeys for the application
+///
+/// Currently registered hotkeys:
+/// - Control + Space: Start recording / Stop with no polishing
+/// - Control + Shift + 1: Start recording / Stop with basic polishing
+/// - Control + Shift + 2: Stop with meeting notes
+/// - Control + Shift + 0: Take screenshot (only during recording)
+/// - Control + Shift + 9: Region screenshot (select area with mouse)
+/// - Control + Shift + 8: Capture the last selected region again
+/// - Control + Shift + M: Insert a timestamped marker (only during recording)
+/// - Control + Shift + N: Type a note into the transcript (only during recording)
+/// - Control + Shift + P: Polish the selected text or clipboard (quick polish)
+/// - Control + Shift + R: Connect ahead of the next recording (prepare)
+/// - Control + Shift + L, or as set in Settings: Cycle the favorite languages
+/// - Control + Option + 1, 2 and 3, or as set in Settings: Stop with no polishing,
+///   basic polishing and meeting notes
+pub(crate) fn init_hotkeys() -> Result<(), String> {
+    let manager = GlobalHotKeyManager::new()
+        .map_err(|e| format!("Failed to create hotkey manager: {}", e))?;
+
+    // Control + Space: Start recording / Stop with no polishing
+    let no_polish_hotkey = HotKey::new(Some(Modifiers::CONTROL), Code::Space);
+
+    manager
+        .register(no_polish_hotkey)
+        .map_err(|e| format!("Failed to register no polish hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Space (no polishing)");
+
+    // Control + Shift + 1: Start recording / Stop with basic polishing
+    let basic_polish_hotkey =
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit1);
+
+    manager
+        .register(basic_polish_hotkey)
+        .map_err(|e| format!("Failed to register basic polish hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + 1 (basic polishing)");
+
+    // Control + Shift + 2: Stop with meeting notes
+    let meeting_notes_hotkey =
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit2);
+
+    manager
+        .register(meeting_notes_hotkey)
+        .map_err(|e| format!("Failed to register meeting notes hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + 2 (meeting notes)");
+
+    // Control + Shift + 0: Take screenshot
+    let screenshot_hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit0);
+
+    manager
+        .register(screenshot_hotkey)
+        .map_err(|e| format!("Failed to register screenshot hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + 0 (screenshot)");
+
+    // Control + Shift + 9: Region screenshot (select area with mouse)
+    let region_screenshot_hotkey =
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit9);
+
+    manager
+        .register(region_screenshot_hotkey)
+        .map_err(|e| format!("Failed to register region screenshot hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + 9 (region screenshot)");
+
+    // Control + Shift + 8: Capture the last selected region again
+    let last_region_screenshot_hotkey =
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit8);
+
+    manager
+        .register(last_region_screenshot_hotkey)
+        .map_err(|e| format!("Failed to register last region screenshot hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + 8 (last region screenshot)");
+
+    // Control + Shift + M: Insert marker
+    let marker_hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyM);
+
+    manager
+        .register(marker_hotkey)
+        .map_err(|e| format!("Failed to register marker hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + M (marker)");
+
+    // Control + Shift + N: Type a note
+    let note_hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyN);
+
+    manager
+        .register(note_hotkey)
+        .map_err(|e| format!("Failed to register note hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + N (note)");
+
+    // Control + Shift + P: Quick polish
+    let quick_polish_hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyP);
+
+    manager
+        .register(quick_polish_hotkey)
+        .map_err(|e| format!("Failed to register quick polish hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + P (quick polish)");
+
+    // Control + Shift + R: Prepare the connection for the next recording
+    let prepare_hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyR);
+
+    manager
+        .register(prepare_hotkey)
+        .map_err(|e| format!("Failed to register prepare hotkey: {}", e))?;
+
+    info!("Registered global hotkey: Control + Shift + R (prepare)");
+
+    language::register(&manager, preferences::get_language_hotkey());
+    stop::register(&manager, preferences::get_stop_hotkeys());
+
+    MANAGER.with(|slot| *slot.borrow_mut() = Some(manager));
+    Ok(())
+}
+
+/// Register `preset` as the language hotkey in place of the previous one
+///
+/// Must be called on the main thread.
+pub(crate) fn set_language_hotkey(preset: LanguageHotkey) {
+    MANAGER.with(|slot| match slot.borrow().as_ref() {
+        Some(manager) => language::register(manager, preset),
+        None => warn!("Hotkeys are not initialized on this thread"),
+    });
+}
+
+/// Register `hotkeys` as the stop hotkeys in place of the previous ones
+///
+/// Must be called on the main thread.
+pub(crate) fn set_stop_hotkeys(hotkeys: StopHotkeys) {
+    MANAGER.with(|slot| match slot.borrow().as_ref() {
+        Some(manager) => stop::register(manager, hotkeys),
+        None => warn!("Hotkeys are not initialized on this thread"),
+    });
+}
+
+/// Unregister every global hotkey and stop the listener thread
+///
+/// Called on shutdown once the event loop has stopped.
+pub(crate) fn unregister_hotkeys() {
+    LISTENER_STOPPED.store(true, Ordering::SeqCst);
+
+    let Some(manager) = MANAGER.with(|slot| slot.borrow_mut().take()) else {
+        return;
+    };
+    let mut hotkeys = vec![
+        HotKey::new(Some(Modifiers::CONTROL), Code::Space),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit1),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit2),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit0),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit9),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit8),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyM),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyN),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyP),
+        HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyR),
+    ];
+    hotkeys.extend(language::registered());
+    hotkeys.extend(stop::registered());
+    match manager.unregister_all(&hotkeys) {
+        Ok(()) => info!("Global hotkeys unregistered"),
+        Err(e) => warn!("Failed to unregister global hotkeys: {}", e),
+    }
+}
+
+/// Get the hotkey ID for no polishing (Control + Space)
+fn no_polish_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL), Code::Space);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for basic polishing (Control + Shift + 1)
+fn basic_polish_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit1);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for meeting notes (Control + Shift + 2)
+fn meeting_notes_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit2);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for screenshot (Control + Shift + 0)
+fn screenshot_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit0);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for region screenshot (Control + Shift + 9)
+fn region_screenshot_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit9);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for last region screenshot (Control + Shift + 8)
+fn last_region_screenshot_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Digit8);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for marker insertion (Control + Shift + M)
+fn marker_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyM);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for note insertion (Control + Shift + N)
+fn note_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyN);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for quick polish (Control + Shift + P)
+fn quick_polish_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyP);
+    hotkey.id()
+}
+
+/// Get the hotkey ID for preparing the connection (Control + Shift + R)
+fn prepare_hotkey_id() -> u32 {
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyR);
+    hotkey.id()
+}
+
+/// Start listening for hotkey events
+///
+/// This spawns a background thread (not tokio task) that polls for hotkey events
+/// and dispatches callbacks to the main thread via dispatch queue.
+///
+/// # Arguments
+/// * `on_no_polish` - Callback for Control + Space (no polishing)
+/// * `on_basic_polish` - Callback for Control + Shift + 1 (basic polishing)
+/// * `on_meeting_notes` - Callback for Control + Shift + 2 (meeting notes)
+/// * `on_screenshot` - Callback for Control + Shift + 0 (screenshot during recording)
+/// * `on_region_screenshot` - Callback for Control + Shift + 9 (region screenshot)
+/// * `on_last_region_screenshot` - Callback for Control + Shift + 8 (last region again)
+/// * `on_marker` - Callback for Control + Shift + M (marker during recording)
+/// * `on_note` - Callback for Control + Shift + N (note during recording)
+/// * `on_quick_polish` - Callback for Control + Shift + P (quick polish)
+/// * `on_prepare` - Callback for Control + Shift + R (prepare the connection)
+/// * `on_cycle_language` - Callback for the language hotkey set in Settings
+/// * `on_stop_with_mode` - Callback for the stop hotkeys set in Settings (takes the tab
+///   whose output mode to stop with)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn start_hotkey_listener(
+    on_no_polish: Arc<dyn Fn() + Send + Sync>,
+    on_basic_polish: Arc<dyn Fn() + Send + Sync>,
+    on_meeting_notes: Arc<dyn Fn() + Send + Sync>,
+    on_screenshot: Arc<dyn Fn() + Send + Sync>,
+    on_region_screenshot: Arc<dyn Fn() + Send + Sync>,
+    on_last_region_screenshot: Arc<dyn Fn() + Send + Sync>,
+    on_marker: Arc<dyn Fn() + Send + Sync>,
+    on_note: Arc<dyn Fn() + Send + Sync>,
+    on_quick_polish: Arc<dyn Fn() + Send + Sync>,
+    on_prepare: Arc<dyn Fn() + Send + Sync>,
+    on_cycle_language: Arc<dyn Fn() + Send + Sync>,
+    on_stop_with_mode: Arc<dyn Fn(TabType) + Send + Sync>,
+) {
+    let no_polish_id = no_polish_hotkey_id();
+    let basic_polish_id = basic_polish_hotkey_id();
+    let meeting_notes_id = meeting_notes_hotkey_id();
+    let screenshot_id = screenshot_hotkey_id();
+    let region_screenshot_id = region_screenshot_hotkey_id();
+    let last_region_screenshot_id = last_region_screenshot_hotkey_id();
+    let marker_id = marker_hotkey_id();
+    let note_id = note_hotkey_id();
+    let quick_polish_id = quick_polish_hotkey_id();
+    let prepare_id = prepare_hotkey_id();
+
+    std::thread::spawn(move || {
+        let receiver = GlobalHotKeyEvent::receiver();
+
+        info!("Hotkey listener started on dedicated thread");
+
+        while !LISTENER_STOPPED.load(Ordering::SeqCst) {
+            // Use try_recv with sleep to avoid blocking issues
+            match receiver.try_recv() {
+                Ok(event) => {
+                    info!("Hotkey event received: {:?}", event);
+
+                    // Only handle key press, ignore key release
+                    if event.state != HotKeyState::Pressed {
+                        continue;
+                    }
+
+                    // Determine which hotkey was pressed and dispatch appropriate callback
+                    if event.id == no_polish_id {
+                        let callback = on_no_polish.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == basic_polish_id {
+                        let callback = on_basic_polish.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == meeting_notes_id {
+                        let callback = on_meeting_notes.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == screenshot_id {
+                        let callback = on_screenshot.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == region_screenshot_id {
+                        let callback = on_region_screenshot.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == last_region_screenshot_id {
+                        let callback = on_last_region_screenshot.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == marker_id {
+                        let callback = on_marker.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == note_id {
+                        let callback = on_note.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == quick_polish_id {
+                        let callback = on_quick_polish.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if event.id == prepare_id {
+                        let callback = on_prepare.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if language::is_language_hotkey(event.id) {
+                        let callback = on_cycle_language.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)();
+                        });
+                    } else if let Some(tab) = stop::stop_mode(event.id) {
+                        let callback = on_stop_with_mode.clone();
+                        dispatch::Queue::main().exec_async(move || {
+                            (callback)(tab);
+                        });
+                    }
+                }
+                Err(_) => {
+                    // No event, sleep briefly to avoid busy-waiting
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+            }
+        }
+    });
+}
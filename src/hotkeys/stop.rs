@@ -0,0 +1,215 @@
+//! Hotkeys stopping the recording with a chosen output mode
+//!
+//! Control + Space and Control + Shift + 1 toggle: pressed just after a
+//! recording stopped by other means, they start a new one. These only ever
+//! stop, each with its own mode, so they are safe to press at any time. Like
+//! the language hotkey, each is picked from a few presets in Settings or
+//! turned off, and a change is registered right away.
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use global_hotkey::GlobalHotKeyManager;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, PoisonError};
+use tracing::{info, warn};
+
+use crate::transcription_window::TabType;
+
+/// NSEventModifierFlagControl | NSEventModifierFlagOption, for menu key equivalents
+const CONTROL_OPTION_MASK: u64 = 262144 | 524288;
+
+/// The registered stop hotkeys with the tab each stops with
+static REGISTERED: Mutex<Vec<(HotKey, TabType)>> = Mutex::new(Vec::new());
+
+/// Key combinations offered for stopping with a mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StopHotkey {
+    Off,
+    /// Control + Option + 1
+    ControlOption1,
+    /// Control + Option + 2
+    ControlOption2,
+    /// Control + Option + 3
+    ControlOption3,
+    /// Control + Option + 4
+    ControlOption4,
+}
+
+impl StopHotkey {
+    /// Presets in the order Settings lists them
+    pub(crate) const ALL: [StopHotkey; 5] = [
+        StopHotkey::Off,
+        StopHotkey::ControlOption1,
+        StopHotkey::ControlOption2,
+        StopHotkey::ControlOption3,
+        StopHotkey::ControlOption4,
+    ];
+
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|preset| *preset == self)
+            .unwrap_or_default() as isize
+    }
+
+    /// Short label for Settings, in macOS modifier symbols
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            StopHotkey::Off => "Off",
+            StopHotkey::ControlOption1 => "⌃⌥1",
+            StopHotkey::ControlOption2 => "⌃⌥2",
+            StopHotkey::ControlOption3 => "⌃⌥3",
+            StopHotkey::ControlOption4 => "⌃⌥4",
+        }
+    }
+
+    /// Key and modifier mask for showing the hotkey on a menu item
+    pub(crate) fn key_equivalent(self) -> Option<(&'static str, u64)> {
+        let key = match self {
+            StopHotkey::Off => return None,
+            StopHotkey::ControlOption1 => "1",
+            StopHotkey::ControlOption2 => "2",
+            StopHotkey::ControlOption3 => "3",
+            StopHotkey::ControlOption4 => "4",
+        };
+        Some((key, CONTROL_OPTION_MASK))
+    }
+
+    fn hotkey(self) -> Option<HotKey> {
+        let code = match self {
+            StopHotkey::Off => return None,
+            StopHotkey::ControlOption1 => Code::Digit1,
+            StopHotkey::ControlOption2 => Code::Digit2,
+            StopHotkey::ControlOption3 => Code::Digit3,
+            StopHotkey::ControlOption4 => Code::Digit4,
+        };
+        Some(HotKey::new(Some(Modifiers::CONTROL | Modifiers::ALT), code))
+    }
+}
+
+/// The stop hotkey of each stop mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct StopHotkeys {
+    pub(crate) no_polish: StopHotkey,
+    pub(crate) basic_polish: StopHotkey,
+    pub(crate) meeting_notes: StopHotkey,
+}
+
+impl Default for StopHotkeys {
+    fn default() -> Self {
+        Self {
+            no_polish: StopHotkey::ControlOption1,
+            basic_polish: StopHotkey::ControlOption2,
+            meeting_notes: StopHotkey::ControlOption3,
+        }
+    }
+}
+
+impl StopHotkeys {
+    /// Stop modes with a hotkey, by the tab they stop with, in the order
+    /// Settings lists them
+    pub(crate) const MODES: [TabType; 3] =
+        [TabType::Live, TabType::BasicPolish, TabType::MeetingNotes];
+
+    /// The hotkey stopping with `tab`'s mode (Off for modes without one)
+    pub(crate) fn get(&self, tab: TabType) -> StopHotkey {
+        match tab {
+            TabType::Live => self.no_polish,
+            TabType::BasicPolish => self.basic_polish,
+            TabType::MeetingNotes => self.meeting_notes,
+            _ => StopHotkey::Off,
+        }
+    }
+
+    /// Set the hotkey stopping with `tab`'s mode
+    pub(crate) fn set(&mut self, tab: TabType, preset: StopHotkey) {
+        match tab {
+            TabType::Live => self.no_polish = preset,
+            TabType::BasicPolish => self.basic_polish = preset,
+            TabType::MeetingNotes => self.meeting_notes = preset,
+            _ => warn!(?tab, "No stop hotkey for this mode"),
+        }
+    }
+}
+
+/// The tab to stop with if `id` is a stop hotkey's
+pub(super) fn stop_mode(id: u32) -> Option<TabType> {
+    REGISTERED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|(hotkey, _)| hotkey.id() == id)
+        .map(|(_, tab)| *tab)
+}
+
+/// The registered stop hotkeys
+pub(super) fn registered() -> Vec<HotKey> {
+    REGISTERED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(hotkey, _)| *hotkey)
+        .collect()
+}
+
+/// Register `hotkeys` in place of the stop hotkeys registered before
+pub(super) fn register(manager: &GlobalHotKeyManager, hotkeys: StopHotkeys) {
+    let mut registered = REGISTERED.lock().unwrap_or_else(PoisonError::into_inner);
+    for (hotkey, _) in registered.drain(..) {
+        if let Err(e) = manager.unregister(hotkey) {
+            warn!("Failed to unregister stop hotkey: {}", e);
+        }
+    }
+
+    for tab in StopHotkeys::MODES {
+        let preset = hotkeys.get(tab);
+        let Some(hotkey) = preset.hotkey() else {
+            continue;
+        };
+        if registered.iter().any(|(taken, _)| *taken == hotkey) {
+            warn!(
+                ?tab,
+                "Stop hotkey {} is already used by another mode",
+                preset.label()
+            );
+            continue;
+        }
+        match manager.register(hotkey) {
+            Ok(()) => {
+                registered.push((hotkey, tab));
+                info!(?tab, "Registered global hotkey: {} (stop)", preset.label());
+            }
+            // Another app may own the combination; the other hotkeys still work
+            Err(e) => warn!(
+                ?tab,
+                "Failed to register stop hotkey {}: {}",
+                preset.label(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_hotkeys_fill_in_missing_modes() {
+        let hotkeys: StopHotkeys = serde_json::from_str(r#"{"basic_polish": "off"}"#).unwrap();
+        assert_eq!(hotkeys.get(TabType::Live), StopHotkey::ControlOption1);
+        assert_eq!(hotkeys.get(TabType::BasicPolish), StopHotkey::Off);
+        assert_eq!(
+            hotkeys.get(TabType::MeetingNotes),
+            StopHotkey::ControlOption3
+        );
+        assert_eq!(hotkeys.get(TabType::FollowUpEmail), StopHotkey::Off);
+    }
+}
@@ -0,0 +1,106 @@
+//! Warning when the microphone drops to call quality
+//!
+//! A Bluetooth headset switching to its hands-free profile mid-recording
+//! lowers the input to 8, 16 or 24 kHz and transcription gets noticeably
+//! worse. When the capture reports such a drop while recording, an alert
+//! says so and offers to continue on the best other microphone, or to keep
+//! recording with the headset.
+
+use objc2_app_kit::{NSAlert, NSAlertStyle};
+use objc2_foundation::{MainThreadMarker, NSString};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::info;
+
+use crate::audio::{self, CALL_QUALITY_RATE};
+use crate::event_bus::AppEvent;
+
+/// NSAlertFirstButtonReturn
+const ALERT_FIRST_BUTTON: isize = 1000;
+
+/// Whether a recording is in progress; microphone tests aren't warned about
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Whether the warning is showing, so repeated drops don't stack alerts
+static SHOWING: AtomicBool = AtomicBool::new(false);
+
+/// Warn about a drop to call quality during a recording.
+pub(crate) fn handle_event(event: &AppEvent) {
+    match event {
+        AppEvent::RecordingStarted => RECORDING.store(true, Ordering::SeqCst),
+        AppEvent::RecordingStopped => RECORDING.store(false, Ordering::SeqCst),
+        AppEvent::InputRateChanged { from, to } => {
+            if RECORDING.load(Ordering::SeqCst) && is_call_quality_drop(*from, *to) {
+                warn_user(*from, *to);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether going from `from` to `to` Hz is a drop to call quality
+fn is_call_quality_drop(from: u32, to: u32) -> bool {
+    to < from && to <= CALL_QUALITY_RATE
+}
+
+/// Show the warning on the main thread and act on the choice.
+fn warn_user(from: u32, to: u32) {
+    if SHOWING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let alternative = audio::alternative_input_device();
+
+    dispatch::Queue::main().exec_async(move || {
+        if let Some(mtm) = MainThreadMarker::new() {
+            let switch = run_alert(mtm, from, to, alternative.as_deref());
+            match alternative {
+                Some(name) if switch => {
+                    info!("Switching microphone after a drop to call quality");
+                    audio::switch_input_device(name);
+                }
+                _ => info!("Continuing with the call quality microphone"),
+            }
+        }
+        SHOWING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Run the modal warning, returning whether the user chose to switch.
+fn run_alert(mtm: MainThreadMarker, from: u32, to: u32, alternative: Option<&str>) -> bool {
+    let switch_advice = match alternative {
+        Some(name) => format!(
+            "Switch to {} to keep recording in full quality, or continue with the current microphone.",
+            name
+        ),
+        None => "Connect another microphone, or make the headset use only its \
+                 speakers, for better results."
+            .to_string(),
+    };
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Warning);
+        alert.setMessageText(&NSString::from_str("Microphone switched to call quality"));
+        alert.setInformativeText(&NSString::from_str(&format!(
+            "The microphone dropped from {} to {}, which usually means a Bluetooth \
+             headset switched to its hands-free profile. The transcript will be less \
+             accurate.\n\n{}",
+            format_khz(from),
+            format_khz(to),
+            switch_advice
+        )));
+        if let Some(name) = alternative {
+            alert.addButtonWithTitle(&NSString::from_str(&format!("Switch to {}", name)));
+        }
+        alert.addButtonWithTitle(&NSString::from_str("Continue"));
+        alert.runModal()
+    };
+
+    alternative.is_some() && response == ALERT_FIRST_BUTTON
+}
+
+/// A sample rate in kHz, e.g. "16 kHz" or "22.05 kHz"
+fn format_khz(rate: u32) -> String {
+    let khz = format!("{:.2}", f64::from(rate) / 1000.0);
+    format!("{} kHz", khz.trim_end_matches('0').trim_end_matches('.'))
+}
@@ -0,0 +1,111 @@
+//! Action item and attendee extraction from generated meeting notes
+
+/// Heading of the action items section in the meeting notes prompt
+const ACTION_ITEMS_HEADING: &str = "action items";
+
+/// Placeholder the prompt asks for when a section is empty
+const NONE_IDENTIFIED: &str = "none identified";
+
+/// Collect the top-level bullets under every "## Action Items" heading.
+pub(crate) fn parse_action_items(notes: &str) -> Vec<String> {
+    parse_section_items(notes, ACTION_ITEMS_HEADING)
+}
+
+/// Collect the top-level bullets under every `## <heading>` section.
+///
+/// Notes polished in parts have one section per part, so all of them are
+/// read. Checkbox markers and bold markup are removed; nested bullets are
+/// details of the item above and are left out.
+pub(crate) fn parse_section_items(notes: &str, heading: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut in_section = false;
+
+    for line in notes.lines() {
+        if let Some(title) = section_heading(line) {
+            in_section = title.eq_ignore_ascii_case(heading);
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if let Some(item) = bullet_text(line) {
+            if !item.is_empty() && !item.eq_ignore_ascii_case(NONE_IDENTIFIED) {
+                items.push(item);
+            }
+        }
+    }
+
+    items
+}
+
+/// Title of a `#` or `##` heading; deeper headings stay inside the section
+fn section_heading(line: &str) -> Option<&str> {
+    line.strip_prefix("## ")
+        .or_else(|| line.strip_prefix("# "))
+        .map(|title| title.trim().trim_end_matches(':'))
+}
+
+/// Text of a top-level bullet or numbered item
+fn bullet_text(line: &str) -> Option<String> {
+    let rest = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+        .or_else(|| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            (digits > 0)
+                .then(|| line[digits..].strip_prefix(". "))
+                .flatten()
+        })?;
+
+    let rest = rest.trim();
+    let rest = ["[ ] ", "[x] ", "[X] "]
+        .iter()
+        .find_map(|marker| rest.strip_prefix(marker))
+        .unwrap_or(rest);
+
+    Some(rest.replace("**", "").trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "## Summary\n- Not an item\n\n## Action Items\n- Send the budget to Anna\n- [ ] **Bob:** book the venue\n  - ask about parking\n1. Draft the agenda\n\n## Decisions\n- Not an item either\n";
+
+    #[test]
+    fn test_parse_action_items() {
+        assert_eq!(
+            parse_action_items(NOTES),
+            vec![
+                "Send the budget to Anna",
+                "Bob: book the venue",
+                "Draft the agenda"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_action_items_across_parts() {
+        let notes = "# Part 1 of 2\n\n## Action Items\n- First\n\n# Part 2 of 2\n\n## Action Items\n- Second\n\n## Transcript\n- Said in passing\n";
+        assert_eq!(parse_action_items(notes), vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_parse_action_items_none_identified() {
+        let notes = "## Action Items\n- None identified\n\n## Decisions\n- Ship it\n";
+        assert!(parse_action_items(notes).is_empty());
+        assert!(parse_action_items("No sections at all").is_empty());
+    }
+
+    #[test]
+    fn test_parse_section_items_other_heading() {
+        let notes = "## Attendees\n- Anna\n- Bob\n\n## Main Items\n- Budget\n";
+        assert_eq!(parse_section_items(notes, "Attendees"), vec!["Anna", "Bob"]);
+    }
+
+    #[test]
+    fn test_parse_action_items_keeps_topic_headings_in_section() {
+        let notes = "## Action Items\n### [0:05] Budget\n- Review costs\n";
+        assert_eq!(parse_action_items(notes), vec!["Review costs"]);
+    }
+}
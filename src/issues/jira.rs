@@ -0,0 +1,78 @@
+//! Jira Cloud issue creation via the REST API (v3)
+
+use serde::Deserialize;
+use serde_json::json;
+use tracing::info;
+
+use super::{issue_title, server_error, PushResult};
+use crate::error::IssueError;
+use crate::keychain::IssueTrackerCredentials;
+
+/// Issue type used for action items
+const ISSUE_TYPE: &str = "Task";
+
+/// Response to a successful issue creation
+#[derive(Debug, Deserialize)]
+struct CreatedIssue {
+    key: String,
+}
+
+/// Create a Jira task for each item in the configured project.
+pub(super) async fn create_issues(
+    client: &reqwest::Client,
+    creds: &IssueTrackerCredentials,
+    items: &[String],
+) -> PushResult {
+    let url = format!("{}/rest/api/3/issue", creds.site_url.trim_end_matches('/'));
+
+    for (created, item) in items.iter().enumerate() {
+        let body = json!({
+            "fields": {
+                "project": { "key": creds.project_key },
+                "summary": issue_title(item),
+                "issuetype": { "name": ISSUE_TYPE },
+                "description": description(item),
+            }
+        });
+
+        let response = client
+            .post(&url)
+            .basic_auth(&creds.email, Some(&creds.api_token))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| (created, IssueError::from(e)))?;
+
+        if !response.status().is_success() {
+            return Err((created, server_error(response).await));
+        }
+
+        let issue: CreatedIssue = response.json().await.map_err(|e| {
+            (
+                created,
+                IssueError::InvalidResponse(format!("Failed to parse Jira response: {}", e)),
+            )
+        })?;
+        info!(key = %issue.key, "Created Jira issue");
+    }
+
+    Ok(items.len())
+}
+
+/// The full item as an Atlassian Document Format description
+fn description(item: &str) -> serde_json::Value {
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": [
+            {
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": item }]
+            },
+            {
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": "Created by Vissper from meeting notes." }]
+            }
+        ]
+    })
+}
@@ -0,0 +1,147 @@
+//! Linear issue creation via the GraphQL API
+
+use serde::Deserialize;
+use serde_json::json;
+use tracing::info;
+
+use super::{issue_title, server_error, PushResult};
+use crate::error::IssueError;
+use crate::keychain::IssueTrackerCredentials;
+
+/// Linear GraphQL endpoint
+const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+
+/// Look up a team's id by its key (e.g. "ENG")
+const TEAM_QUERY: &str =
+    "query Team($key: String!) { teams(filter: { key: { eq: $key } }) { nodes { id } } }";
+
+/// Create an issue and return its identifier (e.g. "ENG-123")
+const CREATE_MUTATION: &str = "mutation Create($input: IssueCreateInput!) { \
+     issueCreate(input: $input) { success issue { identifier } } }";
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamData {
+    teams: Nodes<Team>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Team {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateData {
+    issue_create: IssueCreate,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueCreate {
+    success: bool,
+    issue: Option<Issue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Issue {
+    identifier: String,
+}
+
+/// Create a Linear issue for each item in the configured team.
+pub(super) async fn create_issues(
+    client: &reqwest::Client,
+    creds: &IssueTrackerCredentials,
+    items: &[String],
+) -> PushResult {
+    let team: TeamData = query(
+        client,
+        creds,
+        TEAM_QUERY,
+        json!({ "key": creds.project_key }),
+    )
+    .await
+    .map_err(|e| (0, e))?;
+    let team_id = team
+        .teams
+        .nodes
+        .into_iter()
+        .next()
+        .ok_or_else(|| (0, IssueError::TeamNotFound(creds.project_key.clone())))?
+        .id;
+
+    for (created, item) in items.iter().enumerate() {
+        let input = json!({
+            "input": {
+                "teamId": team_id,
+                "title": issue_title(item),
+                "description": format!("{}\n\nCreated by Vissper from meeting notes.", item),
+            }
+        });
+
+        let data: CreateData = query(client, creds, CREATE_MUTATION, input)
+            .await
+            .map_err(|e| (created, e))?;
+        match data.issue_create {
+            IssueCreate {
+                success: true,
+                issue: Some(issue),
+            } => info!(identifier = %issue.identifier, "Created Linear issue"),
+            _ => {
+                return Err((
+                    created,
+                    IssueError::InvalidResponse("Linear did not create the issue".to_string()),
+                ))
+            }
+        }
+    }
+
+    Ok(items.len())
+}
+
+/// Run a GraphQL query or mutation and return its data.
+async fn query<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    creds: &IssueTrackerCredentials,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<T, IssueError> {
+    // Personal API keys are sent as-is, without a "Bearer" prefix
+    let response = client
+        .post(LINEAR_API_URL)
+        .header("Authorization", &creds.api_token)
+        .json(&json!({ "query": query, "variables": variables }))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(server_error(response).await);
+    }
+
+    let body: GraphQlResponse<T> = response.json().await.map_err(|e| {
+        IssueError::InvalidResponse(format!("Failed to parse Linear response: {}", e))
+    })?;
+
+    match (body.data, body.errors.into_iter().next()) {
+        (_, Some(error)) => Err(IssueError::InvalidResponse(error.message)),
+        (Some(data), None) => Ok(data),
+        (None, None) => Err(IssueError::InvalidResponse(
+            "Linear response had no data".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,188 @@
+//! Create Jira or Linear issues from meeting action items
+//!
+//! "Create Issues from Action Items…" in the menu bar reads the bullets under
+//! "## Action Items" in the Meeting Notes tab and shows them in a review
+//! dialog. Each item the user keeps selected becomes an issue in the Jira
+//! project or Linear team set up in the Issues tab of Settings, using a token
+//! kept in the keychain. A notification says how many issues were created.
+//!
+//! Action item text is only sent to the configured tracker and never logged.
+
+mod action_items;
+mod jira;
+mod linear;
+mod review;
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tracing::{error, info};
+
+use crate::error::IssueError;
+use crate::feedback;
+use crate::keychain::{self, IssueTrackerCredentials};
+use crate::transcription_window::{TabType, TranscriptionWindow};
+
+pub(crate) use action_items::{parse_action_items, parse_section_items};
+
+/// Longest issue title; longer items are cut and sent in full as the description
+const MAX_TITLE_CHARS: usize = 200;
+
+/// Timeout for each request to the tracker
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Set while issues are being created, so repeated clicks are ignored
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Issue tracker that action items are pushed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum IssueTracker {
+    #[default]
+    Jira,
+    Linear,
+}
+
+impl IssueTracker {
+    /// All trackers, in the order shown in Settings
+    pub(crate) const ALL: [IssueTracker; 2] = [IssueTracker::Jira, IssueTracker::Linear];
+
+    /// Segment label in Settings and name in messages
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            IssueTracker::Jira => "Jira",
+            IssueTracker::Linear => "Linear",
+        }
+    }
+
+    /// Look up a tracker by its index in `ALL` (the selected segment)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    /// Index in `ALL`
+    pub(crate) fn to_index(self) -> isize {
+        Self::ALL.iter().position(|t| *t == self).unwrap_or(0) as isize
+    }
+
+    /// Whether the tracker needs a site URL and account email
+    pub(crate) fn needs_site(self) -> bool {
+        self == IssueTracker::Jira
+    }
+}
+
+/// Review the meeting notes' action items and push the selected ones.
+///
+/// Must be called on the main thread; the issues are created in the background.
+pub(crate) fn create_from_meeting_notes() {
+    if RUNNING.load(Ordering::SeqCst) {
+        info!("Issue creation already running, ignoring");
+        return;
+    }
+
+    let Ok(creds) = keychain::get_issue_tracker_credentials() else {
+        review::show_message(
+            "Issue tracker not set up",
+            "Add your Jira or Linear connection in the Issues tab of Settings first.",
+        );
+        return;
+    };
+
+    let items = TranscriptionWindow::get_generated_content(TabType::MeetingNotes)
+        .map(|notes| parse_action_items(&notes))
+        .unwrap_or_default();
+    if items.is_empty() {
+        review::show_message(
+            "No action items found",
+            "Generate meeting notes with an Action Items section first.",
+        );
+        return;
+    }
+
+    let Some(selected) = review::choose_items(&items, creds.tracker) else {
+        info!("Issue creation cancelled");
+        return;
+    };
+    if selected.is_empty() {
+        info!("No action items selected");
+        return;
+    }
+
+    RUNNING.store(true, Ordering::SeqCst);
+    tokio::spawn(async move {
+        let total = selected.len();
+        let tracker = creds.tracker.label();
+        let created = push(&creds, &selected).await;
+        let (title, body) = if created == total {
+            (
+                "Issues created".to_string(),
+                format!("Created {} issue(s) in {}.", created, tracker),
+            )
+        } else {
+            (
+                "Some issues were not created".to_string(),
+                format!(
+                    "Created {} of {} issue(s) in {}. Check the connection in the Issues tab of Settings.",
+                    created, total, tracker
+                ),
+            )
+        };
+        feedback::notify_now(&title, &body);
+        RUNNING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Create one issue per item, stopping at the first failure.
+///
+/// Returns how many issues were created.
+async fn push(creds: &IssueTrackerCredentials, items: &[String]) -> usize {
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to create HTTP client for issue tracker: {}", e);
+            return 0;
+        }
+    };
+
+    let result = match creds.tracker {
+        IssueTracker::Jira => jira::create_issues(&client, creds, items).await,
+        IssueTracker::Linear => linear::create_issues(&client, creds, items).await,
+    };
+
+    match result {
+        Ok(created) => {
+            info!(created, tracker = ?creds.tracker, "Created issues from action items");
+            created
+        }
+        Err((created, e)) => {
+            error!(created, tracker = ?creds.tracker, "Failed to create issue: {}", e);
+            created
+        }
+    }
+}
+
+/// Issue title for an action item, cut at a word boundary if it is too long
+fn issue_title(item: &str) -> String {
+    if item.chars().count() <= MAX_TITLE_CHARS {
+        return item.to_string();
+    }
+    let cut: String = item.chars().take(MAX_TITLE_CHARS - 1).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space > MAX_TITLE_CHARS / 2 => &cut[..space],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+/// Result of creating issues: the count created, or the count before the failure
+type PushResult = Result<usize, (usize, IssueError)>;
+
+/// Turn an unsuccessful response into an error with the tracker's message
+async fn server_error(response: reqwest::Response) -> IssueError {
+    IssueError::ServerError {
+        status: response.status().as_u16(),
+        message: response.text().await.unwrap_or_default(),
+    }
+}
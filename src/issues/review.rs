@@ -0,0 +1,136 @@
+//! Review dialog for choosing which action items become issues
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSButton, NSScrollView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+
+use super::IssueTracker;
+use crate::settings_window::constants::NS_BUTTON_TYPE_SWITCH;
+
+/// NSAlertFirstButtonReturn
+const ALERT_FIRST_BUTTON: isize = 1000;
+
+/// Width of the list of items
+const LIST_WIDTH: CGFloat = 420.0;
+
+/// Height of each item's checkbox row
+const ROW_HEIGHT: CGFloat = 24.0;
+
+/// Rows shown before the list scrolls
+const VISIBLE_ROWS: usize = 10;
+
+/// Show the action items with a checkbox each and return the checked ones.
+///
+/// All items start checked. Returns `None` if the user cancelled or this
+/// isn't the main thread.
+pub(super) fn choose_items(items: &[String], tracker: IssueTracker) -> Option<Vec<String>> {
+    let mtm = MainThreadMarker::new()?;
+
+    let (list, checkboxes) = create_item_list(mtm, items);
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(&format!(
+            "Create {} issues from action items",
+            tracker.label()
+        )));
+        alert.setInformativeText(&NSString::from_str(
+            "Uncheck the items you don't want to create issues for.",
+        ));
+        alert.setAccessoryView(Some(&list));
+        alert.addButtonWithTitle(&NSString::from_str("Create Issues"));
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+
+    if response != ALERT_FIRST_BUTTON {
+        return None;
+    }
+
+    let selected = items
+        .iter()
+        .zip(&checkboxes)
+        // SAFETY: reading the state of a valid NSButton on the main thread
+        .filter(|(_, checkbox)| unsafe { checkbox.state() } == 1)
+        .map(|(item, _)| item.clone())
+        .collect();
+    Some(selected)
+}
+
+/// Build a scrollable list with a checked checkbox per item.
+fn create_item_list(
+    mtm: MainThreadMarker,
+    items: &[String],
+) -> (Retained<NSScrollView>, Vec<Retained<NSButton>>) {
+    let list_height = ROW_HEIGHT * items.len() as CGFloat;
+    let visible_height = ROW_HEIGHT * items.len().min(VISIBLE_ROWS) as CGFloat;
+
+    // SAFETY: view allocation and configuration on the main thread with valid frames
+    unsafe {
+        let scroll_view: Retained<NSScrollView> = msg_send_id![
+            mtm.alloc::<NSScrollView>(),
+            initWithFrame: NSRect::new(
+                NSPoint::new(0.0, 0.0),
+                NSSize::new(LIST_WIDTH, visible_height),
+            )
+        ];
+        scroll_view.setHasVerticalScroller(items.len() > VISIBLE_ROWS);
+        scroll_view.setHasHorizontalScroller(false);
+        let _: () = msg_send![&scroll_view, setDrawsBackground: false];
+
+        let document: Retained<NSView> = msg_send_id![
+            mtm.alloc::<NSView>(),
+            initWithFrame: NSRect::new(
+                NSPoint::new(0.0, 0.0),
+                NSSize::new(LIST_WIDTH, list_height),
+            )
+        ];
+
+        // Views are not flipped, so the first item goes at the top
+        let checkboxes: Vec<Retained<NSButton>> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let y = list_height - ROW_HEIGHT * (index + 1) as CGFloat;
+                let checkbox: Retained<NSButton> = msg_send_id![
+                    mtm.alloc::<NSButton>(),
+                    initWithFrame: NSRect::new(
+                        NSPoint::new(0.0, y),
+                        NSSize::new(LIST_WIDTH, ROW_HEIGHT),
+                    )
+                ];
+                let _: () = msg_send![&checkbox, setButtonType: NS_BUTTON_TYPE_SWITCH];
+                checkbox.setTitle(&NSString::from_str(item));
+                let _: () = msg_send![&checkbox, setState: 1_isize];
+                // NSLineBreakByTruncatingTail = 4
+                let _: () = msg_send![&checkbox, setLineBreakMode: 4_usize];
+                document.addSubview(&checkbox);
+                checkbox
+            })
+            .collect();
+
+        scroll_view.setDocumentView(Some(&document));
+        // Start scrolled to the first item
+        let _: () = msg_send![&document, scrollPoint: NSPoint::new(0.0, list_height)];
+
+        (scroll_view, checkboxes)
+    }
+}
+
+/// Show an informational alert, e.g. when there is nothing to push.
+pub(super) fn show_message(title: &str, message: &str) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(title));
+        alert.setInformativeText(&NSString::from_str(message));
+        alert.runModal();
+    }
+}
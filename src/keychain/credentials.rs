@@ -0,0 +1,130 @@
+//! Credentials kept in the keychain
+
+use crate::azure_openai::AzurePolishRoute;
+use crate::issues::IssueTracker;
+use serde::{Deserialize, Serialize};
+
+/// Realtime API version used when the Azure credentials don't set one
+pub(crate) const DEFAULT_REALTIME_API_VERSION: &str = "2024-10-01-preview";
+
+/// Responses API version used when the Azure credentials don't set one
+pub(crate) const DEFAULT_RESPONSES_API_VERSION: &str = "2025-04-01-preview";
+
+/// Azure OpenAI credentials for direct connection.
+///
+/// Stored encrypted in OS Keychain. Users provide their own Azure OpenAI
+/// resources for STT and transcript polishing.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct AzureCredentials {
+    /// Azure OpenAI API key
+    pub(crate) api_key: String,
+    /// Azure OpenAI endpoint URL (e.g., "https://myresource.openai.azure.com")
+    pub(crate) endpoint_url: String,
+    /// Deployment name for STT (e.g., "gpt-4o-transcribe")
+    pub(crate) stt_deployment: String,
+    /// Deployment name for transcript polishing (e.g., "gpt-5.1")
+    pub(crate) polish_deployment: String,
+    /// Realtime API version for transcription (None = [`DEFAULT_REALTIME_API_VERSION`])
+    #[serde(default)]
+    pub(crate) realtime_api_version: Option<String>,
+    /// Responses API version for polishing (None = [`DEFAULT_RESPONSES_API_VERSION`])
+    #[serde(default)]
+    pub(crate) responses_api_version: Option<String>,
+    /// Route polish requests take (None = detect the one the resource has)
+    #[serde(default)]
+    pub(crate) polish_route: Option<AzurePolishRoute>,
+}
+
+impl AzureCredentials {
+    /// Realtime API version to connect with
+    pub(crate) fn realtime_api_version(&self) -> &str {
+        self.realtime_api_version
+            .as_deref()
+            .unwrap_or(DEFAULT_REALTIME_API_VERSION)
+    }
+
+    /// Responses API version to polish with
+    pub(crate) fn responses_api_version(&self) -> &str {
+        self.responses_api_version
+            .as_deref()
+            .unwrap_or(DEFAULT_RESPONSES_API_VERSION)
+    }
+}
+
+/// Whether `version` has the shape of an Azure OpenAI API version
+///
+/// Azure names versions by release date, e.g. "2024-10-21" or
+/// "2025-04-01-preview".
+pub(crate) fn is_valid_api_version(version: &str) -> bool {
+    let date = version.strip_suffix("-preview").unwrap_or(version);
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok() && date.len() == 10
+}
+
+/// OpenAI credentials for direct connection.
+///
+/// Stored encrypted in OS Keychain. Users provide their own OpenAI API key.
+/// Unlike Azure, OpenAI only requires an API key (no endpoint or deployment names).
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct OpenAICredentials {
+    /// OpenAI API key
+    pub(crate) api_key: String,
+}
+
+/// Jira or Linear connection for creating issues from action items.
+///
+/// Stored encrypted in OS Keychain together with the API token.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct IssueTrackerCredentials {
+    pub(crate) tracker: IssueTracker,
+    /// Jira API token or Linear personal API key
+    pub(crate) api_token: String,
+    /// Jira site (e.g. "https://acme.atlassian.net"); empty for Linear
+    #[serde(default)]
+    pub(crate) site_url: String,
+    /// Atlassian account email for Jira; empty for Linear
+    #[serde(default)]
+    pub(crate) email: String,
+    /// Jira project key or Linear team key (e.g. "ENG")
+    pub(crate) project_key: String,
+}
+
+/// Notion connection for exporting meeting notes.
+///
+/// Stored encrypted in OS Keychain together with the integration token.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NotionCredentials {
+    /// Internal integration token ("secret_..." or "ntn_...")
+    pub(crate) token: String,
+    /// Database that gets a page per session
+    pub(crate) database_id: String,
+}
+
+/// Google Drive connection for uploading saved transcripts.
+///
+/// Stored encrypted in OS Keychain together with the OAuth refresh token.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct GoogleDriveCredentials {
+    /// OAuth client ID of the user's desktop app in Google Cloud
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    /// Long-lived token used to get an access token for each upload
+    pub(crate) refresh_token: String,
+    /// Folder that receives the uploads
+    pub(crate) folder_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_version_shape() {
+        assert!(is_valid_api_version(DEFAULT_REALTIME_API_VERSION));
+        assert!(is_valid_api_version(DEFAULT_RESPONSES_API_VERSION));
+        assert!(is_valid_api_version("2024-10-21"));
+        assert!(!is_valid_api_version("2024-13-01"));
+        assert!(!is_valid_api_version("2024-10-01-beta"));
+        assert!(!is_valid_api_version("v1"));
+        assert!(!is_valid_api_version(""));
+    }
+}
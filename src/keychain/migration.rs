@@ -0,0 +1,100 @@
+//! Carrying keychain items over to the current schema version
+//!
+//! Runs once per schema version, on launch. For each account the newest
+//! item from an earlier version is converted, stored under the current name
+//! and read back before the old item is removed, so a failure part way
+//! leaves the old item in place for the next launch to try again.
+
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+use super::{item_name, read_item, remove_item, write_item, ACCOUNTS, SCHEMA_VERSION};
+use crate::error::KeychainError;
+use crate::preferences;
+
+/// Move items saved by earlier versions to the current names
+pub(crate) fn migrate() {
+    let from = preferences::get_keychain_schema_version();
+    if from >= SCHEMA_VERSION {
+        return;
+    }
+
+    let mut complete = true;
+    for account in ACCOUNTS {
+        match migrate_item(account, from) {
+            Ok(Some(version)) => info!(account, version, "Migrated keychain item"),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(account, "Failed to migrate keychain item: {}", e);
+                complete = false;
+            }
+        }
+    }
+
+    if complete {
+        if let Err(e) = preferences::set_keychain_schema_version(SCHEMA_VERSION) {
+            warn!("Failed to save keychain schema version: {}", e);
+        }
+    }
+}
+
+/// Move the newest earlier item for `account`, returning the version it had
+fn migrate_item(account: &str, from: u32) -> Result<Option<u32>, KeychainError> {
+    let current = item_name(account, SCHEMA_VERSION);
+    if read_item(&current)?.is_some() {
+        return Ok(None);
+    }
+
+    for version in (from.max(1)..SCHEMA_VERSION).rev() {
+        let old = item_name(account, version);
+        let Some(data) = read_item(&old)?.map(Zeroizing::new) else {
+            continue;
+        };
+
+        let data = upgrade(account, version, data)?;
+        write_item(&current, &data)?;
+        if read_item(&current)?.map(Zeroizing::new).as_deref() != Some(&*data) {
+            return Err(KeychainError::Store(format!(
+                "{} did not read back after migration",
+                current
+            )));
+        }
+        remove_item(&old)?;
+        return Ok(Some(version));
+    }
+    Ok(None)
+}
+
+/// Convert an item's contents from schema `version` to the current one
+fn upgrade(
+    _account: &str,
+    version: u32,
+    data: Zeroizing<Vec<u8>>,
+) -> Result<Zeroizing<Vec<u8>>, KeychainError> {
+    match version {
+        // Version 2 only renamed the items
+        1 => Ok(data),
+        _ => Err(KeychainError::InvalidData(format!(
+            "No migration from keychain schema version {}",
+            version
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_one_items_keep_their_contents() {
+        let data = Zeroizing::new(br#"{"api_key":"sk-test"}"#.to_vec());
+        let upgraded = upgrade("openai_credentials", 1, data.clone()).unwrap();
+        assert_eq!(*upgraded, *data);
+    }
+
+    #[test]
+    fn test_unknown_versions_are_refused() {
+        let data = Zeroizing::new(Vec::new());
+        assert!(upgrade("openai_credentials", SCHEMA_VERSION, data).is_err());
+    }
+}
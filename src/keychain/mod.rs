@@ -0,0 +1,332 @@
+//! Secure credential storage using macOS Keychain
+//!
+//! Provides secure storage for Azure OpenAI credentials using OS-native
+//! credential storage. On macOS, uses the Keychain. On Windows, would
+//! use DPAPI (Data Protection API) - currently not implemented.
+//!
+//! # Security
+//! - Credentials are stored encrypted in the OS keychain
+//! - Only the Vissper application can access these credentials
+//!
+//! # Item naming
+//! Each item's account name carries the schema version, e.g.
+//! `azure_credentials.v2`. When the stored format changes the version goes
+//! up, and [`migrate`] converts items saved by earlier versions on launch, so
+//! an update never silently loses saved credentials.
+
+mod credentials;
+mod migration;
+mod transfer;
+mod validation;
+
+pub(crate) use credentials::{
+    is_valid_api_version, AzureCredentials, GoogleDriveCredentials, IssueTrackerCredentials,
+    NotionCredentials, OpenAICredentials, DEFAULT_REALTIME_API_VERSION,
+    DEFAULT_RESPONSES_API_VERSION,
+};
+pub(crate) use migration::migrate;
+pub(crate) use transfer::{open_credentials, seal_credentials, CredentialBundle};
+pub(crate) use validation::{check_api_key, check_deployment, normalize_endpoint};
+
+use crate::error::KeychainError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+#[cfg(target_os = "macos")]
+use security_framework::passwords::*;
+
+const SERVICE_NAME: &str = "com.vissper.desktop";
+
+/// Version of the stored item format, part of every account name
+const SCHEMA_VERSION: u32 = 2;
+
+/// Keychain account holding the Azure OpenAI connection
+const AZURE_ACCOUNT: &str = "azure_credentials";
+
+/// Keychain account holding the OpenAI API key
+const OPENAI_ACCOUNT: &str = "openai_credentials";
+
+/// Keychain account holding the Jira or Linear connection
+const ISSUE_TRACKER_ACCOUNT: &str = "issue_tracker_credentials";
+
+/// Keychain account holding the Notion integration token and database
+const NOTION_ACCOUNT: &str = "notion_credentials";
+
+/// Keychain account holding the Google Drive OAuth client and refresh token
+const GOOGLE_DRIVE_ACCOUNT: &str = "google_drive_credentials";
+
+/// Keychain account holding the key for encrypted transcripts and screenshots
+const STORAGE_KEY_ACCOUNT: &str = "storage_encryption_key";
+
+/// Keychain account holding the token for the local HTTP API
+const HTTP_API_TOKEN_ACCOUNT: &str = "http_api_token";
+
+/// Every account, for migration and export
+const ACCOUNTS: &[&str] = &[
+    AZURE_ACCOUNT,
+    OPENAI_ACCOUNT,
+    ISSUE_TRACKER_ACCOUNT,
+    NOTION_ACCOUNT,
+    GOOGLE_DRIVE_ACCOUNT,
+    STORAGE_KEY_ACCOUNT,
+    HTTP_API_TOKEN_ACCOUNT,
+];
+
+/// `errSecItemNotFound`: no keychain item exists for the account
+#[cfg(target_os = "macos")]
+const ERR_SEC_ITEM_NOT_FOUND: i32 = -25300;
+
+/// Store Azure credentials securely in the keychain.
+pub(crate) fn store_azure_credentials(creds: &AzureCredentials) -> Result<(), KeychainError> {
+    store_json(AZURE_ACCOUNT, creds, "Azure credentials")
+}
+
+/// Retrieve Azure credentials from keychain.
+///
+/// An endpoint or deployment locked by a managed policy replaces the stored value.
+pub(crate) fn get_azure_credentials() -> Result<AzureCredentials, KeychainError> {
+    let mut creds: AzureCredentials = get_json(AZURE_ACCOUNT, "Azure credentials")?;
+    crate::managed::policy().apply_to_azure(&mut creds);
+    Ok(creds)
+}
+
+/// Delete Azure credentials from keychain.
+pub(crate) fn delete_azure_credentials() -> Result<(), KeychainError> {
+    delete_item(AZURE_ACCOUNT)
+}
+
+/// Store OpenAI credentials securely in the keychain.
+pub(crate) fn store_openai_credentials(creds: &OpenAICredentials) -> Result<(), KeychainError> {
+    store_json(OPENAI_ACCOUNT, creds, "OpenAI credentials")
+}
+
+/// Retrieve OpenAI credentials from keychain.
+pub(crate) fn get_openai_credentials() -> Result<OpenAICredentials, KeychainError> {
+    get_json(OPENAI_ACCOUNT, "OpenAI credentials")
+}
+
+/// Delete OpenAI credentials from keychain.
+pub(crate) fn delete_openai_credentials() -> Result<(), KeychainError> {
+    delete_item(OPENAI_ACCOUNT)
+}
+
+/// Store the issue tracker connection securely in the keychain.
+pub(crate) fn store_issue_tracker_credentials(
+    creds: &IssueTrackerCredentials,
+) -> Result<(), KeychainError> {
+    store_json(ISSUE_TRACKER_ACCOUNT, creds, "issue tracker credentials")
+}
+
+/// Retrieve the issue tracker connection from keychain.
+pub(crate) fn get_issue_tracker_credentials() -> Result<IssueTrackerCredentials, KeychainError> {
+    get_json(ISSUE_TRACKER_ACCOUNT, "issue tracker credentials")
+}
+
+/// Delete the issue tracker connection from keychain.
+pub(crate) fn delete_issue_tracker_credentials() -> Result<(), KeychainError> {
+    delete_item(ISSUE_TRACKER_ACCOUNT)
+}
+
+/// Store the Notion connection securely in the keychain.
+pub(crate) fn store_notion_credentials(creds: &NotionCredentials) -> Result<(), KeychainError> {
+    store_json(NOTION_ACCOUNT, creds, "Notion credentials")
+}
+
+/// Retrieve the Notion connection from keychain.
+pub(crate) fn get_notion_credentials() -> Result<NotionCredentials, KeychainError> {
+    get_json(NOTION_ACCOUNT, "Notion credentials")
+}
+
+/// Delete the Notion connection from keychain.
+pub(crate) fn delete_notion_credentials() -> Result<(), KeychainError> {
+    delete_item(NOTION_ACCOUNT)
+}
+
+/// Store the Google Drive connection securely in the keychain.
+pub(crate) fn store_google_drive_credentials(
+    creds: &GoogleDriveCredentials,
+) -> Result<(), KeychainError> {
+    store_json(GOOGLE_DRIVE_ACCOUNT, creds, "Google Drive credentials")
+}
+
+/// Retrieve the Google Drive connection from keychain.
+pub(crate) fn get_google_drive_credentials() -> Result<GoogleDriveCredentials, KeychainError> {
+    get_json(GOOGLE_DRIVE_ACCOUNT, "Google Drive credentials")
+}
+
+/// Delete the Google Drive connection from keychain.
+pub(crate) fn delete_google_drive_credentials() -> Result<(), KeychainError> {
+    delete_item(GOOGLE_DRIVE_ACCOUNT)
+}
+
+/// Store the storage encryption key in the keychain.
+pub(crate) fn store_storage_key(key: &[u8]) -> Result<(), KeychainError> {
+    write_item(&item_name(STORAGE_KEY_ACCOUNT, SCHEMA_VERSION), key)
+}
+
+/// Retrieve the storage encryption key from the keychain.
+///
+/// Returns `Ok(None)` only when no key has been created yet, so callers can
+/// tell a missing key apart from a keychain failure (and never replace a key
+/// that existing files were encrypted with).
+pub(crate) fn get_storage_key() -> Result<Option<Vec<u8>>, KeychainError> {
+    read_item(&item_name(STORAGE_KEY_ACCOUNT, SCHEMA_VERSION))
+}
+
+/// Store the local HTTP API token in the keychain.
+pub(crate) fn store_http_api_token(token: &str) -> Result<(), KeychainError> {
+    write_item(
+        &item_name(HTTP_API_TOKEN_ACCOUNT, SCHEMA_VERSION),
+        token.as_bytes(),
+    )
+}
+
+/// Retrieve the local HTTP API token, or `None` if none was generated yet.
+pub(crate) fn get_http_api_token() -> Result<Option<String>, KeychainError> {
+    read_item(&item_name(HTTP_API_TOKEN_ACCOUNT, SCHEMA_VERSION))?
+        .map(|data| {
+            String::from_utf8(data)
+                .map_err(|e| KeychainError::InvalidData(format!("Invalid HTTP API token: {}", e)))
+        })
+        .transpose()
+}
+
+/// Keychain account name of `account` in schema `version`
+///
+/// Version 1 items were named without a suffix.
+fn item_name(account: &str, version: u32) -> String {
+    if version == 1 {
+        account.to_string()
+    } else {
+        format!("{}.v{}", account, version)
+    }
+}
+
+/// Replace `account` with `value` as JSON
+fn store_json<T: Serialize>(account: &str, value: &T, what: &str) -> Result<(), KeychainError> {
+    let json = Zeroizing::new(
+        serde_json::to_vec(value)
+            .map_err(|e| KeychainError::Store(format!("Failed to serialize {}: {}", what, e)))?,
+    );
+
+    // Delete existing item if present
+    let name = item_name(account, SCHEMA_VERSION);
+    let _ = remove_item(&name);
+    write_item(&name, &json)
+}
+
+/// Read `account` as JSON
+fn get_json<T: DeserializeOwned>(account: &str, what: &str) -> Result<T, KeychainError> {
+    let json = read_item(&item_name(account, SCHEMA_VERSION))?
+        .map(Zeroizing::new)
+        .ok_or_else(|| KeychainError::Retrieve(format!("No {} in keychain", what)))?;
+
+    serde_json::from_slice(&json)
+        .map_err(|e| KeychainError::InvalidData(format!("Failed to deserialize {}: {}", what, e)))
+}
+
+fn delete_item(account: &str) -> Result<(), KeychainError> {
+    remove_item(&item_name(account, SCHEMA_VERSION))
+}
+
+#[cfg(target_os = "macos")]
+fn write_item(name: &str, data: &[u8]) -> Result<(), KeychainError> {
+    set_generic_password(SERVICE_NAME, name, data).map_err(|e| KeychainError::Store(e.to_string()))
+}
+
+/// Contents of the item `name`, or `None` if there is no such item
+#[cfg(target_os = "macos")]
+fn read_item(name: &str) -> Result<Option<Vec<u8>>, KeychainError> {
+    match get_generic_password(SERVICE_NAME, name) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.code() == ERR_SEC_ITEM_NOT_FOUND => Ok(None),
+        Err(e) => Err(KeychainError::Retrieve(e.to_string())),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn remove_item(name: &str) -> Result<(), KeychainError> {
+    delete_generic_password(SERVICE_NAME, name).map_err(|e| KeychainError::Delete(e.to_string()))
+}
+
+// Stub implementations for non-macOS platforms
+// In the future, implement Windows DPAPI here
+#[cfg(not(target_os = "macos"))]
+fn write_item(_name: &str, _data: &[u8]) -> Result<(), KeychainError> {
+    Err(KeychainError::NotImplemented)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_item(_name: &str) -> Result<Option<Vec<u8>>, KeychainError> {
+    Err(KeychainError::NotImplemented)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn remove_item(_name: &str) -> Result<(), KeychainError> {
+    Err(KeychainError::NotImplemented)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_names_carry_the_schema_version() {
+        assert_eq!(item_name(AZURE_ACCOUNT, 1), "azure_credentials");
+        assert_eq!(item_name(AZURE_ACCOUNT, 2), "azure_credentials.v2");
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_azure_credentials_storage() {
+        let test_creds = AzureCredentials {
+            api_key: "test_key_12345".to_string(),
+            endpoint_url: "https://test.openai.azure.com".to_string(),
+            stt_deployment: "gpt-4o-transcribe".to_string(),
+            polish_deployment: "gpt-4o".to_string(),
+            realtime_api_version: None,
+            responses_api_version: Some("2025-04-01-preview".to_string()),
+            polish_route: None,
+        };
+
+        // Store credentials
+        store_azure_credentials(&test_creds).expect("Failed to store credentials");
+
+        // Retrieve credentials
+        let retrieved = get_azure_credentials().expect("Failed to retrieve credentials");
+        assert_eq!(retrieved.api_key, test_creds.api_key);
+        assert_eq!(retrieved.endpoint_url, test_creds.endpoint_url);
+        assert_eq!(
+            retrieved.responses_api_version,
+            test_creds.responses_api_version
+        );
+
+        // Delete credentials
+        delete_azure_credentials().expect("Failed to delete credentials");
+
+        // Verify deletion
+        assert!(get_azure_credentials().is_err());
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_openai_credentials_storage() {
+        let test_creds = OpenAICredentials {
+            api_key: "sk-test_openai_key_12345".to_string(),
+        };
+
+        // Store credentials
+        store_openai_credentials(&test_creds).expect("Failed to store credentials");
+
+        // Retrieve credentials
+        let retrieved = get_openai_credentials().expect("Failed to retrieve credentials");
+        assert_eq!(retrieved.api_key, test_creds.api_key);
+
+        // Delete credentials
+        delete_openai_credentials().expect("Failed to delete credentials");
+
+        // Verify deletion
+        assert!(get_openai_credentials().is_err());
+    }
+}
@@ -0,0 +1,231 @@
+//! Credentials sealed with a passphrase, for moving to another Mac
+//!
+//! The keychain doesn't travel with exported settings, so saved credentials
+//! are bundled and encrypted with a key derived from a passphrase the user
+//! picks (Argon2id). The storage encryption key is included so encrypted
+//! transcripts stay readable on the new Mac.
+//!
+//! # File format
+//! `MAGIC` (8 bytes) | salt (16 bytes) | nonce (12 bytes) | ciphertext with 16-byte tag
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+use super::{
+    get_azure_credentials, get_google_drive_credentials, get_issue_tracker_credentials,
+    get_notion_credentials, get_openai_credentials, get_storage_key, store_azure_credentials,
+    store_google_drive_credentials, store_issue_tracker_credentials, store_notion_credentials,
+    store_openai_credentials, store_storage_key, AzureCredentials, GoogleDriveCredentials,
+    IssueTrackerCredentials, NotionCredentials, OpenAICredentials,
+};
+use crate::error::KeychainError;
+
+/// Header identifying sealed credentials (format version 1)
+const MAGIC: &[u8; 8] = b"VSPRKEY1";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Everything saved in the keychain
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct CredentialBundle {
+    #[serde(default)]
+    azure: Option<AzureCredentials>,
+    #[serde(default)]
+    openai: Option<OpenAICredentials>,
+    #[serde(default)]
+    issue_tracker: Option<IssueTrackerCredentials>,
+    #[serde(default)]
+    notion: Option<NotionCredentials>,
+    #[serde(default)]
+    google_drive: Option<GoogleDriveCredentials>,
+    /// Key for encrypted transcripts and screenshots, base64
+    #[serde(default)]
+    storage_key: Option<String>,
+}
+
+impl CredentialBundle {
+    /// Collect the credentials saved on this Mac
+    pub(crate) fn from_keychain() -> Self {
+        Self {
+            azure: get_azure_credentials().ok(),
+            openai: get_openai_credentials().ok(),
+            issue_tracker: get_issue_tracker_credentials().ok(),
+            notion: get_notion_credentials().ok(),
+            google_drive: get_google_drive_credentials().ok(),
+            storage_key: get_storage_key()
+                .ok()
+                .flatten()
+                .map(|key| base64::engine::general_purpose::STANDARD.encode(key)),
+        }
+    }
+
+    /// Whether nothing was saved
+    pub(crate) fn is_empty(&self) -> bool {
+        self.azure.is_none()
+            && self.openai.is_none()
+            && self.issue_tracker.is_none()
+            && self.notion.is_none()
+            && self.google_drive.is_none()
+            && self.storage_key.is_none()
+    }
+
+    /// Save the bundled credentials in the keychain, returning how many were saved
+    ///
+    /// A storage key already on this Mac is kept, since files may have been
+    /// encrypted with it.
+    pub(crate) fn store(self) -> Result<usize, KeychainError> {
+        let mut stored = 0;
+        if let Some(creds) = self.azure {
+            store_azure_credentials(&creds)?;
+            stored += 1;
+        }
+        if let Some(creds) = self.openai {
+            store_openai_credentials(&creds)?;
+            stored += 1;
+        }
+        if let Some(creds) = self.issue_tracker {
+            store_issue_tracker_credentials(&creds)?;
+            stored += 1;
+        }
+        if let Some(creds) = self.notion {
+            store_notion_credentials(&creds)?;
+            stored += 1;
+        }
+        if let Some(creds) = self.google_drive {
+            store_google_drive_credentials(&creds)?;
+            stored += 1;
+        }
+        if let Some(encoded) = self.storage_key {
+            let key = Zeroizing::new(
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded)
+                    .map_err(|e| KeychainError::InvalidData(e.to_string()))?,
+            );
+            match get_storage_key()? {
+                None => {
+                    store_storage_key(&key)?;
+                    stored += 1;
+                }
+                Some(existing) if Zeroizing::new(existing) == key => {}
+                Some(_) => {
+                    warn!("Kept this Mac's storage encryption key instead of the imported one")
+                }
+            }
+        }
+        info!(stored, "Imported credentials into keychain");
+        Ok(stored)
+    }
+}
+
+/// Encrypt `bundle` with `passphrase` into the format in the module docs
+pub(crate) fn seal_credentials(
+    bundle: &CredentialBundle,
+    passphrase: &str,
+) -> Result<Vec<u8>, KeychainError> {
+    let json = Zeroizing::new(
+        serde_json::to_vec(bundle).map_err(|e| KeychainError::InvalidData(e.to_string()))?,
+    );
+    let salt: [u8; SALT_LEN] = rand::random();
+    let key = derive_key(passphrase, &salt)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_slice()));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, json.as_slice())
+        .map_err(|_| KeychainError::InvalidData("Encryption failed".to_string()))?;
+
+    let mut sealed = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(MAGIC);
+    sealed.extend_from_slice(&salt);
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt credentials sealed by [`seal_credentials`]
+pub(crate) fn open_credentials(
+    sealed: &[u8],
+    passphrase: &str,
+) -> Result<CredentialBundle, KeychainError> {
+    let body = sealed
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or_else(|| KeychainError::InvalidData("Not a Vissper credentials file".to_string()))?;
+    if body.len() < SALT_LEN + NONCE_LEN {
+        return Err(KeychainError::InvalidData(
+            "Credentials file is truncated".to_string(),
+        ));
+    }
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key = derive_key(passphrase, salt)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_slice()));
+    let json = Zeroizing::new(
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| KeychainError::WrongPassphrase)?,
+    );
+    serde_json::from_slice(&json).map_err(|e| KeychainError::InvalidData(e.to_string()))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Zeroizing<[u8; KEY_LEN]>, KeychainError> {
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+        .map_err(|e| KeychainError::InvalidData(e.to_string()))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> CredentialBundle {
+        CredentialBundle {
+            openai: Some(OpenAICredentials {
+                api_key: "sk-test".to_string(),
+            }),
+            storage_key: Some("c2VjcmV0".to_string()),
+            ..CredentialBundle::default()
+        }
+    }
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let sealed = seal_credentials(&bundle(), "correct horse").unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        assert!(!sealed.windows(7).any(|w| w == b"sk-test"));
+
+        let opened = open_credentials(&sealed, "correct horse").unwrap();
+        assert_eq!(opened.openai.unwrap().api_key, "sk-test");
+        assert_eq!(opened.storage_key.as_deref(), Some("c2VjcmV0"));
+        assert!(opened.azure.is_none());
+    }
+
+    #[test]
+    fn test_wrong_passphrase_and_damage_are_refused() {
+        let mut sealed = seal_credentials(&bundle(), "correct horse").unwrap();
+        assert!(matches!(
+            open_credentials(&sealed, "battery staple"),
+            Err(KeychainError::WrongPassphrase)
+        ));
+        assert!(open_credentials(&sealed[..MAGIC.len() + 4], "correct horse").is_err());
+
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(open_credentials(&sealed, "correct horse").is_err());
+    }
+
+    #[test]
+    fn test_empty_bundle() {
+        assert!(CredentialBundle::default().is_empty());
+        assert!(!bundle().is_empty());
+    }
+}
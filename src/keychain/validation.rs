@@ -0,0 +1,152 @@
+//! Checks for Azure connection settings before they are saved
+//!
+//! A URL copied from the Azure portal or a code sample often carries a path
+//! (`/openai/deployments/...`), a query or the default port. The app builds
+//! every request path itself, so anything beyond the scheme and host turns
+//! into confusing connection failures later. The endpoint is reduced to
+//! `https://host`, and deployment names and keys are checked for the shape
+//! Azure accepts.
+
+use url::Url;
+
+use crate::error::AzureInputError;
+
+/// Longest deployment name Azure accepts
+const MAX_DEPLOYMENT_NAME_LEN: usize = 64;
+
+/// Shortest Azure OpenAI API key; current keys are longer
+const MIN_API_KEY_LEN: usize = 32;
+
+/// Reduce an endpoint to `https://host[:port]`, e.g.
+/// "myresource.openai.azure.com/openai/" to "https://myresource.openai.azure.com"
+pub(crate) fn normalize_endpoint(input: &str) -> Result<String, AzureInputError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(AzureInputError::Missing);
+    }
+
+    let with_scheme = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{}", input)
+    };
+    let url = Url::parse(&with_scheme).map_err(|_| AzureInputError::InvalidUrl)?;
+
+    // The realtime URL uses wss:// for the same host
+    match url.scheme() {
+        "https" | "wss" => {}
+        "http" | "ws" => return Err(AzureInputError::NotHttps),
+        _ => return Err(AzureInputError::InvalidUrl),
+    }
+    let host = url.host_str().ok_or(AzureInputError::InvalidUrl)?;
+
+    Ok(match url.port() {
+        Some(port) => format!("https://{}:{}", host, port),
+        None => format!("https://{}", host),
+    })
+}
+
+/// Check a deployment name as Azure allows it
+pub(crate) fn check_deployment(name: &str) -> Result<(), AzureInputError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AzureInputError::Missing);
+    }
+    if name.len() > MAX_DEPLOYMENT_NAME_LEN {
+        return Err(AzureInputError::DeploymentNameTooLong);
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(AzureInputError::InvalidDeploymentName);
+    }
+    Ok(())
+}
+
+/// Check that `key` looks like an Azure OpenAI API key
+pub(crate) fn check_api_key(key: &str) -> Result<(), AzureInputError> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(AzureInputError::Missing);
+    }
+    if key.starts_with("sk-") {
+        return Err(AzureInputError::OpenAIKey);
+    }
+    if key.len() < MIN_API_KEY_LEN || !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(AzureInputError::InvalidApiKey);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_is_reduced_to_scheme_and_host() {
+        for input in [
+            "https://myresource.openai.azure.com",
+            "  https://myresource.openai.azure.com/  ",
+            "myresource.openai.azure.com",
+            "https://myresource.openai.azure.com:443/openai/",
+            "https://MyResource.openai.azure.com/openai/deployments/gpt-4o/chat/completions?api-version=2024-10-21",
+            "wss://myresource.openai.azure.com/openai/realtime?deployment=gpt-4o-transcribe",
+        ] {
+            assert_eq!(
+                normalize_endpoint(input).as_deref(),
+                Ok("https://myresource.openai.azure.com"),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            normalize_endpoint("https://proxy.example.com:8443/openai").as_deref(),
+            Ok("https://proxy.example.com:8443")
+        );
+    }
+
+    #[test]
+    fn test_endpoint_errors() {
+        assert_eq!(normalize_endpoint(" "), Err(AzureInputError::Missing));
+        assert_eq!(
+            normalize_endpoint("http://myresource.openai.azure.com"),
+            Err(AzureInputError::NotHttps)
+        );
+        assert_eq!(
+            normalize_endpoint("my resource.openai.azure.com"),
+            Err(AzureInputError::InvalidUrl)
+        );
+        assert_eq!(
+            normalize_endpoint("ftp://myresource.openai.azure.com"),
+            Err(AzureInputError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn test_deployment_and_key_checks() {
+        assert_eq!(check_deployment("gpt-4o-transcribe"), Ok(()));
+        assert_eq!(check_deployment("gpt-5.1_eu"), Ok(()));
+        assert_eq!(check_deployment(""), Err(AzureInputError::Missing));
+        assert_eq!(
+            check_deployment("gpt 4o"),
+            Err(AzureInputError::InvalidDeploymentName)
+        );
+        assert_eq!(
+            check_deployment(&"a".repeat(65)),
+            Err(AzureInputError::DeploymentNameTooLong)
+        );
+
+        assert_eq!(check_api_key(&"0123abcd".repeat(4)), Ok(()));
+        assert_eq!(check_api_key(&"Ab1".repeat(28)), Ok(()));
+        assert_eq!(
+            check_api_key("sk-proj-abc"),
+            Err(AzureInputError::OpenAIKey)
+        );
+        assert_eq!(check_api_key("abc123"), Err(AzureInputError::InvalidApiKey));
+        assert_eq!(
+            check_api_key(&format!("{}-", "a".repeat(32))),
+            Err(AzureInputError::InvalidApiKey)
+        );
+    }
+}
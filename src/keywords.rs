@@ -0,0 +1,299 @@
+//! Keyword highlighting rules for the live transcript
+//!
+//! Users list keywords (names, projects, "action", "deadline") in Settings,
+//! one rule per line with an optional color, e.g. `deadline: red`. Matches
+//! are case-insensitive and whole-word, and may span several words.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::preferences;
+
+/// Highlight colors available for keyword rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HighlightColor {
+    #[default]
+    Yellow,
+    Green,
+    Blue,
+    Red,
+    Purple,
+    Orange,
+}
+
+impl HighlightColor {
+    /// All colors, in the order shown in Settings
+    pub(crate) const ALL: [HighlightColor; 6] = [
+        HighlightColor::Yellow,
+        HighlightColor::Green,
+        HighlightColor::Blue,
+        HighlightColor::Red,
+        HighlightColor::Purple,
+        HighlightColor::Orange,
+    ];
+
+    /// Parse a color name (case-insensitive)
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|color| color.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Lowercase color name as written in the rules list
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            HighlightColor::Yellow => "yellow",
+            HighlightColor::Green => "green",
+            HighlightColor::Blue => "blue",
+            HighlightColor::Red => "red",
+            HighlightColor::Purple => "purple",
+            HighlightColor::Orange => "orange",
+        }
+    }
+
+    /// RGB components (0.0 to 1.0)
+    pub(crate) fn rgb(self) -> (f64, f64, f64) {
+        match self {
+            HighlightColor::Yellow => (1.0, 0.84, 0.0),
+            HighlightColor::Green => (0.2, 0.78, 0.35),
+            HighlightColor::Blue => (0.0, 0.48, 1.0),
+            HighlightColor::Red => (1.0, 0.23, 0.19),
+            HighlightColor::Purple => (0.69, 0.32, 0.87),
+            HighlightColor::Orange => (1.0, 0.58, 0.0),
+        }
+    }
+}
+
+impl fmt::Display for HighlightColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A keyword to highlight and its color
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct KeywordRule {
+    pub keyword: String,
+    #[serde(default)]
+    pub color: HighlightColor,
+}
+
+/// Rules currently in effect, loaded from preferences on first use.
+///
+/// Cached because the live view is re-rendered on every transcript update.
+static ACTIVE_RULES: Lazy<RwLock<Vec<KeywordRule>>> =
+    Lazy::new(|| RwLock::new(preferences::get_keyword_rules()));
+
+/// Get the active keyword rules.
+pub(crate) fn active_rules() -> Vec<KeywordRule> {
+    ACTIVE_RULES
+        .read()
+        .map(|rules| rules.clone())
+        .unwrap_or_default()
+}
+
+/// Replace the active keyword rules and persist them.
+pub(crate) fn set_rules(rules: Vec<KeywordRule>) -> Result<(), preferences::PreferencesError> {
+    preferences::set_keyword_rules(rules.clone())?;
+    if let Ok(mut active) = ACTIVE_RULES.write() {
+        *active = rules;
+    }
+    Ok(())
+}
+
+/// Parse the rules list as edited in Settings.
+///
+/// Each non-empty line is `keyword` or `keyword: color`. Unknown colors
+/// fall back to the default; duplicate keywords keep the first rule.
+pub(crate) fn parse_rules(text: &str) -> Vec<KeywordRule> {
+    let mut rules: Vec<KeywordRule> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (keyword, color) = match line.rsplit_once(':') {
+            Some((keyword, color)) => match HighlightColor::from_name(color) {
+                Some(color) => (keyword.trim(), color),
+                None => (line, HighlightColor::default()),
+            },
+            None => (line, HighlightColor::default()),
+        };
+
+        if keyword.is_empty()
+            || rules
+                .iter()
+                .any(|rule| rule.keyword.to_lowercase() == keyword.to_lowercase())
+        {
+            continue;
+        }
+
+        rules.push(KeywordRule {
+            keyword: keyword.to_string(),
+            color,
+        });
+    }
+
+    rules
+}
+
+/// Format rules for editing, one per line.
+pub(crate) fn format_rules(rules: &[KeywordRule]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("{}: {}", rule.keyword, rule.color))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Find keyword matches in `text`.
+///
+/// Returns byte ranges `(start, end)` with the color of the matching rule,
+/// sorted by position. Overlapping matches prefer the earlier, then longer one.
+pub(crate) fn find_matches(
+    text: &str,
+    rules: &[KeywordRule],
+) -> Vec<(usize, usize, HighlightColor)> {
+    let keywords: Vec<(Vec<char>, HighlightColor)> = rules
+        .iter()
+        .map(|rule| (fold(rule.keyword.trim()), rule.color))
+        .filter(|(keyword, _)| !keyword.is_empty())
+        .collect();
+    if keywords.is_empty() {
+        return Vec::new();
+    }
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut matches = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let at_word_start = i == 0 || !is_word_char(chars[i - 1].1);
+        if !at_word_start || !is_word_char(chars[i].1) {
+            i += 1;
+            continue;
+        }
+
+        let longest = keywords
+            .iter()
+            .filter_map(|(keyword, color)| {
+                let len = match_len(&chars[i..], keyword)?;
+                let next = chars.get(i + len).map(|&(_, c)| c);
+                (!next.is_some_and(is_word_char)).then_some((len, *color))
+            })
+            .max_by_key(|&(len, _)| len);
+
+        match longest {
+            Some((len, color)) => {
+                let start = chars[i].0;
+                let end = chars.get(i + len).map_or(text.len(), |&(idx, _)| idx);
+                matches.push((start, end, color));
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+
+    matches
+}
+
+/// Number of characters of `chars` matched by the case-folded keyword, if it matches.
+fn match_len(chars: &[(usize, char)], keyword: &[char]) -> Option<usize> {
+    let mut folded = Vec::with_capacity(keyword.len());
+    for (count, &(_, ch)) in chars.iter().enumerate() {
+        if folded.len() >= keyword.len() {
+            return (folded == keyword).then_some(count);
+        }
+        folded.extend(ch.to_lowercase());
+        if !keyword.starts_with(&folded) {
+            return None;
+        }
+    }
+    (folded == keyword).then_some(chars.len())
+}
+
+/// Lowercase a keyword into characters for matching
+fn fold(text: &str) -> Vec<char> {
+    text.chars().flat_map(char::to_lowercase).collect()
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(keyword: &str, color: HighlightColor) -> KeywordRule {
+        KeywordRule {
+            keyword: keyword.to_string(),
+            color,
+        }
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = parse_rules("Sven\n\ndeadline: red\n  action item : Blue \nratio: 3:2\nsven");
+        assert_eq!(
+            rules,
+            vec![
+                rule("Sven", HighlightColor::Yellow),
+                rule("deadline", HighlightColor::Red),
+                rule("action item", HighlightColor::Blue),
+                rule("ratio: 3:2", HighlightColor::Yellow),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_rules_round_trips() {
+        let rules = vec![
+            rule("Vissper", HighlightColor::Green),
+            rule("deadline", HighlightColor::Red),
+        ];
+        assert_eq!(parse_rules(&format_rules(&rules)), rules);
+    }
+
+    #[test]
+    fn test_find_matches_whole_words_case_insensitive() {
+        let rules = vec![rule("action", HighlightColor::Orange)];
+        let text = "Action items: no transaction, one action.";
+        let matches = find_matches(text, &rules);
+        assert_eq!(
+            matches,
+            vec![
+                (0, 6, HighlightColor::Orange),
+                (34, 40, HighlightColor::Orange)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_prefers_longest_phrase() {
+        let rules = vec![
+            rule("project", HighlightColor::Blue),
+            rule("project phoenix", HighlightColor::Purple),
+        ];
+        let text = "The project phoenix deadline";
+        assert_eq!(
+            find_matches(text, &rules),
+            vec![(4, 19, HighlightColor::Purple)]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_non_ascii() {
+        let rules = vec![rule("møte", HighlightColor::Green)];
+        let text = "Neste MØTE er fredag";
+        let matches = find_matches(text, &rules);
+        assert_eq!(matches.len(), 1);
+        let (start, end, _) = matches[0];
+        assert_eq!(&text[start..end], "MØTE");
+    }
+}
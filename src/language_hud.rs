@@ -0,0 +1,158 @@
+//! Language HUD bubble
+//!
+//! When the language hotkey switches the transcription language, a dark
+//! rounded bubble with the new language's name shows near the bottom of the
+//! screen for a moment, like the volume HUD, so the switch can be confirmed
+//! without looking at the menu bar. It ignores the mouse and shows on every
+//! Space, above full-screen apps.
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{
+    NSBackingStoreType, NSColor, NSFont, NSScreen, NSTextAlignment, NSTextField, NSWindow,
+    NSWindowStyleMask,
+};
+use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::debug;
+
+use crate::app_context;
+
+/// Bubble size in points
+const HUD_WIDTH: f64 = 260.0;
+const HUD_HEIGHT: f64 = 64.0;
+
+/// Gap between the bottom of the screen and the bubble, in points
+const BOTTOM_GAP: f64 = 140.0;
+
+/// How long the bubble stays up
+const HUD_DURATION: Duration = Duration::from_millis(1200);
+
+/// NSStatusWindowLevel: above normal and floating windows
+const HUD_WINDOW_LEVEL: isize = 25;
+
+/// NSWindowCollectionBehaviorCanJoinAllSpaces | Stationary | FullScreenAuxiliary
+const ON_ALL_SPACES: usize = (1 << 0) | (1 << 4) | (1 << 8);
+
+/// Counts shown bubbles, so only the latest one hides itself
+static HUD_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// The bubble window, set once it is first shown (main thread only).
+fn hud_state() -> Option<&'static RefCell<Option<HudState>>> {
+    Some(&app_context::windows()?.language_hud)
+}
+
+/// Inner state holding the bubble window and its label.
+pub(crate) struct HudState {
+    window: Retained<NSWindow>,
+    label: Retained<NSTextField>,
+}
+
+/// Show `text` in the bubble for a moment, restarting the timer if it is
+/// already up.
+///
+/// Safe to call from any thread.
+pub(crate) fn show(text: &str) {
+    let text = text.to_string();
+    let generation = HUD_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    dispatch::Queue::main().exec_async(move || {
+        let Some(mtm) = MainThreadMarker::new() else {
+            return;
+        };
+        let Some(Ok(mut guard)) = hud_state().map(RefCell::try_borrow_mut) else {
+            return;
+        };
+        if guard.is_none() {
+            *guard = create_hud(mtm);
+        }
+        let Some(state) = guard.as_ref() else {
+            return;
+        };
+
+        // SAFETY: label and window are valid and used on the main thread
+        unsafe {
+            state.label.setStringValue(&NSString::from_str(&text));
+            state.window.orderFrontRegardless();
+        }
+        debug!("Language HUD shown");
+    });
+
+    dispatch::Queue::main().exec_after(HUD_DURATION, move || {
+        // A later switch restarted the timer
+        if HUD_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let Some(Ok(guard)) = hud_state().map(RefCell::try_borrow) else {
+            return;
+        };
+        if let Some(state) = guard.as_ref() {
+            state.window.orderOut(None);
+        }
+    });
+}
+
+/// Create the bubble, centered near the bottom of the main screen. It is
+/// kept and reused, and only ordered out when hidden.
+fn create_hud(mtm: MainThreadMarker) -> Option<HudState> {
+    let screen = NSScreen::mainScreen(mtm)?.visibleFrame();
+    let frame = NSRect::new(
+        NSPoint::new(
+            screen.origin.x + (screen.size.width - HUD_WIDTH) / 2.0,
+            screen.origin.y + BOTTOM_GAP,
+        ),
+        NSSize::new(HUD_WIDTH, HUD_HEIGHT),
+    );
+
+    // SAFETY: NSWindow and NSTextField creation and configuration on the
+    // main thread, with a valid frame, style mask and backing store type
+    unsafe {
+        let window = NSWindow::initWithContentRect_styleMask_backing_defer(
+            mtm.alloc(),
+            frame,
+            NSWindowStyleMask::Borderless,
+            NSBackingStoreType::NSBackingStoreBuffered,
+            false,
+        );
+        window.setReleasedWhenClosed(false);
+        window.setOpaque(false);
+        // The rounded content view draws the background
+        window.setBackgroundColor(Some(&NSColor::clearColor()));
+        window.setLevel(HUD_WINDOW_LEVEL);
+        let _: () = msg_send![&window, setIgnoresMouseEvents: true];
+        let _: () = msg_send![&window, setHasShadow: false];
+        let _: () = msg_send![&window, setCollectionBehavior: ON_ALL_SPACES];
+        let _: () = msg_send![&window, setHidesOnDeactivate: false];
+
+        let content_view = window.contentView()?;
+        let _: () = msg_send![&content_view, setWantsLayer: true];
+        let layer: *mut AnyObject = msg_send![&content_view, layer];
+        if !layer.is_null() {
+            let background = NSColor::colorWithRed_green_blue_alpha(0.1, 0.1, 0.1, 0.85);
+            let cg_color: *mut AnyObject = msg_send![&background, CGColor];
+            let _: () = msg_send![layer, setBackgroundColor: cg_color];
+            let _: () = msg_send![layer, setCornerRadius: 16.0f64];
+            let _: () = msg_send![layer, setMasksToBounds: true];
+        }
+
+        let label_frame = NSRect::new(
+            NSPoint::new(0.0, (HUD_HEIGHT - 30.0) / 2.0),
+            NSSize::new(HUD_WIDTH, 30.0),
+        );
+        let label: Retained<NSTextField> =
+            msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: label_frame];
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setTextColor(Some(&NSColor::whiteColor()));
+        label.setFont(Some(&NSFont::boldSystemFontOfSize(22.0)));
+        label.setAlignment(NSTextAlignment::Center);
+        content_view.addSubview(&label);
+
+        Some(HudState { window, label })
+    }
+}
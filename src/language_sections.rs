@@ -0,0 +1,130 @@
+//! Language sections in mixed-language transcripts
+//!
+//! When the language is switched during a recording, the session tags the
+//! segments committed after the switch with the new language. The
+//! transcript sent for polishing then carries a cue like
+//! `[language: Norwegian]` where each language starts, and the prompt asks
+//! for every section to be polished in the language it was spoken in rather
+//! than translated. Like time cues, they are stripped wherever the
+//! transcript itself is shown.
+
+use crate::response::language_code_to_name;
+use crate::transcription::TranscriptionSession;
+
+/// Start of a language cue, followed by the language's English name and `]`
+const CUE_PREFIX: &str = "[language: ";
+
+/// Instructions appended to the prompt when the transcript has language cues
+pub(crate) const PROMPT_INSTRUCTIONS: &str = r#"LANGUAGE SECTIONS: The speakers switched language during this recording. A cue like `[language: Norwegian]` marks where a language starts, and the transcript up to the next cue was spoken in that language. Polish each part in the language it was spoken in, even where it differs from the output language above, and do not translate between them. Write headings, summaries and other text of your own in the output language. Remove the cues from the output."#;
+
+/// Cues before the speech segments where the session's language changes
+///
+/// Expects increasing indexes, as `transcript_with_cues` asks for them. A
+/// session in a single language gets no cues.
+pub(crate) fn cues(session: &TranscriptionSession) -> impl FnMut(usize) -> Option<String> + '_ {
+    let switches = match session.language_switches.as_slice() {
+        [_] => &[],
+        switches => switches,
+    };
+    let mut switches = switches.iter().peekable();
+
+    move |index| {
+        // Only the last of several switches before a segment counts
+        let mut language = None;
+        while let Some((_, code)) = switches.next_if(|(start, _)| *start <= index) {
+            language = Some(code);
+        }
+        language
+            .filter(|code| !code.is_empty())
+            .map(|code| format!("{}{}]", CUE_PREFIX, language_code_to_name(code)))
+    }
+}
+
+/// `prompt` with the language section instructions if `transcript` has cues
+pub(crate) fn add_to_prompt(prompt: String, transcript: &str) -> String {
+    if transcript.contains(CUE_PREFIX) {
+        format!("{}\n\n{}", prompt, PROMPT_INSTRUCTIONS)
+    } else {
+        prompt
+    }
+}
+
+/// Remove language cues (and the space after each) from a transcript
+pub(crate) fn strip_cues(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(CUE_PREFIX) {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + CUE_PREFIX.len()..];
+        let name_len = after
+            .find(']')
+            .filter(|&end| end > 0 && !after[..end].contains('\n'));
+        match name_len {
+            Some(end) => {
+                let after_cue = &after[end + 1..];
+                rest = after_cue.strip_prefix(' ').unwrap_or(after_cue);
+            }
+            None => {
+                result.push_str(CUE_PREFIX);
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(segments: &[&str], languages: &[(usize, &str)]) -> TranscriptionSession {
+        TranscriptionSession {
+            committed_segments: segments.iter().map(|s| s.to_string()).collect(),
+            language_switches: languages
+                .iter()
+                .map(|(index, code)| (*index, code.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cues_mark_where_languages_start() {
+        let session = session(
+            &["Hello.", "\n\n=== MARKER 14:32 ===\n\n", "Hei.", "Ja."],
+            &[(0, "en"), (1, "no")],
+        );
+        let text = session.transcript_with_cues(cues(&session));
+        assert_eq!(
+            text,
+            "[language: English] Hello.\n\n=== MARKER 14:32 ===\n\n\
+             [language: Norwegian] Hei. Ja."
+        );
+        assert_eq!(strip_cues(&text), session.full_transcript());
+    }
+
+    #[test]
+    fn test_single_language_has_no_cues() {
+        let session = session(&["Hello."], &[(0, "en")]);
+        let text = session.transcript_with_cues(cues(&session));
+        assert_eq!(text, "Hello.");
+        assert_eq!(add_to_prompt("Prompt".to_string(), &text), "Prompt");
+    }
+
+    #[test]
+    fn test_prompt_instructions_follow_cues() {
+        let prompt = add_to_prompt("Prompt".to_string(), "[language: German] Hallo.");
+        assert!(prompt.starts_with("Prompt\n\nLANGUAGE SECTIONS"));
+    }
+
+    #[test]
+    fn test_strip_cues_leaves_other_brackets() {
+        assert_eq!(
+            strip_cues("[language: Danish] Hej [language: ] [language: x\n]"),
+            "Hej [language: ] [language: x\n]"
+        );
+    }
+}
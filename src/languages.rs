@@ -0,0 +1,181 @@
+//! Transcription languages and the short list shown in the menu bar
+//!
+//! The speech services transcribe many more languages than fit in a menu, so
+//! the menu bar's Languages submenu lists only the user's favorites, picked
+//! with its "Customize…" item and saved in preferences. The language in use
+//! is always listed, even if it isn't a favorite, so its checkmark stays
+//! visible. The language hotkey cycles through the favorites.
+
+use crate::preferences::{self, PreferencesError};
+
+/// A language the speech services transcribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Language {
+    /// ISO 639-1 code sent to the speech service, e.g. "no"
+    pub code: &'static str,
+    /// English name, used in polishing prompts
+    pub name: &'static str,
+    /// The language's name for itself, shown in menus
+    pub native_name: &'static str,
+}
+
+const fn language(code: &'static str, name: &'static str, native_name: &'static str) -> Language {
+    Language {
+        code,
+        name,
+        native_name,
+    }
+}
+
+/// Every language offered, in the order the Customize dialog lists them
+pub(crate) const LANGUAGES: &[Language] = &[
+    language("en", "English", "English"),
+    language("no", "Norwegian", "Norsk"),
+    language("da", "Danish", "Dansk"),
+    language("fi", "Finnish", "Suomi"),
+    language("de", "German", "Deutsch"),
+    language("sv", "Swedish", "Svenska"),
+    language("is", "Icelandic", "Íslenska"),
+    language("nl", "Dutch", "Nederlands"),
+    language("fr", "French", "Français"),
+    language("es", "Spanish", "Español"),
+    language("it", "Italian", "Italiano"),
+    language("pt", "Portuguese", "Português"),
+    language("pl", "Polish", "Polski"),
+    language("cs", "Czech", "Čeština"),
+    language("et", "Estonian", "Eesti"),
+    language("lv", "Latvian", "Latviešu"),
+    language("lt", "Lithuanian", "Lietuvių"),
+    language("uk", "Ukrainian", "Українська"),
+    language("tr", "Turkish", "Türkçe"),
+    language("ja", "Japanese", "日本語"),
+    language("zh", "Chinese", "中文"),
+];
+
+/// Most favorites in the menu, to keep it short
+const MAX_MENU_LANGUAGES: usize = 5;
+
+/// Favorites until the user customizes them, the languages the menu always had
+const DEFAULT_MENU_LANGUAGES: [&str; 5] = ["en", "no", "da", "fi", "de"];
+
+/// The language with `code`, if it is offered
+pub(crate) fn find(code: &str) -> Option<&'static Language> {
+    LANGUAGES.iter().find(|language| language.code == code)
+}
+
+/// The favorite languages, in the order they are listed
+pub(crate) fn favorites() -> Vec<&'static Language> {
+    saved_favorites(preferences::get_menu_languages().as_deref())
+}
+
+/// Languages to list in the menu bar: the favorites plus the one in use
+pub(crate) fn menu_languages() -> Vec<&'static Language> {
+    with_current(favorites(), &preferences::get_language_code())
+}
+
+/// The favorite after `current`, wrapping around; the first favorite if
+/// `current` isn't one, None if there are no favorites
+pub(crate) fn next_favorite(current: &str) -> Option<&'static Language> {
+    next_in(&favorites(), current)
+}
+
+/// Save the favorites listed in the menu bar; unknown codes are dropped and
+/// at most `MAX_MENU_LANGUAGES` are kept
+pub(crate) fn set_favorites(codes: &[&str]) -> Result<(), PreferencesError> {
+    let codes = known(codes.iter().copied())
+        .map(|language| language.code.to_string())
+        .collect();
+    preferences::set_menu_languages(codes)
+}
+
+/// Known languages among `codes`, without repeats, capped for the menu
+fn known<'a>(codes: impl Iterator<Item = &'a str>) -> impl Iterator<Item = &'static Language> {
+    let mut seen = Vec::new();
+    codes
+        .filter_map(find)
+        .filter(move |language| {
+            let new = !seen.contains(&language.code);
+            seen.push(language.code);
+            new
+        })
+        .take(MAX_MENU_LANGUAGES)
+}
+
+fn saved_favorites(saved: Option<&[String]>) -> Vec<&'static Language> {
+    match saved {
+        Some(codes) => known(codes.iter().map(String::as_str)).collect(),
+        None => known(DEFAULT_MENU_LANGUAGES.into_iter()).collect(),
+    }
+}
+
+fn with_current(mut list: Vec<&'static Language>, current: &str) -> Vec<&'static Language> {
+    if let Some(current) = find(current) {
+        if !list.contains(&current) {
+            list.push(current);
+        }
+    }
+    list
+}
+
+fn next_in(favorites: &[&'static Language], current: &str) -> Option<&'static Language> {
+    let next = favorites
+        .iter()
+        .position(|language| language.code == current)
+        .map_or(0, |index| (index + 1) % favorites.len());
+    favorites.get(next).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(list: &[&Language]) -> Vec<&'static str> {
+        list.iter().map(|language| language.code).collect()
+    }
+
+    #[test]
+    fn test_defaults_until_customized() {
+        assert_eq!(
+            codes(&saved_favorites(None)),
+            ["en", "no", "da", "fi", "de"]
+        );
+    }
+
+    #[test]
+    fn test_saved_favorites_are_cleaned_up() {
+        let saved: Vec<String> = ["sv", "xx", "sv", "en", "fr", "es", "it", "pl"]
+            .iter()
+            .map(|code| code.to_string())
+            .collect();
+        assert_eq!(
+            codes(&saved_favorites(Some(&saved))),
+            ["sv", "en", "fr", "es", "it"]
+        );
+    }
+
+    #[test]
+    fn test_language_in_use_is_always_listed() {
+        let favorites = vec![find("en").unwrap(), find("sv").unwrap()];
+        assert_eq!(
+            codes(&with_current(favorites.clone(), "de")),
+            ["en", "sv", "de"]
+        );
+        assert_eq!(codes(&with_current(favorites.clone(), "sv")), ["en", "sv"]);
+        // A code that isn't offered has no item to check
+        assert_eq!(codes(&with_current(favorites, "xx")), ["en", "sv"]);
+    }
+
+    #[test]
+    fn test_next_favorite_wraps_around() {
+        let favorites = vec![
+            find("en").unwrap(),
+            find("no").unwrap(),
+            find("de").unwrap(),
+        ];
+        assert_eq!(next_in(&favorites, "en").unwrap().code, "no");
+        assert_eq!(next_in(&favorites, "de").unwrap().code, "en");
+        // From a language that isn't a favorite, start over
+        assert_eq!(next_in(&favorites, "fr").unwrap().code, "en");
+        assert_eq!(next_in(&[], "en"), None);
+    }
+}
@@ -0,0 +1,210 @@
+//! Danish strings
+
+pub(super) const STRINGS: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.start_recording", "Start optagelse"),
+    ("menu.stop_recording", "Stop optagelse"),
+    ("menu.stop_no_polish", "Uden polering"),
+    ("menu.stop_basic_polish", "Grundlæggende polering"),
+    ("menu.stop_meeting_notes", "Mødenoter"),
+    ("menu.stop_follow_up_email", "Opfølgningsmail"),
+    ("menu.show_transcription", "Vis transskription"),
+    ("menu.print_transcript", "Udskriv transskription…"),
+    ("menu.screenshots", "Skærmbilleder"),
+    ("menu.capture_screen", "Tag billede af hele skærmen"),
+    ("menu.capture_area", "Tag billede af valgt område"),
+    (
+        "menu.capture_last_area",
+        "Tag billede af forrige område igen",
+    ),
+    ("menu.private_session", "Privat session"),
+    ("menu.practice_script", "Øv med manuskript…"),
+    ("menu.create_issues", "Opret sager fra handlingspunkter…"),
+    ("menu.compare_polish", "Sammenlign polerede versioner…"),
+    ("menu.demo_session", "Prøv en demosession"),
+    ("menu.workspace", "Arbejdsområde"),
+    ("menu.templates", "Skabeloner"),
+    ("menu.no_templates", "Tilføj skabeloner under Indstillinger → Arbejdsområder"),
+    ("menu.settings", "Indstillinger"),
+    ("menu.export_settings", "Eksportér indstillinger…"),
+    ("menu.import_settings", "Importér indstillinger…"),
+    ("menu.languages", "Sprog"),
+    ("menu.about", "Om Vissper"),
+    ("menu.report_problem", "Rapportér et problem…"),
+    ("menu.update_available", "Opdatering tilgængelig"),
+    ("menu.quit", "Afslut Vissper"),
+    ("menu.customize_languages", "Tilpas…"),
+    ("languages.customize_title", "Sprog i menuen"),
+    (
+        "languages.customize_message",
+        "Vælg op til 5 sprog, der vises i menulinjen. Det sprog, der bruges, vises altid.",
+    ),
+    ("languages.save", "Gem"),
+    ("languages.cancel", "Annuller"),
+    // Transcription overlay
+    ("overlay.live_transcription", "Live-transskription"),
+    ("overlay.polished_transcript", "Poleret transskription"),
+    ("overlay.meeting_notes", "Mødenoter"),
+    ("overlay.follow_up_email", "Opfølgningsmail"),
+    ("overlay.tab_live", "Live"),
+    ("overlay.tab_polished", "Poleret"),
+    ("overlay.tab_email", "E-mail"),
+    ("overlay.tab_debug", "Fejlfinding"),
+    ("overlay.debug_log", "Fejlfindingslog"),
+    ("overlay.debug_empty", "Ingen hændelser endnu. Udbyderens hændelser og forbindelsesændringer vises her, når en optagelse forbinder."),
+    ("overlay.close", "Luk transskriptionsvinduet"),
+    (
+        "overlay.insert_marker",
+        "Indsæt markør (Control + Shift + M)",
+    ),
+    ("overlay.add_note", "Tilføj note (Control + Shift + N)"),
+    ("overlay.stop_no_polish", "■ Rå"),
+    ("overlay.stop_no_polish_tooltip", "Stop uden polering (Control + Space)"),
+    ("overlay.stop_polish", "■ Polér"),
+    ("overlay.stop_polish_tooltip", "Stop og polér (Control + Shift + 1)"),
+    ("overlay.stop_meeting_notes", "■ Noter"),
+    ("overlay.stop_meeting_notes_tooltip", "Stop med mødenoter (Control + Shift + 2)"),
+    (
+        "overlay.note_placeholder",
+        "Tilføj en note, f.eks. \"Bob kom for sent\"",
+    ),
+    ("overlay.attendees", "Deltagere"),
+    (
+        "overlay.attendees_placeholder",
+        "Deltagere, adskilt med komma",
+    ),
+    ("overlay.recording", "Optager"),
+    ("overlay.recording_banner", "Vissper optager"),
+    ("overlay.processing", "Behandler"),
+    ("overlay.save", "Gem"),
+    (
+        "overlay.data_usage",
+        "Data sendt og modtaget under denne optagelse",
+    ),
+    ("overlay.listening", "Lytter..."),
+    (
+        "overlay.input_muted",
+        "Mikrofonen er slået fra — der optages intet",
+    ),
+    (
+        "overlay.input_volume_zero",
+        "Mikrofonens inputniveau er 0 — der optages intet",
+    ),
+    (
+        "overlay.applies_next_recording",
+        "Indstilling gemt — gælder fra næste optagelse",
+    ),
+    ("overlay.session_info", "Sessionsinfo"),
+    ("overlay.session_model", "Model"),
+    ("overlay.session_language", "Sprog"),
+    ("overlay.session_vad", "Stemmeregistrering"),
+    ("overlay.session_format", "Lydformat"),
+    ("overlay.session_noise", "Støjreduktion"),
+    ("overlay.session_not_set", "Ikke angivet"),
+    ("overlay.session_off", "Fra"),
+    ("overlay.polishing", "Polerer..."),
+    (
+        "overlay.click_to_polish",
+        "Klik for at generere poleret transskription...",
+    ),
+    (
+        "overlay.click_for_notes",
+        "Klik for at generere mødenoter...",
+    ),
+    (
+        "overlay.click_for_email",
+        "Klik for at skrive en opfølgningsmail...",
+    ),
+    ("overlay.click_to_generate", "Klik for at lave..."),
+    (
+        "overlay.generating_polish",
+        "⏳ Genererer poleret transskription...",
+    ),
+    ("overlay.generating_notes", "⏳ Genererer mødenoter..."),
+    ("overlay.generating_email", "⏳ Skriver opfølgningsmail..."),
+    ("overlay.generating", "⏳ Laver..."),
+    (
+        "overlay.stop_first",
+        "⚠️ Stop optagelsen først for at generere indhold til denne fane.",
+    ),
+    (
+        "overlay.no_polish_yet",
+        "📝 Intet poleret indhold endnu.\n\nTransskriptionen poleres, når du stopper \
+         optagelsen med 'Grundlæggende polering',\neller du kan klikke her efter \
+         optagelsen for at generere den.",
+    ),
+    (
+        "overlay.no_notes_yet",
+        "📋 Ingen mødenoter endnu.\n\nMødenoter genereres, når du stopper optagelsen \
+         med 'Mødenoter',\neller du kan klikke her efter optagelsen for at generere dem.",
+    ),
+    (
+        "overlay.no_email_yet",
+        "✉️ Ingen opfølgningsmail endnu.\n\nMailen skrives, når du stopper optagelsen \
+         med 'Opfølgningsmail',\neller du kan klikke her efter optagelsen for at skrive den.",
+    ),
+    (
+        "overlay.no_output_yet",
+        "📝 Intet lavet endnu.\n\nFanen udfyldes, når du stopper optagelsen \
+         med dens outputtilstand,\neller du kan klikke her efter optagelsen for at lave den.",
+    ),
+    ("overlay.window", "Vissper-transskription"),
+    ("overlay.header", "Transskriptionskontroller"),
+    ("overlay.view_selector", "Vælg transskriptionsvisning"),
+    ("overlay.recording_in_progress", "Optagelse i gang"),
+    ("overlay.save_transcript", "Gem transskriptionen i en fil"),
+    ("overlay.note_field", "Notetekst"),
+    ("overlay.attendees_field", "Deltagernavne"),
+    ("overlay.screenshots", "Skærmbilleder"),
+    (
+        "overlay.toggle_screenshots",
+        "Vis eller skjul skærmbillederne fra denne session",
+    ),
+    // Settings window
+    ("settings.title", "Vissper-indstillinger"),
+    ("settings.tab_general", "Generelt"),
+    ("settings.tab_highlights", "Fremhævninger"),
+    ("settings.tab_notes", "Noter"),
+    ("settings.tab_notifications", "Notifikationer"),
+    ("settings.tab_integrations", "Integrationer"),
+    ("settings.tab_issues", "Sager"),
+    ("settings.tab_export", "Eksport"),
+    ("settings.tab_storage", "Lagring"),
+    ("settings.tab_workspaces", "Arbejdsområder"),
+    ("settings.tab_screenshots", "Skærmbilleder"),
+    ("settings.tab_captions", "Undertekster"),
+    ("settings.tab_privacy", "Privatliv"),
+    ("settings.tab_audio", "Lyd"),
+    ("settings.tab_microphone", "Mikrofon"),
+    ("settings.tab_session", "Session"),
+    ("settings.tab_stats", "Statistik"),
+    (
+        "settings.overlay_transparency",
+        "Gennemsigtighed for overlay",
+    ),
+    ("settings.background", "Baggrund"),
+    ("settings.language_hotkey", "Genvejstast for sprog"),
+    (
+        "settings.language_hotkey_help",
+        "Skifter mellem favoritsprogene fra menuen Sprog",
+    ),
+    ("settings.stop_hotkeys", "Genvejstaster til stop"),
+    ("settings.stop_hotkeys_help", "Stopper optagelsen med en tilstand; de starter aldrig en"),
+    ("settings.dark", "Mørk"),
+    ("settings.light", "Lys"),
+    ("settings.interface_language", "Sprog i brugerfladen"),
+    (
+        "settings.restart_to_apply",
+        "Genstart Vissper for at anvende",
+    ),
+    (
+        "settings.transcript_location",
+        "Placering af transskriptioner",
+    ),
+    ("settings.screenshot_location", "Placering af skærmbilleder"),
+    ("settings.choose", "Vælg..."),
+    ("settings.reset_to_default", "Nulstil til standard"),
+    ("settings.transcription_provider", "Transskription"),
+    ("settings.polish_provider", "Polering"),
+    ("settings.polish_same", "Som transskription"),
+];
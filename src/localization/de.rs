@@ -0,0 +1,191 @@
+//! German strings
+
+pub(super) const STRINGS: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.start_recording", "Aufnahme starten"),
+    ("menu.stop_recording", "Aufnahme beenden"),
+    ("menu.stop_no_polish", "Ohne Überarbeitung"),
+    ("menu.stop_basic_polish", "Einfache Überarbeitung"),
+    ("menu.stop_meeting_notes", "Besprechungsnotizen"),
+    ("menu.stop_follow_up_email", "Follow-up-E-Mail"),
+    ("menu.show_transcription", "Transkription anzeigen"),
+    ("menu.print_transcript", "Transkription drucken…"),
+    ("menu.screenshots", "Bildschirmfotos"),
+    ("menu.capture_screen", "Ganzen Bildschirm aufnehmen"),
+    ("menu.capture_area", "Ausgewählten Bereich aufnehmen"),
+    ("menu.capture_last_area", "Letzten Bereich erneut aufnehmen"),
+    ("menu.private_session", "Private Sitzung"),
+    ("menu.practice_script", "Mit Skript üben…"),
+    ("menu.create_issues", "Tickets aus Aufgaben erstellen…"),
+    ("menu.compare_polish", "Überarbeitete Fassungen vergleichen…"),
+    ("menu.demo_session", "Demositzung ausprobieren"),
+    ("menu.workspace", "Arbeitsbereich"),
+    ("menu.templates", "Vorlagen"),
+    ("menu.no_templates", "Vorlagen unter Einstellungen → Arbeitsbereiche hinzufügen"),
+    ("menu.settings", "Einstellungen"),
+    ("menu.export_settings", "Einstellungen exportieren…"),
+    ("menu.import_settings", "Einstellungen importieren…"),
+    ("menu.languages", "Sprachen"),
+    ("menu.about", "Über Vissper"),
+    ("menu.report_problem", "Problem melden…"),
+    ("menu.update_available", "Update verfügbar"),
+    ("menu.quit", "Vissper beenden"),
+    ("menu.customize_languages", "Anpassen…"),
+    ("languages.customize_title", "Sprachen im Menü"),
+    ("languages.customize_message", "Wähle bis zu 5 Sprachen für die Menüleiste. Die verwendete Sprache wird immer angezeigt."),
+    ("languages.save", "Sichern"),
+    ("languages.cancel", "Abbrechen"),
+    // Transcription overlay
+    ("overlay.live_transcription", "Live-Transkription"),
+    ("overlay.polished_transcript", "Überarbeitete Transkription"),
+    ("overlay.meeting_notes", "Besprechungsnotizen"),
+    ("overlay.follow_up_email", "Follow-up-E-Mail"),
+    ("overlay.tab_live", "Live"),
+    ("overlay.tab_polished", "Überarbeitet"),
+    ("overlay.tab_email", "E-Mail"),
+    ("overlay.tab_debug", "Debug"),
+    ("overlay.debug_log", "Debug-Protokoll"),
+    ("overlay.debug_empty", "Noch keine Ereignisse. Ereignisse des Anbieters und Verbindungsänderungen erscheinen hier, sobald eine Aufnahme verbunden ist."),
+    ("overlay.close", "Transkriptionsfenster schließen"),
+    (
+        "overlay.insert_marker",
+        "Markierung einfügen (Control + Shift + M)",
+    ),
+    ("overlay.add_note", "Notiz hinzufügen (Control + Shift + N)"),
+    ("overlay.stop_no_polish", "■ Roh"),
+    ("overlay.stop_no_polish_tooltip", "Ohne Überarbeitung beenden (Control + Space)"),
+    ("overlay.stop_polish", "■ Überarbeiten"),
+    ("overlay.stop_polish_tooltip", "Beenden und überarbeiten (Control + Shift + 1)"),
+    ("overlay.stop_meeting_notes", "■ Notizen"),
+    ("overlay.stop_meeting_notes_tooltip", "Mit Besprechungsnotizen beenden (Control + Shift + 2)"),
+    (
+        "overlay.note_placeholder",
+        "Notiz hinzufügen, z. B. „Bob kam später dazu“",
+    ),
+    ("overlay.attendees", "Teilnehmende"),
+    ("overlay.attendees_placeholder", "Teilnehmende, durch Kommas getrennt"),
+    ("overlay.recording", "Aufnahme"),
+    ("overlay.recording_banner", "Vissper nimmt auf"),
+    ("overlay.processing", "Verarbeitung"),
+    ("overlay.save", "Sichern"),
+    ("overlay.data_usage", "Gesendete und empfangene Daten dieser Aufnahme"),
+    ("overlay.listening", "Höre zu..."),
+    ("overlay.input_muted", "Mikrofon ist stummgeschaltet — es wird nichts aufgenommen"),
+    ("overlay.input_volume_zero", "Eingangslautstärke des Mikrofons ist 0 — es wird nichts aufgenommen"),
+    ("overlay.applies_next_recording", "Einstellung gespeichert — gilt ab der nächsten Aufnahme"),
+    ("overlay.session_info", "Sitzungsinfo"),
+    ("overlay.session_model", "Modell"),
+    ("overlay.session_language", "Sprache"),
+    ("overlay.session_vad", "Spracherkennung"),
+    ("overlay.session_format", "Audioformat"),
+    ("overlay.session_noise", "Rauschunterdrückung"),
+    ("overlay.session_not_set", "Nicht festgelegt"),
+    ("overlay.session_off", "Aus"),
+    ("overlay.polishing", "Wird überarbeitet..."),
+    (
+        "overlay.click_to_polish",
+        "Klicken, um eine überarbeitete Transkription zu erstellen...",
+    ),
+    (
+        "overlay.click_for_notes",
+        "Klicken, um Besprechungsnotizen zu erstellen...",
+    ),
+    (
+        "overlay.click_for_email",
+        "Klicken, um eine Follow-up-E-Mail zu entwerfen...",
+    ),
+    ("overlay.click_to_generate", "Klicken zum Erstellen..."),
+    (
+        "overlay.generating_polish",
+        "⏳ Überarbeitete Transkription wird erstellt...",
+    ),
+    (
+        "overlay.generating_notes",
+        "⏳ Besprechungsnotizen werden erstellt...",
+    ),
+    (
+        "overlay.generating_email",
+        "⏳ Follow-up-E-Mail wird entworfen...",
+    ),
+    ("overlay.generating", "⏳ Wird erstellt..."),
+    (
+        "overlay.stop_first",
+        "⚠️ Beende zuerst die Aufnahme, um Inhalte für diesen Tab zu erstellen.",
+    ),
+    (
+        "overlay.no_polish_yet",
+        "📝 Noch keine überarbeitete Fassung.\n\nDie Transkription wird überarbeitet, \
+         wenn du die Aufnahme mit „Einfache Überarbeitung“ beendest,\noder du klickst \
+         nach der Aufnahme hier, um sie zu erstellen.",
+    ),
+    (
+        "overlay.no_notes_yet",
+        "📋 Noch keine Besprechungsnotizen.\n\nBesprechungsnotizen werden erstellt, \
+         wenn du die Aufnahme mit „Besprechungsnotizen“ beendest,\noder du klickst \
+         nach der Aufnahme hier, um sie zu erstellen.",
+    ),
+    (
+        "overlay.no_email_yet",
+        "✉️ Noch keine Follow-up-E-Mail.\n\nDie E-Mail wird entworfen, wenn du die \
+         Aufnahme mit „Follow-up-E-Mail“ beendest,\noder du klickst nach der Aufnahme \
+         hier, um sie zu entwerfen.",
+    ),
+    (
+        "overlay.no_output_yet",
+        "📝 Noch nichts erstellt.\n\nDieser Tab wird gefüllt, wenn du die Aufnahme \
+         mit seinem Ausgabemodus beendest,\noder du klickst nach der Aufnahme hier, um ihn zu erstellen.",
+    ),
+    ("overlay.window", "Vissper-Transkription"),
+    ("overlay.header", "Transkriptionssteuerung"),
+    ("overlay.view_selector", "Transkriptionsansicht auswählen"),
+    ("overlay.recording_in_progress", "Aufnahme läuft"),
+    ("overlay.save_transcript", "Transkription in Datei sichern"),
+    ("overlay.note_field", "Notiztext"),
+    ("overlay.attendees_field", "Namen der Teilnehmenden"),
+    ("overlay.screenshots", "Bildschirmfotos"),
+    ("overlay.toggle_screenshots", "Bildschirmfotos dieser Sitzung ein- oder ausblenden"),
+    // Settings window
+    ("settings.title", "Vissper-Einstellungen"),
+    ("settings.tab_general", "Allgemein"),
+    ("settings.tab_highlights", "Hervorhebungen"),
+    ("settings.tab_notes", "Notizen"),
+    ("settings.tab_notifications", "Mitteilungen"),
+    ("settings.tab_integrations", "Integrationen"),
+    ("settings.tab_issues", "Tickets"),
+    ("settings.tab_export", "Export"),
+    ("settings.tab_storage", "Speicher"),
+    ("settings.tab_workspaces", "Arbeitsbereiche"),
+    ("settings.tab_screenshots", "Bildschirmfotos"),
+    ("settings.tab_captions", "Untertitel"),
+    ("settings.tab_privacy", "Datenschutz"),
+    ("settings.tab_audio", "Audio"),
+    ("settings.tab_microphone", "Mikrofon"),
+    ("settings.tab_session", "Sitzung"),
+    ("settings.tab_stats", "Statistik"),
+    ("settings.overlay_transparency", "Transparenz des Overlays"),
+    ("settings.background", "Hintergrund"),
+    ("settings.language_hotkey", "Sprach-Tastenkürzel"),
+    ("settings.language_hotkey_help", "Wechselt zwischen den Lieblingssprachen aus dem Menü Sprachen"),
+    ("settings.stop_hotkeys", "Tastenkürzel zum Beenden"),
+    ("settings.stop_hotkeys_help", "Beenden die Aufnahme mit einem Modus, starten aber nie eine"),
+    ("settings.dark", "Dunkel"),
+    ("settings.light", "Hell"),
+    ("settings.interface_language", "Sprache der Oberfläche"),
+    (
+        "settings.restart_to_apply",
+        "Zum Übernehmen Vissper neu starten",
+    ),
+    (
+        "settings.transcript_location",
+        "Speicherort für Transkripte",
+    ),
+    (
+        "settings.screenshot_location",
+        "Speicherort für Bildschirmfotos",
+    ),
+    ("settings.choose", "Auswählen..."),
+    ("settings.reset_to_default", "Zurücksetzen"),
+    ("settings.transcription_provider", "Transkription"),
+    ("settings.polish_provider", "Überarbeitung"),
+    ("settings.polish_same", "Wie Transkription"),
+];
@@ -0,0 +1,200 @@
+//! English strings; every other table translates these keys
+
+pub(super) const STRINGS: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.start_recording", "Start Recording"),
+    ("menu.stop_recording", "Stop Recording"),
+    ("menu.stop_no_polish", "No polishing"),
+    ("menu.stop_basic_polish", "Basic polishing"),
+    ("menu.stop_meeting_notes", "Meeting notes"),
+    ("menu.stop_follow_up_email", "Follow-up email"),
+    ("menu.show_transcription", "Show Transcription"),
+    ("menu.print_transcript", "Print Transcript…"),
+    ("menu.screenshots", "Screenshots"),
+    ("menu.capture_screen", "Capture Entire Screen"),
+    ("menu.capture_area", "Capture Selected Area"),
+    ("menu.capture_last_area", "Capture Last Area Again"),
+    ("menu.private_session", "Private Session"),
+    ("menu.practice_script", "Practice with Script…"),
+    ("menu.create_issues", "Create Issues from Action Items…"),
+    ("menu.compare_polish", "Compare Polish Outputs…"),
+    ("menu.demo_session", "Try Demo Session"),
+    ("menu.workspace", "Workspace"),
+    ("menu.templates", "Templates"),
+    ("menu.no_templates", "Add templates in Settings → Workspaces"),
+    ("menu.settings", "Settings"),
+    ("menu.export_settings", "Export Settings…"),
+    ("menu.import_settings", "Import Settings…"),
+    ("menu.languages", "Languages"),
+    ("menu.about", "About Vissper"),
+    ("menu.report_problem", "Report a Problem…"),
+    ("menu.update_available", "Update Available"),
+    ("menu.quit", "Quit Vissper"),
+    ("menu.customize_languages", "Customize…"),
+    ("languages.customize_title", "Languages in the Menu"),
+    (
+        "languages.customize_message",
+        "Pick up to 5 languages to list in the menu bar. The language in use is always listed.",
+    ),
+    ("languages.save", "Save"),
+    ("languages.cancel", "Cancel"),
+    // Transcription overlay
+    ("overlay.live_transcription", "Live Transcription"),
+    ("overlay.polished_transcript", "Polished Transcript"),
+    ("overlay.meeting_notes", "Meeting Notes"),
+    ("overlay.follow_up_email", "Follow-up Email"),
+    ("overlay.tab_live", "Live"),
+    ("overlay.tab_polished", "Polished"),
+    ("overlay.tab_email", "Email"),
+    ("overlay.tab_debug", "Debug"),
+    ("overlay.debug_log", "Debug Log"),
+    ("overlay.debug_empty", "No events yet. Provider events and connection changes show here once a recording connects."),
+    ("overlay.close", "Close transcription window"),
+    (
+        "overlay.insert_marker",
+        "Insert marker (Control + Shift + M)",
+    ),
+    ("overlay.add_note", "Add note (Control + Shift + N)"),
+    ("overlay.stop_no_polish", "■ Raw"),
+    ("overlay.stop_no_polish_tooltip", "Stop without polishing (Control + Space)"),
+    ("overlay.stop_polish", "■ Polish"),
+    ("overlay.stop_polish_tooltip", "Stop and polish (Control + Shift + 1)"),
+    ("overlay.stop_meeting_notes", "■ Notes"),
+    ("overlay.stop_meeting_notes_tooltip", "Stop with meeting notes (Control + Shift + 2)"),
+    (
+        "overlay.note_placeholder",
+        "Add a note, e.g. \"Bob joined late\"",
+    ),
+    ("overlay.attendees", "Attendees"),
+    (
+        "overlay.attendees_placeholder",
+        "Attendees, separated by commas",
+    ),
+    ("overlay.recording", "Recording"),
+    ("overlay.recording_banner", "Vissper is recording"),
+    ("overlay.processing", "Processing"),
+    ("overlay.save", "Save"),
+    (
+        "overlay.data_usage",
+        "Data sent and received by this recording",
+    ),
+    ("overlay.listening", "Listening..."),
+    (
+        "overlay.input_muted",
+        "Microphone is muted — nothing is being recorded",
+    ),
+    (
+        "overlay.input_volume_zero",
+        "Microphone input volume is 0 — nothing is being recorded",
+    ),
+    (
+        "overlay.applies_next_recording",
+        "Setting saved — applies to the next recording",
+    ),
+    ("overlay.session_info", "Session info"),
+    ("overlay.session_model", "Model"),
+    ("overlay.session_language", "Language"),
+    ("overlay.session_vad", "Voice detection"),
+    ("overlay.session_format", "Audio format"),
+    ("overlay.session_noise", "Noise reduction"),
+    ("overlay.session_not_set", "Not set"),
+    ("overlay.session_off", "Off"),
+    ("overlay.polishing", "Polishing..."),
+    (
+        "overlay.click_to_polish",
+        "Click to generate polished transcript...",
+    ),
+    (
+        "overlay.click_for_notes",
+        "Click to generate meeting notes...",
+    ),
+    (
+        "overlay.click_for_email",
+        "Click to draft a follow-up email...",
+    ),
+    ("overlay.click_to_generate", "Click to generate..."),
+    (
+        "overlay.generating_polish",
+        "⏳ Generating polished transcript...",
+    ),
+    ("overlay.generating_notes", "⏳ Generating meeting notes..."),
+    ("overlay.generating_email", "⏳ Drafting follow-up email..."),
+    ("overlay.generating", "⏳ Generating..."),
+    (
+        "overlay.stop_first",
+        "⚠️ Stop recording first to generate content for this tab.",
+    ),
+    (
+        "overlay.no_polish_yet",
+        "📝 No polished content yet.\n\nThe transcript will be polished when you stop \
+         recording with 'Basic Polishing',\nor you can click here after recording to \
+         generate it.",
+    ),
+    (
+        "overlay.no_notes_yet",
+        "📋 No meeting notes yet.\n\nMeeting notes will be generated when you stop \
+         recording with 'Meeting Notes',\nor you can click here after recording to \
+         generate them.",
+    ),
+    (
+        "overlay.no_email_yet",
+        "✉️ No follow-up email yet.\n\nThe email will be drafted when you stop \
+         recording with 'Follow-up Email',\nor you can click here after recording to \
+         draft it.",
+    ),
+    (
+        "overlay.no_output_yet",
+        "📝 Nothing generated yet.\n\nThis tab is filled when you stop recording \
+         with its output mode,\nor you can click here after recording to generate it.",
+    ),
+    ("overlay.window", "Vissper transcription"),
+    ("overlay.header", "Transcription controls"),
+    ("overlay.view_selector", "Transcription view selector"),
+    ("overlay.recording_in_progress", "Recording in progress"),
+    ("overlay.save_transcript", "Save transcript to file"),
+    ("overlay.note_field", "Note text"),
+    ("overlay.attendees_field", "Attendee names"),
+    ("overlay.screenshots", "Screenshots"),
+    (
+        "overlay.toggle_screenshots",
+        "Show or hide this session's screenshots",
+    ),
+    // Settings window
+    ("settings.title", "Vissper Settings"),
+    ("settings.tab_general", "General"),
+    ("settings.tab_highlights", "Highlights"),
+    ("settings.tab_notes", "Notes"),
+    ("settings.tab_notifications", "Notifications"),
+    ("settings.tab_integrations", "Integrations"),
+    ("settings.tab_issues", "Issues"),
+    ("settings.tab_export", "Export"),
+    ("settings.tab_storage", "Storage"),
+    ("settings.tab_workspaces", "Workspaces"),
+    ("settings.tab_screenshots", "Screenshots"),
+    ("settings.tab_captions", "Captions"),
+    ("settings.tab_privacy", "Privacy"),
+    ("settings.tab_audio", "Audio"),
+    ("settings.tab_microphone", "Microphone"),
+    ("settings.tab_session", "Session"),
+    ("settings.tab_stats", "Stats"),
+    ("settings.overlay_transparency", "Overlay Transparency"),
+    ("settings.background", "Background"),
+    ("settings.language_hotkey", "Language Hotkey"),
+    (
+        "settings.language_hotkey_help",
+        "Cycles the favorite languages from the Languages menu",
+    ),
+    ("settings.stop_hotkeys", "Stop Hotkeys"),
+    ("settings.stop_hotkeys_help", "Stop the recording with a mode; they never start one"),
+    ("settings.dark", "Dark"),
+    ("settings.light", "Light"),
+    ("settings.interface_language", "Interface Language"),
+    ("settings.restart_to_apply", "Restart Vissper to apply"),
+    ("settings.transcript_location", "Transcript Location"),
+    ("settings.screenshot_location", "Screenshot Location"),
+    ("settings.choose", "Choose..."),
+    ("settings.reset_to_default", "Reset to Default"),
+    ("settings.transcription_provider", "Transcription"),
+    ("settings.polish_provider", "Polishing"),
+    ("settings.polish_same", "Same as transcription"),
+];
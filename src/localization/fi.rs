@@ -0,0 +1,212 @@
+//! Finnish strings
+
+pub(super) const STRINGS: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.start_recording", "Aloita tallennus"),
+    ("menu.stop_recording", "Lopeta tallennus"),
+    ("menu.stop_no_polish", "Ei viimeistelyä"),
+    ("menu.stop_basic_polish", "Perusviimeistely"),
+    ("menu.stop_meeting_notes", "Kokousmuistiinpanot"),
+    ("menu.stop_follow_up_email", "Jatkoviesti"),
+    ("menu.show_transcription", "Näytä litterointi"),
+    ("menu.print_transcript", "Tulosta litterointi…"),
+    ("menu.screenshots", "Kuvakaappaukset"),
+    ("menu.capture_screen", "Kaappaa koko näyttö"),
+    ("menu.capture_area", "Kaappaa valittu alue"),
+    ("menu.capture_last_area", "Kaappaa edellinen alue uudelleen"),
+    ("menu.private_session", "Yksityinen istunto"),
+    ("menu.practice_script", "Harjoittele käsikirjoituksella…"),
+    ("menu.create_issues", "Luo tehtävät toimenpiteistä…"),
+    ("menu.compare_polish", "Vertaa viimeisteltyjä versioita…"),
+    ("menu.demo_session", "Kokeile esittelyistuntoa"),
+    ("menu.workspace", "Työtila"),
+    ("menu.templates", "Mallit"),
+    ("menu.no_templates", "Lisää malleja kohdassa Asetukset → Työtilat"),
+    ("menu.settings", "Asetukset"),
+    ("menu.export_settings", "Vie asetukset…"),
+    ("menu.import_settings", "Tuo asetukset…"),
+    ("menu.languages", "Kielet"),
+    ("menu.about", "Tietoja Vissperistä"),
+    ("menu.report_problem", "Ilmoita ongelmasta…"),
+    ("menu.update_available", "Päivitys saatavilla"),
+    ("menu.quit", "Lopeta Vissper"),
+    ("menu.customize_languages", "Muokkaa…"),
+    ("languages.customize_title", "Valikon kielet"),
+    (
+        "languages.customize_message",
+        "Valitse enintään 5 kieltä valikkoriville. Käytössä oleva kieli näkyy aina.",
+    ),
+    ("languages.save", "Tallenna"),
+    ("languages.cancel", "Peruuta"),
+    // Transcription overlay
+    ("overlay.live_transcription", "Reaaliaikainen litterointi"),
+    ("overlay.polished_transcript", "Viimeistelty litterointi"),
+    ("overlay.meeting_notes", "Kokousmuistiinpanot"),
+    ("overlay.follow_up_email", "Jatkoviesti"),
+    ("overlay.tab_live", "Live"),
+    ("overlay.tab_polished", "Viimeistelty"),
+    ("overlay.tab_email", "Sähköposti"),
+    ("overlay.tab_debug", "Vianetsintä"),
+    ("overlay.debug_log", "Vianetsintäloki"),
+    ("overlay.debug_empty", "Ei vielä tapahtumia. Palveluntarjoajan tapahtumat ja yhteyden muutokset näkyvät tässä, kun tallennus yhdistää."),
+    ("overlay.close", "Sulje litterointi-ikkuna"),
+    (
+        "overlay.insert_marker",
+        "Lisää merkki (Control + Shift + M)",
+    ),
+    (
+        "overlay.add_note",
+        "Lisää muistiinpano (Control + Shift + N)",
+    ),
+    ("overlay.stop_no_polish", "■ Raaka"),
+    ("overlay.stop_no_polish_tooltip", "Lopeta ilman viimeistelyä (Control + Space)"),
+    ("overlay.stop_polish", "■ Viimeistele"),
+    ("overlay.stop_polish_tooltip", "Lopeta ja viimeistele (Control + Shift + 1)"),
+    ("overlay.stop_meeting_notes", "■ Muistiot"),
+    ("overlay.stop_meeting_notes_tooltip", "Lopeta ja luo kokousmuistio (Control + Shift + 2)"),
+    (
+        "overlay.note_placeholder",
+        "Lisää muistiinpano, esim. \"Bob liittyi myöhässä\"",
+    ),
+    ("overlay.attendees", "Osallistujat"),
+    (
+        "overlay.attendees_placeholder",
+        "Osallistujat pilkuilla erotettuina",
+    ),
+    ("overlay.recording", "Tallennetaan"),
+    ("overlay.recording_banner", "Vissper tallentaa"),
+    ("overlay.processing", "Käsitellään"),
+    ("overlay.save", "Tallenna"),
+    (
+        "overlay.data_usage",
+        "Tämän tallennuksen lähettämä ja vastaanottama data",
+    ),
+    ("overlay.listening", "Kuunnellaan..."),
+    (
+        "overlay.input_muted",
+        "Mikrofoni on mykistetty — mitään ei tallenneta",
+    ),
+    (
+        "overlay.input_volume_zero",
+        "Mikrofonin tulotaso on 0 — mitään ei tallenneta",
+    ),
+    (
+        "overlay.applies_next_recording",
+        "Asetus tallennettu — koskee seuraavaa tallennusta",
+    ),
+    ("overlay.session_info", "Istunnon tiedot"),
+    ("overlay.session_model", "Malli"),
+    ("overlay.session_language", "Kieli"),
+    ("overlay.session_vad", "Puheentunnistus"),
+    ("overlay.session_format", "Äänimuoto"),
+    ("overlay.session_noise", "Kohinanvaimennus"),
+    ("overlay.session_not_set", "Ei asetettu"),
+    ("overlay.session_off", "Pois"),
+    ("overlay.polishing", "Viimeistellään..."),
+    (
+        "overlay.click_to_polish",
+        "Luo viimeistelty litterointi napsauttamalla...",
+    ),
+    (
+        "overlay.click_for_notes",
+        "Luo kokousmuistiinpanot napsauttamalla...",
+    ),
+    (
+        "overlay.click_for_email",
+        "Luonnostele jatkoviesti napsauttamalla...",
+    ),
+    ("overlay.click_to_generate", "Luo napsauttamalla..."),
+    (
+        "overlay.generating_polish",
+        "⏳ Luodaan viimeisteltyä litterointia...",
+    ),
+    (
+        "overlay.generating_notes",
+        "⏳ Luodaan kokousmuistiinpanoja...",
+    ),
+    (
+        "overlay.generating_email",
+        "⏳ Luonnostellaan jatkoviestiä...",
+    ),
+    ("overlay.generating", "⏳ Luodaan..."),
+    (
+        "overlay.stop_first",
+        "⚠️ Lopeta tallennus ensin, jotta tälle välilehdelle voidaan luoda sisältöä.",
+    ),
+    (
+        "overlay.no_polish_yet",
+        "📝 Viimeisteltyä sisältöä ei vielä ole.\n\nLitterointi viimeistellään, kun \
+         lopetat tallennuksen valinnalla 'Perusviimeistely',\ntai voit luoda sen \
+         napsauttamalla tätä tallennuksen jälkeen.",
+    ),
+    (
+        "overlay.no_notes_yet",
+        "📋 Kokousmuistiinpanoja ei vielä ole.\n\nKokousmuistiinpanot luodaan, kun \
+         lopetat tallennuksen valinnalla 'Kokousmuistiinpanot',\ntai voit luoda ne \
+         napsauttamalla tätä tallennuksen jälkeen.",
+    ),
+    (
+        "overlay.no_email_yet",
+        "✉️ Jatkoviestiä ei vielä ole.\n\nJatkoviesti luonnostellaan, kun lopetat \
+         tallennuksen valinnalla 'Jatkoviesti',\ntai voit luonnostella sen \
+         napsauttamalla tätä tallennuksen jälkeen.",
+    ),
+    (
+        "overlay.no_output_yet",
+        "📝 Mitään ei ole vielä luotu.\n\nVälilehti täytetään, kun lopetat tallennuksen \
+         sen tulostustilalla,\ntai voit luoda sen napsauttamalla tätä tallennuksen jälkeen.",
+    ),
+    ("overlay.window", "Vissper-litterointi"),
+    ("overlay.header", "Litteroinnin säätimet"),
+    ("overlay.view_selector", "Litterointinäkymän valinta"),
+    ("overlay.recording_in_progress", "Tallennus käynnissä"),
+    ("overlay.save_transcript", "Tallenna litterointi tiedostoon"),
+    ("overlay.note_field", "Muistiinpanon teksti"),
+    ("overlay.attendees_field", "Osallistujien nimet"),
+    ("overlay.screenshots", "Kuvakaappaukset"),
+    (
+        "overlay.toggle_screenshots",
+        "Näytä tai piilota tämän istunnon kuvakaappaukset",
+    ),
+    // Settings window
+    ("settings.title", "Vissperin asetukset"),
+    ("settings.tab_general", "Yleiset"),
+    ("settings.tab_highlights", "Korostukset"),
+    ("settings.tab_notes", "Muistiinpanot"),
+    ("settings.tab_notifications", "Ilmoitukset"),
+    ("settings.tab_integrations", "Integraatiot"),
+    ("settings.tab_issues", "Tehtävät"),
+    ("settings.tab_export", "Vienti"),
+    ("settings.tab_storage", "Tallennus"),
+    ("settings.tab_workspaces", "Työtilat"),
+    ("settings.tab_screenshots", "Kuvakaappaukset"),
+    ("settings.tab_captions", "Tekstitys"),
+    ("settings.tab_privacy", "Yksityisyys"),
+    ("settings.tab_audio", "Ääni"),
+    ("settings.tab_microphone", "Mikrofoni"),
+    ("settings.tab_session", "Istunto"),
+    ("settings.tab_stats", "Tilastot"),
+    ("settings.overlay_transparency", "Ikkunan läpinäkyvyys"),
+    ("settings.background", "Tausta"),
+    ("settings.language_hotkey", "Kielen pikanäppäin"),
+    (
+        "settings.language_hotkey_help",
+        "Vaihtaa Kielet-valikon suosikkikielten välillä",
+    ),
+    ("settings.stop_hotkeys", "Lopetuksen pikanäppäimet"),
+    ("settings.stop_hotkeys_help", "Lopettavat tallennuksen valitulla tilalla, eivätkä koskaan aloita uutta"),
+    ("settings.dark", "Tumma"),
+    ("settings.light", "Vaalea"),
+    ("settings.interface_language", "Käyttöliittymän kieli"),
+    (
+        "settings.restart_to_apply",
+        "Käynnistä Vissper uudelleen, jotta muutos tulee voimaan",
+    ),
+    ("settings.transcript_location", "Litterointien sijainti"),
+    ("settings.screenshot_location", "Kuvakaappausten sijainti"),
+    ("settings.choose", "Valitse..."),
+    ("settings.reset_to_default", "Palauta oletus"),
+    ("settings.transcription_provider", "Litterointi"),
+    ("settings.polish_provider", "Viimeistely"),
+    ("settings.polish_same", "Sama kuin litterointi"),
+];
@@ -0,0 +1,168 @@
+//! User interface translations
+//!
+//! Menu titles, Settings labels and overlay text are looked up by key with
+//! [`tr`] instead of being written inline. Each language has a string table in
+//! its own file; a key missing from a table falls back to English.
+//!
+//! The interface language is read from preferences once per launch, so a
+//! change in Settings applies the next time Vissper starts. The languages
+//! match those offered for transcription.
+
+mod da;
+mod de;
+mod en;
+mod fi;
+mod no;
+
+use once_cell::sync::Lazy;
+
+use crate::preferences;
+
+/// A language the user interface is translated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum UiLanguage {
+    #[default]
+    English,
+    Norwegian,
+    Danish,
+    Finnish,
+    German,
+}
+
+impl UiLanguage {
+    /// All interface languages in the order shown in Settings
+    pub(crate) const ALL: [UiLanguage; 5] = [
+        UiLanguage::English,
+        UiLanguage::Norwegian,
+        UiLanguage::Danish,
+        UiLanguage::Finnish,
+        UiLanguage::German,
+    ];
+
+    /// Language code, the same as used for transcription
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            UiLanguage::English => "en",
+            UiLanguage::Norwegian => "no",
+            UiLanguage::Danish => "da",
+            UiLanguage::Finnish => "fi",
+            UiLanguage::German => "de",
+        }
+    }
+
+    /// Parse a language code, returning `None` for unsupported languages
+    pub(crate) fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+
+    /// The language's name for itself, shown in the Settings selector
+    pub(crate) fn native_name(self) -> &'static str {
+        match self {
+            UiLanguage::English => "English",
+            UiLanguage::Norwegian => "Norsk",
+            UiLanguage::Danish => "Dansk",
+            UiLanguage::Finnish => "Suomi",
+            UiLanguage::German => "Deutsch",
+        }
+    }
+
+    fn strings(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            UiLanguage::English => en::STRINGS,
+            UiLanguage::Norwegian => no::STRINGS,
+            UiLanguage::Danish => da::STRINGS,
+            UiLanguage::Finnish => fi::STRINGS,
+            UiLanguage::German => de::STRINGS,
+        }
+    }
+
+    /// Translation of `key` in this language, falling back to English
+    fn translate(self, key: &'static str) -> &'static str {
+        lookup(self.strings(), key)
+            .or_else(|| lookup(en::STRINGS, key))
+            .unwrap_or(key)
+    }
+}
+
+/// Interface language chosen in Settings when the app started
+static CURRENT: Lazy<UiLanguage> =
+    Lazy::new(|| UiLanguage::from_code(&preferences::get_ui_language()).unwrap_or_default());
+
+/// The interface language for this launch
+pub(crate) fn current() -> UiLanguage {
+    *CURRENT
+}
+
+/// Translate a user interface string into the current language.
+///
+/// Unknown keys are returned unchanged so a missing entry shows up as the
+/// key rather than as empty text.
+pub(crate) fn tr(key: &'static str) -> &'static str {
+    current().translate(key)
+}
+
+fn lookup(strings: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    strings
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, text)| *text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn keys(language: UiLanguage) -> HashSet<&'static str> {
+        language.strings().iter().map(|(key, _)| *key).collect()
+    }
+
+    #[test]
+    fn test_every_language_translates_every_key() {
+        let english = keys(UiLanguage::English);
+        for language in UiLanguage::ALL {
+            let translated = keys(language);
+            let missing: Vec<_> = english.difference(&translated).collect();
+            let unknown: Vec<_> = translated.difference(&english).collect();
+            assert!(
+                missing.is_empty(),
+                "{:?} is missing {:?}",
+                language,
+                missing
+            );
+            assert!(
+                unknown.is_empty(),
+                "{:?} has unknown {:?}",
+                language,
+                unknown
+            );
+        }
+    }
+
+    #[test]
+    fn test_tables_have_no_duplicate_keys() {
+        for language in UiLanguage::ALL {
+            assert_eq!(
+                keys(language).len(),
+                language.strings().len(),
+                "{:?} has duplicate keys",
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn test_codes_round_trip_and_unknown_falls_back() {
+        for language in UiLanguage::ALL {
+            assert_eq!(UiLanguage::from_code(language.code()), Some(language));
+        }
+        assert_eq!(UiLanguage::from_code("sv"), None);
+        assert_eq!(
+            UiLanguage::German.translate("menu.settings"),
+            "Einstellungen"
+        );
+        assert_eq!(UiLanguage::German.translate("no.such.key"), "no.such.key");
+    }
+}
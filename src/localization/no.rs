@@ -0,0 +1,206 @@
+//! Norwegian (Bokmål) strings
+
+pub(super) const STRINGS: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.start_recording", "Start opptak"),
+    ("menu.stop_recording", "Stopp opptak"),
+    ("menu.stop_no_polish", "Uten polering"),
+    ("menu.stop_basic_polish", "Enkel polering"),
+    ("menu.stop_meeting_notes", "Møtereferat"),
+    ("menu.stop_follow_up_email", "Oppfølgings-e-post"),
+    ("menu.show_transcription", "Vis transkripsjon"),
+    ("menu.print_transcript", "Skriv ut transkripsjon…"),
+    ("menu.screenshots", "Skjermbilder"),
+    ("menu.capture_screen", "Ta bilde av hele skjermen"),
+    ("menu.capture_area", "Ta bilde av valgt område"),
+    ("menu.capture_last_area", "Ta bilde av forrige område igjen"),
+    ("menu.private_session", "Privat økt"),
+    ("menu.practice_script", "Øv med manus…"),
+    ("menu.create_issues", "Opprett saker fra handlingspunkter…"),
+    ("menu.compare_polish", "Sammenlign polerte versjoner…"),
+    ("menu.demo_session", "Prøv en demoøkt"),
+    ("menu.workspace", "Arbeidsområde"),
+    ("menu.templates", "Maler"),
+    ("menu.no_templates", "Legg til maler under Innstillinger → Arbeidsområder"),
+    ("menu.settings", "Innstillinger"),
+    ("menu.export_settings", "Eksporter innstillinger…"),
+    ("menu.import_settings", "Importer innstillinger…"),
+    ("menu.languages", "Språk"),
+    ("menu.about", "Om Vissper"),
+    ("menu.report_problem", "Rapporter et problem…"),
+    ("menu.update_available", "Oppdatering tilgjengelig"),
+    ("menu.quit", "Avslutt Vissper"),
+    ("menu.customize_languages", "Tilpass…"),
+    ("languages.customize_title", "Språk i menyen"),
+    (
+        "languages.customize_message",
+        "Velg opptil 5 språk som vises i menylinjen. Språket som brukes, vises alltid.",
+    ),
+    ("languages.save", "Lagre"),
+    ("languages.cancel", "Avbryt"),
+    // Transcription overlay
+    ("overlay.live_transcription", "Direkte transkripsjon"),
+    ("overlay.polished_transcript", "Polert transkripsjon"),
+    ("overlay.meeting_notes", "Møtereferat"),
+    ("overlay.follow_up_email", "Oppfølgings-e-post"),
+    ("overlay.tab_live", "Direkte"),
+    ("overlay.tab_polished", "Polert"),
+    ("overlay.tab_email", "E-post"),
+    ("overlay.tab_debug", "Feilsøking"),
+    ("overlay.debug_log", "Feilsøkingslogg"),
+    ("overlay.debug_empty", "Ingen hendelser ennå. Leverandørens hendelser og tilkoblingsendringer vises her når et opptak kobler til."),
+    ("overlay.close", "Lukk transkripsjonsvinduet"),
+    (
+        "overlay.insert_marker",
+        "Sett inn markør (Control + Shift + M)",
+    ),
+    ("overlay.add_note", "Legg til notat (Control + Shift + N)"),
+    ("overlay.stop_no_polish", "■ Rå"),
+    ("overlay.stop_no_polish_tooltip", "Stopp uten polering (Control + Space)"),
+    ("overlay.stop_polish", "■ Poler"),
+    ("overlay.stop_polish_tooltip", "Stopp og poler (Control + Shift + 1)"),
+    ("overlay.stop_meeting_notes", "■ Notater"),
+    ("overlay.stop_meeting_notes_tooltip", "Stopp med møtenotater (Control + Shift + 2)"),
+    (
+        "overlay.note_placeholder",
+        "Legg til et notat, f.eks. «Bob kom sent»",
+    ),
+    ("overlay.attendees", "Deltakere"),
+    (
+        "overlay.attendees_placeholder",
+        "Deltakere, skilt med komma",
+    ),
+    ("overlay.recording", "Tar opp"),
+    ("overlay.recording_banner", "Vissper tar opp"),
+    ("overlay.processing", "Behandler"),
+    ("overlay.save", "Lagre"),
+    (
+        "overlay.data_usage",
+        "Data sendt og mottatt under dette opptaket",
+    ),
+    ("overlay.listening", "Lytter..."),
+    (
+        "overlay.input_muted",
+        "Mikrofonen er dempet — ingenting blir tatt opp",
+    ),
+    (
+        "overlay.input_volume_zero",
+        "Mikrofonens inngangsvolum er 0 — ingenting blir tatt opp",
+    ),
+    (
+        "overlay.applies_next_recording",
+        "Innstilling lagret — gjelder fra neste opptak",
+    ),
+    ("overlay.session_info", "Øktinfo"),
+    ("overlay.session_model", "Modell"),
+    ("overlay.session_language", "Språk"),
+    ("overlay.session_vad", "Stemmegjenkjenning"),
+    ("overlay.session_format", "Lydformat"),
+    ("overlay.session_noise", "Støyreduksjon"),
+    ("overlay.session_not_set", "Ikke angitt"),
+    ("overlay.session_off", "Av"),
+    ("overlay.polishing", "Polerer..."),
+    (
+        "overlay.click_to_polish",
+        "Klikk for å lage polert transkripsjon...",
+    ),
+    ("overlay.click_for_notes", "Klikk for å lage møtereferat..."),
+    (
+        "overlay.click_for_email",
+        "Klikk for å skrive en oppfølgings-e-post...",
+    ),
+    ("overlay.click_to_generate", "Klikk for å lage..."),
+    (
+        "overlay.generating_polish",
+        "⏳ Lager polert transkripsjon...",
+    ),
+    ("overlay.generating_notes", "⏳ Lager møtereferat..."),
+    (
+        "overlay.generating_email",
+        "⏳ Skriver oppfølgings-e-post...",
+    ),
+    ("overlay.generating", "⏳ Lager..."),
+    (
+        "overlay.stop_first",
+        "⚠️ Stopp opptaket først for å lage innhold i denne fanen.",
+    ),
+    (
+        "overlay.no_polish_yet",
+        "📝 Ingen polert tekst ennå.\n\nTranskripsjonen poleres når du stopper opptaket \
+         med «Enkel polering»,\neller du kan klikke her etter opptaket for å lage den.",
+    ),
+    (
+        "overlay.no_notes_yet",
+        "📋 Ikke noe møtereferat ennå.\n\nMøtereferatet lages når du stopper opptaket \
+         med «Møtereferat»,\neller du kan klikke her etter opptaket for å lage det.",
+    ),
+    (
+        "overlay.no_email_yet",
+        "✉️ Ingen oppfølgings-e-post ennå.\n\nE-posten skrives når du stopper opptaket \
+         med «Oppfølgings-e-post»,\neller du kan klikke her etter opptaket for å skrive den.",
+    ),
+    (
+        "overlay.no_output_yet",
+        "📝 Ingenting laget ennå.\n\nFanen fylles når du stopper opptaket \
+         med utdatamodusen,\neller du kan klikke her etter opptaket for å lage den.",
+    ),
+    ("overlay.window", "Vissper-transkripsjon"),
+    ("overlay.header", "Transkripsjonskontroller"),
+    ("overlay.view_selector", "Velg transkripsjonsvisning"),
+    ("overlay.recording_in_progress", "Opptak pågår"),
+    ("overlay.save_transcript", "Lagre transkripsjonen til fil"),
+    ("overlay.note_field", "Notattekst"),
+    ("overlay.attendees_field", "Deltakernavn"),
+    ("overlay.screenshots", "Skjermbilder"),
+    (
+        "overlay.toggle_screenshots",
+        "Vis eller skjul skjermbildene fra denne økten",
+    ),
+    // Settings window
+    ("settings.title", "Vissper-innstillinger"),
+    ("settings.tab_general", "Generelt"),
+    ("settings.tab_highlights", "Uthevinger"),
+    ("settings.tab_notes", "Notater"),
+    ("settings.tab_notifications", "Varsler"),
+    ("settings.tab_integrations", "Integrasjoner"),
+    ("settings.tab_issues", "Saker"),
+    ("settings.tab_export", "Eksport"),
+    ("settings.tab_storage", "Lagring"),
+    ("settings.tab_workspaces", "Arbeidsområder"),
+    ("settings.tab_screenshots", "Skjermbilder"),
+    ("settings.tab_captions", "Teksting"),
+    ("settings.tab_privacy", "Personvern"),
+    ("settings.tab_audio", "Lyd"),
+    ("settings.tab_microphone", "Mikrofon"),
+    ("settings.tab_session", "Økt"),
+    ("settings.tab_stats", "Statistikk"),
+    (
+        "settings.overlay_transparency",
+        "Gjennomsiktighet for overlegget",
+    ),
+    ("settings.background", "Bakgrunn"),
+    ("settings.language_hotkey", "Hurtigtast for språk"),
+    (
+        "settings.language_hotkey_help",
+        "Bytter mellom favorittspråkene fra Språk-menyen",
+    ),
+    ("settings.stop_hotkeys", "Hurtigtaster for stopp"),
+    ("settings.stop_hotkeys_help", "Stopper opptaket med en modus; de starter aldri et"),
+    ("settings.dark", "Mørk"),
+    ("settings.light", "Lys"),
+    ("settings.interface_language", "Språk i grensesnittet"),
+    (
+        "settings.restart_to_apply",
+        "Start Vissper på nytt for å ta i bruk",
+    ),
+    (
+        "settings.transcript_location",
+        "Plassering av transkripsjoner",
+    ),
+    ("settings.screenshot_location", "Plassering av skjermbilder"),
+    ("settings.choose", "Velg..."),
+    ("settings.reset_to_default", "Tilbakestill"),
+    ("settings.transcription_provider", "Transkripsjon"),
+    ("settings.polish_provider", "Polering"),
+    ("settings.polish_same", "Som transkripsjon"),
+];
@@ -1,26 +1,71 @@
 #![deny(clippy::all)]
 
+mod app_context;
+mod attendees;
 mod audio;
+mod automation;
 mod azure_openai;
+mod bug_report;
 mod callbacks;
+mod captions;
+mod consent;
+mod encryption;
 mod error;
+mod event_bus;
+mod feedback;
+mod git_archive;
+mod google_drive;
 mod hotkeys;
+mod input_quality;
+mod issues;
 mod keychain;
+mod keywords;
+mod language_hud;
+mod language_sections;
+mod languages;
+mod localization;
+mod managed;
 mod menubar;
+mod metrics;
+mod mic_test;
+mod notion;
 mod openai;
+mod output_modes;
+mod polish_compare;
+mod power;
 mod preferences;
+mod private_mode;
+mod provenance;
+mod quick_polish;
+mod recent_logs;
 mod recording;
+mod recording_banner;
 mod region_selection;
 mod response;
+mod retention;
+mod retry;
+mod rich_text;
 mod screenshot;
+mod screenshot_blocklist;
 mod screenshot_flash;
+mod settings_transfer;
 mod settings_window;
+mod shutdown;
+mod speaking;
 mod storage;
+mod teleprompter;
+mod templates;
+mod tokens;
+mod topics;
 mod transcription;
 mod transcription_window;
+mod ui_sink;
 mod version_check;
+mod wake_word;
+mod workspaces;
 
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tracing::info;
 
 // Re-export error types (used by other modules)
@@ -39,6 +84,9 @@ struct VersionCheckConfig {
     enabled: bool,
 }
 
+/// How long tasks still running after the event loop stops get to finish
+const RUNTIME_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// Load configuration from embedded config.toml
 fn load_config() -> Result<Config, Box<dyn std::error::Error>> {
     const CONFIG_TOML: &str = include_str!("../config.toml");
@@ -46,10 +94,15 @@ fn load_config() -> Result<Config, Box<dyn std::error::Error>> {
     Ok(config)
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing for structured logging
-    tracing_subscriber::fmt::init();
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize tracing for structured logging, keeping recent lines for bug reports
+    recent_logs::init();
+
+    // The AppKit event loop owns the main thread; async work runs on the
+    // runtime's worker threads. Entering the runtime lets main-thread
+    // callbacks spawn tasks.
+    let runtime = tokio::runtime::Runtime::new()?;
+    let runtime_guard = runtime.enter();
 
     // Load configuration from embedded config.toml
     let config = load_config()?;
@@ -57,6 +110,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create shared application state
     let app_state = Arc::new(menubar::AppState::new());
 
+    // Move credentials saved by earlier versions to the current keychain names
+    keychain::migrate();
+
     // Check for stored credentials based on selected provider
     let ai_provider = preferences::get_ai_provider();
     let has_credentials = match ai_provider {
@@ -79,71 +135,138 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             has_creds
         }
     };
+    // A mock fixture stands in for credentials during development
+    #[cfg(feature = "mock-provider")]
+    let has_credentials =
+        has_credentials || std::env::var_os(transcription::MOCK_TRANSCRIPT_ENV).is_some();
+
     info!("Selected AI provider: {:?}", ai_provider);
 
+    // Remove decrypted copies of encrypted files opened in the previous run
+    encryption::clear_temp_dir();
+
     // Create shared state for recording session
     let recording_state: Arc<Mutex<Option<recording::RecordingSession>>> =
         Arc::new(Mutex::new(None));
 
+    // Recording and polishing report progress through the AppKit UI
+    let ui: ui_sink::SharedUi = Arc::new(ui_sink::AppKitUi);
+
+    // Everything the menu, hotkey and automation callbacks act on
+    let context = app_context::AppContext {
+        recording_state,
+        app_state,
+        ui,
+    };
+
     // Initialize transcription window callbacks
+    let ui_generation = context.ui.clone();
+    let recording_state_marker = context.recording_state.clone();
+    let recording_state_note = context.recording_state.clone();
+    let recording_state_stop = context.recording_state.clone();
+    let ui_stop = context.ui.clone();
     let window_callbacks = transcription_window::WindowCallbacks {
         on_hide: Arc::new(|| {
             info!("Transcription window hidden via button");
         }),
-        on_request_basic_polish: Arc::new(move |transcript: String| {
-            tokio::spawn(async move {
-                recording::polish_transcript_on_demand(
-                    transcript,
-                    transcription_window::TabType::BasicPolish,
-                )
-                .await;
-            });
+        on_request_generation: Arc::new(
+            move |tab: transcription_window::TabType, transcript: String| {
+                let ui = ui_generation.clone();
+                tokio::spawn(async move {
+                    recording::polish_transcript_on_demand(transcript, tab, ui).await;
+                });
+            },
+        ),
+        on_live_transcript_edited: Arc::new(|transcript: String| {
+            recording::copy_to_clipboard(&transcript);
+        }),
+        on_insert_marker: Arc::new(move || {
+            recording::insert_marker(&recording_state_marker);
         }),
-        on_request_meeting_notes: Arc::new(move |transcript: String| {
-            tokio::spawn(async move {
-                recording::polish_transcript_on_demand(
-                    transcript,
-                    transcription_window::TabType::MeetingNotes,
-                )
-                .await;
-            });
+        on_insert_note: Arc::new(move |text: String| {
+            recording::insert_note(&recording_state_note, &text);
+        }),
+        on_stop_with_mode: Arc::new(move |tab: transcription_window::TabType| {
+            recording::stop_recording_with_mode(recording_state_stop.clone(), ui_stop.clone(), tab);
         }),
     };
     transcription_window::TranscriptionWindow::init(window_callbacks);
     transcription_window::TranscriptionWindow::load_appearance_preferences();
 
-    // Create callback configuration
-    let callback_config = callbacks::CallbackConfig { recording_state };
-
     // Create and initialize menu bar with callbacks
-    let menu_callbacks = callbacks::create_menu_callbacks(&callback_config);
-    menubar::MenuBar::init(app_state.clone(), menu_callbacks);
+    let menu_callbacks = callbacks::create_menu_callbacks(&context);
+    menubar::MenuBar::init(context.app_state.clone(), menu_callbacks);
 
     // Set initial credentials state based on selected provider
     menubar::MenuBar::set_azure_credentials(has_credentials);
 
+    // Ask for notification permission if any notification is enabled
+    feedback::init();
+
+    // Subscribe to app events before hotkeys or automation can publish any
+    let runtime_handle = tokio::runtime::Handle::current();
+    event_bus::listen(&runtime_handle, feedback::handle_event);
+    event_bus::listen(&runtime_handle, metrics::handle_event);
+    event_bus::listen(&runtime_handle, captions::handle_event);
+    event_bus::listen(&runtime_handle, teleprompter::handle_event);
+    event_bus::listen(&runtime_handle, wake_word::handle_event);
+    event_bus::listen(&runtime_handle, notion::handle_event);
+    event_bus::listen(&runtime_handle, menubar::MenuBar::handle_event);
+    event_bus::listen(&runtime_handle, recording_banner::handle_event);
+    event_bus::listen(&runtime_handle, mic_test::handle_event);
+    event_bus::listen(&runtime_handle, input_quality::handle_event);
+    event_bus::listen(
+        &runtime_handle,
+        screenshot_flash::ScreenshotFlash::handle_event,
+    );
+    event_bus::listen(
+        &runtime_handle,
+        transcription_window::TranscriptionWindow::handle_event,
+    );
+
     // Initialize global hotkeys
-    let hotkey_manager = hotkeys::init_hotkeys()?;
+    hotkeys::init_hotkeys()?;
     info!("Global hotkeys initialized successfully");
 
     // Setup hotkey callbacks
-    let runtime_handle = tokio::runtime::Handle::current();
-    callbacks::setup_hotkey_callbacks(&callback_config, app_state, runtime_handle);
+    callbacks::setup_hotkey_callbacks(&context, runtime_handle.clone());
+
+    // Start live caption output if enabled in Settings
+    captions::init(runtime_handle.clone());
 
-    // Keep hotkey manager alive
-    std::mem::forget(hotkey_manager);
+    // Export meeting notes to Notion if enabled in Settings
+    notion::init(runtime_handle.clone());
+
+    // Delete old transcripts and screenshots if a retention policy is set
+    retention::init(runtime_handle.clone());
+
+    // Pause recordings across sleep and stop them on screen lock if enabled
+    power::init(context.clone(), runtime_handle.clone());
+
+    // Listen for the wake phrase if enabled in Settings
+    wake_word::init(context.clone(), runtime_handle.clone());
+
+    // Start the automation socket and HTTP API if enabled in Settings
+    automation::init(context, runtime_handle);
 
     // Initialize and start version update checker
-    if config.version_check.enabled {
+    if config.version_check.enabled && !managed::policy().disable_update_check {
         info!("Version checker enabled, initializing...");
         version_check::initialize(config.version_check.url);
         version_check::start_update_checker();
     } else {
-        info!("Version checker disabled in configuration");
+        info!("Version checker disabled in configuration or by managed policy");
     }
 
-    // Run the application event loop
+    // Run the application event loop until shutdown stops it
     menubar::MenuBar::run();
 
+    // Recording and polish jobs were wound down before the loop stopped;
+    // cancel whatever is left, such as the automation server and listeners
+    hotkeys::unregister_hotkeys();
+    drop(runtime_guard);
+    runtime.shutdown_timeout(RUNTIME_SHUTDOWN_TIMEOUT);
+    info!("Vissper shut down");
+
     Ok(())
 }
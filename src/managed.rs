@@ -0,0 +1,278 @@
+//! Organization-wide managed configuration
+//!
+//! IT departments can enforce a policy by deploying either
+//! `/Library/Application Support/Vissper/managed.json` or a configuration
+//! profile for the `com.vissper.desktop` domain, which macOS writes to
+//! `/Library/Managed Preferences/com.vissper.desktop.plist`. Both use the same
+//! keys:
+//!
+//! ```json
+//! {
+//!   "ai_provider": "azure",
+//!   "azure_endpoint_url": "https://contoso.openai.azure.com",
+//!   "azure_stt_deployment": "gpt-4o-transcribe",
+//!   "azure_polish_deployment": "gpt-5.1",
+//!   "disable_screenshots": true,
+//!   "disable_polishing": false,
+//!   "disable_automation": true,
+//!   "disable_captions": true,
+//!   "disable_update_check": true,
+//!   "encrypt_at_rest": true
+//! }
+//! ```
+//!
+//! Every key is optional. When both sources exist, values from the profile
+//! win and a feature disabled in either one stays disabled. Settings locked by
+//! the policy are greyed out in the Settings window.
+//!
+//! The policy is read once at startup. A file that can't be parsed is logged
+//! and ignored.
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::path::Path;
+use tracing::{error, info};
+
+use crate::keychain::AzureCredentials;
+use crate::preferences::AiProvider;
+
+/// Managed config file for deployments without MDM
+const MANAGED_JSON_PATH: &str = "/Library/Application Support/Vissper/managed.json";
+
+/// Where macOS places a configuration profile pushed by MDM
+const MANAGED_PLIST_PATH: &str = "/Library/Managed Preferences/com.vissper.desktop.plist";
+
+/// Tooltip shown on settings locked by the policy
+pub(crate) const MANAGED_TOOLTIP: &str = "Managed by your organization";
+
+/// Policy loaded at startup (`None` when the app isn't managed)
+static POLICY: Lazy<Option<ManagedPolicy>> = Lazy::new(load_policy);
+
+/// Errors reading a managed config source
+#[derive(Debug, thiserror::Error)]
+enum ManagedError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid property list: {0}")]
+    Plist(#[from] plist::Error),
+}
+
+/// Settings enforced by the organization
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub(crate) struct ManagedPolicy {
+    /// Provider users can't switch away from
+    pub(crate) ai_provider: Option<AiProvider>,
+    /// Azure OpenAI endpoint users must connect to
+    pub(crate) azure_endpoint_url: Option<String>,
+    /// Azure deployment used for speech-to-text
+    pub(crate) azure_stt_deployment: Option<String>,
+    /// Azure deployment used for polishing
+    pub(crate) azure_polish_deployment: Option<String>,
+    /// Refuse all screenshots
+    pub(crate) disable_screenshots: bool,
+    /// Never send transcripts for polishing
+    pub(crate) disable_polishing: bool,
+    /// Never start the automation socket or HTTP API
+    pub(crate) disable_automation: bool,
+    /// Never publish live captions
+    pub(crate) disable_captions: bool,
+    /// Never check for new versions
+    pub(crate) disable_update_check: bool,
+    /// Force encryption at rest on or off
+    pub(crate) encrypt_at_rest: Option<bool>,
+}
+
+impl ManagedPolicy {
+    /// Combine two sources, letting `profile` override `file`.
+    fn merge(file: Self, profile: Self) -> Self {
+        Self {
+            ai_provider: profile.ai_provider.or(file.ai_provider),
+            azure_endpoint_url: profile.azure_endpoint_url.or(file.azure_endpoint_url),
+            azure_stt_deployment: profile.azure_stt_deployment.or(file.azure_stt_deployment),
+            azure_polish_deployment: profile
+                .azure_polish_deployment
+                .or(file.azure_polish_deployment),
+            disable_screenshots: profile.disable_screenshots || file.disable_screenshots,
+            disable_polishing: profile.disable_polishing || file.disable_polishing,
+            disable_automation: profile.disable_automation || file.disable_automation,
+            disable_captions: profile.disable_captions || file.disable_captions,
+            disable_update_check: profile.disable_update_check || file.disable_update_check,
+            encrypt_at_rest: profile.encrypt_at_rest.or(file.encrypt_at_rest),
+        }
+    }
+
+    /// Whether any part of the Azure connection is locked
+    pub(crate) fn locks_azure_connection(&self) -> bool {
+        self.azure_endpoint_url.is_some()
+            || self.azure_stt_deployment.is_some()
+            || self.azure_polish_deployment.is_some()
+    }
+
+    /// Replace the endpoint and deployments with the managed values.
+    ///
+    /// The API key is always the user's own.
+    pub(crate) fn apply_to_azure(&self, creds: &mut AzureCredentials) {
+        if let Some(url) = &self.azure_endpoint_url {
+            creds.endpoint_url.clone_from(url);
+        }
+        if let Some(deployment) = &self.azure_stt_deployment {
+            creds.stt_deployment.clone_from(deployment);
+        }
+        if let Some(deployment) = &self.azure_polish_deployment {
+            creds.polish_deployment.clone_from(deployment);
+        }
+    }
+}
+
+/// Whether an organization policy is installed
+pub(crate) fn is_managed() -> bool {
+    POLICY.is_some()
+}
+
+/// The enforced policy, or an empty one when the app isn't managed
+pub(crate) fn policy() -> &'static ManagedPolicy {
+    static UNMANAGED: ManagedPolicy = ManagedPolicy {
+        ai_provider: None,
+        azure_endpoint_url: None,
+        azure_stt_deployment: None,
+        azure_polish_deployment: None,
+        disable_screenshots: false,
+        disable_polishing: false,
+        disable_automation: false,
+        disable_captions: false,
+        disable_update_check: false,
+        encrypt_at_rest: None,
+    };
+    POLICY.as_ref().unwrap_or(&UNMANAGED)
+}
+
+/// Read both sources and combine whichever exist
+fn load_policy() -> Option<ManagedPolicy> {
+    let file = read_source(Path::new(MANAGED_JSON_PATH), |path| {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    });
+    let profile = read_source(Path::new(MANAGED_PLIST_PATH), |path| {
+        Ok(plist::from_file(path)?)
+    });
+
+    let policy = match (file, profile) {
+        (None, None) => return None,
+        (Some(file), None) => file,
+        (None, Some(profile)) => profile,
+        (Some(file), Some(profile)) => ManagedPolicy::merge(file, profile),
+    };
+    info!(?policy, "Managed configuration in effect");
+    Some(policy)
+}
+
+/// Parse one source, logging and skipping it if it can't be read
+fn read_source(
+    path: &Path,
+    parse: impl FnOnce(&Path) -> Result<ManagedPolicy, ManagedError>,
+) -> Option<ManagedPolicy> {
+    if !path.exists() {
+        return None;
+    }
+    match parse(path) {
+        Ok(policy) => {
+            info!("Loaded managed configuration from {:?}", path);
+            Some(policy)
+        }
+        Err(e) => {
+            error!("Ignoring managed configuration {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_policy() {
+        let policy: ManagedPolicy = serde_json::from_str(
+            r#"{"ai_provider": "openai", "disable_screenshots": true, "encrypt_at_rest": true}"#,
+        )
+        .unwrap();
+
+        assert_eq!(policy.ai_provider, Some(AiProvider::OpenAI));
+        assert!(policy.disable_screenshots);
+        assert!(!policy.disable_polishing);
+        assert_eq!(policy.encrypt_at_rest, Some(true));
+        assert!(!policy.locks_azure_connection());
+    }
+
+    #[test]
+    fn test_parse_plist_policy() {
+        let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>azure_endpoint_url</key>
+    <string>https://contoso.openai.azure.com</string>
+    <key>disable_automation</key>
+    <true/>
+</dict>
+</plist>"#;
+        let policy: ManagedPolicy = plist::from_bytes(xml).unwrap();
+
+        assert_eq!(
+            policy.azure_endpoint_url.as_deref(),
+            Some("https://contoso.openai.azure.com")
+        );
+        assert!(policy.disable_automation);
+        assert!(policy.locks_azure_connection());
+    }
+
+    #[test]
+    fn test_profile_overrides_file() {
+        let file = ManagedPolicy {
+            ai_provider: Some(AiProvider::Azure),
+            azure_endpoint_url: Some("https://file.example".to_string()),
+            disable_captions: true,
+            ..Default::default()
+        };
+        let profile = ManagedPolicy {
+            ai_provider: Some(AiProvider::OpenAI),
+            disable_screenshots: true,
+            ..Default::default()
+        };
+
+        let merged = ManagedPolicy::merge(file, profile);
+        assert_eq!(merged.ai_provider, Some(AiProvider::OpenAI));
+        assert_eq!(
+            merged.azure_endpoint_url.as_deref(),
+            Some("https://file.example")
+        );
+        assert!(merged.disable_captions);
+        assert!(merged.disable_screenshots);
+    }
+
+    #[test]
+    fn test_apply_to_azure_keeps_api_key() {
+        let policy = ManagedPolicy {
+            azure_endpoint_url: Some("https://contoso.openai.azure.com".to_string()),
+            ..Default::default()
+        };
+        let mut creds = AzureCredentials {
+            api_key: "user-key".to_string(),
+            endpoint_url: "https://personal.openai.azure.com".to_string(),
+            stt_deployment: "stt".to_string(),
+            polish_deployment: "polish".to_string(),
+            realtime_api_version: None,
+            responses_api_version: None,
+            polish_route: None,
+        };
+
+        policy.apply_to_azure(&mut creds);
+        assert_eq!(creds.endpoint_url, "https://contoso.openai.azure.com");
+        assert_eq!(creds.api_key, "user-key");
+        assert_eq!(creds.stt_deployment, "stt");
+    }
+}
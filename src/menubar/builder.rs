@@ -3,7 +3,7 @@
 //! Functions for constructing the menu structure and its items.
 
 use objc2::rc::Retained;
-use objc2::sel;
+use objc2::{msg_send, sel};
 use objc2_app_kit::{NSMenu, NSMenuItem};
 use objc2_foundation::{MainThreadMarker, NSString};
 use std::sync::atomic::Ordering;
@@ -11,8 +11,14 @@ use tracing::info;
 
 use super::delegate::VissperMenuDelegate;
 use super::items::{create_menu_item, create_menu_item_with_key};
+use super::languages::build_languages_submenu;
+use super::stop_keys;
+use super::templates::build_templates_submenu;
+use super::workspaces::build_workspace_submenu;
 use super::APP_STATE;
-use crate::preferences;
+use crate::localization::tr;
+use crate::output_modes;
+use crate::transcription_window::TabType;
 
 /// Build all menu items and add them to the menu
 #[allow(clippy::type_complexity)]
@@ -23,27 +29,28 @@ pub(super) fn build_menu_items(
 ) -> (
     Retained<NSMenuItem>,
     Retained<NSMenu>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
+    Vec<Retained<NSMenuItem>>, // stop_items
     Retained<NSMenuItem>,
     Retained<NSMenuItem>, // screenshots_item
     Retained<NSMenu>,     // screenshots_submenu
     Retained<NSMenuItem>, // screenshot_fullscreen_item
     Retained<NSMenuItem>, // screenshot_region_item
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
+    Retained<NSMenuItem>, // screenshot_last_region_item
+    Retained<NSMenuItem>, // private_session_item
+    Retained<NSMenuItem>, // practice_script_item
+    Retained<NSMenuItem>, // settings_item
+    Retained<NSMenuItem>, // languages_item
+    Retained<NSMenu>,     // languages_submenu
+    Retained<NSMenuItem>, // workspace_item
+    Retained<NSMenu>,     // workspace_submenu
+    Retained<NSMenuItem>, // templates_item
+    Retained<NSMenu>,     // templates_submenu
     Retained<NSMenuItem>, // update_available_item
 ) {
     // Recording item with keyboard shortcut
     let recording_item = create_menu_item_with_key(
         mtm,
-        "Start Recording",
+        tr("menu.start_recording"),
         sel!(handleStartRecording:),
         delegate,
         " ",
@@ -51,44 +58,53 @@ pub(super) fn build_menu_items(
     );
     menu.addItem(&recording_item);
 
+    // Templates submenu, rebuilt each time the menu opens
+    let (templates_item, templates_submenu) = build_templates_submenu(mtm, menu, delegate);
+
     // Stop recording submenu
     let stop_submenu = NSMenu::new(mtm);
     unsafe { stop_submenu.setAutoenablesItems(false) };
 
-    let stop_no_polish_item = create_menu_item_with_key(
-        mtm,
-        "No polishing",
-        sel!(handleStopNoPolish:),
-        delegate,
-        " ",
-        262144,
-    );
-    stop_submenu.addItem(&stop_no_polish_item);
+    // One item per output mode, tagged with its tab so a single action
+    // handles them all
+    let stop_items: Vec<Retained<NSMenuItem>> = std::iter::once(TabType::Live)
+        .chain(output_modes::polish_tabs())
+        .map(|tab| {
+            let (key, modifiers) = stop_keys::key_equivalent(tab);
+            let item = create_menu_item_with_key(
+                mtm,
+                &output_modes::menu_title(tab),
+                sel!(handleStopWithMode:),
+                delegate,
+                key,
+                modifiers,
+            );
+            // SAFETY: setTag: on a valid NSMenuItem
+            unsafe {
+                let _: () = msg_send![&item, setTag: tab.to_index()];
+            }
+            stop_submenu.addItem(&item);
+            item
+        })
+        .collect();
 
-    let stop_basic_polish_item = create_menu_item_with_key(
+    // Show Window item
+    let show_window_item = create_menu_item(
         mtm,
-        "Basic polishing",
-        sel!(handleStopBasicPolish:),
+        tr("menu.show_transcription"),
+        sel!(handleShowWindow:),
         delegate,
-        "1",
-        393216,
     );
-    stop_submenu.addItem(&stop_basic_polish_item);
+    menu.addItem(&show_window_item);
 
-    let stop_meeting_notes_item = create_menu_item_with_key(
+    // Print the transcription window's active tab, laid out like a saved PDF
+    let print_item = create_menu_item(
         mtm,
-        "Meeting notes",
-        sel!(handleStopMeetingNotes:),
+        tr("menu.print_transcript"),
+        sel!(handlePrintTranscript:),
         delegate,
-        "2",
-        393216,
     );
-    stop_submenu.addItem(&stop_meeting_notes_item);
-
-    // Show Window item
-    let show_window_item =
-        create_menu_item(mtm, "Show Transcription", sel!(handleShowWindow:), delegate);
-    menu.addItem(&show_window_item);
+    menu.addItem(&print_item);
 
     // Screenshots submenu
     let screenshots_submenu = NSMenu::new(mtm);
@@ -97,7 +113,7 @@ pub(super) fn build_menu_items(
     // Capture Entire Screen (Ctrl+Shift+0)
     let screenshot_fullscreen_item = create_menu_item_with_key(
         mtm,
-        "Capture Entire Screen",
+        tr("menu.capture_screen"),
         sel!(handleScreenshot:),
         delegate,
         "0",
@@ -108,7 +124,7 @@ pub(super) fn build_menu_items(
     // Capture Selected Area (Ctrl+Shift+9)
     let screenshot_region_item = create_menu_item_with_key(
         mtm,
-        "Capture Selected Area",
+        tr("menu.capture_area"),
         sel!(handleRegionScreenshot:),
         delegate,
         "9",
@@ -116,9 +132,20 @@ pub(super) fn build_menu_items(
     );
     screenshots_submenu.addItem(&screenshot_region_item);
 
+    // Capture Last Area Again (Ctrl+Shift+8)
+    let screenshot_last_region_item = create_menu_item_with_key(
+        mtm,
+        tr("menu.capture_last_area"),
+        sel!(handleLastRegionScreenshot:),
+        delegate,
+        "8",
+        393216,
+    );
+    screenshots_submenu.addItem(&screenshot_last_region_item);
+
     // Create Screenshots parent menu item (no action, just shows submenu)
     let screenshots_item = {
-        let title_str = NSString::from_str("Screenshots");
+        let title_str = NSString::from_str(tr("menu.screenshots"));
         let key = NSString::from_str("");
         unsafe {
             NSMenuItem::initWithTitle_action_keyEquivalent(mtm.alloc(), &title_str, None, &key)
@@ -127,30 +154,96 @@ pub(super) fn build_menu_items(
     screenshots_item.setSubmenu(Some(&screenshots_submenu));
     menu.addItem(&screenshots_item);
 
+    // Private Session toggle (checkmark shows when on)
+    let private_session_item = create_menu_item(
+        mtm,
+        tr("menu.private_session"),
+        sel!(handlePrivateSession:),
+        delegate,
+    );
+    menu.addItem(&private_session_item);
+
+    // Practice with Script toggle (checkmark shows while a script is loaded)
+    let practice_script_item = create_menu_item(
+        mtm,
+        tr("menu.practice_script"),
+        sel!(handlePracticeScript:),
+        delegate,
+    );
+    menu.addItem(&practice_script_item);
+
+    // Push the meeting notes' action items to Jira or Linear
+    let create_issues_item = create_menu_item(
+        mtm,
+        tr("menu.create_issues"),
+        sel!(handleCreateIssues:),
+        delegate,
+    );
+    menu.addItem(&create_issues_item);
+
+    // Polish the transcript two ways and show the results side by side
+    let compare_polish_item = create_menu_item(
+        mtm,
+        tr("menu.compare_polish"),
+        sel!(handleComparePolish:),
+        delegate,
+    );
+    menu.addItem(&compare_polish_item);
+
+    // Replay a sample meeting without the microphone or credentials
+    let demo_session_item = create_menu_item(
+        mtm,
+        tr("menu.demo_session"),
+        sel!(handleDemoSession:),
+        delegate,
+    );
+    menu.addItem(&demo_session_item);
+
     menu.addItem(&NSMenuItem::separatorItem(mtm));
 
     // Settings item
-    let settings_item = create_menu_item(mtm, "Settings", sel!(handleSettings:), delegate);
+    let settings_item = create_menu_item(mtm, tr("menu.settings"), sel!(handleSettings:), delegate);
     menu.addItem(&settings_item);
 
+    // Back up or share the settings as a single file
+    let export_settings_item = create_menu_item(
+        mtm,
+        tr("menu.export_settings"),
+        sel!(handleExportSettings:),
+        delegate,
+    );
+    menu.addItem(&export_settings_item);
+    let import_settings_item = create_menu_item(
+        mtm,
+        tr("menu.import_settings"),
+        sel!(handleImportSettings:),
+        delegate,
+    );
+    menu.addItem(&import_settings_item);
+
     menu.addItem(&NSMenuItem::separatorItem(mtm));
 
     // Languages submenu
-    let (
-        languages_item,
-        lang_english_item,
-        lang_norwegian_item,
-        lang_danish_item,
-        lang_finnish_item,
-        lang_german_item,
-    ) = build_languages_submenu(mtm, menu, delegate);
+    let (languages_item, languages_submenu) = build_languages_submenu(mtm, menu, delegate);
+
+    // Workspace submenu, rebuilt each time the menu opens
+    let (workspace_item, workspace_submenu) = build_workspace_submenu(mtm, menu, delegate);
 
     menu.addItem(&NSMenuItem::separatorItem(mtm));
 
     // About item
-    let about_item = create_menu_item(mtm, "About Vissper", sel!(handleAbout:), delegate);
+    let about_item = create_menu_item(mtm, tr("menu.about"), sel!(handleAbout:), delegate);
     menu.addItem(&about_item);
 
+    // Collect logs and diagnostics for a bug report
+    let report_problem_item = create_menu_item(
+        mtm,
+        tr("menu.report_problem"),
+        sel!(handleReportProblem:),
+        delegate,
+    );
+    menu.addItem(&report_problem_item);
+
     // Update Available item (initially hidden)
     let update_available_item = create_menu_item(
         mtm,
@@ -162,96 +255,32 @@ pub(super) fn build_menu_items(
     menu.addItem(&update_available_item);
 
     // Quit item
-    let quit_item = create_menu_item(mtm, "Quit Vissper", sel!(handleQuit:), delegate);
+    let quit_item = create_menu_item(mtm, tr("menu.quit"), sel!(handleQuit:), delegate);
     menu.addItem(&quit_item);
 
     (
         recording_item,
         stop_submenu,
-        stop_no_polish_item,
-        stop_basic_polish_item,
-        stop_meeting_notes_item,
+        stop_items,
         show_window_item,
         screenshots_item,
         screenshots_submenu,
         screenshot_fullscreen_item,
         screenshot_region_item,
+        screenshot_last_region_item,
+        private_session_item,
+        practice_script_item,
         settings_item,
         languages_item,
-        lang_english_item,
-        lang_norwegian_item,
-        lang_danish_item,
-        lang_finnish_item,
-        lang_german_item,
+        languages_submenu,
+        workspace_item,
+        workspace_submenu,
+        templates_item,
+        templates_submenu,
         update_available_item,
     )
 }
 
-/// Build the languages submenu
-#[allow(clippy::type_complexity)]
-pub(super) fn build_languages_submenu(
-    mtm: MainThreadMarker,
-    menu: &NSMenu,
-    delegate: &VissperMenuDelegate,
-) -> (
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-    Retained<NSMenuItem>,
-) {
-    let languages_menu = NSMenu::new(mtm);
-    unsafe { languages_menu.setAutoenablesItems(false) };
-
-    let lang_english_item =
-        create_menu_item(mtm, "English", sel!(handleLanguageEnglish:), delegate);
-    languages_menu.addItem(&lang_english_item);
-
-    let lang_norwegian_item =
-        create_menu_item(mtm, "Norwegian", sel!(handleLanguageNorwegian:), delegate);
-    languages_menu.addItem(&lang_norwegian_item);
-
-    let lang_danish_item = create_menu_item(mtm, "Danish", sel!(handleLanguageDanish:), delegate);
-    languages_menu.addItem(&lang_danish_item);
-
-    let lang_finnish_item =
-        create_menu_item(mtm, "Finnish", sel!(handleLanguageFinnish:), delegate);
-    languages_menu.addItem(&lang_finnish_item);
-
-    let lang_german_item = create_menu_item(mtm, "German", sel!(handleLanguageGerman:), delegate);
-    languages_menu.addItem(&lang_german_item);
-
-    // Create Languages menu item and attach submenu
-    let languages_item = {
-        let title_str = NSString::from_str("Languages");
-        let key = NSString::from_str("");
-        unsafe {
-            NSMenuItem::initWithTitle_action_keyEquivalent(mtm.alloc(), &title_str, None, &key)
-        }
-    };
-    languages_item.setSubmenu(Some(&languages_menu));
-    menu.addItem(&languages_item);
-
-    // Set initial checkmarks
-    update_language_checkmarks_for_items(
-        &lang_english_item,
-        &lang_norwegian_item,
-        &lang_danish_item,
-        &lang_finnish_item,
-        &lang_german_item,
-    );
-
-    (
-        languages_item,
-        lang_english_item,
-        lang_norwegian_item,
-        lang_danish_item,
-        lang_finnish_item,
-        lang_german_item,
-    )
-}
-
 /// Apply initial UI state to menu items
 #[allow(clippy::too_many_arguments)]
 pub(super) fn apply_initial_state(
@@ -261,6 +290,7 @@ pub(super) fn apply_initial_state(
     screenshots_item: &NSMenuItem,
     screenshot_fullscreen_item: &NSMenuItem,
     screenshot_region_item: &NSMenuItem,
+    screenshot_last_region_item: &NSMenuItem,
     languages_item: &NSMenuItem,
 ) {
     if let Some(state) = APP_STATE.get() {
@@ -285,26 +315,8 @@ pub(super) fn apply_initial_state(
             screenshots_item.setEnabled(true);
             screenshot_fullscreen_item.setEnabled(true);
             screenshot_region_item.setEnabled(true);
+            screenshot_last_region_item.setEnabled(true);
             languages_item.setEnabled(true);
         }
     }
 }
-
-/// Update checkmarks for the given language menu items
-pub(super) fn update_language_checkmarks_for_items(
-    english: &NSMenuItem,
-    norwegian: &NSMenuItem,
-    danish: &NSMenuItem,
-    finnish: &NSMenuItem,
-    german: &NSMenuItem,
-) {
-    let current_lang = preferences::get_language_code();
-
-    unsafe {
-        english.setState(if current_lang == "en" { 1 } else { 0 });
-        norwegian.setState(if current_lang == "no" { 1 } else { 0 });
-        danish.setState(if current_lang == "da" { 1 } else { 0 });
-        finnish.setState(if current_lang == "fi" { 1 } else { 0 });
-        german.setState(if current_lang == "de" { 1 } else { 0 });
-    }
-}
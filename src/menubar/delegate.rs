@@ -1,14 +1,17 @@
 //! Menu delegate for handling Objective-C callbacks
 //!
-//! Defines the VissperMenuDelegate class that handles menu item actions.
+//! Defines the VissperMenuDelegate class that handles menu item actions
+//! and learns when the status item's menu opens.
 
 use objc2::rc::Retained;
-use objc2::{declare_class, msg_send_id, mutability, ClassType, DeclaredClass};
+use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType, DeclaredClass};
 use objc2_app_kit::{NSAlert, NSAlertStyle};
 use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSString};
 use tracing::info;
 
 use super::{MenuBar, CALLBACKS};
+use crate::transcription_window::{TabType, TranscriptionWindow};
+use crate::{bug_report, issues, polish_compare, private_mode, settings_transfer, teleprompter};
 
 /// Version from Cargo.toml
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -30,6 +33,18 @@ declare_class!(
     impl DeclaredClass for VissperMenuDelegate {}
 
     unsafe impl VissperMenuDelegate {
+        // NSMenuDelegate, for the status item's menu
+        #[method(menuWillOpen:)]
+        fn menu_will_open(&self, _menu: *mut NSObject) {
+            super::workspaces::refresh(self);
+            super::templates::refresh(self);
+            super::languages::refresh();
+            super::stop_keys::refresh();
+            if let Some(callbacks) = CALLBACKS.get() {
+                (callbacks.on_menu_opened)();
+            }
+        }
+
         #[method(handleStartRecording:)]
         fn handle_start_recording(&self, _sender: *mut NSObject) {
             info!("Start Recording menu item clicked");
@@ -38,28 +53,34 @@ declare_class!(
             }
         }
 
-        #[method(handleStopNoPolish:)]
-        fn handle_stop_no_polish(&self, _sender: *mut NSObject) {
-            info!("Stop Recording (No polishing) clicked");
+        #[method(handleStopWithMode:)]
+        fn handle_stop_with_mode(&self, sender: *mut NSObject) {
+            // SAFETY: the sender is one of the stop submenu's NSMenuItems,
+            // tagged with the tab of its output mode
+            let tag: isize = unsafe { msg_send![sender, tag] };
+            let tab = TabType::from_index(tag);
+            info!(?tab, "Stop Recording clicked");
             if let Some(callbacks) = CALLBACKS.get() {
-                (callbacks.on_stop_no_polish)();
+                (callbacks.on_stop_with_mode)(tab);
             }
         }
 
-        #[method(handleStopBasicPolish:)]
-        fn handle_stop_basic_polish(&self, _sender: *mut NSObject) {
-            info!("Stop Recording (Basic polishing) clicked");
-            if let Some(callbacks) = CALLBACKS.get() {
-                (callbacks.on_stop_basic_polish)();
-            }
+        #[method(handleSwitchWorkspace:)]
+        fn handle_switch_workspace(&self, sender: *mut NSObject) {
+            // SAFETY: the sender is one of the Workspace submenu's
+            // NSMenuItems, tagged with the workspace's index
+            let tag: isize = unsafe { msg_send![sender, tag] };
+            info!(tag, "Workspace menu item clicked");
+            super::workspaces::switch_to(tag);
         }
 
-        #[method(handleStopMeetingNotes:)]
-        fn handle_stop_meeting_notes(&self, _sender: *mut NSObject) {
-            info!("Stop Recording (Meeting notes) clicked");
-            if let Some(callbacks) = CALLBACKS.get() {
-                (callbacks.on_stop_meeting_notes)();
-            }
+        #[method(handleStartTemplate:)]
+        fn handle_start_template(&self, sender: *mut NSObject) {
+            // SAFETY: the sender is one of the Templates submenu's
+            // NSMenuItems, tagged with the template's index
+            let tag: isize = unsafe { msg_send![sender, tag] };
+            info!(tag, "Template menu item clicked");
+            super::templates::start(tag);
         }
 
         #[method(handleShowWindow:)]
@@ -86,6 +107,14 @@ declare_class!(
             }
         }
 
+        #[method(handleLastRegionScreenshot:)]
+        fn handle_last_region_screenshot(&self, _sender: *mut NSObject) {
+            info!("Capture Last Area Again clicked");
+            if let Some(callbacks) = CALLBACKS.get() {
+                (callbacks.on_last_region_screenshot)();
+            }
+        }
+
         #[method(handleSettings:)]
         fn handle_settings(&self, _sender: *mut NSObject) {
             info!("Settings menu item clicked");
@@ -94,43 +123,87 @@ declare_class!(
             }
         }
 
+        #[method(handleExportSettings:)]
+        fn handle_export_settings(&self, _sender: *mut NSObject) {
+            info!("Export Settings menu item clicked");
+            settings_transfer::export_settings();
+        }
+
+        #[method(handleImportSettings:)]
+        fn handle_import_settings(&self, _sender: *mut NSObject) {
+            info!("Import Settings menu item clicked");
+            settings_transfer::import_settings();
+        }
+
         #[method(handleQuit:)]
         fn handle_quit(&self, _sender: *mut NSObject) {
             info!("Quit menu item clicked");
             if let Some(callbacks) = CALLBACKS.get() {
                 (callbacks.on_quit)();
             }
-            MenuBar::stop();
         }
 
-        #[method(handleLanguageEnglish:)]
-        fn handle_language_english(&self, _sender: *mut NSObject) {
-            info!("Language English selected");
-            MenuBar::set_language("en");
+        #[method(handlePrivateSession:)]
+        fn handle_private_session(&self, _sender: *mut NSObject) {
+            info!("Private Session menu item clicked");
+            MenuBar::set_private_session(!private_mode::is_active());
+        }
+
+        #[method(handlePracticeScript:)]
+        fn handle_practice_script(&self, _sender: *mut NSObject) {
+            info!("Practice with Script menu item clicked");
+            if teleprompter::is_active() {
+                teleprompter::clear();
+            } else {
+                teleprompter::choose_script();
+            }
+            MenuBar::update_ui();
+        }
+
+        #[method(handlePrintTranscript:)]
+        fn handle_print_transcript(&self, _sender: *mut NSObject) {
+            info!("Print Transcript menu item clicked");
+            TranscriptionWindow::handle_print_action();
         }
 
-        #[method(handleLanguageNorwegian:)]
-        fn handle_language_norwegian(&self, _sender: *mut NSObject) {
-            info!("Language Norwegian selected");
-            MenuBar::set_language("no");
+        #[method(handleCreateIssues:)]
+        fn handle_create_issues(&self, _sender: *mut NSObject) {
+            info!("Create Issues menu item clicked");
+            issues::create_from_meeting_notes();
+        }
+
+        #[method(handleComparePolish:)]
+        fn handle_compare_polish(&self, _sender: *mut NSObject) {
+            info!("Compare Polish Outputs menu item clicked");
+            polish_compare::compare_polish_outputs();
+        }
+
+        #[method(handleDemoSession:)]
+        fn handle_demo_session(&self, _sender: *mut NSObject) {
+            info!("Try Demo Session menu item clicked");
+            if let Some(callbacks) = CALLBACKS.get() {
+                (callbacks.on_start_demo)();
+            }
         }
 
-        #[method(handleLanguageDanish:)]
-        fn handle_language_danish(&self, _sender: *mut NSObject) {
-            info!("Language Danish selected");
-            MenuBar::set_language("da");
+        #[method(handleSelectLanguage:)]
+        fn handle_select_language(&self, sender: *mut NSObject) {
+            // SAFETY: the sender is one of the Languages submenu's
+            // NSMenuItems, tagged with the language's index
+            let tag: isize = unsafe { msg_send![sender, tag] };
+            super::languages::select(tag);
         }
 
-        #[method(handleLanguageFinnish:)]
-        fn handle_language_finnish(&self, _sender: *mut NSObject) {
-            info!("Language Finnish selected");
-            MenuBar::set_language("fi");
+        #[method(handleCustomizeLanguages:)]
+        fn handle_customize_languages(&self, _sender: *mut NSObject) {
+            info!("Customize Languages menu item clicked");
+            super::languages::customize();
         }
 
-        #[method(handleLanguageGerman:)]
-        fn handle_language_german(&self, _sender: *mut NSObject) {
-            info!("Language German selected");
-            MenuBar::set_language("de");
+        #[method(handleReportProblem:)]
+        fn handle_report_problem(&self, _sender: *mut NSObject) {
+            info!("Report a Problem menu item clicked");
+            bug_report::report_problem();
         }
 
         #[method(handleAbout:)]
@@ -7,6 +7,8 @@ use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{NSImage, NSStatusBarButton, NSStatusItem};
 use objc2_foundation::{MainThreadMarker, NSData, NSSize};
 
+use super::indicator::RecordingIndicator;
+
 // Embedded icons as PNG data (18x18 template images)
 
 /// Idle icon - microphone outline (18x18 PNG)
@@ -19,17 +21,21 @@ const ICON_RECORDING: &[u8] = include_bytes!("../../assets/icon_recording.png");
 const ICON_PROCESSING: &[u8] = include_bytes!("../../assets/icon_processing.png");
 
 /// Set the menu bar icon based on recording/processing state
+///
+/// A discreet `indicator` shows the recording icon as a template, in the
+/// menu bar's own color rather than red.
 pub(super) fn set_icon(
     status_item: &NSStatusItem,
     is_recording: bool,
     is_processing: bool,
+    indicator: RecordingIndicator,
     mtm: MainThreadMarker,
 ) {
     let (icon_data, is_template) = if is_processing {
         // Processing icon is NOT a template - it should stay orange
         (ICON_PROCESSING, false)
     } else if is_recording {
-        (ICON_RECORDING, false)
+        (ICON_RECORDING, indicator == RecordingIndicator::Discreet)
     } else {
         (ICON_IDLE, true)
     };
@@ -0,0 +1,111 @@
+//! How visibly the menu bar shows a recording in progress
+//!
+//! Where recording others needs their consent, a prominent indicator pulses
+//! the red icon so a recording is hard to miss. For dictating on your own, a
+//! discreet indicator shows a plain filled microphone instead of the red one.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::NSStatusBarButton;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use super::menu_bar;
+
+/// Time between the bright and dim phases of the pulse
+const PULSE_INTERVAL: Duration = Duration::from_millis(700);
+
+/// Opacity of the icon in the dim phase of the pulse
+const PULSE_DIM_ALPHA: f64 = 0.3;
+
+/// Whether the pulse thread is running
+static PULSING: AtomicBool = AtomicBool::new(false);
+
+/// Style of the menu bar icon while recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RecordingIndicator {
+    /// Red microphone
+    #[default]
+    Standard,
+    /// Pulsing red microphone
+    Prominent,
+    /// Filled microphone in the menu bar's own color
+    Discreet,
+}
+
+impl RecordingIndicator {
+    /// All styles, in the order shown in Settings
+    pub(crate) const ALL: [RecordingIndicator; 3] = [
+        RecordingIndicator::Standard,
+        RecordingIndicator::Prominent,
+        RecordingIndicator::Discreet,
+    ];
+
+    /// Look up a style by its index in `ALL` (used as the segment index)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|style| *style == self)
+            .unwrap_or_default() as isize
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            RecordingIndicator::Standard => "Standard",
+            RecordingIndicator::Prominent => "Prominent",
+            RecordingIndicator::Discreet => "Discreet",
+        }
+    }
+}
+
+/// Start or stop pulsing the icon.
+///
+/// The pulse runs on its own thread, which dims and brightens the status
+/// item's button on the main thread until pulsing is turned off.
+pub(super) fn set_pulsing(pulse: bool) {
+    if !pulse {
+        PULSING.store(false, Ordering::SeqCst);
+        return;
+    }
+    if PULSING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::spawn(|| {
+        let mut dim = false;
+        while PULSING.load(Ordering::SeqCst) {
+            std::thread::sleep(PULSE_INTERVAL);
+            dim = !dim && PULSING.load(Ordering::SeqCst);
+            set_icon_alpha(if dim { PULSE_DIM_ALPHA } else { 1.0 });
+        }
+        set_icon_alpha(1.0);
+    });
+}
+
+/// Set the status item button's opacity on the main thread.
+fn set_icon_alpha(alpha: f64) {
+    dispatch::Queue::main().exec_async(move || {
+        let Some(menu_bar) = menu_bar() else {
+            return;
+        };
+        let Ok(inner) = menu_bar.try_borrow() else {
+            return;
+        };
+        // SAFETY: the status item and its button are used on the main thread
+        unsafe {
+            let button: Option<Retained<NSStatusBarButton>> =
+                msg_send_id![&inner.status_item, button];
+            if let Some(button) = button {
+                let _: () = msg_send![&button, setAlphaValue: alpha];
+            }
+        }
+    });
+}
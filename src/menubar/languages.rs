@@ -0,0 +1,192 @@
+//! Languages submenu
+//!
+//! Lists the favorite transcription languages saved in preferences, with a
+//! checkmark on the one in use, followed by "Customize…" to pick the
+//! favorites from every language offered. The list is rebuilt each time the
+//! menu opens and after a change, like the Workspace submenu.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id, sel};
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSButton, NSMenu, NSMenuItem, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use tracing::{error, info};
+
+use super::delegate::VissperMenuDelegate;
+use super::items::create_menu_item;
+use super::{menu_bar, MenuBar};
+use crate::languages::{self, LANGUAGES};
+use crate::localization::tr;
+use crate::preferences;
+use crate::settings_window::constants::NS_BUTTON_TYPE_SWITCH;
+
+/// NSAlertFirstButtonReturn
+const ALERT_FIRST_BUTTON: isize = 1000;
+
+/// Width of each column of checkboxes in the Customize dialog
+const COLUMN_WIDTH: CGFloat = 160.0;
+
+/// Height of each checkbox row in the Customize dialog
+const ROW_HEIGHT: CGFloat = 24.0;
+
+/// Build the Languages submenu, returning its parent item and the submenu
+pub(super) fn build_languages_submenu(
+    mtm: MainThreadMarker,
+    menu: &NSMenu,
+    delegate: &VissperMenuDelegate,
+) -> (Retained<NSMenuItem>, Retained<NSMenu>) {
+    let submenu = NSMenu::new(mtm);
+    unsafe { submenu.setAutoenablesItems(false) };
+    fill(mtm, &submenu, delegate);
+
+    let languages_item = {
+        let title_str = NSString::from_str(tr("menu.languages"));
+        let key = NSString::from_str("");
+        unsafe {
+            NSMenuItem::initWithTitle_action_keyEquivalent(mtm.alloc(), &title_str, None, &key)
+        }
+    };
+    languages_item.setSubmenu(Some(&submenu));
+    menu.addItem(&languages_item);
+
+    (languages_item, submenu)
+}
+
+/// Rebuild the submenu from the current favorites (main thread only)
+pub(super) fn refresh() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let Some(menu_bar) = menu_bar() else {
+        return;
+    };
+    let Ok(inner) = menu_bar.try_borrow() else {
+        return;
+    };
+    unsafe { inner.languages_submenu.removeAllItems() };
+    fill(mtm, &inner.languages_submenu, &inner.delegate);
+}
+
+/// Add one item per listed language, tagged with its index in `LANGUAGES`,
+/// then the Customize item
+fn fill(mtm: MainThreadMarker, submenu: &NSMenu, delegate: &VissperMenuDelegate) {
+    let current = preferences::get_language_code();
+
+    for language in languages::menu_languages() {
+        let Some(index) = LANGUAGES.iter().position(|l| l == language) else {
+            continue;
+        };
+        let item = create_menu_item(
+            mtm,
+            language.native_name,
+            sel!(handleSelectLanguage:),
+            delegate,
+        );
+        // SAFETY: setTag: on a valid NSMenuItem
+        unsafe {
+            let _: () = msg_send![&item, setTag: index as isize];
+            item.setState(if language.code == current { 1 } else { 0 });
+        }
+        submenu.addItem(&item);
+    }
+
+    submenu.addItem(&NSMenuItem::separatorItem(mtm));
+    let customize_item = create_menu_item(
+        mtm,
+        tr("menu.customize_languages"),
+        sel!(handleCustomizeLanguages:),
+        delegate,
+    );
+    submenu.addItem(&customize_item);
+}
+
+/// Transcribe in the language at `index` in `LANGUAGES`
+pub(super) fn select(index: isize) {
+    let Some(language) = usize::try_from(index).ok().and_then(|i| LANGUAGES.get(i)) else {
+        return;
+    };
+    info!("Language {} selected", language.name);
+    MenuBar::set_language(language.code);
+}
+
+/// Ask which languages to list in the menu and save them (main thread only)
+pub(super) fn customize() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let (grid, checkboxes) = create_checkbox_grid(mtm, &languages::favorites());
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(tr("languages.customize_title")));
+        alert.setInformativeText(&NSString::from_str(tr("languages.customize_message")));
+        alert.setAccessoryView(Some(&grid));
+        alert.addButtonWithTitle(&NSString::from_str(tr("languages.save")));
+        alert.addButtonWithTitle(&NSString::from_str(tr("languages.cancel")));
+        alert.runModal()
+    };
+
+    if response != ALERT_FIRST_BUTTON {
+        return;
+    }
+
+    let checked: Vec<&str> = LANGUAGES
+        .iter()
+        .zip(&checkboxes)
+        // SAFETY: reading the state of a valid NSButton on the main thread
+        .filter(|(_, checkbox)| unsafe { checkbox.state() } == 1)
+        .map(|(language, _)| language.code)
+        .collect();
+    if let Err(e) = languages::set_favorites(&checked) {
+        error!("Failed to save menu languages: {}", e);
+        return;
+    }
+    refresh();
+}
+
+/// Lay out a checkbox per language in two columns, checking `favorites`
+fn create_checkbox_grid(
+    mtm: MainThreadMarker,
+    favorites: &[&languages::Language],
+) -> (Retained<NSView>, Vec<Retained<NSButton>>) {
+    let rows = LANGUAGES.len().div_ceil(2);
+    let height = ROW_HEIGHT * rows as CGFloat;
+
+    // SAFETY: view allocation and configuration on the main thread with valid frames
+    unsafe {
+        let grid: Retained<NSView> = msg_send_id![
+            mtm.alloc::<NSView>(),
+            initWithFrame: NSRect::new(
+                NSPoint::new(0.0, 0.0),
+                NSSize::new(COLUMN_WIDTH * 2.0, height),
+            )
+        ];
+
+        // Views are not flipped, so the first row goes at the top; the
+        // languages run down the first column, then the second
+        let checkboxes = LANGUAGES
+            .iter()
+            .enumerate()
+            .map(|(index, language)| {
+                let x = COLUMN_WIDTH * (index / rows) as CGFloat;
+                let y = height - ROW_HEIGHT * (index % rows + 1) as CGFloat;
+                let checkbox: Retained<NSButton> = msg_send_id![
+                    mtm.alloc::<NSButton>(),
+                    initWithFrame: NSRect::new(
+                        NSPoint::new(x, y),
+                        NSSize::new(COLUMN_WIDTH, ROW_HEIGHT),
+                    )
+                ];
+                let _: () = msg_send![&checkbox, setButtonType: NS_BUTTON_TYPE_SWITCH];
+                checkbox.setTitle(&NSString::from_str(language.native_name));
+                let checked = favorites.contains(&language);
+                let _: () = msg_send![&checkbox, setState: isize::from(checked)];
+                grid.addSubview(&checkbox);
+                checkbox
+            })
+            .collect();
+
+        (grid, checkboxes)
+    }
+}
@@ -6,29 +6,46 @@
 mod builder;
 mod delegate;
 mod icons;
+mod indicator;
 mod items;
+mod languages;
 mod state;
+mod stop_keys;
+mod templates;
 mod updates;
+mod workspaces;
 
+pub(crate) use indicator::RecordingIndicator;
 pub use state::{AppState, MenuCallbacks};
 
 use builder::{apply_initial_state, build_menu_items};
 use delegate::VissperMenuDelegate;
+use objc2::msg_send;
 use objc2::rc::Retained;
 use objc2_app_kit::{
-    NSApplication, NSApplicationActivationPolicy, NSMenu, NSMenuItem, NSStatusBar, NSStatusItem,
+    NSApplication, NSApplicationActivationPolicy, NSEvent, NSEventModifierFlags, NSEventType,
+    NSMenu, NSMenuItem, NSStatusBar, NSStatusItem,
 };
-use objc2_foundation::MainThreadMarker;
+use objc2_foundation::{MainThreadMarker, NSPoint};
 use once_cell::sync::OnceCell;
-use std::sync::{Arc, Mutex};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use crate::app_context;
+use crate::event_bus::AppEvent;
+use crate::transcription_window::TabType;
 
 /// Global state for menu bar (needed for Objective-C callbacks)
-pub(super) static MENU_BAR: OnceCell<Mutex<MenuBarInner>> = OnceCell::new();
 pub(super) static APP_STATE: OnceCell<Arc<AppState>> = OnceCell::new();
 pub(super) static CALLBACKS: OnceCell<MenuCallbacks> = OnceCell::new();
 
+/// The menu bar's retained objects, once created (main thread only)
+pub(super) fn menu_bar() -> Option<&'static RefCell<MenuBarInner>> {
+    app_context::windows()?.menu_bar.get()
+}
+
 /// Inner menu bar state holding retained references
-pub(super) struct MenuBarInner {
+pub(crate) struct MenuBarInner {
     pub(super) status_item: Retained<NSStatusItem>,
     #[allow(dead_code)]
     menu: Retained<NSMenu>,
@@ -36,30 +53,26 @@ pub(super) struct MenuBarInner {
     delegate: Retained<VissperMenuDelegate>,
     pub(super) recording_item: Retained<NSMenuItem>,
     pub(super) stop_submenu: Retained<NSMenu>,
-    #[allow(dead_code)]
-    stop_no_polish_item: Retained<NSMenuItem>,
-    #[allow(dead_code)]
-    stop_basic_polish_item: Retained<NSMenuItem>,
-    #[allow(dead_code)]
-    stop_meeting_notes_item: Retained<NSMenuItem>,
+    pub(super) stop_items: Vec<Retained<NSMenuItem>>,
     pub(super) show_window_item: Retained<NSMenuItem>,
     pub(super) screenshots_item: Retained<NSMenuItem>,
     #[allow(dead_code)]
     pub(super) screenshots_submenu: Retained<NSMenu>,
     pub(super) screenshot_fullscreen_item: Retained<NSMenuItem>,
     pub(super) screenshot_region_item: Retained<NSMenuItem>,
+    pub(super) screenshot_last_region_item: Retained<NSMenuItem>,
+    pub(super) private_session_item: Retained<NSMenuItem>,
+    pub(super) practice_script_item: Retained<NSMenuItem>,
     pub(super) settings_item: Retained<NSMenuItem>,
     pub(super) languages_item: Retained<NSMenuItem>,
-    pub(super) lang_english_item: Retained<NSMenuItem>,
-    pub(super) lang_norwegian_item: Retained<NSMenuItem>,
-    pub(super) lang_danish_item: Retained<NSMenuItem>,
-    pub(super) lang_finnish_item: Retained<NSMenuItem>,
-    pub(super) lang_german_item: Retained<NSMenuItem>,
+    pub(super) languages_submenu: Retained<NSMenu>,
+    pub(super) workspace_item: Retained<NSMenuItem>,
+    pub(super) workspace_submenu: Retained<NSMenu>,
+    pub(super) templates_item: Retained<NSMenuItem>,
+    pub(super) templates_submenu: Retained<NSMenu>,
     pub(super) update_available_item: Retained<NSMenuItem>,
 }
 
-unsafe impl Send for MenuBarInner {}
-
 /// Menu bar manager
 pub struct MenuBar;
 
@@ -88,7 +101,13 @@ impl MenuBar {
         let status_item = unsafe { status_bar.statusItemWithLength(-2.0) };
 
         // Set initial icon (idle state)
-        icons::set_icon(&status_item, false, false, mtm);
+        icons::set_icon(
+            &status_item,
+            false,
+            false,
+            RecordingIndicator::default(),
+            mtm,
+        );
 
         // Create menu and disable auto-enabling so we control enabled state
         let menu = NSMenu::new(mtm);
@@ -98,26 +117,30 @@ impl MenuBar {
         let (
             recording_item,
             stop_submenu,
-            stop_no_polish_item,
-            stop_basic_polish_item,
-            stop_meeting_notes_item,
+            stop_items,
             show_window_item,
             screenshots_item,
             screenshots_submenu,
             screenshot_fullscreen_item,
             screenshot_region_item,
+            screenshot_last_region_item,
+            private_session_item,
+            practice_script_item,
             settings_item,
             languages_item,
-            lang_english_item,
-            lang_norwegian_item,
-            lang_danish_item,
-            lang_finnish_item,
-            lang_german_item,
+            languages_submenu,
+            workspace_item,
+            workspace_submenu,
+            templates_item,
+            templates_submenu,
             update_available_item,
         ) = build_menu_items(mtm, &menu, &delegate);
 
-        // Attach menu to status item
-        unsafe { status_item.setMenu(Some(&menu)) };
+        // Attach menu to status item, telling the delegate when it opens
+        unsafe {
+            let _: () = msg_send![&menu, setDelegate: &*delegate];
+            status_item.setMenu(Some(&menu));
+        }
 
         // Apply initial UI state
         apply_initial_state(
@@ -127,39 +150,42 @@ impl MenuBar {
             &screenshots_item,
             &screenshot_fullscreen_item,
             &screenshot_region_item,
+            &screenshot_last_region_item,
             &languages_item,
         );
 
-        // Store in global state
+        // Store in the main-thread window registry
         let inner = MenuBarInner {
             status_item,
             menu,
             delegate,
             recording_item,
             stop_submenu,
-            stop_no_polish_item,
-            stop_basic_polish_item,
-            stop_meeting_notes_item,
+            stop_items,
             show_window_item,
             screenshots_item,
             screenshots_submenu,
             screenshot_fullscreen_item,
             screenshot_region_item,
+            screenshot_last_region_item,
+            private_session_item,
+            practice_script_item,
             settings_item,
             languages_item,
-            lang_english_item,
-            lang_norwegian_item,
-            lang_danish_item,
-            lang_finnish_item,
-            lang_german_item,
+            languages_submenu,
+            workspace_item,
+            workspace_submenu,
+            templates_item,
+            templates_submenu,
             update_available_item,
         };
 
-        MENU_BAR.set(Mutex::new(inner)).ok();
+        if let Some(windows) = app_context::windows() {
+            windows.menu_bar.set(RefCell::new(inner)).ok();
+        }
     }
 
     /// Update the menu bar UI based on current state
-    #[allow(dead_code)]
     pub fn update_ui() {
         updates::update_ui();
     }
@@ -181,6 +207,11 @@ impl MenuBar {
         updates::set_processing(processing);
     }
 
+    /// Turn private sessions on or off (thread-safe)
+    pub fn set_private_session(active: bool) {
+        updates::set_private_session(active);
+    }
+
     /// Set the transcription language and update the menu checkmarks
     pub fn set_language(code: &str) {
         updates::set_language(code);
@@ -191,6 +222,20 @@ impl MenuBar {
         updates::show_update_available(version);
     }
 
+    /// Show the update menu item when a newer version is announced
+    pub fn handle_event(event: &AppEvent) {
+        if let AppEvent::UpdateAvailable { version } = event {
+            updates::show_update_available(version);
+        }
+    }
+
+    /// Stop the recording as the stop submenu's item for `tab` would
+    pub fn stop_recording(tab: TabType) {
+        if let Some(callbacks) = CALLBACKS.get() {
+            (callbacks.on_stop_with_mode)(tab);
+        }
+    }
+
     /// Hide update available menu item (thread-safe)
     pub fn hide_update_available() {
         updates::hide_update_available();
@@ -205,11 +250,31 @@ impl MenuBar {
         unsafe { app.run() };
     }
 
-    /// Stop the application
+    /// Stop the application event loop so [`MenuBar::run`] returns
     pub fn stop() {
         if let Some(mtm) = MainThreadMarker::new() {
             let app = NSApplication::sharedApplication(mtm);
             app.stop(None);
+
+            // `stop:` only takes effect after the next event is processed,
+            // which may never come for an idle menu bar app, so post one
+            // SAFETY: creating and posting an application-defined event on the main thread
+            unsafe {
+                let event = NSEvent::otherEventWithType_location_modifierFlags_timestamp_windowNumber_context_subtype_data1_data2(
+                    NSEventType::ApplicationDefined,
+                    NSPoint::new(0.0, 0.0),
+                    NSEventModifierFlags(0),
+                    0.0,
+                    0,
+                    None,
+                    0,
+                    0,
+                    0,
+                );
+                if let Some(event) = event {
+                    app.postEvent_atStart(&event, true);
+                }
+            }
         }
     }
 
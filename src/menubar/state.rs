@@ -2,6 +2,8 @@
 
 use std::sync::atomic::AtomicBool;
 
+use crate::transcription_window::TabType;
+
 /// Application state shared between menu callbacks
 #[derive(Debug)]
 pub struct AppState {
@@ -29,13 +31,17 @@ impl Default for AppState {
 /// Callbacks for menu actions
 pub struct MenuCallbacks {
     pub on_start_recording: Box<dyn Fn() + Send + Sync>,
-    pub on_stop_no_polish: Box<dyn Fn() + Send + Sync>,
-    pub on_stop_basic_polish: Box<dyn Fn() + Send + Sync>,
-    pub on_stop_meeting_notes: Box<dyn Fn() + Send + Sync>,
+    /// Replay the bundled sample meeting as a recording
+    pub on_start_demo: Box<dyn Fn() + Send + Sync>,
+    /// Stop recording with the output mode of a tab (Live = no polishing)
+    pub on_stop_with_mode: Box<dyn Fn(TabType) + Send + Sync>,
     pub on_show_window: Box<dyn Fn() + Send + Sync>,
     pub on_screenshot: Box<dyn Fn() + Send + Sync>,
     pub on_region_screenshot: Box<dyn Fn() + Send + Sync>,
+    pub on_last_region_screenshot: Box<dyn Fn() + Send + Sync>,
     pub on_settings: Box<dyn Fn() + Send + Sync>,
     pub on_quit: Box<dyn Fn() + Send + Sync>,
     pub on_update_available: Box<dyn Fn() + Send + Sync>,
+    /// The status item's menu is about to open
+    pub on_menu_opened: Box<dyn Fn() + Send + Sync>,
 }
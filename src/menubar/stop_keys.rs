@@ -0,0 +1,45 @@
+//! Key equivalents of the Stop Recording submenu
+//!
+//! Each stop item shows the hotkey stopping with its mode from Settings,
+//! falling back to the recording toggle's for a mode whose stop hotkey is
+//! off. They are refreshed each time the menu opens, like the Languages
+//! submenu, so a change in Settings shows up right away.
+
+use objc2::msg_send;
+use objc2_foundation::NSString;
+
+use super::menu_bar;
+use crate::preferences;
+use crate::transcription_window::TabType;
+
+/// Key and modifier mask shown on the stop item of `tab`
+pub(super) fn key_equivalent(tab: TabType) -> (&'static str, u64) {
+    if let Some(key) = preferences::get_stop_hotkeys().get(tab).key_equivalent() {
+        return key;
+    }
+    match tab {
+        TabType::Live => (" ", 262144),
+        TabType::BasicPolish => ("1", 393216),
+        TabType::MeetingNotes => ("2", 393216),
+        _ => ("", 0),
+    }
+}
+
+/// Show the current stop hotkeys on the stop items (main thread only)
+pub(super) fn refresh() {
+    let Some(menu_bar) = menu_bar() else {
+        return;
+    };
+    let Ok(inner) = menu_bar.try_borrow() else {
+        return;
+    };
+    for item in &inner.stop_items {
+        // SAFETY: tag and key equivalent calls on a valid NSMenuItem
+        unsafe {
+            let tag: isize = msg_send![item, tag];
+            let (key, mask) = key_equivalent(TabType::from_index(tag));
+            let _: () = msg_send![item, setKeyEquivalent: &*NSString::from_str(key)];
+            let _: () = msg_send![item, setKeyEquivalentModifierMask: mask];
+        }
+    }
+}
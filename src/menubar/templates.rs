@@ -0,0 +1,103 @@
+//! Templates submenu
+//!
+//! Lists the recording templates set up in Settings; picking one applies its
+//! language and provider and starts a recording with it. Like the Workspace
+//! submenu, the list is rebuilt each time the menu opens and is disabled
+//! while recording.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, sel};
+use objc2_app_kit::{NSMenu, NSMenuItem};
+use objc2_foundation::{MainThreadMarker, NSString};
+use tracing::{error, info};
+
+use super::delegate::VissperMenuDelegate;
+use super::items::create_menu_item;
+use super::workspaces::update_credentials;
+use super::{menu_bar, CALLBACKS};
+use crate::localization::tr;
+use crate::{preferences, templates};
+
+/// Build the Templates submenu, returning its parent item and the submenu
+pub(super) fn build_templates_submenu(
+    mtm: MainThreadMarker,
+    menu: &NSMenu,
+    delegate: &VissperMenuDelegate,
+) -> (Retained<NSMenuItem>, Retained<NSMenu>) {
+    let submenu = NSMenu::new(mtm);
+    unsafe { submenu.setAutoenablesItems(false) };
+    fill(mtm, &submenu, delegate);
+
+    let templates_item = {
+        let title_str = NSString::from_str(tr("menu.templates"));
+        let key = NSString::from_str("");
+        unsafe {
+            NSMenuItem::initWithTitle_action_keyEquivalent(mtm.alloc(), &title_str, None, &key)
+        }
+    };
+    templates_item.setSubmenu(Some(&submenu));
+    menu.addItem(&templates_item);
+
+    (templates_item, submenu)
+}
+
+/// Rebuild the submenu from the current templates (main thread only)
+pub(super) fn refresh(delegate: &VissperMenuDelegate) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let Some(menu_bar) = menu_bar() else {
+        return;
+    };
+    let Ok(inner) = menu_bar.try_borrow() else {
+        return;
+    };
+    unsafe { inner.templates_submenu.removeAllItems() };
+    fill(mtm, &inner.templates_submenu, delegate);
+}
+
+/// Add one item per template, tagged with its index in the templates list,
+/// or a disabled hint where to set them up
+fn fill(mtm: MainThreadMarker, submenu: &NSMenu, delegate: &VissperMenuDelegate) {
+    let templates = preferences::get_templates();
+    if templates.is_empty() {
+        let item = create_menu_item(
+            mtm,
+            tr("menu.no_templates"),
+            sel!(handleSettings:),
+            delegate,
+        );
+        unsafe { item.setEnabled(false) };
+        submenu.addItem(&item);
+        return;
+    }
+
+    for (index, template) in templates.iter().enumerate() {
+        let item = create_menu_item(mtm, &template.name, sel!(handleStartTemplate:), delegate);
+        // SAFETY: setTag: on a valid NSMenuItem
+        unsafe {
+            let _: () = msg_send![&item, setTag: index as isize];
+        }
+        submenu.addItem(&item);
+    }
+}
+
+/// Apply the template at `index` and start a recording with it
+pub(super) fn start(index: isize) {
+    let templates = preferences::get_templates();
+    let Some(template) = usize::try_from(index).ok().and_then(|i| templates.get(i)) else {
+        return;
+    };
+    if let Err(e) = templates::apply(template) {
+        error!("Failed to apply template: {}", e);
+        return;
+    }
+    info!("Starting recording with template {}", template.name);
+
+    // The template may use another provider for transcription
+    update_credentials();
+
+    if let Some(callbacks) = CALLBACKS.get() {
+        (callbacks.on_start_recording)();
+    }
+}
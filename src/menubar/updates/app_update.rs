@@ -8,11 +8,12 @@ use objc2::ClassType;
 use objc2_app_kit::{NSColor, NSFont};
 use objc2_foundation::{MainThreadMarker, NSMutableAttributedString, NSRange, NSString};
 
-use crate::menubar::MENU_BAR;
+use crate::localization::tr;
+use crate::menubar::menu_bar;
 
 /// Show update available menu item (thread-safe)
 pub fn show_update_available(version: &str) {
-    let title = format!("Update Available: v{}", version);
+    let title = format!("{}: v{}", tr("menu.update_available"), version);
 
     if MainThreadMarker::new().is_some() {
         update_update_item(&title, false);
@@ -37,10 +38,10 @@ pub fn hide_update_available() {
 
 /// Update the update item (must be called on main thread)
 fn update_update_item(title: &str, hidden: bool) {
-    let Some(menu_bar) = MENU_BAR.get() else {
+    let Some(menu_bar) = menu_bar() else {
         return;
     };
-    let Ok(inner) = menu_bar.lock() else {
+    let Ok(inner) = menu_bar.try_borrow() else {
         return;
     };
 
@@ -8,23 +8,25 @@ mod state;
 
 pub use app_update::{hide_update_available, show_update_available};
 pub use language::set_language;
-pub use state::{set_azure_credentials, set_processing, set_recording};
+pub use state::{set_azure_credentials, set_private_session, set_processing, set_recording};
 
 use objc2_foundation::MainThreadMarker;
 use std::sync::atomic::Ordering;
 
-use super::icons;
-use super::{APP_STATE, MENU_BAR};
+use super::{icons, indicator, RecordingIndicator};
+use super::{menu_bar, APP_STATE};
+use crate::localization::tr;
+use crate::{managed, preferences, private_mode, teleprompter};
 
 /// Update the menu bar UI based on current state
 pub(super) fn update_ui() {
     let Some(state) = APP_STATE.get() else {
         return;
     };
-    let Some(menu_bar) = MENU_BAR.get() else {
+    let Some(menu_bar) = menu_bar() else {
         return;
     };
-    let Ok(inner) = menu_bar.lock() else {
+    let Ok(inner) = menu_bar.try_borrow() else {
         return;
     };
 
@@ -32,21 +34,25 @@ pub(super) fn update_ui() {
     let is_processing = state.is_processing.load(Ordering::SeqCst);
     let has_azure_credentials = state.has_azure_credentials.load(Ordering::SeqCst);
 
-    // Update icon
+    // Update icon, pulsing it while recording if the indicator is prominent
+    let style = preferences::get_recording_indicator();
     if let Some(mtm) = MainThreadMarker::new() {
-        icons::set_icon(&inner.status_item, is_recording, is_processing, mtm);
+        icons::set_icon(&inner.status_item, is_recording, is_processing, style, mtm);
     }
+    indicator::set_pulsing(
+        is_recording && !is_processing && style == RecordingIndicator::Prominent,
+    );
 
     // Update recording item
     if is_recording {
-        let title_str = objc2_foundation::NSString::from_str("Stop Recording");
+        let title_str = objc2_foundation::NSString::from_str(tr("menu.stop_recording"));
         unsafe {
             inner.recording_item.setTitle(&title_str);
             inner.recording_item.setSubmenu(Some(&inner.stop_submenu));
             inner.recording_item.setEnabled(true);
         }
     } else {
-        let title_str = objc2_foundation::NSString::from_str("Start Recording");
+        let title_str = objc2_foundation::NSString::from_str(tr("menu.start_recording"));
         unsafe {
             inner.recording_item.setTitle(&title_str);
             inner.recording_item.setSubmenu(None);
@@ -54,13 +60,51 @@ pub(super) fn update_ui() {
         }
     }
 
+    // Private sessions can't be switched while a transcript is in progress,
+    // and don't allow screenshots; a managed policy may disable them entirely
+    let is_private = private_mode::is_active();
+    let screenshots_allowed = !is_private && !managed::policy().disable_screenshots;
+    unsafe {
+        inner
+            .private_session_item
+            .setState(if is_private { 1 } else { 0 });
+        inner
+            .private_session_item
+            .setEnabled(!is_recording && !is_processing);
+        inner.screenshots_item.setEnabled(screenshots_allowed);
+        inner
+            .screenshot_fullscreen_item
+            .setEnabled(screenshots_allowed);
+        inner.screenshot_region_item.setEnabled(screenshots_allowed);
+        inner
+            .screenshot_last_region_item
+            .setEnabled(screenshots_allowed);
+    }
+
+    // A session's files all land in the workspace it started in
+    unsafe {
+        inner
+            .workspace_item
+            .setEnabled(!is_recording && !is_processing);
+    }
+
+    // Templates start a recording
+    unsafe {
+        inner
+            .templates_item
+            .setEnabled(!is_recording && !is_processing);
+    }
+
+    unsafe {
+        inner
+            .practice_script_item
+            .setState(if teleprompter::is_active() { 1 } else { 0 });
+    }
+
     // These items are always enabled in OSS version
     unsafe {
         inner.settings_item.setEnabled(true);
         inner.show_window_item.setEnabled(true);
-        inner.screenshots_item.setEnabled(true);
-        inner.screenshot_fullscreen_item.setEnabled(true);
-        inner.screenshot_region_item.setEnabled(true);
         inner.languages_item.setEnabled(true);
     }
 }
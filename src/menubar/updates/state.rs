@@ -1,11 +1,13 @@
 //! State setter functions for menu bar updates
 //!
-//! Thread-safe functions for updating recording, processing, and Azure credentials states.
+//! Thread-safe functions for updating recording, processing, private session,
+//! and Azure credentials states.
 
 use std::sync::atomic::Ordering;
 
 use super::dispatch_ui_update;
 use crate::menubar::APP_STATE;
+use crate::private_mode;
 
 /// Set Azure credentials state (thread-safe)
 ///
@@ -37,3 +39,10 @@ pub fn set_processing(processing: bool) {
 
     dispatch_ui_update();
 }
+
+/// Turn private sessions on or off (thread-safe)
+pub fn set_private_session(active: bool) {
+    private_mode::set_active(active);
+
+    dispatch_ui_update();
+}
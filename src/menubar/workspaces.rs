@@ -0,0 +1,102 @@
+//! Workspace submenu
+//!
+//! Lists Default and the workspaces set up in Settings, with a checkmark on
+//! the active one. The list is rebuilt each time the menu opens, so changes
+//! made in Settings show up without restarting. Switching is disabled while
+//! recording, so a session's files all land in one workspace.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, sel};
+use objc2_app_kit::{NSMenu, NSMenuItem};
+use objc2_foundation::{MainThreadMarker, NSString};
+use std::sync::atomic::Ordering;
+use tracing::{error, info};
+
+use super::delegate::VissperMenuDelegate;
+use super::items::create_menu_item;
+use super::{menu_bar, MenuBar, APP_STATE};
+use crate::localization::tr;
+use crate::preferences::{self, AiProvider};
+use crate::{keychain, workspaces};
+
+/// Build the Workspace submenu, returning its parent item and the submenu
+pub(super) fn build_workspace_submenu(
+    mtm: MainThreadMarker,
+    menu: &NSMenu,
+    delegate: &VissperMenuDelegate,
+) -> (Retained<NSMenuItem>, Retained<NSMenu>) {
+    let submenu = NSMenu::new(mtm);
+    unsafe { submenu.setAutoenablesItems(false) };
+    fill(mtm, &submenu, delegate);
+
+    let workspace_item = {
+        let title_str = NSString::from_str(tr("menu.workspace"));
+        let key = NSString::from_str("");
+        unsafe {
+            NSMenuItem::initWithTitle_action_keyEquivalent(mtm.alloc(), &title_str, None, &key)
+        }
+    };
+    workspace_item.setSubmenu(Some(&submenu));
+    menu.addItem(&workspace_item);
+
+    (workspace_item, submenu)
+}
+
+/// Rebuild the submenu from the current workspaces (main thread only)
+pub(super) fn refresh(delegate: &VissperMenuDelegate) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let Some(menu_bar) = menu_bar() else {
+        return;
+    };
+    let Ok(inner) = menu_bar.try_borrow() else {
+        return;
+    };
+    unsafe { inner.workspace_submenu.removeAllItems() };
+    fill(mtm, &inner.workspace_submenu, delegate);
+}
+
+/// Add one item per workspace, tagged with its index in `workspaces::names`
+fn fill(mtm: MainThreadMarker, submenu: &NSMenu, delegate: &VissperMenuDelegate) {
+    let active = workspaces::active_name();
+    let is_recording = APP_STATE
+        .get()
+        .is_some_and(|state| state.is_recording.load(Ordering::SeqCst));
+
+    for (index, name) in workspaces::names().into_iter().enumerate() {
+        let item = create_menu_item(mtm, &name, sel!(handleSwitchWorkspace:), delegate);
+        // SAFETY: setTag: on a valid NSMenuItem
+        unsafe {
+            let _: () = msg_send![&item, setTag: index as isize];
+            item.setState(if name == active { 1 } else { 0 });
+            item.setEnabled(!is_recording);
+        }
+        submenu.addItem(&item);
+    }
+}
+
+/// Make the workspace at `index` in `workspaces::names` active
+pub(super) fn switch_to(index: isize) {
+    let names = workspaces::names();
+    let Some(name) = usize::try_from(index).ok().and_then(|i| names.get(i)) else {
+        return;
+    };
+    if let Err(e) = workspaces::switch_to(name) {
+        error!("Failed to switch workspace: {}", e);
+        return;
+    }
+    info!("Switched to workspace {}", name);
+
+    // The workspace may use another provider for transcription
+    update_credentials();
+}
+
+/// Enable recording if the transcription provider now in use has credentials
+pub(super) fn update_credentials() {
+    let has_credentials = match preferences::get_ai_provider() {
+        AiProvider::Azure => keychain::get_azure_credentials().is_ok(),
+        AiProvider::OpenAI => keychain::get_openai_credentials().is_ok(),
+    };
+    MenuBar::set_azure_credentials(has_credentials);
+}
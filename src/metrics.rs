@@ -0,0 +1,323 @@
+//! Opt-in local usage statistics
+//!
+//! When enabled in the Stats tab of Settings, counts sessions, recording time,
+//! reconnects and errors in `metrics.json` next to the preferences file, along
+//! with speaking analytics for the last session. The numbers are only shown in
+//! Settings; nothing is ever sent over the network.
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{error, warn};
+
+use crate::event_bus::AppEvent;
+use crate::preferences;
+use crate::speaking::SpeakingStats;
+
+/// When the current recording started, for measuring its duration
+static SESSION_START: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Speech committed in the current recording, for speaking analytics
+static SESSION_SEGMENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Serializes read-modify-write cycles of the metrics file
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Something worth counting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MetricEvent {
+    SessionStarted,
+    SessionEnded,
+    Reconnected,
+    ReconnectFailed,
+    TranscriptionError,
+    PolishSucceeded,
+    PolishFailed,
+}
+
+/// Counters stored on disk
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct Metrics {
+    /// When counting started (RFC 3339)
+    pub(crate) since: Option<String>,
+    pub(crate) sessions: u64,
+    pub(crate) recording_secs: u64,
+    pub(crate) longest_session_secs: u64,
+    pub(crate) reconnects: u64,
+    pub(crate) reconnect_failures: u64,
+    pub(crate) transcription_errors: u64,
+    pub(crate) polish_succeeded: u64,
+    pub(crate) polish_failed: u64,
+    /// Speaking analytics for the most recent session
+    pub(crate) last_session: Option<SpeakingStats>,
+}
+
+impl Metrics {
+    /// Count one event. `session_length` is only used for `SessionEnded`.
+    fn apply(&mut self, event: MetricEvent, session_length: Option<Duration>) {
+        match event {
+            MetricEvent::SessionStarted => self.sessions += 1,
+            MetricEvent::SessionEnded => {
+                let secs = session_length.map_or(0, |d| d.as_secs());
+                self.recording_secs += secs;
+                self.longest_session_secs = self.longest_session_secs.max(secs);
+            }
+            MetricEvent::Reconnected => self.reconnects += 1,
+            MetricEvent::ReconnectFailed => self.reconnect_failures += 1,
+            MetricEvent::TranscriptionError => self.transcription_errors += 1,
+            MetricEvent::PolishSucceeded => self.polish_succeeded += 1,
+            MetricEvent::PolishFailed => self.polish_failed += 1,
+        }
+    }
+
+    /// Multi-line summary for the Stats tab
+    pub(crate) fn summary(&self) -> String {
+        if self.sessions == 0 && self.polish_succeeded + self.polish_failed == 0 {
+            return "No sessions recorded yet".to_string();
+        }
+
+        let since = self
+            .since
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let average = self.recording_secs.checked_div(self.sessions).unwrap_or(0);
+        let per_session = if self.sessions == 0 {
+            0.0
+        } else {
+            self.reconnects as f64 / self.sessions as f64
+        };
+        let polish_total = self.polish_succeeded + self.polish_failed;
+        let polish_error_rate = if polish_total == 0 {
+            0.0
+        } else {
+            self.polish_failed as f64 * 100.0 / polish_total as f64
+        };
+
+        let mut summary = format!(
+            "Since {}\n\
+             Sessions: {} (total {}, average {}, longest {})\n\
+             Reconnects: {} ({:.1} per session), {} failed\n\
+             Transcription errors: {}\n\
+             Polishing: {} succeeded, {} failed ({:.0}% error rate)",
+            since,
+            self.sessions,
+            format_duration(self.recording_secs),
+            format_duration(average),
+            format_duration(self.longest_session_secs),
+            self.reconnects,
+            per_session,
+            self.reconnect_failures,
+            self.transcription_errors,
+            self.polish_succeeded,
+            self.polish_failed,
+            polish_error_rate,
+        );
+        if let Some(last_session) = &self.last_session {
+            summary.push_str("\n\n");
+            summary.push_str(&last_session.summary());
+        }
+        summary
+    }
+}
+
+/// Whether statistics are being recorded
+pub(crate) fn is_enabled() -> bool {
+    preferences::get_metrics_enabled()
+}
+
+/// Count the app events that statistics track.
+pub(crate) fn handle_event(event: &AppEvent) {
+    if let AppEvent::SegmentCommitted { text } = event {
+        if is_enabled() {
+            if let Ok(mut segments) = SESSION_SEGMENTS.lock() {
+                segments.push(text.clone());
+            }
+        }
+        return;
+    }
+
+    let metric = match event {
+        AppEvent::RecordingStarted => MetricEvent::SessionStarted,
+        AppEvent::RecordingStopped => MetricEvent::SessionEnded,
+        AppEvent::Reconnected => MetricEvent::Reconnected,
+        AppEvent::ReconnectFailed => MetricEvent::ReconnectFailed,
+        AppEvent::TranscriptionError => MetricEvent::TranscriptionError,
+        AppEvent::PolishCompleted { .. } => MetricEvent::PolishSucceeded,
+        AppEvent::PolishFailed => MetricEvent::PolishFailed,
+        _ => return,
+    };
+    record(metric);
+}
+
+/// Count an event if statistics are enabled.
+pub(crate) fn record(event: MetricEvent) {
+    if !is_enabled() {
+        return;
+    }
+
+    let session_length = match event {
+        MetricEvent::SessionStarted => {
+            if let Ok(mut start) = SESSION_START.lock() {
+                *start = Some(Instant::now());
+            }
+            if let Ok(mut segments) = SESSION_SEGMENTS.lock() {
+                segments.clear();
+            }
+            None
+        }
+        MetricEvent::SessionEnded => match SESSION_START.lock().ok().and_then(|mut s| s.take()) {
+            Some(start) => Some(start.elapsed()),
+            // Statistics were turned on mid-session, so the length is unknown
+            None => return,
+        },
+        _ => None,
+    };
+
+    let Ok(_guard) = FILE_LOCK.lock() else {
+        return;
+    };
+    let mut metrics = load();
+    if metrics.since.is_none() {
+        metrics.since = Some(Local::now().to_rfc3339());
+    }
+    metrics.apply(event, session_length);
+    if let (MetricEvent::SessionEnded, Some(length)) = (event, session_length) {
+        let segments = SESSION_SEGMENTS
+            .lock()
+            .map(|mut s| std::mem::take(&mut *s))
+            .unwrap_or_default();
+        metrics.last_session = Some(SpeakingStats::analyze(
+            &segments,
+            length,
+            &preferences::get_language_code(),
+        ));
+    }
+    if let Err(e) = save(&metrics) {
+        error!("Failed to save usage statistics: {}", e);
+    }
+}
+
+/// Current statistics
+pub(crate) fn load() -> Metrics {
+    let Some(path) = metrics_path() else {
+        return Metrics::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable usage statistics: {}", e);
+            Metrics::default()
+        }),
+        Err(_) => Metrics::default(),
+    }
+}
+
+/// Delete all recorded statistics.
+pub(crate) fn reset() -> std::io::Result<()> {
+    let _guard = FILE_LOCK.lock();
+    match metrics_path() {
+        Some(path) if path.exists() => fs::remove_file(path),
+        _ => Ok(()),
+    }
+}
+
+fn save(metrics: &Metrics) -> std::io::Result<()> {
+    let Some(path) = metrics_path() else {
+        return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(metrics)?;
+    fs::write(path, json)
+}
+
+fn metrics_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("Vissper").join("metrics.json"))
+}
+
+/// Compact duration, e.g. "45s", "12m", "3h 05m"
+fn format_duration(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_counts_events() {
+        let mut metrics = Metrics::default();
+        metrics.apply(MetricEvent::SessionStarted, None);
+        metrics.apply(MetricEvent::SessionEnded, Some(Duration::from_secs(600)));
+        metrics.apply(MetricEvent::SessionStarted, None);
+        metrics.apply(MetricEvent::SessionEnded, Some(Duration::from_secs(1200)));
+        metrics.apply(MetricEvent::Reconnected, None);
+        metrics.apply(MetricEvent::PolishFailed, None);
+
+        assert_eq!(metrics.sessions, 2);
+        assert_eq!(metrics.recording_secs, 1800);
+        assert_eq!(metrics.longest_session_secs, 1200);
+        assert_eq!(metrics.reconnects, 1);
+        assert_eq!(metrics.polish_failed, 1);
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(Metrics::default().summary(), "No sessions recorded yet");
+
+        let metrics = Metrics {
+            since: Some("2026-01-05T09:00:00+01:00".to_string()),
+            sessions: 4,
+            recording_secs: 2 * 3600,
+            longest_session_secs: 3600,
+            reconnects: 2,
+            reconnect_failures: 1,
+            transcription_errors: 0,
+            polish_succeeded: 3,
+            polish_failed: 1,
+            last_session: None,
+        };
+        let summary = metrics.summary();
+
+        assert!(summary.starts_with("Since 2026-01-05\n"));
+        assert!(summary.contains("Sessions: 4 (total 2h 00m, average 30m, longest 1h 00m)"));
+        assert!(summary.contains("Reconnects: 2 (0.5 per session), 1 failed"));
+        assert!(summary.contains("Polishing: 3 succeeded, 1 failed (25% error rate)"));
+        assert!(!summary.contains("Last session"));
+    }
+
+    #[test]
+    fn test_summary_includes_last_session() {
+        let metrics = Metrics {
+            sessions: 1,
+            recording_secs: 60,
+            last_session: Some(SpeakingStats {
+                words: 130,
+                duration_secs: 60,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(metrics
+            .summary()
+            .contains("\n\nLast session: 130 words, 130 words per minute\n"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(12 * 60 + 30), "12m");
+        assert_eq!(format_duration(3 * 3600 + 5 * 60), "3h 05m");
+    }
+}
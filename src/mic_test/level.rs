@@ -0,0 +1,158 @@
+//! Loudness of the test clip
+//!
+//! Levels are in dBFS: 0 is the loudest a sample can be, and quieter audio
+//! is further below zero. Everything is computed on the 16-bit samples the
+//! speech service would receive.
+
+/// Quietest level shown; anything below counts as silence
+const FLOOR_DB: f32 = -60.0;
+
+/// Peaks at or above this level are clipped
+const CLIPPING_DB: f32 = -0.5;
+
+/// Peaks below this level mean no usable audio reached the app
+const SILENT_PEAK_DB: f32 = -50.0;
+
+/// Speech with a lower average level is hard to transcribe
+const QUIET_RMS_DB: f32 = -40.0;
+
+/// Blocks in the live level meter
+const METER_BLOCKS: usize = 20;
+
+/// Bar heights of the waveform, from quiet to loud
+const WAVEFORM_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// How usable the recorded level is for transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    /// Nothing was heard
+    Silent,
+    /// Speech was heard but is too quiet
+    Quiet,
+    /// Loud enough without clipping
+    Good,
+    /// Loud parts were cut off
+    Clipping,
+}
+
+impl Verdict {
+    /// Judge a clip by its peak and average level
+    pub(crate) fn of(samples: &[i16]) -> Self {
+        let peak = peak_db(samples);
+        if peak < SILENT_PEAK_DB {
+            Verdict::Silent
+        } else if peak >= CLIPPING_DB {
+            Verdict::Clipping
+        } else if rms_db(samples) < QUIET_RMS_DB {
+            Verdict::Quiet
+        } else {
+            Verdict::Good
+        }
+    }
+
+    pub(crate) fn message(self) -> &'static str {
+        match self {
+            Verdict::Silent => {
+                "No sound was picked up. Check the input device and microphone permission."
+            }
+            Verdict::Quiet => "Your voice is quiet. Move closer or raise the input volume.",
+            Verdict::Good => "Your microphone level is good.",
+            Verdict::Clipping => "Your voice is too loud and clips. Lower the input volume.",
+        }
+    }
+}
+
+/// Loudest sample in dBFS, at least `FLOOR_DB`
+pub(crate) fn peak_db(samples: &[i16]) -> f32 {
+    let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+    to_db(f32::from(peak) / 32768.0)
+}
+
+/// Average (root mean square) level in dBFS, at least `FLOOR_DB`
+fn rms_db(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return FLOOR_DB;
+    }
+    let sum: f64 = samples
+        .iter()
+        .map(|&s| {
+            let s = f64::from(s) / 32768.0;
+            s * s
+        })
+        .sum();
+    to_db((sum / samples.len() as f64).sqrt() as f32)
+}
+
+/// A bar of filled and empty blocks for `db`, followed by the level
+pub(crate) fn meter(db: f32) -> String {
+    let filled = (((db - FLOOR_DB) / -FLOOR_DB) * METER_BLOCKS as f32).round() as usize;
+    let filled = filled.min(METER_BLOCKS);
+    format!(
+        "{}{}  {:.0} dB",
+        "▮".repeat(filled),
+        "▯".repeat(METER_BLOCKS - filled),
+        db
+    )
+}
+
+/// The clip's loudness over time as `width` bars
+pub(crate) fn waveform(samples: &[i16], width: usize) -> String {
+    if samples.is_empty() || width == 0 {
+        return String::new();
+    }
+    let bucket = samples.len().div_ceil(width);
+    samples
+        .chunks(bucket)
+        .map(|chunk| {
+            let level = (peak_db(chunk) - FLOOR_DB) / -FLOOR_DB;
+            let index = (level * (WAVEFORM_BARS.len() - 1) as f32).round() as usize;
+            WAVEFORM_BARS[index.min(WAVEFORM_BARS.len() - 1)]
+        })
+        .collect()
+}
+
+fn to_db(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        return FLOOR_DB;
+    }
+    (20.0 * amplitude.log10()).max(FLOOR_DB)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A sine wave at `amplitude` (0-1 of full scale)
+    fn tone(amplitude: f32, len: usize) -> Vec<i16> {
+        (0..len)
+            .map(|i| ((i as f32 * 0.1).sin() * amplitude * 32767.0) as i16)
+            .collect()
+    }
+
+    #[test]
+    fn test_verdict_follows_the_level() {
+        assert_eq!(Verdict::of(&[0; 1600]), Verdict::Silent);
+        assert_eq!(Verdict::of(&tone(0.005, 1600)), Verdict::Quiet);
+        assert_eq!(Verdict::of(&tone(0.3, 1600)), Verdict::Good);
+        assert_eq!(Verdict::of(&tone(1.0, 1600)), Verdict::Clipping);
+    }
+
+    #[test]
+    fn test_levels_are_in_dbfs() {
+        assert_eq!(peak_db(&[]), FLOOR_DB);
+        assert!((peak_db(&[i16::MIN]) - 0.0).abs() < 0.01);
+        assert!((peak_db(&[16384]) + 6.02).abs() < 0.01);
+        assert!((rms_db(&tone(1.0, 16000)) + 3.01).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_meter_and_waveform_have_fixed_width() {
+        assert_eq!(meter(-30.0), "▮▮▮▮▮▮▮▮▮▮▯▯▯▯▯▯▯▯▯▯  -30 dB");
+        assert_eq!(meter(FLOOR_DB).chars().filter(|&c| c == '▯').count(), 20);
+
+        let mut samples = vec![0; 800];
+        samples.extend(tone(1.0, 800));
+        let waveform = waveform(&samples, 4);
+        assert_eq!(waveform, "▁▁██");
+    }
+}
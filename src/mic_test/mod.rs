@@ -0,0 +1,223 @@
+//! Microphone test in Settings
+//!
+//! Before an important meeting, the Microphone tab of Settings records five
+//! seconds from the microphone while showing its level, shows the clip's
+//! waveform with a verdict on the level, and plays it back. Optionally the
+//! clip is then sent once to the configured speech service, so the whole
+//! pipeline from microphone to transcript is checked.
+//!
+//! The clip is captured exactly as a recording would be, at the provider's
+//! sample rate, and kept in memory only until the next test or recording.
+//! It is never written to disk or logged.
+
+mod level;
+mod playback;
+mod stt;
+
+pub(crate) use level::Verdict;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{error, info};
+
+use crate::audio::{self, AudioTuning, AZURE_SAMPLE_RATE, OPENAI_SAMPLE_RATE};
+use crate::event_bus::AppEvent;
+use crate::preferences::{self, AiProvider};
+use crate::transcription::AudioEncoding;
+
+/// Length of the test recording
+const TEST_DURATION: Duration = Duration::from_secs(5);
+
+/// Bars in the waveform shown after recording
+const WAVEFORM_WIDTH: usize = 60;
+
+/// Whether a recording is in progress; it has the microphone to itself
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Whether a test is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Set to end the running test early
+static CANCEL: AtomicBool = AtomicBool::new(false);
+
+/// The last test clip, for playing it again
+static CLIP: Mutex<Option<Clip>> = Mutex::new(None);
+
+/// Recorded test audio
+#[derive(Clone)]
+struct Clip {
+    samples: Vec<i16>,
+    sample_rate: u32,
+}
+
+/// What a running test reports to Settings
+#[derive(Debug, Clone)]
+pub(crate) enum Progress {
+    /// Level meter of the latest audio and the seconds left to record
+    Recording { meter: String, seconds_left: u64 },
+    /// The clip's waveform and verdict, while it plays back
+    Playing { waveform: String, verdict: Verdict },
+    /// The clip was sent to the speech service
+    Transcribing,
+    /// What the speech service heard
+    Transcript(String),
+    /// The test ended without a problem
+    Finished,
+    /// The test could not run, with a message to show
+    Failed(String),
+}
+
+/// Called with the test's progress from a background task
+pub(crate) type Reporter = fn(Progress);
+
+/// Why a test could not be started
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum MicTestError {
+    #[error("A microphone test is already running")]
+    Running,
+
+    #[error("Stop the recording to test the microphone")]
+    Recording,
+
+    #[error("Record a test first")]
+    NoClip,
+}
+
+/// Keep tests off the microphone while recording, and drop the last clip
+/// when a recording starts.
+pub(crate) fn handle_event(event: &AppEvent) {
+    match event {
+        AppEvent::RecordingStarted => {
+            RECORDING.store(true, Ordering::SeqCst);
+            CANCEL.store(true, Ordering::SeqCst);
+            if let Ok(mut clip) = CLIP.lock() {
+                *clip = None;
+            }
+        }
+        AppEvent::RecordingStopped => RECORDING.store(false, Ordering::SeqCst),
+        _ => {}
+    }
+}
+
+/// Record, show and play back a test clip, then transcribe it if asked.
+///
+/// Runs on the Tokio runtime and calls `report` as the test goes on.
+pub(crate) fn start(transcribe: bool, report: Reporter) -> Result<(), MicTestError> {
+    claim()?;
+    tokio::spawn(async move {
+        let result = run(transcribe, report).await;
+        RUNNING.store(false, Ordering::SeqCst);
+        report(match result {
+            Ok(()) => Progress::Finished,
+            Err(message) => Progress::Failed(message),
+        });
+    });
+    Ok(())
+}
+
+/// Play the last test clip again.
+pub(crate) fn play_again(report: Reporter) -> Result<(), MicTestError> {
+    let clip = CLIP
+        .lock()
+        .ok()
+        .and_then(|clip| clip.clone())
+        .ok_or(MicTestError::NoClip)?;
+    claim()?;
+    tokio::spawn(async move {
+        let result = play(clip, report).await;
+        RUNNING.store(false, Ordering::SeqCst);
+        report(match result {
+            Ok(()) => Progress::Finished,
+            Err(message) => Progress::Failed(message),
+        });
+    });
+    Ok(())
+}
+
+/// Mark a test as running unless one is running or a recording is active
+fn claim() -> Result<(), MicTestError> {
+    if RECORDING.load(Ordering::SeqCst) {
+        return Err(MicTestError::Recording);
+    }
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(MicTestError::Running);
+    }
+    CANCEL.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+async fn run(transcribe: bool, report: Reporter) -> Result<(), String> {
+    let clip = record(report).await?;
+    if let Ok(mut last) = CLIP.lock() {
+        *last = Some(clip.clone());
+    }
+    play(clip.clone(), report).await?;
+
+    if transcribe && !CANCEL.load(Ordering::SeqCst) {
+        report(Progress::Transcribing);
+        let transcript = stt::transcribe(clip.samples, clip.sample_rate).await?;
+        report(Progress::Transcript(transcript));
+    }
+    Ok(())
+}
+
+/// Record `TEST_DURATION` of audio at the rate a recording would use.
+async fn record(report: Reporter) -> Result<Clip, String> {
+    let provider_rate = match preferences::get_ai_provider() {
+        AiProvider::Azure => AZURE_SAMPLE_RATE,
+        AiProvider::OpenAI => OPENAI_SAMPLE_RATE,
+    };
+    let sample_rate = AudioEncoding::from_preferences().sample_rate(provider_rate);
+    let (mut handle, mut audio_rx) =
+        audio::start_capture_with_sample_rate(sample_rate, AudioTuning::default())
+            .map_err(|e| e.to_string())?;
+    info!("Microphone test recording at {} Hz", sample_rate);
+
+    let started = Instant::now();
+    let mut samples = Vec::new();
+    while started.elapsed() < TEST_DURATION && !CANCEL.load(Ordering::SeqCst) {
+        let wait = TEST_DURATION.saturating_sub(started.elapsed());
+        match tokio::time::timeout(wait, audio_rx.recv()).await {
+            Ok(Some(chunk)) => {
+                let left = TEST_DURATION.saturating_sub(started.elapsed());
+                report(Progress::Recording {
+                    meter: level::meter(level::peak_db(&chunk.samples)),
+                    seconds_left: left.as_secs() + 1,
+                });
+                samples.extend_from_slice(&chunk.samples);
+            }
+            // The capture ended, e.g. because there is no input device
+            Ok(None) => break,
+            Err(_) => {}
+        }
+    }
+    let _ = tokio::task::spawn_blocking(move || handle.stop()).await;
+
+    if CANCEL.load(Ordering::SeqCst) {
+        return Err("The test was stopped because a recording started.".to_string());
+    }
+    if samples.is_empty() {
+        error!("Microphone test captured no audio");
+        return Err("No audio was captured. Check the input device and permission.".to_string());
+    }
+    Ok(Clip {
+        samples,
+        sample_rate,
+    })
+}
+
+/// Show the clip's waveform and verdict and play it on the default output.
+async fn play(clip: Clip, report: Reporter) -> Result<(), String> {
+    report(Progress::Playing {
+        waveform: level::waveform(&clip.samples, WAVEFORM_WIDTH),
+        verdict: Verdict::of(&clip.samples),
+    });
+    tokio::task::spawn_blocking(move || playback::play(&clip.samples, clip.sample_rate, &CANCEL))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| {
+            error!("Microphone test playback failed: {}", e);
+            format!("Playback failed: {}", e)
+        })
+}
@@ -0,0 +1,114 @@
+//! Playing the test clip on the default output device
+//!
+//! The clip is mono at the capture rate, so it is stretched to the output
+//! device's rate with linear interpolation and copied to every channel.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{SampleFormat, StreamConfig};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info};
+
+use crate::audio::AudioCaptureError;
+
+/// Extra time after the last sample so the device can drain its buffer
+const DRAIN_TIME: Duration = Duration::from_millis(300);
+
+/// Play `samples` recorded at `sample_rate` and return when they have played.
+///
+/// Blocks the calling thread; stops early when `cancel` is set.
+pub(super) fn play(
+    samples: &[i16],
+    sample_rate: u32,
+    cancel: &AtomicBool,
+) -> Result<(), AudioCaptureError> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| AudioCaptureError::ConfigError("No audio output device found".into()))?;
+    let supported = device.default_output_config()?;
+    let format = supported.sample_format();
+    let config: StreamConfig = supported.into();
+    info!(
+        "Playing microphone test at {} Hz on {} channels",
+        config.sample_rate.0, config.channels
+    );
+
+    let output = Arc::new(resample(samples, sample_rate, config.sample_rate.0));
+    let position = Arc::new(AtomicUsize::new(0));
+    let channels = usize::from(config.channels);
+    let on_error = |e: cpal::StreamError| error!("Audio playback error: {}", e);
+
+    let stream = match format {
+        SampleFormat::F32 => {
+            let (output, position) = (output.clone(), position.clone());
+            device.build_output_stream(
+                &config,
+                move |data: &mut [f32], _| fill(data, channels, &output, &position, |s| s),
+                on_error,
+                None,
+            )?
+        }
+        SampleFormat::I16 => {
+            let (output, position) = (output.clone(), position.clone());
+            device.build_output_stream(
+                &config,
+                move |data: &mut [i16], _| {
+                    fill(data, channels, &output, &position, |s| {
+                        (s * f32::from(i16::MAX)) as i16
+                    })
+                },
+                on_error,
+                None,
+            )?
+        }
+        other => {
+            return Err(AudioCaptureError::UnsupportedFormat(format!("{:?}", other)));
+        }
+    };
+    stream.play()?;
+
+    let duration = Duration::from_secs_f64(output.len() as f64 / f64::from(config.sample_rate.0));
+    let deadline = std::time::Instant::now() + duration + DRAIN_TIME;
+    while std::time::Instant::now() < deadline && !cancel.load(Ordering::SeqCst) {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    Ok(())
+}
+
+/// Fill an interleaved output buffer from the mono clip, then silence.
+fn fill<T: Copy + Default>(
+    data: &mut [T],
+    channels: usize,
+    clip: &[f32],
+    position: &AtomicUsize,
+    convert: impl Fn(f32) -> T,
+) {
+    let mut next = position.load(Ordering::Relaxed);
+    for frame in data.chunks_mut(channels.max(1)) {
+        let value = clip.get(next).map_or_else(T::default, |&s| convert(s));
+        frame.fill(value);
+        next += 1;
+    }
+    position.store(next, Ordering::Relaxed);
+}
+
+/// Convert 16-bit samples at `from` Hz to -1..1 samples at `to` Hz.
+fn resample(samples: &[i16], from: u32, to: u32) -> Vec<f32> {
+    let scaled: Vec<f32> = samples.iter().map(|&s| f32::from(s) / 32768.0).collect();
+    if from == to || scaled.len() < 2 {
+        return scaled;
+    }
+    let step = f64::from(from) / f64::from(to);
+    let len = (scaled.len() as f64 / step) as usize;
+    (0..len)
+        .map(|i| {
+            let pos = i as f64 * step;
+            let index = pos as usize;
+            let frac = (pos - index as f64) as f32;
+            let a = scaled[index.min(scaled.len() - 1)];
+            let b = scaled[(index + 1).min(scaled.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
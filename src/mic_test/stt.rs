@@ -0,0 +1,132 @@
+//! Sending the test clip to the speech service once
+//!
+//! Uses the provider, credentials, encoding and noise reduction a recording
+//! would use, so a transcript here means the whole pipeline works. The clip
+//! is followed by a moment of silence so the service commits the speech.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{timeout_at, Instant};
+use tracing::{error, info};
+
+use crate::audio::AudioChunk;
+use crate::keychain;
+use crate::preferences::{self, AiProvider};
+use crate::transcription::{AudioEncoding, TranscriptEvent, TranscriptionClient};
+
+/// Silence sent after the clip so the service ends the utterance
+const TRAILING_SILENCE: Duration = Duration::from_millis(1500);
+
+/// Longest wait for the first transcript
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Wait after a committed segment for any that follow it
+const SETTLE_TIME: Duration = Duration::from_secs(2);
+
+/// Samples per chunk sent to the service (100 ms at 16 kHz)
+const CHUNK_SAMPLES: usize = 1600;
+
+/// Transcribe `samples`, captured at `sample_rate`, with the selected provider.
+///
+/// Returns the transcript, or a message saying what went wrong.
+pub(super) async fn transcribe(samples: Vec<i16>, sample_rate: u32) -> Result<String, String> {
+    let language_code = preferences::get_language_code();
+    let provider = preferences::get_ai_provider();
+    let client = Arc::new(
+        match provider {
+            AiProvider::Azure => TranscriptionClient::new_azure(language_code),
+            AiProvider::OpenAI => TranscriptionClient::new_openai(language_code),
+        }
+        .with_encoding(AudioEncoding::from_preferences())
+        .with_noise_reduction(preferences::get_noise_reduction()),
+    );
+    let mut events = client.subscribe();
+
+    let (audio_tx, audio_rx) = mpsc::channel(samples.len() / CHUNK_SAMPLES + 64);
+    let run_client = client.clone();
+    let mut session = match provider {
+        AiProvider::Azure => {
+            let creds = keychain::get_azure_credentials()
+                .map_err(|_| "Azure credentials are not set up.".to_string())?;
+            tokio::spawn(async move {
+                run_client
+                    .start_azure(
+                        &creds.endpoint_url,
+                        &creds.stt_deployment,
+                        creds.realtime_api_version(),
+                        &creds.api_key,
+                        audio_rx,
+                    )
+                    .await
+            })
+        }
+        AiProvider::OpenAI => {
+            let creds = keychain::get_openai_credentials()
+                .map_err(|_| "OpenAI credentials are not set up.".to_string())?;
+            let model = preferences::get_openai_transcribe_model();
+            tokio::spawn(async move {
+                run_client
+                    .start_openai(model, &creds.api_key, audio_rx)
+                    .await
+            })
+        }
+    };
+    info!("Sending microphone test to the speech service");
+
+    let silence_len = (TRAILING_SILENCE.as_secs_f64() * f64::from(sample_rate)) as usize;
+    let mut padded = samples;
+    padded.resize(padded.len() + silence_len, 0);
+    for chunk in padded.chunks(CHUNK_SAMPLES) {
+        let chunk = AudioChunk {
+            samples: chunk.to_vec(),
+            sample_rate,
+            captured_at: std::time::Instant::now(),
+        };
+        if audio_tx.send(chunk).await.is_err() {
+            break;
+        }
+    }
+
+    // Keep the audio channel open until the result is in, closing it ends the session
+    let mut transcript = Vec::new();
+    let mut deadline = Instant::now() + RESPONSE_TIMEOUT;
+    let result = loop {
+        tokio::select! {
+            event = timeout_at(deadline, events.recv()) => match event {
+                Ok(Ok(TranscriptEvent::CommittedTranscript { text })) => {
+                    transcript.push(text.trim().to_string());
+                    deadline = Instant::now() + SETTLE_TIME;
+                }
+                Ok(Ok(TranscriptEvent::Error { message })) => break Err(message),
+                Ok(Ok(TranscriptEvent::ProviderFailed)) => {
+                    break Err("The speech service could not be reached.".to_string())
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(_)) | Err(_) => break finish(transcript),
+            },
+            finished = &mut session => match finished {
+                Ok(Err(e)) => break Err(e.to_string()),
+                _ => break finish(transcript),
+            },
+        }
+    };
+
+    client.stop();
+    drop(audio_tx);
+    session.abort();
+    if let Err(ref message) = result {
+        error!("Microphone test transcription failed: {}", message);
+    }
+    result
+}
+
+/// The collected transcript, or an error when nothing was transcribed
+fn finish(transcript: Vec<String>) -> Result<String, String> {
+    let text = transcript.join(" ");
+    if text.trim().is_empty() {
+        Err("The speech service returned no transcript.".to_string())
+    } else {
+        Ok(text)
+    }
+}
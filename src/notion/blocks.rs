@@ -0,0 +1,156 @@
+//! Conversion of Markdown meeting notes to Notion blocks
+
+use serde_json::{json, Value};
+
+/// Longest text Notion accepts in one rich text object
+const MAX_TEXT_CHARS: usize = 2000;
+
+/// Convert Markdown notes to Notion blocks, one block per non-empty line.
+///
+/// Headings, bullets, numbered items, checkboxes, quotes and dividers map to
+/// their Notion counterparts and `**bold**` text keeps its emphasis.
+/// Screenshot references point at local files, so they are left out.
+pub(super) fn markdown_to_blocks(markdown: &str) -> Vec<Value> {
+    markdown.lines().filter_map(line_to_block).collect()
+}
+
+/// Notion block for one line of Markdown
+fn line_to_block(line: &str) -> Option<Value> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with("![") {
+        return None;
+    }
+    if line == "---" || line == "***" {
+        return Some(json!({ "object": "block", "type": "divider", "divider": {} }));
+    }
+
+    let (kind, text) = if let Some(text) = line.strip_prefix("### ") {
+        ("heading_3", text)
+    } else if let Some(text) = line.strip_prefix("## ") {
+        ("heading_2", text)
+    } else if let Some(text) = line.strip_prefix("# ") {
+        ("heading_1", text)
+    } else if let Some(text) = line.strip_prefix("> ") {
+        ("quote", text)
+    } else if let Some(text) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some(bullet_block(text));
+    } else if let Some(text) = numbered_item(line) {
+        ("numbered_list_item", text)
+    } else {
+        ("paragraph", line)
+    };
+
+    Some(block(kind, json!({ "rich_text": rich_text(text) })))
+}
+
+/// A bulleted item, or a to-do for a Markdown checkbox
+fn bullet_block(text: &str) -> Value {
+    for (marker, checked) in [("[ ] ", false), ("[x] ", true), ("[X] ", true)] {
+        if let Some(text) = text.strip_prefix(marker) {
+            return block(
+                "to_do",
+                json!({ "rich_text": rich_text(text), "checked": checked }),
+            );
+        }
+    }
+    block(
+        "bulleted_list_item",
+        json!({ "rich_text": rich_text(text) }),
+    )
+}
+
+/// Text of a `1. item` line
+fn numbered_item(line: &str) -> Option<&str> {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+fn block(kind: &str, content: Value) -> Value {
+    json!({ "object": "block", "type": kind, kind: content })
+}
+
+/// Rich text for a line, with `**bold**` spans annotated.
+///
+/// Long spans are split, since Notion limits the length of each text object.
+pub(super) fn rich_text(text: &str) -> Vec<Value> {
+    let mut spans = Vec::new();
+    for (index, span) in text.split("**").enumerate() {
+        // Odd spans sit between a pair of ** markers
+        let bold = index % 2 == 1;
+        let chars: Vec<char> = span.chars().collect();
+        for chunk in chars.chunks(MAX_TEXT_CHARS) {
+            let content: String = chunk.iter().collect();
+            spans.push(json!({
+                "type": "text",
+                "text": { "content": content },
+                "annotations": { "bold": bold },
+            }));
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(blocks: &[Value]) -> Vec<&str> {
+        blocks.iter().map(|b| b["type"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_markdown_to_blocks_kinds() {
+        let markdown = "# Part 1\n## Summary\nWe met.\n\n### [0:05] Budget\n- Point\n- [ ] Task\n- [x] Done\n2. Second\n> Note: late\n---\n![Screenshot](screenshots/a.png)\n";
+        let blocks = markdown_to_blocks(markdown);
+        assert_eq!(
+            kinds(&blocks),
+            vec![
+                "heading_1",
+                "heading_2",
+                "paragraph",
+                "heading_3",
+                "bulleted_list_item",
+                "to_do",
+                "to_do",
+                "numbered_list_item",
+                "quote",
+                "divider"
+            ]
+        );
+        assert_eq!(blocks[5]["to_do"]["checked"], false);
+        assert_eq!(blocks[6]["to_do"]["checked"], true);
+        assert_eq!(
+            blocks[3]["heading_3"]["rich_text"][0]["text"]["content"],
+            "[0:05] Budget"
+        );
+    }
+
+    #[test]
+    fn test_rich_text_bold_spans() {
+        let spans = rich_text("**Bob:** book the venue");
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|s| {
+                (
+                    s["text"]["content"].as_str().unwrap(),
+                    s["annotations"]["bold"].as_bool().unwrap(),
+                )
+            })
+            .filter(|(content, _)| !content.is_empty())
+            .collect();
+        assert_eq!(parts, vec![("Bob:", true), (" book the venue", false)]);
+    }
+
+    #[test]
+    fn test_rich_text_splits_long_text() {
+        let spans = rich_text(&"a".repeat(MAX_TEXT_CHARS + 10));
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[1]["text"]["content"].as_str().unwrap().len(), 10);
+    }
+}
@@ -0,0 +1,186 @@
+//! Notion API calls for creating a session page
+
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::blocks::rich_text;
+use crate::error::NotionError;
+use crate::keychain::NotionCredentials;
+
+const NOTION_API_URL: &str = "https://api.notion.com/v1";
+
+/// API version sent with every request
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Most blocks Notion accepts in one request
+const MAX_BLOCKS_PER_REQUEST: usize = 100;
+
+/// Timeout for each request to Notion
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A session's meeting notes and the details shown as page properties
+pub(super) struct SessionPage {
+    pub(super) title: String,
+    pub(super) date: DateTime<Local>,
+    pub(super) attendees: Vec<String>,
+    pub(super) duration: Option<Duration>,
+    pub(super) blocks: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Database {
+    properties: HashMap<String, Property>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Property {
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreatedPage {
+    id: String,
+}
+
+/// Create a page for the session in the configured database.
+///
+/// The body is sent with the page up to Notion's block limit and appended
+/// in batches after that.
+pub(super) async fn create_page(
+    creds: &NotionCredentials,
+    page: &SessionPage,
+) -> Result<(), NotionError> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+
+    let database: Database = send(
+        client.get(format!(
+            "{}/databases/{}",
+            NOTION_API_URL, creds.database_id
+        )),
+        creds,
+    )
+    .await?;
+    let properties = page_properties(&database, page)?;
+
+    let mut batches = page.blocks.chunks(MAX_BLOCKS_PER_REQUEST);
+    let body = json!({
+        "parent": { "database_id": creds.database_id },
+        "properties": properties,
+        "children": batches.next().unwrap_or_default(),
+    });
+    let created: CreatedPage = send(
+        client.post(format!("{}/pages", NOTION_API_URL)).json(&body),
+        creds,
+    )
+    .await?;
+
+    for batch in batches {
+        let _: Value = send(
+            client
+                .patch(format!("{}/blocks/{}/children", NOTION_API_URL, created.id))
+                .json(&json!({ "children": batch })),
+            creds,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Send an authenticated request and parse the JSON response.
+async fn send<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+    creds: &NotionCredentials,
+) -> Result<T, NotionError> {
+    let response = request
+        .bearer_auth(&creds.token)
+        .header("Notion-Version", NOTION_VERSION)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(NotionError::ServerError {
+            status: response.status().as_u16(),
+            message: response.text().await.unwrap_or_default(),
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| NotionError::InvalidResponse(format!("Failed to parse response: {}", e)))
+}
+
+/// Page properties for the columns the database has.
+///
+/// The title goes in the database's title column. "Date", "Attendees" and
+/// "Duration" are filled if a column with that name (any case) and a
+/// supported type exists, and skipped otherwise.
+fn page_properties(database: &Database, page: &SessionPage) -> Result<Value, NotionError> {
+    let title_column = database
+        .properties
+        .iter()
+        .find(|(_, property)| property.kind == "title")
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| NotionError::InvalidResponse("Database has no title column".to_string()))?;
+
+    let mut properties = Map::new();
+    properties.insert(title_column, json!({ "title": rich_text(&page.title) }));
+
+    let column = |name: &str| {
+        database
+            .properties
+            .iter()
+            .find(|(column, _)| column.eq_ignore_ascii_case(name))
+            .map(|(column, property)| (column.clone(), property.kind.as_str()))
+    };
+
+    if let Some((name, "date")) = column("Date") {
+        properties.insert(name, json!({ "date": { "start": page.date.to_rfc3339() } }));
+    }
+
+    if !page.attendees.is_empty() {
+        match column("Attendees") {
+            Some((name, "multi_select")) => {
+                // Commas aren't allowed in select options
+                let options: Vec<Value> = page
+                    .attendees
+                    .iter()
+                    .map(|attendee| json!({ "name": attendee.replace(',', " ") }))
+                    .collect();
+                properties.insert(name, json!({ "multi_select": options }));
+            }
+            Some((name, "rich_text")) => {
+                properties.insert(
+                    name,
+                    json!({ "rich_text": rich_text(&page.attendees.join(", ")) }),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(duration) = page.duration {
+        let minutes = duration.as_secs().div_ceil(60);
+        match column("Duration") {
+            Some((name, "number")) => {
+                properties.insert(name, json!({ "number": minutes }));
+            }
+            Some((name, "rich_text")) => {
+                properties.insert(
+                    name,
+                    json!({ "rich_text": rich_text(&format!("{} min", minutes)) }),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Value::Object(properties))
+}
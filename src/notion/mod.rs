@@ -0,0 +1,187 @@
+//! Export each session's meeting notes to a Notion database
+//!
+//! When turned on in the Export tab of Settings, the first meeting notes
+//! generated for a recording become a page in the configured database, using
+//! an internal integration token kept in the keychain. The page is titled
+//! with the session's start time and, if the database has matching columns,
+//! gets the date, the attendees named in the notes and the duration in
+//! minutes. The notes themselves are converted to Notion blocks.
+//!
+//! Nothing is exported during private sessions, and the notes are never logged.
+
+mod blocks;
+mod client;
+
+use chrono::{DateTime, Local};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+use crate::event_bus::AppEvent;
+use crate::transcription_window::{TabType, TranscriptionWindow};
+use crate::{attendees, consent, feedback, issues, keychain, preferences, private_mode};
+
+use client::SessionPage;
+
+/// Section of the meeting notes that lists who took part
+const ATTENDEES_HEADING: &str = "Attendees";
+
+static RUNTIME: OnceCell<tokio::runtime::Handle> = OnceCell::new();
+
+/// The current or last recording
+static SESSION: Mutex<Session> = Mutex::new(Session {
+    started: None,
+    duration: None,
+    exported: false,
+});
+
+/// When the session started, how long it ran and whether it was exported
+struct Session {
+    started: Option<(DateTime<Local>, Instant)>,
+    duration: Option<Duration>,
+    exported: bool,
+}
+
+/// Database ID from a pasted ID or database link.
+///
+/// Notion links end in the 32-character ID, optionally followed by a query;
+/// IDs may also be written with dashes.
+pub(crate) fn parse_database_id(input: &str) -> Option<String> {
+    let path = input.trim().split(['?', '#']).next().unwrap_or_default();
+    let last = path.rsplit('/').next().unwrap_or_default();
+    let hex: String = last.chars().filter(|c| *c != '-').collect();
+    let id = hex.get(hex.len().checked_sub(32)?..)?;
+    id.chars()
+        .all(|c| c.is_ascii_hexdigit())
+        .then(|| id.to_ascii_lowercase())
+}
+
+/// Remember the runtime that exports run on.
+pub(crate) fn init(runtime: tokio::runtime::Handle) {
+    if RUNTIME.set(runtime).is_err() {
+        warn!("Notion export already initialized");
+    }
+}
+
+/// Track sessions and export their meeting notes once ready.
+pub(crate) fn handle_event(event: &AppEvent) {
+    let Ok(mut session) = SESSION.lock() else {
+        return;
+    };
+
+    match event {
+        AppEvent::RecordingStarted => {
+            *session = Session {
+                started: Some((Local::now(), Instant::now())),
+                duration: None,
+                exported: false,
+            };
+        }
+        AppEvent::RecordingStopped => {
+            session.duration = session.started.map(|(_, instant)| instant.elapsed());
+        }
+        AppEvent::PolishCompleted {
+            tab: TabType::MeetingNotes,
+        } => {
+            let Some((started, _)) = session.started else {
+                return;
+            };
+            if session.exported || !preferences::get_notion_export() || private_mode::is_active() {
+                return;
+            }
+            // Later meeting notes for the same recording don't get another page
+            session.exported = true;
+            let duration = session.duration;
+            drop(session);
+            export_meeting_notes(started, duration);
+        }
+        _ => {}
+    }
+}
+
+/// Read the meeting notes and export them in the background.
+fn export_meeting_notes(started: DateTime<Local>, duration: Option<Duration>) {
+    // Generated content lives in the transcription window and is read on the
+    // main thread, after the update that stored the new notes
+    dispatch::Queue::main().exec_async(move || {
+        let Some(notes) = TranscriptionWindow::get_generated_content(TabType::MeetingNotes) else {
+            warn!("Meeting notes missing, skipping Notion export");
+            return;
+        };
+        let Some(runtime) = RUNTIME.get() else {
+            warn!("Notion export requested before initialization");
+            return;
+        };
+        runtime.spawn(export(notes, started, duration));
+    });
+}
+
+/// Create the session's page in Notion and report failures.
+async fn export(notes: String, started: DateTime<Local>, duration: Option<Duration>) {
+    let creds = match keychain::get_notion_credentials() {
+        Ok(creds) => creds,
+        Err(e) => {
+            warn!("Notion export is on but no connection is saved: {}", e);
+            return;
+        }
+    };
+
+    // Names typed in the overlay come first, then any others the notes list.
+    // Notes polished in parts list the attendees once per part
+    let mut attendees = attendees::current();
+    attendees.extend(issues::parse_section_items(&notes, ATTENDEES_HEADING));
+    let mut seen = std::collections::HashSet::new();
+    attendees.retain(|attendee| seen.insert(attendee.to_lowercase()));
+
+    let page = SessionPage {
+        title: format!("Meeting notes {}", started.format("%Y-%m-%d %H:%M")),
+        date: started,
+        attendees,
+        duration,
+        blocks: blocks::markdown_to_blocks(&consent::add_to_export(
+            TabType::MeetingNotes,
+            &notes,
+            started,
+        )),
+    };
+
+    match client::create_page(&creds, &page).await {
+        Ok(()) => info!(
+            blocks = page.blocks.len(),
+            "Exported meeting notes to Notion"
+        ),
+        Err(e) => {
+            error!("Failed to export meeting notes to Notion: {}", e);
+            feedback::notify_now(
+                "Notion export failed",
+                "The meeting notes were not added to Notion. Check the connection in the Export tab of Settings.",
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_database_id() {
+        let id = "0123456789abcdef0123456789abcdef";
+        assert_eq!(parse_database_id(id).as_deref(), Some(id));
+        assert_eq!(
+            parse_database_id("01234567-89ab-cdef-0123-456789ABCDEF").as_deref(),
+            Some(id)
+        );
+        assert_eq!(
+            parse_database_id(&format!(
+                "https://www.notion.so/acme/Meetings-{}?v=1234",
+                id
+            ))
+            .as_deref(),
+            Some(id)
+        );
+        assert_eq!(parse_database_id("not an id"), None);
+        assert_eq!(parse_database_id(""), None);
+    }
+}
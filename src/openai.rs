@@ -3,9 +3,15 @@
 //! This module provides a client that connects directly to OpenAI's Chat Completions API.
 //! Users provide their own OpenAI API key.
 
+use crate::attendees;
 use crate::error::ResponseError;
 use crate::keychain::OpenAICredentials;
-use crate::response::{language_code_to_name, PolishConfig};
+use crate::language_sections;
+use crate::output_modes;
+use crate::response::{language_code_to_name, PolishConfig, FOLLOW_UP_EMAIL_PROMPT_TEMPLATE};
+use crate::retry::{self, RetryNotice};
+use crate::topics;
+use crate::workspaces;
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -22,12 +28,13 @@ const INITIAL_RETRY_DELAY_MS: u64 = 1000;
 const OPENAI_API_URL: &str = "https://api.openai.com/v1/chat/completions";
 
 /// Default model for polishing
-const POLISH_MODEL: &str = "gpt-5.2";
+pub(crate) const POLISH_MODEL: &str = "gpt-5.2";
 
 /// Client for direct OpenAI Chat Completions API calls.
 pub(crate) struct OpenAIClient {
     api_key: String,
     client: reqwest::Client,
+    retry_notice: Option<RetryNotice>,
 }
 
 /// Request body for OpenAI Chat Completions API.
@@ -68,6 +75,8 @@ const POLISH_PROMPT_TEMPLATE: &str = r#"You are an expert copy editor. Your task
 
 IMPORTANT: The transcript may contain screenshot references in markdown image format like `![Screenshot](screenshots/filename.png)`. These must be preserved exactly as they appear, in their original positions within the transcript. Do not modify, remove, or relocate these screenshot references.
 
+The transcript may also contain timestamped markers like `=== MARKER 14:32 ===` that the user added to flag important moments, and typed notes like `> Note: Bob joined late` that add context not in the audio. Preserve each marker and note exactly as it appears, on its own line, at its original position in the transcript. Lines like `[connection lost 14:02–14:03, audio may be missing]` mark where the recording lost its connection; keep them the same way and do not fill in what might have been said.
+
 Return only the polished transcript without any additional commentary."#;
 
 /// System prompt template for live meeting recording.
@@ -79,6 +88,9 @@ Extract and organize the key information into the following sections:
 ## Summary
 Provide a concise overview of what the meeting was about and its main outcomes. Use as many sentences as needed to capture the essence, proportional to the meeting length and complexity.
 
+## Attendees
+List the people who took part in the meeting, one name per bullet point, if they can be identified from the transcript.
+
 ## Main Items
 List the most important topics or points discussed in the meeting. Include only the most significant items, maximum 7 bullet points.
 
@@ -98,18 +110,40 @@ Condense and polish the transcript for readability. Remove filler words, meaning
 
 IMPORTANT: The transcript may contain screenshot references in markdown image format like `![Screenshot](screenshots/filename.png)`. These must be preserved exactly as they appear, in their original positions within the transcript. Do not modify, remove, or relocate these screenshot references.
 
+The transcript may also contain timestamped markers like `=== MARKER 14:32 ===` that the user added to flag important moments, and typed notes like `> Note: Bob joined late` that add context not in the audio. Preserve each marker and note exactly as it appears, on its own line, at its original position in the transcript. Lines like `[connection lost 14:02–14:03, audio may be missing]` mark where the recording lost its connection; keep them the same way and do not fill in what might have been said.
+
 If a section has no relevant content from the transcript, write "None identified" for that section.
 
 Return the output in the format above with the section headers as shown."#;
 
-/// Select the appropriate prompt based on config, with language injected
+/// Select the appropriate prompt based on config, with language and the
+/// session's attendees injected
 fn select_prompt(config: &PolishConfig) -> String {
     let language = language_code_to_name(&config.language_code);
+    attendees::add_to_prompt(
+        prompt_template(config).replace("{language}", language),
+        config,
+    )
+}
+
+/// Prompt template for config, with the active workspace's instructions,
+/// before the language is injected
+pub(crate) fn prompt_template(config: &PolishConfig) -> String {
     let template = match config.prompt_type.as_deref() {
-        Some("live_meeting") => LIVE_MEETING_PROMPT_TEMPLATE,
-        _ => POLISH_PROMPT_TEMPLATE,
+        Some("live_meeting") => LIVE_MEETING_PROMPT_TEMPLATE.to_string(),
+        Some("meeting_topics") => format!(
+            "{}\n\n{}",
+            LIVE_MEETING_PROMPT_TEMPLATE,
+            topics::PROMPT_INSTRUCTIONS
+        ),
+        Some("follow_up_email") => FOLLOW_UP_EMAIL_PROMPT_TEMPLATE.to_string(),
+        Some("custom") => match config.custom_mode.and_then(output_modes::custom_mode) {
+            Some(mode) => mode.prompt_template(),
+            None => POLISH_PROMPT_TEMPLATE.to_string(),
+        },
+        _ => POLISH_PROMPT_TEMPLATE.to_string(),
     };
-    template.replace("{language}", language)
+    workspaces::add_to_prompt(template)
 }
 
 impl OpenAIClient {
@@ -124,20 +158,27 @@ impl OpenAIClient {
         Ok(Self {
             api_key: creds.api_key.clone(),
             client,
+            retry_notice: None,
         })
     }
 
+    /// Set a callback that is told when the client waits on a rate limit and when it resumes.
+    pub(crate) fn with_retry_notice(mut self, notice: RetryNotice) -> Self {
+        self.retry_notice = Some(notice);
+        self
+    }
+
     /// Polish a transcript using OpenAI Chat Completions API.
     ///
     /// Sends the raw transcript to OpenAI for copyediting and polishing.
-    /// Includes retry logic for transient network failures.
+    /// Includes retry logic for transient network failures and rate limits.
     #[instrument(skip(self, transcript, config), fields(transcript_len = transcript.len()))]
     pub(crate) async fn polish_transcript(
         &self,
         transcript: &str,
         config: &PolishConfig,
     ) -> Result<String, ResponseError> {
-        let prompt = select_prompt(config);
+        let prompt = language_sections::add_to_prompt(select_prompt(config), transcript);
         let request_body = ChatCompletionRequest {
             model: POLISH_MODEL.to_string(),
             messages: vec![
@@ -154,17 +195,23 @@ impl OpenAIClient {
 
         let mut last_error: Option<ResponseError> = None;
         let mut retry_delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS);
+        let mut rate_limit_wait: Option<Duration> = None;
+        let mut rate_limited_for = Duration::ZERO;
 
         for attempt in 0..=MAX_RETRIES {
             if attempt > 0 {
+                let delay = rate_limit_wait.take().unwrap_or(retry_delay);
                 warn!(
                     attempt = attempt,
                     max_retries = MAX_RETRIES,
-                    delay_ms = retry_delay.as_millis(),
+                    delay_ms = delay.as_millis(),
                     "Retrying OpenAI polish request after transient failure"
                 );
-                tokio::time::sleep(retry_delay).await;
+                tokio::time::sleep(delay).await;
                 retry_delay *= 2;
+                if let Some(notice) = &self.retry_notice {
+                    notice(None);
+                }
             }
 
             let result = self
@@ -201,10 +248,37 @@ impl OpenAIClient {
                     }
 
                     let status = response.status().as_u16();
+                    let server_hint = Self::retry_after_hint(response.headers());
                     let message = response.text().await.unwrap_or_default();
+                    let quota_exhausted = retry::is_quota_exhausted(&message);
 
                     let error = ResponseError::ServerError { status, message };
 
+                    // Retry on rate limits, honoring the server's Retry-After hint
+                    // as long as the waits fit in the rate-limit budget
+                    let rate_limit_delay = if status == retry::TOO_MANY_REQUESTS
+                        && !quota_exhausted
+                        && attempt < MAX_RETRIES
+                    {
+                        retry::next_rate_limit_delay(server_hint, retry_delay, rate_limited_for)
+                    } else {
+                        None
+                    };
+                    if let Some(delay) = rate_limit_delay {
+                        warn!(
+                            attempt = attempt,
+                            delay_ms = delay.as_millis(),
+                            "Rate limited, will retry"
+                        );
+                        if let Some(notice) = &self.retry_notice {
+                            notice(Some(delay));
+                        }
+                        rate_limited_for += delay;
+                        rate_limit_wait = Some(delay);
+                        last_error = Some(error);
+                        continue;
+                    }
+
                     // Retry on 5xx server errors
                     if (500..600).contains(&status) && attempt < MAX_RETRIES {
                         warn!(
@@ -247,6 +321,16 @@ impl OpenAIClient {
             })
     }
 
+    /// Read the server's retry hint from rate-limit response headers.
+    fn retry_after_hint(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        retry::parse_retry_after(
+            header("retry-after"),
+            header("retry-after-ms"),
+            chrono::Utc::now(),
+        )
+    }
+
     /// Check if a reqwest error is retryable (transient).
     fn is_retryable_error(error: &reqwest::Error) -> bool {
         error.is_timeout() || error.is_connect() || error.is_request()
@@ -319,6 +403,7 @@ mod tests {
             reasoning_effort: None,
             prompt_type: None,
             language_code: "en".to_string(),
+            custom_mode: None,
         };
         let prompt = select_prompt(&config);
         assert!(prompt.contains("The output MUST be in English"));
@@ -331,9 +416,40 @@ mod tests {
             reasoning_effort: None,
             prompt_type: Some("live_meeting".to_string()),
             language_code: "da".to_string(),
+            custom_mode: None,
         };
         let prompt = select_prompt(&config);
         assert!(prompt.contains("The output MUST be in Danish"));
         assert!(prompt.contains("## Summary"));
     }
+
+    #[test]
+    fn test_select_prompt_follow_up_email() {
+        let config = PolishConfig {
+            reasoning_effort: None,
+            prompt_type: Some("follow_up_email".to_string()),
+            language_code: "fi".to_string(),
+            custom_mode: None,
+        };
+        let prompt = select_prompt(&config);
+        assert!(prompt.contains("The output MUST be in Finnish"));
+        assert!(prompt.contains("Subject:"));
+        assert!(!prompt.contains("## Summary"));
+    }
+
+    #[test]
+    fn test_prompts_preserve_markers_and_notes() {
+        for prompt_type in [None, Some("live_meeting".to_string())] {
+            let config = PolishConfig {
+                reasoning_effort: None,
+                prompt_type,
+                language_code: "en".to_string(),
+                custom_mode: None,
+            };
+            let prompt = select_prompt(&config);
+            assert!(prompt.contains("=== MARKER 14:32 ==="));
+            assert!(prompt.contains("> Note:"));
+            assert!(prompt.contains("[connection lost"));
+        }
+    }
 }
@@ -0,0 +1,342 @@
+//! Output modes: what a recording can be turned into
+//!
+//! Each output mode pairs a tab in the transcription window with the polish
+//! config that produces its content. Besides the built-in modes (basic
+//! polishing, meeting notes and the follow-up email), users define their own
+//! in the Notes tab of Settings: a name, a prompt, whether the result is
+//! Markdown or plain text, and the format the Save button suggests. The stop
+//! submenu, the transcription window tabs and on-demand generation are all
+//! built from this registry.
+//!
+//! Custom modes are read once per launch, like the interface language, so
+//! the tabs and menu items built at startup keep matching them; edits apply
+//! the next time Vissper starts.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::localization::tr;
+use crate::preferences;
+use crate::response::PolishConfig;
+use crate::transcription_window::TabType;
+
+/// Most custom modes, to keep the tab bar readable
+pub(crate) const MAX_CUSTOM_MODES: usize = 3;
+
+/// Built-in modes that polish the transcript, in menu and tab order
+const BUILT_IN: [TabType; 3] = [
+    TabType::BasicPolish,
+    TabType::MeetingNotes,
+    TabType::FollowUpEmail,
+];
+
+/// How a custom mode's result is formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum OutputFormat {
+    /// Markdown headings, lists and emphasis
+    #[default]
+    Markdown,
+    /// Plain text without Markdown formatting
+    PlainText,
+}
+
+/// File format the Save button suggests for a mode's result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ExportFormat {
+    #[default]
+    Markdown,
+    Pdf,
+}
+
+/// An output mode defined by the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CustomMode {
+    pub name: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub format: OutputFormat,
+    #[serde(default)]
+    pub export: ExportFormat,
+}
+
+impl CustomMode {
+    /// System prompt template for polishing, with the output language left as
+    /// a `{language}` placeholder like the built-in prompts
+    pub(crate) fn prompt_template(&self) -> String {
+        let format = match self.format {
+            OutputFormat::Markdown => "Format the output as Markdown.",
+            OutputFormat::PlainText => "Return plain text without any Markdown formatting.",
+        };
+        format!(
+            "{}\n\nThe output MUST be in {{language}}. Do not translate to any other language. \
+             {} Leave out screenshot references like `![Screenshot](screenshots/filename.png)` \
+             and markers like `=== MARKER 14:32 ===` unless asked for above. Return only the \
+             output without any additional commentary.",
+            self.prompt.trim(),
+            format
+        )
+    }
+}
+
+/// Custom modes in effect for this launch
+static CUSTOM_MODES: Lazy<Vec<CustomMode>> = Lazy::new(|| {
+    let mut modes = preferences::get_custom_output_modes();
+    modes.truncate(MAX_CUSTOM_MODES);
+    modes
+});
+
+/// Custom modes in effect for this launch
+pub(crate) fn custom_modes() -> &'static [CustomMode] {
+    &CUSTOM_MODES
+}
+
+/// The custom mode shown in `Custom(index)`, if it exists
+pub(crate) fn custom_mode(index: usize) -> Option<&'static CustomMode> {
+    CUSTOM_MODES.get(index)
+}
+
+/// Tabs of all modes that polish the transcript, built-in modes first
+pub(crate) fn polish_tabs() -> Vec<TabType> {
+    BUILT_IN
+        .into_iter()
+        .chain((0..custom_modes().len()).map(TabType::Custom))
+        .collect()
+}
+
+/// Polish config producing a tab's content, or `None` for the Live tab
+pub(crate) fn polish_config(tab: TabType) -> Option<PolishConfig> {
+    match tab {
+        TabType::Live => None,
+        TabType::BasicPolish => Some(PolishConfig::basic_polish()),
+        TabType::MeetingNotes => Some(PolishConfig::live_meeting()),
+        TabType::FollowUpEmail => Some(PolishConfig::follow_up_email()),
+        TabType::Custom(index) => custom_mode(index).map(|_| PolishConfig::custom(index)),
+    }
+}
+
+/// Tab showing the content a polish config produces
+pub(crate) fn tab_for(config: &PolishConfig) -> TabType {
+    if let Some(index) = config.custom_mode {
+        TabType::Custom(index)
+    } else if config.is_meeting_notes() {
+        TabType::MeetingNotes
+    } else if config.is_follow_up_email() {
+        TabType::FollowUpEmail
+    } else {
+        TabType::BasicPolish
+    }
+}
+
+/// Title of a mode in the stop submenu
+pub(crate) fn menu_title(tab: TabType) -> String {
+    match tab {
+        TabType::Live => tr("menu.stop_no_polish").to_string(),
+        TabType::BasicPolish => tr("menu.stop_basic_polish").to_string(),
+        TabType::MeetingNotes => tr("menu.stop_meeting_notes").to_string(),
+        TabType::FollowUpEmail => tr("menu.stop_follow_up_email").to_string(),
+        TabType::Custom(index) => custom_name(index),
+    }
+}
+
+/// Label of a mode's tab in the transcription window
+pub(crate) fn tab_label(tab: TabType) -> String {
+    match tab {
+        TabType::Live => tr("overlay.tab_live").to_string(),
+        TabType::BasicPolish => tr("overlay.tab_polished").to_string(),
+        TabType::MeetingNotes => tr("overlay.meeting_notes").to_string(),
+        TabType::FollowUpEmail => tr("overlay.tab_email").to_string(),
+        TabType::Custom(index) => custom_name(index),
+    }
+}
+
+/// Header title of the transcription window while a mode's tab is shown
+pub(crate) fn title(tab: TabType) -> String {
+    match tab {
+        TabType::Live => tr("overlay.live_transcription").to_string(),
+        TabType::BasicPolish => tr("overlay.polished_transcript").to_string(),
+        TabType::MeetingNotes => tr("overlay.meeting_notes").to_string(),
+        TabType::FollowUpEmail => tr("overlay.follow_up_email").to_string(),
+        TabType::Custom(index) => custom_name(index),
+    }
+}
+
+/// Text a tab shows before anything is in it
+pub(crate) fn placeholder(tab: TabType) -> &'static str {
+    match tab {
+        TabType::Live => tr("overlay.listening"),
+        TabType::BasicPolish => tr("overlay.click_to_polish"),
+        TabType::MeetingNotes => tr("overlay.click_for_notes"),
+        TabType::FollowUpEmail => tr("overlay.click_for_email"),
+        TabType::Custom(_) => tr("overlay.click_to_generate"),
+    }
+}
+
+/// Text a mode's tab shows while its content is generated
+pub(crate) fn generating_message(tab: TabType) -> Option<&'static str> {
+    match tab {
+        TabType::Live => None,
+        TabType::BasicPolish => Some(tr("overlay.generating_polish")),
+        TabType::MeetingNotes => Some(tr("overlay.generating_notes")),
+        TabType::FollowUpEmail => Some(tr("overlay.generating_email")),
+        TabType::Custom(_) => Some(tr("overlay.generating")),
+    }
+}
+
+/// Text a mode's tab shows when there is no transcript to generate from
+pub(crate) fn empty_message(tab: TabType) -> Option<&'static str> {
+    match tab {
+        TabType::Live => None,
+        TabType::BasicPolish => Some(tr("overlay.no_polish_yet")),
+        TabType::MeetingNotes => Some(tr("overlay.no_notes_yet")),
+        TabType::FollowUpEmail => Some(tr("overlay.no_email_yet")),
+        TabType::Custom(_) => Some(tr("overlay.no_output_yet")),
+    }
+}
+
+/// Save format suggested for a tab's content
+pub(crate) fn export_format(tab: TabType) -> ExportFormat {
+    match tab {
+        TabType::Custom(index) => custom_mode(index).map(|mode| mode.export),
+        _ => None,
+    }
+    .unwrap_or_default()
+}
+
+fn custom_name(index: usize) -> String {
+    custom_mode(index)
+        .map(|mode| mode.name.clone())
+        .unwrap_or_default()
+}
+
+/// Option marking plain text output in the modes list
+const PLAIN_OPTION: &str = "plain";
+
+/// Option marking PDF as the suggested save format in the modes list
+const PDF_OPTION: &str = "pdf";
+
+/// Parse the modes list as edited in Settings.
+///
+/// Each mode starts with a `## Name` line, optionally followed by options in
+/// brackets (`## Standup [plain, pdf]`); the lines up to the next heading are
+/// its prompt. Modes without a name or prompt are dropped, duplicate names
+/// keep the first mode, and only the first `MAX_CUSTOM_MODES` are kept.
+pub(crate) fn parse_modes(text: &str) -> Vec<CustomMode> {
+    let mut modes: Vec<CustomMode> = Vec::new();
+    let mut current: Option<CustomMode> = None;
+
+    for line in text.lines() {
+        if let Some(heading) = line.trim().strip_prefix("## ") {
+            modes.extend(current.take());
+            current = Some(parse_heading(heading));
+        } else if let Some(mode) = current.as_mut() {
+            mode.prompt.push_str(line);
+            mode.prompt.push('\n');
+        }
+    }
+    modes.extend(current);
+
+    let mut valid: Vec<CustomMode> = Vec::new();
+    for mut mode in modes {
+        mode.prompt = mode.prompt.trim().to_string();
+        if mode.name.is_empty()
+            || mode.prompt.is_empty()
+            || valid
+                .iter()
+                .any(|other| other.name.to_lowercase() == mode.name.to_lowercase())
+        {
+            continue;
+        }
+        valid.push(mode);
+    }
+    valid.truncate(MAX_CUSTOM_MODES);
+    valid
+}
+
+/// Name and options of a `## Name [options]` heading
+fn parse_heading(heading: &str) -> CustomMode {
+    let heading = heading.trim();
+    let (name, options) = match heading.strip_suffix(']').and_then(|h| h.rsplit_once('[')) {
+        Some((name, options)) => (name.trim(), options),
+        None => (heading, ""),
+    };
+
+    let mut mode = CustomMode {
+        name: name.to_string(),
+        prompt: String::new(),
+        format: OutputFormat::default(),
+        export: ExportFormat::default(),
+    };
+    for option in options.split(',').map(str::trim) {
+        if option.eq_ignore_ascii_case(PLAIN_OPTION) {
+            mode.format = OutputFormat::PlainText;
+        } else if option.eq_ignore_ascii_case(PDF_OPTION) {
+            mode.export = ExportFormat::Pdf;
+        }
+    }
+    mode
+}
+
+/// Format modes for editing, separated by blank lines.
+pub(crate) fn format_modes(modes: &[CustomMode]) -> String {
+    modes
+        .iter()
+        .map(|mode| {
+            let mut options = Vec::new();
+            if mode.format == OutputFormat::PlainText {
+                options.push(PLAIN_OPTION);
+            }
+            if mode.export == ExportFormat::Pdf {
+                options.push(PDF_OPTION);
+            }
+            let options = if options.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", options.join(", "))
+            };
+            format!("## {}{}\n{}", mode.name, options, mode.prompt)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_modes() {
+        let text = "ignored preamble\n## Standup [plain, pdf]\nWhat I did,\nwhat is next.\n\n## Empty\n\n## standup\nDuplicate.\n## Tweet\nA short tweet.";
+        let modes = parse_modes(text);
+
+        assert_eq!(modes.len(), 2);
+        assert_eq!(modes[0].name, "Standup");
+        assert_eq!(modes[0].prompt, "What I did,\nwhat is next.");
+        assert_eq!(modes[0].format, OutputFormat::PlainText);
+        assert_eq!(modes[0].export, ExportFormat::Pdf);
+        assert_eq!(modes[1].name, "Tweet");
+        assert_eq!(modes[1].format, OutputFormat::Markdown);
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        let text = "## Standup [plain, pdf]\nWhat I did.\n\n## Tweet\nA short tweet.";
+        assert_eq!(format_modes(&parse_modes(text)), text);
+    }
+
+    #[test]
+    fn test_parse_limits_mode_count() {
+        let text: String = (0..5).map(|i| format!("## Mode {}\nPrompt\n", i)).collect();
+        assert_eq!(parse_modes(&text).len(), MAX_CUSTOM_MODES);
+    }
+
+    #[test]
+    fn test_prompt_template_keeps_language_placeholder() {
+        let mode = &parse_modes("## Tweet [plain]\nWrite a tweet.")[0];
+        let prompt = mode.prompt_template();
+        assert!(prompt.starts_with("Write a tweet.\n\n"));
+        assert!(prompt.contains("MUST be in {language}"));
+        assert!(prompt.contains("plain text"));
+    }
+}
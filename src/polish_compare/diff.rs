@@ -0,0 +1,140 @@
+//! Word differences between two polish outputs
+//!
+//! The longest common sequence of words is kept; every other word is
+//! changed. Words are compared exactly, so a changed comma or capital counts.
+
+use std::ops::Range;
+
+/// Largest word grid compared exactly; beyond it the differing middle of
+/// the texts is marked changed as a whole
+const MAX_CELLS: usize = 4_000_000;
+
+/// Byte ranges in `a` and in `b` of the words the other text doesn't have
+///
+/// Neighbouring changed words are joined into one range, so the spaces
+/// between them are highlighted too.
+pub(super) fn changed_ranges(a: &str, b: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let a_words = words(a);
+    let b_words = words(b);
+    let (a_changed, b_changed) = changed_words(&a_words, &b_words);
+    (
+        join_ranges(&a_words, &a_changed),
+        join_ranges(&b_words, &b_changed),
+    )
+}
+
+/// Each word of `text` with its byte range
+fn words(text: &str) -> Vec<(&str, Range<usize>)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(begin)) => {
+                words.push((&text[begin..index], begin..index));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push((&text[begin..], begin..text.len()));
+    }
+    words
+}
+
+/// Whether each word of `a` and of `b` is missing from the other
+fn changed_words(a: &[(&str, Range<usize>)], b: &[(&str, Range<usize>)]) -> (Vec<bool>, Vec<bool>) {
+    let mut a_changed = vec![false; a.len()];
+    let mut b_changed = vec![false; b.len()];
+
+    // Texts usually share their start and end; only the middle is compared
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x.0 == y.0).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x.0 == y.0)
+        .count();
+    let a_mid = prefix..a.len() - suffix;
+    let b_mid = prefix..b.len() - suffix;
+    let (n, m) = (a_mid.len(), b_mid.len());
+
+    if n.saturating_mul(m) > MAX_CELLS {
+        a_changed[a_mid].fill(true);
+        b_changed[b_mid].fill(true);
+        return (a_changed, b_changed);
+    }
+
+    // lengths[i * (m + 1) + j]: common words of a_mid[i..] and b_mid[j..]
+    let width = m + 1;
+    let mut lengths = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * width + j] = if a[prefix + i].0 == b[prefix + j].0 {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[prefix + i].0 == b[prefix + j].0 {
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            a_changed[prefix + i] = true;
+            i += 1;
+        } else {
+            b_changed[prefix + j] = true;
+            j += 1;
+        }
+    }
+    a_changed[prefix + i..prefix + n].fill(true);
+    b_changed[prefix + j..prefix + m].fill(true);
+    (a_changed, b_changed)
+}
+
+/// Byte ranges of runs of changed words
+fn join_ranges(words: &[(&str, Range<usize>)], changed: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut previous_changed = false;
+    for ((_, range), &is_changed) in words.iter().zip(changed) {
+        if is_changed {
+            match ranges.last_mut() {
+                Some(last) if previous_changed => last.end = range.end,
+                _ => ranges.push(range.clone()),
+            }
+        }
+        previous_changed = is_changed;
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changed<'a>(text: &'a str, ranges: &[Range<usize>]) -> Vec<&'a str> {
+        ranges.iter().map(|range| &text[range.clone()]).collect()
+    }
+
+    #[test]
+    fn test_changed_ranges() {
+        let a = "We agreed to ship on Friday.\nAnna owns the release.";
+        let b = "We agreed to ship the beta on Monday.\nAnna owns the release.";
+        let (a_ranges, b_ranges) = changed_ranges(a, b);
+        assert_eq!(changed(a, &a_ranges), vec!["Friday."]);
+        assert_eq!(changed(b, &b_ranges), vec!["the beta", "Monday."]);
+    }
+
+    #[test]
+    fn test_identical_and_empty_texts() {
+        assert_eq!(changed_ranges("Same text", "Same  text"), (vec![], vec![]));
+        let (a_ranges, b_ranges) = changed_ranges("", "New words");
+        assert!(a_ranges.is_empty());
+        assert_eq!(b_ranges, vec![0..9]);
+    }
+}
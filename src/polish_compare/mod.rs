@@ -0,0 +1,167 @@
+//! Compare two polish configurations side by side
+//!
+//! "Compare Polish Outputs…" in the menu bar asks for a provider and output
+//! mode for each of two sides, polishes the Live tab's transcript with both
+//! at the same time and shows the results next to each other in their own
+//! window. Words only one side has are highlighted, so the effect of a model
+//! or prompt is easy to see before settling on one for the team.
+//!
+//! The comparison is shown only; nothing is saved, copied or put in the
+//! transcription window's tabs.
+
+mod diff;
+mod picker;
+mod window;
+
+pub(crate) use window::CompareWindow;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{error, info};
+
+use crate::keychain;
+use crate::managed;
+use crate::openai;
+use crate::output_modes;
+use crate::preferences::{self, AiProvider};
+use crate::private_mode;
+use crate::recording;
+use crate::transcription_window::{TabType, TranscriptionWindow};
+
+/// Set while a comparison is being polished, so repeated clicks are ignored
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// One side of the comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Variant {
+    provider: AiProvider,
+    /// Output mode whose prompt is used
+    tab: TabType,
+}
+
+impl Variant {
+    /// Heading over the side's output
+    fn label(self) -> String {
+        format!(
+            "{} — {}",
+            output_modes::title(self.tab),
+            provider_label(self.provider)
+        )
+    }
+}
+
+/// Provider name with the model or deployment it polishes with
+fn provider_label(provider: AiProvider) -> String {
+    match provider {
+        AiProvider::Azure => match keychain::get_azure_credentials() {
+            Ok(creds) => format!("{} ({})", provider, creds.polish_deployment),
+            Err(_) => provider.to_string(),
+        },
+        AiProvider::OpenAI => format!("{} ({})", provider, openai::POLISH_MODEL),
+    }
+}
+
+/// Choose two configurations and compare their output for the transcript.
+///
+/// Must be called on the main thread; polishing happens in the background.
+pub(crate) fn compare_polish_outputs() {
+    if RUNNING.load(Ordering::SeqCst) {
+        info!("Polish comparison already running, ignoring");
+        return;
+    }
+    if managed::policy().disable_polishing {
+        picker::show_message(
+            "Polishing is turned off",
+            "Polishing is turned off by your organization.",
+        );
+        return;
+    }
+    if private_mode::skips_polishing() {
+        picker::show_message(
+            "Polishing is turned off",
+            "Polishing is turned off during private sessions.",
+        );
+        return;
+    }
+
+    let Some(transcript) =
+        TranscriptionWindow::get_live_transcript().filter(|t| !t.trim().is_empty())
+    else {
+        picker::show_message(
+            "No transcript to compare",
+            "Record something first; the transcript in the Live tab is what gets polished.",
+        );
+        return;
+    };
+
+    let providers: Vec<AiProvider> = [AiProvider::Azure, AiProvider::OpenAI]
+        .into_iter()
+        .filter(|provider| match provider {
+            AiProvider::Azure => keychain::get_azure_credentials().is_ok(),
+            AiProvider::OpenAI => keychain::get_openai_credentials().is_ok(),
+        })
+        .collect();
+    if providers.is_empty() {
+        picker::show_message(
+            "No polishing provider set up",
+            "Add your Azure OpenAI or OpenAI credentials in Settings first.",
+        );
+        return;
+    }
+
+    let Some(variants) = picker::choose_variants(&providers, default_variants(&providers)) else {
+        info!("Polish comparison cancelled");
+        return;
+    };
+    info!(?variants, "Comparing polish outputs");
+
+    window::show_pending(&variants.map(Variant::label));
+    RUNNING.store(true, Ordering::SeqCst);
+    tokio::spawn(async move {
+        let (a, b) = tokio::join!(
+            polish(variants[0], &transcript),
+            polish(variants[1], &transcript)
+        );
+        window::show_results([a, b]);
+        RUNNING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// The polishing provider against the other one with the Basic Polish
+/// prompt, or against Meeting Notes when only one provider is set up
+fn default_variants(providers: &[AiProvider]) -> [Variant; 2] {
+    let preferred = preferences::get_polish_provider();
+    let first = if providers.contains(&preferred) {
+        preferred
+    } else {
+        providers[0]
+    };
+    let second = match providers.iter().find(|provider| **provider != first) {
+        Some(&other) => Variant {
+            provider: other,
+            tab: TabType::BasicPolish,
+        },
+        None => Variant {
+            provider: first,
+            tab: TabType::MeetingNotes,
+        },
+    };
+    [
+        Variant {
+            provider: first,
+            tab: TabType::BasicPolish,
+        },
+        second,
+    ]
+}
+
+/// One side's output, or the message shown instead
+async fn polish(variant: Variant, transcript: &str) -> Result<String, String> {
+    let config = output_modes::polish_config(variant.tab)
+        .ok_or_else(|| "This output mode no longer exists.".to_string())?;
+    recording::polish_text_with(variant.provider, transcript, &config)
+        .await
+        .map_err(|e| {
+            error!(provider = %variant.provider, "Polish comparison failed: {}", e);
+            format!("Polishing failed.\n\n{}", e)
+        })
+}
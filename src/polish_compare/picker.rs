@@ -0,0 +1,170 @@
+//! Dialog for choosing the two configurations to compare
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSFont, NSPopUpButton, NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+
+use super::{provider_label, Variant};
+use crate::output_modes;
+use crate::preferences::AiProvider;
+
+/// NSAlertFirstButtonReturn
+const ALERT_FIRST_BUTTON: isize = 1000;
+
+/// Height of each side's row
+const ROW_HEIGHT: CGFloat = 32.0;
+
+/// Width of the "A" and "B" labels
+const LABEL_WIDTH: CGFloat = 24.0;
+
+const PROVIDER_WIDTH: CGFloat = 210.0;
+const MODE_WIDTH: CGFloat = 210.0;
+
+/// A side's provider and output mode popups
+type Row = (Retained<NSPopUpButton>, Retained<NSPopUpButton>);
+
+/// Ask for the provider and output mode of each side.
+///
+/// `providers` are those with saved credentials and `defaults` are selected
+/// at first. Returns `None` if the user cancelled or this isn't the main
+/// thread.
+pub(super) fn choose_variants(
+    providers: &[AiProvider],
+    defaults: [Variant; 2],
+) -> Option<[Variant; 2]> {
+    let mtm = MainThreadMarker::new()?;
+    let tabs = output_modes::polish_tabs();
+    let provider_titles: Vec<String> = providers.iter().map(|p| provider_label(*p)).collect();
+    let mode_titles: Vec<String> = tabs.iter().map(|tab| output_modes::title(*tab)).collect();
+
+    let width = LABEL_WIDTH + PROVIDER_WIDTH + MODE_WIDTH + 8.0;
+    // SAFETY: NSView allocation on the main thread with a valid frame
+    let rows_view: Retained<NSView> = unsafe {
+        msg_send_id![
+            mtm.alloc::<NSView>(),
+            initWithFrame: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(width, ROW_HEIGHT * 2.0))
+        ]
+    };
+
+    let rows: Vec<Row> = ["A", "B"]
+        .into_iter()
+        .zip(defaults)
+        .enumerate()
+        .map(|(index, (name, variant))| {
+            // Views are not flipped, so A goes at the top
+            let y = ROW_HEIGHT * (1 - index) as CGFloat;
+            let label = create_label(mtm, NSPoint::new(0.0, y + 5.0), name);
+            let provider_popup = create_popup(
+                mtm,
+                NSRect::new(
+                    NSPoint::new(LABEL_WIDTH, y),
+                    NSSize::new(PROVIDER_WIDTH, 26.0),
+                ),
+                &provider_titles,
+                providers.iter().position(|p| *p == variant.provider),
+            );
+            let mode_popup = create_popup(
+                mtm,
+                NSRect::new(
+                    NSPoint::new(LABEL_WIDTH + PROVIDER_WIDTH + 8.0, y),
+                    NSSize::new(MODE_WIDTH, 26.0),
+                ),
+                &mode_titles,
+                tabs.iter().position(|tab| *tab == variant.tab),
+            );
+            // SAFETY: adding valid subviews on the main thread
+            unsafe {
+                rows_view.addSubview(&label);
+                rows_view.addSubview(&provider_popup);
+                rows_view.addSubview(&mode_popup);
+            }
+            (provider_popup, mode_popup)
+        })
+        .collect();
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str("Compare polish outputs"));
+        alert.setInformativeText(&NSString::from_str(
+            "The transcript is polished with both configurations and the results are shown side by side, with the differences highlighted.",
+        ));
+        alert.setAccessoryView(Some(&rows_view));
+        alert.addButtonWithTitle(&NSString::from_str("Compare"));
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+
+    if response != ALERT_FIRST_BUTTON {
+        return None;
+    }
+
+    let selected = |(provider_popup, mode_popup): &Row| -> Option<Variant> {
+        // SAFETY: reading the selection of valid popups on the main thread
+        let (provider_index, mode_index): (isize, isize) = unsafe {
+            (
+                msg_send![provider_popup, indexOfSelectedItem],
+                msg_send![mode_popup, indexOfSelectedItem],
+            )
+        };
+        Some(Variant {
+            provider: *providers.get(usize::try_from(provider_index).ok()?)?,
+            tab: *tabs.get(usize::try_from(mode_index).ok()?)?,
+        })
+    };
+    Some([selected(&rows[0])?, selected(&rows[1])?])
+}
+
+/// Create a popup with `titles`, selecting `selected` or the first title
+fn create_popup(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    titles: &[String],
+    selected: Option<usize>,
+) -> Retained<NSPopUpButton> {
+    // SAFETY: NSPopUpButton allocation and configuration on the main thread
+    unsafe {
+        let popup: Retained<NSPopUpButton> =
+            msg_send_id![mtm.alloc::<NSPopUpButton>(), initWithFrame: frame, pullsDown: false];
+        for title in titles {
+            let _: () = msg_send![&popup, addItemWithTitle: &*NSString::from_str(title)];
+        }
+        let _: () = msg_send![&popup, selectItemAtIndex: selected.unwrap_or(0) as isize];
+        popup
+    }
+}
+
+/// Create the bold "A" or "B" label of a row
+fn create_label(mtm: MainThreadMarker, origin: NSPoint, text: &str) -> Retained<NSTextField> {
+    // SAFETY: NSTextField allocation and configuration on the main thread
+    unsafe {
+        let label: Retained<NSTextField> = msg_send_id![
+            mtm.alloc::<NSTextField>(),
+            initWithFrame: NSRect::new(origin, NSSize::new(LABEL_WIDTH, 18.0))
+        ];
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setStringValue(&NSString::from_str(text));
+        label.setFont(Some(&NSFont::boldSystemFontOfSize(13.0)));
+        label
+    }
+}
+
+/// Show an informational alert, e.g. when there is nothing to compare.
+pub(super) fn show_message(title: &str, message: &str) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(title));
+        alert.setInformativeText(&NSString::from_str(message));
+        alert.runModal();
+    }
+}
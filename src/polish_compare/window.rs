@@ -0,0 +1,308 @@
+//! Window showing two polish outputs side by side
+//!
+//! Created on the first comparison and kept in the window registry; later
+//! comparisons reuse it, even after it was closed.
+
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_app_kit::{
+    NSApplication, NSBackingStoreType, NSColor, NSFont, NSScrollView, NSTextField, NSTextView,
+    NSView, NSWindow, NSWindowStyleMask,
+};
+use objc2_foundation::{
+    CGFloat, MainThreadMarker, NSMutableAttributedString, NSOperationQueue, NSPoint, NSRange,
+    NSRect, NSSize, NSString,
+};
+use std::ops::Range;
+use tracing::error;
+
+use super::diff;
+use crate::app_context;
+
+const WINDOW_WIDTH: CGFloat = 960.0;
+const WINDOW_HEIGHT: CGFloat = 640.0;
+const PADDING: CGFloat = 16.0;
+const TITLE_HEIGHT: CGFloat = 20.0;
+
+/// Background of words only A has, and of words only B has
+const HIGHLIGHTS: [(CGFloat, CGFloat, CGFloat); 2] = [(1.0, 0.35, 0.3), (0.2, 0.75, 0.35)];
+
+/// Alpha of the highlight behind changed words
+const HIGHLIGHT_ALPHA: CGFloat = 0.3;
+
+// NSAutoresizingMaskOptions
+const MIN_X_MARGIN: usize = 1;
+const WIDTH_SIZABLE: usize = 2;
+const MAX_X_MARGIN: usize = 4;
+const MIN_Y_MARGIN: usize = 8;
+const HEIGHT_SIZABLE: usize = 16;
+
+/// The comparison window and its two columns
+pub(crate) struct CompareWindow {
+    window: Retained<NSWindow>,
+    titles: [Retained<NSTextField>; 2],
+    text_views: [Retained<NSTextView>; 2],
+}
+
+/// Show the window headed by `labels` while both outputs are polished.
+pub(super) fn show_pending(labels: &[String; 2]) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let Some(windows) = app_context::windows() else {
+        return;
+    };
+    let Ok(mut slot) = windows.polish_compare.try_borrow_mut() else {
+        error!("Comparison window already borrowed in show_pending");
+        return;
+    };
+    let compare = slot.get_or_insert_with(|| create_window(mtm));
+
+    for ((title, text_view), label) in compare.titles.iter().zip(&compare.text_views).zip(labels) {
+        // SAFETY: updating valid views on the main thread
+        unsafe { title.setStringValue(&NSString::from_str(label)) };
+        set_text(text_view, "Polishing…", &[], None);
+    }
+
+    // A menu bar app has to come forward for its window to take focus
+    let app = NSApplication::sharedApplication(mtm);
+    #[allow(deprecated)]
+    app.activateIgnoringOtherApps(true);
+    compare.window.makeKeyAndOrderFront(None);
+}
+
+/// Show both outputs, highlighting the words only one of them has.
+///
+/// Safe to call from any thread; the window is updated on the main thread.
+pub(super) fn show_results(results: [Result<String, String>; 2]) {
+    let block = RcBlock::new(move || {
+        let Some(windows) = app_context::windows() else {
+            return;
+        };
+        let Ok(slot) = windows.polish_compare.try_borrow() else {
+            error!("Comparison window already borrowed in show_results");
+            return;
+        };
+        let Some(compare) = slot.as_ref() else {
+            return;
+        };
+
+        let ranges = match &results {
+            [Ok(a), Ok(b)] => {
+                let (a_ranges, b_ranges) = diff::changed_ranges(a, b);
+                [a_ranges, b_ranges]
+            }
+            _ => [Vec::new(), Vec::new()],
+        };
+        for (side, (text_view, result)) in compare.text_views.iter().zip(&results).enumerate() {
+            match result {
+                Ok(text) => set_text(text_view, text, &ranges[side], Some(HIGHLIGHTS[side])),
+                Err(message) => set_text(text_view, &format!("⚠️ {}", message), &[], None),
+            }
+        }
+    });
+
+    // SAFETY: NSOperationQueue::mainQueue() is safe to call from any thread,
+    // and addOperationWithBlock: schedules the block on the main thread
+    unsafe {
+        let queue = NSOperationQueue::mainQueue();
+        let _: () = msg_send![&queue, addOperationWithBlock: &*block];
+    }
+}
+
+/// Create the window with a title and a text view per side
+fn create_window(mtm: MainThreadMarker) -> CompareWindow {
+    let style_mask = NSWindowStyleMask::Titled
+        | NSWindowStyleMask::Closable
+        | NSWindowStyleMask::Miniaturizable
+        | NSWindowStyleMask::Resizable;
+
+    // SAFETY: NSWindow initialization with valid parameters on main thread
+    let window = unsafe {
+        NSWindow::initWithContentRect_styleMask_backing_defer(
+            mtm.alloc(),
+            NSRect::new(
+                NSPoint::new(0.0, 0.0),
+                NSSize::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+            ),
+            style_mask,
+            NSBackingStoreType::NSBackingStoreBuffered,
+            false,
+        )
+    };
+    window.setTitle(&NSString::from_str("Compare Polish Outputs"));
+    // SAFETY: setReleasedWhenClosed is a standard NSWindow configuration call
+    unsafe { window.setReleasedWhenClosed(false) };
+    window.center();
+
+    // SAFETY: NSView allocation on the main thread with a valid frame
+    let content_view: Retained<NSView> = unsafe {
+        msg_send_id![
+            mtm.alloc::<NSView>(),
+            initWithFrame: NSRect::new(
+                NSPoint::new(0.0, 0.0),
+                NSSize::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+            )
+        ]
+    };
+
+    let column_width = (WINDOW_WIDTH - PADDING * 3.0) / 2.0;
+    let text_height = WINDOW_HEIGHT - PADDING * 3.0 - TITLE_HEIGHT;
+    let columns = [0, 1].map(|side| {
+        let x = PADDING + side as CGFloat * (column_width + PADDING);
+        // The columns share the width, so each grows towards its outer edge
+        let margin = if side == 0 {
+            MAX_X_MARGIN
+        } else {
+            MIN_X_MARGIN
+        };
+
+        let title = create_title(
+            mtm,
+            NSRect::new(
+                NSPoint::new(x, PADDING * 2.0 + text_height),
+                NSSize::new(column_width, TITLE_HEIGHT),
+            ),
+        );
+        let (scroll_view, text_view) = create_text_view(
+            mtm,
+            NSRect::new(
+                NSPoint::new(x, PADDING),
+                NSSize::new(column_width, text_height),
+            ),
+        );
+        // SAFETY: configuring and adding valid views on the main thread
+        unsafe {
+            let _: () =
+                msg_send![&title, setAutoresizingMask: WIDTH_SIZABLE | margin | MIN_Y_MARGIN];
+            let _: () = msg_send![
+                &scroll_view,
+                setAutoresizingMask: WIDTH_SIZABLE | margin | HEIGHT_SIZABLE
+            ];
+            content_view.addSubview(&title);
+            content_view.addSubview(&scroll_view);
+        }
+        (title, text_view)
+    });
+    window.setContentView(Some(&content_view));
+
+    let [(title_a, text_a), (title_b, text_b)] = columns;
+    CompareWindow {
+        window,
+        titles: [title_a, title_b],
+        text_views: [text_a, text_b],
+    }
+}
+
+/// Create the bold heading naming a side's provider and output mode
+fn create_title(mtm: MainThreadMarker, frame: NSRect) -> Retained<NSTextField> {
+    // SAFETY: NSTextField allocation and configuration on the main thread
+    unsafe {
+        let label: Retained<NSTextField> =
+            msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: frame];
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setFont(Some(&NSFont::boldSystemFontOfSize(13.0)));
+        // NSLineBreakByTruncatingTail = 4
+        let _: () = msg_send![&label, setLineBreakMode: 4_usize];
+        label
+    }
+}
+
+/// Create a read-only, scrollable text view filling `frame`
+fn create_text_view(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+) -> (Retained<NSScrollView>, Retained<NSTextView>) {
+    // SAFETY: view allocation and configuration on the main thread with valid frames
+    unsafe {
+        let scroll_view: Retained<NSScrollView> =
+            msg_send_id![mtm.alloc::<NSScrollView>(), initWithFrame: frame];
+        scroll_view.setHasVerticalScroller(true);
+        scroll_view.setHasHorizontalScroller(false);
+        let _: () = msg_send![&scroll_view, setAutohidesScrollers: true];
+        let _: () = msg_send![&scroll_view, setBorderType: 2u64]; // NSBezelBorder
+
+        let text_view: Retained<NSTextView> = msg_send_id![
+            mtm.alloc::<NSTextView>(),
+            initWithFrame: NSRect::new(NSPoint::new(0.0, 0.0), frame.size)
+        ];
+        text_view.setEditable(false);
+        text_view.setSelectable(true);
+        let _: () = msg_send![&text_view, setUsesFindBar: true];
+        let _: () = msg_send![&text_view, setTextContainerInset: NSSize::new(6.0, 8.0)];
+
+        // Wrap lines at the width of the view
+        let text_container: *mut AnyObject = msg_send![&text_view, textContainer];
+        if !text_container.is_null() {
+            let _: () = msg_send![text_container, setWidthTracksTextView: true];
+        }
+        let _: () = msg_send![&text_view, setMinSize: NSSize::new(0.0, frame.size.height)];
+        let _: () = msg_send![&text_view, setMaxSize: NSSize::new(CGFloat::MAX, CGFloat::MAX)];
+        let _: () = msg_send![&text_view, setVerticallyResizable: true];
+        let _: () = msg_send![&text_view, setHorizontallyResizable: false];
+        // Follow the scroll view's width (NSViewWidthSizable)
+        let _: () = msg_send![&text_view, setAutoresizingMask: WIDTH_SIZABLE];
+
+        scroll_view.setDocumentView(Some(&text_view));
+        (scroll_view, text_view)
+    }
+}
+
+/// Show `text` with `ranges` highlighted in `highlight`
+fn set_text(
+    text_view: &NSTextView,
+    text: &str,
+    ranges: &[Range<usize>],
+    highlight: Option<(CGFloat, CGFloat, CGFloat)>,
+) {
+    let utf16_len = |text: &str| text.encode_utf16().count();
+
+    // SAFETY: attribute ranges lie within the attributed string, and the
+    // text view is updated on the main thread
+    unsafe {
+        let attr_string: Retained<NSMutableAttributedString> = msg_send_id![
+            NSMutableAttributedString::alloc(),
+            initWithString: &*NSString::from_str(text)
+        ];
+        let full_range = NSRange::new(0, utf16_len(text));
+        let _: () = msg_send![
+            &attr_string,
+            addAttribute: &*NSString::from_str("NSFont"),
+            value: &*NSFont::systemFontOfSize(13.0),
+            range: full_range
+        ];
+        let _: () = msg_send![
+            &attr_string,
+            addAttribute: &*NSString::from_str("NSColor"),
+            value: &*NSColor::textColor(),
+            range: full_range
+        ];
+
+        if let Some((red, green, blue)) = highlight {
+            let color = NSColor::colorWithRed_green_blue_alpha(red, green, blue, HIGHLIGHT_ALPHA);
+            let background_attr = NSString::from_str("NSBackgroundColor");
+            for range in ranges {
+                let _: () = msg_send![
+                    &attr_string,
+                    addAttribute: &*background_attr,
+                    value: &*color,
+                    range: NSRange::new(
+                        utf16_len(&text[..range.start]),
+                        utf16_len(&text[range.clone()])
+                    )
+                ];
+            }
+        }
+
+        let text_storage: *mut AnyObject = msg_send![text_view, textStorage];
+        if !text_storage.is_null() {
+            let _: () = msg_send![text_storage, setAttributedString: &*attr_string];
+        }
+        text_view.scrollRangeToVisible(NSRange::new(0, 0));
+    }
+}
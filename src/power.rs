@@ -0,0 +1,151 @@
+//! System sleep, wake and screen lock handling
+//!
+//! A Mac that sleeps mid-recording comes back with a WebSocket that looks
+//! open but is dead. Instead, the recording's connection is paused before
+//! sleep and replaced a few seconds after wake, once the network is back,
+//! so the session carries on with its transcript intact.
+//!
+//! When enabled in the Audio tab of Settings, locking the screen stops the
+//! recording without polishing, as the no-polish stop hotkey would.
+//!
+//! [`user_idle_time`] reports how long the keyboard and mouse have been left
+//! alone, for the reminder about recordings that have gone quiet.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2_foundation::NSString;
+use once_cell::sync::OnceCell;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::app_context::AppContext;
+use crate::preferences;
+use crate::recording;
+
+/// Posted by the workspace notification center before the Mac sleeps
+const WILL_SLEEP: &str = "NSWorkspaceWillSleepNotification";
+
+/// Posted by the workspace notification center after the Mac wakes
+const DID_WAKE: &str = "NSWorkspaceDidWakeNotification";
+
+/// Distributed notification posted when the screen locks
+const SCREEN_LOCKED: &str = "com.apple.screenIsLocked";
+
+/// How long to wait after wake before reconnecting, so Wi-Fi can rejoin
+const WAKE_RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// kCGEventSourceStateHIDSystemState: input from any process or device
+const HID_SYSTEM_STATE: i32 = 1;
+
+/// kCGAnyInputEventType
+const ANY_INPUT_EVENT: u32 = !0;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(state: i32, event_type: u32) -> f64;
+}
+
+/// What sleep, wake and lock act on
+static CONTEXT: OnceCell<(AppContext, tokio::runtime::Handle)> = OnceCell::new();
+
+/// Observe sleep, wake and screen lock (call on the main thread).
+pub(crate) fn init(context: AppContext, runtime: tokio::runtime::Handle) {
+    if CONTEXT.set((context, runtime)).is_err() {
+        warn!("Sleep and lock handling already initialized");
+        return;
+    }
+
+    let Some(workspace_class) = AnyClass::get("NSWorkspace") else {
+        return;
+    };
+    // SAFETY: sharedWorkspace and notificationCenter return shared objects
+    // that live as long as the app
+    let workspace_center: *mut AnyObject = unsafe {
+        let workspace: *mut AnyObject = msg_send![workspace_class, sharedWorkspace];
+        msg_send![workspace, notificationCenter]
+    };
+    observe(workspace_center, WILL_SLEEP, on_sleep);
+    observe(workspace_center, DID_WAKE, on_wake);
+
+    if let Some(distributed_class) = AnyClass::get("NSDistributedNotificationCenter") {
+        // SAFETY: defaultCenter returns the shared distributed center
+        let distributed_center: *mut AnyObject =
+            unsafe { msg_send![distributed_class, defaultCenter] };
+        observe(distributed_center, SCREEN_LOCKED, on_lock);
+    }
+}
+
+/// Call `handler` on the main queue whenever `center` posts `name`.
+fn observe(center: *mut AnyObject, name: &str, handler: fn()) {
+    let Some(queue_class) = AnyClass::get("NSOperationQueue") else {
+        return;
+    };
+    if center.is_null() {
+        return;
+    }
+    let block = RcBlock::new(move |_notification: *mut AnyObject| handler());
+
+    // SAFETY: valid notification center, name, queue and block. The center
+    // keeps the returned observer (and the block) registered for the app's
+    // lifetime, so the token isn't kept.
+    unsafe {
+        let queue: *mut AnyObject = msg_send![queue_class, mainQueue];
+        let _observer: *mut AnyObject = msg_send![
+            center,
+            addObserverForName: &*NSString::from_str(name),
+            object: std::ptr::null::<AnyObject>(),
+            queue: queue,
+            usingBlock: &*block
+        ];
+    }
+}
+
+/// Pause the recording's connection before the Mac sleeps.
+fn on_sleep() {
+    let Some((context, _)) = CONTEXT.get() else {
+        return;
+    };
+    if recording::pause_connection(&context.recording_state) {
+        info!("Mac is going to sleep: recording paused");
+    }
+}
+
+/// Reconnect the recording once the network is back after wake.
+fn on_wake() {
+    let Some((context, runtime)) = CONTEXT.get() else {
+        return;
+    };
+    let recording_state = context.recording_state.clone();
+    runtime.spawn(async move {
+        tokio::time::sleep(WAKE_RECONNECT_DELAY).await;
+        recording::resume_connection(&recording_state);
+    });
+}
+
+/// Stop recording when the screen locks, if enabled.
+fn on_lock() {
+    let Some((context, runtime)) = CONTEXT.get() else {
+        return;
+    };
+    if !preferences::get_stop_on_screen_lock()
+        || !context.app_state.is_recording.load(Ordering::SeqCst)
+    {
+        return;
+    }
+
+    info!("Screen locked: stopping recording");
+    let recording_state = context.recording_state.clone();
+    let ui = context.ui.clone();
+    runtime.spawn(async move {
+        recording::stop_recording_no_polish(recording_state, ui);
+    });
+}
+
+/// Time since the last keyboard, mouse or trackpad input.
+pub(crate) fn user_idle_time() -> Duration {
+    // SAFETY: plain query of the HID event source; safe from any thread
+    let secs = unsafe { CGEventSourceSecondsSinceLastEventType(HID_SYSTEM_STATE, ANY_INPUT_EVENT) };
+    Duration::try_from_secs_f64(secs).unwrap_or_default()
+}
@@ -1,14 +1,36 @@
 //! User preferences storage
 //!
 //! Handles saving and loading user preferences to a JSON file
-//! in the application support directory.
+//! in the application support directory. The file is read once and kept in
+//! memory; every setter writes it back and refreshes the cached copy.
 
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{PoisonError, RwLock};
 use tracing::{error, info};
 
+use crate::audio;
+use crate::captions::CaptionOutput;
+use crate::consent::ConsentNotice;
+use crate::event_bus::{self, AppEvent, Setting};
+use crate::feedback::FeedbackSettings;
+use crate::hotkeys::{LanguageHotkey, StopHotkeys};
+use crate::keywords::KeywordRule;
+use crate::managed;
+use crate::menubar::RecordingIndicator;
+use crate::output_modes::CustomMode;
+use crate::recording::{AutoCopySettings, SessionLimits};
+use crate::screenshot::ScreenshotSettings;
+use crate::templates::Template;
+use crate::transcription::{self, NoiseReduction, NormalizeOptions, TranscribeModel};
+use crate::transcription_window::PdfSettings;
+use crate::wake_word;
+use crate::workspaces::Workspace;
+
 /// AI provider selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -28,11 +50,13 @@ impl fmt::Display for AiProvider {
 }
 
 /// User preferences
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct Preferences {
     /// AI provider selection (Azure OpenAI or OpenAI)
     /// Defaults to Azure for backward compatibility
     pub ai_provider: Option<AiProvider>,
+    /// Provider used for polishing (None = same as `ai_provider`)
+    pub polish_provider: Option<AiProvider>,
     /// Custom transcript storage location (None = use default)
     pub transcript_location: Option<PathBuf>,
     /// Custom screenshot storage location (None = use default)
@@ -40,6 +64,13 @@ pub(crate) struct Preferences {
     /// Language code for transcription (e.g., "en", "no", "da", "fi", "de")
     /// Defaults to "en" (English) if not set
     pub language_code: Option<String>,
+    /// Language codes listed in the menu bar's Languages submenu
+    /// (None = English, Norwegian, Danish, Finnish and German)
+    pub menu_languages: Option<Vec<String>>,
+    /// Hotkey cycling through the menu bar's favorite languages
+    pub language_hotkey: Option<LanguageHotkey>,
+    /// Hotkeys stopping the recording with no polishing, basic polishing or meeting notes
+    pub stop_hotkeys: Option<StopHotkeys>,
     /// Last time version check was performed (ISO 8601 timestamp)
     pub last_version_check: Option<String>,
     /// Latest known version from remote (cached)
@@ -50,6 +81,116 @@ pub(crate) struct Preferences {
     pub overlay_transparency: Option<f64>,
     /// Background mode (true = dark, false = light, defaults to true)
     pub is_dark_mode: Option<bool>,
+    /// Keywords highlighted in the live transcript (None = no highlighting)
+    pub keyword_rules: Option<Vec<KeywordRule>>,
+    /// Sound cues and notifications for recording events (None = all off)
+    pub feedback: Option<FeedbackSettings>,
+    /// Accept automation commands on a local Unix socket (defaults to false)
+    pub automation_socket_enabled: Option<bool>,
+    /// Serve the local HTTP API on 127.0.0.1 (defaults to false)
+    pub http_api_enabled: Option<bool>,
+    /// Port for the local HTTP API (defaults to 7717)
+    pub http_api_port: Option<u16>,
+    /// Live caption output for streaming (defaults to off)
+    pub caption_output: Option<CaptionOutput>,
+    /// Port for the caption WebSocket feed (defaults to 7718)
+    pub caption_websocket_port: Option<u16>,
+    /// Encrypt saved transcripts and screenshots (defaults to false)
+    pub encrypt_at_rest: Option<bool>,
+    /// Delete transcripts and screenshots older than this many days (None = keep forever)
+    pub retention_days: Option<u32>,
+    /// Cap on transcript and screenshot disk usage in megabytes (None = no cap)
+    pub retention_max_mb: Option<u64>,
+    /// Allow cloud polishing during private sessions (defaults to false)
+    pub private_session_polish: Option<bool>,
+    /// Record local usage statistics (defaults to false)
+    pub metrics_enabled: Option<bool>,
+    /// Show the Debug tab with provider events in the transcription window (defaults to false)
+    pub debug_tab: Option<bool>,
+    /// Audio per chunk sent to the speech service, in milliseconds (defaults to 100)
+    pub audio_chunk_ms: Option<u32>,
+    /// Seconds of audio buffered while the connection is slow (defaults to 60)
+    pub audio_buffer_secs: Option<u32>,
+    /// Connect to the speech service when the menu bar menu opens (defaults to false)
+    pub prewarm_connection: Option<bool>,
+    /// Send smaller audio, less often, to save data (defaults to false)
+    pub low_bandwidth_mode: Option<bool>,
+    /// Seconds of sending audio without a reply before reconnecting (defaults to 20)
+    pub stall_timeout_secs: Option<u32>,
+    /// Continue on the other provider when the selected one fails (defaults to false)
+    pub provider_failover: Option<bool>,
+    /// Model OpenAI transcribes with (defaults to gpt-4o-transcribe)
+    pub openai_transcribe_model: Option<TranscribeModel>,
+    /// Noise reduction the provider applies to the microphone audio (defaults to near field)
+    pub noise_reduction: Option<NoiseReduction>,
+    /// Spoken number, date and amount rewrites, by transcription language
+    pub normalize: Option<BTreeMap<String, NormalizeOptions>>,
+    /// Keychain item format the saved credentials were migrated to (None = 1)
+    pub keychain_schema_version: Option<u32>,
+    /// Language code for menus, Settings and the overlay (defaults to "en")
+    pub ui_language: Option<String>,
+    /// App names or bundle IDs during which screenshots are refused
+    pub screenshot_blocklist: Option<Vec<String>>,
+    /// What is copied to the clipboard when each stop mode finishes
+    pub auto_copy: Option<AutoCopySettings>,
+    /// Whether recording starts when the wake phrase is heard
+    pub wake_word_enabled: Option<bool>,
+    /// Phrase that starts a recording (defaults to "Hey Vissper")
+    pub wake_word_phrase: Option<String>,
+    /// Whether locking the screen stops the recording
+    pub stop_on_screen_lock: Option<bool>,
+    /// Whether meeting notes are split into topic sections
+    pub topic_sections: Option<bool>,
+    /// Notice at the top of exported meeting notes (None = turned off)
+    pub consent_notice: Option<ConsentNotice>,
+    /// Output modes defined by the user, shown after the built-in ones
+    pub custom_output_modes: Option<Vec<CustomMode>>,
+    /// Whether each session's meeting notes are exported to Notion
+    pub notion_export: Option<bool>,
+    /// Whether saved transcripts are also uploaded to Google Drive
+    pub google_drive_upload: Option<bool>,
+    /// Whether saved transcripts are committed to a git repository
+    pub git_archive: Option<bool>,
+    /// Whether each git archive commit is pushed
+    pub git_archive_push: Option<bool>,
+    /// Remote URL the git archive is pushed to
+    pub git_archive_remote: Option<String>,
+    /// Whether transcripts are stored in iCloud Drive
+    pub store_in_icloud: Option<bool>,
+    /// Screenshot format, quality and resolution (None = full-resolution PNG)
+    pub screenshot: Option<ScreenshotSettings>,
+    /// PDF page size, margins and header/footer (None = A4 with title and page numbers)
+    pub pdf: Option<PdfSettings>,
+    /// Recording duration and transcript size limits (None = the defaults)
+    pub session_limits: Option<SessionLimits>,
+    /// How visibly the menu bar icon shows a recording (None = standard)
+    pub recording_indicator: Option<RecordingIndicator>,
+    /// Whether a "Vissper is recording" banner is shown while recording
+    pub recording_banner: Option<bool>,
+    /// Whether the microphone test is also transcribed (None = false)
+    pub mic_test_transcribe: Option<bool>,
+    /// Workspaces besides Default, each overriding some of the settings above
+    pub workspaces: Option<Vec<Workspace>>,
+    /// Name of the active workspace (None = Default)
+    pub active_workspace: Option<String>,
+    /// Recording templates listed in the menu bar's Templates submenu
+    pub templates: Option<Vec<Template>>,
+}
+
+/// The active workspace, if one besides Default is active
+fn active_workspace(prefs: &Preferences) -> Option<&Workspace> {
+    let name = prefs.active_workspace.as_deref()?;
+    prefs.workspaces.as_ref()?.iter().find(|w| w.name == name)
+}
+
+/// The active workspace for changing the settings it overrides
+fn active_workspace_mut(prefs: &mut Preferences) -> Option<&mut Workspace> {
+    let name = prefs.active_workspace.clone()?;
+    prefs
+        .workspaces
+        .as_mut()?
+        .iter_mut()
+        .find(|w| w.name == name)
 }
 
 /// Get the preferences file path
@@ -57,10 +198,63 @@ fn preferences_path() -> Option<PathBuf> {
     dirs::config_dir().map(|d| d.join("Vissper").join("preferences.json"))
 }
 
-/// Load preferences from disk
+/// Preferences as last read from or written to disk
 ///
-/// Returns default preferences if the file doesn't exist or can't be read
-pub(crate) fn load_preferences() -> Preferences {
+/// Getters run on hot paths such as overlay redraws and session setup, so the
+/// file is parsed once and the cache is replaced whenever preferences are saved.
+static CACHE: Lazy<RwLock<Option<Preferences>>> = Lazy::new(|| RwLock::new(None));
+
+/// Look at the cached preferences, reading the file on first use
+///
+/// Uses default preferences if the file doesn't exist or can't be read
+fn read<T>(view: impl FnOnce(&Preferences) -> T) -> T {
+    if let Some(prefs) = CACHE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+    {
+        return view(prefs);
+    }
+
+    view(
+        CACHE
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(read_from_disk),
+    )
+}
+
+/// Change preferences and save them to disk
+///
+/// Holds the cache lock across the read-modify-write so concurrent setters
+/// can't overwrite each other's changes. The cache is only updated once the
+/// file has been written.
+fn update(change: impl FnOnce(&mut Preferences)) -> Result<(), PreferencesError> {
+    let mut cache = CACHE.write().unwrap_or_else(PoisonError::into_inner);
+    let mut prefs = match cache.as_ref() {
+        Some(prefs) => prefs.clone(),
+        None => read_from_disk(),
+    };
+
+    change(&mut prefs);
+    write_to_disk(&prefs)?;
+    *cache = Some(prefs);
+
+    Ok(())
+}
+
+/// Change preferences, save them and tell listeners which setting changed
+fn update_setting(
+    setting: Setting,
+    change: impl FnOnce(&mut Preferences),
+) -> Result<(), PreferencesError> {
+    update(change)?;
+    event_bus::publish(AppEvent::SettingChanged { setting });
+    Ok(())
+}
+
+/// Read preferences from disk, or defaults if the file is missing or invalid
+fn read_from_disk() -> Preferences {
     let Some(path) = preferences_path() else {
         return Preferences::default();
     };
@@ -84,8 +278,8 @@ pub(crate) fn load_preferences() -> Preferences {
     }
 }
 
-/// Save preferences to disk
-pub(crate) fn save_preferences(prefs: &Preferences) -> Result<(), PreferencesError> {
+/// Write preferences to disk
+fn write_to_disk(prefs: &Preferences) -> Result<(), PreferencesError> {
     let path = preferences_path().ok_or(PreferencesError::NoConfigDir)?;
 
     // Ensure parent directory exists
@@ -104,15 +298,33 @@ pub(crate) fn save_preferences(prefs: &Preferences) -> Result<(), PreferencesErr
 }
 
 /// Get the custom transcript location, if set
+///
+/// A workspace always has a transcript location of its own.
 pub(crate) fn get_transcript_location() -> Option<PathBuf> {
-    load_preferences().transcript_location
+    read(|prefs| transcript_location_in(prefs, active_workspace(prefs)))
 }
 
-/// Set a custom transcript location
+/// Transcript location of `workspace`, or of Default when None
+pub(crate) fn get_transcript_location_in(workspace: Option<&Workspace>) -> Option<PathBuf> {
+    read(|prefs| transcript_location_in(prefs, workspace))
+}
+
+fn transcript_location_in(prefs: &Preferences, workspace: Option<&Workspace>) -> Option<PathBuf> {
+    match workspace {
+        Some(w) => w
+            .transcript_location
+            .clone()
+            .or_else(|| w.default_dir("transcripts")),
+        None => prefs.transcript_location.clone(),
+    }
+}
+
+/// Set a custom transcript location for the active workspace
 pub(crate) fn set_transcript_location(path: Option<PathBuf>) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.transcript_location = path;
-    save_preferences(&prefs)
+    update(|prefs| match active_workspace_mut(prefs) {
+        Some(workspace) => workspace.transcript_location = path,
+        None => prefs.transcript_location = path,
+    })
 }
 
 /// Get the default transcript location path for display
@@ -121,15 +333,33 @@ pub(crate) fn default_transcript_location() -> Option<PathBuf> {
 }
 
 /// Get the custom screenshot location, if set
+///
+/// A workspace always has a screenshot location of its own.
 pub(crate) fn get_screenshot_location() -> Option<PathBuf> {
-    load_preferences().screenshot_location
+    read(|prefs| screenshot_location_in(prefs, active_workspace(prefs)))
+}
+
+/// Screenshot location of `workspace`, or of Default when None
+pub(crate) fn get_screenshot_location_in(workspace: Option<&Workspace>) -> Option<PathBuf> {
+    read(|prefs| screenshot_location_in(prefs, workspace))
 }
 
-/// Set a custom screenshot location
+fn screenshot_location_in(prefs: &Preferences, workspace: Option<&Workspace>) -> Option<PathBuf> {
+    match workspace {
+        Some(w) => w
+            .screenshot_location
+            .clone()
+            .or_else(|| w.default_dir("screenshots")),
+        None => prefs.screenshot_location.clone(),
+    }
+}
+
+/// Set a custom screenshot location for the active workspace
 pub(crate) fn set_screenshot_location(path: Option<PathBuf>) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.screenshot_location = path;
-    save_preferences(&prefs)
+    update(|prefs| match active_workspace_mut(prefs) {
+        Some(workspace) => workspace.screenshot_location = path,
+        None => prefs.screenshot_location = path,
+    })
 }
 
 /// Get the default screenshot location path
@@ -140,29 +370,107 @@ pub(crate) fn default_screenshot_location() -> Option<PathBuf> {
 /// Get the language code for transcription
 /// Returns "en" (English) if not set
 pub(crate) fn get_language_code() -> String {
-    load_preferences()
-        .language_code
-        .unwrap_or_else(|| "en".to_string())
+    read(|prefs| prefs.language_code.clone()).unwrap_or_else(|| "en".to_string())
 }
 
 /// Set the language code for transcription
 pub(crate) fn set_language_code(code: &str) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.language_code = Some(code.to_string());
-    save_preferences(&prefs)
+    update_setting(Setting::Language, |prefs| {
+        prefs.language_code = Some(code.to_string())
+    })
+}
+
+/// Get the language codes listed in the menu bar, None if never customized
+pub(crate) fn get_menu_languages() -> Option<Vec<String>> {
+    read(|prefs| prefs.menu_languages.clone())
+}
+
+/// Set the language codes listed in the menu bar
+pub(crate) fn set_menu_languages(codes: Vec<String>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.menu_languages = Some(codes))
+}
+
+/// Get the hotkey cycling through the favorite languages
+/// Returns Control + Shift + L if not set
+pub(crate) fn get_language_hotkey() -> LanguageHotkey {
+    read(|prefs| prefs.language_hotkey).unwrap_or_default()
+}
+
+/// Set the hotkey cycling through the favorite languages
+pub(crate) fn set_language_hotkey(hotkey: LanguageHotkey) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.language_hotkey = Some(hotkey))
+}
+
+/// Get the hotkeys stopping the recording with a mode
+/// Returns Control + Option + 1, 2 and 3 if not set
+pub(crate) fn get_stop_hotkeys() -> StopHotkeys {
+    read(|prefs| prefs.stop_hotkeys).unwrap_or_default()
+}
+
+/// Set the hotkeys stopping the recording with a mode
+pub(crate) fn set_stop_hotkeys(hotkeys: StopHotkeys) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.stop_hotkeys = Some(hotkeys))
 }
 
 /// Get the selected AI provider
-/// Returns Azure (default) for backward compatibility if not set
+/// Returns the provider locked by a managed policy if any, otherwise
+/// Azure (default) for backward compatibility if not set
 pub(crate) fn get_ai_provider() -> AiProvider {
-    load_preferences().ai_provider.unwrap_or_default()
+    if let Some(provider) = managed::policy().ai_provider {
+        return provider;
+    }
+    read(ai_provider).unwrap_or_default()
+}
+
+/// Transcription provider of the active workspace, falling back to Default's
+fn ai_provider(prefs: &Preferences) -> Option<AiProvider> {
+    active_workspace(prefs)
+        .and_then(|w| w.ai_provider)
+        .or(prefs.ai_provider)
 }
 
-/// Set the AI provider
+/// Set the AI provider for the active workspace
 pub(crate) fn set_ai_provider(provider: AiProvider) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.ai_provider = Some(provider);
-    save_preferences(&prefs)
+    update_setting(Setting::Provider, |prefs| {
+        match active_workspace_mut(prefs) {
+            Some(workspace) => workspace.ai_provider = Some(provider),
+            None => prefs.ai_provider = Some(provider),
+        }
+    })
+}
+
+/// Get the provider used for polishing
+/// Returns the provider locked by a managed policy if any, otherwise the
+/// transcription provider if polishing has no provider of its own
+pub(crate) fn get_polish_provider() -> AiProvider {
+    if let Some(provider) = managed::policy().ai_provider {
+        return provider;
+    }
+    read(|prefs| polish_provider(prefs).or(ai_provider(prefs))).unwrap_or_default()
+}
+
+/// Get the provider chosen for polishing, or None if it follows transcription
+pub(crate) fn get_polish_provider_setting() -> Option<AiProvider> {
+    read(polish_provider)
+}
+
+/// Polishing provider of the active workspace, falling back to Default's
+fn polish_provider(prefs: &Preferences) -> Option<AiProvider> {
+    active_workspace(prefs)
+        .and_then(|w| w.polish_provider)
+        .or(prefs.polish_provider)
+}
+
+/// Set the provider used for polishing in the active workspace (None = same
+/// as transcription)
+pub(crate) fn set_polish_provider(provider: Option<AiProvider>) -> Result<(), PreferencesError> {
+    update_setting(
+        Setting::PolishProvider,
+        |prefs| match active_workspace_mut(prefs) {
+            Some(workspace) => workspace.polish_provider = provider,
+            None => prefs.polish_provider = provider,
+        },
+    )
 }
 
 /// Default overlay transparency value (95%)
@@ -174,31 +482,606 @@ const DEFAULT_IS_DARK_MODE: bool = true;
 /// Get the overlay transparency setting
 /// Returns 0.95 (95%) if not set
 pub(crate) fn get_overlay_transparency() -> f64 {
-    load_preferences()
-        .overlay_transparency
-        .unwrap_or(DEFAULT_OVERLAY_TRANSPARENCY)
+    read(|prefs| prefs.overlay_transparency).unwrap_or(DEFAULT_OVERLAY_TRANSPARENCY)
 }
 
 /// Set the overlay transparency setting
 pub(crate) fn set_overlay_transparency(value: f64) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.overlay_transparency = Some(value.clamp(0.3, 1.0));
-    save_preferences(&prefs)
+    update_setting(Setting::Transparency, |prefs| {
+        prefs.overlay_transparency = Some(value.clamp(0.3, 1.0))
+    })
 }
 
 /// Get the dark mode setting
 /// Returns true (dark mode) if not set
 pub(crate) fn get_is_dark_mode() -> bool {
-    load_preferences()
-        .is_dark_mode
-        .unwrap_or(DEFAULT_IS_DARK_MODE)
+    read(|prefs| prefs.is_dark_mode).unwrap_or(DEFAULT_IS_DARK_MODE)
 }
 
 /// Set the dark mode setting
 pub(crate) fn set_is_dark_mode(is_dark: bool) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.is_dark_mode = Some(is_dark);
-    save_preferences(&prefs)
+    update_setting(Setting::DarkMode, |prefs| {
+        prefs.is_dark_mode = Some(is_dark)
+    })
+}
+
+/// Get the keyword highlighting rules
+/// Returns an empty list if not set
+pub(crate) fn get_keyword_rules() -> Vec<KeywordRule> {
+    read(|prefs| prefs.keyword_rules.clone()).unwrap_or_default()
+}
+
+/// Set the keyword highlighting rules
+pub(crate) fn set_keyword_rules(rules: Vec<KeywordRule>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.keyword_rules = Some(rules))
+}
+
+/// Get the sound cue and notification settings
+/// Returns all options off if not set
+pub(crate) fn get_feedback_settings() -> FeedbackSettings {
+    read(|prefs| prefs.feedback).unwrap_or_default()
+}
+
+/// Set the sound cue and notification settings
+pub(crate) fn set_feedback_settings(settings: FeedbackSettings) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.feedback = Some(settings))
+}
+
+/// Get whether the automation socket is enabled
+/// Returns false if not set
+pub(crate) fn get_automation_socket_enabled() -> bool {
+    read(|prefs| prefs.automation_socket_enabled).unwrap_or(false)
+}
+
+/// Set whether the automation socket is enabled
+pub(crate) fn set_automation_socket_enabled(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.automation_socket_enabled = Some(enabled))
+}
+
+/// Default port for the local HTTP API
+pub(crate) const DEFAULT_HTTP_API_PORT: u16 = 7717;
+
+/// Get whether the local HTTP API is enabled
+/// Returns false if not set
+pub(crate) fn get_http_api_enabled() -> bool {
+    read(|prefs| prefs.http_api_enabled).unwrap_or(false)
+}
+
+/// Set whether the local HTTP API is enabled
+pub(crate) fn set_http_api_enabled(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.http_api_enabled = Some(enabled))
+}
+
+/// Get the local HTTP API port
+/// Returns 7717 if not set
+pub(crate) fn get_http_api_port() -> u16 {
+    read(|prefs| prefs.http_api_port).unwrap_or(DEFAULT_HTTP_API_PORT)
+}
+
+/// Default port for the caption WebSocket feed
+pub(crate) const DEFAULT_CAPTION_WEBSOCKET_PORT: u16 = 7718;
+
+/// Get the live caption output
+/// Returns off if not set
+pub(crate) fn get_caption_output() -> CaptionOutput {
+    read(|prefs| prefs.caption_output).unwrap_or_default()
+}
+
+/// Set the live caption output
+pub(crate) fn set_caption_output(output: CaptionOutput) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.caption_output = Some(output))
+}
+
+/// Get the caption WebSocket port
+/// Returns 7718 if not set
+pub(crate) fn get_caption_websocket_port() -> u16 {
+    read(|prefs| prefs.caption_websocket_port).unwrap_or(DEFAULT_CAPTION_WEBSOCKET_PORT)
+}
+
+/// Check if saved transcripts and screenshots are encrypted
+/// Returns false if not set
+pub(crate) fn get_encrypt_at_rest() -> bool {
+    read(|prefs| prefs.encrypt_at_rest).unwrap_or(false)
+}
+
+/// Set whether saved transcripts and screenshots are encrypted
+pub(crate) fn set_encrypt_at_rest(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.encrypt_at_rest = Some(enabled))
+}
+
+/// Get the retention age limit in days of the active workspace
+/// Returns None (keep forever) if not set
+pub(crate) fn get_retention_days() -> Option<u32> {
+    read(|prefs| retention_days_in(prefs, active_workspace(prefs)))
+}
+
+/// Retention age limit of `workspace`, or of Default when None
+pub(crate) fn get_retention_days_in(workspace: Option<&Workspace>) -> Option<u32> {
+    read(|prefs| retention_days_in(prefs, workspace))
+}
+
+fn retention_days_in(prefs: &Preferences, workspace: Option<&Workspace>) -> Option<u32> {
+    match workspace {
+        Some(workspace) => workspace.retention_days,
+        None => prefs.retention_days,
+    }
+}
+
+/// Set the retention age limit in days of the active workspace (None = keep forever)
+pub(crate) fn set_retention_days(days: Option<u32>) -> Result<(), PreferencesError> {
+    update(|prefs| match active_workspace_mut(prefs) {
+        Some(workspace) => workspace.retention_days = days,
+        None => prefs.retention_days = days,
+    })
+}
+
+/// Get the disk usage cap in megabytes of the active workspace
+/// Returns None (no cap) if not set
+pub(crate) fn get_retention_max_mb() -> Option<u64> {
+    read(|prefs| retention_max_mb_in(prefs, active_workspace(prefs)))
+}
+
+/// Disk usage cap of `workspace`, or of Default when None
+pub(crate) fn get_retention_max_mb_in(workspace: Option<&Workspace>) -> Option<u64> {
+    read(|prefs| retention_max_mb_in(prefs, workspace))
+}
+
+fn retention_max_mb_in(prefs: &Preferences, workspace: Option<&Workspace>) -> Option<u64> {
+    match workspace {
+        Some(workspace) => workspace.retention_max_mb,
+        None => prefs.retention_max_mb,
+    }
+}
+
+/// Set the disk usage cap in megabytes of the active workspace (None = no cap)
+pub(crate) fn set_retention_max_mb(max_mb: Option<u64>) -> Result<(), PreferencesError> {
+    update(|prefs| match active_workspace_mut(prefs) {
+        Some(workspace) => workspace.retention_max_mb = max_mb,
+        None => prefs.retention_max_mb = max_mb,
+    })
+}
+
+/// Check if cloud polishing is allowed during private sessions
+/// Returns false if not set
+pub(crate) fn get_private_session_polish() -> bool {
+    read(|prefs| prefs.private_session_polish).unwrap_or(false)
+}
+
+/// Set whether cloud polishing is allowed during private sessions
+pub(crate) fn set_private_session_polish(allowed: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.private_session_polish = Some(allowed))
+}
+
+/// Check if local usage statistics are recorded
+/// Returns false if not set
+pub(crate) fn get_metrics_enabled() -> bool {
+    read(|prefs| prefs.metrics_enabled).unwrap_or(false)
+}
+
+/// Set whether local usage statistics are recorded
+pub(crate) fn set_metrics_enabled(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.metrics_enabled = Some(enabled))
+}
+
+/// Whether the transcription window shows the Debug tab
+pub(crate) fn get_debug_tab() -> bool {
+    read(|prefs| prefs.debug_tab).unwrap_or(false)
+}
+
+/// Set whether the transcription window shows the Debug tab
+pub(crate) fn set_debug_tab(enabled: bool) -> Result<(), PreferencesError> {
+    update_setting(Setting::DebugTab, |prefs| prefs.debug_tab = Some(enabled))
+}
+
+/// Get the audio chunk duration in milliseconds
+/// Returns 100 if not set
+pub(crate) fn get_audio_chunk_ms() -> u32 {
+    read(|prefs| prefs.audio_chunk_ms).unwrap_or(audio::DEFAULT_CHUNK_MS)
+}
+
+/// Set the audio chunk duration in milliseconds
+pub(crate) fn set_audio_chunk_ms(chunk_ms: u32) -> Result<(), PreferencesError> {
+    update_setting(Setting::Audio, |prefs| {
+        prefs.audio_chunk_ms = Some(chunk_ms)
+    })
+}
+
+/// Get the audio buffer depth in seconds
+/// Returns 60 if not set
+pub(crate) fn get_audio_buffer_secs() -> u32 {
+    read(|prefs| prefs.audio_buffer_secs).unwrap_or(audio::DEFAULT_BUFFER_SECS)
+}
+
+/// Set the audio buffer depth in seconds
+pub(crate) fn set_audio_buffer_secs(secs: u32) -> Result<(), PreferencesError> {
+    update_setting(Setting::Audio, |prefs| prefs.audio_buffer_secs = Some(secs))
+}
+
+/// Get how long audio is sent without a reply before reconnecting, in seconds
+/// Returns 20 if not set
+pub(crate) fn get_stall_timeout_secs() -> u32 {
+    read(|prefs| prefs.stall_timeout_secs).unwrap_or(transcription::DEFAULT_STALL_TIMEOUT_SECS)
+}
+
+/// Set how long audio is sent without a reply before reconnecting, in seconds
+pub(crate) fn set_stall_timeout_secs(secs: u32) -> Result<(), PreferencesError> {
+    update_setting(Setting::StallTimeout, |prefs| {
+        prefs.stall_timeout_secs = Some(secs)
+    })
+}
+
+/// Check if the connection is prepared when the menu bar menu opens
+/// Returns false if not set
+pub(crate) fn get_prewarm_connection() -> bool {
+    read(|prefs| prefs.prewarm_connection).unwrap_or(false)
+}
+
+/// Set whether the connection is prepared when the menu bar menu opens
+pub(crate) fn set_prewarm_connection(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.prewarm_connection = Some(enabled))
+}
+
+/// Check if low-bandwidth mode is on
+/// Returns false if not set
+pub(crate) fn get_low_bandwidth_mode() -> bool {
+    read(|prefs| prefs.low_bandwidth_mode).unwrap_or(false)
+}
+
+/// Set whether audio is sent in low-bandwidth mode
+pub(crate) fn set_low_bandwidth_mode(enabled: bool) -> Result<(), PreferencesError> {
+    update_setting(Setting::Audio, |prefs| {
+        prefs.low_bandwidth_mode = Some(enabled)
+    })
+}
+
+/// Get the keychain item format saved credentials are in
+/// Returns 1, the format before item names were versioned, if not set
+pub(crate) fn get_keychain_schema_version() -> u32 {
+    read(|prefs| prefs.keychain_schema_version).unwrap_or(1)
+}
+
+/// Record that saved credentials were migrated to keychain format `version`
+pub(crate) fn set_keychain_schema_version(version: u32) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.keychain_schema_version = Some(version))
+}
+
+/// Check if a failing provider hands the recording to the other provider
+/// Returns false if not set
+pub(crate) fn get_provider_failover() -> bool {
+    read(|prefs| prefs.provider_failover).unwrap_or(false)
+}
+
+/// Set whether a failing provider hands the recording to the other provider
+pub(crate) fn set_provider_failover(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.provider_failover = Some(enabled))
+}
+
+/// Get the model OpenAI transcribes with
+/// Returns gpt-4o-transcribe if not set
+pub(crate) fn get_openai_transcribe_model() -> TranscribeModel {
+    read(|prefs| prefs.openai_transcribe_model).unwrap_or_default()
+}
+
+/// Set the model OpenAI transcribes with
+pub(crate) fn set_openai_transcribe_model(model: TranscribeModel) -> Result<(), PreferencesError> {
+    update_setting(Setting::TranscribeModel, |prefs| {
+        prefs.openai_transcribe_model = Some(model)
+    })
+}
+
+/// Get the noise reduction the provider applies to the microphone audio
+/// Returns near field (headset) if not set
+pub(crate) fn get_noise_reduction() -> NoiseReduction {
+    read(|prefs| prefs.noise_reduction).unwrap_or_default()
+}
+
+/// Set the noise reduction the provider applies to the microphone audio
+pub(crate) fn set_noise_reduction(mode: NoiseReduction) -> Result<(), PreferencesError> {
+    update_setting(Setting::NoiseReduction, |prefs| {
+        prefs.noise_reduction = Some(mode)
+    })
+}
+
+/// Get the spoken number, date and amount rewrites for a transcription language
+/// Returns all off if not set
+pub(crate) fn get_normalize_options(language: &str) -> NormalizeOptions {
+    read(|prefs| prefs.normalize.as_ref()?.get(language).copied()).unwrap_or_default()
+}
+
+/// Set the spoken number, date and amount rewrites for a transcription language
+pub(crate) fn set_normalize_options(
+    language: &str,
+    options: NormalizeOptions,
+) -> Result<(), PreferencesError> {
+    update(|prefs| {
+        prefs
+            .normalize
+            .get_or_insert_with(BTreeMap::new)
+            .insert(language.to_string(), options);
+    })
+}
+
+/// Get the language code for the user interface
+/// Returns "en" (English) if not set
+pub(crate) fn get_ui_language() -> String {
+    read(|prefs| prefs.ui_language.clone()).unwrap_or_else(|| "en".to_string())
+}
+
+/// Set the language code for the user interface
+pub(crate) fn set_ui_language(code: &str) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.ui_language = Some(code.to_string()))
+}
+
+/// Get the apps during which screenshots are refused
+/// Returns an empty list if not set
+pub(crate) fn get_screenshot_blocklist() -> Vec<String> {
+    read(|prefs| prefs.screenshot_blocklist.clone()).unwrap_or_default()
+}
+
+/// Set the apps during which screenshots are refused
+pub(crate) fn set_screenshot_blocklist(apps: Vec<String>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.screenshot_blocklist = Some(apps))
+}
+
+/// Get the screenshot format, quality and resolution settings
+/// Returns full-resolution PNG if not set
+pub(crate) fn get_screenshot_settings() -> ScreenshotSettings {
+    read(|prefs| prefs.screenshot).unwrap_or_default()
+}
+
+/// Set the screenshot format, quality and resolution settings
+pub(crate) fn set_screenshot_settings(
+    settings: ScreenshotSettings,
+) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.screenshot = Some(settings))
+}
+
+/// Get the page size, margins and header/footer of saved PDFs
+/// Returns A4 with normal margins, the session title and page numbers if not set
+pub(crate) fn get_pdf_settings() -> PdfSettings {
+    read(|prefs| prefs.pdf).unwrap_or_default()
+}
+
+/// Set the page size, margins and header/footer of saved PDFs
+pub(crate) fn set_pdf_settings(settings: PdfSettings) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.pdf = Some(settings))
+}
+
+/// Get the recording duration and transcript size limits
+/// Returns a warning after 2 hours, a stop after 8 hours and a warning at
+/// 25,000 words if not set
+pub(crate) fn get_session_limits() -> SessionLimits {
+    read(|prefs| prefs.session_limits).unwrap_or_default()
+}
+
+/// Set the recording duration and transcript size limits
+pub(crate) fn set_session_limits(limits: SessionLimits) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.session_limits = Some(limits))
+}
+
+/// Get the notice at the top of exported meeting notes
+/// Returns the default notice, turned off, if not set
+pub(crate) fn get_consent_notice() -> ConsentNotice {
+    read(|prefs| prefs.consent_notice.clone()).unwrap_or_default()
+}
+
+/// Set the notice at the top of exported meeting notes
+pub(crate) fn set_consent_notice(notice: ConsentNotice) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.consent_notice = Some(notice))
+}
+
+/// Get how visibly the menu bar icon shows a recording
+/// Returns the standard red icon if not set
+pub(crate) fn get_recording_indicator() -> RecordingIndicator {
+    read(|prefs| prefs.recording_indicator).unwrap_or_default()
+}
+
+/// Set how visibly the menu bar icon shows a recording
+pub(crate) fn set_recording_indicator(
+    indicator: RecordingIndicator,
+) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.recording_indicator = Some(indicator))
+}
+
+/// Get whether a "Vissper is recording" banner is shown while recording
+/// Returns false if not set
+pub(crate) fn get_recording_banner() -> bool {
+    read(|prefs| prefs.recording_banner).unwrap_or(false)
+}
+
+/// Set whether a "Vissper is recording" banner is shown while recording
+pub(crate) fn set_recording_banner(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.recording_banner = Some(enabled))
+}
+
+/// Get whether the microphone test in Settings is also sent for transcription
+/// Returns false if not set
+pub(crate) fn get_mic_test_transcribe() -> bool {
+    read(|prefs| prefs.mic_test_transcribe).unwrap_or(false)
+}
+
+/// Set whether the microphone test in Settings is also sent for transcription
+pub(crate) fn set_mic_test_transcribe(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.mic_test_transcribe = Some(enabled))
+}
+
+/// Get what is copied to the clipboard when each stop mode finishes
+/// Returns the defaults (copy each mode's result) if not set
+pub(crate) fn get_auto_copy_settings() -> AutoCopySettings {
+    read(|prefs| prefs.auto_copy).unwrap_or_default()
+}
+
+/// Set what is copied to the clipboard when each stop mode finishes
+pub(crate) fn set_auto_copy_settings(settings: AutoCopySettings) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.auto_copy = Some(settings))
+}
+
+/// Check if recording starts when the wake phrase is heard
+/// Returns false if not set
+pub(crate) fn get_wake_word_enabled() -> bool {
+    read(|prefs| prefs.wake_word_enabled).unwrap_or(false)
+}
+
+/// Set whether recording starts when the wake phrase is heard
+pub(crate) fn set_wake_word_enabled(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.wake_word_enabled = Some(enabled))
+}
+
+/// Get the phrase that starts a recording
+/// Returns "Hey Vissper" if not set
+pub(crate) fn get_wake_word_phrase() -> String {
+    read(|prefs| prefs.wake_word_phrase.clone())
+        .unwrap_or_else(|| wake_word::DEFAULT_PHRASE.to_string())
+}
+
+/// Set the phrase that starts a recording
+pub(crate) fn set_wake_word_phrase(phrase: &str) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.wake_word_phrase = Some(phrase.to_string()))
+}
+
+/// Check if locking the screen stops the recording
+/// Returns false if not set
+pub(crate) fn get_stop_on_screen_lock() -> bool {
+    read(|prefs| prefs.stop_on_screen_lock).unwrap_or(false)
+}
+
+/// Set whether locking the screen stops the recording
+pub(crate) fn set_stop_on_screen_lock(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.stop_on_screen_lock = Some(enabled))
+}
+
+/// Check if meeting notes are split into topic sections
+/// Returns false if not set
+pub(crate) fn get_topic_sections() -> bool {
+    read(|prefs| prefs.topic_sections).unwrap_or(false)
+}
+
+/// Set whether meeting notes are split into topic sections
+pub(crate) fn set_topic_sections(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.topic_sections = Some(enabled))
+}
+
+/// Check if each session's meeting notes are exported to Notion
+/// Returns false if not set
+pub(crate) fn get_notion_export() -> bool {
+    read(|prefs| prefs.notion_export).unwrap_or(false)
+}
+
+/// Set whether each session's meeting notes are exported to Notion
+pub(crate) fn set_notion_export(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.notion_export = Some(enabled))
+}
+
+/// Check if saved transcripts are also uploaded to Google Drive
+/// Returns false if not set
+pub(crate) fn get_google_drive_upload() -> bool {
+    read(|prefs| prefs.google_drive_upload).unwrap_or(false)
+}
+
+/// Set whether saved transcripts are also uploaded to Google Drive
+pub(crate) fn set_google_drive_upload(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.google_drive_upload = Some(enabled))
+}
+
+/// Check if saved transcripts are committed to a git repository
+/// Returns false if not set
+pub(crate) fn get_git_archive() -> bool {
+    read(|prefs| prefs.git_archive).unwrap_or(false)
+}
+
+/// Set whether saved transcripts are committed to a git repository
+pub(crate) fn set_git_archive(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.git_archive = Some(enabled))
+}
+
+/// Check if git archive commits are pushed
+/// Returns false if not set
+pub(crate) fn get_git_archive_push() -> bool {
+    read(|prefs| prefs.git_archive_push).unwrap_or(false)
+}
+
+/// Set whether git archive commits are pushed
+pub(crate) fn set_git_archive_push(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.git_archive_push = Some(enabled))
+}
+
+/// Get the remote URL the git archive is pushed to
+pub(crate) fn get_git_archive_remote() -> Option<String> {
+    read(|prefs| prefs.git_archive_remote.clone()).flatten()
+}
+
+/// Set the remote URL the git archive is pushed to, or clear it with `None`
+pub(crate) fn set_git_archive_remote(url: Option<String>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.git_archive_remote = url)
+}
+
+/// Check if transcripts are stored in iCloud Drive
+/// Returns false if not set
+pub(crate) fn get_store_in_icloud() -> bool {
+    read(|prefs| prefs.store_in_icloud).unwrap_or(false)
+}
+
+/// Set whether transcripts are stored in iCloud Drive
+pub(crate) fn set_store_in_icloud(enabled: bool) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.store_in_icloud = Some(enabled))
+}
+
+/// Get the output modes defined by the user
+pub(crate) fn get_custom_output_modes() -> Vec<CustomMode> {
+    read(|prefs| prefs.custom_output_modes.clone()).unwrap_or_default()
+}
+
+/// Set the output modes defined by the user
+pub(crate) fn set_custom_output_modes(modes: Vec<CustomMode>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.custom_output_modes = Some(modes))
+}
+
+/// Get the workspaces besides Default
+pub(crate) fn get_workspaces() -> Vec<Workspace> {
+    read(|prefs| prefs.workspaces.clone()).unwrap_or_default()
+}
+
+/// Set the workspaces besides Default
+///
+/// Switches back to Default if the active workspace is no longer listed.
+pub(crate) fn set_workspaces(workspaces: Vec<Workspace>) -> Result<(), PreferencesError> {
+    update(|prefs| {
+        prefs.workspaces = Some(workspaces);
+        if active_workspace(prefs).is_none() {
+            prefs.active_workspace = None;
+        }
+    })
+}
+
+/// Get the name of the active workspace, or None for Default
+pub(crate) fn get_active_workspace() -> Option<String> {
+    read(|prefs| active_workspace(prefs).map(|w| w.name.clone()))
+}
+
+/// Make a workspace active (None = Default)
+pub(crate) fn set_active_workspace(name: Option<String>) -> Result<(), PreferencesError> {
+    // The workspace brings its own providers
+    update_setting(Setting::Provider, |prefs| prefs.active_workspace = name)
+}
+
+/// Get the settings of the active workspace, or None for Default
+pub(crate) fn get_active_workspace_settings() -> Option<Workspace> {
+    read(|prefs| active_workspace(prefs).cloned())
+}
+
+/// Get the recording templates
+pub(crate) fn get_templates() -> Vec<Template> {
+    read(|prefs| prefs.templates.clone()).unwrap_or_default()
+}
+
+/// Set the recording templates
+pub(crate) fn set_templates(templates: Vec<Template>) -> Result<(), PreferencesError> {
+    update(|prefs| prefs.templates = Some(templates))
+}
+
+/// Get the active workspace's instructions for polishing, if any
+pub(crate) fn get_workspace_prompt() -> Option<String> {
+    read(|prefs| active_workspace(prefs).and_then(|w| w.prompt.clone()))
 }
 
 /// Check if enough time has elapsed to perform a version check
@@ -208,8 +1091,7 @@ pub(crate) fn set_is_dark_mode(is_dark: bool) -> Result<(), PreferencesError> {
 /// - More than 24 hours have elapsed since last check
 /// - Last check timestamp is invalid
 pub(crate) fn should_check_for_updates() -> bool {
-    let prefs = load_preferences();
-    match prefs.last_version_check {
+    match read(|prefs| prefs.last_version_check.clone()) {
         None => true, // Never checked
         Some(last_check_str) => match chrono::DateTime::parse_from_rfc3339(&last_check_str) {
             Ok(last_check) => {
@@ -227,37 +1109,37 @@ pub(crate) fn should_check_for_updates() -> bool {
 
 /// Update the last version check timestamp to now
 pub(crate) fn update_version_check_time() -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.last_version_check = Some(chrono::Utc::now().to_rfc3339());
-    save_preferences(&prefs)
+    update(|prefs| prefs.last_version_check = Some(chrono::Utc::now().to_rfc3339()))
 }
 
 /// Get the latest known version from cache
 pub(crate) fn get_latest_known_version() -> Option<String> {
-    load_preferences()
-        .latest_known_version
-        .filter(|v| !v.is_empty())
+    read(|prefs| prefs.latest_known_version.clone()).filter(|v| !v.is_empty())
 }
 
 /// Set the latest known version in cache
 pub(crate) fn set_latest_known_version(version: &str) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.latest_known_version = Some(version.to_string());
-    save_preferences(&prefs)
+    update(|prefs| prefs.latest_known_version = Some(version.to_string()))
 }
 
 /// Get the latest known download URL from cache
 pub(crate) fn get_latest_download_url() -> Option<String> {
-    load_preferences()
-        .latest_download_url
-        .filter(|v| !v.is_empty())
+    read(|prefs| prefs.latest_download_url.clone()).filter(|v| !v.is_empty())
 }
 
 /// Set the latest known download URL in cache
 pub(crate) fn set_latest_download_url(url: &str) -> Result<(), PreferencesError> {
-    let mut prefs = load_preferences();
-    prefs.latest_download_url = Some(url.to_string());
-    save_preferences(&prefs)
+    update(|prefs| prefs.latest_download_url = Some(url.to_string()))
+}
+
+/// Copy of all preferences, for exporting them
+pub(crate) fn snapshot() -> Preferences {
+    read(Preferences::clone)
+}
+
+/// Replace all preferences, e.g. with imported ones
+pub(crate) fn replace(prefs: Preferences) -> Result<(), PreferencesError> {
+    update(|current| *current = prefs)
 }
 
 /// Preferences errors
@@ -0,0 +1,37 @@
+//! Private sessions for sensitive conversations
+//!
+//! While a private session is on, nothing from the conversation leaves the
+//! transcription window:
+//! - screenshots are refused, so the screenshots folder is never created
+//! - the save button stays hidden
+//! - transcripts are not copied to the clipboard
+//! - live captions are not published
+//!
+//! Polishing sends the transcript to the cloud provider, so it is skipped as
+//! well unless allowed in the Privacy tab of Settings.
+//!
+//! The toggle is in the menu bar and can't change while recording or
+//! polishing. It is kept in memory only, so every launch starts with it off.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::info;
+
+use crate::preferences;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Whether a private session is on
+pub(crate) fn is_active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Turn private sessions on or off
+pub(crate) fn set_active(active: bool) {
+    ACTIVE.store(active, Ordering::SeqCst);
+    info!(active, "Private session toggled");
+}
+
+/// Whether polishing is skipped because a private session is on
+pub(crate) fn skips_polishing() -> bool {
+    is_active() && !preferences::get_private_session_polish()
+}
@@ -0,0 +1,224 @@
+//! Which providers, models and prompts produced a transcript
+//!
+//! The speech-to-text services of the current recording and the polish
+//! model and prompt behind the text waiting to be saved are recorded here.
+//! Saved Markdown files get them as front-matter and the git archive puts
+//! them in its commit messages, so output from different configurations can
+//! be compared later.
+//!
+//! Prompts have no version numbers of their own; a prompt's version is a
+//! short hash of its template, so it changes whenever the wording does.
+
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::response::PolishConfig;
+
+/// A speech-to-text service used for a recording
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Transcription {
+    pub(crate) provider: String,
+    /// Model, or deployment on Azure
+    pub(crate) model: String,
+}
+
+/// The model and prompt a text was polished with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Polish {
+    pub(crate) provider: String,
+    /// Model, or deployment on Azure
+    pub(crate) model: String,
+    /// Prompt type, such as "live_meeting" or "custom"
+    pub(crate) prompt: String,
+    pub(crate) prompt_version: String,
+}
+
+impl Polish {
+    /// Polish with `model` from `provider`, using `template` for `config`
+    pub(crate) fn new(
+        provider: impl fmt::Display,
+        model: &str,
+        config: &PolishConfig,
+        template: &str,
+    ) -> Self {
+        Self {
+            provider: provider.to_string(),
+            model: model.to_string(),
+            prompt: config
+                .prompt_type
+                .clone()
+                .unwrap_or_else(|| "default".to_string()),
+            prompt_version: prompt_version(template),
+        }
+    }
+}
+
+/// Everything known about how a transcript was produced
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Provenance {
+    /// Speech-to-text services in the order used; two after a failover
+    pub(crate) transcription: Vec<Transcription>,
+    /// None when the text is the raw transcript
+    pub(crate) polish: Option<Polish>,
+}
+
+static CURRENT: Mutex<Provenance> = Mutex::new(Provenance {
+    transcription: Vec::new(),
+    polish: None,
+});
+
+/// Forget the previous recording's services when a new recording starts
+pub(crate) fn recording_started() {
+    if let Ok(mut current) = CURRENT.lock() {
+        *current = Provenance::default();
+    }
+}
+
+/// Record that the recording is transcribed with `model` from `provider`
+pub(crate) fn transcribed_with(provider: impl fmt::Display, model: &str) {
+    if let Ok(mut current) = CURRENT.lock() {
+        current.transcription.push(Transcription {
+            provider: provider.to_string(),
+            model: model.to_string(),
+        });
+    }
+}
+
+/// Record how the text waiting to be saved was polished, if it was
+pub(crate) fn set_polish(polish: Option<Polish>) {
+    if let Ok(mut current) = CURRENT.lock() {
+        current.polish = polish;
+    }
+}
+
+/// How the text waiting to be saved was produced
+pub(crate) fn current() -> Provenance {
+    CURRENT
+        .lock()
+        .map(|current| current.clone())
+        .unwrap_or_default()
+}
+
+impl Provenance {
+    /// YAML front-matter for a Markdown file, or nothing if nothing is known
+    pub(crate) fn front_matter(&self) -> String {
+        let fields = self.fields();
+        if fields.is_empty() {
+            return String::new();
+        }
+        let lines: String = fields
+            .iter()
+            .map(|(key, value)| format!("{}: {}\n", key, yaml_string(value)))
+            .collect();
+        format!("---\n{}---\n\n", lines)
+    }
+
+    /// Lines for a commit message, one per service
+    pub(crate) fn summary(&self) -> String {
+        let mut summary = String::new();
+        if !self.transcription.is_empty() {
+            let services: Vec<String> = self
+                .transcription
+                .iter()
+                .map(|t| format!("{} ({})", t.provider, t.model))
+                .collect();
+            summary.push_str(&format!("Transcribed with: {}\n", services.join(", ")));
+        }
+        if let Some(polish) = &self.polish {
+            summary.push_str(&format!(
+                "Polished with: {} ({})\nPrompt: {} ({})\n",
+                polish.provider, polish.model, polish.prompt, polish.prompt_version
+            ));
+        }
+        summary
+    }
+
+    /// Front-matter keys and values, leaving out what isn't known
+    fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = Vec::new();
+        if !self.transcription.is_empty() {
+            let join = |part: fn(&Transcription) -> &str| {
+                let parts: Vec<&str> = self.transcription.iter().map(part).collect();
+                parts.join(", ")
+            };
+            fields.push(("transcription_provider", join(|t| &t.provider)));
+            fields.push(("transcription_model", join(|t| &t.model)));
+        }
+        if let Some(polish) = &self.polish {
+            fields.push(("polish_provider", polish.provider.clone()));
+            fields.push(("polish_model", polish.model.clone()));
+            fields.push(("prompt", polish.prompt.clone()));
+            fields.push(("prompt_version", polish.prompt_version.clone()));
+        }
+        fields
+    }
+}
+
+/// Version of a prompt template: its 32-bit FNV-1a hash in hex
+///
+/// Unlike the standard library's hasher, this stays the same across builds,
+/// so versions in files saved months apart can be compared.
+fn prompt_version(template: &str) -> String {
+    let hash = template.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{:08x}", hash)
+}
+
+/// `value` as a quoted YAML string; JSON strings are valid YAML
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provenance() -> Provenance {
+        Provenance {
+            transcription: vec![
+                Transcription {
+                    provider: "Azure OpenAI".to_string(),
+                    model: "stt: eu".to_string(),
+                },
+                Transcription {
+                    provider: "OpenAI".to_string(),
+                    model: "gpt-4o-transcribe".to_string(),
+                },
+            ],
+            polish: Some(Polish::new(
+                "OpenAI",
+                "gpt-5.2",
+                &PolishConfig {
+                    prompt_type: Some("follow_up_email".to_string()),
+                    ..Default::default()
+                },
+                "a",
+            )),
+        }
+    }
+
+    #[test]
+    fn test_front_matter() {
+        assert_eq!(
+            provenance().front_matter(),
+            "---\ntranscription_provider: \"Azure OpenAI, OpenAI\"\ntranscription_model: \"stt: eu, gpt-4o-transcribe\"\npolish_provider: \"OpenAI\"\npolish_model: \"gpt-5.2\"\nprompt: \"follow_up_email\"\nprompt_version: \"e40c292c\"\n---\n\n"
+        );
+        assert_eq!(Provenance::default().front_matter(), "");
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(
+            provenance().summary(),
+            "Transcribed with: Azure OpenAI (stt: eu), OpenAI (gpt-4o-transcribe)\nPolished with: OpenAI (gpt-5.2)\nPrompt: follow_up_email (e40c292c)\n"
+        );
+        assert_eq!(Provenance::default().summary(), "");
+    }
+
+    #[test]
+    fn test_prompt_version_is_stable() {
+        assert_eq!(prompt_version(""), "811c9dc5");
+        assert_eq!(prompt_version("a"), "e40c292c");
+    }
+}
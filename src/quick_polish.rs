@@ -0,0 +1,140 @@
+//! Quick polish: clean up any text with the Basic Polish prompt
+//!
+//! Control + Shift + P takes the text selected in the frontmost app, read
+//! through the accessibility API, or the clipboard when nothing is selected
+//! (or Vissper isn't trusted for accessibility). The text is polished like a
+//! transcript and the result is put on the clipboard, as plain and rich text,
+//! ready to paste over the original. A notification says when it's ready or why nothing happened.
+//!
+//! No recording is needed and the transcription window is left alone.
+
+use arboard::Clipboard;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, Bool};
+use objc2::{msg_send, ClassType};
+use objc2_foundation::NSString;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{error, info};
+
+use crate::feedback;
+use crate::managed;
+use crate::private_mode;
+use crate::recording;
+use crate::response::PolishConfig;
+use crate::rich_text;
+
+/// kAXErrorSuccess
+const AX_ERROR_SUCCESS: i32 = 0;
+
+#[link(name = "ApplicationServices", kind = "framework")]
+extern "C" {
+    fn AXIsProcessTrusted() -> Bool;
+    fn AXUIElementCreateSystemWide() -> *mut AnyObject;
+    fn AXUIElementCopyAttributeValue(
+        element: *const AnyObject,
+        attribute: *const NSString,
+        value: *mut *mut AnyObject,
+    ) -> i32;
+}
+
+/// Set while a quick polish is in flight, so repeated presses are ignored
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Polish the selected text or the clipboard and copy the result.
+pub(crate) async fn run() {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        info!("Quick polish already running, ignoring");
+        return;
+    }
+
+    let (title, body) = match polish_selection_or_clipboard().await {
+        Ok(chars) => {
+            info!(chars, "Quick polish copied to clipboard");
+            ("Polished text copied", "Paste it wherever you need it.")
+        }
+        Err(reason) => {
+            info!("Quick polish skipped: {}", reason);
+            ("Quick polish", reason)
+        }
+    };
+    feedback::notify_now(title, body);
+
+    RUNNING.store(false, Ordering::SeqCst);
+}
+
+/// Returns the length of the polished text, or why nothing was polished
+async fn polish_selection_or_clipboard() -> Result<usize, &'static str> {
+    if managed::policy().disable_polishing {
+        return Err("Polishing is turned off by your organization.");
+    }
+    if private_mode::skips_polishing() {
+        return Err("Polishing is turned off during private sessions.");
+    }
+
+    let Some(text) = selected_text().or_else(clipboard_text) else {
+        return Err("Select some text or copy it to the clipboard first.");
+    };
+
+    let polished = recording::polish_text(&text, &PolishConfig::basic_polish())
+        .await
+        .map_err(|e| {
+            error!("Quick polish failed: {}", e);
+            "Polishing failed. The clipboard was left unchanged."
+        })?;
+
+    Clipboard::new()
+        .and_then(|mut clipboard| {
+            let html = rich_text::markdown_to_html(&polished);
+            clipboard.set_html(html.as_str(), Some(polished.as_str()))
+        })
+        .map_err(|e| {
+            error!("Failed to copy polished text to clipboard: {}", e);
+            "The polished text could not be copied to the clipboard."
+        })?;
+
+    Ok(polished.len())
+}
+
+/// Text on the clipboard, if any
+fn clipboard_text() -> Option<String> {
+    Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .ok()
+        .filter(|text| !text.trim().is_empty())
+}
+
+/// Text selected in the focused element of the frontmost app, if Vissper is
+/// trusted for accessibility
+fn selected_text() -> Option<String> {
+    // SAFETY: AX functions may be called from any thread; copied values are
+    // owned (+1) and released by Retained
+    unsafe {
+        if !AXIsProcessTrusted().as_bool() {
+            return None;
+        }
+        let system = Retained::from_raw(AXUIElementCreateSystemWide())?;
+        let focused = copy_attribute(&system, "AXFocusedUIElement")?;
+        let selected = copy_attribute(&focused, "AXSelectedText")?;
+
+        // AXSelectedText is a CFString, toll-free bridged to NSString
+        let is_string: Bool = msg_send![&selected, isKindOfClass: NSString::class()];
+        if !is_string.as_bool() {
+            return None;
+        }
+        let text = &*(Retained::as_ptr(&selected) as *const NSString);
+        Some(text.to_string()).filter(|text| !text.trim().is_empty())
+    }
+}
+
+/// Copy an accessibility attribute value of `element`.
+///
+/// # Safety
+/// `element` must be an AXUIElement.
+unsafe fn copy_attribute(element: &AnyObject, attribute: &str) -> Option<Retained<AnyObject>> {
+    let mut value: *mut AnyObject = std::ptr::null_mut();
+    let error = AXUIElementCopyAttributeValue(element, &*NSString::from_str(attribute), &mut value);
+    if error != AX_ERROR_SUCCESS {
+        return None;
+    }
+    Retained::from_raw(value)
+}
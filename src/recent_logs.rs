@@ -0,0 +1,117 @@
+//! Recent log lines kept in memory for bug reports
+//!
+//! Logging goes to stderr as before. A second copy of every line, without
+//! terminal colors, is kept in a bounded buffer so Report a Problem can
+//! include what happened just before it. Nothing is written to disk.
+//!
+//! Lines logged with the [`TRANSCRIPT_TARGET`] target carry what was said
+//! and only go to stderr.
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+use tracing::Subscriber;
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::fmt;
+use tracing_subscriber::layer::{Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Target for log lines containing transcript text, which are never kept
+pub(crate) const TRANSCRIPT_TARGET: &str = "transcript";
+
+/// Lines kept before the oldest are dropped
+const MAX_LINES: usize = 1000;
+
+static LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Log info and above to stderr, keeping the recent lines.
+pub(crate) fn init() {
+    tracing_subscriber::registry()
+        .with(LevelFilter::INFO)
+        .with(fmt::layer())
+        .with(recent_layer())
+        .init();
+}
+
+/// Layer keeping every line except transcript text
+fn recent_layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fmt::layer()
+        .with_ansi(false)
+        .with_writer(|| RecentLogs)
+        .with_filter(filter_fn(|metadata| metadata.target() != TRANSCRIPT_TARGET))
+}
+
+/// The kept lines, oldest first
+pub(crate) fn recent_lines() -> String {
+    LINES
+        .lock()
+        .map(|lines| {
+            lines
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+fn keep(lines: &mut VecDeque<String>, text: &str) {
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        if lines.len() == MAX_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line.to_string());
+    }
+}
+
+/// Writer for the formatted events, each written in one piece
+struct RecentLogs;
+
+impl io::Write for RecentLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Ok(mut lines) = LINES.lock() {
+            keep(&mut lines, &String::from_utf8_lossy(buf));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_the_most_recent_lines() {
+        let mut lines = VecDeque::new();
+        for i in 0..MAX_LINES {
+            keep(&mut lines, &format!("line {}\n", i));
+        }
+        keep(&mut lines, "next to last\nlast\n");
+
+        assert_eq!(lines.len(), MAX_LINES);
+        assert_eq!(lines.front().map(String::as_str), Some("line 2"));
+        assert_eq!(lines.back().map(String::as_str), Some("last"));
+    }
+
+    #[test]
+    fn test_transcript_lines_are_not_kept() {
+        let subscriber = tracing_subscriber::registry().with(recent_layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: TRANSCRIPT_TARGET, "Committed: the secret plan");
+            tracing::info!("Recording stopped");
+        });
+
+        let recent = recent_lines();
+        assert!(recent.contains("Recording stopped"));
+        assert!(!recent.contains("secret plan"));
+    }
+}
@@ -1,12 +1,128 @@
 //! Clipboard functionality for recording module
 //!
-//! Handles copying transcripts to the system clipboard.
+//! Handles copying transcripts to the system clipboard. What is copied when a
+//! stop mode finishes is configured per mode in the Notifications tab of
+//! Settings; the mode is identified by the tab its result is shown in.
+//! Polished output is copied as rich text too, so it pastes with its
+//! headings and bullets into Mail, Pages or Outlook.
 
 use arboard::Clipboard;
+use serde::{Deserialize, Serialize};
 use tracing::{error, info};
 
+use crate::preferences;
+use crate::private_mode;
+use crate::rich_text;
+use crate::transcription_window::TabType;
+
+/// What is copied to the clipboard when a stop mode finishes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AutoCopy {
+    /// The mode's result: the raw transcript, polished text, meeting notes or
+    /// follow-up email
+    #[default]
+    Result,
+    /// The raw transcript, even when polishing succeeded
+    Raw,
+    /// Leave the clipboard alone
+    Nothing,
+}
+
+impl AutoCopy {
+    /// Choices offered for a stop mode, in the order shown in Settings.
+    ///
+    /// Without polishing the result is the raw transcript, so there is no
+    /// separate raw choice.
+    pub(crate) fn choices(mode: TabType) -> &'static [AutoCopy] {
+        match mode {
+            TabType::Live => &[AutoCopy::Result, AutoCopy::Nothing],
+            _ => &[AutoCopy::Result, AutoCopy::Raw, AutoCopy::Nothing],
+        }
+    }
+
+    /// Segment label in Settings
+    pub(crate) fn label(self, mode: TabType) -> &'static str {
+        match (self, mode) {
+            (AutoCopy::Result, TabType::Live) | (AutoCopy::Raw, _) => "Raw transcript",
+            (AutoCopy::Result, TabType::BasicPolish) => "Polished text",
+            (AutoCopy::Result, TabType::MeetingNotes) => "Meeting notes",
+            (AutoCopy::Result, TabType::FollowUpEmail) => "Email",
+            (AutoCopy::Result, TabType::Custom(_)) => "Result",
+            (AutoCopy::Nothing, _) => "Nothing",
+        }
+    }
+
+    /// Text to copy, given the raw transcript and the mode's result
+    fn text<'a>(self, raw: &'a str, result: &'a str) -> Option<&'a str> {
+        match self {
+            AutoCopy::Result => Some(result),
+            AutoCopy::Raw => Some(raw),
+            AutoCopy::Nothing => None,
+        }
+    }
+}
+
+/// Persisted auto-copy choice for each stop mode
+///
+/// Defaults to copying each mode's result, as before this was configurable.
+/// Custom output modes always copy their result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct AutoCopySettings {
+    pub no_polish: AutoCopy,
+    pub basic_polish: AutoCopy,
+    pub meeting_notes: AutoCopy,
+    pub follow_up_email: AutoCopy,
+}
+
+impl AutoCopySettings {
+    pub(crate) fn for_mode(&self, mode: TabType) -> AutoCopy {
+        match mode {
+            TabType::Live => self.no_polish,
+            TabType::BasicPolish => self.basic_polish,
+            TabType::MeetingNotes => self.meeting_notes,
+            TabType::FollowUpEmail => self.follow_up_email,
+            TabType::Custom(_) => AutoCopy::Result,
+        }
+    }
+
+    pub(crate) fn set_for_mode(&mut self, mode: TabType, choice: AutoCopy) {
+        let field = match mode {
+            TabType::Live => &mut self.no_polish,
+            TabType::BasicPolish => &mut self.basic_polish,
+            TabType::MeetingNotes => &mut self.meeting_notes,
+            TabType::FollowUpEmail => &mut self.follow_up_email,
+            TabType::Custom(_) => return,
+        };
+        *field = choice;
+    }
+}
+
+/// Copy the text configured for a finished stop mode.
+///
+/// `result` is what the mode produced; when polishing failed it is the raw
+/// transcript shown in its place.
+pub(crate) fn auto_copy(mode: TabType, raw: &str, result: &str) {
+    let choice = preferences::get_auto_copy_settings().for_mode(mode);
+    match choice.text(raw, result) {
+        // The result differs from the raw transcript only when polishing succeeded
+        Some(text) if choice == AutoCopy::Result && result != raw => {
+            copy_rich_text_to_clipboard(text)
+        }
+        Some(text) => copy_to_clipboard(text),
+        None => info!(?mode, "Clipboard left unchanged: auto-copy is off"),
+    }
+}
+
 /// Copy text to clipboard
+///
+/// Skipped during a private session.
 pub(crate) fn copy_to_clipboard(transcript: &str) {
+    if private_mode::is_active() {
+        info!("Transcript not copied to clipboard: private session");
+        return;
+    }
     if !transcript.trim().is_empty() {
         match Clipboard::new() {
             Ok(mut clipboard) => match clipboard.set_text(transcript) {
@@ -28,3 +144,69 @@ pub(crate) fn copy_to_clipboard(transcript: &str) {
         info!("No transcript to copy (empty)");
     }
 }
+
+/// Copy Markdown to the clipboard as HTML, with the Markdown as plain text
+///
+/// Apps that take rich text paste formatted headings, lists and bold text;
+/// others paste the Markdown. Skipped during a private session.
+pub(crate) fn copy_rich_text_to_clipboard(markdown: &str) {
+    if private_mode::is_active() {
+        info!("Transcript not copied to clipboard: private session");
+        return;
+    }
+    if markdown.trim().is_empty() {
+        info!("No transcript to copy (empty)");
+        return;
+    }
+    let html = rich_text::markdown_to_html(markdown);
+    match Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_html(html.as_str(), Some(markdown)))
+    {
+        Ok(()) => info!(
+            "Transcript copied to clipboard as rich text ({} chars)",
+            markdown.len()
+        ),
+        Err(e) => error!("Failed to copy transcript to clipboard: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_to_copy() {
+        assert_eq!(AutoCopy::Result.text("raw", "polished"), Some("polished"));
+        assert_eq!(AutoCopy::Raw.text("raw", "polished"), Some("raw"));
+        assert_eq!(AutoCopy::Nothing.text("raw", "polished"), None);
+    }
+
+    #[test]
+    fn test_settings_per_mode() {
+        let mut settings = AutoCopySettings::default();
+        assert_eq!(settings.for_mode(TabType::MeetingNotes), AutoCopy::Result);
+
+        settings.set_for_mode(TabType::MeetingNotes, AutoCopy::Raw);
+        assert_eq!(settings.for_mode(TabType::MeetingNotes), AutoCopy::Raw);
+        assert_eq!(settings.for_mode(TabType::BasicPolish), AutoCopy::Result);
+    }
+
+    #[test]
+    fn test_missing_modes_default_to_result() {
+        let settings: AutoCopySettings =
+            serde_json::from_str(r#"{"basic_polish":"nothing"}"#).unwrap();
+        assert_eq!(settings.basic_polish, AutoCopy::Nothing);
+        assert_eq!(settings.no_polish, AutoCopy::Result);
+        assert_eq!(settings.follow_up_email, AutoCopy::Result);
+    }
+
+    #[test]
+    fn test_labels() {
+        let labels: Vec<&str> = AutoCopy::choices(TabType::BasicPolish)
+            .iter()
+            .map(|choice| choice.label(TabType::BasicPolish))
+            .collect();
+        assert_eq!(labels, ["Polished text", "Raw transcript", "Nothing"]);
+        assert_eq!(AutoCopy::choices(TabType::Live).len(), 2);
+    }
+}
@@ -0,0 +1,33 @@
+//! Data usage shown in the transcription window footer while recording
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::interval;
+
+use super::RecordingSession;
+use crate::transcription;
+use crate::ui_sink::SharedUi;
+
+/// How often the footer figure is refreshed
+const UPDATE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Refresh the footer until the recording's connection has closed
+///
+/// The last refresh, after the recording state is cleared, includes the
+/// final transcript received after audio capture stopped.
+pub(super) fn spawn_usage_updates(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+) {
+    tokio::spawn(async move {
+        let mut ticker = interval(UPDATE_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let recording = recording_state.lock().is_ok_and(|state| state.is_some());
+            ui.show_data_usage(&transcription::data_usage().summary());
+            if !recording {
+                break;
+            }
+        }
+    });
+}
@@ -0,0 +1,128 @@
+//! Demo session for new users
+//!
+//! Replays a bundled sample meeting through the normal recording flow, so the
+//! live partials, polishing and meeting notes can be seen before speaking or
+//! setting up credentials. The microphone is never opened and nothing is sent
+//! to a provider: the transcript comes from `resources/demo/session.json` and
+//! the polished outputs are canned.
+//!
+//! The demo stays active after it stops, so polishing another tab of the
+//! sample meeting shows its canned output too, until a real recording starts.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{error, info};
+
+use crate::audio::AZURE_SAMPLE_RATE;
+use crate::output_modes;
+use crate::preferences;
+use crate::transcription::MockFixture;
+use crate::transcription_window::TabType;
+use crate::ui_sink::SharedUi;
+
+use super::phase;
+use super::polish_helpers::{focus_tab, set_polished_content, show_save_button};
+use super::transcription_task::TranscriptionProviderConfig;
+use super::RecordingSession;
+
+/// Canned polished transcript of the sample meeting
+const POLISHED: &str = include_str!("../../resources/demo/polished.md");
+
+/// Canned meeting notes of the sample meeting
+const MEETING_NOTES: &str = include_str!("../../resources/demo/meeting_notes.md");
+
+/// Canned follow-up email of the sample meeting
+const FOLLOW_UP_EMAIL: &str = include_str!("../../resources/demo/follow_up_email.md");
+
+/// Pause after the last replayed line before the demo stops itself
+const STOP_DELAY: Duration = Duration::from_millis(1500);
+
+/// Pretend polishing takes a moment, so the processing state is visible
+const POLISH_DELAY: Duration = Duration::from_millis(1200);
+
+/// Whether the transcript on screen is the sample meeting
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Bumped by every recording, so a demo's timer doesn't stop a later one
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Replay the sample meeting as a recording, then stop with meeting notes
+///
+/// Does nothing while a recording is in progress.
+pub(crate) fn start_demo(recording_state: Arc<Mutex<Option<RecordingSession>>>, ui: SharedUi) {
+    if !phase::begin_start() {
+        info!("Demo session not started: a recording is in progress");
+        return;
+    }
+
+    let fixture = match MockFixture::demo() {
+        Ok(fixture) => fixture,
+        Err(e) => {
+            error!("Cannot start demo session: {}", e);
+            phase::start_failed();
+            ui.show_error(&format!("The demo session could not be loaded.\n\n{}", e));
+            return;
+        }
+    };
+    let stop_after = fixture.duration() + STOP_DELAY;
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    ACTIVE.store(true, Ordering::SeqCst);
+    info!("Starting demo session");
+
+    super::start_session(
+        recording_state.clone(),
+        ui.clone(),
+        false,
+        preferences::get_ai_provider(),
+        TranscriptionProviderConfig::Mock { fixture },
+        AZURE_SAMPLE_RATE,
+    );
+
+    tokio::spawn(async move {
+        tokio::time::sleep(stop_after).await;
+        if GENERATION.load(Ordering::SeqCst) != generation || !is_capturing(&recording_state) {
+            return;
+        }
+        info!("Demo session finished, stopping with meeting notes");
+        super::stop_recording_with_mode(recording_state, ui, TabType::MeetingNotes);
+    });
+}
+
+/// Whether polishing should show the sample meeting's canned output
+pub(super) fn is_active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+/// A real recording replaces the sample meeting
+pub(super) fn recording_started() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    ACTIVE.store(false, Ordering::SeqCst);
+}
+
+/// Show the canned output for `target_tab` as if it had been polished
+pub(super) async fn polish(ui: &SharedUi, target_tab: TabType) {
+    tokio::time::sleep(POLISH_DELAY).await;
+    let content = match target_tab {
+        TabType::BasicPolish => POLISHED.to_string(),
+        TabType::MeetingNotes => MEETING_NOTES.to_string(),
+        TabType::FollowUpEmail => FOLLOW_UP_EMAIL.to_string(),
+        TabType::Custom(_) => format!(
+            "{} is not part of the demo.\n\nSet up a provider in Settings to try it on your own recordings.",
+            output_modes::title(target_tab)
+        ),
+        TabType::Live => return,
+    };
+    info!(?target_tab, "Showing demo output");
+    set_polished_content(ui.as_ref(), &content, target_tab);
+    focus_tab(ui.as_ref(), target_tab);
+    show_save_button(ui.as_ref(), content, None);
+}
+
+fn is_capturing(recording_state: &Arc<Mutex<Option<RecordingSession>>>) -> bool {
+    recording_state
+        .lock()
+        .ok()
+        .and_then(|state| state.as_ref().map(|s| s.audio_handle.is_capturing()))
+        .unwrap_or(false)
+}
@@ -1,61 +1,110 @@
 //! Transcript event handling
 //!
 //! Handles events from the transcription service and updates the UI accordingly.
+//! Partial transcripts are throttled, see [`PartialThrottle`].
 
-use crate::transcription::{TranscriptEvent, TranscriptionSession};
-use crate::transcription_window;
-use std::sync::{Arc, Mutex};
+use super::partial_throttle::PartialThrottle;
+use crate::captions;
+use crate::event_bus::{self, AppEvent};
+use crate::recent_logs::TRANSCRIPT_TARGET;
+use crate::transcription::{self, SessionHandle, TranscriptEvent};
+use crate::ui_sink::{SharedUi, UiSink};
+use tokio::sync::broadcast::Receiver;
+use tokio::time::{sleep_until, Instant};
 use tracing::{error, info, warn};
 
 /// Event handler loop that processes transcription events
-#[tracing::instrument(skip(event_rx, session_data))]
+#[tracing::instrument(skip(event_rx, session_data, ui))]
 async fn run_event_handler(
-    mut event_rx: tokio::sync::broadcast::Receiver<TranscriptEvent>,
-    session_data: Arc<Mutex<TranscriptionSession>>,
+    mut event_rx: Receiver<TranscriptEvent>,
+    session_data: SessionHandle,
+    ui: SharedUi,
     log_events: bool,
 ) {
-    while let Ok(event) = event_rx.recv().await {
-        handle_transcript_event(&event, &session_data, log_events);
+    let mut throttle = PartialThrottle::default();
+    loop {
+        let event = match throttle.deadline() {
+            Some(deadline) => tokio::select! {
+                event = event_rx.recv() => event,
+                _ = sleep_until(deadline) => {
+                    if let Some(text) = throttle.take_held(Instant::now()) {
+                        show_partial(&text, &session_data, ui.as_ref());
+                    }
+                    continue;
+                }
+            },
+            None => event_rx.recv().await,
+        };
+        let Ok(event) = event else {
+            break;
+        };
+
+        match &event {
+            TranscriptEvent::PartialTranscript { text } => {
+                if log_events {
+                    info!(target: TRANSCRIPT_TARGET, "Partial: {}", text);
+                }
+                if throttle.offer(text, Instant::now()) {
+                    show_partial(text, &session_data, ui.as_ref());
+                }
+                continue;
+            }
+            // These redraw the live text from the session, which already
+            // includes the held partial or has replaced it
+            TranscriptEvent::CommittedTranscript { .. }
+            | TranscriptEvent::ConnectionLost
+            | TranscriptEvent::Reconnected
+            | TranscriptEvent::SegmentInserted => throttle.discard(),
+            _ => {}
+        }
+        handle_transcript_event(&event, &session_data, ui.as_ref(), log_events);
     }
 }
 
 /// Spawn the event handler task for processing transcription events
 pub(super) fn spawn_event_handler(
-    event_rx: tokio::sync::broadcast::Receiver<TranscriptEvent>,
-    session_data: Arc<Mutex<TranscriptionSession>>,
+    event_rx: Receiver<TranscriptEvent>,
+    session_data: SessionHandle,
+    ui: SharedUi,
     log_events: bool,
 ) {
-    tokio::spawn(run_event_handler(event_rx, session_data, log_events));
+    tokio::spawn(run_event_handler(event_rx, session_data, ui, log_events));
 }
 
 /// Handle a single transcript event
 fn handle_transcript_event(
     event: &TranscriptEvent,
-    session_data: &Arc<Mutex<TranscriptionSession>>,
+    session_data: &SessionHandle,
+    ui: &dyn UiSink,
     log_events: bool,
 ) {
     match event {
         TranscriptEvent::PartialTranscript { ref text } => {
             if log_events {
-                info!("Partial: {}", text);
+                info!(target: TRANSCRIPT_TARGET, "Partial: {}", text);
             }
-            let committed = get_committed_transcript(session_data);
-            // Update the live tab with the transcript
-            transcription_window::TranscriptionWindow::update_live_text(&committed, Some(text));
+            show_partial(text, session_data, ui);
         }
         TranscriptEvent::CommittedTranscript { ref text } => {
             if log_events {
-                info!("Committed: {}", text);
+                info!(target: TRANSCRIPT_TARGET, "Committed: {}", text);
             }
             let committed = get_committed_transcript(session_data);
             // Update the live tab with the committed transcript
-            transcription_window::TranscriptionWindow::update_live_text(&committed, None);
+            ui.update_live_text(&committed, None);
+            transcription::transcript_shown();
+            captions::publish(&committed, None);
+            // Empty when the segment repeated text already committed
+            if !text.is_empty() {
+                event_bus::publish(AppEvent::SegmentCommitted { text: text.clone() });
+            }
         }
         TranscriptEvent::Error { ref message } => {
             error!("Transcription error: {}", message);
+            event_bus::publish(AppEvent::TranscriptionError);
         }
         TranscriptEvent::ConnectionLost => {
-            handle_connection_lost(session_data, log_events);
+            handle_connection_lost(session_data, ui, log_events);
         }
         TranscriptEvent::Reconnecting { attempt } => {
             if log_events {
@@ -66,35 +115,107 @@ fn handle_transcript_event(
             if log_events {
                 info!("Reconnected to STT service");
             }
+            event_bus::publish(AppEvent::Reconnected);
             let committed = get_committed_transcript(session_data);
-            transcription_window::TranscriptionWindow::update_live_text(&committed, None);
+            ui.update_live_text(&committed, None);
         }
         TranscriptEvent::ReconnectFailed => {
             error!("Failed to reconnect to STT service after multiple attempts");
+            event_bus::publish(AppEvent::ReconnectFailed);
+        }
+        TranscriptEvent::ProviderFailed => {
+            warn!("STT provider gave up");
+        }
+        TranscriptEvent::ProviderActive { ref provider } => {
+            info!("Transcribing with {}", provider);
+            ui.show_active_provider(provider);
+        }
+        TranscriptEvent::SessionConfigured { ref info } => {
+            if log_events {
+                info!("Session configured: {:?}", info);
+            }
+            ui.show_session_info(info);
+        }
+        TranscriptEvent::SegmentInserted => {
+            // Show the screenshot, marker or note right away rather than on
+            // the next transcript event
+            let (committed, partial) = session_data.live_text();
+            ui.update_live_text(&committed, partial.as_deref());
         }
     }
 }
 
+/// Show the committed transcript followed by the partial `text`
+fn show_partial(text: &str, session_data: &SessionHandle, ui: &dyn UiSink) {
+    let committed = get_committed_transcript(session_data);
+    // Update the live tab with the transcript
+    ui.update_live_text(&committed, Some(text));
+    transcription::transcript_shown();
+    captions::publish(&committed, Some(text));
+}
+
 /// Handle connection lost event
-fn handle_connection_lost(session_data: &Arc<Mutex<TranscriptionSession>>, log_events: bool) {
+fn handle_connection_lost(session_data: &SessionHandle, ui: &dyn UiSink, log_events: bool) {
     if log_events {
         warn!("Connection to STT service lost");
     }
     // Only update UI if recording wasn't manually stopped
-    if let Ok(session) = session_data.lock() {
-        if !session.manually_stopped {
-            let committed = session.full_transcript();
-            drop(session);
-            transcription_window::TranscriptionWindow::update_live_text(&committed, None);
-        }
+    if !session_data.manually_stopped() {
+        ui.update_live_text(&session_data.full_transcript(), None);
     }
 }
 
 /// Get committed transcript from session
-pub(super) fn get_committed_transcript(session_data: &Arc<Mutex<TranscriptionSession>>) -> String {
-    if let Ok(session) = session_data.lock() {
-        session.full_transcript()
-    } else {
-        String::new()
+pub(super) fn get_committed_transcript(session_data: &SessionHandle) -> String {
+    session_data.full_transcript()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::{SessionCommand, SessionInfo};
+    use crate::transcription_window::TabType;
+    use std::sync::Mutex;
+
+    /// Records live text updates
+    #[derive(Default)]
+    struct LiveTextUi {
+        updates: Mutex<Vec<String>>,
+    }
+
+    impl UiSink for LiveTextUi {
+        fn recording_started(&self) {}
+        fn update_live_text(&self, committed: &str, _partial: Option<&str>) {
+            self.updates.lock().unwrap().push(committed.to_string());
+        }
+        fn set_recording(&self, _recording: bool) {}
+        fn set_processing(&self, _processing: bool) {}
+        fn set_processing_message(&self, _message: &str) {}
+        fn show_data_usage(&self, _summary: &str) {}
+        fn show_active_provider(&self, _provider: &str) {}
+        fn show_input_warning(&self, _warning: Option<&str>) {}
+        fn show_session_info(&self, _info: &SessionInfo) {}
+        fn show_error(&self, _message: &str) {}
+        fn set_tab_content(&self, _tab: TabType, _content: &str) {}
+        fn switch_to_tab(&self, _tab: TabType) {}
+        fn show_save_button(&self, _transcript: String) {}
+    }
+
+    #[tokio::test]
+    async fn test_connection_lost_refreshes_live_text_unless_stopped() {
+        let (event_tx, mut events) = tokio::sync::broadcast::channel(16);
+        let session_data = SessionHandle::spawn(event_tx);
+        session_data.send(SessionCommand::Commit("Hello there.".to_string()));
+        events.recv().await.unwrap();
+        let ui = LiveTextUi::default();
+
+        handle_connection_lost(&session_data, &ui, false);
+        assert_eq!(*ui.updates.lock().unwrap(), vec!["Hello there."]);
+
+        session_data.send(SessionCommand::MarkManuallyStopped);
+        session_data.notify(TranscriptEvent::ConnectionLost);
+        events.recv().await.unwrap();
+        handle_connection_lost(&session_data, &ui, false);
+        assert_eq!(ui.updates.lock().unwrap().len(), 1);
     }
 }
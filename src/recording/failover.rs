@@ -0,0 +1,152 @@
+//! Continuing a recording on the other provider
+//!
+//! With failover enabled in Settings, a recording whose provider can't
+//! connect or gives up (quota exceeded, reconnecting failed) continues on the
+//! other provider, provided its credentials are saved. Audio reaches the
+//! provider through an [`AudioRelay`], so the audio not yet transcribed can
+//! be sent again at the other provider's sample rate. Failover happens once
+//! per recording.
+
+use tokio::sync::{broadcast, mpsc};
+use tracing::{info, warn};
+
+use super::transcription_task::{start_provider, TranscriptionProviderConfig};
+use crate::audio::{AudioChunk, AZURE_SAMPLE_RATE, OPENAI_SAMPLE_RATE};
+use crate::keychain;
+use crate::preferences::{self, AiProvider};
+use crate::transcription::{
+    AudioEncoding, AudioRelay, TranscriptEvent, TranscriptionClient, TranscriptionError,
+};
+
+/// Where a recording continues if its provider fails
+pub(super) struct Failover {
+    primary: AiProvider,
+    secondary: AiProvider,
+    config: TranscriptionProviderConfig,
+    /// Capture rate the secondary provider expects
+    sample_rate: u32,
+    relay: AudioRelay,
+}
+
+/// Route `audio_rx` through a relay when failover is enabled and possible
+///
+/// Returns the audio for the primary provider, and the failover if one is
+/// set up. `client` must not have started yet, so the relay sees every
+/// committed segment.
+pub(super) fn prepare(
+    primary: AiProvider,
+    primary_config: &TranscriptionProviderConfig,
+    encoding: AudioEncoding,
+    sample_rate: u32,
+    client: &TranscriptionClient,
+    audio_rx: mpsc::Receiver<AudioChunk>,
+) -> (mpsc::Receiver<AudioChunk>, Option<Failover>) {
+    if !preferences::get_provider_failover() {
+        return (audio_rx, None);
+    }
+
+    // A replayed fixture has nothing to fail over to
+    if let TranscriptionProviderConfig::Mock { .. } = primary_config {
+        return (audio_rx, None);
+    }
+
+    let Some((secondary, config, secondary_rate)) = secondary_config(primary) else {
+        info!("Provider failover unavailable: no credentials for the other provider");
+        return (audio_rx, None);
+    };
+
+    let (relay, provider_rx) = AudioRelay::start(audio_rx, sample_rate, client.subscribe());
+    let failover = Failover {
+        primary,
+        secondary,
+        config,
+        sample_rate: encoding.sample_rate(secondary_rate),
+        relay,
+    };
+    (provider_rx, Some(failover))
+}
+
+impl Failover {
+    /// Transcribe with the primary provider, continuing on the secondary if it fails
+    pub(super) async fn run(
+        self,
+        client: &TranscriptionClient,
+        primary_config: &TranscriptionProviderConfig,
+        audio_rx: mpsc::Receiver<AudioChunk>,
+    ) -> Result<(), TranscriptionError> {
+        let session = client.session_handle();
+        let mut events = client.subscribe();
+        session.notify(TranscriptEvent::ProviderActive {
+            provider: self.primary.to_string(),
+        });
+
+        let primary_run = start_provider(client, primary_config, audio_rx);
+        tokio::pin!(primary_run);
+
+        let audio_rx = tokio::select! {
+            result = &mut primary_run => match result {
+                Err(e) if !session.manually_stopped() => {
+                    warn!("{} could not connect: {}", self.primary, e);
+                    self.relay.switch(self.sample_rate)
+                }
+                result => return result,
+            },
+            () = provider_failed(&mut events) => {
+                let audio_rx = self.relay.switch(self.sample_rate);
+                // The primary finishes once its audio ends
+                if let Err(e) = primary_run.await {
+                    warn!("{} ended with an error: {}", self.primary, e);
+                }
+                audio_rx
+            }
+        };
+
+        info!("Failing over from {} to {}", self.primary, self.secondary);
+        let secondary = client.continuing();
+        session.notify(TranscriptEvent::ProviderActive {
+            provider: self.secondary.to_string(),
+        });
+        start_provider(&secondary, &self.config, audio_rx).await
+    }
+}
+
+/// Wait until the provider reports that it gave up
+async fn provider_failed(events: &mut broadcast::Receiver<TranscriptEvent>) {
+    loop {
+        match events.recv().await {
+            Ok(TranscriptEvent::ProviderFailed) => return,
+            Err(broadcast::error::RecvError::Closed) => std::future::pending::<()>().await,
+            _ => {}
+        }
+    }
+}
+
+/// The other provider, its config and capture rate, if its credentials are saved
+fn secondary_config(primary: AiProvider) -> Option<(AiProvider, TranscriptionProviderConfig, u32)> {
+    match primary {
+        AiProvider::Azure => {
+            let creds = keychain::get_openai_credentials().ok()?;
+            Some((
+                AiProvider::OpenAI,
+                TranscriptionProviderConfig::OpenAI {
+                    api_key: creds.api_key,
+                    model: preferences::get_openai_transcribe_model(),
+                },
+                OPENAI_SAMPLE_RATE,
+            ))
+        }
+        AiProvider::OpenAI => {
+            let creds = keychain::get_azure_credentials().ok()?;
+            Some((
+                AiProvider::Azure,
+                TranscriptionProviderConfig::Azure {
+                    api_version: creds.realtime_api_version().to_string(),
+                    endpoint: creds.endpoint_url,
+                    deployment: creds.stt_deployment,
+                    api_key: creds.api_key,
+                },
+                AZURE_SAMPLE_RATE,
+            ))
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Muted microphone warning while recording
+//!
+//! Checks the input device when recording starts and keeps watching it, so a
+//! muted microphone or one at zero volume is flagged in the overlay right
+//! away instead of the session silently producing an empty transcript.
+
+use std::sync::Mutex;
+use tracing::warn;
+
+use crate::audio::{self, InputSilence};
+use crate::localization::tr;
+use crate::ui_sink::SharedUi;
+
+/// Where the warning of the running recording is shown
+static UI: Mutex<Option<SharedUi>> = Mutex::new(None);
+
+/// Last state shown, so repeated volume changes don't redraw the overlay
+static SHOWN: Mutex<Option<InputSilence>> = Mutex::new(None);
+
+/// Warn now if the input is silent, and whenever that changes while recording
+pub(super) fn start(ui: SharedUi) {
+    if let Ok(mut current) = UI.lock() {
+        *current = Some(ui);
+    }
+    if let Ok(mut shown) = SHOWN.lock() {
+        *shown = None;
+    }
+    show(audio::input_silence(), true);
+    audio::watch_input_silence(|silence| show(silence, false));
+}
+
+/// Stop watching the input and clear the warning
+pub(super) fn stop() {
+    audio::stop_watching_input_silence();
+    let ui = UI.lock().ok().and_then(|mut current| current.take());
+    if let Some(ui) = ui {
+        ui.show_input_warning(None);
+    }
+}
+
+/// Show or clear the warning when the state differs from the one shown
+fn show(silence: Option<InputSilence>, initial: bool) {
+    let Ok(mut shown) = SHOWN.lock() else {
+        return;
+    };
+    if !initial && *shown == silence {
+        return;
+    }
+    *shown = silence;
+    drop(shown);
+
+    let ui = UI.lock().ok().and_then(|current| current.clone());
+    let Some(ui) = ui else {
+        return;
+    };
+    match silence {
+        Some(silence) => {
+            warn!("Recording from a silent input device: {:?}", silence);
+            ui.show_input_warning(Some(message(silence)));
+        }
+        None => ui.show_input_warning(None),
+    }
+}
+
+fn message(silence: InputSilence) -> &'static str {
+    match silence {
+        InputSilence::Muted => tr("overlay.input_muted"),
+        InputSilence::VolumeZero => tr("overlay.input_volume_zero"),
+    }
+}
@@ -0,0 +1,203 @@
+//! Polish job manager
+//!
+//! Tracks polish and meeting-notes generation per tab. Each tab runs at most
+//! one job at a time; different tabs run in parallel. A request for content
+//! that is already being generated (or already queued) is dropped, and a
+//! request with new content for a busy tab is queued and started when the
+//! running job finishes, replacing any older queued request.
+
+use once_cell::sync::Lazy;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use tracing::{error, info};
+
+use crate::response::PolishConfig;
+use crate::transcription_window::TabType;
+
+/// Global job table shared by the stop and on-demand polish paths
+static JOBS: Lazy<Mutex<JobTable>> = Lazy::new(|| Mutex::new(JobTable::default()));
+
+/// Outcome of submitting a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SubmitOutcome {
+    /// No job was running for the tab; the caller should run it now
+    Started,
+    /// The same content is already running or queued for the tab
+    Duplicate,
+    /// Another job is running for the tab; this one runs after it
+    Queued,
+}
+
+/// A job waiting for the tab's running job to finish
+pub(super) struct PendingJob {
+    pub(super) transcript: String,
+    pub(super) config: PolishConfig,
+    key: u64,
+}
+
+/// Per-tab job bookkeeping
+#[derive(Default)]
+struct JobTable {
+    /// Content key of the job currently running for each tab
+    running: HashMap<TabType, u64>,
+    /// Next job to run for each tab
+    pending: HashMap<TabType, PendingJob>,
+    /// Tab the user most recently asked for; only it is brought to front on completion
+    focus: Option<TabType>,
+}
+
+impl JobTable {
+    fn submit(&mut self, tab: TabType, transcript: String, config: PolishConfig) -> SubmitOutcome {
+        let key = job_key(&transcript, &config);
+        self.focus = Some(tab);
+
+        if self.running.get(&tab) == Some(&key)
+            || self.pending.get(&tab).is_some_and(|job| job.key == key)
+        {
+            return SubmitOutcome::Duplicate;
+        }
+
+        if self.running.contains_key(&tab) {
+            self.pending.insert(
+                tab,
+                PendingJob {
+                    transcript,
+                    config,
+                    key,
+                },
+            );
+            return SubmitOutcome::Queued;
+        }
+
+        self.running.insert(tab, key);
+        SubmitOutcome::Started
+    }
+
+    fn finish(&mut self, tab: TabType) -> Option<PendingJob> {
+        match self.pending.remove(&tab) {
+            Some(next) => {
+                self.running.insert(tab, next.key);
+                Some(next)
+            }
+            None => {
+                self.running.remove(&tab);
+                None
+            }
+        }
+    }
+}
+
+/// Identify a job by its content and polish settings
+fn job_key(transcript: &str, config: &PolishConfig) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    transcript.hash(&mut hasher);
+    config.prompt_type.hash(&mut hasher);
+    config.reasoning_effort.hash(&mut hasher);
+    config.language_code.hash(&mut hasher);
+    config.custom_mode.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Submit a generation job for a tab.
+pub(super) fn submit(tab: TabType, transcript: String, config: PolishConfig) -> SubmitOutcome {
+    let Ok(mut jobs) = JOBS.lock() else {
+        error!("Failed to acquire polish job lock");
+        return SubmitOutcome::Started;
+    };
+    let outcome = jobs.submit(tab, transcript, config);
+    info!(?tab, ?outcome, "Polish job submitted");
+    outcome
+}
+
+/// Mark the tab's running job as finished and take its queued successor, if any.
+pub(super) fn finish(tab: TabType) -> Option<PendingJob> {
+    let Ok(mut jobs) = JOBS.lock() else {
+        error!("Failed to acquire polish job lock");
+        return None;
+    };
+    jobs.finish(tab)
+}
+
+/// Whether no polish job is running for any tab.
+pub(super) fn is_idle() -> bool {
+    JOBS.lock()
+        .map(|jobs| jobs.running.is_empty())
+        .unwrap_or(true)
+}
+
+/// Whether a finished job for `tab` should bring its tab to the front.
+pub(super) fn is_focused(tab: TabType) -> bool {
+    JOBS.lock()
+        .map(|jobs| jobs.focus.is_none_or(|focus| focus == tab))
+        .unwrap_or(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(prompt_type: Option<&str>) -> PolishConfig {
+        PolishConfig {
+            reasoning_effort: None,
+            prompt_type: prompt_type.map(str::to_string),
+            language_code: "en".to_string(),
+            custom_mode: None,
+        }
+    }
+
+    #[test]
+    fn test_duplicate_request_for_running_job_is_dropped() {
+        let mut table = JobTable::default();
+        let outcome = table.submit(TabType::BasicPolish, "hello".into(), config(None));
+        assert_eq!(outcome, SubmitOutcome::Started);
+        let outcome = table.submit(TabType::BasicPolish, "hello".into(), config(None));
+        assert_eq!(outcome, SubmitOutcome::Duplicate);
+        assert!(table.finish(TabType::BasicPolish).is_none());
+        assert!(table.running.is_empty());
+    }
+
+    #[test]
+    fn test_different_tabs_run_in_parallel() {
+        let mut table = JobTable::default();
+        let basic = table.submit(TabType::BasicPolish, "hello".into(), config(None));
+        let notes = table.submit(
+            TabType::MeetingNotes,
+            "hello".into(),
+            config(Some("live_meeting")),
+        );
+        assert_eq!(basic, SubmitOutcome::Started);
+        assert_eq!(notes, SubmitOutcome::Started);
+        assert_eq!(table.running.len(), 2);
+    }
+
+    #[test]
+    fn test_new_content_is_queued_and_latest_wins() {
+        let mut table = JobTable::default();
+        table.submit(TabType::BasicPolish, "one".into(), config(None));
+        let second = table.submit(TabType::BasicPolish, "two".into(), config(None));
+        let third = table.submit(TabType::BasicPolish, "three".into(), config(None));
+        assert_eq!(second, SubmitOutcome::Queued);
+        assert_eq!(third, SubmitOutcome::Queued);
+
+        let next = table.finish(TabType::BasicPolish).expect("queued job");
+        assert_eq!(next.transcript, "three");
+        assert!(table.running.contains_key(&TabType::BasicPolish));
+
+        assert!(table.finish(TabType::BasicPolish).is_none());
+        assert!(table.running.is_empty());
+    }
+
+    #[test]
+    fn test_focus_follows_latest_request() {
+        let mut table = JobTable::default();
+        table.submit(TabType::BasicPolish, "hello".into(), config(None));
+        table.submit(
+            TabType::MeetingNotes,
+            "hello".into(),
+            config(Some("live_meeting")),
+        );
+        assert_eq!(table.focus, Some(TabType::MeetingNotes));
+    }
+}
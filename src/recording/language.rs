@@ -0,0 +1,40 @@
+//! Language switches during a recording
+//!
+//! Choosing another language in the menu bar, or with the language hotkey,
+//! while a recording runs switches the running session over to it, for
+//! meetings that move between languages. Spoken numbers are then rewritten
+//! as turned on for the new language.
+
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::info;
+
+use crate::event_bus::{self, AppEvent, Setting};
+use crate::preferences;
+use crate::transcription::{LanguageSwitch, Normalizer, SessionCommand, SessionHandle};
+
+/// Follow the language setting until the recording stops
+///
+/// Subscribes right away, so a switch made as soon as the recording has
+/// started isn't missed.
+pub(super) fn spawn_language_updates(language: Arc<LanguageSwitch>, session: SessionHandle) {
+    let mut events = event_bus::subscribe();
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(AppEvent::SettingChanged {
+                    setting: Setting::Language,
+                }) => {
+                    let code = preferences::get_language_code();
+                    if language.switch(&code) {
+                        info!("Recording switched to language {}", code);
+                        let options = preferences::get_normalize_options(&code);
+                        session.send(SessionCommand::Normalize(Normalizer::new(&code, options)));
+                    }
+                }
+                Ok(AppEvent::RecordingStopped) | Err(RecvError::Closed) => break,
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+            }
+        }
+    });
+}
@@ -0,0 +1,156 @@
+//! Session duration and transcript size guardrails
+//!
+//! A recording left running by accident keeps streaming audio to a paid
+//! speech service all day. While recording, a watchdog posts a notification
+//! after the configured duration, stops the recording without polishing at
+//! the hard limit, and warns once the transcript grows past a word count.
+//! A recording that has transcribed nothing for 15 minutes while the Mac sits
+//! idle gets a reminder asking whether to keep recording. Each limit is set,
+//! or turned off, in the Session tab of Settings.
+
+mod watch;
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{interval, Instant};
+use tracing::info;
+
+use super::RecordingSession;
+use crate::feedback;
+use crate::power;
+use crate::preferences;
+use crate::transcription::SessionHandle;
+use crate::ui_sink::SharedUi;
+use watch::{Guardrail, Watch};
+
+/// How often the watchdog checks the running session
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Choices for the duration warning, in minutes (`None` = off)
+pub(crate) const WARN_AFTER_PRESETS: [Option<u32>; 4] = [None, Some(60), Some(120), Some(240)];
+
+/// Choices for the hard limit, in minutes (`None` = off)
+pub(crate) const STOP_AFTER_PRESETS: [Option<u32>; 4] = [None, Some(180), Some(360), Some(480)];
+
+/// Choices for the transcript size warning, in words (`None` = off)
+pub(crate) const WARN_WORDS_PRESETS: [Option<u32>; 4] =
+    [None, Some(10_000), Some(25_000), Some(50_000)];
+
+/// Persisted session limits
+///
+/// Defaults warn after 2 hours, stop after 8 hours and warn at 25,000 words,
+/// roughly three hours of steady speech, with the idle reminder on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct SessionLimits {
+    /// Minutes of recording before the duration warning
+    pub(crate) warn_after_mins: Option<u32>,
+    /// Minutes of recording before the recording is stopped
+    pub(crate) stop_after_mins: Option<u32>,
+    /// Transcript words before the size warning
+    pub(crate) warn_words: Option<u32>,
+    /// Whether to remind about a recording that has gone quiet
+    pub(crate) idle_reminder: bool,
+}
+
+impl Default for SessionLimits {
+    fn default() -> Self {
+        Self {
+            warn_after_mins: Some(120),
+            stop_after_mins: Some(480),
+            warn_words: Some(25_000),
+            idle_reminder: true,
+        }
+    }
+}
+
+/// Watch the recording until its audio capture stops
+///
+/// Limits are read on every check, so changes in Settings apply to the
+/// running session.
+pub(super) fn spawn_watchdog(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    capturing: Arc<AtomicBool>,
+    session_data: SessionHandle,
+    ui: SharedUi,
+) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let mut watch = Watch::default();
+        let mut ticker = interval(CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if !capturing.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let words = session_data.full_transcript().split_whitespace().count();
+            let guardrails = watch.check(
+                &preferences::get_session_limits(),
+                started.elapsed(),
+                words,
+                power::user_idle_time(),
+            );
+            for guardrail in guardrails {
+                info!(?guardrail, words, "Session limit reached");
+                let (title, body) = guardrail.notification_text();
+                match guardrail {
+                    Guardrail::IdleReminder => feedback::remind_recording(title, &body),
+                    _ => feedback::notify_now(title, &body),
+                }
+                if let Guardrail::Stop(_) = guardrail {
+                    super::stop_recording_no_polish(recording_state, ui);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Label for a duration in minutes, e.g. "2 hours"
+fn format_minutes(mins: u32) -> String {
+    match mins {
+        60 => "1 hour".to_string(),
+        m if m % 60 == 0 => format!("{} hours", m / 60),
+        m => format!("{} minutes", m),
+    }
+}
+
+/// Word count with thousands separators, e.g. "25,000"
+pub(crate) fn format_words(words: u32) -> String {
+    let digits = words.to_string();
+    let mut formatted = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_fill_missing_fields() {
+        let limits: SessionLimits = serde_json::from_str(r#"{"warn_words":null}"#).unwrap();
+        assert_eq!(limits.warn_after_mins, Some(120));
+        assert_eq!(limits.stop_after_mins, Some(480));
+        assert_eq!(limits.warn_words, None);
+        assert!(limits.idle_reminder);
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(format_minutes(60), "1 hour");
+        assert_eq!(format_minutes(480), "8 hours");
+        assert_eq!(format_minutes(90), "90 minutes");
+        assert_eq!(format_words(25_000), "25,000");
+        assert_eq!(format_words(999), "999");
+        assert_eq!(format_words(1_000_000), "1,000,000");
+    }
+}
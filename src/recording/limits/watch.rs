@@ -0,0 +1,175 @@
+//! Which limits a running session has reached
+
+use std::time::Duration;
+
+use super::{format_minutes, format_words, SessionLimits};
+
+/// How long without new transcript text and without input before the reminder
+const IDLE_AFTER: Duration = Duration::from_secs(15 * 60);
+
+/// What the watchdog does when a limit is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Guardrail {
+    WarnDuration(u32),
+    Stop(u32),
+    WarnSize(u32),
+    IdleReminder,
+}
+
+impl Guardrail {
+    /// Notification title and body
+    pub(super) fn notification_text(self) -> (&'static str, String) {
+        match self {
+            Guardrail::WarnDuration(mins) => (
+                "Still recording",
+                format!(
+                    "Vissper has been recording for {}. \
+                     Stop the recording if the meeting is over.",
+                    format_minutes(mins)
+                ),
+            ),
+            Guardrail::Stop(mins) => (
+                "Recording stopped",
+                format!(
+                    "The recording reached the {} limit and was stopped without polishing. \
+                     The transcript is kept in the transcription window.",
+                    format_minutes(mins)
+                ),
+            ),
+            Guardrail::WarnSize(words) => (
+                "Long transcript",
+                format!(
+                    "The transcript has passed {} words. \
+                     Polishing it will take longer and cost more.",
+                    format_words(words)
+                ),
+            ),
+            Guardrail::IdleReminder => (
+                "Still recording?",
+                "Nothing has been transcribed for 15 minutes. Stop the recording if \
+                 it's no longer needed, so silence isn't streamed to the speech service."
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+/// Limits already acted on in the current session
+#[derive(Debug, Default)]
+pub(super) struct Watch {
+    warned_duration: bool,
+    warned_size: bool,
+    /// Word count at the last check
+    words: usize,
+    /// Session time when the word count last changed
+    changed_at: Duration,
+    /// Whether the quiet stretch since `changed_at` was reminded about
+    reminded_idle: bool,
+}
+
+impl Watch {
+    /// Guardrails reached at `elapsed` with `words` transcribed and no input
+    /// for `user_idle`, each once (the idle reminder once per quiet stretch)
+    pub(super) fn check(
+        &mut self,
+        limits: &SessionLimits,
+        elapsed: Duration,
+        words: usize,
+        user_idle: Duration,
+    ) -> Vec<Guardrail> {
+        let minutes = elapsed.as_secs() / 60;
+        let reached = |limit: Option<u32>| limit.filter(|&limit| minutes >= u64::from(limit));
+
+        let mut guardrails = Vec::new();
+        if let Some(limit) = reached(limits.stop_after_mins) {
+            guardrails.push(Guardrail::Stop(limit));
+        } else if let Some(limit) = reached(limits.warn_after_mins) {
+            if !std::mem::replace(&mut self.warned_duration, true) {
+                guardrails.push(Guardrail::WarnDuration(limit));
+            }
+        }
+        if let Some(limit) = limits.warn_words {
+            if words >= limit as usize && !std::mem::replace(&mut self.warned_size, true) {
+                guardrails.push(Guardrail::WarnSize(limit));
+            }
+        }
+
+        if words != self.words {
+            self.words = words;
+            self.changed_at = elapsed;
+            self.reminded_idle = false;
+        }
+        let quiet = elapsed.saturating_sub(self.changed_at);
+        if limits.idle_reminder
+            && quiet >= IDLE_AFTER
+            && user_idle >= IDLE_AFTER
+            && !std::mem::replace(&mut self.reminded_idle, true)
+        {
+            guardrails.push(Guardrail::IdleReminder);
+        }
+        guardrails
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+    const MINUTE: Duration = Duration::from_secs(60);
+    const ACTIVE: Duration = Duration::ZERO;
+
+    #[test]
+    fn test_warnings_fire_once() {
+        let limits = SessionLimits::default();
+        let mut watch = Watch::default();
+        assert!(watch.check(&limits, HOUR, 9_000, ACTIVE).is_empty());
+        assert_eq!(
+            watch.check(&limits, HOUR * 2, 26_000, ACTIVE),
+            vec![Guardrail::WarnDuration(120), Guardrail::WarnSize(25_000)]
+        );
+        assert!(watch.check(&limits, HOUR * 3, 40_000, ACTIVE).is_empty());
+        assert_eq!(
+            watch.check(&limits, HOUR * 8, 60_000, ACTIVE),
+            vec![Guardrail::Stop(480)]
+        );
+    }
+
+    #[test]
+    fn test_idle_reminder_needs_quiet_transcript_and_idle_mac() {
+        let limits = SessionLimits::default();
+        let mut watch = Watch::default();
+        assert!(watch.check(&limits, MINUTE, 100, ACTIVE).is_empty());
+        // Quiet transcript, but someone is at the Mac
+        assert!(watch.check(&limits, MINUTE * 20, 100, ACTIVE).is_empty());
+        assert_eq!(
+            watch.check(&limits, MINUTE * 20, 100, MINUTE * 16),
+            vec![Guardrail::IdleReminder]
+        );
+        // Once per quiet stretch
+        assert!(watch
+            .check(&limits, MINUTE * 40, 100, MINUTE * 36)
+            .is_empty());
+        // New text starts a new stretch
+        assert!(watch
+            .check(&limits, MINUTE * 41, 120, MINUTE * 37)
+            .is_empty());
+        assert_eq!(
+            watch.check(&limits, MINUTE * 56, 120, MINUTE * 52),
+            vec![Guardrail::IdleReminder]
+        );
+    }
+
+    #[test]
+    fn test_turned_off_limits_never_fire() {
+        let limits = SessionLimits {
+            warn_after_mins: None,
+            stop_after_mins: None,
+            warn_words: None,
+            idle_reminder: false,
+        };
+        assert!(Watch::default()
+            .check(&limits, HOUR * 24, 500_000, HOUR * 24)
+            .is_empty());
+    }
+}
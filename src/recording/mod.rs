@@ -10,26 +10,62 @@
 //! - Real-time transcription
 //! - UI updates in the transcription window
 //! - Transcript polishing via the selected provider (on stop)
+//! - Duration and transcript size limits (see `limits`)
+//! - A warning when the microphone is muted or at zero volume (see `input_check`)
+//! - A demo session replaying a sample meeting (see `demo`)
+//! - Switching the language mid-session (see `language`)
+//! - Ignoring starts and stops that race an earlier one (see `phase`)
 //!
-//! The session state is shared via `Arc<Mutex<Option<RecordingSession>>>`.
+//! The session state is shared via `Arc<Mutex<Option<RecordingSession>>>`; the
+//! transcript itself lives in a session actor reached through [`SessionHandle`].
 
 mod clipboard;
+mod data_usage;
+mod demo;
 mod events;
+mod failover;
+mod input_check;
+mod jobs;
+mod language;
+mod limits;
+mod partial_throttle;
+mod phase;
 mod polish;
 mod polish_helpers;
+mod preflight;
+mod prewarm;
 mod transcription_task;
 
-// Re-export polish_transcript_on_demand for use from main.rs
-pub(crate) use polish::polish_transcript_on_demand;
+// Re-export for use from main.rs
+pub(crate) use clipboard::{copy_to_clipboard, AutoCopy, AutoCopySettings};
+pub(crate) use demo::start_demo;
+pub(crate) use limits::{
+    format_words, SessionLimits, STOP_AFTER_PRESETS, WARN_AFTER_PRESETS, WARN_WORDS_PRESETS,
+};
+pub(crate) use phase::{current_phase, RecordingPhase};
+pub(crate) use polish::{polish_text, polish_text_with, polish_transcript_on_demand};
+pub(crate) use prewarm::{prepare_connection, prepare_connection_on_menu_open};
 
+use crate::attendees;
 use crate::audio::{self, AudioCaptureHandle, AZURE_SAMPLE_RATE, OPENAI_SAMPLE_RATE};
+use crate::event_bus::{self, AppEvent};
 use crate::keychain;
-use crate::menubar;
+use crate::language_sections;
+use crate::localization::tr;
+use crate::managed;
+use crate::output_modes;
 use crate::preferences::{self, AiProvider};
+use crate::private_mode;
+use crate::provenance;
 use crate::response::PolishConfig;
-use crate::transcription::{self, TranscriptionSession};
-use crate::transcription_window;
+use crate::shutdown;
+use crate::templates;
+use crate::topics;
+use crate::transcription::{self, ConnectionControl, SessionCommand, SessionHandle};
+use crate::transcription_window::TabType;
+use crate::ui_sink::{SharedUi, UiSink};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tracing::{error, info};
 
 use transcription_task::{
@@ -43,8 +79,12 @@ use transcription_task::{
 pub(crate) struct RecordingSession {
     /// Handle to control audio capture (stop, check status)
     pub(crate) audio_handle: AudioCaptureHandle,
-    /// Shared session data containing transcripts and timestamps
-    pub(crate) session_data: Arc<Mutex<TranscriptionSession>>,
+    /// Handle to the session actor holding the transcript
+    pub(crate) session_data: SessionHandle,
+    /// Pauses the connection while the Mac sleeps
+    pub(crate) connection: Arc<ConnectionControl>,
+    /// Where the session's progress is shown
+    pub(crate) ui: SharedUi,
 }
 
 /// Start a recording session
@@ -56,63 +96,85 @@ pub(crate) struct RecordingSession {
 /// 4. Spawns event handler for UI updates
 /// 5. Spawns transcription task
 /// 6. Updates recording state and UI
+///
+/// Does nothing unless idle, so repeated triggers start one recording.
 pub(crate) fn start_recording(
     recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
     log_events: bool,
 ) {
+    if shutdown::in_progress() {
+        info!("Recording not started: Vissper is quitting");
+        return;
+    }
+    if !phase::begin_start() {
+        info!("Recording not started: already {:?}", current_phase());
+        return;
+    }
+
     // Determine which provider to use
     let provider = preferences::get_ai_provider();
     info!("Starting recording with provider: {:?}", provider);
 
     // Get credentials and create provider config based on selected provider
-    let (provider_config, sample_rate) = match provider {
-        AiProvider::Azure => match keychain::get_azure_credentials() {
-            Ok(creds) => (
-                TranscriptionProviderConfig::Azure {
-                    endpoint: creds.endpoint_url,
-                    deployment: creds.stt_deployment,
-                    api_key: creds.api_key,
-                },
-                AZURE_SAMPLE_RATE,
-            ),
-            Err(e) => {
-                error!("Cannot start recording without Azure credentials: {}", e);
-                transcription_window::TranscriptionWindow::show();
-                transcription_window::TranscriptionWindow::update_live_text(
-                        "Azure credentials not configured.\n\nPlease go to Settings and enter your Azure OpenAI credentials.",
-                        None,
-                    );
-                return;
-            }
-        },
-        AiProvider::OpenAI => match keychain::get_openai_credentials() {
-            Ok(creds) => (
-                TranscriptionProviderConfig::OpenAI {
-                    api_key: creds.api_key,
-                },
-                OPENAI_SAMPLE_RATE,
-            ),
-            Err(e) => {
-                error!("Cannot start recording without OpenAI credentials: {}", e);
-                transcription_window::TranscriptionWindow::show();
-                transcription_window::TranscriptionWindow::update_live_text(
-                        "OpenAI credentials not configured.\n\nPlease go to Settings and enter your OpenAI API key.",
-                        None,
-                    );
-                return;
-            }
-        },
+    let Some((provider_config, sample_rate)) = provider_config(provider, ui.as_ref()) else {
+        phase::start_failed();
+        return;
     };
+    demo::recording_started();
+    templates::recording_started();
+    start_session(
+        recording_state,
+        ui,
+        log_events,
+        provider,
+        provider_config,
+        sample_rate,
+    );
+}
+
+/// Start capture and transcription with an already chosen provider config
+///
+/// Shared by real recordings and the demo session, which have claimed the
+/// start with [`phase::begin_start`].
+fn start_session(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+    log_events: bool,
+    provider: AiProvider,
+    provider_config: TranscriptionProviderConfig,
+    sample_rate: u32,
+) {
+    provenance::recording_started();
+    attendees::recording_started();
+
+    // Low-bandwidth mode sends 8 kHz μ-law audio instead of PCM
+    let encoding = transcription::AudioEncoding::from_preferences();
+    let sample_rate = encoding.sample_rate(sample_rate);
 
     // Start audio capture with provider-specific sample rate
-    let (audio_handle, audio_rx) = match audio::start_capture_with_sample_rate(sample_rate) {
+    let (audio_handle, audio_rx) = match start_capture(&provider_config, sample_rate) {
         Ok(result) => result,
         Err(e) => {
             error!("Failed to start audio capture: {}", e);
+            phase::start_failed();
             return;
         }
     };
 
+    // Measure capture-to-transcript latency for this provider and chunk duration
+    transcription::start_measuring(format!(
+        "{}, {} ms chunks{}",
+        provider,
+        audio::AudioTuning::from_preferences().chunk_ms,
+        if encoding == transcription::AudioEncoding::G711Ulaw {
+            ", low bandwidth"
+        } else {
+            ""
+        }
+    ));
+    transcription::start_counting();
+
     // Get language preference
     let language_code = preferences::get_language_code();
     info!(
@@ -120,14 +182,38 @@ pub(crate) fn start_recording(
         language_code, sample_rate
     );
 
+    // Spoken numbers, dates and amounts are rewritten as turned on for the language
+    let normalizer = transcription::Normalizer::new(
+        &language_code,
+        preferences::get_normalize_options(&language_code),
+    );
+
     // Create transcription client based on provider
     let transcription_client = match provider {
         AiProvider::Azure => transcription::TranscriptionClient::new_azure(language_code),
         AiProvider::OpenAI => transcription::TranscriptionClient::new_openai(language_code),
-    };
+    }
+    .with_encoding(encoding)
+    .with_noise_reduction(preferences::get_noise_reduction())
+    .with_stall_timeout(Duration::from_secs(
+        preferences::get_stall_timeout_secs().max(1).into(),
+    ))
+    .with_normalizer(normalizer);
 
-    // Get the session Arc for sharing
-    let session_data = transcription_client.session_arc();
+    // With failover on, audio goes through a relay that can switch providers
+    let (audio_rx, failover) = failover::prepare(
+        provider,
+        &provider_config,
+        encoding,
+        sample_rate,
+        &transcription_client,
+        audio_rx,
+    );
+
+    // Get the session handle for sharing
+    let session_data = transcription_client.session_handle();
+    let connection = transcription_client.connection_control();
+    language::spawn_language_updates(transcription_client.language_switch(), session_data.clone());
 
     // Subscribe to events for logging and UI updates
     let event_rx = transcription_client.subscribe();
@@ -137,98 +223,369 @@ pub(crate) fn start_recording(
     let session_data_for_events = session_data.clone();
 
     // Show transcription overlay window and set recording state immediately
-    transcription_window::TranscriptionWindow::show();
-    transcription_window::TranscriptionWindow::reset_tabs();
-    transcription_window::TranscriptionWindow::set_recording_state(true);
-    transcription_window::TranscriptionWindow::set_recording_type();
-    transcription_window::TranscriptionWindow::update_live_text("", Some("Listening..."));
-    transcription_window::TranscriptionWindow::hide_save_button();
+    ui.recording_started();
 
-    // Spawn event handler
-    events::spawn_event_handler(event_rx, session_data_for_events, log_events);
+    // Flag a muted microphone now rather than after an empty session
+    if !matches!(provider_config, TranscriptionProviderConfig::Mock { .. }) {
+        input_check::start(ui.clone());
+    }
 
-    // Spawn transcription task
-    spawn_transcription_task(TranscriptionTaskConfig {
-        transcription_client,
-        provider_config,
-        audio_rx,
-        recording_state: recording_state_store,
-    });
+    // Spawn event handler
+    events::spawn_event_handler(event_rx, session_data_for_events, ui.clone(), log_events);
 
     // Store the audio handle and session data
+    let capturing = audio_handle.is_capturing.clone();
     if let Ok(mut state) = recording_state.lock() {
         *state = Some(RecordingSession {
             audio_handle,
             session_data: session_data.clone(),
+            connection,
+            ui: ui.clone(),
         });
     }
 
-    menubar::MenuBar::set_recording(true);
+    // Started once the session is stored, so it runs until the session is cleared
+    data_usage::spawn_usage_updates(recording_state.clone(), ui.clone());
+    limits::spawn_watchdog(recording_state.clone(), capturing, session_data, ui.clone());
+
+    phase::started();
+    ui.set_recording(true);
+    event_bus::publish(AppEvent::RecordingStarted);
+
+    // Spawned last, so a session that fails at once is cleared after it was
+    // stored and marked as recording, not before
+    spawn_transcription_task(TranscriptionTaskConfig {
+        transcription_client,
+        provider_config,
+        audio_rx,
+        failover,
+        recording_state: recording_state_store,
+        ui: ui.clone(),
+    });
+
     info!("Recording started with {:?} provider", provider);
 }
 
+/// Build the transcription config for the selected provider
+///
+/// Shows a hint in the transcription window and returns `None` if the
+/// provider's credentials are missing.
+fn provider_config(
+    provider: AiProvider,
+    ui: &dyn UiSink,
+) -> Option<(TranscriptionProviderConfig, u32)> {
+    // Replay a fixture instead of connecting when one is configured
+    #[cfg(feature = "mock-provider")]
+    match transcription::MockFixture::from_env() {
+        Some(Ok(fixture)) => {
+            info!("Using mock transcription provider");
+            return Some((
+                TranscriptionProviderConfig::Mock { fixture },
+                AZURE_SAMPLE_RATE,
+            ));
+        }
+        Some(Err(e)) => {
+            error!("Cannot start mock recording: {}", e);
+            ui.show_error(&format!("Mock transcript could not be loaded.\n\n{}", e));
+            return None;
+        }
+        None => {}
+    }
+
+    match provider {
+        AiProvider::Azure => match keychain::get_azure_credentials() {
+            Ok(creds) => Some((
+                TranscriptionProviderConfig::Azure {
+                    api_version: creds.realtime_api_version().to_string(),
+                    endpoint: creds.endpoint_url,
+                    deployment: creds.stt_deployment,
+                    api_key: creds.api_key,
+                },
+                AZURE_SAMPLE_RATE,
+            )),
+            Err(e) => {
+                error!("Cannot start recording without Azure credentials: {}", e);
+                ui.show_error("Azure credentials not configured.\n\nPlease go to Settings and enter your Azure OpenAI credentials.\n\nTo see how Vissper works first, choose Try Demo Session from the menu bar.");
+                return None;
+            }
+        },
+        AiProvider::OpenAI => match keychain::get_openai_credentials() {
+            Ok(creds) => Some((
+                TranscriptionProviderConfig::OpenAI {
+                    api_key: creds.api_key,
+                    model: preferences::get_openai_transcribe_model(),
+                },
+                OPENAI_SAMPLE_RATE,
+            )),
+            Err(e) => {
+                error!("Cannot start recording without OpenAI credentials: {}", e);
+                ui.show_error("OpenAI credentials not configured.\n\nPlease go to Settings and enter your OpenAI API key.\n\nTo see how Vissper works first, choose Try Demo Session from the menu bar.");
+                return None;
+            }
+        },
+    }
+}
+
+/// Start microphone capture, or a silent capture when replaying a fixture
+fn start_capture(
+    provider_config: &TranscriptionProviderConfig,
+    sample_rate: u32,
+) -> Result<
+    (
+        AudioCaptureHandle,
+        tokio::sync::mpsc::Receiver<audio::AudioChunk>,
+    ),
+    audio::AudioCaptureError,
+> {
+    if let TranscriptionProviderConfig::Mock { .. } = provider_config {
+        return Ok(audio::start_silent_capture());
+    }
+    audio::start_capture_with_sample_rate(sample_rate, audio::AudioTuning::from_preferences())
+}
+
 /// Stop a recording session without polishing (raw transcript)
-pub(crate) fn stop_recording_no_polish(recording_state: Arc<Mutex<Option<RecordingSession>>>) {
+///
+/// Does nothing unless recording, so repeated triggers stop it once.
+pub(crate) fn stop_recording_no_polish(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+) {
+    if begin_stop() {
+        stop_without_polish(recording_state, ui);
+    }
+}
+
+/// Claim the stop of the recording, logging why not if another trigger did
+fn begin_stop() -> bool {
+    let claimed = phase::begin_stop();
+    if !claimed {
+        info!("Stop ignored: recording is {:?}", current_phase());
+    }
+    claimed
+}
+
+/// Stop a claimed recording without polishing
+fn stop_without_polish(recording_state: Arc<Mutex<Option<RecordingSession>>>, ui: SharedUi) {
     let transcript = get_full_transcript(&recording_state);
     stop_audio_capture(&recording_state);
 
     // Update UI - recording stopped
-    menubar::MenuBar::set_recording(false);
-    transcription_window::TranscriptionWindow::set_recording_state(false);
-    transcription_window::TranscriptionWindow::update_live_text(&transcript, None);
+    ui.set_recording(false);
+    ui.update_live_text(&transcript, None);
     info!("Recording stopped (no polishing)");
 
-    // Copy raw transcript to clipboard
-    clipboard::copy_to_clipboard(&transcript);
+    // Copy raw transcript to clipboard, unless turned off for this stop mode
+    clipboard::auto_copy(TabType::Live, &transcript, &transcript);
+
+    // Save without asking if the recording's template says so
+    templates::auto_save(TabType::Live, &transcript);
 
     // Show save button if transcript is not empty
     if !transcript.trim().is_empty() {
-        transcription_window::TranscriptionWindow::show_save_button(transcript);
+        ui.show_save_button(transcript);
     }
 }
 
 /// Stop a recording session and polish the transcript (Basic polishing mode)
-pub(crate) fn stop_recording(recording_state: Arc<Mutex<Option<RecordingSession>>>) {
-    stop_recording_with_config(recording_state, PolishConfig::basic_polish());
+pub(crate) fn stop_recording(recording_state: Arc<Mutex<Option<RecordingSession>>>, ui: SharedUi) {
+    stop_recording_with_config(recording_state, ui, PolishConfig::basic_polish());
 }
 
 /// Stop a recording session with Live Meeting polishing
-pub(crate) fn stop_live_meeting_recording(recording_state: Arc<Mutex<Option<RecordingSession>>>) {
-    stop_recording_with_config(recording_state, PolishConfig::live_meeting());
+pub(crate) fn stop_live_meeting_recording(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+) {
+    stop_recording_with_config(recording_state, ui, PolishConfig::live_meeting());
+}
+
+/// Stop a recording session and draft a follow-up email
+pub(crate) fn stop_follow_up_email_recording(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+) {
+    stop_recording_with_config(recording_state, ui, PolishConfig::follow_up_email());
+}
+
+/// Stop a recording session and produce an output mode's content
+///
+/// The Live tab stops without polishing.
+pub(crate) fn stop_recording_with_mode(
+    recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
+    tab: TabType,
+) {
+    match output_modes::polish_config(tab) {
+        Some(config) => stop_recording_with_config(recording_state, ui, config),
+        None => stop_recording_no_polish(recording_state, ui),
+    }
 }
 
 /// Internal function to stop recording with a specific polish config
 fn stop_recording_with_config(
     recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    ui: SharedUi,
     config: PolishConfig,
 ) {
+    if !begin_stop() {
+        return;
+    }
+    if managed::policy().disable_polishing {
+        info!("Polishing disabled by managed policy: stopping without polishing");
+        stop_without_polish(recording_state, ui);
+        return;
+    }
+    if private_mode::skips_polishing() {
+        info!("Private session: stopping without polishing");
+        stop_without_polish(recording_state, ui);
+        return;
+    }
+
     let transcript = get_full_transcript(&recording_state);
+    // Time cues let the model date each topic section
+    let polish_input = get_polish_input(&recording_state, config.splits_topics());
     stop_audio_capture(&recording_state);
 
     // Update UI - recording stopped, processing started
-    menubar::MenuBar::set_recording(false);
-    menubar::MenuBar::set_processing(true);
-    transcription_window::TranscriptionWindow::set_recording_state(false);
-    transcription_window::TranscriptionWindow::set_processing_state(true);
-    transcription_window::TranscriptionWindow::update_live_text(&transcript, Some("Polishing..."));
+    ui.set_recording(false);
+    ui.set_processing(true);
+    ui.update_live_text(&transcript, Some(tr("overlay.polishing")));
     info!("Recording stopped, polishing transcript...");
 
     // Spawn async task to polish the transcript
     tokio::spawn(async move {
-        polish::polish_transcript_async(transcript, config).await;
+        polish::polish_transcript_async(polish_input, config, ui).await;
     });
 }
 
+/// Insert a timestamped marker into the transcript of the active recording
+///
+/// Does nothing when no recording is in progress.
+pub(crate) fn insert_marker(recording_state: &Arc<Mutex<Option<RecordingSession>>>) {
+    let time = chrono::Local::now().format("%H:%M").to_string();
+    let inserted =
+        update_active_transcript(recording_state, SessionCommand::InsertMarker(time.clone()));
+    if inserted {
+        info!("Marker inserted into transcript at {}", time);
+    } else {
+        info!("Marker ignored: no active recording");
+    }
+}
+
+/// Insert a typed note into the transcript of the active recording
+///
+/// Does nothing when no recording is in progress or the note is blank.
+pub(crate) fn insert_note(recording_state: &Arc<Mutex<Option<RecordingSession>>>, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    let inserted = update_active_transcript(
+        recording_state,
+        SessionCommand::InsertNote(text.to_string()),
+    );
+    if inserted {
+        info!("Note inserted into transcript ({} chars)", text.len());
+    } else {
+        info!("Note ignored: no active recording");
+    }
+}
+
+/// Send `command` to the transcript of the active recording
+///
+/// The live view refreshes when the session reports the insertion. Returns
+/// false without sending when audio capture is not running.
+fn update_active_transcript(
+    recording_state: &Arc<Mutex<Option<RecordingSession>>>,
+    command: SessionCommand,
+) -> bool {
+    let Ok(state) = recording_state.lock() else {
+        return false;
+    };
+    let Some(ref session) = *state else {
+        return false;
+    };
+    if !session.audio_handle.is_capturing() {
+        return false;
+    }
+
+    session.session_data.send(command);
+    true
+}
+
+/// Stop the active recording because the app is quitting
+///
+/// The transcription task still commits the last audio and clears the
+/// recording state once the connection closes. Returns the session's
+/// transcript handle, or `None` when nothing was recording.
+pub(crate) fn stop_for_shutdown(
+    recording_state: &Arc<Mutex<Option<RecordingSession>>>,
+) -> Option<SessionHandle> {
+    let session = {
+        let state = recording_state.lock().ok()?;
+        let recording = state.as_ref()?;
+        // Any stop triggered from here on is ignored
+        phase::begin_stop();
+        recording.ui.set_recording(false);
+        recording.session_data.clone()
+    };
+    stop_audio_capture(recording_state);
+    info!("Recording stopped for shutdown");
+    Some(session)
+}
+
+/// Disconnect the active recording until [`resume_connection`] is called
+///
+/// The partial text is kept as committed, since the service won't finish the
+/// segment after the connection is dropped. Returns false when nothing is
+/// recording.
+pub(crate) fn pause_connection(recording_state: &Arc<Mutex<Option<RecordingSession>>>) -> bool {
+    let Ok(state) = recording_state.lock() else {
+        return false;
+    };
+    let Some(ref session) = *state else {
+        return false;
+    };
+    session
+        .session_data
+        .send(SessionCommand::PreservePartial { reason: "pause" });
+    session.connection.pause();
+    info!("Recording connection paused");
+    true
+}
+
+/// Reconnect the active recording after [`pause_connection`]
+pub(crate) fn resume_connection(recording_state: &Arc<Mutex<Option<RecordingSession>>>) {
+    if let Ok(state) = recording_state.lock() {
+        if let Some(ref session) = *state {
+            if session.connection.is_paused() {
+                session.connection.resume();
+                info!("Recording connection resumed");
+            }
+        }
+    }
+}
+
+/// Whether no polish or meeting-notes job is running
+pub(crate) fn polish_jobs_idle() -> bool {
+    jobs::is_idle()
+}
+
 /// Stop audio capture and mark session as manually stopped
 fn stop_audio_capture(recording_state: &Arc<Mutex<Option<RecordingSession>>>) {
     if let Ok(mut state) = recording_state.lock() {
         if let Some(ref mut session) = *state {
-            if let Ok(mut session_data) = session.session_data.lock() {
-                session_data.manually_stopped = true;
+            session
+                .session_data
+                .send(SessionCommand::MarkManuallyStopped);
+            // A paused connection reconnects to send the remaining audio
+            session.connection.resume();
+            if session.audio_handle.is_capturing() {
+                event_bus::publish(AppEvent::RecordingStopped);
             }
             session.audio_handle.stop();
         }
     }
+    input_check::stop();
 }
 
 /// Get full transcript including partial text
@@ -240,22 +597,47 @@ pub(crate) fn get_full_transcript(
     };
 
     if let Some(ref recording_session) = *state {
-        let Ok(session) = recording_session.session_data.lock() else {
-            return String::new();
-        };
-
-        let committed = session.full_transcript();
-        if let Some(ref partial) = session.partial_transcript {
-            if !partial.trim().is_empty() {
-                if committed.is_empty() {
-                    return partial.clone();
-                } else {
-                    return format!("{} {}", committed, partial);
-                }
+        let (committed, partial) = recording_session.session_data.live_text();
+        join_partial(committed, partial)
+    } else {
+        String::new()
+    }
+}
+
+/// Get full transcript including partial text, with language cues where the
+/// language was switched, and time cues for topic sections if `timed`
+fn get_polish_input(recording_state: &Arc<Mutex<Option<RecordingSession>>>, timed: bool) -> String {
+    let Ok(state) = recording_state.lock() else {
+        return String::new();
+    };
+
+    if let Some(ref recording_session) = *state {
+        let session = recording_session.session_data.snapshot();
+        let mut language_cue = language_sections::cues(&session);
+        let mut time_cue = topics::time_cues(&session);
+        let text = session.transcript_with_cues(|index| {
+            let time = if timed { time_cue(index) } else { None };
+            match (language_cue(index), time) {
+                (Some(language), Some(time)) => Some(format!("{} {}", language, time)),
+                (language, time) => language.or(time),
             }
-        }
-        committed
+        });
+        join_partial(text, session.partial_transcript.clone())
     } else {
         String::new()
     }
 }
+
+/// Append any partial text to the committed transcript
+fn join_partial(committed: String, partial: Option<String>) -> String {
+    match partial {
+        Some(partial) if !partial.trim().is_empty() => {
+            if committed.is_empty() {
+                partial
+            } else {
+                format!("{} {}", committed, partial)
+            }
+        }
+        _ => committed,
+    }
+}
@@ -0,0 +1,89 @@
+//! Throttling of partial transcript updates
+//!
+//! Providers revise the segment in progress many times a second. Redrawing
+//! the overlay for every revision makes it hard to read, so partials are
+//! shown at most about ten times a second: one arriving sooner than that
+//! after the last is held, and only the latest held one is shown when the
+//! interval is up.
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Shortest time between two partial transcript updates (~10 Hz)
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// When the latest partial may be shown
+#[derive(Debug, Default)]
+pub(super) struct PartialThrottle {
+    last_shown: Option<Instant>,
+    held: Option<String>,
+}
+
+impl PartialThrottle {
+    /// Whether `text` can be shown now; if not, it is held until [`Self::deadline`]
+    pub(super) fn offer(&mut self, text: &str, now: Instant) -> bool {
+        if self
+            .last_shown
+            .is_some_and(|shown| now.duration_since(shown) < MIN_INTERVAL)
+        {
+            self.held = Some(text.to_string());
+            return false;
+        }
+        self.last_shown = Some(now);
+        self.held = None;
+        true
+    }
+
+    /// When the held partial is due, if one is held
+    pub(super) fn deadline(&self) -> Option<Instant> {
+        self.held.as_ref()?;
+        self.last_shown.map(|shown| shown + MIN_INTERVAL)
+    }
+
+    /// Take the held partial to show it now
+    pub(super) fn take_held(&mut self, now: Instant) -> Option<String> {
+        let text = self.held.take()?;
+        self.last_shown = Some(now);
+        Some(text)
+    }
+
+    /// Drop the held partial once the live text was redrawn without it
+    pub(super) fn discard(&mut self) {
+        self.held = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partials_within_interval_are_held_until_due() {
+        let start = Instant::now();
+        let mut throttle = PartialThrottle::default();
+        assert!(throttle.offer("Hello", start));
+        assert_eq!(throttle.deadline(), None);
+
+        let soon = start + Duration::from_millis(30);
+        assert!(!throttle.offer("Hello wor", soon));
+        assert!(!throttle.offer("Hello world", soon));
+        assert_eq!(throttle.deadline(), Some(start + MIN_INTERVAL));
+
+        let due = start + MIN_INTERVAL;
+        assert_eq!(throttle.take_held(due).as_deref(), Some("Hello world"));
+        assert_eq!(throttle.deadline(), None);
+        assert!(!throttle.offer("Hello world.", due + Duration::from_millis(10)));
+        assert!(throttle.offer("Hello world.", due + MIN_INTERVAL));
+    }
+
+    #[test]
+    fn test_discarded_partial_is_not_shown() {
+        let start = Instant::now();
+        let mut throttle = PartialThrottle::default();
+        assert!(throttle.offer("Hello", start));
+        assert!(!throttle.offer("Hello there", start));
+        throttle.discard();
+        assert_eq!(throttle.deadline(), None);
+        assert_eq!(throttle.take_held(start + MIN_INTERVAL), None);
+    }
+}
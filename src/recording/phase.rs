@@ -0,0 +1,131 @@
+//! Recording lifecycle: Idle → Starting → Recording → Stopping → Idle
+//!
+//! Starting and stopping can be triggered from hotkeys, the menu bar, the
+//! overlay, automation and timers, often from different threads. Each step
+//! is a single compare-and-swap, so when two triggers race (a double-tapped
+//! hotkey, a menu click as the duration limit hits) only the first moves the
+//! recording on and the others do nothing. Stopping lasts until the
+//! transcription task has sent the last audio and cleared the session, so a
+//! new recording can't start while the previous one still owns the state.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Where the recording is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum RecordingPhase {
+    /// Nothing is recording; a recording can start
+    Idle,
+    /// Credentials, capture and the transcription task are being set up
+    Starting,
+    /// Capturing and transcribing; the recording can be stopped
+    Recording,
+    /// Capture stopped; the transcription task is finishing up
+    Stopping,
+}
+
+impl RecordingPhase {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Starting,
+            2 => Self::Recording,
+            3 => Self::Stopping,
+            _ => Self::Idle,
+        }
+    }
+}
+
+struct Phase(AtomicU8);
+
+impl Phase {
+    const fn new() -> Self {
+        Self(AtomicU8::new(RecordingPhase::Idle as u8))
+    }
+
+    fn get(&self) -> RecordingPhase {
+        RecordingPhase::from_u8(self.0.load(Ordering::SeqCst))
+    }
+
+    /// Move from `from` to `to`, returning false if the phase wasn't `from`
+    fn advance(&self, from: RecordingPhase, to: RecordingPhase) -> bool {
+        self.0
+            .compare_exchange(from as u8, to as u8, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    fn reset(&self) {
+        self.0.store(RecordingPhase::Idle as u8, Ordering::SeqCst);
+    }
+}
+
+static PHASE: Phase = Phase::new();
+
+/// The current phase, for triggers that toggle between starting and stopping
+pub(crate) fn current_phase() -> RecordingPhase {
+    PHASE.get()
+}
+
+/// Claim the start of a recording; false if one is already under way
+pub(super) fn begin_start() -> bool {
+    PHASE.advance(RecordingPhase::Idle, RecordingPhase::Starting)
+}
+
+/// The claimed recording is capturing
+pub(super) fn started() {
+    PHASE.advance(RecordingPhase::Starting, RecordingPhase::Recording);
+}
+
+/// The claimed recording could not start
+pub(super) fn start_failed() {
+    PHASE.advance(RecordingPhase::Starting, RecordingPhase::Idle);
+}
+
+/// Claim the stop of the recording; false if it isn't recording or is
+/// already stopping
+pub(super) fn begin_stop() -> bool {
+    PHASE.advance(RecordingPhase::Recording, RecordingPhase::Stopping)
+}
+
+/// The session is cleared, whether it was stopped or the connection failed
+pub(super) fn finished() {
+    PHASE.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_first_trigger_moves_the_phase_on() {
+        let phase = Phase::new();
+        assert!(phase.advance(RecordingPhase::Idle, RecordingPhase::Starting));
+        assert!(!phase.advance(RecordingPhase::Idle, RecordingPhase::Starting));
+
+        assert!(!phase.advance(RecordingPhase::Recording, RecordingPhase::Stopping));
+        assert!(phase.advance(RecordingPhase::Starting, RecordingPhase::Recording));
+        assert!(phase.advance(RecordingPhase::Recording, RecordingPhase::Stopping));
+        assert!(!phase.advance(RecordingPhase::Recording, RecordingPhase::Stopping));
+        assert_eq!(phase.get(), RecordingPhase::Stopping);
+
+        phase.reset();
+        assert_eq!(phase.get(), RecordingPhase::Idle);
+    }
+
+    #[test]
+    fn test_racing_starts_claim_one_recording() {
+        let phase = std::sync::Arc::new(Phase::new());
+        let claimed = (0..8)
+            .map(|_| {
+                let phase = phase.clone();
+                std::thread::spawn(move || {
+                    phase.advance(RecordingPhase::Idle, RecordingPhase::Starting)
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or(false))
+            .filter(|claimed| *claimed)
+            .count();
+        assert_eq!(claimed, 1);
+    }
+}
@@ -3,32 +3,136 @@
 //! Handles async transcript polishing via Azure OpenAI or OpenAI.
 //! Users provide their own credentials for their selected provider.
 
-use crate::azure_openai::AzureOpenAIClient;
+use crate::azure_openai::{self, AzureOpenAIClient};
 use crate::error::ResponseError;
 use crate::keychain;
-use crate::openai::OpenAIClient;
+use crate::language_sections;
+use crate::managed;
+use crate::openai::{self, OpenAIClient};
+use crate::output_modes;
 use crate::preferences::{self, AiProvider};
+use crate::private_mode;
+use crate::provenance;
 use crate::response::PolishConfig;
-use crate::transcription_window::{self, TabType};
+use crate::retry::{self, RetryNotice};
+use crate::topics;
+use crate::transcription_window::TabType;
+use crate::ui_sink::SharedUi;
+use std::future::Future;
+use std::sync::Arc;
 use tokio::time::{timeout, Duration};
 use tracing::{error, info};
 
+use super::demo;
+use super::jobs::{self, SubmitOutcome};
 use super::polish_helpers::{
-    handle_polish_error, handle_polish_failure, handle_polish_success, handle_transcript_too_large,
-    reset_processing_state,
+    handle_context_window_exceeded, handle_polish_error, handle_polish_failure,
+    handle_polish_success, handle_transcript_too_large, reset_processing_state,
+    set_polished_content,
 };
+use super::preflight::{self, PolishPlan};
 
 /// Timeout for polish API calls (2 minutes for long transcripts)
 const POLISH_TIMEOUT: Duration = Duration::from_secs(120);
 
+// Rate-limit waits happen inside the timeout, so they must leave room for the request itself
+const _: () = assert!(retry::MAX_TOTAL_RATE_LIMIT_DELAY.as_secs() < POLISH_TIMEOUT.as_secs());
+
+/// Polish each part sequentially and join the results.
+///
+/// A single part is passed through unchanged; multiple parts of any output
+/// mode but basic polishing get a heading per part since each produces its
+/// own set of sections.
+async fn polish_parts<'a, F, Fut>(
+    parts: &'a [String],
+    config: &PolishConfig,
+    mut polish_part: F,
+) -> Result<String, ResponseError>
+where
+    F: FnMut(&'a str) -> Fut,
+    Fut: Future<Output = Result<String, ResponseError>>,
+{
+    if let [single] = parts {
+        return polish_part(single).await;
+    }
+
+    let labels_parts =
+        config.is_meeting_notes() || config.is_follow_up_email() || config.custom_mode.is_some();
+    let total = parts.len();
+    let mut outputs = Vec::with_capacity(total);
+
+    for (index, part) in parts.iter().enumerate() {
+        info!(part = index + 1, total = total, "Polishing transcript part");
+        let polished = polish_part(part).await?;
+        if labels_parts {
+            outputs.push(format!("# Part {} of {}\n\n{}", index + 1, total, polished));
+        } else {
+            outputs.push(polished);
+        }
+    }
+
+    Ok(outputs.join("\n\n"))
+}
+
+/// Polish a standalone text with the polishing provider.
+///
+/// Used for text that isn't a recording, so nothing is shown in the
+/// transcription window and the text isn't split into parts.
+pub(crate) async fn polish_text(text: &str, config: &PolishConfig) -> anyhow::Result<String> {
+    polish_text_with(preferences::get_polish_provider(), text, config).await
+}
+
+/// Polish a standalone text with `provider` rather than the chosen one
+pub(crate) async fn polish_text_with(
+    provider: AiProvider,
+    text: &str,
+    config: &PolishConfig,
+) -> anyhow::Result<String> {
+    let result = match provider {
+        AiProvider::Azure => {
+            let client = AzureOpenAIClient::new(&keychain::get_azure_credentials()?)?;
+            timeout(POLISH_TIMEOUT, client.polish_transcript(text, config)).await
+        }
+        AiProvider::OpenAI => {
+            let client = OpenAIClient::new(&keychain::get_openai_credentials()?)?;
+            timeout(POLISH_TIMEOUT, client.polish_transcript(text, config)).await
+        }
+    };
+
+    match result {
+        Ok(polished) => Ok(polished?),
+        Err(_) => anyhow::bail!("Polish request timed out after {:?}", POLISH_TIMEOUT),
+    }
+}
+
+/// Show rate-limit waits in the transcription window's processing indicator
+fn rate_limit_notice(ui: &SharedUi) -> RetryNotice {
+    let ui = ui.clone();
+    Arc::new(move |delay| match delay {
+        Some(delay) => ui.set_processing_message(&retry::rate_limit_message(delay)),
+        None => ui.set_processing(true),
+    })
+}
+
+/// Overall timeout for polishing `parts` sequentially
+fn parts_timeout(parts: &[String]) -> Duration {
+    POLISH_TIMEOUT * parts.len().max(1) as u32
+}
+
 /// Execute polish via Azure OpenAI connection
-async fn azure_polish(transcript: &str, config: &PolishConfig, target_tab: TabType) {
+async fn azure_polish(
+    ui: &SharedUi,
+    transcript: &str,
+    parts: &[String],
+    config: &PolishConfig,
+    target_tab: TabType,
+) {
     // Get Azure credentials
     let creds = match keychain::get_azure_credentials() {
         Ok(c) => c,
         Err(e) => {
             error!("Azure credentials not found: {}", e);
-            handle_polish_failure(transcript, target_tab);
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
             return;
         }
     };
@@ -40,20 +144,27 @@ async fn azure_polish(transcript: &str, config: &PolishConfig, target_tab: TabTy
     );
 
     let client = match AzureOpenAIClient::new(&creds) {
-        Ok(c) => c,
+        Ok(c) => c.with_retry_notice(rate_limit_notice(ui)),
         Err(e) => {
             error!("Failed to create Azure client: {}", e);
-            handle_polish_failure(transcript, target_tab);
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
             return;
         }
     };
 
-    let polish_result = timeout(POLISH_TIMEOUT, client.polish_transcript(transcript, config)).await;
+    let polish_result = timeout(
+        parts_timeout(parts),
+        polish_parts(parts, config, |part| client.polish_transcript(part, config)),
+    )
+    .await;
 
     match polish_result {
         Err(_) => {
-            error!("Azure polish request timed out after {:?}", POLISH_TIMEOUT);
-            handle_polish_failure(transcript, target_tab);
+            error!(
+                "Azure polish request timed out after {:?}",
+                parts_timeout(parts)
+            );
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
         }
         Ok(Ok(polished)) => {
             info!(
@@ -61,30 +172,42 @@ async fn azure_polish(transcript: &str, config: &PolishConfig, target_tab: TabTy
                 transcript.len(),
                 polished.len()
             );
-            handle_polish_success(polished, target_tab);
+            let polish = provenance::Polish::new(
+                AiProvider::Azure,
+                &creds.polish_deployment,
+                config,
+                &azure_openai::prompt_template(config),
+            );
+            handle_polish_success(ui.as_ref(), transcript, polished, target_tab, polish);
         }
         Ok(Err(ResponseError::TranscriptTooLarge { length, max_length })) => {
             error!(
                 "Transcript too large for Azure: {} chars (max: {})",
                 length, max_length
             );
-            handle_transcript_too_large(transcript, length, max_length, target_tab);
+            handle_transcript_too_large(ui.as_ref(), transcript, length, max_length, target_tab);
         }
         Ok(Err(e)) => {
             error!("Failed to polish transcript via Azure: {}", e);
-            handle_polish_error(transcript, target_tab);
+            handle_polish_error(ui.as_ref(), transcript, target_tab);
         }
     }
 }
 
 /// Execute polish via OpenAI connection
-async fn openai_polish(transcript: &str, config: &PolishConfig, target_tab: TabType) {
+async fn openai_polish(
+    ui: &SharedUi,
+    transcript: &str,
+    parts: &[String],
+    config: &PolishConfig,
+    target_tab: TabType,
+) {
     // Get OpenAI credentials
     let creds = match keychain::get_openai_credentials() {
         Ok(c) => c,
         Err(e) => {
             error!("OpenAI credentials not found: {}", e);
-            handle_polish_failure(transcript, target_tab);
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
             return;
         }
     };
@@ -92,20 +215,27 @@ async fn openai_polish(transcript: &str, config: &PolishConfig, target_tab: TabT
     info!("Polishing transcript via OpenAI (gpt-5.2)");
 
     let client = match OpenAIClient::new(&creds) {
-        Ok(c) => c,
+        Ok(c) => c.with_retry_notice(rate_limit_notice(ui)),
         Err(e) => {
             error!("Failed to create OpenAI client: {}", e);
-            handle_polish_failure(transcript, target_tab);
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
             return;
         }
     };
 
-    let polish_result = timeout(POLISH_TIMEOUT, client.polish_transcript(transcript, config)).await;
+    let polish_result = timeout(
+        parts_timeout(parts),
+        polish_parts(parts, config, |part| client.polish_transcript(part, config)),
+    )
+    .await;
 
     match polish_result {
         Err(_) => {
-            error!("OpenAI polish request timed out after {:?}", POLISH_TIMEOUT);
-            handle_polish_failure(transcript, target_tab);
+            error!(
+                "OpenAI polish request timed out after {:?}",
+                parts_timeout(parts)
+            );
+            handle_polish_failure(ui.as_ref(), transcript, target_tab);
         }
         Ok(Ok(polished)) => {
             info!(
@@ -113,80 +243,171 @@ async fn openai_polish(transcript: &str, config: &PolishConfig, target_tab: TabT
                 transcript.len(),
                 polished.len()
             );
-            handle_polish_success(polished, target_tab);
+            let polish = provenance::Polish::new(
+                AiProvider::OpenAI,
+                openai::POLISH_MODEL,
+                config,
+                &openai::prompt_template(config),
+            );
+            handle_polish_success(ui.as_ref(), transcript, polished, target_tab, polish);
         }
         Ok(Err(ResponseError::TranscriptTooLarge { length, max_length })) => {
             error!(
                 "Transcript too large for OpenAI: {} chars (max: {})",
                 length, max_length
             );
-            handle_transcript_too_large(transcript, length, max_length, target_tab);
+            handle_transcript_too_large(ui.as_ref(), transcript, length, max_length, target_tab);
         }
         Ok(Err(e)) => {
             error!("Failed to polish transcript via OpenAI: {}", e);
-            handle_polish_error(transcript, target_tab);
+            handle_polish_error(ui.as_ref(), transcript, target_tab);
         }
     }
 }
 
-/// Polish transcript using the selected provider
-async fn polish_with_provider(transcript: &str, config: &PolishConfig, target_tab: TabType) {
-    let provider = preferences::get_ai_provider();
+/// `transcript` without the time and language cues added for polishing
+fn without_cues(transcript: &str) -> String {
+    language_sections::strip_cues(&topics::strip_time_cues(transcript))
+}
+
+/// Polish transcript using the polishing provider
+///
+/// `transcript` may carry time cues for topic sections and language cues
+/// where the language was switched; they are sent for polishing but left out
+/// wherever the raw transcript is shown or copied.
+async fn polish_with_provider(
+    ui: &SharedUi,
+    transcript: &str,
+    config: &PolishConfig,
+    target_tab: TabType,
+) {
+    // The sample meeting has canned outputs and is never sent to a provider
+    if demo::is_active() {
+        demo::polish(ui, target_tab).await;
+        return;
+    }
+
+    let provider = preferences::get_polish_provider();
+    let polish_input = transcript;
+    let transcript = &without_cues(polish_input);
+
+    // Estimate tokens up front so oversized transcripts get a choice, not a server error
+    let parts = match preflight::plan_polish(polish_input, provider).await {
+        PolishPlan::Parts(parts) => parts,
+        PolishPlan::Cancelled { estimated, budget } => {
+            handle_context_window_exceeded(ui.as_ref(), transcript, estimated, budget, target_tab);
+            return;
+        }
+    };
 
     match provider {
         AiProvider::Azure => {
-            azure_polish(transcript, config, target_tab).await;
+            azure_polish(ui, transcript, &parts, config, target_tab).await;
         }
         AiProvider::OpenAI => {
-            openai_polish(transcript, config, target_tab).await;
+            openai_polish(ui, transcript, &parts, config, target_tab).await;
         }
     }
 }
 
+/// Run a polish job for a tab through the job manager.
+///
+/// Duplicate requests are dropped and requests for a busy tab are queued;
+/// queued jobs for the same tab run here once the current one finishes.
+async fn run_polish_job(
+    ui: SharedUi,
+    transcript: String,
+    config: PolishConfig,
+    target_tab: TabType,
+) {
+    match jobs::submit(target_tab, transcript.clone(), config.clone()) {
+        SubmitOutcome::Started => {}
+        SubmitOutcome::Duplicate => {
+            info!(?target_tab, "Identical polish job already in progress");
+            return;
+        }
+        SubmitOutcome::Queued => {
+            info!(?target_tab, "Polish job queued behind running job");
+            return;
+        }
+    }
+
+    let mut job = (transcript, config);
+    loop {
+        polish_with_provider(&ui, &job.0, &job.1, target_tab).await;
+        match jobs::finish(target_tab) {
+            Some(next) => job = (next.transcript, next.config),
+            None => break,
+        }
+    }
+
+    reset_processing_state(ui.as_ref());
+}
+
 /// Async function to polish transcript (called when stopping recording)
-#[tracing::instrument(skip(transcript))]
-pub(super) async fn polish_transcript_async(transcript: String, config: PolishConfig) {
+#[tracing::instrument(skip(transcript, ui))]
+pub(super) async fn polish_transcript_async(
+    transcript: String,
+    config: PolishConfig,
+    ui: SharedUi,
+) {
     // Determine target tab based on config
-    let target_tab = if config.prompt_type.as_deref() == Some("live_meeting") {
-        TabType::MeetingNotes
-    } else {
-        TabType::BasicPolish
-    };
+    let target_tab = output_modes::tab_for(&config);
 
     // If transcript is empty, skip polishing
     if transcript.trim().is_empty() {
         info!("No transcript to polish (empty)");
-        reset_processing_state();
+        reset_processing_state(ui.as_ref());
         return;
     }
 
     // Store the raw transcript in the live tab
-    transcription_window::TranscriptionWindow::update_live_text(&transcript, None);
+    ui.update_live_text(&without_cues(&transcript), None);
 
     // Polish via selected provider
-    polish_with_provider(&transcript, &config, target_tab).await;
-
-    reset_processing_state();
+    run_polish_job(ui, transcript, config, target_tab).await;
 }
 
 /// Async function to polish transcript on-demand (called when clicking empty tab)
-#[tracing::instrument(skip(transcript))]
-pub(crate) async fn polish_transcript_on_demand(transcript: String, target_tab: TabType) {
+#[tracing::instrument(skip(transcript, ui))]
+pub(crate) async fn polish_transcript_on_demand(
+    transcript: String,
+    target_tab: TabType,
+    ui: SharedUi,
+) {
     // Determine config based on target tab
-    let config = match target_tab {
-        TabType::MeetingNotes => PolishConfig::live_meeting(),
-        TabType::BasicPolish => PolishConfig::basic_polish(),
-        TabType::Live => return,
+    let Some(config) = output_modes::polish_config(target_tab) else {
+        return;
     };
 
     if transcript.trim().is_empty() {
         info!("No transcript to polish on-demand (empty)");
-        reset_processing_state();
+        reset_processing_state(ui.as_ref());
         return;
     }
 
-    // Polish via selected provider
-    polish_with_provider(&transcript, &config, target_tab).await;
+    if managed::policy().disable_polishing {
+        info!("On-demand polishing skipped: disabled by managed policy");
+        set_polished_content(
+            ui.as_ref(),
+            "🔒 Polishing is turned off by your organization.\n\nThe transcript was not sent to the AI provider.",
+            target_tab,
+        );
+        reset_processing_state(ui.as_ref());
+        return;
+    }
 
-    reset_processing_state();
+    if private_mode::skips_polishing() {
+        info!("On-demand polishing skipped: private session");
+        set_polished_content(
+            ui.as_ref(),
+            "🔒 Polishing is turned off during private sessions.\n\nThe transcript was not sent to the AI provider.",
+            target_tab,
+        );
+        reset_processing_state(ui.as_ref());
+        return;
+    }
+
+    // Polish via selected provider
+    run_polish_job(ui, transcript, config, target_tab).await;
 }
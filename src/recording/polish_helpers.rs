@@ -2,38 +2,47 @@
 //!
 //! Contains UI state management and error handling helpers for polish operations.
 
-use crate::menubar;
-use crate::transcription_window::{self, TabType};
+use crate::event_bus::{self, AppEvent};
+use crate::output_modes;
+use crate::provenance::{self, Polish};
+use crate::templates;
+use crate::transcription_window::TabType;
+use crate::ui_sink::UiSink;
 
-use super::clipboard::copy_to_clipboard;
+use super::clipboard::auto_copy;
+use super::jobs;
 
 /// Handle polish failure by falling back to raw transcript
-pub(super) fn handle_polish_failure(transcript: &str, target_tab: TabType) {
-    copy_to_clipboard(transcript);
+pub(super) fn handle_polish_failure(ui: &dyn UiSink, transcript: &str, target_tab: TabType) {
+    event_bus::publish(AppEvent::PolishFailed);
+    auto_copy(target_tab, transcript, transcript);
     // Show raw transcript in the target tab
-    match target_tab {
-        TabType::BasicPolish => {
-            let msg = format!("⚠️ Polishing failed. Raw transcript:\n\n{}", transcript);
-            transcription_window::TranscriptionWindow::set_polished_content(&msg);
-        }
-        TabType::MeetingNotes => {
-            let msg = format!(
-                "⚠️ Meeting notes generation failed. Raw transcript:\n\n{}",
-                transcript
-            );
-            transcription_window::TranscriptionWindow::set_meeting_notes_content(&msg);
-        }
-        TabType::Live => {
-            transcription_window::TranscriptionWindow::update_live_text(transcript, None);
-        }
-    }
-    transcription_window::TranscriptionWindow::switch_to_tab(target_tab);
-    show_save_button(transcript.to_string());
-    reset_processing_state();
+    let msg = match target_tab {
+        TabType::BasicPolish => format!("⚠️ Polishing failed. Raw transcript:\n\n{}", transcript),
+        TabType::MeetingNotes => format!(
+            "⚠️ Meeting notes generation failed. Raw transcript:\n\n{}",
+            transcript
+        ),
+        TabType::FollowUpEmail => format!(
+            "⚠️ Follow-up email generation failed. Raw transcript:\n\n{}",
+            transcript
+        ),
+        TabType::Custom(_) => format!(
+            "⚠️ {} generation failed. Raw transcript:\n\n{}",
+            output_modes::title(target_tab),
+            transcript
+        ),
+        TabType::Live => transcript.to_string(),
+    };
+    ui.set_tab_content(target_tab, &msg);
+    focus_tab(ui, target_tab);
+    show_save_button(ui, transcript.to_string(), None);
+    reset_processing_state(ui);
 }
 
 /// Handle transcript too large error
 pub(super) fn handle_transcript_too_large(
+    ui: &dyn UiSink,
     transcript: &str,
     length: usize,
     max_length: usize,
@@ -43,61 +52,85 @@ pub(super) fn handle_transcript_too_large(
         "⚠️ Transcript too large to process\n\nYour transcript is {} characters, but the maximum is {}.\n\nRaw transcript:\n{}",
         length, max_length, transcript
     );
-    match target_tab {
-        TabType::BasicPolish => {
-            transcription_window::TranscriptionWindow::set_polished_content(&display_text);
-        }
-        TabType::MeetingNotes => {
-            transcription_window::TranscriptionWindow::set_meeting_notes_content(&display_text);
-        }
-        TabType::Live => {
-            transcription_window::TranscriptionWindow::update_live_text(&display_text, None);
-        }
+    ui.set_tab_content(target_tab, &display_text);
+    focus_tab(ui, target_tab);
+    auto_copy(target_tab, transcript, transcript);
+    show_save_button(ui, transcript.to_string(), None);
+    reset_processing_state(ui);
+}
+
+/// Handle a transcript whose estimated token count exceeds the polish context window
+pub(super) fn handle_context_window_exceeded(
+    ui: &dyn UiSink,
+    transcript: &str,
+    estimated_tokens: usize,
+    budget_tokens: usize,
+    target_tab: TabType,
+) {
+    let display_text = format!(
+        "⚠️ Transcript too long for the polish model\n\nYour transcript is about {} tokens, but the model accepts about {}. Polishing was cancelled.\n\nRaw transcript:\n{}",
+        estimated_tokens, budget_tokens, transcript
+    );
+    set_polished_content(ui, &display_text, target_tab);
+    focus_tab(ui, target_tab);
+    auto_copy(target_tab, transcript, transcript);
+    show_save_button(ui, transcript.to_string(), None);
+    reset_processing_state(ui);
+}
+
+/// Bring the tab to the front unless the user has since asked for another tab
+pub(super) fn focus_tab(ui: &dyn UiSink, target_tab: TabType) {
+    if jobs::is_focused(target_tab) {
+        ui.switch_to_tab(target_tab);
     }
-    transcription_window::TranscriptionWindow::switch_to_tab(target_tab);
-    copy_to_clipboard(transcript);
-    show_save_button(transcript.to_string());
-    reset_processing_state();
 }
 
-/// Reset processing state in UI
-pub(super) fn reset_processing_state() {
-    menubar::MenuBar::set_processing(false);
-    transcription_window::TranscriptionWindow::set_processing_state(false);
+/// Reset processing state in UI once no polish job is running
+pub(super) fn reset_processing_state(ui: &dyn UiSink) {
+    if !jobs::is_idle() {
+        return;
+    }
+    ui.set_processing(false);
 }
 
 /// Show save button to allow user to manually save the transcript
-fn show_save_button(transcript: String) {
+///
+/// `polish` is how the transcript was polished, None for the raw transcript.
+pub(super) fn show_save_button(ui: &dyn UiSink, transcript: String, polish: Option<Polish>) {
     if !transcript.trim().is_empty() {
-        transcription_window::TranscriptionWindow::show_save_button(transcript);
+        provenance::set_polish(polish);
+        ui.show_save_button(transcript);
     }
 }
 
 /// Set polished content in the appropriate tab
-fn set_polished_content(content: &str, target_tab: TabType) {
-    match target_tab {
-        TabType::BasicPolish => {
-            transcription_window::TranscriptionWindow::set_polished_content(content);
-        }
-        TabType::MeetingNotes => {
-            transcription_window::TranscriptionWindow::set_meeting_notes_content(content);
-        }
-        TabType::Live => {}
+pub(super) fn set_polished_content(ui: &dyn UiSink, content: &str, target_tab: TabType) {
+    if target_tab != TabType::Live {
+        ui.set_tab_content(target_tab, content);
     }
 }
 
 /// Handle successful polish result
-pub(super) fn handle_polish_success(polished: String, target_tab: TabType) {
-    set_polished_content(&polished, target_tab);
-    transcription_window::TranscriptionWindow::switch_to_tab(target_tab);
-    copy_to_clipboard(&polished);
-    show_save_button(polished);
+pub(super) fn handle_polish_success(
+    ui: &dyn UiSink,
+    transcript: &str,
+    polished: String,
+    target_tab: TabType,
+    polish: Polish,
+) {
+    set_polished_content(ui, &polished, target_tab);
+    focus_tab(ui, target_tab);
+    auto_copy(target_tab, transcript, &polished);
+    show_save_button(ui, polished.clone(), Some(polish));
+    templates::auto_save(target_tab, &polished);
+    event_bus::publish(AppEvent::PolishCompleted { tab: target_tab });
 }
 
 /// Handle generic polish error by showing raw transcript
-pub(super) fn handle_polish_error(transcript: &str, target_tab: TabType) {
-    set_polished_content(transcript, target_tab);
-    transcription_window::TranscriptionWindow::switch_to_tab(target_tab);
-    copy_to_clipboard(transcript);
-    show_save_button(transcript.to_string());
+pub(super) fn handle_polish_error(ui: &dyn UiSink, transcript: &str, target_tab: TabType) {
+    event_bus::publish(AppEvent::PolishFailed);
+    set_polished_content(ui, transcript, target_tab);
+    focus_tab(ui, target_tab);
+    auto_copy(target_tab, transcript, transcript);
+    show_save_button(ui, transcript.to_string(), None);
 }
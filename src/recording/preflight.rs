@@ -0,0 +1,117 @@
+//! Pre-flight token check before polishing
+//!
+//! Estimates the transcript's token count and, when it would overflow the
+//! polish model's context window, asks the user whether to polish in parts,
+//! truncate, or cancel instead of sending a request the provider will reject.
+
+use objc2_app_kit::{NSAlert, NSAlertStyle};
+use objc2_foundation::{MainThreadMarker, NSString};
+use tokio::sync::oneshot;
+use tracing::{info, warn};
+
+use crate::preferences::AiProvider;
+use crate::tokens::{self, Preflight};
+
+/// NSAlertFirstButtonReturn
+const ALERT_FIRST_BUTTON: isize = 1000;
+
+/// NSAlertSecondButtonReturn
+const ALERT_SECOND_BUTTON: isize = 1001;
+
+/// User's choice for a transcript that exceeds the context window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OversizeChoice {
+    Chunk,
+    Truncate,
+    Cancel,
+}
+
+/// How the transcript should be sent to the polish provider
+pub(super) enum PolishPlan {
+    /// Send the transcript in one or more parts, polished sequentially
+    Parts(Vec<String>),
+    /// The user cancelled; `estimated` and `budget` are in tokens
+    Cancelled { estimated: usize, budget: usize },
+}
+
+/// Check the transcript against the provider's context window and build a plan.
+pub(super) async fn plan_polish(transcript: &str, provider: AiProvider) -> PolishPlan {
+    let budget = tokens::transcript_budget(provider);
+
+    let (estimated, budget) = match tokens::preflight(transcript, budget) {
+        Preflight::Fits { estimated } => {
+            info!(
+                estimated_tokens = estimated,
+                "Transcript fits polish context window"
+            );
+            return PolishPlan::Parts(vec![transcript.to_string()]);
+        }
+        Preflight::ExceedsContext { estimated, budget } => (estimated, budget),
+    };
+
+    warn!(
+        estimated_tokens = estimated,
+        budget_tokens = budget,
+        "Transcript exceeds polish context window"
+    );
+
+    match ask_user(estimated, budget).await {
+        OversizeChoice::Chunk => {
+            let parts = tokens::split_into_chunks(transcript, budget);
+            info!(parts = parts.len(), "Polishing transcript in parts");
+            PolishPlan::Parts(parts)
+        }
+        OversizeChoice::Truncate => {
+            let truncated = tokens::truncate_to_tokens(transcript, budget);
+            info!(
+                "Truncated transcript for polishing ({} -> {} chars)",
+                transcript.len(),
+                truncated.len()
+            );
+            PolishPlan::Parts(vec![truncated.to_string()])
+        }
+        OversizeChoice::Cancel => PolishPlan::Cancelled { estimated, budget },
+    }
+}
+
+/// Show the oversize warning on the main thread and wait for the user's choice.
+async fn ask_user(estimated: usize, budget: usize) -> OversizeChoice {
+    let (tx, rx) = oneshot::channel();
+
+    dispatch::Queue::main().exec_async(move || {
+        let choice = match MainThreadMarker::new() {
+            Some(mtm) => run_alert(mtm, estimated, budget),
+            None => OversizeChoice::Cancel,
+        };
+        let _ = tx.send(choice);
+    });
+
+    rx.await.unwrap_or(OversizeChoice::Cancel)
+}
+
+/// Run the modal oversize alert.
+fn run_alert(mtm: MainThreadMarker, estimated: usize, budget: usize) -> OversizeChoice {
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Warning);
+        alert.setMessageText(&NSString::from_str(
+            "Transcript is too long to polish at once",
+        ));
+        alert.setInformativeText(&NSString::from_str(&format!(
+            "The transcript is about {} tokens, but the polish model accepts about {}.\n\n\
+             Polish in parts to process it in sequential chunks, or truncate to polish only the beginning.",
+            estimated, budget
+        )));
+        alert.addButtonWithTitle(&NSString::from_str("Polish in Parts"));
+        alert.addButtonWithTitle(&NSString::from_str("Truncate"));
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+
+    match response {
+        ALERT_FIRST_BUTTON => OversizeChoice::Chunk,
+        ALERT_SECOND_BUTTON => OversizeChoice::Truncate,
+        _ => OversizeChoice::Cancel,
+    }
+}
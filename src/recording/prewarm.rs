@@ -0,0 +1,82 @@
+//! Preparing the transcription connection before a recording starts
+//!
+//! Opening the menu bar menu (when enabled in Settings) or pressing the
+//! prepare hotkey connects to the selected provider in the background, so
+//! Start Recording begins transcribing without waiting for the handshake.
+
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+use super::RecordingSession;
+use crate::keychain;
+use crate::preferences::{self, AiProvider};
+use crate::shutdown;
+use crate::transcription;
+
+/// Connect to the selected provider ahead of the next recording
+///
+/// Does nothing while recording or quitting, or when the provider's
+/// credentials are missing; starting the recording reports those.
+pub(crate) fn prepare_connection(recording_state: &Arc<Mutex<Option<RecordingSession>>>) {
+    if shutdown::in_progress() {
+        return;
+    }
+    if recording_state.lock().map_or(true, |state| state.is_some()) {
+        return;
+    }
+
+    // A replayed fixture doesn't connect
+    #[cfg(feature = "mock-provider")]
+    if transcription::MockFixture::from_env().is_some() {
+        return;
+    }
+
+    tokio::spawn(async {
+        let language_code = preferences::get_language_code();
+        let encoding = transcription::AudioEncoding::from_preferences();
+        let noise_reduction = preferences::get_noise_reduction();
+        let result = match preferences::get_ai_provider() {
+            AiProvider::Azure => {
+                let Ok(creds) = keychain::get_azure_credentials() else {
+                    return;
+                };
+                transcription::prewarm_azure(
+                    &creds.endpoint_url,
+                    &creds.stt_deployment,
+                    creds.realtime_api_version(),
+                    &creds.api_key,
+                    &language_code,
+                    encoding,
+                    noise_reduction,
+                )
+                .await
+            }
+            AiProvider::OpenAI => {
+                let Ok(creds) = keychain::get_openai_credentials() else {
+                    return;
+                };
+                transcription::prewarm_openai(
+                    preferences::get_openai_transcribe_model(),
+                    &creds.api_key,
+                    &language_code,
+                    encoding,
+                    noise_reduction,
+                )
+                .await
+            }
+        };
+
+        if let Err(e) = result {
+            warn!("Failed to prepare transcription connection: {}", e);
+        }
+    });
+}
+
+/// Prepare the connection when the menu bar menu opens, if enabled in Settings
+pub(crate) fn prepare_connection_on_menu_open(
+    recording_state: &Arc<Mutex<Option<RecordingSession>>>,
+) {
+    if preferences::get_prewarm_connection() {
+        prepare_connection(recording_state);
+    }
+}
@@ -4,13 +4,17 @@
 //! to Azure OpenAI or OpenAI Realtime API and error handling.
 
 use crate::audio::AudioChunk;
-use crate::menubar;
-use crate::transcription::TranscriptionClient;
-use crate::transcription_window;
+use crate::preferences::AiProvider;
+use crate::provenance;
+use crate::transcription::{TranscribeModel, TranscriptionClient, TranscriptionError};
+use crate::ui_sink::SharedUi;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tracing::{error, info};
 
+use super::failover::Failover;
+use super::input_check;
+use super::phase;
 use super::RecordingSession;
 
 /// Provider-specific configuration for transcription
@@ -18,10 +22,16 @@ pub(super) enum TranscriptionProviderConfig {
     Azure {
         endpoint: String,
         deployment: String,
+        api_version: String,
         api_key: String,
     },
     OpenAI {
         api_key: String,
+        model: TranscribeModel,
+    },
+    /// Replay a fixture instead of connecting (demo session, development and tests)
+    Mock {
+        fixture: crate::transcription::MockFixture,
     },
 }
 
@@ -30,36 +40,69 @@ pub(super) struct TranscriptionTaskConfig {
     pub transcription_client: TranscriptionClient,
     pub provider_config: TranscriptionProviderConfig,
     pub audio_rx: mpsc::Receiver<AudioChunk>,
+    /// Where to continue if the provider fails, when failover is enabled
+    pub failover: Option<Failover>,
     pub recording_state: Arc<Mutex<Option<RecordingSession>>>,
+    pub ui: SharedUi,
 }
 
-/// Run the transcription task with error handling
-#[tracing::instrument(skip(config))]
-async fn run_transcription_task(config: TranscriptionTaskConfig) {
-    // Start transcription via the appropriate provider
-    let transcription_result = match &config.provider_config {
+/// Start transcription via the provider in `provider_config`
+pub(super) async fn start_provider(
+    client: &TranscriptionClient,
+    provider_config: &TranscriptionProviderConfig,
+    audio_rx: mpsc::Receiver<AudioChunk>,
+) -> Result<(), TranscriptionError> {
+    match provider_config {
         TranscriptionProviderConfig::Azure {
             endpoint,
             deployment,
+            api_version,
             api_key,
         } => {
             info!("Starting Azure OpenAI Realtime transcription");
-            config
-                .transcription_client
-                .start_azure(endpoint, deployment, api_key, config.audio_rx)
+            provenance::transcribed_with(AiProvider::Azure, deployment);
+            client
+                .start_azure(endpoint, deployment, api_version, api_key, audio_rx)
                 .await
         }
-        TranscriptionProviderConfig::OpenAI { api_key } => {
+        TranscriptionProviderConfig::OpenAI { api_key, model } => {
             info!("Starting OpenAI Realtime transcription");
-            config
-                .transcription_client
-                .start_openai(api_key, config.audio_rx)
+            provenance::transcribed_with(AiProvider::OpenAI, model.api_name());
+            client.start_openai(*model, api_key, audio_rx).await
+        }
+        TranscriptionProviderConfig::Mock { fixture } => {
+            info!("Starting mock transcription replay");
+            client.start_mock(fixture, audio_rx).await
+        }
+    }
+}
+
+/// Run the transcription task with error handling
+#[tracing::instrument(skip(config))]
+async fn run_transcription_task(config: TranscriptionTaskConfig) {
+    // Start transcription via the appropriate provider
+    let transcription_result = match config.failover {
+        Some(failover) => {
+            failover
+                .run(
+                    &config.transcription_client,
+                    &config.provider_config,
+                    config.audio_rx,
+                )
                 .await
         }
+        None => {
+            start_provider(
+                &config.transcription_client,
+                &config.provider_config,
+                config.audio_rx,
+            )
+            .await
+        }
     };
 
     // Get final transcript and check if manually stopped
-    let session = config.transcription_client.session();
+    let session = config.transcription_client.settled_session().await;
     let transcript = session.full_transcript();
     let manually_stopped = session.manually_stopped;
 
@@ -73,35 +116,35 @@ async fn run_transcription_task(config: TranscriptionTaskConfig) {
                 recording_session.audio_handle.stop();
             }
         }
+        input_check::stop();
 
         // Update UI to show connection failed
         if !manually_stopped {
-            menubar::MenuBar::set_recording(false);
-            transcription_window::TranscriptionWindow::set_recording_state(false);
+            config.ui.set_recording(false);
 
             let error_message = format!("{}", e);
             if transcript.trim().is_empty() {
-                transcription_window::TranscriptionWindow::update_live_text(
-                    &format!("Connection failed\n\n{}", error_message),
-                    None,
-                );
+                config
+                    .ui
+                    .update_live_text(&format!("Connection failed\n\n{}", error_message), None);
             } else {
-                transcription_window::TranscriptionWindow::update_live_text(
+                config.ui.update_live_text(
                     &format!("{}\n\nConnection lost: {}", transcript, error_message),
                     None,
                 );
-                transcription_window::TranscriptionWindow::show_save_button(transcript.clone());
+                config.ui.show_save_button(transcript.clone());
             }
         }
     } else if !manually_stopped && !transcript.trim().is_empty() {
         // Show save button if NOT manually stopped and transcript has content
-        transcription_window::TranscriptionWindow::show_save_button(transcript);
+        config.ui.show_save_button(transcript);
     }
 
-    // Clear recording state
+    // Clear recording state, after which a new recording can start
     if let Ok(mut state) = config.recording_state.lock() {
         *state = None;
     }
+    phase::finished();
 }
 
 /// Spawn the transcription task
@@ -0,0 +1,141 @@
+//! "Vissper is recording" banner
+//!
+//! When turned on in Settings, a small red banner stays at the top of the
+//! screen, on every Space and above full-screen apps, for as long as a
+//! recording runs, so everyone in the room can see that they are recorded.
+//! It ignores the mouse, so it never gets in the way.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{
+    NSBackingStoreType, NSColor, NSFont, NSScreen, NSTextAlignment, NSTextField, NSWindow,
+    NSWindowStyleMask,
+};
+use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::debug;
+
+use crate::app_context;
+use crate::event_bus::AppEvent;
+use crate::localization::tr;
+use crate::preferences;
+
+/// Banner size in points
+const BANNER_WIDTH: f64 = 210.0;
+const BANNER_HEIGHT: f64 = 26.0;
+
+/// Gap between the menu bar and the banner, in points
+const TOP_GAP: f64 = 6.0;
+
+/// NSStatusWindowLevel: above normal and floating windows
+const BANNER_WINDOW_LEVEL: isize = 25;
+
+/// NSWindowCollectionBehaviorCanJoinAllSpaces | Stationary | FullScreenAuxiliary
+const ON_ALL_SPACES: usize = (1 << 0) | (1 << 4) | (1 << 8);
+
+/// Whether a recording is in progress, so turning the banner on in
+/// Settings shows it right away
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// The banner window, set while the banner is showing (main thread only).
+fn banner_state() -> Option<&'static RefCell<Option<BannerState>>> {
+    Some(&app_context::windows()?.recording_banner)
+}
+
+/// Inner state holding the banner window reference.
+pub(crate) struct BannerState {
+    window: Retained<NSWindow>,
+}
+
+/// Show the banner when recording starts and hide it when it stops.
+pub(crate) fn handle_event(event: &AppEvent) {
+    match event {
+        AppEvent::RecordingStarted => RECORDING.store(true, Ordering::SeqCst),
+        AppEvent::RecordingStopped => RECORDING.store(false, Ordering::SeqCst),
+        _ => return,
+    }
+    refresh();
+}
+
+/// Show or hide the banner to match the recording state and the setting.
+///
+/// Safe to call from any thread.
+pub(crate) fn refresh() {
+    dispatch::Queue::main().exec_async(|| {
+        let Some(mtm) = MainThreadMarker::new() else {
+            return;
+        };
+        let Some(Ok(mut guard)) = banner_state().map(RefCell::try_borrow_mut) else {
+            return;
+        };
+
+        let show = RECORDING.load(Ordering::SeqCst) && preferences::get_recording_banner();
+        if show && guard.is_none() {
+            *guard = create_banner(mtm).map(|window| BannerState { window });
+            debug!("Recording banner shown");
+        } else if !show {
+            if let Some(state) = guard.take() {
+                state.window.close();
+                debug!("Recording banner hidden");
+            }
+        }
+    });
+}
+
+/// Create and show the banner at the top center of the main screen.
+fn create_banner(mtm: MainThreadMarker) -> Option<Retained<NSWindow>> {
+    let screen = NSScreen::mainScreen(mtm)?.visibleFrame();
+    let frame = NSRect::new(
+        NSPoint::new(
+            screen.origin.x + (screen.size.width - BANNER_WIDTH) / 2.0,
+            screen.origin.y + screen.size.height - BANNER_HEIGHT - TOP_GAP,
+        ),
+        NSSize::new(BANNER_WIDTH, BANNER_HEIGHT),
+    );
+
+    // SAFETY: NSWindow and NSTextField creation and configuration on the
+    // main thread, with a valid frame, style mask and backing store type
+    unsafe {
+        let window = NSWindow::initWithContentRect_styleMask_backing_defer(
+            mtm.alloc(),
+            frame,
+            NSWindowStyleMask::Borderless,
+            NSBackingStoreType::NSBackingStoreBuffered,
+            false,
+        );
+        window.setReleasedWhenClosed(false);
+        window.setOpaque(false);
+        let red = NSColor::colorWithRed_green_blue_alpha(0.85, 0.15, 0.15, 0.92);
+        window.setBackgroundColor(Some(&red));
+        window.setLevel(BANNER_WINDOW_LEVEL);
+        let _: () = msg_send![&window, setIgnoresMouseEvents: true];
+        let _: () = msg_send![&window, setHasShadow: true];
+        let _: () = msg_send![&window, setCollectionBehavior: ON_ALL_SPACES];
+        let _: () = msg_send![&window, setHidesOnDeactivate: false];
+
+        let label_frame = NSRect::new(
+            NSPoint::new(0.0, (BANNER_HEIGHT - 17.0) / 2.0),
+            NSSize::new(BANNER_WIDTH, 17.0),
+        );
+        let label: Retained<NSTextField> =
+            msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: label_frame];
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setStringValue(&NSString::from_str(&format!(
+            "● {}",
+            tr("overlay.recording_banner")
+        )));
+        label.setTextColor(Some(&NSColor::whiteColor()));
+        label.setFont(Some(&NSFont::boldSystemFontOfSize(13.0)));
+        label.setAlignment(NSTextAlignment::Center);
+
+        if let Some(content_view) = window.contentView() {
+            content_view.addSubview(&label);
+        }
+        window.orderFrontRegardless();
+        Some(window)
+    }
+}
@@ -1,7 +1,7 @@
 //! Region selection screenshot module
 //!
 //! Provides an interactive overlay for selecting a screen region
-//! to capture via screencapture -R x,y,w,h command.
+//! to capture natively (see `screenshot::capture_region_screenshot`).
 
 mod state;
 mod view;
@@ -13,13 +13,18 @@ use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{NSBackingStoreType, NSColor, NSWindow, NSWindowStyleMask};
 use objc2_foundation::{MainThreadMarker, NSOperationQueue};
 use objc2_foundation::{NSPoint, NSRect, NSSize};
+use std::cell::RefCell;
 use std::sync::{Arc, Mutex};
 use tracing::{debug, error, info};
 
+use crate::event_bus::{self, AppEvent};
 use crate::recording::RecordingSession;
-use crate::screenshot;
+use crate::transcription::SessionCommand;
+use crate::{screenshot, screenshot_blocklist};
 
-use state::{RegionSelectionState, MIN_SELECTION_SIZE, OVERLAY_WINDOW_LEVEL, REGION_STATE};
+pub(crate) use state::RegionSelectionState;
+
+use state::{region_state, MIN_SELECTION_SIZE, OVERLAY_WINDOW_LEVEL};
 use view::RegionSelectionView;
 
 /// Region selection manager
@@ -35,14 +40,6 @@ impl RegionSelection {
     pub(crate) fn start(recording_state: Arc<Mutex<Option<RecordingSession>>>) {
         debug!("Starting region selection");
 
-        // Check if already active
-        if let Ok(guard) = REGION_STATE.lock() {
-            if guard.is_some() {
-                debug!("Region selection already active, ignoring");
-                return;
-            }
-        }
-
         // If already on main thread, start directly
         if let Some(mtm) = MainThreadMarker::new() {
             Self::start_on_main_thread(mtm, recording_state);
@@ -68,6 +65,20 @@ impl RegionSelection {
         mtm: MainThreadMarker,
         recording_state: Arc<Mutex<Option<RecordingSession>>>,
     ) {
+        // Check if already active
+        if let Some(Ok(guard)) = region_state().map(RefCell::try_borrow) {
+            if guard.is_some() {
+                debug!("Region selection already active, ignoring");
+                return;
+            }
+        }
+
+        // Don't even show the overlay over an app that must not be captured
+        if let Some(app) = screenshot_blocklist::blocked_frontmost_app() {
+            screenshot_blocklist::warn_refused(&app);
+            return;
+        }
+
         info!("Creating region selection overlay");
 
         // Get main screen (simplified approach - works on primary monitor)
@@ -100,7 +111,7 @@ impl RegionSelection {
             recording_state,
         };
 
-        if let Ok(mut guard) = REGION_STATE.lock() {
+        if let Some(Ok(mut guard)) = region_state().map(RefCell::try_borrow_mut) {
             *guard = Some(state);
         }
 
@@ -183,7 +194,7 @@ impl RegionSelection {
 
     /// Remove keyboard monitor
     fn remove_keyboard_monitor() {
-        if let Ok(mut guard) = REGION_STATE.lock() {
+        if let Some(Ok(mut guard)) = region_state().map(RefCell::try_borrow_mut) {
             if let Some(ref mut state) = *guard {
                 if let Some(monitor) = state.event_monitor.take() {
                     unsafe {
@@ -199,7 +210,7 @@ impl RegionSelection {
 
     /// Update selection origin when mouse down
     pub(super) fn set_selection_origin(point: NSPoint) {
-        if let Ok(mut guard) = REGION_STATE.lock() {
+        if let Some(Ok(mut guard)) = region_state().map(RefCell::try_borrow_mut) {
             if let Some(ref mut state) = *guard {
                 state.selection_origin = Some(point);
                 state.current_rect = None;
@@ -209,7 +220,7 @@ impl RegionSelection {
 
     /// Update current selection rectangle during drag
     pub(super) fn update_selection(current_point: NSPoint) {
-        if let Ok(mut guard) = REGION_STATE.lock() {
+        if let Some(Ok(mut guard)) = region_state().map(RefCell::try_borrow_mut) {
             if let Some(ref mut state) = *guard {
                 if let Some(origin) = state.selection_origin {
                     // Calculate rectangle (handle negative width/height)
@@ -226,7 +237,7 @@ impl RegionSelection {
 
     /// Get current selection rectangle (if any)
     pub(super) fn get_current_rect() -> Option<NSRect> {
-        if let Ok(guard) = REGION_STATE.lock() {
+        if let Some(Ok(guard)) = region_state().map(RefCell::try_borrow) {
             if let Some(ref state) = *guard {
                 return state.current_rect;
             }
@@ -236,7 +247,7 @@ impl RegionSelection {
 
     /// Get total screen height for coordinate conversion
     fn get_total_screen_height() -> f64 {
-        if let Ok(guard) = REGION_STATE.lock() {
+        if let Some(Ok(guard)) = region_state().map(RefCell::try_borrow) {
             if let Some(ref state) = *guard {
                 return state.total_screen_height;
             }
@@ -246,7 +257,7 @@ impl RegionSelection {
 
     /// Get recording state for inserting screenshot reference
     fn get_recording_state() -> Option<Arc<Mutex<Option<RecordingSession>>>> {
-        if let Ok(guard) = REGION_STATE.lock() {
+        if let Some(Ok(guard)) = region_state().map(RefCell::try_borrow) {
             if let Some(ref state) = *guard {
                 return Some(state.recording_state.clone());
             }
@@ -278,7 +289,7 @@ impl RegionSelection {
             return;
         }
 
-        // Convert macOS coordinates (bottom-left origin) to screencapture (top-left origin)
+        // Convert macOS coordinates (bottom-left origin) to Core Graphics (top-left origin)
         let x = rect.origin.x;
         let y = screen_height - rect.origin.y - rect.size.height;
         let width = rect.size.width;
@@ -301,17 +312,19 @@ impl RegionSelection {
                     info!("Region screenshot captured: {}", filename);
 
                     // Show flash effect after capture
-                    crate::screenshot_flash::ScreenshotFlash::show();
+                    event_bus::publish(AppEvent::ScreenshotCaptured {
+                        filename: filename.clone(),
+                    });
 
                     // Insert screenshot reference into transcript if recording
                     if let Some(recording_state) = recording_state {
                         if let Ok(state) = recording_state.lock() {
                             if let Some(ref session) = *state {
-                                if let Ok(mut session_data) = session.session_data.lock() {
-                                    let relative_path = format!("screenshots/{}", filename);
-                                    session_data.insert_screenshot(&relative_path);
-                                    info!("Screenshot reference inserted into transcript");
-                                }
+                                let relative_path = format!("screenshots/{}", filename);
+                                session
+                                    .session_data
+                                    .send(SessionCommand::InsertScreenshot(relative_path));
+                                info!("Screenshot reference inserted into transcript");
                             }
                         }
                     }
@@ -331,7 +344,7 @@ impl RegionSelection {
         Self::remove_keyboard_monitor();
 
         // Close all windows - use orderOut first to immediately hide
-        if let Ok(mut guard) = REGION_STATE.lock() {
+        if let Some(Ok(mut guard)) = region_state().map(RefCell::try_borrow_mut) {
             if let Some(mut state) = guard.take() {
                 for window in state.windows.drain(..) {
                     // orderOut immediately removes window from screen (no animation)
@@ -345,7 +358,7 @@ impl RegionSelection {
 
     /// Request redraw of all overlay windows
     pub(super) fn request_redraw() {
-        if let Ok(guard) = REGION_STATE.lock() {
+        if let Some(Ok(guard)) = region_state().map(RefCell::try_borrow) {
             if let Some(ref state) = *guard {
                 for window in &state.windows {
                     if let Some(content_view) = window.contentView() {
@@ -2,7 +2,9 @@
 //!
 //! Defines configuration options for transcript polishing using Azure OpenAI.
 
+use crate::languages;
 use crate::preferences;
+use crate::topics;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for transcript polishing
@@ -10,22 +12,45 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct PolishConfig {
     /// Reasoning effort level (e.g., "none", "low", "medium", "high")
     pub(crate) reasoning_effort: Option<String>,
-    /// Prompt type to use ("default" or "live_meeting")
+    /// Prompt type to use ("default", "live_meeting", "meeting_topics",
+    /// "follow_up_email" or "custom")
     pub(crate) prompt_type: Option<String>,
+    /// Index of the custom output mode whose prompt is used, for "custom"
+    #[serde(default)]
+    pub(crate) custom_mode: Option<usize>,
     /// Language code for output (e.g., "en", "no", "da")
     pub(crate) language_code: String,
 }
 
+/// System prompt template for a follow-up email recapping the recording,
+/// shared by the Azure OpenAI and OpenAI clients.
+/// Use `{language}` placeholder for the target language.
+pub(crate) const FOLLOW_UP_EMAIL_PROMPT_TEMPLATE: &str = r#"You are an expert meeting assistant. Your task is to turn the following meeting transcript into a ready-to-send follow-up email to the participants. The output MUST be in {language}. Do not translate to any other language.
+
+Write the email in a friendly, professional tone with the following parts:
+
+Subject: A short subject line on the first line, starting with "Subject:".
+
+Greeting: A brief greeting addressed to the participants. Use names only if they are mentioned in the transcript; otherwise use a general greeting such as "Hi all,".
+
+Summary: A short paragraph thanking the participants and recapping what was discussed and decided.
+
+Action items: A bullet list of the tasks, assignments and commitments that were made, each with the person responsible and any deadline if mentioned. If there are none, leave this part out.
+
+Next steps: One or two sentences on follow-ups or the next meeting, if any were mentioned.
+
+Sign-off: A short closing such as "Best regards," followed by "[Your name]" as a placeholder.
+
+Only include information from the transcript; do not invent facts, names, dates or commitments.
+
+The transcript may contain screenshot references in markdown image format like `![Screenshot](screenshots/filename.png)`, timestamped markers like `=== MARKER 14:32 ===`, and connection notes like `[connection lost 14:02–14:03, audio may be missing]`. Leave these out of the email. Typed notes like `> Note: Bob joined late` add context that is not in the audio; use them but do not quote them.
+
+Return only the email text without any additional commentary."#;
+
 /// Convert a language code to its full name for use in prompts
 pub(crate) fn language_code_to_name(code: &str) -> &str {
-    match code {
-        "en" => "English",
-        "no" => "Norwegian",
-        "da" => "Danish",
-        "fi" => "Finnish",
-        "de" => "German",
-        _ => code, // Return code itself for unknown languages
-    }
+    // Return code itself for unknown languages
+    languages::find(code).map_or(code, |language| language.name)
 }
 
 impl PolishConfig {
@@ -36,18 +61,73 @@ impl PolishConfig {
             reasoning_effort: Some("none".to_string()),
             prompt_type: None,
             language_code: preferences::get_language_code(),
+            custom_mode: None,
         }
     }
 
     /// Create a config for live meeting recording
-    /// Uses "low" reasoning for comprehensive meeting analysis
+    /// Uses "low" reasoning for comprehensive meeting analysis, split into
+    /// topic sections when enabled in Settings
     pub fn live_meeting() -> Self {
+        Self::live_meeting_with(topics::is_enabled())
+    }
+
+    /// Create a config for live meeting recording, split into topic sections
+    /// when `split_topics` is set
+    pub fn live_meeting_with(split_topics: bool) -> Self {
+        let prompt_type = if split_topics {
+            "meeting_topics"
+        } else {
+            "live_meeting"
+        };
+        Self {
+            reasoning_effort: Some("low".to_string()),
+            prompt_type: Some(prompt_type.to_string()),
+            language_code: preferences::get_language_code(),
+            custom_mode: None,
+        }
+    }
+
+    /// Create a config for a follow-up email recapping the recording
+    /// Uses "low" reasoning to pick out the summary and action items
+    pub fn follow_up_email() -> Self {
         Self {
             reasoning_effort: Some("low".to_string()),
-            prompt_type: Some("live_meeting".to_string()),
+            prompt_type: Some("follow_up_email".to_string()),
             language_code: preferences::get_language_code(),
+            custom_mode: None,
         }
     }
+
+    /// Create a config for a custom output mode defined in Settings
+    /// Uses "low" reasoning since custom prompts may ask for anything
+    pub fn custom(index: usize) -> Self {
+        Self {
+            reasoning_effort: Some("low".to_string()),
+            prompt_type: Some("custom".to_string()),
+            language_code: preferences::get_language_code(),
+            custom_mode: Some(index),
+        }
+    }
+
+    /// Whether this config produces meeting notes
+    pub fn is_meeting_notes(&self) -> bool {
+        matches!(
+            self.prompt_type.as_deref(),
+            Some("live_meeting" | "meeting_topics")
+        )
+    }
+
+    /// Whether this config produces a follow-up email
+    pub fn is_follow_up_email(&self) -> bool {
+        self.prompt_type.as_deref() == Some("follow_up_email")
+    }
+
+    /// Whether the transcript is split into topic sections, which needs
+    /// time cues in the transcript sent for polishing
+    pub fn splits_topics(&self) -> bool {
+        self.prompt_type.as_deref() == Some("meeting_topics")
+    }
 }
 
 #[cfg(test)]
@@ -64,10 +144,36 @@ mod tests {
 
     #[test]
     fn test_polish_config_live_meeting() {
-        let config = PolishConfig::live_meeting();
+        let config = PolishConfig::live_meeting_with(false);
         assert_eq!(config.reasoning_effort, Some("low".to_string()));
-        assert_eq!(config.prompt_type, Some("live_meeting".to_string()));
+        assert_eq!(config.prompt_type.as_deref(), Some("live_meeting"));
+        assert!(config.is_meeting_notes());
+        assert!(!config.splits_topics());
         assert!(!config.language_code.is_empty());
+
+        let config = PolishConfig::live_meeting_with(true);
+        assert_eq!(config.prompt_type.as_deref(), Some("meeting_topics"));
+        assert!(config.is_meeting_notes());
+        assert!(config.splits_topics());
+    }
+
+    #[test]
+    fn test_polish_config_follow_up_email() {
+        let config = PolishConfig::follow_up_email();
+        assert!(config.is_follow_up_email());
+        assert!(!config.is_meeting_notes());
+        assert!(!config.splits_topics());
+        assert!(!PolishConfig::basic_polish().is_follow_up_email());
+    }
+
+    #[test]
+    fn test_polish_config_custom() {
+        let config = PolishConfig::custom(1);
+        assert_eq!(config.prompt_type.as_deref(), Some("custom"));
+        assert_eq!(config.custom_mode, Some(1));
+        assert!(!config.is_meeting_notes());
+        assert!(!config.is_follow_up_email());
+        assert_eq!(PolishConfig::basic_polish().custom_mode, None);
     }
 
     #[test]
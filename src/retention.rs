@@ -0,0 +1,423 @@
+//! Retention policy for saved transcripts and screenshots
+//!
+//! Deletes files older than a configured number of days and/or the oldest
+//! files once the folders exceed a disk usage cap. Cleanup runs in the
+//! background at startup and once a day, and on demand from the Privacy tab
+//! of Settings. Both limits are off by default. Each workspace has its own
+//! limits, applied to its own folders.
+
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+
+use crate::workspaces::Workspace;
+use crate::{preferences, storage};
+
+/// Time between background cleanups
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Files modified more recently than this are never deleted, so a session
+/// in progress can't lose its screenshots to the disk usage cap
+const GRACE_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// Extensions of files Vissper writes to the transcript and screenshot folders
+const MANAGED_EXTENSIONS: [&str; 7] = ["md", "txt", "pdf", "png", "jpg", "heic", "enc"];
+
+/// Name prefixes of those files, as given to `storage::timestamped_name`
+const MANAGED_PREFIXES: [&str; 2] = ["transcript-", "screenshot-"];
+
+/// Age limits offered in Settings (`None` keeps files forever)
+pub(crate) const AGE_PRESETS: [Option<u32>; 4] = [None, Some(30), Some(90), Some(365)];
+
+/// Disk usage caps offered in Settings, in megabytes (`None` means no cap)
+pub(crate) const SIZE_PRESETS_MB: [Option<u64>; 4] =
+    [None, Some(1024), Some(5 * 1024), Some(10 * 1024)];
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// When the most recent cleanup ran and what it removed
+type LastCleanup = Option<(DateTime<Local>, CleanupSummary)>;
+
+/// Result of the most recent cleanup, shown in Settings
+static LAST_CLEANUP: Lazy<Mutex<LastCleanup>> = Lazy::new(|| Mutex::new(None));
+
+/// Limits applied by a cleanup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct RetentionPolicy {
+    pub(crate) max_age_days: Option<u32>,
+    pub(crate) max_total_mb: Option<u64>,
+}
+
+impl RetentionPolicy {
+    /// The policy configured in Settings for `workspace`, or for Default
+    /// when None
+    fn of(workspace: Option<&Workspace>) -> Self {
+        Self {
+            max_age_days: preferences::get_retention_days_in(workspace),
+            max_total_mb: preferences::get_retention_max_mb_in(workspace),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.max_age_days.is_some() || self.max_total_mb.is_some()
+    }
+}
+
+/// What a cleanup removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct CleanupSummary {
+    pub(crate) files_removed: usize,
+    pub(crate) bytes_reclaimed: u64,
+}
+
+impl CleanupSummary {
+    /// Short description, e.g. "Removed 3 files, freed 12.4 MB"
+    pub(crate) fn describe(&self) -> String {
+        match self.files_removed {
+            0 => "Nothing to remove".to_string(),
+            1 => format!(
+                "Removed 1 file, freed {}",
+                format_bytes(self.bytes_reclaimed)
+            ),
+            n => format!(
+                "Removed {} files, freed {}",
+                n,
+                format_bytes(self.bytes_reclaimed)
+            ),
+        }
+    }
+}
+
+/// A file considered for cleanup
+#[derive(Debug, Clone)]
+struct FileEntry {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Start the daily background cleanup.
+pub(crate) fn init(runtime: tokio::runtime::Handle) {
+    runtime.spawn(async {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let any_active = scopes()
+                .iter()
+                .any(|workspace| RetentionPolicy::of(workspace.as_ref()).is_active());
+            if !any_active {
+                continue;
+            }
+            match tokio::task::spawn_blocking(run_cleanup).await {
+                Ok(Ok(summary)) => info!("Background cleanup: {}", summary.describe()),
+                Ok(Err(e)) => error!("Background cleanup failed: {}", e),
+                Err(e) => error!("Background cleanup task panicked: {}", e),
+            }
+        }
+    });
+}
+
+/// Apply each workspace's policy now and remember the result.
+///
+/// A folder shared by several workspaces follows the first one's policy,
+/// Default first.
+pub(crate) fn run_cleanup() -> std::io::Result<CleanupSummary> {
+    let mut summary = CleanupSummary::default();
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    for workspace in scopes() {
+        let dirs: Vec<PathBuf> = folders(workspace.as_ref())
+            .into_iter()
+            .filter(|dir| seen.insert(dir.clone()))
+            .collect();
+        let policy = RetentionPolicy::of(workspace.as_ref());
+        if !policy.is_active() {
+            continue;
+        }
+        let files = managed_files(dirs)?;
+        for file in select_expired(&files, &policy, SystemTime::now()) {
+            match fs::remove_file(&file.path) {
+                Ok(()) => {
+                    summary.files_removed += 1;
+                    summary.bytes_reclaimed += file.size;
+                }
+                Err(e) => warn!("Failed to remove {:?}: {}", file.path, e),
+            }
+        }
+    }
+
+    if let Ok(mut last) = LAST_CLEANUP.lock() {
+        *last = Some((Local::now(), summary));
+    }
+    Ok(summary)
+}
+
+/// Description of the most recent cleanup for Settings
+pub(crate) fn last_cleanup_description() -> String {
+    let last = LAST_CLEANUP.lock().ok().and_then(|last| *last);
+    match last {
+        Some((at, summary)) => format!(
+            "Last cleanup {}: {}",
+            at.format("%Y-%m-%d %H:%M"),
+            summary.describe()
+        ),
+        None => "No cleanup has run yet".to_string(),
+    }
+}
+
+/// Index of `value` in `presets`, or -1 (no segment selected) for a custom value
+pub(crate) fn preset_index<T: PartialEq>(presets: &[Option<T>], value: &Option<T>) -> isize {
+    presets
+        .iter()
+        .position(|preset| preset == value)
+        .map_or(-1, |i| i as isize)
+}
+
+/// Default (None) followed by every other workspace
+fn scopes() -> Vec<Option<Workspace>> {
+    std::iter::once(None)
+        .chain(preferences::get_workspaces().into_iter().map(Some))
+        .collect()
+}
+
+/// Existing transcript and screenshot folders of `workspace`
+fn folders(workspace: Option<&Workspace>) -> Vec<PathBuf> {
+    [
+        storage::transcripts_dir_in(workspace),
+        preferences::get_screenshot_location_in(workspace)
+            .or_else(preferences::default_screenshot_location),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|dir| dir.is_dir())
+    .collect()
+}
+
+/// Transcript and screenshot files in `dirs`.
+///
+/// Only the top level of each folder is scanned, and only files named the
+/// way Vissper names them, so unrelated documents kept alongside are never
+/// touched.
+fn managed_files(dirs: Vec<PathBuf>) -> std::io::Result<Vec<FileEntry>> {
+    let mut files = Vec::new();
+    for dir in dirs {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if !is_managed(&path) {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            files.push(FileEntry {
+                path,
+                size: metadata.len(),
+                modified: metadata.modified()?,
+            });
+        }
+    }
+    Ok(files)
+}
+
+/// Whether `path` is a transcript or screenshot Vissper wrote
+fn is_managed(path: &Path) -> bool {
+    let has_prefix = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            MANAGED_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        });
+    let has_extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| MANAGED_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+    has_prefix && has_extension
+}
+
+/// Files the policy removes: everything past the age limit, then the oldest
+/// remaining files until the total fits under the disk usage cap.
+fn select_expired(
+    files: &[FileEntry],
+    policy: &RetentionPolicy,
+    now: SystemTime,
+) -> Vec<FileEntry> {
+    let age = |file: &FileEntry| now.duration_since(file.modified).unwrap_or_default();
+
+    let mut candidates: Vec<&FileEntry> = files.iter().collect();
+    candidates.sort_by_key(|file| file.modified);
+
+    let mut expired = Vec::new();
+    let mut kept = Vec::new();
+    for file in candidates {
+        let too_old = policy
+            .max_age_days
+            .is_some_and(|days| age(file) > Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+        if too_old && age(file) > GRACE_PERIOD {
+            expired.push(file.clone());
+        } else {
+            kept.push(file);
+        }
+    }
+
+    if let Some(max_mb) = policy.max_total_mb {
+        let cap = max_mb.saturating_mul(BYTES_PER_MB);
+        let mut total: u64 = kept.iter().map(|file| file.size).sum();
+        // Oldest first, since `kept` is still sorted by modification time
+        for file in kept {
+            if total <= cap {
+                break;
+            }
+            if age(file) > GRACE_PERIOD {
+                total -= file.size;
+                expired.push(file.clone());
+            }
+        }
+    }
+
+    expired
+}
+
+/// Human-readable size, e.g. "512 KB", "12.4 MB", "1.2 GB"
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes < KB * KB {
+        format!("{:.0} KB", (bytes / KB).ceil())
+    } else if bytes < KB * KB * KB {
+        format!("{:.1} MB", bytes / (KB * KB))
+    } else {
+        format!("{:.1} GB", bytes / (KB * KB * KB))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn file(name: &str, size: u64, age: Duration, now: SystemTime) -> FileEntry {
+        FileEntry {
+            path: PathBuf::from(name),
+            size,
+            modified: now - age,
+        }
+    }
+
+    fn names(files: &[FileEntry]) -> Vec<&str> {
+        files.iter().map(|f| f.path.to_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_age_limit_removes_only_old_files() {
+        let now = SystemTime::now();
+        let files = vec![
+            file("old.md", 10, DAY * 40, now),
+            file("recent.md", 10, DAY * 5, now),
+        ];
+        let policy = RetentionPolicy {
+            max_age_days: Some(30),
+            max_total_mb: None,
+        };
+
+        assert_eq!(names(&select_expired(&files, &policy, now)), vec!["old.md"]);
+        assert!(select_expired(&files, &RetentionPolicy::default(), now).is_empty());
+    }
+
+    #[test]
+    fn test_size_cap_removes_oldest_first() {
+        let now = SystemTime::now();
+        let files = vec![
+            file("newest.png", 400 * BYTES_PER_MB, DAY, now),
+            file("oldest.png", 400 * BYTES_PER_MB, DAY * 3, now),
+            file("middle.png", 400 * BYTES_PER_MB, DAY * 2, now),
+        ];
+        let policy = RetentionPolicy {
+            max_age_days: None,
+            max_total_mb: Some(1024),
+        };
+
+        assert_eq!(
+            names(&select_expired(&files, &policy, now)),
+            vec!["oldest.png"]
+        );
+    }
+
+    #[test]
+    fn test_recent_files_are_never_removed() {
+        let now = SystemTime::now();
+        let files = vec![file(
+            "live.png",
+            2048 * BYTES_PER_MB,
+            Duration::from_secs(60),
+            now,
+        )];
+        let policy = RetentionPolicy {
+            max_age_days: Some(0),
+            max_total_mb: Some(1024),
+        };
+
+        assert!(select_expired(&files, &policy, now).is_empty());
+    }
+
+    #[test]
+    fn test_only_vissper_files_are_managed() {
+        let dir =
+            std::env::temp_dir().join(format!("vissper-retention-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("transcript-2025-01-02-10-00-00.md"), "").unwrap();
+        fs::write(dir.join("screenshot-2025-01-02-10-01-00.png"), "").unwrap();
+        fs::write(dir.join("transcript-2025-01-02-10-00-00 2.md.enc"), "").unwrap();
+        fs::write(dir.join("notes.md"), "").unwrap();
+        fs::write(dir.join("transcript-draft.docx"), "").unwrap();
+
+        let files = managed_files(vec![dir.clone()]).unwrap();
+        let mut managed: Vec<String> = files
+            .iter()
+            .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        managed.sort();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            managed,
+            vec![
+                "screenshot-2025-01-02-10-01-00.png",
+                "transcript-2025-01-02-10-00-00 2.md.enc",
+                "transcript-2025-01-02-10-00-00.md",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summary_and_sizes() {
+        assert_eq!(format_bytes(512 * 1024), "512 KB");
+        assert_eq!(
+            format_bytes(12 * BYTES_PER_MB + BYTES_PER_MB / 2),
+            "12.5 MB"
+        );
+        assert_eq!(format_bytes(3 * 1024 * BYTES_PER_MB), "3.0 GB");
+
+        let summary = CleanupSummary {
+            files_removed: 2,
+            bytes_reclaimed: 3 * BYTES_PER_MB,
+        };
+        assert_eq!(summary.describe(), "Removed 2 files, freed 3.0 MB");
+        assert_eq!(CleanupSummary::default().describe(), "Nothing to remove");
+    }
+
+    #[test]
+    fn test_preset_index() {
+        assert_eq!(preset_index(&AGE_PRESETS, &None), 0);
+        assert_eq!(preset_index(&AGE_PRESETS, &Some(90)), 2);
+        assert_eq!(preset_index(&AGE_PRESETS, &Some(60)), -1);
+    }
+}
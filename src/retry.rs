@@ -0,0 +1,193 @@
+//! Rate-limit retry helpers shared by the polish clients
+//!
+//! Both Azure OpenAI and OpenAI answer throttled requests with HTTP 429 and a
+//! hint of how long to wait, either in the standard `Retry-After` header
+//! (seconds or an HTTP date) or in Azure's `retry-after-ms` header.
+
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// HTTP status code for rate-limited requests.
+pub(crate) const TOO_MANY_REQUESTS: u16 = 429;
+
+/// Upper bound on how long a single rate-limit wait may last.
+pub(crate) const MAX_RATE_LIMIT_DELAY: Duration = Duration::from_secs(60);
+
+/// Upper bound on the combined rate-limit waits of one request.
+///
+/// Kept well below the polish timeout so the attempt after the last wait
+/// still has time to finish.
+pub(crate) const MAX_TOTAL_RATE_LIMIT_DELAY: Duration = Duration::from_secs(60);
+
+/// Callback for rate-limit waits.
+///
+/// Called with `Some(delay)` before sleeping on a rate limit and with `None`
+/// when the client sends its next attempt.
+pub(crate) type RetryNotice = Arc<dyn Fn(Option<Duration>) + Send + Sync>;
+
+/// Parse the server's retry hint from the `retry-after-ms` and `Retry-After` header values.
+///
+/// `retry-after-ms` takes precedence since it is more precise.
+pub(crate) fn parse_retry_after(
+    retry_after: Option<&str>,
+    retry_after_ms: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    if let Some(ms) = retry_after_ms.and_then(|v| v.trim().parse::<f64>().ok()) {
+        if ms.is_finite() && ms >= 0.0 {
+            return Some(Duration::from_millis(ms as u64));
+        }
+    }
+
+    let value = retry_after?.trim();
+    if let Ok(secs) = value.parse::<f64>() {
+        if secs.is_finite() && secs >= 0.0 {
+            return Some(Duration::from_secs_f64(secs));
+        }
+        return None;
+    }
+
+    // HTTP-date form, e.g. "Wed, 21 Oct 2015 07:28:00 GMT"
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&Utc) - now;
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Delay before retrying a rate-limited request.
+///
+/// Honors the server hint when present, never waits less than the current
+/// exponential backoff step, and caps the wait at [`MAX_RATE_LIMIT_DELAY`].
+pub(crate) fn rate_limit_delay(server_hint: Option<Duration>, backoff: Duration) -> Duration {
+    server_hint
+        .map_or(backoff, |hint| hint.max(backoff))
+        .min(MAX_RATE_LIMIT_DELAY)
+}
+
+/// Delay before the next retry of a request that has already waited `waited`
+/// on rate limits, or `None` once that would exceed [`MAX_TOTAL_RATE_LIMIT_DELAY`].
+pub(crate) fn next_rate_limit_delay(
+    server_hint: Option<Duration>,
+    backoff: Duration,
+    waited: Duration,
+) -> Option<Duration> {
+    let delay = rate_limit_delay(server_hint, backoff);
+    (waited + delay <= MAX_TOTAL_RATE_LIMIT_DELAY).then_some(delay)
+}
+
+/// Whether a 429 body indicates exhausted quota rather than temporary throttling.
+///
+/// Retrying cannot help when the account is out of credits.
+pub(crate) fn is_quota_exhausted(body: &str) -> bool {
+    body.contains("insufficient_quota")
+}
+
+/// Format the user-facing rate-limit message, e.g. "Rate limited, retrying in 20s…".
+pub(crate) fn rate_limit_message(delay: Duration) -> String {
+    let secs = delay.as_secs_f64().ceil() as u64;
+    format!("Rate limited, retrying in {}s…", secs.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 5, 23, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        assert_eq!(
+            parse_retry_after(Some("20"), None, now()),
+            Some(Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_ms_takes_precedence() {
+        assert_eq!(
+            parse_retry_after(Some("20"), Some("1500"), now()),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        assert_eq!(
+            parse_retry_after(Some("Fri, 23 May 2025 12:00:30 GMT"), None, now()),
+            Some(Duration::from_secs(30))
+        );
+        // Dates in the past mean "retry now"
+        assert_eq!(
+            parse_retry_after(Some("Fri, 23 May 2025 11:00:00 GMT"), None, now()),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_invalid() {
+        assert_eq!(parse_retry_after(None, None, now()), None);
+        assert_eq!(parse_retry_after(Some("soon"), None, now()), None);
+        assert_eq!(parse_retry_after(Some("-5"), None, now()), None);
+    }
+
+    #[test]
+    fn test_rate_limit_delay() {
+        let backoff = Duration::from_secs(2);
+        assert_eq!(rate_limit_delay(None, backoff), backoff);
+        assert_eq!(
+            rate_limit_delay(Some(Duration::from_secs(20)), backoff),
+            Duration::from_secs(20)
+        );
+        assert_eq!(
+            rate_limit_delay(Some(Duration::from_millis(100)), backoff),
+            backoff
+        );
+        assert_eq!(
+            rate_limit_delay(Some(Duration::from_secs(600)), backoff),
+            MAX_RATE_LIMIT_DELAY
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_waits_stay_within_budget() {
+        let backoff = Duration::from_secs(2);
+        let hint = Some(Duration::from_secs(25));
+        assert_eq!(
+            next_rate_limit_delay(hint, backoff, Duration::ZERO),
+            Some(Duration::from_secs(25))
+        );
+        assert_eq!(
+            next_rate_limit_delay(hint, backoff, Duration::from_secs(25)),
+            Some(Duration::from_secs(25))
+        );
+        assert_eq!(
+            next_rate_limit_delay(hint, backoff, Duration::from_secs(50)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_message() {
+        assert_eq!(
+            rate_limit_message(Duration::from_secs(20)),
+            "Rate limited, retrying in 20s…"
+        );
+        assert_eq!(
+            rate_limit_message(Duration::from_millis(200)),
+            "Rate limited, retrying in 1s…"
+        );
+    }
+
+    #[test]
+    fn test_is_quota_exhausted() {
+        assert!(is_quota_exhausted(
+            r#"{"error":{"code":"insufficient_quota"}}"#
+        ));
+        assert!(!is_quota_exhausted(
+            r#"{"error":{"code":"rate_limit_exceeded"}}"#
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Markdown to HTML for rich text on the clipboard
+//!
+//! Polished output is Markdown. Copied as plain text only, Mail, Pages and
+//! Outlook paste it with its `#` and `-` symbols, so an HTML version is put on
+//! the clipboard next to it. Only what the polish prompts produce is
+//! converted: headings, bullet and numbered lists, bold text, rules and
+//! paragraphs.
+
+/// Kind of list being written
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum List {
+    Bullets,
+    Numbered,
+}
+
+impl List {
+    fn tag(self) -> &'static str {
+        match self {
+            List::Bullets => "ul",
+            List::Numbered => "ol",
+        }
+    }
+}
+
+/// HTML for `markdown`, one element per block
+pub(crate) fn markdown_to_html(markdown: &str) -> String {
+    let mut html = String::new();
+    let mut list: Option<List> = None;
+    let mut paragraph: Vec<String> = Vec::new();
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        let rule = is_rule(trimmed);
+        let item = if rule { None } else { list_item(trimmed) };
+
+        // Close the open paragraph or list when a different block starts
+        let starts_block =
+            trimmed.is_empty() || rule || item.is_some() || heading(trimmed).is_some();
+        if !paragraph.is_empty() && starts_block {
+            html.push_str(&format!("<p>{}</p>\n", paragraph.join("<br>\n")));
+            paragraph.clear();
+        }
+        if let Some(open) = list {
+            if item.map(|(kind, _)| kind) != Some(open) {
+                html.push_str(&format!("</{}>\n", open.tag()));
+                list = None;
+            }
+        }
+
+        if trimmed.is_empty() {
+            continue;
+        } else if let Some((level, text)) = heading(trimmed) {
+            html.push_str(&format!("<h{0}>{1}</h{0}>\n", level, inline(text)));
+        } else if rule {
+            html.push_str("<hr>\n");
+        } else if let Some((kind, text)) = item {
+            if list.is_none() {
+                html.push_str(&format!("<{}>\n", kind.tag()));
+                list = Some(kind);
+            }
+            html.push_str(&format!("<li>{}</li>\n", inline(text)));
+        } else {
+            paragraph.push(inline(trimmed));
+        }
+    }
+
+    if !paragraph.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", paragraph.join("<br>\n")));
+    }
+    if let Some(open) = list {
+        html.push_str(&format!("</{}>\n", open.tag()));
+    }
+    html
+}
+
+/// Level and text of a `#` to `######` heading
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| (level, text.trim()))
+}
+
+/// Kind and text of a `-`, `*`, `+` or `1.` list item
+fn list_item(line: &str) -> Option<(List, &str)> {
+    if let Some(text) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some((List::Bullets, text));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let text = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then_some((List::Numbered, text))
+}
+
+/// Whether `line` is a `---`, `***` or `___` rule
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| compact.chars().all(|c| c == *marker))
+}
+
+/// `text` escaped, with `**bold**` runs in `<strong>`
+fn inline(text: &str) -> String {
+    let mut html = String::new();
+    let mut remaining = text;
+    while let Some(start) = remaining.find("**") {
+        let Some(length) = remaining[start + 2..].find("**") else {
+            break;
+        };
+        html.push_str(&escape(&remaining[..start]));
+        let bold = &remaining[start + 2..start + 2 + length];
+        html.push_str(&format!("<strong>{}</strong>", escape(bold)));
+        remaining = &remaining[start + 4 + length..];
+    }
+    html.push_str(&escape(remaining));
+    html
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meeting_notes() {
+        let markdown = "# Weekly sync\n\n## Action Items\n- **Anna**: write the notes\n- Ben: book the room\n\n## Next Steps\n1. Ship the beta\n2. Collect feedback\n\nThanks, all.\nSee you Monday.";
+        assert_eq!(
+            markdown_to_html(markdown),
+            "<h1>Weekly sync</h1>\n\
+             <h2>Action Items</h2>\n\
+             <ul>\n<li><strong>Anna</strong>: write the notes</li>\n<li>Ben: book the room</li>\n</ul>\n\
+             <h2>Next Steps</h2>\n\
+             <ol>\n<li>Ship the beta</li>\n<li>Collect feedback</li>\n</ol>\n\
+             <p>Thanks, all.<br>\nSee you Monday.</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_escapes_and_leaves_stray_markers() {
+        assert_eq!(
+            markdown_to_html("R&D <team> asked for 2 ** 3\n---\n#hashtag"),
+            "<p>R&amp;D &lt;team&gt; asked for 2 ** 3</p>\n<hr>\n<p>#hashtag</p>\n"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Native screen capture
+//!
+//! Uses ScreenCaptureKit on macOS 14 and later and CGWindowList on older
+//! versions. Both capture the windows on screen in the current Space, without
+//! a shutter sound or a helper process, and can leave out given windows.
+
+use objc2_foundation::{NSPoint, NSRect};
+use std::ffi::c_void;
+
+use super::encode::CfOwned;
+use super::screencapturekit;
+use super::ScreenshotError;
+
+/// kCGWindowListOptionOnScreenOnly
+const ON_SCREEN_ONLY: u32 = 1 << 0;
+
+/// kCGNullWindowID
+const NULL_WINDOW_ID: u32 = 0;
+
+/// kCGWindowImageDefault
+const IMAGE_DEFAULT: u32 = 0;
+
+/// kCFNumberSInt64Type
+const NUMBER_SINT64: isize = 4;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    static kCGWindowNumber: *const c_void;
+    static kCGWindowOwnerPID: *const c_void;
+
+    fn CGMainDisplayID() -> u32;
+    fn CGDisplayBounds(display: u32) -> NSRect;
+    fn CGDisplayCopyDisplayMode(display: u32) -> *mut c_void;
+    fn CGDisplayModeGetWidth(mode: *mut c_void) -> usize;
+    fn CGDisplayModeGetPixelWidth(mode: *mut c_void) -> usize;
+    fn CGDisplayModeRelease(mode: *mut c_void);
+    fn CGWindowListCreate(option: u32, relative_to_window: u32) -> *mut c_void;
+    fn CGWindowListCopyWindowInfo(option: u32, relative_to_window: u32) -> *mut c_void;
+    fn CGWindowListCreateImageFromArray(
+        bounds: NSRect,
+        windows: *const c_void,
+        image_option: u32,
+    ) -> *mut c_void;
+}
+
+#[link(name = "CoreFoundation", kind = "framework")]
+extern "C" {
+    fn CFArrayGetCount(array: *const c_void) -> isize;
+    fn CFArrayGetValueAtIndex(array: *const c_void, index: isize) -> *const c_void;
+    fn CFArrayCreate(
+        allocator: *const c_void,
+        values: *const *const c_void,
+        count: isize,
+        callbacks: *const c_void,
+    ) -> *mut c_void;
+    fn CFDictionaryGetValue(dictionary: *const c_void, key: *const c_void) -> *const c_void;
+    fn CFNumberGetValue(number: *const c_void, number_type: isize, value: *mut c_void) -> bool;
+}
+
+/// Part of the screen to capture
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Area {
+    /// The whole main display
+    MainDisplay,
+    /// A rectangle in global coordinates: points, with the origin at the
+    /// top-left of the main display
+    Region(NSRect),
+}
+
+impl Area {
+    /// The captured rectangle in global coordinates
+    pub(super) fn bounds(self) -> NSRect {
+        match self {
+            // SAFETY: CGDisplayBounds accepts any display ID
+            Area::MainDisplay => unsafe { CGDisplayBounds(main_display_id()) },
+            Area::Region(rect) => rect,
+        }
+    }
+
+    /// Center of the captured rectangle, used to find its display
+    pub(super) fn center(self) -> NSPoint {
+        let bounds = self.bounds();
+        NSPoint::new(
+            bounds.origin.x + bounds.size.width / 2.0,
+            bounds.origin.y + bounds.size.height / 2.0,
+        )
+    }
+}
+
+/// Capture an area of the screen, leaving out the windows in `excluded_windows`.
+pub(super) fn capture(area: Area, excluded_windows: &[u32]) -> Result<CfOwned, ScreenshotError> {
+    match screencapturekit::capture(area, excluded_windows) {
+        Some(result) => result,
+        None => capture_window_list(area, excluded_windows),
+    }
+}
+
+/// IDs of this app's windows that are on screen.
+///
+/// Reads the window server's list rather than AppKit's, so it can be called
+/// off the main thread.
+pub(super) fn own_windows() -> Vec<u32> {
+    let pid = i64::from(std::process::id());
+    // SAFETY: the window info list is released when dropped; each entry is a
+    // dictionary whose number values are read with CFNumberGetValue
+    unsafe {
+        let Some(info) = CfOwned::new(CGWindowListCopyWindowInfo(ON_SCREEN_ONLY, NULL_WINDOW_ID))
+        else {
+            return Vec::new();
+        };
+        (0..CFArrayGetCount(info.0))
+            .map(|i| CFArrayGetValueAtIndex(info.0, i))
+            .filter(|window| number_value(*window, kCGWindowOwnerPID) == Some(pid))
+            .filter_map(|window| number_value(window, kCGWindowNumber))
+            .filter_map(|number| u32::try_from(number).ok())
+            .collect()
+    }
+}
+
+/// A number from a window info dictionary
+///
+/// # Safety
+/// `dictionary` must be a valid CFDictionary and `key` a valid CFString.
+unsafe fn number_value(dictionary: *const c_void, key: *const c_void) -> Option<i64> {
+    let number = CFDictionaryGetValue(dictionary, key);
+    if number.is_null() {
+        return None;
+    }
+    let mut value: i64 = 0;
+    CFNumberGetValue(number, NUMBER_SINT64, (&mut value as *mut i64).cast()).then_some(value)
+}
+
+pub(super) fn main_display_id() -> u32 {
+    // SAFETY: CGMainDisplayID has no preconditions
+    unsafe { CGMainDisplayID() }
+}
+
+/// Backing scale factor of the main display (2.0 on Retina displays).
+///
+/// Uses Core Graphics rather than NSScreen, so it can be called off the main thread.
+pub(super) fn main_display_scale() -> f64 {
+    display_scale(main_display_id())
+}
+
+/// Backing scale factor of a display
+pub(super) fn display_scale(display: u32) -> f64 {
+    // SAFETY: the display mode is checked for null and released after use
+    unsafe {
+        let mode = CGDisplayCopyDisplayMode(display);
+        if mode.is_null() {
+            return 1.0;
+        }
+        let points = CGDisplayModeGetWidth(mode);
+        let pixels = CGDisplayModeGetPixelWidth(mode);
+        CGDisplayModeRelease(mode);
+        if points == 0 {
+            1.0
+        } else {
+            pixels as f64 / points as f64
+        }
+    }
+}
+
+/// Capture with CGWindowList, for macOS versions before ScreenCaptureKit
+/// could take screenshots.
+fn capture_window_list(area: Area, excluded_windows: &[u32]) -> Result<CfOwned, ScreenshotError> {
+    // SAFETY: the window lists are released when dropped; window IDs are
+    // stored in the arrays as pointer-sized integers, as CGWindowList does
+    unsafe {
+        let on_screen = CfOwned::new(CGWindowListCreate(ON_SCREEN_ONLY, NULL_WINDOW_ID))
+            .ok_or_else(|| ScreenshotError::CaptureError("Could not list windows".into()))?;
+
+        let windows: Vec<*const c_void> = (0..CFArrayGetCount(on_screen.0))
+            .map(|i| CFArrayGetValueAtIndex(on_screen.0, i))
+            .filter(|window| !excluded_windows.contains(&(*window as usize as u32)))
+            .collect();
+        let included = CfOwned::new(CFArrayCreate(
+            std::ptr::null(),
+            windows.as_ptr(),
+            windows.len() as isize,
+            std::ptr::null(),
+        ))
+        .ok_or_else(|| ScreenshotError::CaptureError("Could not list windows".into()))?;
+
+        CfOwned::new(CGWindowListCreateImageFromArray(
+            area.bounds(),
+            included.0,
+            IMAGE_DEFAULT,
+        ))
+        .ok_or_else(|| {
+            ScreenshotError::CaptureError(
+                "The screen could not be captured. Check Screen Recording access in \
+                 System Settings → Privacy & Security."
+                    .into(),
+            )
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! Copy captured screenshots to the clipboard
+
+use arboard::Clipboard;
+use image::ImageReader;
+use std::path::Path;
+use tracing::{error, info};
+
+/// Copy a screenshot image file to the system clipboard
+///
+/// Uses arboard to copy the captured PNG to the clipboard so users can
+/// paste the screenshot directly into other applications.
+pub(super) fn copy_to_clipboard(filepath: &Path) {
+    // Read and decode the image
+    let img = match ImageReader::open(filepath) {
+        Ok(reader) => match reader.decode() {
+            Ok(img) => img.to_rgba8(),
+            Err(e) => {
+                error!("Failed to decode screenshot for clipboard: {}", e);
+                return;
+            }
+        },
+        Err(e) => {
+            error!("Failed to open screenshot for clipboard: {}", e);
+            return;
+        }
+    };
+
+    let (width, height) = img.dimensions();
+    let rgba_data = img.into_raw();
+
+    // Create arboard ImageData
+    let image_data = arboard::ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: rgba_data.into(),
+    };
+
+    // Copy to clipboard
+    match Clipboard::new() {
+        Ok(mut clipboard) => match clipboard.set_image(image_data) {
+            Ok(_) => {
+                info!("Screenshot copied to clipboard");
+            }
+            Err(e) => {
+                error!("Failed to copy screenshot to clipboard: {}", e);
+            }
+        },
+        Err(e) => {
+            error!("Failed to initialize clipboard: {}", e);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Re-encode and downscale captured screenshots with ImageIO
+
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_foundation::{NSString, NSURL};
+use std::ffi::c_void;
+use std::path::Path;
+
+use super::settings::ScreenshotFormat;
+use super::ScreenshotError;
+
+#[link(name = "ImageIO", kind = "framework")]
+extern "C" {
+    static kCGImageSourceCreateThumbnailFromImageAlways: *const AnyObject;
+    static kCGImageSourceCreateThumbnailWithTransform: *const AnyObject;
+    static kCGImageSourceThumbnailMaxPixelSize: *const AnyObject;
+    static kCGImageDestinationLossyCompressionQuality: *const AnyObject;
+
+    fn CGImageSourceCreateWithURL(url: *const NSURL, options: *const AnyObject) -> *mut c_void;
+    fn CGImageSourceCreateImageAtIndex(
+        source: *mut c_void,
+        index: usize,
+        options: *const AnyObject,
+    ) -> *mut c_void;
+    fn CGImageSourceCreateThumbnailAtIndex(
+        source: *mut c_void,
+        index: usize,
+        options: *const AnyObject,
+    ) -> *mut c_void;
+    fn CGImageDestinationCreateWithURL(
+        url: *const NSURL,
+        type_identifier: *const NSString,
+        count: usize,
+        options: *const AnyObject,
+    ) -> *mut c_void;
+    fn CGImageDestinationAddImage(
+        destination: *mut c_void,
+        image: *mut c_void,
+        properties: *const AnyObject,
+    );
+    fn CGImageDestinationFinalize(destination: *mut c_void) -> bool;
+}
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGImageGetWidth(image: *mut c_void) -> usize;
+    fn CGImageGetHeight(image: *mut c_void) -> usize;
+}
+
+#[link(name = "CoreFoundation", kind = "framework")]
+extern "C" {
+    fn CFRelease(object: *mut c_void);
+}
+
+/// A Core Foundation object released when dropped
+pub(super) struct CfOwned(pub(super) *mut c_void);
+
+// SAFETY: only immutable images and single-use ImageIO objects are wrapped,
+// and Core Foundation reference counting is thread-safe
+unsafe impl Send for CfOwned {}
+
+impl CfOwned {
+    /// Take ownership of an object returned by a Create or Copy function, if any
+    pub(super) fn new(object: *mut c_void) -> Option<Self> {
+        (!object.is_null()).then_some(Self(object))
+    }
+}
+
+impl Drop for CfOwned {
+    fn drop(&mut self) {
+        // SAFETY: the object came from a Create or Copy function and is released once
+        unsafe { CFRelease(self.0) };
+    }
+}
+
+/// Width and height of an image file in pixels
+pub(super) fn pixel_size(path: &Path) -> Option<(usize, usize)> {
+    let source = open_source(path)?;
+    // SAFETY: the source is valid; the image is released when dropped
+    unsafe {
+        let image = CfOwned::new(CGImageSourceCreateImageAtIndex(
+            source.0,
+            0,
+            std::ptr::null(),
+        ))?;
+        Some((CGImageGetWidth(image.0), CGImageGetHeight(image.0)))
+    }
+}
+
+/// Write the image at `source` to `destination` in another format.
+///
+/// `quality` is the lossy compression quality (0.0 to 1.0) and
+/// `max_pixel_size` limits the longest side, keeping the aspect ratio.
+pub(super) fn convert(
+    source: &Path,
+    destination: &Path,
+    format: ScreenshotFormat,
+    quality: Option<f64>,
+    max_pixel_size: Option<usize>,
+) -> Result<(), ScreenshotError> {
+    let save_error = |what: &str| ScreenshotError::SaveError(what.to_string());
+
+    let image_source = open_source(source).ok_or_else(|| save_error("Could not read capture"))?;
+    let image = match max_pixel_size {
+        Some(size) => {
+            let options = dictionary();
+            // SAFETY: the keys are ImageIO constants and the dictionary is valid
+            unsafe {
+                if let Some(options) = &options {
+                    let always = kCGImageSourceCreateThumbnailFromImageAlways;
+                    set_number(options, always, Number::Bool(true));
+                    let transform = kCGImageSourceCreateThumbnailWithTransform;
+                    set_number(options, transform, Number::Bool(true));
+                    let max_size = kCGImageSourceThumbnailMaxPixelSize;
+                    set_number(options, max_size, Number::Integer(size));
+                }
+                CfOwned::new(CGImageSourceCreateThumbnailAtIndex(
+                    image_source.0,
+                    0,
+                    options.as_ref().map_or(std::ptr::null(), Retained::as_ptr),
+                ))
+            }
+        }
+        // SAFETY: the source is valid
+        None => CfOwned::new(unsafe {
+            CGImageSourceCreateImageAtIndex(image_source.0, 0, std::ptr::null())
+        }),
+    }
+    .ok_or_else(|| save_error("Could not decode capture"))?;
+
+    write(&image, destination, format, quality)
+}
+
+/// Write a CGImage to `destination` in the given format.
+///
+/// `quality` is the lossy compression quality (0.0 to 1.0).
+pub(super) fn write(
+    image: &CfOwned,
+    destination: &Path,
+    format: ScreenshotFormat,
+    quality: Option<f64>,
+) -> Result<(), ScreenshotError> {
+    let url = file_url(destination);
+    let type_identifier = NSString::from_str(format.type_identifier());
+    // SAFETY: valid URL and type identifier; the destination is released when dropped
+    let image_destination = CfOwned::new(unsafe {
+        CGImageDestinationCreateWithURL(&*url, &*type_identifier, 1, std::ptr::null())
+    })
+    .ok_or_else(|| {
+        ScreenshotError::SaveError(format!("{} is not supported on this Mac", format.label()))
+    })?;
+
+    let properties = dictionary();
+    if let (Some(quality), Some(properties)) = (quality, &properties) {
+        // SAFETY: the key is an ImageIO constant and the dictionary is valid
+        unsafe {
+            let key = kCGImageDestinationLossyCompressionQuality;
+            set_number(properties, key, Number::Double(quality));
+        }
+    }
+
+    // SAFETY: destination and image are valid for the duration of these calls
+    let written = unsafe {
+        CGImageDestinationAddImage(
+            image_destination.0,
+            image.0,
+            properties
+                .as_ref()
+                .map_or(std::ptr::null(), Retained::as_ptr),
+        );
+        CGImageDestinationFinalize(image_destination.0)
+    };
+    if written {
+        Ok(())
+    } else {
+        Err(ScreenshotError::SaveError(format!(
+            "Could not write {}",
+            format.label()
+        )))
+    }
+}
+
+fn file_url(path: &Path) -> Retained<NSURL> {
+    let ns_path = NSString::from_str(&path.to_string_lossy());
+    // SAFETY: fileURLWithPath: returns a valid URL for any path string
+    unsafe { msg_send_id![NSURL::class(), fileURLWithPath: &*ns_path] }
+}
+
+fn open_source(path: &Path) -> Option<CfOwned> {
+    let url = file_url(path);
+    // SAFETY: valid URL; the source is released when dropped
+    CfOwned::new(unsafe { CGImageSourceCreateWithURL(&*url, std::ptr::null()) })
+}
+
+/// An empty mutable dictionary for ImageIO options
+fn dictionary() -> Option<Retained<AnyObject>> {
+    let class = AnyClass::get("NSMutableDictionary")?;
+    // SAFETY: +new on NSMutableDictionary returns an owned, empty dictionary
+    unsafe { msg_send_id![class, new] }
+}
+
+/// NSNumber values for ImageIO options
+enum Number {
+    Bool(bool),
+    Integer(usize),
+    Double(f64),
+}
+
+/// Set an NSNumber option in an ImageIO options dictionary.
+///
+/// # Safety
+/// `key` must be a valid CFString constant.
+unsafe fn set_number(dictionary: &AnyObject, key: *const AnyObject, value: Number) {
+    let Some(class) = AnyClass::get("NSNumber") else {
+        return;
+    };
+    let number: *mut AnyObject = match value {
+        Number::Bool(value) => msg_send![class, numberWithBool: value],
+        Number::Integer(value) => msg_send![class, numberWithInteger: value as isize],
+        Number::Double(value) => msg_send![class, numberWithDouble: value],
+    };
+    if !number.is_null() {
+        let _: () = msg_send![dictionary, setObject: number, forKey: key];
+    }
+}
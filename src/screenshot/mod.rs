@@ -0,0 +1,224 @@
+//! Screenshot capture module
+//!
+//! Provides functionality to capture screenshots during Live Meeting Recording.
+//! Screenshots are saved to a configurable directory (default: ~/Documents/Vissper/screenshots).
+//!
+//! Captures natively with ScreenCaptureKit, or CGWindowList before macOS 14,
+//! which properly handles Spaces (virtual desktops). Captures are PNG; depending
+//! on the settings in the Screenshots tab they are then saved as JPEG or HEIC
+//! and/or at standard resolution on Retina displays.
+//! When encryption at rest is enabled, screenshots are stored with an extra `.enc`.
+
+mod capture;
+mod clipboard;
+mod encode;
+mod region;
+mod screencapturekit;
+mod settings;
+
+pub(crate) use region::{capture_last_region, capture_region_screenshot};
+pub(crate) use settings::{ScreenshotFormat, ScreenshotSettings, QUALITY_PRESETS};
+
+use crate::{encryption, managed, preferences, private_mode, screenshot_blocklist};
+use capture::Area;
+use chrono::Local;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+/// Capture a screenshot and save it to the screenshots folder
+///
+/// Captures the main display natively, which correctly captures the currently
+/// visible Space/desktop, without a shutter sound.
+///
+/// Returns the filename of the screenshot (e.g., "screenshot-2025-12-11-14-30-45.png")
+/// for embedding in the transcript as a markdown image reference.
+///
+/// # Returns
+/// - `Ok(filename)` - The filename of the saved screenshot
+/// - `Err(message)` - Error message if capture or save failed
+pub(crate) fn capture_screenshot() -> Result<String, ScreenshotError> {
+    ensure_allowed()?;
+
+    let filepath = capture_path()?;
+    capture_to_png(Area::MainDisplay, &filepath)?;
+    info!("Screenshot saved to: {:?}", filepath);
+
+    finish_capture(&filepath, None)
+}
+
+/// Path for a new capture: a timestamped PNG in the screenshots folder
+fn capture_path() -> Result<PathBuf, ScreenshotError> {
+    let screenshots_dir = ensure_screenshots_dir()?;
+    let timestamp = Local::now().format("%Y-%m-%d-%H-%M-%S");
+    Ok(screenshots_dir.join(format!("screenshot-{}.png", timestamp)))
+}
+
+/// Capture an area of the screen at full resolution, writing a PNG to `filepath`
+///
+/// Vissper's own windows, such as the transcription overlay, are left out
+/// unless turned off in Settings; they stay on screen throughout. The PNG is
+/// converted afterwards if another format is configured.
+fn capture_to_png(area: Area, filepath: &Path) -> Result<(), ScreenshotError> {
+    let excluded = if preferences::get_screenshot_settings().exclude_own_windows {
+        capture::own_windows()
+    } else {
+        Vec::new()
+    };
+    let image =
+        capture::capture(area, &excluded).inspect_err(|e| error!("Capture failed: {}", e))?;
+    encode::write(&image, filepath, ScreenshotFormat::Png, None)
+}
+
+/// Copy, convert and encrypt a captured PNG, returning the saved filename
+///
+/// `points_width` is the width of a captured region in points, used to tell
+/// Retina captures apart; full-screen captures use the main display's scale.
+fn finish_capture(filepath: &Path, points_width: Option<f64>) -> Result<String, ScreenshotError> {
+    // Copy to clipboard
+    clipboard::copy_to_clipboard(filepath);
+
+    let filepath = apply_settings(filepath, points_width);
+
+    // Encrypt after the clipboard copy, which reads the plain image
+    encrypt_if_enabled(&filepath)?;
+
+    // Return filename for markdown embedding
+    Ok(filepath
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default())
+}
+
+/// Save a captured PNG in the configured format and resolution.
+///
+/// Returns the path of the saved screenshot. If converting fails the
+/// original PNG is kept, so the screenshot isn't lost.
+fn apply_settings(capture: &Path, points_width: Option<f64>) -> PathBuf {
+    let settings = preferences::get_screenshot_settings();
+    let capture_bytes = fs::metadata(capture).map_or(0, |meta| meta.len());
+    let format = settings.output_format(capture_bytes);
+
+    let max_pixel_size = if settings.downscale_retina {
+        encode::pixel_size(capture).and_then(|(width, height)| {
+            let scale = points_width
+                .filter(|points| *points > 0.0)
+                .map_or_else(capture::main_display_scale, |points| width as f64 / points);
+            settings::standard_resolution_size(width, height, scale)
+        })
+    } else {
+        None
+    };
+
+    if format == ScreenshotFormat::Png && max_pixel_size.is_none() {
+        return capture.to_path_buf();
+    }
+
+    let destination = capture.with_extension(format.extension());
+    let temp = capture.with_extension(format!("{}.tmp", format.extension()));
+    let converted = encode::convert(
+        capture,
+        &temp,
+        format,
+        settings.compression_quality(format),
+        max_pixel_size,
+    )
+    .and_then(|()| fs::rename(&temp, &destination).map_err(ScreenshotError::from));
+
+    match converted {
+        Ok(()) => {
+            if destination != capture {
+                if let Err(e) = fs::remove_file(capture) {
+                    warn!("Failed to remove captured PNG: {}", e);
+                }
+            }
+            info!(
+                ?format,
+                downscaled = max_pixel_size.is_some(),
+                "Screenshot converted"
+            );
+            destination
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&temp);
+            warn!("Failed to convert screenshot, keeping PNG: {}", e);
+            capture.to_path_buf()
+        }
+    }
+}
+
+/// Refuse screenshots during a private session, when disabled by a managed
+/// policy or while a blocklisted app is frontmost, before anything touches disk
+fn ensure_allowed() -> Result<(), ScreenshotError> {
+    if managed::policy().disable_screenshots {
+        return Err(ScreenshotError::DisabledByPolicy);
+    }
+    if private_mode::is_active() {
+        return Err(ScreenshotError::PrivateSession);
+    }
+    if let Some(app) = screenshot_blocklist::blocked_frontmost_app() {
+        screenshot_blocklist::warn_refused(&app);
+        return Err(ScreenshotError::BlockedApp(app));
+    }
+    Ok(())
+}
+
+/// Ensure the screenshots directory exists
+///
+/// Uses custom screenshot location from preferences if set,
+/// otherwise falls back to default location (~/Documents/Vissper/screenshots).
+fn ensure_screenshots_dir() -> Result<PathBuf, ScreenshotError> {
+    let screenshots_dir = preferences::get_screenshot_location()
+        .or_else(preferences::default_screenshot_location)
+        .ok_or(ScreenshotError::NoScreenshotsDir)?;
+
+    if !screenshots_dir.exists() {
+        fs::create_dir_all(&screenshots_dir)?;
+        info!("Created screenshots directory: {:?}", screenshots_dir);
+    }
+
+    Ok(screenshots_dir)
+}
+
+/// Replace the screenshot with an encrypted copy when encryption at rest is on.
+///
+/// The transcript keeps the plain reference; readers look for `.enc` next to it.
+/// If encryption fails the plain file is removed rather than left behind.
+fn encrypt_if_enabled(filepath: &Path) -> Result<(), ScreenshotError> {
+    if !encryption::is_enabled() {
+        return Ok(());
+    }
+    encryption::encrypt_file_in_place(filepath).map_err(|e| {
+        let _ = fs::remove_file(filepath);
+        ScreenshotError::SaveError(format!("Failed to encrypt screenshot: {}", e))
+    })?;
+    Ok(())
+}
+
+/// Screenshot errors
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ScreenshotError {
+    #[error("Could not determine screenshots directory")]
+    NoScreenshotsDir,
+
+    #[error("Screenshots are disabled during a private session")]
+    PrivateSession,
+
+    #[error("Screenshots are disabled by your organization")]
+    DisabledByPolicy,
+
+    #[error("Screenshots are blocked while {0} is in front")]
+    BlockedApp(String),
+
+    #[error("Select an area to capture first")]
+    NoLastRegion,
+
+    #[error("Screenshot capture failed: {0}")]
+    CaptureError(String),
+
+    #[error("Failed to save screenshot: {0}")]
+    SaveError(String),
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
@@ -0,0 +1,63 @@
+//! Region captures, and repeating the last selected region
+
+use objc2_foundation::{NSPoint, NSRect, NSSize};
+use std::sync::Mutex;
+use tracing::info;
+
+use super::capture::Area;
+use super::{capture_path, capture_to_png, ensure_allowed, finish_capture, ScreenshotError};
+
+/// The last region captured this run, in global top-left-origin points
+static LAST_REGION: Mutex<Option<NSRect>> = Mutex::new(None);
+
+/// Capture a screenshot of a specific region
+///
+/// Captures only the specified rectangular region, on whichever display holds
+/// its center. Coordinates use top-left origin (Core Graphics convention).
+/// The region is remembered for [`capture_last_region`].
+///
+/// # Arguments
+/// * `x` - X coordinate of region origin (from left edge)
+/// * `y` - Y coordinate of region origin (from top edge)
+/// * `width` - Width of region in points
+/// * `height` - Height of region in points
+///
+/// # Returns
+/// - `Ok(filename)` - The filename of the saved screenshot
+/// - `Err(ScreenshotError)` - Error if capture or save failed
+pub(crate) fn capture_region_screenshot(
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+) -> Result<String, ScreenshotError> {
+    let region = NSRect::new(NSPoint::new(x, y), NSSize::new(width, height));
+    let filename = capture_region(region)?;
+    if let Ok(mut last) = LAST_REGION.lock() {
+        *last = Some(region);
+    }
+    Ok(filename)
+}
+
+/// Capture the last selected region again, without showing the selection overlay
+///
+/// Useful for repeatedly snapshotting the same window. Fails with
+/// [`ScreenshotError::NoLastRegion`] until a region has been captured.
+pub(crate) fn capture_last_region() -> Result<String, ScreenshotError> {
+    let region = LAST_REGION
+        .lock()
+        .ok()
+        .and_then(|last| *last)
+        .ok_or(ScreenshotError::NoLastRegion)?;
+    capture_region(region)
+}
+
+fn capture_region(region: NSRect) -> Result<String, ScreenshotError> {
+    ensure_allowed()?;
+
+    let filepath = capture_path()?;
+    capture_to_png(Area::Region(region), &filepath)?;
+    info!("Region screenshot saved to: {:?}", filepath);
+
+    finish_capture(&filepath, Some(region.size.width))
+}
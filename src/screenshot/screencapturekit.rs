@@ -0,0 +1,229 @@
+//! Capture with ScreenCaptureKit on macOS 14 and later
+//!
+//! The framework is loaded at runtime rather than linked, since it doesn't
+//! exist on the oldest supported macOS versions.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject, Bool};
+use objc2_foundation::{NSPoint, NSRect, NSString};
+use once_cell::sync::Lazy;
+use std::ffi::c_void;
+use std::sync::mpsc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::capture::{display_scale, main_display_id, Area};
+use super::encode::CfOwned;
+use super::ScreenshotError;
+
+const FRAMEWORK_PATH: &str = "/System/Library/Frameworks/ScreenCaptureKit.framework";
+
+/// How long to wait for ScreenCaptureKit before giving up on a capture
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[link(name = "CoreFoundation", kind = "framework")]
+extern "C" {
+    fn CFRetain(object: *const c_void) -> *mut c_void;
+}
+
+/// Whether ScreenCaptureKit can take screenshots on this Mac
+static AVAILABLE: Lazy<bool> = Lazy::new(|| {
+    let available = load_framework() && AnyClass::get("SCScreenshotManager").is_some();
+    info!(available, "ScreenCaptureKit screenshots");
+    available
+});
+
+/// Capture an area with ScreenCaptureKit.
+///
+/// Returns `None` when ScreenCaptureKit can't take screenshots on this Mac.
+pub(super) fn capture(
+    area: Area,
+    excluded_windows: &[u32],
+) -> Option<Result<CfOwned, ScreenshotError>> {
+    if !*AVAILABLE {
+        return None;
+    }
+    Some(capture_with_filter(area, excluded_windows))
+}
+
+fn load_framework() -> bool {
+    let Some(bundle_class) = AnyClass::get("NSBundle") else {
+        return false;
+    };
+    // SAFETY: bundleWithPath: returns nil or a valid bundle, which load is sent to
+    unsafe {
+        let bundle: *mut AnyObject =
+            msg_send![bundle_class, bundleWithPath: &*NSString::from_str(FRAMEWORK_PATH)];
+        if bundle.is_null() {
+            return false;
+        }
+        let loaded: Bool = msg_send![bundle, load];
+        loaded.as_bool()
+    }
+}
+
+fn capture_with_filter(area: Area, excluded_windows: &[u32]) -> Result<CfOwned, ScreenshotError> {
+    let failed = |what: &str| ScreenshotError::CaptureError(what.to_string());
+    let (Some(content_class), Some(filter_class), Some(config_class), Some(manager_class)) = (
+        AnyClass::get("SCShareableContent"),
+        AnyClass::get("SCContentFilter"),
+        AnyClass::get("SCStreamConfiguration"),
+        AnyClass::get("SCScreenshotManager"),
+    ) else {
+        return Err(failed("ScreenCaptureKit is not available"));
+    };
+
+    let content = shareable_content(content_class).ok_or_else(|| {
+        failed(
+            "The screen could not be captured. Check Screen Recording access in \
+             System Settings → Privacy & Security.",
+        )
+    })?;
+
+    // SAFETY: messages to valid ScreenCaptureKit objects; alloc/init returns a
+    // +1 object owned by Retained, and the completion handler is copied by
+    // ScreenCaptureKit before this function returns
+    unsafe {
+        let (display, display_frame) =
+            find_display(&content, area).ok_or_else(|| failed("No display to capture"))?;
+        let excluded = excluded_sc_windows(&content, excluded_windows)
+            .ok_or_else(|| failed("Could not list windows"))?;
+
+        let filter: *mut AnyObject = msg_send![filter_class, alloc];
+        let filter: *mut AnyObject =
+            msg_send![filter, initWithDisplay: display, excludingWindows: excluded];
+        let filter = Retained::from_raw(filter).ok_or_else(|| failed("Invalid capture filter"))?;
+
+        let config: *mut AnyObject = msg_send![config_class, new];
+        let config =
+            Retained::from_raw(config).ok_or_else(|| failed("Invalid capture configuration"))?;
+
+        // Capture the area relative to its display, at the display's resolution
+        let bounds = area.bounds();
+        let source = NSRect::new(
+            NSPoint::new(
+                bounds.origin.x - display_frame.origin.x,
+                bounds.origin.y - display_frame.origin.y,
+            ),
+            bounds.size,
+        );
+        let display_id: u32 = msg_send![display, displayID];
+        let scale = display_scale(display_id);
+        let _: () = msg_send![&config, setSourceRect: source];
+        let _: () = msg_send![&config, setWidth: (source.size.width * scale).round() as usize];
+        let _: () = msg_send![&config, setHeight: (source.size.height * scale).round() as usize];
+        let _: () = msg_send![&config, setShowsCursor: false];
+
+        let (tx, rx) = mpsc::channel();
+        let handler = RcBlock::new(move |image: *mut c_void, _error: *mut AnyObject| {
+            let image = if image.is_null() {
+                None
+            } else {
+                // Keep the image past the handler, which doesn't own it
+                CfOwned::new(CFRetain(image))
+            };
+            let _ = tx.send(image);
+        });
+        let _: () = msg_send![
+            manager_class,
+            captureImageWithFilter: &*filter,
+            configuration: &*config,
+            completionHandler: &*handler
+        ];
+
+        match rx.recv_timeout(TIMEOUT) {
+            Ok(Some(image)) => Ok(image),
+            Ok(None) => Err(failed("ScreenCaptureKit returned no image")),
+            Err(_) => {
+                warn!("ScreenCaptureKit capture timed out");
+                Err(failed("Timed out capturing the screen"))
+            }
+        }
+    }
+}
+
+/// Windows and displays currently on screen, or `None` without Screen Recording access
+fn shareable_content(content_class: &AnyClass) -> Option<Retained<AnyObject>> {
+    let (tx, rx) = mpsc::channel();
+    let handler = RcBlock::new(move |content: *mut AnyObject, _error: *mut AnyObject| {
+        // SAFETY: content is a valid SCShareableContent or nil
+        let _ = tx.send(unsafe { Retained::retain(content) });
+    });
+
+    // SAFETY: the class method takes two BOOLs and a completion handler, which
+    // ScreenCaptureKit copies
+    unsafe {
+        let _: () = msg_send![
+            content_class,
+            getShareableContentExcludingDesktopWindows: false,
+            onScreenWindowsOnly: true,
+            completionHandler: &*handler
+        ];
+    }
+    rx.recv_timeout(TIMEOUT).ok().flatten()
+}
+
+/// The SCDisplay containing the area's center, with its global frame.
+///
+/// Falls back to the main display when the center is off screen.
+///
+/// # Safety
+/// `content` must be a valid SCShareableContent.
+unsafe fn find_display(content: &AnyObject, area: Area) -> Option<(*mut AnyObject, NSRect)> {
+    let displays: *mut AnyObject = msg_send![content, displays];
+    let center = area.center();
+    let main_id = main_display_id();
+
+    let mut main = None;
+    for display in array_items(displays) {
+        let frame: NSRect = msg_send![display, frame];
+        let display_id: u32 = msg_send![display, displayID];
+        let contains = center.x >= frame.origin.x
+            && center.x < frame.origin.x + frame.size.width
+            && center.y >= frame.origin.y
+            && center.y < frame.origin.y + frame.size.height;
+        match area {
+            Area::MainDisplay if display_id == main_id => return Some((display, frame)),
+            Area::Region(_) if contains => return Some((display, frame)),
+            _ if display_id == main_id => main = Some((display, frame)),
+            _ => {}
+        }
+    }
+    main
+}
+
+/// Array of the on-screen SCWindows whose IDs are in `window_ids`
+///
+/// # Safety
+/// `content` must be a valid SCShareableContent.
+unsafe fn excluded_sc_windows(content: &AnyObject, window_ids: &[u32]) -> Option<*mut AnyObject> {
+    let excluded: *mut AnyObject = msg_send![AnyClass::get("NSMutableArray")?, array];
+    if excluded.is_null() {
+        return None;
+    }
+
+    let windows: *mut AnyObject = msg_send![content, windows];
+    for window in array_items(windows) {
+        let window_id: u32 = msg_send![window, windowID];
+        if window_ids.contains(&window_id) {
+            let _: () = msg_send![excluded, addObject: window];
+        }
+    }
+    Some(excluded)
+}
+
+/// Items of an NSArray
+///
+/// # Safety
+/// `array` must be nil or a valid NSArray that outlives the items' use.
+unsafe fn array_items(array: *mut AnyObject) -> Vec<*mut AnyObject> {
+    if array.is_null() {
+        return Vec::new();
+    }
+    let count: usize = msg_send![array, count];
+    (0..count)
+        .map(|i| msg_send![array, objectAtIndex: i])
+        .collect()
+}
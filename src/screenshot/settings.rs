@@ -0,0 +1,197 @@
+//! Screenshot file format and size settings
+
+use serde::{Deserialize, Serialize};
+
+/// JPEG and HEIC quality levels offered in Settings, in percent
+pub(crate) const QUALITY_PRESETS: [u8; 4] = [50, 70, 85, 95];
+
+/// PNG captures larger than this are saved as JPEG when compression is on
+const COMPRESS_THRESHOLD_BYTES: u64 = 1024 * 1024;
+
+/// Image format screenshots are saved in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScreenshotFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Heic,
+}
+
+impl ScreenshotFormat {
+    /// All formats, in the order shown in Settings
+    pub(crate) const ALL: [ScreenshotFormat; 3] = [
+        ScreenshotFormat::Png,
+        ScreenshotFormat::Jpeg,
+        ScreenshotFormat::Heic,
+    ];
+
+    /// Look up a format by its index in `ALL` (used as the segment index)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|format| *format == self)
+            .unwrap_or_default() as isize
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ScreenshotFormat::Png => "PNG",
+            ScreenshotFormat::Jpeg => "JPEG",
+            ScreenshotFormat::Heic => "HEIC",
+        }
+    }
+
+    /// File extension, without the dot
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            ScreenshotFormat::Png => "png",
+            ScreenshotFormat::Jpeg => "jpg",
+            ScreenshotFormat::Heic => "heic",
+        }
+    }
+
+    /// Uniform type identifier ImageIO writes the format under
+    pub(crate) fn type_identifier(self) -> &'static str {
+        match self {
+            ScreenshotFormat::Png => "public.png",
+            ScreenshotFormat::Jpeg => "public.jpeg",
+            ScreenshotFormat::Heic => "public.heic",
+        }
+    }
+
+    fn is_lossy(self) -> bool {
+        self != ScreenshotFormat::Png
+    }
+}
+
+/// Persisted screenshot settings
+///
+/// Defaults to full-resolution PNG, as before this was configurable, without
+/// Vissper's own windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ScreenshotSettings {
+    pub format: ScreenshotFormat,
+    /// JPEG and HEIC quality in percent
+    pub quality: u8,
+    /// Save Retina captures at standard resolution (half the width and height)
+    pub downscale_retina: bool,
+    /// Save large PNG captures as JPEG
+    pub compress_large: bool,
+    /// Leave Vissper's own windows out of captures
+    pub exclude_own_windows: bool,
+}
+
+impl Default for ScreenshotSettings {
+    fn default() -> Self {
+        Self {
+            format: ScreenshotFormat::Png,
+            quality: 85,
+            downscale_retina: false,
+            compress_large: false,
+            exclude_own_windows: true,
+        }
+    }
+}
+
+impl ScreenshotSettings {
+    /// Format to save a capture in, given the size of the captured PNG
+    pub(crate) fn output_format(&self, capture_bytes: u64) -> ScreenshotFormat {
+        if self.format == ScreenshotFormat::Png
+            && self.compress_large
+            && capture_bytes > COMPRESS_THRESHOLD_BYTES
+        {
+            ScreenshotFormat::Jpeg
+        } else {
+            self.format
+        }
+    }
+
+    /// Compression quality for ImageIO, from 0.0 to 1.0, for lossy formats
+    pub(crate) fn compression_quality(&self, format: ScreenshotFormat) -> Option<f64> {
+        format
+            .is_lossy()
+            .then(|| f64::from(self.quality.clamp(1, 100)) / 100.0)
+    }
+
+    /// Index of the saved quality in `QUALITY_PRESETS`, or -1 if it isn't one
+    pub(crate) fn quality_index(&self) -> isize {
+        QUALITY_PRESETS
+            .iter()
+            .position(|quality| *quality == self.quality)
+            .map_or(-1, |i| i as isize)
+    }
+}
+
+/// Longest side of a capture saved at standard resolution, if it needs scaling.
+///
+/// `scale` is the display's backing scale factor (2.0 on Retina displays).
+pub(crate) fn standard_resolution_size(width: usize, height: usize, scale: f64) -> Option<usize> {
+    if scale <= 1.0 {
+        return None;
+    }
+    let longest = width.max(height) as f64 / scale;
+    Some(longest.round().max(1.0) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_when_missing() {
+        let settings: ScreenshotSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, ScreenshotSettings::default());
+        assert!(settings.exclude_own_windows);
+        assert_eq!(
+            settings.output_format(10 * COMPRESS_THRESHOLD_BYTES),
+            ScreenshotFormat::Png
+        );
+    }
+
+    #[test]
+    fn test_compress_large_only_affects_png() {
+        let png = ScreenshotSettings {
+            compress_large: true,
+            ..ScreenshotSettings::default()
+        };
+        assert_eq!(
+            png.output_format(COMPRESS_THRESHOLD_BYTES),
+            ScreenshotFormat::Png
+        );
+        assert_eq!(
+            png.output_format(COMPRESS_THRESHOLD_BYTES + 1),
+            ScreenshotFormat::Jpeg
+        );
+
+        let heic = ScreenshotSettings {
+            format: ScreenshotFormat::Heic,
+            ..png
+        };
+        assert_eq!(heic.output_format(u64::MAX), ScreenshotFormat::Heic);
+    }
+
+    #[test]
+    fn test_compression_quality() {
+        let settings = ScreenshotSettings::default();
+        assert_eq!(settings.compression_quality(ScreenshotFormat::Png), None);
+        assert_eq!(
+            settings.compression_quality(ScreenshotFormat::Jpeg),
+            Some(0.85)
+        );
+    }
+
+    #[test]
+    fn test_standard_resolution_size() {
+        assert_eq!(standard_resolution_size(3024, 1964, 2.0), Some(1512));
+        assert_eq!(standard_resolution_size(801, 1201, 2.0), Some(601));
+        assert_eq!(standard_resolution_size(1920, 1080, 1.0), None);
+    }
+}
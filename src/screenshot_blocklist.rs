@@ -0,0 +1,138 @@
+//! Apps during which screenshots are refused
+//!
+//! Some apps should never end up in a screenshot, such as password managers
+//! or a customer's internal tools. While one of the apps listed in the
+//! Privacy tab of Settings is frontmost, screenshot capture and region
+//! selection are refused and a warning is shown instead.
+//!
+//! Entries are app names ("1Password") or bundle IDs ("com.1password.1password"),
+//! matched case-insensitively against the frontmost application.
+
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2_app_kit::{NSAlert, NSAlertStyle};
+use objc2_foundation::{MainThreadMarker, NSString};
+use tracing::{info, warn};
+
+use crate::preferences;
+
+/// The frontmost app, if it is on the blocklist
+///
+/// Returns the app's display name for the warning.
+pub(crate) fn blocked_frontmost_app() -> Option<String> {
+    let blocklist = preferences::get_screenshot_blocklist();
+    if blocklist.is_empty() {
+        return None;
+    }
+
+    let (bundle_id, name) = frontmost_app()?;
+    blocklist
+        .iter()
+        .any(|entry| matches(entry, bundle_id.as_deref(), name.as_deref()))
+        .then(|| name.or(bundle_id).unwrap_or_default())
+}
+
+/// Tell the user a screenshot was refused because `app` is frontmost.
+///
+/// Safe to call from any thread.
+pub(crate) fn warn_refused(app: &str) {
+    warn!("Screenshot refused while {} is frontmost", app);
+    let app = app.to_string();
+    dispatch::Queue::main().exec_async(move || {
+        let Some(mtm) = MainThreadMarker::new() else {
+            return;
+        };
+        // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+        unsafe {
+            let alert = NSAlert::new(mtm);
+            alert.setAlertStyle(NSAlertStyle::Warning);
+            alert.setMessageText(&NSString::from_str("Screenshot blocked"));
+            alert.setInformativeText(&NSString::from_str(&format!(
+                "Screenshots are not taken while {} is in front. \
+                 You can change the blocked apps in the Privacy tab of Settings.",
+                app
+            )));
+            alert.runModal();
+        }
+    });
+}
+
+/// Parse the comma- or line-separated list from Settings
+pub(crate) fn parse(text: &str) -> Vec<String> {
+    let mut apps: Vec<String> = Vec::new();
+    for entry in text.split([',', '\n']).map(str::trim) {
+        if !entry.is_empty() && !apps.iter().any(|app| app.eq_ignore_ascii_case(entry)) {
+            apps.push(entry.to_string());
+        }
+    }
+    apps
+}
+
+/// Format the list for the Settings text field
+pub(crate) fn format(apps: &[String]) -> String {
+    apps.join(", ")
+}
+
+/// Save the blocklist.
+pub(crate) fn set_apps(apps: Vec<String>) -> Result<(), preferences::PreferencesError> {
+    let count = apps.len();
+    preferences::set_screenshot_blocklist(apps)?;
+    info!(count, "Saved screenshot blocklist");
+    Ok(())
+}
+
+/// Whether a blocklist entry names the app with this bundle ID or name
+fn matches(entry: &str, bundle_id: Option<&str>, name: Option<&str>) -> bool {
+    let entry = entry.trim().to_lowercase();
+    [bundle_id, name]
+        .into_iter()
+        .flatten()
+        .any(|value| value.to_lowercase() == entry)
+}
+
+/// Bundle ID and display name of the frontmost application
+fn frontmost_app() -> Option<(Option<String>, Option<String>)> {
+    let workspace_class = AnyClass::get("NSWorkspace")?;
+    // SAFETY: sharedWorkspace and frontmostApplication may be called from any thread;
+    // the returned strings are copied before the autoreleased objects go away
+    unsafe {
+        let workspace: *mut AnyObject = msg_send![workspace_class, sharedWorkspace];
+        if workspace.is_null() {
+            return None;
+        }
+        let app: *mut AnyObject = msg_send![workspace, frontmostApplication];
+        if app.is_null() {
+            return None;
+        }
+        let bundle_id: *mut NSString = msg_send![app, bundleIdentifier];
+        let name: *mut NSString = msg_send![app, localizedName];
+        Some((
+            bundle_id.as_ref().map(NSString::to_string),
+            name.as_ref().map(NSString::to_string),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let apps = parse("1Password, com.bitwarden.desktop\n\n  Acme CRM ,1password");
+        assert_eq!(apps, vec!["1Password", "com.bitwarden.desktop", "Acme CRM"]);
+        assert_eq!(format(&apps), "1Password, com.bitwarden.desktop, Acme CRM");
+        assert!(parse(" , \n").is_empty());
+    }
+
+    #[test]
+    fn test_matches_name_or_bundle_id() {
+        let bundle_id = Some("com.1password.1password");
+        let name = Some("1Password");
+
+        assert!(matches("1password", bundle_id, name));
+        assert!(matches("COM.1Password.1Password", bundle_id, name));
+        assert!(!matches("Password", bundle_id, name));
+        assert!(!matches("1Password", None, None));
+    }
+}
@@ -8,26 +8,25 @@ use objc2::msg_send;
 use objc2::rc::Retained;
 use objc2_app_kit::{NSBackingStoreType, NSColor, NSScreen, NSWindow, NSWindowStyleMask};
 use objc2_foundation::{MainThreadMarker, NSOperationQueue, NSPoint, NSRect, NSSize};
-use once_cell::sync::Lazy;
-use std::sync::Mutex;
+use std::cell::RefCell;
 use tracing::debug;
 
+use crate::app_context;
+use crate::event_bus::AppEvent;
+
 /// Window level high enough to appear above all content (screen saver level).
 const FLASH_WINDOW_LEVEL: isize = 1000;
 
-/// Global state for the flash window.
-/// Only accessed from main thread after initial setup.
-static FLASH_STATE: Lazy<Mutex<Option<FlashState>>> = Lazy::new(|| Mutex::new(None));
+/// The flash window, set while a flash is showing (main thread only).
+fn flash_state() -> Option<&'static RefCell<Option<FlashState>>> {
+    Some(&app_context::windows()?.screenshot_flash)
+}
 
 /// Inner state holding the flash window reference.
-struct FlashState {
+pub(crate) struct FlashState {
     window: Retained<NSWindow>,
 }
 
-// SAFETY: FlashState is only accessed from the main thread via MainThreadMarker checks.
-// The Retained types are Send when the underlying types are MainThreadOnly.
-unsafe impl Send for FlashState {}
-
 /// Screenshot flash manager.
 ///
 /// Provides a brief white overlay flash effect when screenshots are taken,
@@ -65,6 +64,13 @@ impl ScreenshotFlash {
         }
     }
 
+    /// Flash the screen for every captured screenshot.
+    pub(crate) fn handle_event(event: &AppEvent) {
+        if let AppEvent::ScreenshotCaptured { .. } = event {
+            Self::show();
+        }
+    }
+
     /// Internal: show flash on main thread (requires MainThreadMarker).
     fn show_on_main_thread(mtm: MainThreadMarker) {
         // Get main screen dimensions
@@ -114,9 +120,9 @@ impl ScreenshotFlash {
             let _: () = msg_send![&window, setIgnoresMouseEvents: true];
         }
 
-        // Store in global state and show window
+        // Store in the window registry and show window
         let state = FlashState { window };
-        if let Ok(mut guard) = FLASH_STATE.lock() {
+        if let Some(Ok(mut guard)) = flash_state().map(RefCell::try_borrow_mut) {
             // Close any existing flash window first
             if let Some(old_state) = guard.take() {
                 old_state.window.close();
@@ -154,7 +160,7 @@ impl ScreenshotFlash {
     /// Dispatch an alpha update to the main thread.
     fn dispatch_alpha_update(alpha: f64) {
         let block = RcBlock::new(move || {
-            if let Ok(guard) = FLASH_STATE.lock() {
+            if let Some(Ok(guard)) = flash_state().map(RefCell::try_borrow) {
                 if let Some(ref state) = *guard {
                     // SAFETY: NSColor class method and setBackgroundColor are safe on main thread.
                     unsafe {
@@ -175,7 +181,7 @@ impl ScreenshotFlash {
     /// Dispatch window close to the main thread.
     fn dispatch_close() {
         let block = RcBlock::new(|| {
-            if let Ok(mut guard) = FLASH_STATE.lock() {
+            if let Some(Ok(mut guard)) = flash_state().map(RefCell::try_borrow_mut) {
                 if let Some(state) = guard.take() {
                     state.window.close();
                     debug!("Screenshot flash completed");
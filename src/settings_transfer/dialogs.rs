@@ -0,0 +1,316 @@
+//! Panels and alerts for exporting and importing settings
+
+use std::path::{Path, PathBuf};
+
+use objc2::msg_send_id;
+use objc2::rc::Retained;
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSOpenPanel, NSSavePanel, NSSecureTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSArray, NSPoint, NSRect, NSSize, NSString};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+use super::{SettingsFile, SettingsTransferError, EXTENSION};
+use crate::error::KeychainError;
+use crate::settings_window::constants::NS_MODAL_RESPONSE_OK;
+
+/// NSAlertFirstButtonReturn, and the buttons after it
+const ALERT_FIRST_BUTTON: isize = 1000;
+const ALERT_SECOND_BUTTON: isize = 1001;
+
+/// Size of a passphrase field
+const FIELD_WIDTH: CGFloat = 260.0;
+const FIELD_HEIGHT: CGFloat = 24.0;
+const FIELD_SPACING: CGFloat = 8.0;
+
+/// Export the settings to a file the user picks.
+///
+/// Must be called on the main thread.
+pub(crate) fn export_settings() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Not on main thread, cannot export settings");
+        return;
+    };
+
+    let Some(passphrase) = ask_export_passphrase(mtm) else {
+        info!("Settings export cancelled");
+        return;
+    };
+    let Some(path) = choose_export_path(mtm) else {
+        info!("Settings export cancelled");
+        return;
+    };
+
+    match super::export_to(&path, passphrase.as_deref().map(String::as_str)) {
+        Ok(true) => show_message(
+            mtm,
+            "Settings exported",
+            "Your saved credentials are included, encrypted with your passphrase.",
+        ),
+        Ok(false) => show_message(mtm, "Settings exported", "Credentials are not included."),
+        Err(e) => {
+            error!("Failed to export settings: {}", e);
+            show_message(mtm, "Could not export settings", &e.to_string());
+        }
+    }
+}
+
+/// Replace the settings with ones from a file the user picks.
+///
+/// Must be called on the main thread.
+pub(crate) fn import_settings() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Not on main thread, cannot import settings");
+        return;
+    };
+
+    let Some(path) = choose_import_path(mtm) else {
+        info!("Settings import cancelled");
+        return;
+    };
+    let file = match super::read(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            error!("Failed to read settings file: {}", e);
+            show_message(mtm, "Could not import settings", &e.to_string());
+            return;
+        }
+    };
+
+    let Some(passphrase) = confirm_import(mtm, &path, &file) else {
+        info!("Settings import cancelled");
+        return;
+    };
+
+    match file.apply(passphrase.as_deref().map(String::as_str)) {
+        Ok(summary) => {
+            let credentials = match summary.credentials {
+                0 => String::new(),
+                1 => " 1 credential was saved in the keychain.".to_string(),
+                n => format!(" {} credentials were saved in the keychain.", n),
+            };
+            show_message(
+                mtm,
+                "Settings imported",
+                &format!(
+                    "Restart Vissper for all imported settings to take effect.{}",
+                    credentials
+                ),
+            );
+        }
+        Err(SettingsTransferError::Keychain(KeychainError::WrongPassphrase)) => show_message(
+            mtm,
+            "Wrong passphrase",
+            "Nothing was imported. Try again with the passphrase used for the export.",
+        ),
+        Err(e) => {
+            error!("Failed to import settings: {}", e);
+            show_message(mtm, "Could not import settings", &e.to_string());
+        }
+    }
+}
+
+/// Ask for a passphrase to seal the credentials with.
+///
+/// Returns `Some(None)` to export without credentials, `None` if cancelled.
+fn ask_export_passphrase(mtm: MainThreadMarker) -> Option<Option<Zeroizing<String>>> {
+    let (fields, passphrase, confirmation) = passphrase_fields(mtm, true);
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str("Export Settings"));
+        alert.setInformativeText(&NSString::from_str(
+            "Preferences and custom output modes are exported. To include your saved \
+             credentials, enter a passphrase to encrypt them with. Leave it empty to \
+             export without credentials.",
+        ));
+        alert.setAccessoryView(Some(&fields));
+        alert.addButtonWithTitle(&NSString::from_str("Export…"));
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+    if response != ALERT_FIRST_BUTTON {
+        return None;
+    }
+
+    let passphrase = field_value(&passphrase);
+    if passphrase.is_empty() {
+        return Some(None);
+    }
+    if confirmation.map(|field| field_value(&field)).as_ref() != Some(&passphrase) {
+        show_message(
+            mtm,
+            "Passphrases don't match",
+            "Nothing was exported. Enter the same passphrase twice.",
+        );
+        return None;
+    }
+    Some(Some(passphrase))
+}
+
+/// Confirm replacing the settings, asking for the passphrase if the file has credentials.
+///
+/// Returns `Some(None)` to import without credentials, `None` if cancelled.
+fn confirm_import(
+    mtm: MainThreadMarker,
+    path: &Path,
+    file: &SettingsFile,
+) -> Option<Option<Zeroizing<String>>> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let exported = file.exported_at.get(..10).unwrap_or(&file.exported_at);
+    let mut message = format!(
+        "Your current settings are replaced. The file was exported from Vissper {} on {}.",
+        file.app_version, exported
+    );
+    let fields = file.has_credentials().then(|| {
+        message.push_str(" Enter its passphrase to also import the credentials in it.");
+        passphrase_fields(mtm, false)
+    });
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    let response = unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Warning);
+        alert.setMessageText(&NSString::from_str(&format!(
+            "Import settings from “{}”?",
+            name
+        )));
+        alert.setInformativeText(&NSString::from_str(&message));
+        alert.addButtonWithTitle(&NSString::from_str("Import"));
+        if let Some((view, _, _)) = &fields {
+            alert.setAccessoryView(Some(view));
+            alert.addButtonWithTitle(&NSString::from_str("Import Without Credentials"));
+        }
+        alert.addButtonWithTitle(&NSString::from_str("Cancel"));
+        alert.runModal()
+    };
+
+    match (response, fields) {
+        (ALERT_FIRST_BUTTON, Some((_, passphrase, _))) => {
+            let passphrase = field_value(&passphrase);
+            Some((!passphrase.is_empty()).then_some(passphrase))
+        }
+        (ALERT_FIRST_BUTTON, None) | (ALERT_SECOND_BUTTON, Some(_)) => Some(None),
+        _ => None,
+    }
+}
+
+/// A passphrase field, with a second one to confirm it if `confirm` is set
+fn passphrase_fields(
+    mtm: MainThreadMarker,
+    confirm: bool,
+) -> (
+    Retained<NSView>,
+    Retained<NSSecureTextField>,
+    Option<Retained<NSSecureTextField>>,
+) {
+    let rows = if confirm { 2.0 } else { 1.0 };
+    let height = FIELD_HEIGHT * rows + FIELD_SPACING * (rows - 1.0);
+
+    // SAFETY: view allocation and configuration on the main thread with valid frames
+    unsafe {
+        let view: Retained<NSView> = msg_send_id![
+            mtm.alloc::<NSView>(),
+            initWithFrame: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(FIELD_WIDTH, height))
+        ];
+        let field = |y: CGFloat, placeholder: &str| {
+            let field: Retained<NSSecureTextField> = msg_send_id![
+                mtm.alloc::<NSSecureTextField>(),
+                initWithFrame: NSRect::new(NSPoint::new(0.0, y), NSSize::new(FIELD_WIDTH, FIELD_HEIGHT))
+            ];
+            field.setPlaceholderString(Some(&NSString::from_str(placeholder)));
+            view.addSubview(&field);
+            field
+        };
+
+        // Views are not flipped, so the first field goes at the top
+        let passphrase = field(height - FIELD_HEIGHT, "Passphrase");
+        let confirmation = confirm.then(|| field(0.0, "Confirm passphrase"));
+        (view, passphrase, confirmation)
+    }
+}
+
+/// Text typed into a passphrase field
+fn field_value(field: &NSSecureTextField) -> Zeroizing<String> {
+    // SAFETY: reading the value of a valid text field on the main thread
+    Zeroizing::new(unsafe { field.stringValue() }.to_string())
+}
+
+fn choose_export_path(mtm: MainThreadMarker) -> Option<PathBuf> {
+    // SAFETY: NSSavePanel::savePanel is safe to call on main thread
+    let panel = unsafe { NSSavePanel::savePanel(mtm) };
+
+    // SAFETY: These are standard NSSavePanel configuration calls
+    unsafe {
+        panel.setNameFieldStringValue(&NSString::from_str(&format!(
+            "Vissper Settings.{}",
+            EXTENSION
+        )));
+        panel.setMessage(Some(&NSString::from_str(
+            "Choose where to save your settings",
+        )));
+        panel.setPrompt(Some(&NSString::from_str("Export")));
+
+        let extensions: Retained<NSArray<NSString>> =
+            NSArray::from_id_slice(&[NSString::from_str(EXTENSION)]);
+        #[allow(deprecated)]
+        panel.setAllowedFileTypes(Some(&extensions));
+    }
+
+    // SAFETY: runModal blocks until user dismisses the panel
+    let response = unsafe { panel.runModal() };
+    if response != NS_MODAL_RESPONSE_OK {
+        return None;
+    }
+
+    // SAFETY: URL() returns the chosen file URL after a successful modal
+    let url = unsafe { panel.URL() }?;
+    // SAFETY: path() returns the file system path from a file URL
+    unsafe { url.path() }.map(|p| PathBuf::from(p.to_string()))
+}
+
+fn choose_import_path(mtm: MainThreadMarker) -> Option<PathBuf> {
+    // SAFETY: NSOpenPanel::openPanel is safe to call on main thread
+    let panel = unsafe { NSOpenPanel::openPanel(mtm) };
+
+    // SAFETY: These are standard NSOpenPanel configuration calls
+    unsafe {
+        panel.setCanChooseFiles(true);
+        panel.setCanChooseDirectories(false);
+        panel.setAllowsMultipleSelection(false);
+        panel.setMessage(Some(&NSString::from_str("Choose a Vissper settings file")));
+        panel.setPrompt(Some(&NSString::from_str("Import")));
+
+        let extensions: Retained<NSArray<NSString>> =
+            NSArray::from_id_slice(&[NSString::from_str(EXTENSION)]);
+        #[allow(deprecated)]
+        panel.setAllowedFileTypes(Some(&extensions));
+    }
+
+    // SAFETY: runModal blocks until user dismisses the panel
+    let response = unsafe { panel.runModal() };
+    if response != NS_MODAL_RESPONSE_OK {
+        return None;
+    }
+
+    // SAFETY: URLs() returns a valid NSArray after successful modal
+    let urls = unsafe { panel.URLs() };
+    let url = urls.first()?;
+    // SAFETY: path() returns the file system path from a file URL
+    unsafe { url.path() }.map(|p| PathBuf::from(p.to_string()))
+}
+
+fn show_message(mtm: MainThreadMarker, title: &str, message: &str) {
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Informational);
+        alert.setMessageText(&NSString::from_str(title));
+        alert.setInformativeText(&NSString::from_str(message));
+        alert.runModal();
+    }
+}
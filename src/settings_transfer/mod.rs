@@ -0,0 +1,236 @@
+//! Exporting and importing settings
+//!
+//! Bundles preferences.json, which includes the custom output modes (prompt
+//! templates), into a single `.vissper-settings` file for backup or for
+//! handing a team the same setup. Saved credentials are only included when a
+//! passphrase is given, sealed with it by [`keychain::seal_credentials`].
+//! Hotkeys are built into the app, so there are no bindings to carry.
+//!
+//! Fields that only make sense on one Mac are left out of the export and
+//! kept as they are on import: the update check cache and the keychain
+//! schema version. Imported storage locations that don't exist on this Mac
+//! are ignored.
+
+mod dialogs;
+
+pub(crate) use dialogs::{export_settings, import_settings};
+
+use std::fs;
+use std::path::Path;
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use zeroize::Zeroizing;
+
+use crate::error::KeychainError;
+use crate::keychain::{self, CredentialBundle};
+use crate::preferences::{self, Preferences, PreferencesError};
+
+/// File extension for exported settings
+pub(crate) const EXTENSION: &str = "vissper-settings";
+
+/// Version of the settings file layout
+const FORMAT: u32 = 1;
+
+/// Contents of an exported settings file
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SettingsFile {
+    format: u32,
+    /// Version of Vissper that exported the file
+    pub(crate) app_version: String,
+    /// When the file was exported (RFC 3339)
+    pub(crate) exported_at: String,
+    preferences: Preferences,
+    /// Credentials sealed with the export passphrase, base64
+    #[serde(default)]
+    credentials: Option<String>,
+}
+
+/// What an import changed
+pub(crate) struct ImportSummary {
+    /// Number of credentials saved in the keychain
+    pub(crate) credentials: usize,
+}
+
+/// Settings transfer errors
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SettingsTransferError {
+    #[error("Could not read or write the settings file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Not a Vissper settings file: {0}")]
+    Invalid(#[from] serde_json::Error),
+
+    #[error("The settings file was made by a newer version of Vissper")]
+    NewerFormat,
+
+    #[error(transparent)]
+    Keychain(#[from] KeychainError),
+
+    #[error("Could not save settings: {0}")]
+    Preferences(#[from] PreferencesError),
+}
+
+/// Write the current settings to `path`, returning whether credentials were included
+///
+/// Credentials are included only with a non-empty `passphrase`, and only if
+/// any are saved.
+pub(crate) fn export_to(
+    path: &Path,
+    passphrase: Option<&str>,
+) -> Result<bool, SettingsTransferError> {
+    let credentials = match passphrase.filter(|p| !p.is_empty()) {
+        Some(passphrase) => {
+            let bundle = CredentialBundle::from_keychain();
+            if bundle.is_empty() {
+                None
+            } else {
+                let sealed = keychain::seal_credentials(&bundle, passphrase)?;
+                Some(base64::engine::general_purpose::STANDARD.encode(sealed))
+            }
+        }
+        None => None,
+    };
+    let with_credentials = credentials.is_some();
+
+    let file = SettingsFile {
+        format: FORMAT,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        preferences: shareable(preferences::snapshot()),
+        credentials,
+    };
+    fs::write(path, serde_json::to_string_pretty(&file)?)?;
+
+    info!(with_credentials, "Exported settings");
+    Ok(with_credentials)
+}
+
+/// Read a settings file without applying it
+pub(crate) fn read(path: &Path) -> Result<SettingsFile, SettingsTransferError> {
+    let file: SettingsFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+    if file.format > FORMAT {
+        return Err(SettingsTransferError::NewerFormat);
+    }
+    Ok(file)
+}
+
+impl SettingsFile {
+    /// Whether the file carries sealed credentials
+    pub(crate) fn has_credentials(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    /// Replace this Mac's settings with the file's
+    ///
+    /// With a `passphrase`, the credentials are opened first, so a wrong
+    /// passphrase leaves everything unchanged.
+    pub(crate) fn apply(
+        self,
+        passphrase: Option<&str>,
+    ) -> Result<ImportSummary, SettingsTransferError> {
+        let bundle = match (self.credentials, passphrase) {
+            (Some(encoded), Some(passphrase)) => {
+                let sealed = Zeroizing::new(
+                    base64::engine::general_purpose::STANDARD
+                        .decode(encoded)
+                        .map_err(|e| KeychainError::InvalidData(e.to_string()))?,
+                );
+                Some(keychain::open_credentials(&sealed, passphrase)?)
+            }
+            _ => None,
+        };
+
+        preferences::replace(merged(self.preferences, &preferences::snapshot()))?;
+        let credentials = match bundle {
+            Some(bundle) => bundle.store()?,
+            None => 0,
+        };
+
+        info!(credentials, "Imported settings");
+        Ok(ImportSummary { credentials })
+    }
+}
+
+/// `prefs` without the fields that belong to this Mac only
+pub(crate) fn shareable(prefs: Preferences) -> Preferences {
+    Preferences {
+        last_version_check: None,
+        latest_known_version: None,
+        latest_download_url: None,
+        keychain_schema_version: None,
+        ..prefs
+    }
+}
+
+/// `imported` preferences with this Mac's own fields kept from `local`
+fn merged(imported: Preferences, local: &Preferences) -> Preferences {
+    let existing = |path: Option<std::path::PathBuf>| path.filter(|p| p.is_dir());
+    Preferences {
+        transcript_location: existing(imported.transcript_location)
+            .or_else(|| local.transcript_location.clone()),
+        screenshot_location: existing(imported.screenshot_location)
+            .or_else(|| local.screenshot_location.clone()),
+        last_version_check: local.last_version_check.clone(),
+        latest_known_version: local.latest_known_version.clone(),
+        latest_download_url: local.latest_download_url.clone(),
+        keychain_schema_version: local.keychain_schema_version,
+        ..imported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local() -> Preferences {
+        Preferences {
+            keychain_schema_version: Some(2),
+            transcript_location: Some(std::env::temp_dir()),
+            ..Preferences::default()
+        }
+    }
+
+    #[test]
+    fn test_export_leaves_out_machine_fields() {
+        let prefs = Preferences {
+            latest_known_version: Some("9.9.9".to_string()),
+            language_code: Some("de".to_string()),
+            ..local()
+        };
+        let shared = shareable(prefs);
+        assert!(shared.latest_known_version.is_none());
+        assert!(shared.keychain_schema_version.is_none());
+        assert_eq!(shared.language_code.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_import_keeps_machine_fields_and_missing_locations() {
+        let imported = Preferences {
+            language_code: Some("fi".to_string()),
+            transcript_location: Some("/no/such/folder/on/this/mac".into()),
+            ..Preferences::default()
+        };
+        let result = merged(imported, &local());
+        assert_eq!(result.language_code.as_deref(), Some("fi"));
+        assert_eq!(result.keychain_schema_version, Some(2));
+        assert_eq!(result.transcript_location, Some(std::env::temp_dir()));
+    }
+
+    #[test]
+    fn test_newer_format_is_refused() {
+        let path = std::env::temp_dir().join("vissper-settings-format-test.json");
+        let file = SettingsFile {
+            format: FORMAT + 1,
+            app_version: "0.0.0".to_string(),
+            exported_at: String::new(),
+            preferences: Preferences::default(),
+            credentials: None,
+        };
+        fs::write(&path, serde_json::to_string(&file).unwrap()).unwrap();
+        let result = read(&path);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(result, Err(SettingsTransferError::NewerFormat)));
+    }
+}
@@ -0,0 +1,130 @@
+//! Audio actions: chunk duration, buffer depth, noise reduction, connecting
+//! ahead of time, low-bandwidth mode, the wake word and screen lock.
+
+use objc2_foundation::NSString;
+use tracing::{error, info, warn};
+
+use super::super::settings_window;
+use crate::audio::{AudioTuning, BUFFER_SECS_PRESETS, CHUNK_MS_PRESETS};
+use crate::transcription::NoiseReduction;
+use crate::{preferences, wake_word};
+
+/// Save the chunk duration for the selected segment.
+pub(in crate::settings_window) fn set_audio_chunk(selected_segment: isize) {
+    let Some(chunk_ms) = preset(&CHUNK_MS_PRESETS, selected_segment) else {
+        warn!("Unknown audio chunk segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_audio_chunk_ms(chunk_ms) {
+        Ok(()) => info!(chunk_ms, "Saved audio chunk duration"),
+        Err(e) => error!("Failed to save audio chunk duration: {}", e),
+    }
+    update_latency_label();
+}
+
+/// Save the buffer depth for the selected segment.
+pub(in crate::settings_window) fn set_audio_buffer(selected_segment: isize) {
+    let Some(buffer_secs) = preset(&BUFFER_SECS_PRESETS, selected_segment) else {
+        warn!("Unknown audio buffer segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_audio_buffer_secs(buffer_secs) {
+        Ok(()) => info!(buffer_secs, "Saved audio buffer depth"),
+        Err(e) => error!("Failed to save audio buffer depth: {}", e),
+    }
+    update_latency_label();
+}
+
+/// Save the noise reduction mode for the selected segment.
+pub(in crate::settings_window) fn set_noise_reduction(selected_segment: isize) {
+    let Some(mode) = NoiseReduction::from_index(selected_segment) else {
+        warn!("Unknown noise reduction segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_noise_reduction(mode) {
+        Ok(()) => info!(?mode, "Saved noise reduction mode"),
+        Err(e) => error!("Failed to save noise reduction mode: {}", e),
+    }
+}
+
+/// Turn connecting when the menu bar menu opens on or off.
+pub(in crate::settings_window) fn set_prewarm_connection(enabled: bool) {
+    match preferences::set_prewarm_connection(enabled) {
+        Ok(()) => info!(enabled, "Saved prewarm connection setting"),
+        Err(e) => error!("Failed to save prewarm connection setting: {}", e),
+    }
+}
+
+/// Turn low-bandwidth mode on or off.
+pub(in crate::settings_window) fn set_low_bandwidth_mode(enabled: bool) {
+    match preferences::set_low_bandwidth_mode(enabled) {
+        Ok(()) => info!(enabled, "Saved low-bandwidth mode setting"),
+        Err(e) => error!("Failed to save low-bandwidth mode setting: {}", e),
+    }
+    update_latency_label();
+}
+
+/// Turn listening for the wake phrase on or off.
+pub(in crate::settings_window) fn set_wake_word_enabled(enabled: bool) {
+    match wake_word::set_enabled(enabled) {
+        Ok(()) => info!(enabled, "Saved wake word setting"),
+        Err(e) => error!("Failed to save wake word setting: {}", e),
+    }
+}
+
+/// Save the wake phrase from the text field.
+pub(in crate::settings_window) fn save_wake_word_phrase() {
+    let Some(inner) = settings_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        return;
+    };
+
+    // SAFETY: stringValue is safe on a valid NSTextField
+    let text = unsafe { inner.wake_word_phrase_field.stringValue() }.to_string();
+    let phrase = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if phrase.is_empty() {
+        warn!("Ignoring empty wake phrase");
+        return;
+    }
+
+    match wake_word::set_phrase(&phrase) {
+        Ok(()) => unsafe {
+            info!("Saved wake phrase");
+            inner
+                .wake_word_phrase_field
+                .setStringValue(&NSString::from_str(&phrase));
+        },
+        Err(e) => error!("Failed to save wake phrase: {}", e),
+    }
+}
+
+/// Turn stopping the recording when the screen locks on or off.
+pub(in crate::settings_window) fn set_stop_on_screen_lock(enabled: bool) {
+    match preferences::set_stop_on_screen_lock(enabled) {
+        Ok(()) => info!(enabled, "Saved stop on screen lock setting"),
+        Err(e) => error!("Failed to save stop on screen lock setting: {}", e),
+    }
+}
+
+/// Describe the saved settings' latency impact in the Audio tab.
+fn update_latency_label() {
+    let text = AudioTuning::from_preferences().latency_summary();
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .audio_latency_label
+                    .setStringValue(&NSString::from_str(&text));
+            }
+        }
+    }
+}
+
+/// Preset for a selected segment
+fn preset(presets: &[u32], selected_segment: isize) -> Option<u32> {
+    usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| presets.get(i).copied())
+}
@@ -1,21 +1,25 @@
 //! Azure credential management actions.
 
+use objc2::msg_send;
+use objc2_app_kit::{NSColor, NSTextField};
 use objc2_foundation::NSString;
 use tracing::{error, info};
-use zeroize::Zeroize;
 
+use crate::azure_openai::AzurePolishRoute;
+use crate::error::AzureInputError;
+use crate::event_bus::{self, AppEvent, Setting};
 use crate::{keychain, menubar};
 
-use super::super::SETTINGS_WINDOW;
+use super::super::settings_window;
 
 /// Save Azure credentials from the UI fields to keychain.
 pub(in crate::settings_window) fn save_azure_credentials() {
     // Extract values from UI while holding lock, then release lock before updating status
-    let (endpoint_url, stt_deployment, polish_deployment, mut api_key) = {
-        let Some(inner_cell) = SETTINGS_WINDOW.get() else {
+    let (endpoint_url, stt_deployment, polish_deployment, api_key, versions, polish_route) = {
+        let Some(inner_cell) = settings_window() else {
             return;
         };
-        let Ok(inner) = inner_cell.lock() else {
+        let Ok(inner) = inner_cell.try_borrow() else {
             return;
         };
 
@@ -27,48 +31,80 @@ pub(in crate::settings_window) fn save_azure_credentials() {
                 .stringValue()
                 .to_string()
         };
-        let key = unsafe { inner.azure_api_key_field.stringValue().to_string() };
+        let key = inner.azure_api_key_field.value();
+        let versions = [
+            &inner.azure_realtime_version_field,
+            &inner.azure_responses_version_field,
+        ]
+        .map(|field| {
+            let version = unsafe { field.stringValue().to_string() };
+            Some(version.trim().to_string()).filter(|v| !v.is_empty())
+        });
 
-        (endpoint, stt, polish, key)
+        // The first item detects the route; the rest follow AzurePolishRoute::ALL
+        let route_index: isize =
+            unsafe { msg_send![&inner.azure_polish_route_popup, indexOfSelectedItem] };
+        let route = usize::try_from(route_index - 1)
+            .ok()
+            .and_then(|index| AzurePolishRoute::ALL.get(index).copied());
+
+        (endpoint, stt, polish, key, versions, route)
     }; // Lock released here
+    let [realtime_api_version, responses_api_version] = versions;
+
+    // Validate inputs; the marks under the fields say what is wrong
+    let checks = check_fields(&endpoint_url, &stt_deployment, &polish_deployment, &api_key);
+    let Ok(endpoint_url) = keychain::normalize_endpoint(&endpoint_url) else {
+        error!("Cannot save Azure credentials: invalid endpoint");
+        update_azure_status("Status: Please fix the fields marked in red");
+        return;
+    };
+    if checks.iter().any(Result::is_err) {
+        error!("Cannot save Azure credentials: invalid fields");
+        update_azure_status("Status: Please fix the fields marked in red");
+        return;
+    }
+    let stt_deployment = stt_deployment.trim().to_string();
+    let polish_deployment = polish_deployment.trim().to_string();
 
-    // Validate inputs
-    if endpoint_url.is_empty()
-        || stt_deployment.is_empty()
-        || polish_deployment.is_empty()
-        || api_key.is_empty()
+    // Catch mistyped API versions now rather than at the next recording
+    if let Some(version) = [&realtime_api_version, &responses_api_version]
+        .into_iter()
+        .flatten()
+        .find(|v| !keychain::is_valid_api_version(v))
     {
-        error!("Cannot save Azure credentials: all fields are required");
-        update_azure_status("Status: Please fill all fields");
-        api_key.zeroize();
+        error!("Cannot save Azure credentials: invalid API version");
+        update_azure_status(&format!(
+            "Status: \"{}\" is not a valid API version (e.g. 2025-04-01-preview)",
+            version
+        ));
         return;
     }
 
     // Store in keychain
     let creds = keychain::AzureCredentials {
-        api_key: api_key.clone(),
+        api_key: api_key.trim().to_string(),
         endpoint_url,
         stt_deployment,
         polish_deployment,
+        realtime_api_version,
+        responses_api_version,
+        polish_route,
     };
-    api_key.zeroize();
+    // Zeroes the typed key
+    drop(api_key);
 
     match keychain::store_azure_credentials(&creds) {
         Ok(()) => {
             info!("Azure credentials saved to keychain");
             update_azure_status("Status: Credentials saved ✓");
+            event_bus::publish(AppEvent::SettingChanged {
+                setting: Setting::Credentials,
+            });
             // Update menu bar to enable recording
             menubar::MenuBar::set_azure_credentials(true);
-            // Clear the API key field after saving
-            if let Some(inner_cell) = SETTINGS_WINDOW.get() {
-                if let Ok(inner) = inner_cell.lock() {
-                    unsafe {
-                        inner
-                            .azure_api_key_field
-                            .setStringValue(&NSString::from_str("(stored in keychain)"));
-                    }
-                }
-            }
+            show_saved_values(&creds);
+            set_api_key_placeholder("(stored in keychain)");
         }
         Err(e) => {
             error!("Failed to save Azure credentials: {}", e);
@@ -83,6 +119,10 @@ pub(in crate::settings_window) fn clear_azure_credentials() {
         Ok(()) => {
             info!("Azure credentials cleared from keychain");
             update_azure_status("Status: Credentials cleared");
+            event_bus::publish(AppEvent::SettingChanged {
+                setting: Setting::Credentials,
+            });
+            set_api_key_placeholder("Enter API key");
             // Update menu bar to disable recording
             menubar::MenuBar::set_azure_credentials(false);
         }
@@ -93,10 +133,99 @@ pub(in crate::settings_window) fn clear_azure_credentials() {
     }
 }
 
+/// Check the endpoint, deployment and API key fields, mark each one, and
+/// enable Save only when all of them pass.
+pub(in crate::settings_window) fn validate_azure_fields() {
+    let Some(inner) = settings_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        return;
+    };
+
+    let [endpoint, stt, polish] = [
+        &inner.azure_endpoint_field,
+        &inner.azure_stt_deployment_field,
+        &inner.azure_polish_deployment_field,
+    ]
+    .map(|field| unsafe { field.stringValue().to_string() });
+    let key = inner.azure_api_key_field.value();
+    let checks = check_fields(&endpoint, &stt, &polish, &key);
+
+    for (label, check) in inner.azure_check_labels.iter().zip(&checks) {
+        show_check(label, check);
+    }
+    unsafe {
+        inner
+            .azure_save_button
+            .setEnabled(checks.iter().all(Result::is_ok));
+    }
+}
+
+/// Results for the endpoint, STT deployment, polish deployment and API key
+fn check_fields(
+    endpoint: &str,
+    stt_deployment: &str,
+    polish_deployment: &str,
+    api_key: &str,
+) -> [Result<(), AzureInputError>; 4] {
+    [
+        keychain::normalize_endpoint(endpoint).map(drop),
+        keychain::check_deployment(stt_deployment),
+        keychain::check_deployment(polish_deployment),
+        keychain::check_api_key(api_key),
+    ]
+}
+
+/// Show a green check or the red reason under a field; nothing while it is
+/// still empty
+fn show_check(label: &NSTextField, check: &Result<(), AzureInputError>) {
+    let (text, color) = match check {
+        Ok(()) => ("\u{2713}".to_string(), NSColor::systemGreenColor()),
+        Err(AzureInputError::Missing) => (String::new(), NSColor::systemRedColor()),
+        Err(e) => (format!("\u{2717} {}", e), NSColor::systemRedColor()),
+    };
+    unsafe {
+        label.setStringValue(&NSString::from_str(&text));
+        label.setTextColor(Some(&color));
+    }
+}
+
+/// Show the endpoint and deployments as they were saved, e.g. the endpoint
+/// without the path it was pasted with
+fn show_saved_values(creds: &keychain::AzureCredentials) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            for (field, value) in [
+                (&inner.azure_endpoint_field, &creds.endpoint_url),
+                (&inner.azure_stt_deployment_field, &creds.stt_deployment),
+                (
+                    &inner.azure_polish_deployment_field,
+                    &creds.polish_deployment,
+                ),
+            ] {
+                unsafe { field.setStringValue(&NSString::from_str(value)) };
+            }
+        }
+    }
+}
+
+/// Scrub the API key field and show `placeholder` in it.
+fn set_api_key_placeholder(placeholder: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            inner.azure_api_key_field.scrub();
+            inner.azure_api_key_field.set_placeholder(placeholder);
+        }
+    }
+    // Setting values in code doesn't send the edit notification
+    validate_azure_fields();
+}
+
 /// Update the Azure status label.
 pub(in crate::settings_window) fn update_azure_status(status: &str) {
-    if let Some(inner) = SETTINGS_WINDOW.get() {
-        if let Ok(inner) = inner.lock() {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
             unsafe {
                 inner
                     .azure_status_label
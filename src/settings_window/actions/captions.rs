@@ -0,0 +1,18 @@
+//! Live caption output actions.
+
+use tracing::{error, info, warn};
+
+use crate::captions::{self, CaptionOutput};
+
+/// Switch the caption output to the selected segment.
+pub(in crate::settings_window) fn set_caption_output(selected_segment: isize) {
+    let Some(output) = CaptionOutput::from_index(selected_segment) else {
+        warn!("Unknown caption output segment: {}", selected_segment);
+        return;
+    };
+
+    match captions::set_output(output) {
+        Ok(()) => info!(?output, "Saved caption output"),
+        Err(e) => error!("Failed to save caption output: {}", e),
+    }
+}
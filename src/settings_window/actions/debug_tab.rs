@@ -0,0 +1,13 @@
+//! Debug tab actions.
+
+use tracing::{error, info};
+
+use crate::preferences;
+
+/// Show or hide the Debug tab in the transcription window.
+pub(in crate::settings_window) fn set_debug_tab(enabled: bool) {
+    match preferences::set_debug_tab(enabled) {
+        Ok(()) => info!(enabled, "Saved Debug tab setting"),
+        Err(e) => error!("Failed to save Debug tab setting: {}", e),
+    }
+}
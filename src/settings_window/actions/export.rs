@@ -0,0 +1,113 @@
+//! Export destination (Notion) actions.
+
+use objc2::msg_send;
+use objc2_foundation::NSString;
+use tracing::{error, info};
+use zeroize::Zeroize;
+
+use crate::keychain::{self, NotionCredentials};
+use crate::{notion, preferences};
+
+use super::super::settings_window;
+
+/// Turn exporting meeting notes to Notion on or off.
+pub(in crate::settings_window) fn set_notion_export(enabled: bool) {
+    match preferences::set_notion_export(enabled) {
+        Ok(()) => info!(enabled, "Saved Notion export setting"),
+        Err(e) => error!("Failed to save Notion export setting: {}", e),
+    }
+}
+
+/// Save the Notion connection from the UI fields to keychain.
+pub(in crate::settings_window) fn save_notion() {
+    // Extract values from UI while holding lock, then release lock before updating status
+    let (mut token, database) = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: reading valid text fields on the main thread
+        unsafe {
+            (
+                inner.notion_token_field.stringValue().to_string(),
+                inner.notion_database_field.stringValue().to_string(),
+            )
+        }
+    }; // Lock released here
+
+    if token.trim().is_empty() || database.trim().is_empty() {
+        error!("Cannot save Notion connection: required fields are empty");
+        update_notion_status("Status: Please fill all fields");
+        token.zeroize();
+        return;
+    }
+    let Some(database_id) = notion::parse_database_id(&database) else {
+        update_notion_status("Status: Paste the database ID or the database link");
+        token.zeroize();
+        return;
+    };
+
+    let creds = NotionCredentials {
+        token: token.trim().to_string(),
+        database_id: database_id.clone(),
+    };
+    token.zeroize();
+
+    match keychain::store_notion_credentials(&creds) {
+        Ok(()) => {
+            info!("Notion connection saved to keychain");
+            update_notion_status("Status: Connected to Notion ✓");
+            // Show the normalized ID and clear the token field after saving
+            if let Some(inner) = settings_window() {
+                if let Ok(inner) = inner.try_borrow() {
+                    unsafe {
+                        inner
+                            .notion_database_field
+                            .setStringValue(&NSString::from_str(&database_id));
+                        inner
+                            .notion_token_field
+                            .setStringValue(&NSString::from_str(""));
+                        let _: () = msg_send![
+                            &inner.notion_token_field,
+                            setPlaceholderString: &*NSString::from_str("(stored in keychain)")
+                        ];
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            error!("Failed to save Notion connection: {}", e);
+            update_notion_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Clear the Notion connection from keychain.
+pub(in crate::settings_window) fn clear_notion() {
+    match keychain::delete_notion_credentials() {
+        Ok(()) => {
+            info!("Notion connection cleared from keychain");
+            update_notion_status("Status: Not set up");
+        }
+        Err(e) => {
+            error!("Failed to clear Notion connection: {}", e);
+            update_notion_status("Status: No connection to clear");
+        }
+    }
+}
+
+/// Update the Notion status label.
+fn update_notion_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .notion_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Sound cue, notification and clipboard option actions.
+
+use tracing::{error, info, warn};
+
+use crate::feedback::{self, FeedbackOption};
+use crate::preferences;
+use crate::recording::AutoCopy;
+use crate::transcription_window::TabType;
+
+/// Enable or disable the feedback option identified by a checkbox tag.
+pub(in crate::settings_window) fn set_feedback_option(tag: isize, enabled: bool) {
+    let Some(option) = FeedbackOption::from_index(tag) else {
+        warn!("Unknown feedback option tag: {}", tag);
+        return;
+    };
+
+    match feedback::set_option(option, enabled) {
+        Ok(()) => info!(?option, enabled, "Saved feedback option"),
+        Err(e) => error!("Failed to save feedback option: {}", e),
+    }
+}
+
+/// Save what is copied when the stop mode identified by a control tag finishes.
+pub(in crate::settings_window) fn set_auto_copy(tag: isize, selected_segment: isize) {
+    let mode = TabType::from_index(tag);
+    let Some(choice) = usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| AutoCopy::choices(mode).get(i).copied())
+    else {
+        warn!("Unknown auto-copy segment: {}", selected_segment);
+        return;
+    };
+
+    let mut settings = preferences::get_auto_copy_settings();
+    settings.set_for_mode(mode, choice);
+    match preferences::set_auto_copy_settings(settings) {
+        Ok(()) => info!(?mode, ?choice, "Saved auto-copy setting"),
+        Err(e) => error!("Failed to save auto-copy setting: {}", e),
+    }
+}
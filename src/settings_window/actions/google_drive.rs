@@ -0,0 +1,195 @@
+//! Google Drive connection actions.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use objc2::msg_send;
+use objc2_foundation::NSString;
+use tracing::{error, info};
+use zeroize::Zeroize;
+
+use crate::keychain::{self, GoogleDriveCredentials};
+use crate::{google_drive, preferences};
+
+use super::super::settings_window;
+
+/// Set while the browser sign-in is in progress
+static CONNECTING: AtomicBool = AtomicBool::new(false);
+
+/// Turn uploading saved transcripts to Google Drive on or off.
+pub(in crate::settings_window) fn set_google_drive_upload(enabled: bool) {
+    match preferences::set_google_drive_upload(enabled) {
+        Ok(()) => info!(enabled, "Saved Google Drive upload setting"),
+        Err(e) => error!("Failed to save Google Drive upload setting: {}", e),
+    }
+}
+
+/// Connect Google Drive with the OAuth client and folder from the UI fields.
+///
+/// Signs in through the browser. When only the folder changed, the saved
+/// sign-in is kept and the secret field may be left empty.
+pub(in crate::settings_window) fn connect_google_drive() {
+    if CONNECTING.load(Ordering::SeqCst) {
+        update_google_drive_status("Status: Finish signing in in your browser…");
+        return;
+    }
+
+    // Extract values from UI while holding lock, then release lock before updating status
+    let (client_id, mut client_secret, folder) = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: reading valid text fields on the main thread
+        unsafe {
+            (
+                inner.google_drive_client_id_field.stringValue().to_string(),
+                inner
+                    .google_drive_client_secret_field
+                    .stringValue()
+                    .to_string(),
+                inner.google_drive_folder_field.stringValue().to_string(),
+            )
+        }
+    }; // Lock released here
+
+    let client_id = client_id.trim().to_string();
+    let Some(folder_id) = google_drive::parse_folder_id(&folder) else {
+        update_google_drive_status("Status: Paste the folder ID or the folder link");
+        client_secret.zeroize();
+        return;
+    };
+    if client_id.is_empty() {
+        update_google_drive_status("Status: Please fill all fields");
+        client_secret.zeroize();
+        return;
+    }
+
+    let saved = keychain::get_google_drive_credentials()
+        .ok()
+        .filter(|saved| saved.client_id == client_id);
+    if client_secret.trim().is_empty() {
+        let Some(mut saved) = saved else {
+            update_google_drive_status("Status: Please fill all fields");
+            return;
+        };
+        // Same client and sign-in, new folder
+        saved.folder_id = folder_id.clone();
+        match keychain::store_google_drive_credentials(&saved) {
+            Ok(()) => {
+                info!("Google Drive folder saved to keychain");
+                update_google_drive_status("Status: Connected to Google Drive ✓");
+                show_folder_id(&folder_id);
+            }
+            Err(e) => {
+                error!("Failed to save Google Drive folder: {}", e);
+                update_google_drive_status("Status: Failed to save");
+            }
+        }
+        return;
+    }
+
+    let client_secret_value = client_secret.trim().to_string();
+    client_secret.zeroize();
+    CONNECTING.store(true, Ordering::SeqCst);
+    update_google_drive_status("Status: Finish signing in in your browser…");
+    show_folder_id(&folder_id);
+
+    tokio::spawn(async move {
+        let result = google_drive::authorize(&client_id, &client_secret_value).await;
+        let (status, connected) = match result {
+            Ok(refresh_token) => {
+                let creds = GoogleDriveCredentials {
+                    client_id,
+                    client_secret: client_secret_value,
+                    refresh_token,
+                    folder_id,
+                };
+                match keychain::store_google_drive_credentials(&creds) {
+                    Ok(()) => {
+                        info!("Google Drive connection saved to keychain");
+                        ("Status: Connected to Google Drive ✓", true)
+                    }
+                    Err(e) => {
+                        error!("Failed to save Google Drive connection: {}", e);
+                        ("Status: Failed to save", false)
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Google Drive sign-in failed: {}", e);
+                (
+                    "Status: Sign-in failed, check the client ID and secret",
+                    false,
+                )
+            }
+        };
+        CONNECTING.store(false, Ordering::SeqCst);
+
+        dispatch::Queue::main().exec_async(move || {
+            update_google_drive_status(status);
+            if connected {
+                clear_client_secret_field();
+            }
+        });
+    });
+}
+
+/// Clear the Google Drive connection from keychain.
+pub(in crate::settings_window) fn disconnect_google_drive() {
+    match keychain::delete_google_drive_credentials() {
+        Ok(()) => {
+            info!("Google Drive connection cleared from keychain");
+            update_google_drive_status("Status: Not set up");
+        }
+        Err(e) => {
+            error!("Failed to clear Google Drive connection: {}", e);
+            update_google_drive_status("Status: No connection to clear");
+        }
+    }
+}
+
+/// Show the normalized folder ID in place of a pasted link.
+fn show_folder_id(folder_id: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .google_drive_folder_field
+                    .setStringValue(&NSString::from_str(folder_id));
+            }
+        }
+    }
+}
+
+/// Clear the client secret field once it is stored.
+fn clear_client_secret_field() {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .google_drive_client_secret_field
+                    .setStringValue(&NSString::from_str(""));
+                let _: () = msg_send![
+                    &inner.google_drive_client_secret_field,
+                    setPlaceholderString: &*NSString::from_str("(stored in keychain)")
+                ];
+            }
+        }
+    }
+}
+
+/// Update the Google Drive status label.
+fn update_google_drive_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .google_drive_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Recording indicator actions.
+
+use tracing::{error, info, warn};
+
+use crate::menubar::{MenuBar, RecordingIndicator};
+use crate::preferences;
+use crate::recording_banner;
+
+/// Save the menu bar recording indicator for the selected segment.
+pub(in crate::settings_window) fn set_recording_indicator(selected_segment: isize) {
+    let Some(indicator) = RecordingIndicator::from_index(selected_segment) else {
+        warn!("Unknown recording indicator segment: {}", selected_segment);
+        return;
+    };
+
+    match preferences::set_recording_indicator(indicator) {
+        Ok(()) => {
+            info!(?indicator, "Saved recording indicator");
+            MenuBar::update_ui();
+        }
+        Err(e) => error!("Failed to save recording indicator: {}", e),
+    }
+}
+
+/// Turn the "Vissper is recording" banner on or off.
+pub(in crate::settings_window) fn set_recording_banner(enabled: bool) {
+    match preferences::set_recording_banner(enabled) {
+        Ok(()) => {
+            info!(enabled, "Saved recording banner setting");
+            recording_banner::refresh();
+        }
+        Err(e) => error!("Failed to save recording banner setting: {}", e),
+    }
+}
@@ -0,0 +1,51 @@
+//! Automation socket and HTTP API actions.
+
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::automation;
+
+use super::super::settings_window;
+
+/// Enable or disable the automation socket.
+pub(in crate::settings_window) fn set_automation_enabled(enabled: bool) {
+    match automation::set_enabled(enabled) {
+        Ok(()) => info!(enabled, "Saved automation socket setting"),
+        Err(e) => error!("Failed to save automation socket setting: {}", e),
+    }
+}
+
+/// Enable or disable the local HTTP API.
+pub(in crate::settings_window) fn set_http_api_enabled(enabled: bool) {
+    match automation::set_http_api_enabled(enabled) {
+        Ok(()) => info!(enabled, "Saved HTTP API setting"),
+        Err(e) => error!("Failed to save HTTP API setting: {}", e),
+    }
+}
+
+/// Generate a new HTTP API token and show it.
+pub(in crate::settings_window) fn regenerate_http_api_token() {
+    match automation::regenerate_http_api_token() {
+        Ok(token) => {
+            info!("Regenerated HTTP API token");
+            update_token_label(&format!("Token: {}", token));
+        }
+        Err(e) => {
+            error!("Failed to regenerate HTTP API token: {}", e);
+            update_token_label("Token: Failed to save new token");
+        }
+    }
+}
+
+/// Update the HTTP API token label.
+fn update_token_label(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .http_api_token_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Interface language action: save the choice and ask for a restart.
+
+use objc2_foundation::NSString;
+use tracing::{error, info, warn};
+
+use super::super::settings_window;
+use crate::localization::{tr, UiLanguage};
+use crate::preferences;
+
+/// Save the interface language for the selected segment.
+///
+/// Translations are loaded once per launch, so the section label is updated
+/// to tell the user the change applies after restarting.
+pub(in crate::settings_window) fn set_ui_language(selected_segment: isize) {
+    let Some(language) = usize::try_from(selected_segment)
+        .ok()
+        .and_then(|index| UiLanguage::ALL.get(index).copied())
+    else {
+        warn!("Unknown interface language segment: {}", selected_segment);
+        return;
+    };
+
+    if let Err(e) = preferences::set_ui_language(language.code()) {
+        error!("Failed to save interface language: {}", e);
+        return;
+    }
+    info!(code = language.code(), "Saved interface language");
+
+    let text = format!(
+        "{} – {}",
+        tr("settings.interface_language"),
+        tr("settings.restart_to_apply")
+    );
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .interface_language_label
+                    .setStringValue(&NSString::from_str(&text));
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Issue tracker (Jira / Linear) connection actions.
+
+use objc2::msg_send;
+use objc2_foundation::NSString;
+use tracing::{error, info, warn};
+use zeroize::Zeroize;
+
+use crate::issues::IssueTracker;
+use crate::keychain::{self, IssueTrackerCredentials};
+
+use super::super::settings_window;
+
+/// Enable the fields the selected tracker needs.
+pub(in crate::settings_window) fn set_issue_tracker(selected_segment: isize) {
+    let Some(tracker) = IssueTracker::from_index(selected_segment) else {
+        warn!("Unknown issue tracker segment: {}", selected_segment);
+        return;
+    };
+
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            // SAFETY: enabling valid text fields on the main thread
+            unsafe {
+                inner.issue_site_field.setEnabled(tracker.needs_site());
+                inner.issue_email_field.setEnabled(tracker.needs_site());
+            }
+        }
+    }
+
+    update_issue_status(match tracker {
+        IssueTracker::Jira => {
+            "Status: Jira needs the site URL, your account email, a project key and an API token"
+        }
+        IssueTracker::Linear => "Status: Linear needs a team key and a personal API key",
+    });
+}
+
+/// Save the issue tracker connection from the UI fields to keychain.
+pub(in crate::settings_window) fn save_issue_tracker() {
+    // Extract values from UI while holding lock, then release lock before updating status
+    let (selected, site_url, email, project_key, mut api_token) = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: reading valid controls on the main thread
+        unsafe {
+            (
+                inner.issue_tracker_selector.selectedSegment(),
+                inner.issue_site_field.stringValue().to_string(),
+                inner.issue_email_field.stringValue().to_string(),
+                inner.issue_project_field.stringValue().to_string(),
+                inner.issue_token_field.stringValue().to_string(),
+            )
+        }
+    }; // Lock released here
+
+    let tracker = IssueTracker::from_index(selected).unwrap_or_default();
+    let (site_url, email) = if tracker.needs_site() {
+        (site_url.trim().to_string(), email.trim().to_string())
+    } else {
+        (String::new(), String::new())
+    };
+    let project_key = project_key.trim().to_string();
+
+    let missing_site = tracker.needs_site() && (site_url.is_empty() || email.is_empty());
+    if missing_site || project_key.is_empty() || api_token.trim().is_empty() {
+        error!("Cannot save issue tracker connection: required fields are empty");
+        update_issue_status("Status: Please fill all fields");
+        api_token.zeroize();
+        return;
+    }
+    if tracker.needs_site() && !site_url.starts_with("https://") {
+        update_issue_status("Status: The site URL must start with https://");
+        api_token.zeroize();
+        return;
+    }
+
+    let creds = IssueTrackerCredentials {
+        tracker,
+        api_token: api_token.trim().to_string(),
+        site_url,
+        email,
+        project_key,
+    };
+    api_token.zeroize();
+
+    match keychain::store_issue_tracker_credentials(&creds) {
+        Ok(()) => {
+            info!(?tracker, "Issue tracker connection saved to keychain");
+            update_issue_status(&format!("Status: Connected to {} ✓", tracker.label()));
+            // Clear the token field after saving
+            if let Some(inner) = settings_window() {
+                if let Ok(inner) = inner.try_borrow() {
+                    unsafe {
+                        inner
+                            .issue_token_field
+                            .setStringValue(&NSString::from_str(""));
+                        let _: () = msg_send![
+                            &inner.issue_token_field,
+                            setPlaceholderString: &*NSString::from_str("(stored in keychain)")
+                        ];
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            error!("Failed to save issue tracker connection: {}", e);
+            update_issue_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Clear the issue tracker connection from keychain.
+pub(in crate::settings_window) fn clear_issue_tracker() {
+    match keychain::delete_issue_tracker_credentials() {
+        Ok(()) => {
+            info!("Issue tracker connection cleared from keychain");
+            update_issue_status("Status: Not set up");
+        }
+        Err(e) => {
+            error!("Failed to clear issue tracker connection: {}", e);
+            update_issue_status("Status: No connection to clear");
+        }
+    }
+}
+
+/// Update the issue tracker status label.
+fn update_issue_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .issue_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
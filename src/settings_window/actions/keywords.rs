@@ -0,0 +1,77 @@
+//! Keyword highlighting rule actions.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::keywords;
+use crate::transcription_window::TranscriptionWindow;
+
+use super::super::settings_window;
+
+/// Save the keyword rules list from the settings text view.
+pub(in crate::settings_window) fn save_keyword_rules() {
+    // Extract text from UI while holding lock
+    let rules_text = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: string is safe on a valid NSTextView
+        let text: Retained<NSString> =
+            unsafe { msg_send_id![&inner.keyword_rules_text_view, string] };
+        text.to_string()
+    }; // Lock released here
+
+    let rules = keywords::parse_rules(&rules_text);
+    let count = rules.len();
+    let formatted = keywords::format_rules(&rules);
+
+    match keywords::set_rules(rules) {
+        Ok(()) => {
+            info!("Saved {} keyword highlighting rules", count);
+            update_keyword_status(&format!("Status: {} keywords saved ✓", count));
+            set_rules_text(&formatted);
+
+            // Re-render the live transcript so the new rules apply immediately
+            if let Some(transcript) = TranscriptionWindow::get_live_transcript() {
+                TranscriptionWindow::update_live_text(&transcript, None);
+            }
+        }
+        Err(e) => {
+            error!("Failed to save keyword rules: {}", e);
+            update_keyword_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Replace the rules list with its normalized form.
+fn set_rules_text(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                let _: () = msg_send![
+                    &inner.keyword_rules_text_view,
+                    setString: &*NSString::from_str(text)
+                ];
+            }
+        }
+    }
+}
+
+/// Update the keyword status label.
+fn update_keyword_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .keyword_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
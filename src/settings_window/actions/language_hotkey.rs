@@ -0,0 +1,22 @@
+//! Language hotkey actions.
+
+use tracing::{error, info, warn};
+
+use crate::hotkeys::{self, LanguageHotkey};
+use crate::preferences;
+
+/// Save and register the language hotkey for the selected segment.
+pub(in crate::settings_window) fn set_language_hotkey(selected_segment: isize) {
+    let Some(preset) = LanguageHotkey::from_index(selected_segment) else {
+        warn!("Unknown language hotkey segment: {}", selected_segment);
+        return;
+    };
+
+    match preferences::set_language_hotkey(preset) {
+        Ok(()) => {
+            info!(?preset, "Saved language hotkey");
+            hotkeys::set_language_hotkey(preset);
+        }
+        Err(e) => error!("Failed to save language hotkey: {}", e),
+    }
+}
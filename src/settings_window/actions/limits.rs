@@ -0,0 +1,68 @@
+//! Session limit and stall timeout actions.
+
+use tracing::{error, info, warn};
+
+use crate::preferences;
+use crate::recording::{SessionLimits, STOP_AFTER_PRESETS, WARN_AFTER_PRESETS, WARN_WORDS_PRESETS};
+use crate::transcription::STALL_TIMEOUT_PRESETS;
+
+/// Save the duration warning for the selected segment.
+pub(in crate::settings_window) fn set_limit_warn_after(selected_segment: isize) {
+    if let Some(mins) = preset(&WARN_AFTER_PRESETS, selected_segment) {
+        save(|limits| limits.warn_after_mins = mins);
+    }
+}
+
+/// Save the hard recording limit for the selected segment.
+pub(in crate::settings_window) fn set_limit_stop_after(selected_segment: isize) {
+    if let Some(mins) = preset(&STOP_AFTER_PRESETS, selected_segment) {
+        save(|limits| limits.stop_after_mins = mins);
+    }
+}
+
+/// Save the transcript size warning for the selected segment.
+pub(in crate::settings_window) fn set_limit_warn_words(selected_segment: isize) {
+    if let Some(words) = preset(&WARN_WORDS_PRESETS, selected_segment) {
+        save(|limits| limits.warn_words = words);
+    }
+}
+
+/// Turn the reminder about a quiet recording on or off.
+pub(in crate::settings_window) fn set_idle_reminder(enabled: bool) {
+    save(|limits| limits.idle_reminder = enabled);
+}
+
+/// Save how long audio is sent without a reply before reconnecting.
+pub(in crate::settings_window) fn set_stall_timeout(selected_segment: isize) {
+    let Some(secs) = usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| STALL_TIMEOUT_PRESETS.get(i).copied())
+    else {
+        warn!("Unknown stall timeout segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_stall_timeout_secs(secs) {
+        Ok(()) => info!(secs, "Saved stall timeout"),
+        Err(e) => error!("Failed to save stall timeout: {}", e),
+    }
+}
+
+/// Preset at `selected_segment`, where `Some(None)` turns the limit off
+fn preset(presets: &[Option<u32>], selected_segment: isize) -> Option<Option<u32>> {
+    let preset = usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| presets.get(i).copied());
+    if preset.is_none() {
+        warn!("Unknown session limit segment: {}", selected_segment);
+    }
+    preset
+}
+
+fn save(change: impl FnOnce(&mut SessionLimits)) {
+    let mut limits = preferences::get_session_limits();
+    change(&mut limits);
+    match preferences::set_session_limits(limits) {
+        Ok(()) => info!(?limits, "Saved session limits"),
+        Err(e) => error!("Failed to save session limits: {}", e),
+    }
+}
@@ -0,0 +1,104 @@
+//! Microphone test actions.
+
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::mic_test::{self, Progress};
+use crate::preferences;
+
+use super::super::settings_window;
+
+/// Turn sending the microphone test to the speech service on or off.
+pub(in crate::settings_window) fn set_mic_test_transcribe(enabled: bool) {
+    match preferences::set_mic_test_transcribe(enabled) {
+        Ok(()) => info!(enabled, "Saved microphone test transcription setting"),
+        Err(e) => error!(
+            "Failed to save microphone test transcription setting: {}",
+            e
+        ),
+    }
+}
+
+/// Record, show and play back a test clip.
+pub(in crate::settings_window) fn test_microphone() {
+    let transcribe = preferences::get_mic_test_transcribe();
+    match mic_test::start(transcribe, report_progress) {
+        Ok(()) => update_labels("", "Speak now…", ""),
+        Err(e) => update_labels("", &e.to_string(), ""),
+    }
+}
+
+/// Play the last test clip again.
+pub(in crate::settings_window) fn play_mic_test() {
+    if let Err(e) = mic_test::play_again(report_progress) {
+        update_labels("", &e.to_string(), "");
+    }
+}
+
+/// Show the test's progress in the Microphone tab, from any thread.
+fn report_progress(progress: Progress) {
+    dispatch::Queue::main().exec_async(move || match progress {
+        Progress::Recording {
+            meter,
+            seconds_left,
+        } => update_labels(&meter, &format!("Speak now… {} s", seconds_left), ""),
+        Progress::Playing { waveform, verdict } => update_labels(
+            &waveform,
+            &format!("Playing back. {}", verdict.message()),
+            "",
+        ),
+        Progress::Transcribing => update_status("Sending the test to the speech service…"),
+        Progress::Transcript(text) => {
+            update_status("The speech service heard:");
+            update_transcript(&text);
+        }
+        Progress::Finished => {}
+        Progress::Failed(message) => update_status(&message),
+    });
+}
+
+/// Set the level, status and transcript labels together.
+fn update_labels(level: &str, status: &str, transcript: &str) {
+    update_level(level);
+    update_status(status);
+    update_transcript(transcript);
+}
+
+/// Update the level meter or waveform label.
+fn update_level(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .mic_test_level_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
+
+/// Update the microphone test status label.
+fn update_status(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .mic_test_status_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
+
+/// Update the label showing what the speech service heard.
+fn update_transcript(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .mic_test_transcript_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
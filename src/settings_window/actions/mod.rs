@@ -3,18 +3,93 @@
 //! This module contains handlers for user actions in the settings window,
 //! extracted to keep the main mod.rs focused on window creation and state.
 
+mod audio;
 mod azure;
+mod captions;
+mod debug_tab;
+mod export;
+mod feedback;
+mod google_drive;
+mod indicator;
+mod integrations;
+mod interface;
+mod issues;
+mod keywords;
+mod language_hotkey;
+mod limits;
+mod microphone;
+mod normalize;
+mod notes;
 mod openai;
 mod paths;
+mod pdf;
+mod privacy;
 mod provider;
+mod screenshots;
+mod secrets;
+mod stats;
+mod stop_hotkeys;
+mod storage;
+mod templates;
+mod workspaces;
 
-pub(super) use azure::{clear_azure_credentials, save_azure_credentials};
-pub(super) use openai::{clear_openai_credentials, save_openai_credentials};
+pub(super) use audio::{
+    save_wake_word_phrase, set_audio_buffer, set_audio_chunk, set_low_bandwidth_mode,
+    set_noise_reduction, set_prewarm_connection, set_stop_on_screen_lock, set_wake_word_enabled,
+};
+pub(super) use azure::{clear_azure_credentials, save_azure_credentials, validate_azure_fields};
+pub(super) use captions::set_caption_output;
+pub(super) use debug_tab::set_debug_tab;
+pub(super) use export::{clear_notion, save_notion, set_notion_export};
+pub(super) use feedback::{set_auto_copy, set_feedback_option};
+pub(super) use google_drive::{
+    connect_google_drive, disconnect_google_drive, set_google_drive_upload,
+};
+pub(super) use indicator::{set_recording_banner, set_recording_indicator};
+pub(super) use integrations::{
+    regenerate_http_api_token, set_automation_enabled, set_http_api_enabled,
+};
+pub(super) use interface::set_ui_language;
+pub(super) use issues::{clear_issue_tracker, save_issue_tracker, set_issue_tracker};
+pub(super) use keywords::save_keyword_rules;
+pub(super) use language_hotkey::set_language_hotkey;
+pub(super) use limits::{
+    set_idle_reminder, set_limit_stop_after, set_limit_warn_after, set_limit_warn_words,
+    set_stall_timeout,
+};
+pub(super) use microphone::{play_mic_test, set_mic_test_transcribe, test_microphone};
+pub(super) use normalize::{set_normalize_language, set_normalize_option};
+pub(super) use notes::{
+    save_consent_notice, save_output_modes, set_consent_notice_enabled, set_topic_sections,
+};
+pub(super) use openai::{
+    clear_openai_credentials, save_openai_credentials, set_openai_transcribe_model,
+};
 pub(super) use paths::{
     reset_screenshot_location, reset_transcript_location, show_folder_picker,
     show_screenshot_folder_picker,
 };
-pub(super) use provider::{create_provider_selector, handle_provider_selection};
+pub(super) use pdf::{set_pdf_header_footer, set_pdf_margins, set_pdf_page_size};
+pub(super) use privacy::{
+    clean_up_now, open_encrypted_file, save_screenshot_blocklist, set_encrypt_at_rest,
+    set_private_session_polish, set_retention_age, set_retention_size,
+};
+pub(super) use provider::{
+    create_provider_selector, handle_polish_provider_selection, handle_provider_selection,
+    set_provider_failover,
+};
+pub(super) use screenshots::{
+    set_screenshot_compress, set_screenshot_downscale, set_screenshot_exclude_own_windows,
+    set_screenshot_format, set_screenshot_quality,
+};
+pub(super) use secrets::reveal_secret;
+pub(super) use stats::{refresh_stats, reset_stats, set_metrics_enabled};
+pub(super) use stop_hotkeys::set_stop_hotkey;
+pub(super) use storage::{
+    save_git_remote, set_git_archive, set_git_archive_push, set_store_in_icloud,
+};
+pub(super) use templates::save_templates;
+pub(super) use workspaces::save_workspaces;
 
 // Re-export for use within action submodules
 use azure::update_azure_status;
@@ -0,0 +1,68 @@
+//! Spoken number, date and currency rewriting actions.
+
+use tracing::{error, info, warn};
+
+use crate::preferences;
+use crate::transcription::{NormalizeOption, Normalizer};
+
+use super::super::settings_window;
+
+/// Language code of a language selector segment.
+fn language_at(selected_segment: isize) -> Option<&'static str> {
+    let index = usize::try_from(selected_segment).ok()?;
+    Normalizer::LANGUAGES.get(index).map(|(code, _)| *code)
+}
+
+/// Show the rewrites saved for the selected language.
+pub(in crate::settings_window) fn set_normalize_language(selected_segment: isize) {
+    let Some(language) = language_at(selected_segment) else {
+        warn!(
+            "Unknown normalization language segment: {}",
+            selected_segment
+        );
+        return;
+    };
+    let options = preferences::get_normalize_options(language);
+
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            for (checkbox, option) in inner
+                .normalize_option_checkboxes
+                .iter()
+                .zip(NormalizeOption::ALL)
+            {
+                // SAFETY: updating a valid checkbox on the main thread
+                unsafe { checkbox.setState(if options.enabled(option) { 1 } else { 0 }) };
+            }
+        }
+    }
+}
+
+/// Turn the rewrite identified by a checkbox tag on or off for the selected language.
+pub(in crate::settings_window) fn set_normalize_option(tag: isize, enabled: bool) {
+    let Some(option) = NormalizeOption::from_index(tag) else {
+        warn!("Unknown normalization option tag: {}", tag);
+        return;
+    };
+    let selected = {
+        let Some(inner) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            return;
+        };
+        // SAFETY: reading a valid control on the main thread
+        unsafe { inner.normalize_language_selector.selectedSegment() }
+    };
+    let Some(language) = language_at(selected) else {
+        warn!("Unknown normalization language segment: {}", selected);
+        return;
+    };
+
+    let mut options = preferences::get_normalize_options(language);
+    options.set(option, enabled);
+    match preferences::set_normalize_options(language, options) {
+        Ok(()) => info!(language, ?option, enabled, "Saved normalization option"),
+        Err(e) => error!("Failed to save normalization option: {}", e),
+    }
+}
@@ -0,0 +1,125 @@
+//! Meeting notes and output mode actions.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_foundation::NSString;
+use tracing::{error, info, warn};
+
+use crate::{output_modes, preferences};
+
+use super::super::settings_window;
+
+/// Turn splitting meeting notes into topic sections on or off.
+pub(in crate::settings_window) fn set_topic_sections(enabled: bool) {
+    match preferences::set_topic_sections(enabled) {
+        Ok(()) => info!(enabled, "Saved topic sections setting"),
+        Err(e) => error!("Failed to save topic sections setting: {}", e),
+    }
+}
+
+/// Turn the consent notice at the top of exported meeting notes on or off.
+pub(in crate::settings_window) fn set_consent_notice_enabled(enabled: bool) {
+    let mut notice = preferences::get_consent_notice();
+    notice.enabled = enabled;
+    match preferences::set_consent_notice(notice) {
+        Ok(()) => info!(enabled, "Saved consent notice setting"),
+        Err(e) => error!("Failed to save consent notice setting: {}", e),
+    }
+}
+
+/// Save the consent notice text from the text field.
+pub(in crate::settings_window) fn save_consent_notice() {
+    let Some(inner) = settings_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        return;
+    };
+
+    // SAFETY: stringValue is safe on a valid NSTextField
+    let text = unsafe { inner.consent_notice_field.stringValue() }.to_string();
+    let text = text.trim();
+    if text.is_empty() {
+        warn!("Ignoring empty consent notice");
+        return;
+    }
+
+    let mut notice = preferences::get_consent_notice();
+    notice.text = text.to_string();
+    match preferences::set_consent_notice(notice) {
+        Ok(()) => unsafe {
+            info!("Saved consent notice text");
+            inner
+                .consent_notice_field
+                .setStringValue(&NSString::from_str(text));
+        },
+        Err(e) => error!("Failed to save consent notice text: {}", e),
+    }
+}
+
+/// Save the custom output modes from the settings text view.
+///
+/// The stop menu and tabs are built at launch, so the saved modes take
+/// effect after a restart.
+pub(in crate::settings_window) fn save_output_modes() {
+    // Extract text from UI while holding lock
+    let modes_text = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: string is safe on a valid NSTextView
+        let text: Retained<NSString> =
+            unsafe { msg_send_id![&inner.output_modes_text_view, string] };
+        text.to_string()
+    }; // Lock released here
+
+    let modes = output_modes::parse_modes(&modes_text);
+    let count = modes.len();
+    let formatted = output_modes::format_modes(&modes);
+
+    match preferences::set_custom_output_modes(modes) {
+        Ok(()) => {
+            info!("Saved {} custom output modes", count);
+            update_status(&format!(
+                "Status: {} modes saved ✓ Restart Vissper to apply",
+                count
+            ));
+            set_modes_text(&formatted);
+        }
+        Err(e) => {
+            error!("Failed to save output modes: {}", e);
+            update_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Replace the modes list with its normalized form.
+fn set_modes_text(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                let _: () = msg_send![
+                    &inner.output_modes_text_view,
+                    setString: &*NSString::from_str(text)
+                ];
+            }
+        }
+    }
+}
+
+/// Update the output modes status label.
+fn update_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .output_modes_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -1,60 +1,55 @@
 //! OpenAI credential management actions.
 
 use objc2_foundation::NSString;
-use tracing::{error, info};
-use zeroize::Zeroize;
+use tracing::{error, info, warn};
 
+use crate::event_bus::{self, AppEvent, Setting};
 use crate::preferences::{self, AiProvider};
+use crate::transcription::TranscribeModel;
 use crate::{keychain, menubar};
 
-use super::super::SETTINGS_WINDOW;
+use super::super::settings_window;
 
 /// Save OpenAI credentials from the UI fields to keychain.
 pub(in crate::settings_window) fn save_openai_credentials() {
     // Extract values from UI while holding lock
-    let mut api_key = {
-        let Some(inner_cell) = SETTINGS_WINDOW.get() else {
+    let api_key = {
+        let Some(inner_cell) = settings_window() else {
             return;
         };
-        let Ok(inner) = inner_cell.lock() else {
+        let Ok(inner) = inner_cell.try_borrow() else {
             return;
         };
 
-        unsafe { inner.openai_api_key_field.stringValue().to_string() }
+        inner.openai_api_key_field.value()
     }; // Lock released here
 
     // Validate input
-    if api_key.is_empty() || api_key == "(stored in keychain)" {
+    if api_key.is_empty() {
         error!("Cannot save OpenAI credentials: API key is required");
         update_openai_status("Status: Please enter your API key");
-        api_key.zeroize();
         return;
     }
 
     // Store in keychain
     let creds = keychain::OpenAICredentials {
-        api_key: api_key.clone(),
+        api_key: api_key.to_string(),
     };
-    api_key.zeroize();
+    // Zeroes the typed key
+    drop(api_key);
 
     match keychain::store_openai_credentials(&creds) {
         Ok(()) => {
             info!("OpenAI credentials saved to keychain");
             update_openai_status("Status: Credentials saved ✓");
+            event_bus::publish(AppEvent::SettingChanged {
+                setting: Setting::Credentials,
+            });
             // Update menu bar if OpenAI is the selected provider
             if preferences::get_ai_provider() == AiProvider::OpenAI {
                 menubar::MenuBar::set_azure_credentials(true);
             }
-            // Clear the API key field after saving
-            if let Some(inner_cell) = SETTINGS_WINDOW.get() {
-                if let Ok(inner) = inner_cell.lock() {
-                    unsafe {
-                        inner
-                            .openai_api_key_field
-                            .setStringValue(&NSString::from_str("(stored in keychain)"));
-                    }
-                }
-            }
+            set_api_key_placeholder("(stored in keychain)");
         }
         Err(e) => {
             error!("Failed to save OpenAI credentials: {}", e);
@@ -69,6 +64,10 @@ pub(in crate::settings_window) fn clear_openai_credentials() {
         Ok(()) => {
             info!("OpenAI credentials cleared from keychain");
             update_openai_status("Status: Credentials cleared");
+            event_bus::publish(AppEvent::SettingChanged {
+                setting: Setting::Credentials,
+            });
+            set_api_key_placeholder("sk-...");
             // Update menu bar if OpenAI is the selected provider
             if preferences::get_ai_provider() == AiProvider::OpenAI {
                 menubar::MenuBar::set_azure_credentials(false);
@@ -81,10 +80,33 @@ pub(in crate::settings_window) fn clear_openai_credentials() {
     }
 }
 
+/// Save the transcription model for the selected segment.
+pub(in crate::settings_window) fn set_openai_transcribe_model(selected_segment: isize) {
+    let Some(model) = TranscribeModel::from_index(selected_segment) else {
+        warn!("Unknown transcription model segment: {}", selected_segment);
+        return;
+    };
+
+    match preferences::set_openai_transcribe_model(model) {
+        Ok(()) => info!(?model, "Saved OpenAI transcription model"),
+        Err(e) => error!("Failed to save OpenAI transcription model: {}", e),
+    }
+}
+
+/// Scrub the API key field and show `placeholder` in it.
+fn set_api_key_placeholder(placeholder: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            inner.openai_api_key_field.scrub();
+            inner.openai_api_key_field.set_placeholder(placeholder);
+        }
+    }
+}
+
 /// Update the OpenAI status label.
 pub(in crate::settings_window) fn update_openai_status(status: &str) {
-    if let Some(inner) = SETTINGS_WINDOW.get() {
-        if let Ok(inner) = inner.lock() {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
             unsafe {
                 inner
                     .openai_status_label
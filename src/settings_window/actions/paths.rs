@@ -2,7 +2,7 @@
 
 use objc2_foundation::NSString;
 
-use super::super::{folder_picker, path_utils, SETTINGS_WINDOW};
+use super::super::{folder_picker, path_utils, settings_window};
 
 /// Show the folder picker dialog for selecting transcript location.
 pub(in crate::settings_window) fn show_folder_picker() {
@@ -20,8 +20,8 @@ pub(in crate::settings_window) fn reset_transcript_location() {
 
 /// Update the transcript path label with the current location.
 pub(in crate::settings_window) fn update_transcript_path_label() {
-    if let Some(inner) = SETTINGS_WINDOW.get() {
-        if let Ok(inner) = inner.lock() {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
             let display_path = path_utils::get_transcript_display_path();
             unsafe {
                 inner
@@ -48,8 +48,8 @@ pub(in crate::settings_window) fn reset_screenshot_location() {
 
 /// Update the screenshot path label with the current location.
 pub(in crate::settings_window) fn update_screenshot_path_label() {
-    if let Some(inner) = SETTINGS_WINDOW.get() {
-        if let Ok(inner) = inner.lock() {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
             let display_path = path_utils::get_screenshot_display_path();
             unsafe {
                 inner
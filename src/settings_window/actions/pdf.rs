@@ -0,0 +1,38 @@
+//! PDF page layout actions.
+
+use tracing::{error, info, warn};
+
+use crate::preferences;
+use crate::transcription_window::{PdfMargins, PdfPageSize, PdfSettings};
+
+/// Save the PDF page size for the selected segment.
+pub(in crate::settings_window) fn set_pdf_page_size(selected_segment: isize) {
+    let Some(page_size) = PdfPageSize::from_index(selected_segment) else {
+        warn!("Unknown PDF page size segment: {}", selected_segment);
+        return;
+    };
+    save(|settings| settings.page_size = page_size);
+}
+
+/// Save the PDF margins for the selected segment.
+pub(in crate::settings_window) fn set_pdf_margins(selected_segment: isize) {
+    let Some(margins) = PdfMargins::from_index(selected_segment) else {
+        warn!("Unknown PDF margins segment: {}", selected_segment);
+        return;
+    };
+    save(|settings| settings.margins = margins);
+}
+
+/// Turn the session title header and page number footer on or off.
+pub(in crate::settings_window) fn set_pdf_header_footer(enabled: bool) {
+    save(|settings| settings.header_footer = enabled);
+}
+
+fn save(change: impl FnOnce(&mut PdfSettings)) {
+    let mut settings = preferences::get_pdf_settings();
+    change(&mut settings);
+    match preferences::set_pdf_settings(settings) {
+        Ok(()) => info!(?settings, "Saved PDF settings"),
+        Err(e) => error!("Failed to save PDF settings: {}", e),
+    }
+}
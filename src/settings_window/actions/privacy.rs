@@ -0,0 +1,200 @@
+//! Privacy actions: private sessions, screenshot blocklist, encryption at rest
+//! and retention.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use objc2::rc::Retained;
+use objc2::{msg_send_id, ClassType};
+use objc2_app_kit::NSOpenPanel;
+use objc2_foundation::{MainThreadMarker, NSArray, NSString, NSURL};
+use tracing::{error, info, warn};
+
+use super::super::settings_window;
+use crate::retention::{self, AGE_PRESETS, SIZE_PRESETS_MB};
+use crate::settings_window::constants::NS_MODAL_RESPONSE_OK;
+use crate::{encryption, preferences, screenshot_blocklist, storage};
+
+/// Whether a cleanup started from Settings is still running
+static CLEANING_UP: AtomicBool = AtomicBool::new(false);
+
+/// Allow or disallow cloud polishing during private sessions.
+pub(in crate::settings_window) fn set_private_session_polish(allowed: bool) {
+    match preferences::set_private_session_polish(allowed) {
+        Ok(()) => info!(allowed, "Saved private session polishing setting"),
+        Err(e) => error!("Failed to save private session polishing setting: {}", e),
+    }
+}
+
+/// Save the apps during which screenshots are refused from the text field.
+pub(in crate::settings_window) fn save_screenshot_blocklist() {
+    let Some(inner) = settings_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        return;
+    };
+
+    // SAFETY: stringValue is safe on a valid NSTextField
+    let text = unsafe { inner.screenshot_blocklist_field.stringValue() }.to_string();
+    let apps = screenshot_blocklist::parse(&text);
+    let formatted = screenshot_blocklist::format(&apps);
+
+    match screenshot_blocklist::set_apps(apps) {
+        // Show the normalized list so duplicates and stray commas disappear
+        Ok(()) => unsafe {
+            inner
+                .screenshot_blocklist_field
+                .setStringValue(&NSString::from_str(&formatted));
+        },
+        Err(e) => error!("Failed to save screenshot blocklist: {}", e),
+    }
+}
+
+/// Enable or disable encryption at rest.
+///
+/// Returns `false` if the setting could not be applied (e.g. the keychain
+/// key could not be created), so the checkbox can be reset.
+pub(in crate::settings_window) fn set_encrypt_at_rest(enabled: bool) -> bool {
+    match encryption::set_enabled(enabled) {
+        Ok(()) => {
+            info!(enabled, "Saved encryption at rest setting");
+            true
+        }
+        Err(e) => {
+            error!("Failed to change encryption at rest: {}", e);
+            false
+        }
+    }
+}
+
+/// Let the user pick an encrypted file, then open a decrypted copy of it.
+pub(in crate::settings_window) fn open_encrypted_file() {
+    let Some(path) = choose_encrypted_file() else {
+        return;
+    };
+
+    match encryption::decrypt_to_temp(&path) {
+        Ok(decrypted) => {
+            info!("Opening decrypted copy of {:?}", path);
+            if let Err(e) = open::that(&decrypted) {
+                error!("Failed to open decrypted file: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to decrypt {:?}: {}", path, e),
+    }
+}
+
+/// Save the retention age limit for the selected segment.
+pub(in crate::settings_window) fn set_retention_age(selected_segment: isize) {
+    let Some(days) = preset(&AGE_PRESETS, selected_segment) else {
+        warn!("Unknown retention age segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_retention_days(days) {
+        Ok(()) => info!(?days, "Saved retention age limit"),
+        Err(e) => error!("Failed to save retention age limit: {}", e),
+    }
+}
+
+/// Save the disk usage cap for the selected segment.
+pub(in crate::settings_window) fn set_retention_size(selected_segment: isize) {
+    let Some(max_mb) = preset(&SIZE_PRESETS_MB, selected_segment) else {
+        warn!("Unknown retention size segment: {}", selected_segment);
+        return;
+    };
+    match preferences::set_retention_max_mb(max_mb) {
+        Ok(()) => info!(?max_mb, "Saved retention disk usage cap"),
+        Err(e) => error!("Failed to save retention disk usage cap: {}", e),
+    }
+}
+
+/// Apply the retention policy now and show what was reclaimed.
+///
+/// The folders are scanned and pruned on a background thread, and the
+/// summary is shown once it is done.
+pub(in crate::settings_window) fn clean_up_now() {
+    if CLEANING_UP.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    show_retention_summary("Cleaning up…");
+    std::thread::spawn(|| {
+        let text = match retention::run_cleanup() {
+            Ok(summary) => {
+                info!("Manual cleanup: {}", summary.describe());
+                retention::last_cleanup_description()
+            }
+            Err(e) => {
+                error!("Manual cleanup failed: {}", e);
+                format!("Cleanup failed: {}", e)
+            }
+        };
+        CLEANING_UP.store(false, Ordering::SeqCst);
+        dispatch::Queue::main().exec_async(move || show_retention_summary(&text));
+    });
+}
+
+/// Show `text` under the retention settings
+fn show_retention_summary(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .retention_summary_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
+
+/// Preset for a selected segment
+fn preset<T: Copy>(presets: &[Option<T>], selected_segment: isize) -> Option<Option<T>> {
+    usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| presets.get(i).copied())
+}
+
+/// Show an open panel limited to `.enc` files, starting in the transcripts folder.
+fn choose_encrypted_file() -> Option<PathBuf> {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Not on main thread, cannot show open panel");
+        return None;
+    };
+
+    // SAFETY: NSOpenPanel::openPanel is safe to call on main thread
+    let panel = unsafe { NSOpenPanel::openPanel(mtm) };
+
+    // SAFETY: These are standard NSOpenPanel configuration calls
+    unsafe {
+        panel.setCanChooseFiles(true);
+        panel.setCanChooseDirectories(false);
+        panel.setAllowsMultipleSelection(false);
+        panel.setMessage(Some(&NSString::from_str(
+            "Choose an encrypted transcript or screenshot",
+        )));
+        panel.setPrompt(Some(&NSString::from_str("Open")));
+
+        let extensions: Retained<NSArray<NSString>> =
+            NSArray::from_id_slice(&[NSString::from_str(encryption::ENCRYPTED_EXTENSION)]);
+        #[allow(deprecated)]
+        panel.setAllowedFileTypes(Some(&extensions));
+
+        if let Some(dir) = storage::transcripts_dir().filter(|d| d.exists()) {
+            let ns_path = NSString::from_str(&dir.to_string_lossy());
+            let url: Retained<NSURL> = msg_send_id![NSURL::class(), fileURLWithPath: &*ns_path];
+            panel.setDirectoryURL(Some(&url));
+        }
+    }
+
+    // SAFETY: runModal blocks until user dismisses the panel
+    let response = unsafe { panel.runModal() };
+    if response != NS_MODAL_RESPONSE_OK {
+        return None;
+    }
+
+    // SAFETY: URLs() returns a valid NSArray after successful modal
+    let urls = unsafe { panel.URLs() };
+    let url = urls.first()?;
+    // SAFETY: path() returns the file system path from a file URL
+    unsafe { url.path() }.map(|p| PathBuf::from(p.to_string()))
+}
@@ -1,4 +1,7 @@
 //! AI provider selection actions.
+//!
+//! Transcription and polishing each have a provider. Polishing follows the
+//! transcription provider unless set on its own.
 
 use objc2::rc::Retained;
 use objc2::sel;
@@ -6,13 +9,16 @@ use objc2_app_kit::{NSSegmentedControl, NSView};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
 use tracing::{error, info};
 
+use crate::localization::tr;
 use crate::preferences::{self, AiProvider};
-use crate::{keychain, menubar};
+use crate::{keychain, managed, menubar};
 
-use super::super::{constants, controls, SettingsActionDelegate, SETTINGS_WINDOW};
+use super::super::{constants, controls, settings_window, SettingsActionDelegate};
 use super::{update_azure_status, update_openai_status};
 
-/// Create the AI provider selector control.
+/// Create the transcription and polishing provider selectors.
+///
+/// Returns the transcription provider selector.
 pub(in crate::settings_window) fn create_provider_selector(
     mtm: MainThreadMarker,
     content_view: &NSView,
@@ -21,14 +27,15 @@ pub(in crate::settings_window) fn create_provider_selector(
     let content_width = content_view.frame().size.width;
     let control_width: CGFloat = 220.0;
     let control_height: CGFloat = 28.0;
-    let y_pos: CGFloat = 20.0; // Below Screenshot Location and separator
+    let y_pos: CGFloat = 48.0; // Below Screenshot Location and separator
 
     // Create label
     let label_frame = NSRect::new(
         NSPoint::new(constants::PADDING, y_pos + 6.0),
-        NSSize::new(100.0, 20.0),
+        NSSize::new(160.0, 20.0),
     );
-    let label = controls::create_section_label(mtm, label_frame, "AI Provider");
+    let label =
+        controls::create_section_label(mtm, label_frame, tr("settings.transcription_provider"));
 
     // Create segmented control
     let control_x = content_width - control_width - constants::PADDING;
@@ -53,14 +60,108 @@ pub(in crate::settings_window) fn create_provider_selector(
         sel!(handleProviderChanged:),
     );
 
+    if managed::policy().ai_provider.is_some() {
+        controls::lock_managed_control(&control);
+    }
+
+    // Between the label and the selector
+    let failover_checkbox = controls::create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(190.0, y_pos + 4.0), NSSize::new(260.0, 20.0)),
+        "Switch to the other provider on failure",
+        preferences::get_provider_failover(),
+        0,
+        delegate,
+        sel!(handleProviderFailoverToggle:),
+    );
+
     unsafe {
         content_view.addSubview(&label);
         content_view.addSubview(&control);
+        content_view.addSubview(&failover_checkbox);
     }
 
+    add_polish_provider_selector(mtm, content_view, delegate, y_pos - 36.0);
+
     control
 }
 
+/// Create the polishing provider selector below the transcription provider.
+fn add_polish_provider_selector(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    y_pos: CGFloat,
+) {
+    let content_width = content_view.frame().size.width;
+    let control_width: CGFloat = 340.0;
+
+    let label = controls::create_section_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(constants::PADDING, y_pos + 6.0),
+            NSSize::new(160.0, 20.0),
+        ),
+        tr("settings.polish_provider"),
+    );
+
+    let selected_segment = match preferences::get_polish_provider_setting() {
+        None => 0,
+        Some(AiProvider::Azure) => 1,
+        Some(AiProvider::OpenAI) => 2,
+    };
+    let control = controls::create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(content_width - control_width - constants::PADDING, y_pos),
+            NSSize::new(control_width, 28.0),
+        ),
+        &[tr("settings.polish_same"), "Azure OpenAI", "OpenAI"],
+        selected_segment,
+        delegate,
+        sel!(handlePolishProviderChanged:),
+    );
+
+    let policy = managed::policy();
+    if policy.ai_provider.is_some() || policy.disable_polishing {
+        controls::lock_managed_control(&control);
+    }
+
+    unsafe {
+        content_view.addSubview(&label);
+        content_view.addSubview(&control);
+    }
+}
+
+/// Turn failing over to the other provider on or off.
+pub(in crate::settings_window) fn set_provider_failover(enabled: bool) {
+    match preferences::set_provider_failover(enabled) {
+        Ok(()) => info!(enabled, "Saved provider failover setting"),
+        Err(e) => error!("Failed to save provider failover setting: {}", e),
+    }
+}
+
+/// Handle polishing provider selection change.
+pub(in crate::settings_window) fn handle_polish_provider_selection(selected_segment: isize) {
+    let provider = match selected_segment {
+        1 => Some(AiProvider::Azure),
+        2 => Some(AiProvider::OpenAI),
+        _ => None,
+    };
+
+    if let Err(e) = preferences::set_polish_provider(provider) {
+        error!("Failed to save polish provider preference: {}", e);
+        return;
+    }
+
+    let provider = preferences::get_polish_provider();
+    info!("Polish provider changed to: {}", provider);
+
+    if !credentials_saved(provider) {
+        show_missing_credentials(provider);
+    }
+}
+
 /// Handle AI provider selection change.
 pub(in crate::settings_window) fn handle_provider_selection(selected_segment: isize) {
     let provider = if selected_segment == 0 {
@@ -78,41 +179,54 @@ pub(in crate::settings_window) fn handle_provider_selection(selected_segment: is
     info!("AI provider changed to: {}", provider);
 
     // Check if credentials exist for the selected provider
-    let has_credentials = match provider {
-        AiProvider::Azure => keychain::get_azure_credentials().is_ok(),
-        AiProvider::OpenAI => keychain::get_openai_credentials().is_ok(),
-    };
+    let has_credentials = credentials_saved(provider);
 
     // Update menu bar state
     menubar::MenuBar::set_azure_credentials(has_credentials);
 
-    // If no credentials, switch to the appropriate tab and show warning
+    // Polishing may follow this provider, so check both
+    let polish_provider = preferences::get_polish_provider();
     if !has_credentials {
-        // Dispatch asynchronously to avoid potential deadlock
-        let tab_index: isize = match provider {
-            AiProvider::Azure => 1,
-            AiProvider::OpenAI => 2,
-        };
-        dispatch::Queue::main().exec_async(move || {
-            if let Some(inner) = SETTINGS_WINDOW.get() {
-                if let Ok(inner) = inner.lock() {
-                    // Switch to the appropriate credentials tab
-                    unsafe {
-                        inner.tab_view.selectTabViewItemAtIndex(tab_index);
-                    }
+        show_missing_credentials(provider);
+    } else if !credentials_saved(polish_provider) {
+        show_missing_credentials(polish_provider);
+    }
+}
+
+/// Whether credentials for `provider` are in the keychain.
+fn credentials_saved(provider: AiProvider) -> bool {
+    match provider {
+        AiProvider::Azure => keychain::get_azure_credentials().is_ok(),
+        AiProvider::OpenAI => keychain::get_openai_credentials().is_ok(),
+    }
+}
+
+/// Switch to the provider's credentials tab and show a warning there.
+fn show_missing_credentials(provider: AiProvider) {
+    // Dispatch asynchronously to avoid potential deadlock
+    let tab_index: isize = match provider {
+        AiProvider::Azure => 1,
+        AiProvider::OpenAI => 2,
+    };
+    dispatch::Queue::main().exec_async(move || {
+        if let Some(inner) = settings_window() {
+            if let Ok(inner) = inner.try_borrow() {
+                // Switch to the appropriate credentials tab
+                unsafe {
+                    inner.tab_view.selectTabViewItemAtIndex(tab_index);
                 }
             }
+        }
 
-            // Update the status label with a warning
-            let warning = "Please enter credentials to use this provider";
-            match provider {
-                AiProvider::Azure => {
-                    update_azure_status(warning);
-                }
-                AiProvider::OpenAI => {
-                    update_openai_status(warning);
-                }
+        // Update the status label with a warning
+        let warning = "Please enter credentials to use this provider";
+        match provider {
+            AiProvider::Azure => {
+                update_azure_status(warning);
             }
-        });
-    }
+            AiProvider::OpenAI => {
+                update_openai_status(warning);
+            }
+        }
+    });
 }
@@ -0,0 +1,51 @@
+//! Screenshot format, resolution and capture actions.
+
+use tracing::{error, info, warn};
+
+use crate::preferences;
+use crate::screenshot::{ScreenshotFormat, ScreenshotSettings, QUALITY_PRESETS};
+
+/// Save the screenshot format for the selected segment.
+pub(in crate::settings_window) fn set_screenshot_format(selected_segment: isize) {
+    let Some(format) = ScreenshotFormat::from_index(selected_segment) else {
+        warn!("Unknown screenshot format segment: {}", selected_segment);
+        return;
+    };
+    save(|settings| settings.format = format);
+}
+
+/// Save the JPEG and HEIC quality for the selected segment.
+pub(in crate::settings_window) fn set_screenshot_quality(selected_segment: isize) {
+    let Some(quality) = usize::try_from(selected_segment)
+        .ok()
+        .and_then(|i| QUALITY_PRESETS.get(i).copied())
+    else {
+        warn!("Unknown screenshot quality segment: {}", selected_segment);
+        return;
+    };
+    save(|settings| settings.quality = quality);
+}
+
+/// Turn saving Retina screenshots at standard resolution on or off.
+pub(in crate::settings_window) fn set_screenshot_downscale(enabled: bool) {
+    save(|settings| settings.downscale_retina = enabled);
+}
+
+/// Turn saving large PNG screenshots as JPEG on or off.
+pub(in crate::settings_window) fn set_screenshot_compress(enabled: bool) {
+    save(|settings| settings.compress_large = enabled);
+}
+
+/// Turn leaving Vissper's own windows out of screenshots on or off.
+pub(in crate::settings_window) fn set_screenshot_exclude_own_windows(enabled: bool) {
+    save(|settings| settings.exclude_own_windows = enabled);
+}
+
+fn save(change: impl FnOnce(&mut ScreenshotSettings)) {
+    let mut settings = preferences::get_screenshot_settings();
+    change(&mut settings);
+    match preferences::set_screenshot_settings(settings) {
+        Ok(()) => info!(?settings, "Saved screenshot settings"),
+        Err(e) => error!("Failed to save screenshot settings: {}", e),
+    }
+}
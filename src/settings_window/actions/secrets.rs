@@ -0,0 +1,27 @@
+//! Show checkbox actions of the masked API key fields.
+
+use tracing::warn;
+
+use crate::settings_window::controls::{AZURE_API_KEY_TAG, OPENAI_API_KEY_TAG};
+
+use super::super::settings_window;
+
+/// Show the key typed into the field with `tag` in the clear, or mask it.
+pub(in crate::settings_window) fn reveal_secret(tag: isize, revealed: bool) {
+    let Some(inner) = settings_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        return;
+    };
+
+    let field = match tag {
+        AZURE_API_KEY_TAG => &inner.azure_api_key_field,
+        OPENAI_API_KEY_TAG => &inner.openai_api_key_field,
+        _ => {
+            warn!("Unknown API key field tag: {}", tag);
+            return;
+        }
+    };
+    field.set_revealed(revealed);
+}
@@ -0,0 +1,62 @@
+//! Local usage statistics actions.
+
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::{metrics, preferences, transcription};
+
+use super::super::settings_window;
+
+/// Turn local usage statistics on or off.
+pub(in crate::settings_window) fn set_metrics_enabled(enabled: bool) {
+    match preferences::set_metrics_enabled(enabled) {
+        Ok(()) => info!(enabled, "Saved usage statistics setting"),
+        Err(e) => error!("Failed to save usage statistics setting: {}", e),
+    }
+}
+
+/// Reload the statistics and measured latency shown in the Stats tab.
+pub(in crate::settings_window) fn refresh_stats() {
+    update_summary_label(&metrics::load().summary());
+    update_latency_label(&transcription::latency_summary());
+}
+
+/// Delete recorded statistics and show the empty summary.
+pub(in crate::settings_window) fn reset_stats() {
+    match metrics::reset() {
+        Ok(()) => {
+            info!("Reset usage statistics");
+            refresh_stats();
+        }
+        Err(e) => {
+            error!("Failed to reset usage statistics: {}", e);
+            update_summary_label("Failed to reset statistics");
+        }
+    }
+}
+
+/// Update the statistics summary label.
+fn update_summary_label(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .stats_summary_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
+
+/// Update the measured latency label.
+fn update_latency_label(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .stats_latency_label
+                    .setStringValue(&NSString::from_str(text));
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Stop hotkey actions.
+
+use tracing::{error, info, warn};
+
+use crate::hotkeys::{self, StopHotkey};
+use crate::preferences;
+use crate::transcription_window::TabType;
+
+/// Save and register the stop hotkey of the mode tagged `tag` for the
+/// selected segment.
+pub(in crate::settings_window) fn set_stop_hotkey(tag: isize, selected_segment: isize) {
+    let mode = TabType::from_index(tag);
+    let Some(preset) = StopHotkey::from_index(selected_segment) else {
+        warn!("Unknown stop hotkey segment: {}", selected_segment);
+        return;
+    };
+
+    let mut stop_hotkeys = preferences::get_stop_hotkeys();
+    stop_hotkeys.set(mode, preset);
+    match preferences::set_stop_hotkeys(stop_hotkeys) {
+        Ok(()) => {
+            info!(?mode, ?preset, "Saved stop hotkey");
+            hotkeys::set_stop_hotkeys(stop_hotkeys);
+        }
+        Err(e) => error!("Failed to save stop hotkey: {}", e),
+    }
+}
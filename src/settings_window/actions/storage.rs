@@ -0,0 +1,96 @@
+//! Storage actions (git archive and iCloud Drive).
+
+use objc2_foundation::NSString;
+use tracing::{error, info};
+use url::Url;
+
+use crate::preferences;
+
+use super::super::settings_window;
+use super::paths::update_transcript_path_label;
+
+/// Turn committing saved transcripts to git on or off.
+pub(in crate::settings_window) fn set_git_archive(enabled: bool) {
+    match preferences::set_git_archive(enabled) {
+        Ok(()) => info!(enabled, "Saved git archive setting"),
+        Err(e) => error!("Failed to save git archive setting: {}", e),
+    }
+}
+
+/// Turn pushing git archive commits on or off.
+pub(in crate::settings_window) fn set_git_archive_push(enabled: bool) {
+    match preferences::set_git_archive_push(enabled) {
+        Ok(()) => info!(enabled, "Saved git archive push setting"),
+        Err(e) => error!("Failed to save git archive push setting: {}", e),
+    }
+}
+
+/// Save the git archive remote URL from the settings field.
+///
+/// An empty field clears the remote. URLs with a password are refused,
+/// since preferences are stored in plain text.
+pub(in crate::settings_window) fn save_git_remote() {
+    let remote = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+        // SAFETY: reading a valid text field on the main thread
+        unsafe { inner.git_remote_field.stringValue().to_string() }
+    };
+    let remote = remote.trim();
+
+    if remote.chars().any(char::is_whitespace) {
+        update_git_status("Status: The remote URL can't contain spaces");
+        return;
+    }
+    if Url::parse(remote).is_ok_and(|url| url.password().is_some()) {
+        update_git_status(
+            "Status: Remove the password from the URL and use a git credential helper",
+        );
+        return;
+    }
+
+    let url = (!remote.is_empty()).then(|| remote.to_string());
+    let cleared = url.is_none();
+    match preferences::set_git_archive_remote(url) {
+        Ok(()) => {
+            info!(cleared, "Saved git archive remote");
+            update_git_status(if cleared {
+                "Status: Remote cleared"
+            } else {
+                "Status: Remote saved ✓"
+            });
+        }
+        Err(e) => {
+            error!("Failed to save git archive remote: {}", e);
+            update_git_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Turn storing transcripts in iCloud Drive on or off.
+pub(in crate::settings_window) fn set_store_in_icloud(enabled: bool) {
+    match preferences::set_store_in_icloud(enabled) {
+        Ok(()) => {
+            info!(enabled, "Saved iCloud Drive storage setting");
+            update_transcript_path_label();
+        }
+        Err(e) => error!("Failed to save iCloud Drive storage setting: {}", e),
+    }
+}
+
+/// Update the git archive status label.
+fn update_git_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .git_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Recording template actions.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::{preferences, templates};
+
+use super::super::settings_window;
+
+/// Save the recording templates from the settings text view.
+pub(in crate::settings_window) fn save_templates() {
+    // Extract text from UI while holding lock
+    let text = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: string is safe on a valid NSTextView
+        let text: Retained<NSString> = unsafe { msg_send_id![&inner.templates_text_view, string] };
+        text.to_string()
+    }; // Lock released here
+
+    let parsed = templates::parse(&text);
+    let count = parsed.len();
+    let formatted = templates::format(&parsed);
+
+    match preferences::set_templates(parsed) {
+        Ok(()) => {
+            info!("Saved {} recording templates", count);
+            update_status(&format!("Status: {} templates saved ✓", count));
+            set_templates_text(&formatted);
+        }
+        Err(e) => {
+            error!("Failed to save recording templates: {}", e);
+            update_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Replace the templates list with its normalized form.
+fn set_templates_text(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                let _: () = msg_send![
+                    &inner.templates_text_view,
+                    setString: &*NSString::from_str(text)
+                ];
+            }
+        }
+    }
+}
+
+/// Update the templates status label.
+fn update_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .templates_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Workspace actions.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_foundation::NSString;
+use tracing::{error, info};
+
+use crate::{preferences, workspaces};
+
+use super::super::settings_window;
+
+/// Save the workspaces from the settings text view.
+///
+/// Switches back to Default if the active workspace was removed.
+pub(in crate::settings_window) fn save_workspaces() {
+    // Extract text from UI while holding lock
+    let text = {
+        let Some(inner_cell) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner_cell.try_borrow() else {
+            return;
+        };
+
+        // SAFETY: string is safe on a valid NSTextView
+        let text: Retained<NSString> = unsafe { msg_send_id![&inner.workspaces_text_view, string] };
+        text.to_string()
+    }; // Lock released here
+
+    let parsed = workspaces::parse(&text);
+    let count = parsed.len();
+    let formatted = workspaces::format(&parsed);
+
+    match preferences::set_workspaces(parsed) {
+        Ok(()) => {
+            info!("Saved {} workspaces", count);
+            update_status(&format!(
+                "Status: {} workspaces saved ✓ Active: {}",
+                count,
+                workspaces::active_name()
+            ));
+            set_workspaces_text(&formatted);
+        }
+        Err(e) => {
+            error!("Failed to save workspaces: {}", e);
+            update_status("Status: Failed to save");
+        }
+    }
+}
+
+/// Replace the workspaces list with its normalized form.
+fn set_workspaces_text(text: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                let _: () = msg_send![
+                    &inner.workspaces_text_view,
+                    setString: &*NSString::from_str(text)
+                ];
+            }
+        }
+    }
+}
+
+/// Update the workspaces status label.
+fn update_status(status: &str) {
+    if let Some(inner) = settings_window() {
+        if let Ok(inner) = inner.try_borrow() {
+            unsafe {
+                inner
+                    .workspaces_status_label
+                    .setStringValue(&NSString::from_str(status));
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Audio latency settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+};
+use crate::audio::{AudioTuning, BUFFER_SECS_PRESETS, CHUNK_MS_PRESETS};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription::NoiseReduction;
+
+/// Segment labels for `audio::CHUNK_MS_PRESETS`
+const CHUNK_LABELS: [&str; 5] = ["50 ms", "100 ms", "200 ms", "300 ms", "500 ms"];
+
+/// Segment labels for `audio::BUFFER_SECS_PRESETS`
+const BUFFER_LABELS: [&str; 4] = ["15 s", "30 s", "60 s", "2 min"];
+
+/// Audio controls returned to caller for state management.
+pub(crate) struct AudioControls {
+    pub(crate) latency_label: Retained<NSTextField>,
+}
+
+/// Add audio latency controls to the content view.
+///
+/// Creates a Latency section with chunk duration, buffer depth and noise
+/// reduction selectors, checkboxes for connecting ahead of time and
+/// low-bandwidth mode, all saved immediately, and a summary of how they
+/// affect latency.
+pub(crate) fn add_audio_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    tuning: &AudioTuning,
+    prewarm_connection: bool,
+    low_bandwidth_mode: bool,
+    noise_reduction: NoiseReduction,
+) -> AudioControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 345.0), NSSize::new(inner_width, 20.0)),
+        "Latency",
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 314.0), NSSize::new(inner_width, 30.0)),
+        "Advanced. Smaller chunks show words sooner; a deeper buffer rides out slow \
+         connections without losing audio.",
+    );
+
+    let row_label_width: CGFloat = 150.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+    let selector_width: CGFloat = 400.0;
+
+    let chunk_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 284.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Chunk duration",
+    );
+    let chunk_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 280.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &CHUNK_LABELS,
+        preset_index(&CHUNK_MS_PRESETS, tuning.chunk_ms),
+        delegate,
+        sel!(handleAudioChunkChanged:),
+    );
+
+    let buffer_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 252.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Buffer depth",
+    );
+    let buffer_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 248.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &BUFFER_LABELS,
+        preset_index(&BUFFER_SECS_PRESETS, tuning.buffer_secs),
+        delegate,
+        sel!(handleAudioBufferChanged:),
+    );
+
+    let noise_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 220.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Noise reduction",
+    );
+    let noise_labels: Vec<&str> = NoiseReduction::ALL.iter().map(|m| m.label()).collect();
+    let noise_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 216.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &noise_labels,
+        noise_reduction.to_index(),
+        delegate,
+        sel!(handleNoiseReductionChanged:),
+    );
+
+    let prewarm_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 190.0), NSSize::new(inner_width, 24.0)),
+        "Connect when the menu bar menu opens, so recording starts instantly",
+        prewarm_connection,
+        0,
+        delegate,
+        sel!(handlePrewarmConnectionToggle:),
+    );
+
+    let low_bandwidth_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 166.0), NSSize::new(inner_width, 24.0)),
+        "Low-bandwidth mode: send compressed 8 kHz audio in larger chunks, for tethering \
+         or busy Wi-Fi",
+        low_bandwidth_mode,
+        0,
+        delegate,
+        sel!(handleLowBandwidthModeToggle:),
+    );
+
+    let latency_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 136.0), NSSize::new(inner_width, 30.0)),
+        &tuning.latency_summary(),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&help_label);
+        content_view.addSubview(&chunk_label);
+        content_view.addSubview(&chunk_selector);
+        content_view.addSubview(&buffer_label);
+        content_view.addSubview(&buffer_selector);
+        content_view.addSubview(&noise_label);
+        content_view.addSubview(&noise_selector);
+        content_view.addSubview(&prewarm_checkbox);
+        content_view.addSubview(&low_bandwidth_checkbox);
+        content_view.addSubview(&latency_label);
+    }
+
+    AudioControls { latency_label }
+}
+
+/// Segment index for a saved value, or -1 if it isn't one of the presets
+fn preset_index(presets: &[u32], value: u32) -> isize {
+    presets
+        .iter()
+        .position(|&preset| preset == value)
+        .map_or(-1, |i| i as isize)
+}
@@ -5,11 +5,16 @@
 
 use objc2::rc::Retained;
 use objc2::{msg_send, msg_send_id};
-use objc2_app_kit::NSTextField;
+use objc2_app_kit::{NSButton, NSPopUpButton, NSTextField};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
 
-use super::helpers::{create_section_label, create_small_button};
-use crate::keychain::AzureCredentials;
+use super::helpers::{create_section_label, create_small_button, lock_managed_control};
+use super::secret_field::{SecretField, AZURE_API_KEY_TAG};
+use crate::azure_openai::AzurePolishRoute;
+use crate::keychain::{
+    AzureCredentials, DEFAULT_REALTIME_API_VERSION, DEFAULT_RESPONSES_API_VERSION,
+};
+use crate::managed;
 use crate::settings_window::constants::PADDING;
 use crate::settings_window::delegate::SettingsActionDelegate;
 
@@ -18,9 +23,19 @@ pub(crate) struct AzureControls {
     pub(crate) endpoint_field: Retained<NSTextField>,
     pub(crate) stt_deployment_field: Retained<NSTextField>,
     pub(crate) polish_deployment_field: Retained<NSTextField>,
-    /// API key field. Note: Uses NSTextField since objc2-app-kit doesn't export NSSecureTextField.
-    /// The API key is stored securely in the macOS Keychain.
-    pub(crate) api_key_field: Retained<NSTextField>,
+    /// Masked API key field; the key itself is stored in the macOS Keychain
+    pub(crate) api_key_field: SecretField,
+    /// Realtime API version (empty = default)
+    pub(crate) realtime_version_field: Retained<NSTextField>,
+    /// Responses API version (empty = default)
+    pub(crate) responses_version_field: Retained<NSTextField>,
+    /// Polish route: "Detect automatically" followed by [`AzurePolishRoute::ALL`]
+    pub(crate) polish_route_popup: Retained<NSPopUpButton>,
+    /// Check results under the endpoint, STT deployment, polish deployment
+    /// and API key fields
+    pub(crate) check_labels: [Retained<NSTextField>; 4],
+    /// Enabled only while every checked field passes
+    pub(crate) save_button: Retained<NSButton>,
     pub(crate) status_label: Retained<NSTextField>,
 }
 
@@ -29,10 +44,16 @@ pub(crate) struct AzureControls {
 /// Creates a section with two-column layout:
 /// - Row 1: Endpoint URL | STT Deployment
 /// - Row 2: Polish Deployment | API Key
+/// - Row 3: Realtime API Version | Responses API Version (empty = default)
+/// - Row 4: Polish Route
 /// - Status label and save/clear buttons
 ///
+/// The endpoint, deployment and API key fields are checked as they are typed,
+/// with the result shown under each one.
+///
 /// If `saved_credentials` is provided, the fields will be populated with saved values
-/// (except API key which remains empty for security).
+/// (except API key which remains empty for security). Fields locked by a managed
+/// policy show the enforced value and can't be edited.
 pub(crate) fn add_azure_controls(
     mtm: MainThreadMarker,
     content_view: &objc2_app_kit::NSView,
@@ -54,7 +75,7 @@ pub(crate) fn add_azure_controls(
     let right_x = PADDING + column_width + column_gap;
 
     // Section header
-    let section_y: CGFloat = 280.0;
+    let section_y: CGFloat = 320.0;
     let section_label_frame = NSRect::new(
         NSPoint::new(PADDING, section_y),
         NSSize::new(content_width - PADDING * 2.0, 20.0),
@@ -66,8 +87,8 @@ pub(crate) fn add_azure_controls(
     );
 
     // Row 1: Endpoint URL (left) | STT Deployment (right)
-    let row1_label_y: CGFloat = 245.0;
-    let row1_field_y: CGFloat = 220.0;
+    let row1_label_y: CGFloat = 285.0;
+    let row1_field_y: CGFloat = 260.0;
 
     // Endpoint URL (left column)
     let endpoint_label =
@@ -104,8 +125,8 @@ pub(crate) fn add_azure_controls(
     }
 
     // Row 2: Polish Deployment (left) | API Key (right)
-    let row2_label_y: CGFloat = 180.0;
-    let row2_field_y: CGFloat = 155.0;
+    let row2_label_y: CGFloat = 225.0;
+    let row2_field_y: CGFloat = 200.0;
 
     // Polish Deployment (left column)
     let polish_label =
@@ -126,7 +147,7 @@ pub(crate) fn add_azure_controls(
 
     // API Key (right column)
     let key_label = create_field_label_at(mtm, right_x, row2_label_y, column_width, "API Key");
-    let api_key_field = create_text_field(
+    let api_key_field = SecretField::new(
         mtm,
         NSRect::new(
             NSPoint::new(right_x, row2_field_y),
@@ -137,10 +158,107 @@ pub(crate) fn add_azure_controls(
         } else {
             "Enter API key"
         },
+        AZURE_API_KEY_TAG,
+        delegate,
+    );
+
+    // Row 3: API versions, left empty to use the defaults
+    let row3_label_y: CGFloat = 165.0;
+    let row3_field_y: CGFloat = 140.0;
+
+    let realtime_version_label = create_field_label_at(
+        mtm,
+        left_x,
+        row3_label_y,
+        column_width,
+        "Realtime API Version",
+    );
+    let realtime_version_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, row3_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        DEFAULT_REALTIME_API_VERSION,
+    );
+
+    let responses_version_label = create_field_label_at(
+        mtm,
+        right_x,
+        row3_label_y,
+        column_width,
+        "Responses API Version",
+    );
+    let responses_version_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, row3_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        DEFAULT_RESPONSES_API_VERSION,
+    );
+
+    if let Some(creds) = saved_credentials {
+        for (field, version) in [
+            (&realtime_version_field, &creds.realtime_api_version),
+            (&responses_version_field, &creds.responses_api_version),
+        ] {
+            if let Some(version) = version {
+                unsafe { field.setStringValue(&NSString::from_str(version)) };
+            }
+        }
+    }
+
+    // Row 4: how polish requests reach the resource
+    let row4_label_y: CGFloat = 105.0;
+    let row4_field_y: CGFloat = 78.0;
+
+    let route_label =
+        create_field_label_at(mtm, left_x, row4_label_y, column_width, "Polish Route");
+    let saved_route = saved_credentials.and_then(|creds| creds.polish_route);
+    let polish_route_popup = create_route_popup(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, row4_field_y),
+            NSSize::new(column_width, field_height + 4.0),
+        ),
+        saved_route,
     );
 
+    // Show and lock the connection settings enforced by a managed policy
+    let policy = managed::policy();
+    for (field, value) in [
+        (&endpoint_field, &policy.azure_endpoint_url),
+        (&stt_deployment_field, &policy.azure_stt_deployment),
+        (&polish_deployment_field, &policy.azure_polish_deployment),
+    ] {
+        if let Some(value) = value {
+            unsafe { field.setStringValue(&NSString::from_str(value)) };
+            lock_managed_control(field);
+        }
+    }
+
+    // Check results, in the gap below each checked field
+    let check_labels = [
+        (left_x, row1_field_y),
+        (right_x, row1_field_y),
+        (left_x, row2_field_y),
+        (right_x, row2_field_y),
+    ]
+    .map(|(x, field_y)| create_check_label(mtm, x, field_y - 15.0, column_width));
+    for field in [
+        &endpoint_field,
+        &stt_deployment_field,
+        &polish_deployment_field,
+    ] {
+        unsafe {
+            let _: () = msg_send![field, setDelegate: delegate];
+        }
+    }
+    api_key_field.set_delegate(delegate);
+
     // Status label
-    let status_y: CGFloat = 115.0;
+    let status_y: CGFloat = 50.0;
     let status_text = if has_credentials {
         "Status: Credentials saved ✓"
     } else {
@@ -156,7 +274,7 @@ pub(crate) fn add_azure_controls(
     );
 
     // Buttons
-    let buttons_y: CGFloat = 75.0;
+    let buttons_y: CGFloat = 12.0;
     let save_button_width: CGFloat = 120.0;
     let clear_button_width: CGFloat = 130.0;
     let buttons_total_width = save_button_width + clear_button_width + 10.0;
@@ -194,21 +312,63 @@ pub(crate) fn add_azure_controls(
         content_view.addSubview(&polish_label);
         content_view.addSubview(&polish_deployment_field);
         content_view.addSubview(&key_label);
-        content_view.addSubview(&api_key_field);
+        content_view.addSubview(&realtime_version_label);
+        content_view.addSubview(&realtime_version_field);
+        content_view.addSubview(&responses_version_label);
+        content_view.addSubview(&responses_version_field);
+        content_view.addSubview(&route_label);
+        content_view.addSubview(&polish_route_popup);
         content_view.addSubview(&status_label);
         content_view.addSubview(&save_button);
         content_view.addSubview(&clear_button);
+        for label in &check_labels {
+            content_view.addSubview(label);
+        }
     }
+    api_key_field.add_to(content_view);
 
     AzureControls {
         endpoint_field,
         stt_deployment_field,
         polish_deployment_field,
         api_key_field,
+        realtime_version_field,
+        responses_version_field,
+        polish_route_popup,
+        check_labels,
+        save_button,
         status_label,
     }
 }
 
+/// Create the polish route popup, selecting `selected` (None = detect).
+fn create_route_popup(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    selected: Option<AzurePolishRoute>,
+) -> Retained<NSPopUpButton> {
+    let popup: Retained<NSPopUpButton> = unsafe {
+        msg_send_id![mtm.alloc::<NSPopUpButton>(), initWithFrame: frame, pullsDown: false]
+    };
+
+    let titles = std::iter::once("Detect automatically".to_string())
+        .chain(AzurePolishRoute::ALL.iter().map(ToString::to_string));
+    for title in titles {
+        unsafe {
+            let _: () = msg_send![&popup, addItemWithTitle: &*NSString::from_str(&title)];
+        }
+    }
+
+    let index = selected
+        .and_then(|route| AzurePolishRoute::ALL.iter().position(|r| *r == route))
+        .map_or(0, |position| position as isize + 1);
+    unsafe {
+        let _: () = msg_send![&popup, selectItemAtIndex: index];
+    }
+
+    popup
+}
+
 /// Create a field label at a specific position.
 fn create_field_label_at(
     mtm: MainThreadMarker,
@@ -268,6 +428,31 @@ fn create_text_field(
     field
 }
 
+/// Create an empty label for a field's check result.
+fn create_check_label(
+    mtm: MainThreadMarker,
+    x: CGFloat,
+    y: CGFloat,
+    width: CGFloat,
+) -> Retained<NSTextField> {
+    let frame = NSRect::new(NSPoint::new(x, y), NSSize::new(width, 13.0));
+
+    let label: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: frame] };
+
+    unsafe {
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+
+        let font = objc2_app_kit::NSFont::systemFontOfSize(10.0);
+        label.setFont(Some(&font));
+    }
+
+    label
+}
+
 /// Create a status label at a specific position.
 fn create_status_label_at(
     mtm: MainThreadMarker,
@@ -6,6 +6,7 @@ use objc2_app_kit::{NSSegmentedControl, NSView};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
 
 use super::helpers::{create_section_label, create_segmented_control};
+use crate::localization::tr;
 use crate::settings_window::constants::PADDING;
 use crate::settings_window::delegate::SettingsActionDelegate;
 use crate::transcription_window::TranscriptionWindow;
@@ -18,24 +19,24 @@ pub(crate) fn add_background_controls(
     content_view: &NSView,
     delegate: &SettingsActionDelegate,
 ) -> Retained<NSSegmentedControl> {
-    // Get content view width for layout calculations
-    let content_width = content_view.frame().size.width;
+    // Background shares its row with Interface Language and takes the left half
+    let column_width = content_view.frame().size.width / 2.0;
 
     let label_height: CGFloat = 20.0;
     let control_width: CGFloat = 160.0;
     let control_height: CGFloat = 24.0;
 
     // Section label below transparency separator
-    let label_y: CGFloat = 245.0;
+    let label_y: CGFloat = 275.0;
     let label_frame = NSRect::new(
         NSPoint::new(PADDING, label_y),
-        NSSize::new(content_width - PADDING * 2.0, label_height),
+        NSSize::new(column_width - PADDING * 2.0, label_height),
     );
-    let label = create_section_label(mtm, label_frame, "Background");
+    let label = create_section_label(mtm, label_frame, tr("settings.background"));
 
     // Segmented control centered below label
-    let control_y: CGFloat = 210.0;
-    let control_x = (content_width - control_width) / 2.0;
+    let control_y: CGFloat = 240.0;
+    let control_x = (column_width - control_width) / 2.0;
     let control_frame = NSRect::new(
         NSPoint::new(control_x, control_y),
         NSSize::new(control_width, control_height),
@@ -48,7 +49,7 @@ pub(crate) fn add_background_controls(
     let segmented_control = create_segmented_control(
         mtm,
         control_frame,
-        &["Dark", "Light"],
+        &[tr("settings.dark"), tr("settings.light")],
         selected_segment,
         delegate,
         sel!(handleBackgroundSegment:),
@@ -0,0 +1,93 @@
+//! Live caption output settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_path_label, create_section_label, create_segmented_control, lock_managed_control,
+};
+use crate::captions::{self, CaptionOutput};
+use crate::managed;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Add live caption controls to the content view.
+///
+/// Creates a section with:
+/// - Output selector (Off / Text File / WebSocket), saved immediately
+/// - Where each output can be found, for setting up the OBS source
+pub(crate) fn add_caption_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    output: CaptionOutput,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    // Section header
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Live Captions",
+    );
+
+    let helper_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 280.0), NSSize::new(inner_width, 36.0)),
+        "Publish the latest spoken line while recording, for captioning streams and webinars.",
+    );
+
+    // Captions disabled by a managed policy always show as off
+    let locked = managed::policy().disable_captions;
+    let output = if locked { CaptionOutput::Off } else { output };
+
+    let labels: Vec<&str> = CaptionOutput::ALL.iter().map(|o| o.label()).collect();
+    let selector_width: CGFloat = 300.0;
+    let selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 240.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &labels,
+        output.to_index(),
+        delegate,
+        sel!(handleCaptionOutputChanged:),
+    );
+
+    let file_path = captions::file_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "Unavailable".to_string());
+    let file_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 180.0), NSSize::new(inner_width, 44.0)),
+        &format!(
+            "Text File: {}\nIn OBS, add a Text source and enable \"Read from file\".",
+            file_path
+        ),
+    );
+
+    let websocket_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 120.0), NSSize::new(inner_width, 44.0)),
+        &format!(
+            "WebSocket: {}/?token=<API token>\nUse the API token shown in the Integrations tab.",
+            captions::websocket_url()
+        ),
+    );
+
+    if locked {
+        lock_managed_control(&selector);
+    }
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&helper_label);
+        content_view.addSubview(&selector);
+        content_view.addSubview(&file_label);
+        content_view.addSubview(&websocket_label);
+    }
+}
@@ -0,0 +1,54 @@
+//! Debug tab settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_checkbox, create_path_label, create_section_label};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Add the Debug tab opt-in to its own Stats tab section.
+///
+/// Creates a section with:
+/// - Checkbox showing the Debug tab in the transcription window, saved immediately
+/// - Help text on what the tab lists and what it leaves out
+pub(crate) fn add_debug_tab_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    enabled: bool,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 64.0), NSSize::new(inner_width, 20.0)),
+        "Troubleshooting",
+    );
+
+    let checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 38.0), NSSize::new(inner_width, 22.0)),
+        "Show a Debug tab in the transcription window",
+        enabled,
+        0,
+        delegate,
+        sel!(handleDebugTabToggle:),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 2.0), NSSize::new(inner_width, 32.0)),
+        "Lists the provider's events and connection changes with timestamps, to copy into \
+         bug reports. What was said is left out.",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&checkbox);
+        content_view.addSubview(&help_label);
+    }
+}
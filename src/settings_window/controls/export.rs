@@ -0,0 +1,164 @@
+//! Notion export settings UI controls, at the top of the Export tab.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_separator,
+    create_small_button, create_text_field,
+};
+use super::keywords::create_helper_label;
+use crate::keychain::NotionCredentials;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Export controls returned to caller for state management.
+pub(crate) struct ExportControls {
+    /// Integration token field; the token itself is only kept in the keychain
+    pub(crate) notion_token_field: Retained<NSTextField>,
+    pub(crate) notion_database_field: Retained<NSTextField>,
+    pub(crate) notion_status_label: Retained<NSTextField>,
+}
+
+/// Add Notion export controls to the content view.
+///
+/// Creates a section with:
+/// - Checkbox to export each session's meeting notes, saved immediately
+/// - Integration Token | Database ID
+/// - Status label and save/clear buttons
+pub(crate) fn add_export_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    notion_enabled: bool,
+    saved_notion: Option<&NotionCredentials>,
+) -> ExportControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let field_height: CGFloat = 22.0;
+    let button_height: CGFloat = 28.0;
+    let column_gap: CGFloat = 20.0;
+
+    let column_width = (inner_width - column_gap) / 2.0;
+    let left_x = PADDING;
+    let right_x = PADDING + column_width + column_gap;
+
+    // Notion section
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Notion",
+    );
+
+    let checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 292.0), NSSize::new(inner_width, 22.0)),
+        "Add a page to a Notion database with each recording's meeting notes",
+        notion_enabled,
+        0,
+        delegate,
+        sel!(handleNotionExportToggle:),
+    );
+
+    let token_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(left_x, 265.0), NSSize::new(column_width, 16.0)),
+        "Internal Integration Token",
+    );
+    let notion_token_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, 242.0),
+            NSSize::new(column_width, field_height),
+        ),
+        if saved_notion.is_some() {
+            "(stored in keychain)"
+        } else {
+            "ntn_..."
+        },
+        "",
+    );
+
+    let database_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, 265.0),
+            NSSize::new(column_width, 16.0),
+        ),
+        "Database ID (shared with the integration)",
+    );
+    let notion_database_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, 242.0),
+            NSSize::new(column_width, field_height),
+        ),
+        "32-character ID from the database link",
+        saved_notion.map_or("", |c| c.database_id.as_str()),
+    );
+
+    // Status label (left) and buttons (right)
+    let save_button_width: CGFloat = 120.0;
+    let clear_button_width: CGFloat = 130.0;
+    let buttons_width = save_button_width + clear_button_width + 10.0;
+    let buttons_x = content_width - PADDING - buttons_width;
+
+    let notion_status_label = create_helper_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 200.0),
+            NSSize::new(inner_width - buttons_width - 10.0, 28.0),
+        ),
+        if saved_notion.is_some() {
+            "Status: Connected to Notion ✓"
+        } else {
+            "Status: Not set up"
+        },
+    );
+
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x, 202.0),
+            NSSize::new(save_button_width, button_height),
+        ),
+        "Save Connection",
+        delegate,
+        sel!(handleSaveNotion:),
+    );
+
+    let clear_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x + save_button_width + 10.0, 202.0),
+            NSSize::new(clear_button_width, button_height),
+        ),
+        "Clear Connection",
+        delegate,
+        sel!(handleClearNotion:),
+    );
+
+    let separator = create_separator(mtm, 185.0, content_width);
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&checkbox);
+        content_view.addSubview(&token_label);
+        content_view.addSubview(&notion_token_field);
+        content_view.addSubview(&database_label);
+        content_view.addSubview(&notion_database_field);
+        content_view.addSubview(&notion_status_label);
+        content_view.addSubview(&save_button);
+        content_view.addSubview(&clear_button);
+        content_view.addSubview(&separator);
+    }
+
+    ExportControls {
+        notion_token_field,
+        notion_database_field,
+        notion_status_label,
+    }
+}
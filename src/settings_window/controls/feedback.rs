@@ -0,0 +1,138 @@
+//! Sound cue, notification and clipboard settings UI controls.
+
+use objc2::{msg_send, sel};
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+    create_separator,
+};
+use crate::feedback::{FeedbackOption, FeedbackSettings};
+use crate::recording::{AutoCopy, AutoCopySettings};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription_window::TabType;
+
+/// Add sound cue and notification controls to the content view.
+///
+/// Creates a section with one checkbox per feedback option. Each checkbox is
+/// tagged with the option's index and saves immediately when toggled.
+pub(crate) fn add_feedback_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    settings: &FeedbackSettings,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    // Section header
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Sounds & Notifications",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe { content_view.addSubview(&section_label) };
+
+    let row_height: CGFloat = 28.0;
+    let first_row_y: CGFloat = 285.0;
+
+    for (index, option) in FeedbackOption::ALL.into_iter().enumerate() {
+        let frame = NSRect::new(
+            NSPoint::new(PADDING, first_row_y - row_height * index as CGFloat),
+            NSSize::new(inner_width, 22.0),
+        );
+        let checkbox = create_checkbox(
+            mtm,
+            frame,
+            option.label(),
+            settings.is_enabled(option),
+            index as isize,
+            delegate,
+            sel!(handleFeedbackOption:),
+        );
+
+        // SAFETY: Adding valid subviews to a valid parent view
+        unsafe { content_view.addSubview(&checkbox) };
+    }
+}
+
+/// Add the clipboard section below the feedback options.
+///
+/// Creates one row per stop mode with a segmented control choosing what is
+/// copied when the mode finishes. Each control is tagged with the mode's tab
+/// index and saves immediately when changed.
+pub(crate) fn add_auto_copy_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    settings: &AutoCopySettings,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let separator = create_separator(mtm, 160.0, content_width);
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 130.0), NSSize::new(inner_width, 20.0)),
+        "Copy to Clipboard When Recording Stops",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&separator);
+        content_view.addSubview(&section_label);
+    }
+
+    let row_label_width: CGFloat = 150.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+    let row_height: CGFloat = 30.0;
+    let first_row_y: CGFloat = 98.0;
+    let modes = [
+        (TabType::Live, "Without polishing"),
+        (TabType::BasicPolish, "Basic polishing"),
+        (TabType::MeetingNotes, "Meeting notes"),
+        (TabType::FollowUpEmail, "Follow-up email"),
+    ];
+
+    for (index, (mode, title)) in modes.into_iter().enumerate() {
+        let y = first_row_y - row_height * index as CGFloat;
+        let choices = AutoCopy::choices(mode);
+        let labels: Vec<&str> = choices.iter().map(|choice| choice.label(mode)).collect();
+        let selected = choices
+            .iter()
+            .position(|choice| *choice == settings.for_mode(mode))
+            .unwrap_or(0);
+
+        let label = create_path_label(
+            mtm,
+            NSRect::new(
+                NSPoint::new(PADDING, y + 4.0),
+                NSSize::new(row_label_width, 20.0),
+            ),
+            title,
+        );
+        let selector = create_segmented_control(
+            mtm,
+            NSRect::new(
+                NSPoint::new(selector_x, y),
+                NSSize::new(120.0 * labels.len() as CGFloat, 28.0),
+            ),
+            &labels,
+            selected as isize,
+            delegate,
+            sel!(handleAutoCopyChanged:),
+        );
+
+        // SAFETY: setTag is a standard NSControl call; adding valid subviews to a valid parent view
+        unsafe {
+            let _: () = msg_send![&selector, setTag: mode.to_index()];
+            content_view.addSubview(&label);
+            content_view.addSubview(&selector);
+        }
+    }
+}
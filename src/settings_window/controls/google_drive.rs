@@ -0,0 +1,177 @@
+//! Google Drive upload settings UI controls, below Notion in the Export tab.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_small_button,
+    create_text_field,
+};
+use super::keywords::create_helper_label;
+use crate::keychain::GoogleDriveCredentials;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Google Drive controls returned to caller for state management.
+pub(crate) struct GoogleDriveControls {
+    pub(crate) client_id_field: Retained<NSTextField>,
+    /// Client secret field; the secret itself is only kept in the keychain
+    pub(crate) client_secret_field: Retained<NSTextField>,
+    pub(crate) folder_field: Retained<NSTextField>,
+    pub(crate) status_label: Retained<NSTextField>,
+}
+
+/// Add Google Drive upload controls to the content view.
+///
+/// Creates a section with:
+/// - Checkbox to upload saved transcripts, saved immediately
+/// - OAuth Client ID | Client Secret
+/// - Folder | connect/disconnect buttons
+/// - Status label
+pub(crate) fn add_google_drive_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    upload_enabled: bool,
+    saved: Option<&GoogleDriveCredentials>,
+) -> GoogleDriveControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let field_height: CGFloat = 22.0;
+    let button_height: CGFloat = 28.0;
+    let column_gap: CGFloat = 20.0;
+
+    let column_width = (inner_width - column_gap) / 2.0;
+    let left_x = PADDING;
+    let right_x = PADDING + column_width + column_gap;
+
+    // Google Drive section
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 160.0), NSSize::new(inner_width, 20.0)),
+        "Google Drive",
+    );
+
+    let checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 132.0), NSSize::new(inner_width, 22.0)),
+        "Upload saved transcripts to a Drive folder (Markdown becomes a Google Doc)",
+        upload_enabled,
+        0,
+        delegate,
+        sel!(handleGoogleDriveUploadToggle:),
+    );
+
+    let client_id_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(left_x, 105.0), NSSize::new(column_width, 16.0)),
+        "OAuth Client ID (desktop app)",
+    );
+    let client_id_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, 82.0),
+            NSSize::new(column_width, field_height),
+        ),
+        "....apps.googleusercontent.com",
+        saved.map_or("", |c| c.client_id.as_str()),
+    );
+
+    let client_secret_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, 105.0),
+            NSSize::new(column_width, 16.0),
+        ),
+        "Client Secret",
+    );
+    let client_secret_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, 82.0),
+            NSSize::new(column_width, field_height),
+        ),
+        if saved.is_some() {
+            "(stored in keychain)"
+        } else {
+            "GOCSPX-..."
+        },
+        "",
+    );
+
+    let folder_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(left_x, 55.0), NSSize::new(column_width, 16.0)),
+        "Folder ID or link",
+    );
+    let folder_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, 32.0),
+            NSSize::new(column_width, field_height),
+        ),
+        "https://drive.google.com/drive/folders/...",
+        saved.map_or("", |c| c.folder_id.as_str()),
+    );
+
+    // Buttons (right of the folder field)
+    let connect_button_width: CGFloat = 120.0;
+    let disconnect_button_width: CGFloat = 130.0;
+    let buttons_x = content_width - PADDING - connect_button_width - disconnect_button_width - 10.0;
+
+    let connect_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x, 29.0),
+            NSSize::new(connect_button_width, button_height),
+        ),
+        "Connect…",
+        delegate,
+        sel!(handleConnectGoogleDrive:),
+    );
+
+    let disconnect_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x + connect_button_width + 10.0, 29.0),
+            NSSize::new(disconnect_button_width, button_height),
+        ),
+        "Disconnect",
+        delegate,
+        sel!(handleDisconnectGoogleDrive:),
+    );
+
+    let status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 4.0), NSSize::new(inner_width, 20.0)),
+        if saved.is_some() {
+            "Status: Connected to Google Drive ✓"
+        } else {
+            "Status: Not set up"
+        },
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&checkbox);
+        content_view.addSubview(&client_id_label);
+        content_view.addSubview(&client_id_field);
+        content_view.addSubview(&client_secret_label);
+        content_view.addSubview(&client_secret_field);
+        content_view.addSubview(&folder_label);
+        content_view.addSubview(&folder_field);
+        content_view.addSubview(&connect_button);
+        content_view.addSubview(&disconnect_button);
+        content_view.addSubview(&status_label);
+    }
+
+    GoogleDriveControls {
+        client_id_field,
+        client_secret_field,
+        folder_field,
+        status_label,
+    }
+}
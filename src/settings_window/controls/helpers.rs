@@ -3,12 +3,13 @@
 use objc2::rc::Retained;
 use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{
-    NSBox, NSBoxType, NSButton, NSFont, NSSegmentedControl, NSSlider, NSTabView, NSTabViewItem,
-    NSTextField,
+    NSBox, NSBoxType, NSButton, NSControl, NSFont, NSSegmentedControl, NSSlider, NSTabView,
+    NSTabViewItem, NSTextField,
 };
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
 
-use crate::settings_window::constants::NS_BEZEL_STYLE_ROUNDED;
+use crate::managed::MANAGED_TOOLTIP;
+use crate::settings_window::constants::{NS_BEZEL_STYLE_ROUNDED, NS_BUTTON_TYPE_SWITCH};
 use crate::settings_window::delegate::SettingsActionDelegate;
 
 /// Create a section label (bold, non-editable text field).
@@ -104,6 +105,46 @@ pub(crate) fn create_small_button(
     button
 }
 
+/// Create a checkbox with the given title, initial state and action.
+///
+/// The `tag` lets a single action distinguish between checkboxes.
+pub(crate) fn create_checkbox(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    title: &str,
+    checked: bool,
+    tag: isize,
+    delegate: &SettingsActionDelegate,
+    action: objc2::runtime::Sel,
+) -> Retained<NSButton> {
+    // SAFETY: NSButton allocation and initialization is safe on main thread with valid frame
+    let checkbox: Retained<NSButton> =
+        unsafe { msg_send_id![mtm.alloc::<NSButton>(), initWithFrame: frame] };
+
+    // SAFETY: Standard NSButton configuration with valid delegate target
+    unsafe {
+        let _: () = msg_send![&checkbox, setButtonType: NS_BUTTON_TYPE_SWITCH];
+        checkbox.setTitle(&NSString::from_str(title));
+        let _: () = msg_send![&checkbox, setState: if checked { 1_isize } else { 0_isize }];
+        let _: () = msg_send![&checkbox, setTag: tag];
+        let _: () = msg_send![&checkbox, setTarget: delegate];
+        let _: () = msg_send![&checkbox, setAction: action];
+    }
+
+    checkbox
+}
+
+/// Disable a control whose setting is locked by a managed policy.
+///
+/// The tooltip explains why the control can't be changed.
+pub(crate) fn lock_managed_control(control: &NSControl) {
+    // SAFETY: Standard NSControl configuration calls on a valid control
+    unsafe {
+        control.setEnabled(false);
+        control.setToolTip(Some(&NSString::from_str(MANAGED_TOOLTIP)));
+    }
+}
+
 /// Create a horizontal slider control.
 pub(crate) fn create_slider(
     mtm: MainThreadMarker,
@@ -209,6 +250,41 @@ pub(crate) fn create_value_label(
     label
 }
 
+/// Create an editable single-line text field with placeholder and value.
+pub(crate) fn create_text_field(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    placeholder: &str,
+    value: &str,
+) -> Retained<NSTextField> {
+    // SAFETY: NSTextField allocation and initialization is safe on main thread with valid frame
+    let field: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: frame] };
+
+    // SAFETY: Standard NSTextField and NSTextFieldCell configuration calls
+    unsafe {
+        field.setEditable(true);
+        field.setSelectable(true);
+        field.setBordered(true);
+        field.setDrawsBackground(true);
+        let _: () = msg_send![&field, setPlaceholderString: &*NSString::from_str(placeholder)];
+        field.setStringValue(&NSString::from_str(value));
+
+        // Configure for single-line mode (no word wrap)
+        let cell: *mut objc2::runtime::AnyObject = msg_send![&field, cell];
+        if !cell.is_null() {
+            // NSLineBreakByTruncatingTail = 4
+            let _: () = msg_send![cell, setLineBreakMode: 4_usize];
+            let _: () = msg_send![cell, setUsesSingleLineMode: true];
+            let _: () = msg_send![cell, setScrollable: true];
+        }
+
+        field.setFont(Some(&NSFont::systemFontOfSize(12.0)));
+    }
+
+    field
+}
+
 /// Create a tab view for organizing settings into tabs.
 pub(crate) fn create_tab_view(mtm: MainThreadMarker, frame: NSRect) -> Retained<NSTabView> {
     // SAFETY: NSTabView allocation and initialization is safe on main thread with valid frame
@@ -0,0 +1,90 @@
+//! Recording indicator settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+    create_separator,
+};
+use crate::menubar::RecordingIndicator;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Add recording indicator controls below the session limits.
+///
+/// Creates a section with:
+/// - Selector for how visibly the menu bar icon shows a recording
+/// - Checkbox for the "Vissper is recording" banner
+pub(crate) fn add_indicator_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    indicator: RecordingIndicator,
+    banner: bool,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let separator = create_separator(mtm, 128.0, content_width);
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 98.0), NSSize::new(inner_width, 20.0)),
+        "Recording Indicator",
+    );
+
+    let row_label_width: CGFloat = 190.0;
+    let icon_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 70.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Menu bar icon while recording",
+    );
+
+    let labels: Vec<&str> = RecordingIndicator::ALL
+        .iter()
+        .map(|style| style.label())
+        .collect();
+    let icon_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING + row_label_width + 10.0, 66.0),
+            NSSize::new(270.0, 28.0),
+        ),
+        &labels,
+        indicator.index(),
+        delegate,
+        sel!(handleRecordingIndicatorChanged:),
+    );
+
+    let banner_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 36.0), NSSize::new(inner_width, 22.0)),
+        "Show a \"Vissper is recording\" banner at the top of the screen while recording",
+        banner,
+        0,
+        delegate,
+        sel!(handleRecordingBannerToggle:),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 4.0), NSSize::new(inner_width, 28.0)),
+        "Prominent pulses the red icon so others can tell they are recorded. Discreet \
+         shows a plain filled microphone instead of the red one.",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&separator);
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&icon_label);
+        content_view.addSubview(&icon_selector);
+        content_view.addSubview(&banner_checkbox);
+        content_view.addSubview(&help_label);
+    }
+}
@@ -0,0 +1,144 @@
+//! Automation (Raycast, Alfred, Shortcuts) and local HTTP API settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_separator,
+    create_small_button, lock_managed_control,
+};
+use crate::managed;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Integrations controls returned to caller for state management.
+pub(crate) struct IntegrationsControls {
+    pub(crate) http_api_token_label: Retained<NSTextField>,
+}
+
+/// Current integration settings shown in the tab
+pub(crate) struct IntegrationsState {
+    pub(crate) socket_enabled: bool,
+    pub(crate) http_api_enabled: bool,
+    pub(crate) http_api_port: u16,
+    pub(crate) http_api_token: String,
+}
+
+/// Add automation socket and HTTP API controls to the content view.
+///
+/// Creates two sections:
+/// - Automation: socket checkbox, socket path and an example command
+/// - HTTP API: checkbox, token label and a button to regenerate the token
+///
+/// Checkboxes save immediately when toggled.
+pub(crate) fn add_integrations_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    state: &IntegrationsState,
+) -> IntegrationsControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    // Automation section
+    let socket_section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Automation",
+    );
+
+    let socket_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 292.0), NSSize::new(inner_width, 22.0)),
+        "Accept commands from other apps on a local socket",
+        state.socket_enabled,
+        0,
+        delegate,
+        sel!(handleAutomationToggle:),
+    );
+
+    let socket_path = crate::automation::socket_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "Unavailable".to_string());
+    let socket_example_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 225.0), NSSize::new(inner_width, 60.0)),
+        &format!(
+            "Send one JSON command per line, e.g.\n\
+             echo '{{\"command\":\"start_recording\"}}' | nc -U \"{}\"",
+            socket_path
+        ),
+    );
+
+    let separator = create_separator(mtm, 210.0, content_width);
+
+    // HTTP API section
+    let http_section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 180.0), NSSize::new(inner_width, 20.0)),
+        "HTTP API",
+    );
+
+    let http_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 152.0), NSSize::new(inner_width, 22.0)),
+        &format!(
+            "Serve a local API on http://127.0.0.1:{}",
+            state.http_api_port
+        ),
+        state.http_api_enabled,
+        0,
+        delegate,
+        sel!(handleHttpApiToggle:),
+    );
+
+    let http_api_token_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 124.0), NSSize::new(inner_width, 20.0)),
+        &format!("Token: {}", state.http_api_token),
+    );
+
+    let http_help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 70.0), NSSize::new(inner_width, 48.0)),
+        "GET /status, GET /transcript, POST /polish. Send the token as \
+         \"Authorization: Bearer <token>\" or append ?token=<token> (e.g. for an OBS browser source).",
+    );
+
+    let button_width: CGFloat = 140.0;
+    let regenerate_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - button_width) / 2.0, 25.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Regenerate Token",
+        delegate,
+        sel!(handleRegenerateHttpApiToken:),
+    );
+
+    if managed::policy().disable_automation {
+        lock_managed_control(&socket_checkbox);
+        lock_managed_control(&http_checkbox);
+        lock_managed_control(&regenerate_button);
+    }
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&socket_section_label);
+        content_view.addSubview(&socket_checkbox);
+        content_view.addSubview(&socket_example_label);
+        content_view.addSubview(&separator);
+        content_view.addSubview(&http_section_label);
+        content_view.addSubview(&http_checkbox);
+        content_view.addSubview(&http_api_token_label);
+        content_view.addSubview(&http_help_label);
+        content_view.addSubview(&regenerate_button);
+    }
+
+    IntegrationsControls {
+        http_api_token_label,
+    }
+}
@@ -0,0 +1,73 @@
+//! Interface language control UI elements for the settings window.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_section_label, create_segmented_control};
+use crate::localization::{tr, UiLanguage};
+use crate::preferences;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Add interface language control UI elements to the content view.
+///
+/// Placed in the right half of the row shared with Background. Returns the
+/// section label so it can show the restart hint after a change.
+pub(crate) fn add_interface_language_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+) -> Retained<NSTextField> {
+    let column_width = content_view.frame().size.width / 2.0;
+
+    let label_height: CGFloat = 20.0;
+    let control_width: CGFloat = 300.0;
+    let control_height: CGFloat = 24.0;
+
+    // Section label level with the Background label
+    let label_y: CGFloat = 275.0;
+    let label_frame = NSRect::new(
+        NSPoint::new(column_width + PADDING, label_y),
+        NSSize::new(column_width - PADDING * 2.0, label_height),
+    );
+    let label = create_section_label(mtm, label_frame, tr("settings.interface_language"));
+
+    // Segmented control centered in the right half below label
+    let control_y: CGFloat = 240.0;
+    let control_x = column_width + (column_width - control_width) / 2.0;
+    let control_frame = NSRect::new(
+        NSPoint::new(control_x, control_y),
+        NSSize::new(control_width, control_height),
+    );
+
+    // Select the saved language, which may differ from the one in use until restart
+    let saved = UiLanguage::from_code(&preferences::get_ui_language()).unwrap_or_default();
+    let selected_segment = UiLanguage::ALL
+        .iter()
+        .position(|language| *language == saved)
+        .unwrap_or(0) as isize;
+
+    let names: Vec<&str> = UiLanguage::ALL
+        .iter()
+        .map(|language| language.native_name())
+        .collect();
+
+    let segmented_control = create_segmented_control(
+        mtm,
+        control_frame,
+        &names,
+        selected_segment,
+        delegate,
+        sel!(handleUiLanguageChanged:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&label);
+        content_view.addSubview(&segmented_control);
+    }
+
+    label
+}
@@ -0,0 +1,235 @@
+//! Issue tracker (Jira / Linear) settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSSegmentedControl, NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_path_label, create_section_label, create_segmented_control, create_small_button,
+    create_text_field,
+};
+use super::keywords::create_helper_label;
+use crate::issues::IssueTracker;
+use crate::keychain::IssueTrackerCredentials;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Issue tracker controls returned to caller for state management.
+pub(crate) struct IssueControls {
+    pub(crate) tracker_selector: Retained<NSSegmentedControl>,
+    pub(crate) site_field: Retained<NSTextField>,
+    pub(crate) email_field: Retained<NSTextField>,
+    pub(crate) project_field: Retained<NSTextField>,
+    /// API token field; the token itself is only kept in the keychain
+    pub(crate) token_field: Retained<NSTextField>,
+    pub(crate) status_label: Retained<NSTextField>,
+}
+
+/// Add issue tracker connection controls to the content view.
+///
+/// Creates a section with:
+/// - Tracker selector (Jira / Linear)
+/// - Row 1: Site URL | Account Email (Jira only)
+/// - Row 2: Project or Team Key | API Token
+/// - Status label and save/clear buttons
+///
+/// If `saved_credentials` is provided, the fields show the saved values
+/// (except the token, which stays empty).
+pub(crate) fn add_issue_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    saved_credentials: Option<&IssueTrackerCredentials>,
+) -> IssueControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let field_height: CGFloat = 22.0;
+    let button_height: CGFloat = 28.0;
+    let column_gap: CGFloat = 20.0;
+
+    let column_width = (inner_width - column_gap) / 2.0;
+    let left_x = PADDING;
+    let right_x = PADDING + column_width + column_gap;
+
+    let tracker = saved_credentials.map(|c| c.tracker).unwrap_or_default();
+
+    // Section header
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Issue Tracker",
+    );
+
+    let helper_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 280.0), NSSize::new(inner_width, 36.0)),
+        "Create issues from the action items in your meeting notes with \
+         \"Create Issues from Action Items…\" in the menu bar.",
+    );
+
+    let labels: Vec<&str> = IssueTracker::ALL.iter().map(|t| t.label()).collect();
+    let tracker_selector = create_segmented_control(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 245.0), NSSize::new(200.0, 28.0)),
+        &labels,
+        tracker.to_index(),
+        delegate,
+        sel!(handleIssueTrackerChanged:),
+    );
+
+    // Row 1: Site URL (left) | Account Email (right)
+    let row1_label_y: CGFloat = 215.0;
+    let row1_field_y: CGFloat = 192.0;
+
+    let site_label = create_field_label(mtm, left_x, row1_label_y, column_width, "Jira Site URL");
+    let site_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, row1_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        "https://yourcompany.atlassian.net",
+        saved_credentials.map_or("", |c| c.site_url.as_str()),
+    );
+
+    let email_label = create_field_label(
+        mtm,
+        right_x,
+        row1_label_y,
+        column_width,
+        "Atlassian Account Email",
+    );
+    let email_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, row1_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        "you@yourcompany.com",
+        saved_credentials.map_or("", |c| c.email.as_str()),
+    );
+
+    // Row 2: Project or Team Key (left) | API Token (right)
+    let row2_label_y: CGFloat = 160.0;
+    let row2_field_y: CGFloat = 137.0;
+
+    let project_label = create_field_label(
+        mtm,
+        left_x,
+        row2_label_y,
+        column_width,
+        "Jira Project Key / Linear Team Key",
+    );
+    let project_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(left_x, row2_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        "ENG",
+        saved_credentials.map_or("", |c| c.project_key.as_str()),
+    );
+
+    let token_label = create_field_label(mtm, right_x, row2_label_y, column_width, "API Token");
+    let token_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(right_x, row2_field_y),
+            NSSize::new(column_width, field_height),
+        ),
+        if saved_credentials.is_some() {
+            "(stored in keychain)"
+        } else {
+            "Enter API token"
+        },
+        "",
+    );
+
+    // SAFETY: enabling valid text fields on the main thread
+    unsafe {
+        site_field.setEnabled(tracker.needs_site());
+        email_field.setEnabled(tracker.needs_site());
+    }
+
+    let status_text = if saved_credentials.is_some() {
+        format!("Status: Connected to {} ✓", tracker.label())
+    } else {
+        "Status: Not set up".to_string()
+    };
+    let status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 100.0), NSSize::new(inner_width, 28.0)),
+        &status_text,
+    );
+
+    // Buttons
+    let buttons_y: CGFloat = 60.0;
+    let save_button_width: CGFloat = 120.0;
+    let clear_button_width: CGFloat = 130.0;
+    let buttons_x = (content_width - save_button_width - clear_button_width - 10.0) / 2.0;
+
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x, buttons_y),
+            NSSize::new(save_button_width, button_height),
+        ),
+        "Save Connection",
+        delegate,
+        sel!(handleSaveIssueTracker:),
+    );
+
+    let clear_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x + save_button_width + 10.0, buttons_y),
+            NSSize::new(clear_button_width, button_height),
+        ),
+        "Clear Connection",
+        delegate,
+        sel!(handleClearIssueTracker:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&helper_label);
+        content_view.addSubview(&tracker_selector);
+        content_view.addSubview(&site_label);
+        content_view.addSubview(&site_field);
+        content_view.addSubview(&email_label);
+        content_view.addSubview(&email_field);
+        content_view.addSubview(&project_label);
+        content_view.addSubview(&project_field);
+        content_view.addSubview(&token_label);
+        content_view.addSubview(&token_field);
+        content_view.addSubview(&status_label);
+        content_view.addSubview(&save_button);
+        content_view.addSubview(&clear_button);
+    }
+
+    IssueControls {
+        tracker_selector,
+        site_field,
+        email_field,
+        project_field,
+        token_field,
+        status_label,
+    }
+}
+
+/// Create a small field label above a text field.
+fn create_field_label(
+    mtm: MainThreadMarker,
+    x: CGFloat,
+    y: CGFloat,
+    width: CGFloat,
+    text: &str,
+) -> Retained<NSTextField> {
+    create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(x, y), NSSize::new(width, 16.0)),
+        text,
+    )
+}
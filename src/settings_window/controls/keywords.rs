@@ -0,0 +1,169 @@
+//! Keyword highlighting settings UI controls.
+//!
+//! A plain-text rules list, one keyword per line with an optional color.
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_app_kit::{NSFont, NSScrollView, NSTextField, NSTextView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+
+use super::helpers::{create_section_label, create_small_button};
+use crate::keywords::HighlightColor;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Keyword controls returned to caller for state management.
+pub(crate) struct KeywordControls {
+    pub(crate) rules_text_view: Retained<NSTextView>,
+    pub(crate) status_label: Retained<NSTextField>,
+}
+
+/// Add keyword highlighting controls to the settings window.
+///
+/// Creates a section with:
+/// - Editable rules list (one `keyword: color` per line)
+/// - Helper text listing the available colors
+/// - Status label and save button
+pub(crate) fn add_keyword_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    rules_text: &str,
+) -> KeywordControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    // Section header
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Keyword Highlighting",
+    );
+
+    // Helper text
+    let color_names: Vec<&str> = HighlightColor::ALL.iter().map(|c| c.name()).collect();
+    let helper_text = format!(
+        "Keywords are highlighted in the live transcript as they appear. One per line, optionally followed by a color, e.g. \"deadline: red\". Colors: {}.",
+        color_names.join(", ")
+    );
+    let helper_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 280.0), NSSize::new(inner_width, 36.0)),
+        &helper_text,
+    );
+
+    // Rules list
+    let (scroll_view, rules_text_view) = create_rules_editor(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 95.0), NSSize::new(inner_width, 180.0)),
+        rules_text,
+    );
+
+    // Status label
+    let status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 65.0), NSSize::new(inner_width, 16.0)),
+        "",
+    );
+
+    // Save button
+    let button_width: CGFloat = 120.0;
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - button_width) / 2.0, 25.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Save Keywords",
+        delegate,
+        objc2::sel!(handleSaveKeywordRules:),
+    );
+
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&helper_label);
+        content_view.addSubview(&scroll_view);
+        content_view.addSubview(&status_label);
+        content_view.addSubview(&save_button);
+    }
+
+    KeywordControls {
+        rules_text_view,
+        status_label,
+    }
+}
+
+/// Create a bordered, scrollable plain-text editor for the rules list.
+pub(super) fn create_rules_editor(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    text: &str,
+) -> (Retained<NSScrollView>, Retained<NSTextView>) {
+    let scroll_view: Retained<NSScrollView> =
+        unsafe { msg_send_id![mtm.alloc::<NSScrollView>(), initWithFrame: frame] };
+
+    let text_frame = NSRect::new(NSPoint::new(0.0, 0.0), frame.size);
+    let text_view: Retained<NSTextView> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextView>(), initWithFrame: text_frame] };
+
+    unsafe {
+        scroll_view.setHasVerticalScroller(true);
+        scroll_view.setHasHorizontalScroller(false);
+        let _: () = msg_send![&scroll_view, setBorderType: 2u64]; // NSBezelBorder
+
+        text_view.setEditable(true);
+        text_view.setSelectable(true);
+        let _: () = msg_send![&text_view, setRichText: false];
+        let _: () = msg_send![&text_view, setAllowsUndo: true];
+        let _: () = msg_send![&text_view, setAutomaticQuoteSubstitutionEnabled: false];
+        let _: () = msg_send![&text_view, setAutomaticSpellingCorrectionEnabled: false];
+
+        let font: Retained<NSFont> = msg_send_id![
+            NSFont::class(),
+            monospacedSystemFontOfSize: 12.0,
+            weight: 0.0
+        ];
+        text_view.setFont(Some(&font));
+
+        // Wrap to the scroll view width
+        let text_container: *mut AnyObject = msg_send![&text_view, textContainer];
+        if !text_container.is_null() {
+            let _: () = msg_send![text_container, setWidthTracksTextView: true];
+        }
+        let _: () = msg_send![&text_view, setVerticallyResizable: true];
+        let _: () = msg_send![&text_view, setHorizontallyResizable: false];
+        let _: () = msg_send![&text_view, setAutoresizingMask: 2u64]; // NSViewWidthSizable
+
+        let _: () = msg_send![&text_view, setString: &*NSString::from_str(text)];
+        scroll_view.setDocumentView(Some(&text_view));
+    }
+
+    (scroll_view, text_view)
+}
+
+/// Create a small wrapping helper/status label.
+pub(super) fn create_helper_label(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    text: &str,
+) -> Retained<NSTextField> {
+    let label: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: frame] };
+
+    unsafe {
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setStringValue(&NSString::from_str(text));
+
+        let font = NSFont::systemFontOfSize(10.0);
+        label.setFont(Some(&font));
+
+        let color = objc2_app_kit::NSColor::secondaryLabelColor();
+        label.setTextColor(Some(&color));
+    }
+
+    label
+}
@@ -0,0 +1,61 @@
+//! Language hotkey settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_path_label, create_section_label, create_segmented_control};
+use crate::hotkeys::LanguageHotkey;
+use crate::localization::tr;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Add the language hotkey selector to its own General tab section.
+///
+/// Creates a section with:
+/// - Selector for the key combination cycling the favorite languages, or Off
+pub(crate) fn add_language_hotkey_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    preset: LanguageHotkey,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 64.0), NSSize::new(inner_width, 20.0)),
+        tr("settings.language_hotkey"),
+    );
+
+    let control_width: CGFloat = 300.0;
+    let labels: Vec<&str> = LanguageHotkey::ALL
+        .iter()
+        .map(|preset| preset.label())
+        .collect();
+    let selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - control_width) / 2.0, 32.0),
+            NSSize::new(control_width, 24.0),
+        ),
+        &labels,
+        preset.index(),
+        delegate,
+        sel!(handleLanguageHotkeyChanged:),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 4.0), NSSize::new(inner_width, 20.0)),
+        tr("settings.language_hotkey_help"),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&selector);
+        content_view.addSubview(&help_label);
+    }
+}
@@ -0,0 +1,160 @@
+//! Session limit and stall timeout settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+};
+use crate::recording::{
+    format_words, SessionLimits, STOP_AFTER_PRESETS, WARN_AFTER_PRESETS, WARN_WORDS_PRESETS,
+};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription::STALL_TIMEOUT_PRESETS;
+
+/// Add session limit controls to the content view.
+///
+/// Creates a section with a selector per limit and a checkbox, each saved
+/// immediately:
+/// - Duration after which a notification is posted
+/// - Duration after which the recording stops
+/// - Transcript length after which a notification is posted
+/// - Time audio is sent without a reply before reconnecting
+/// - Checkbox for the reminder about a recording that has gone quiet
+pub(crate) fn add_limits_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    limits: &SessionLimits,
+    stall_timeout_secs: u32,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Session Limits",
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 284.0), NSSize::new(inner_width, 32.0)),
+        "Guards against a recording left running by accident, which keeps sending audio \
+         to the speech service. A stopped recording is not polished; its transcript is kept.",
+    );
+
+    let row_label_width: CGFloat = 190.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+    let selector_width: CGFloat = 360.0;
+
+    let duration_labels = |presets: &[Option<u32>]| -> Vec<String> {
+        presets
+            .iter()
+            .map(|preset| match preset {
+                Some(mins) => format!("{} h", mins / 60),
+                None => "Off".to_string(),
+            })
+            .collect()
+    };
+    let word_labels: Vec<String> = WARN_WORDS_PRESETS
+        .iter()
+        .map(|preset| match preset {
+            Some(words) => format!("{} words", format_words(*words)),
+            None => "Off".to_string(),
+        })
+        .collect();
+
+    let rows = [
+        (
+            "Notify after recording for",
+            duration_labels(&WARN_AFTER_PRESETS),
+            preset_index(&WARN_AFTER_PRESETS, limits.warn_after_mins),
+            sel!(handleLimitWarnAfterChanged:),
+        ),
+        (
+            "Stop recording after",
+            duration_labels(&STOP_AFTER_PRESETS),
+            preset_index(&STOP_AFTER_PRESETS, limits.stop_after_mins),
+            sel!(handleLimitStopAfterChanged:),
+        ),
+        (
+            "Notify when the transcript passes",
+            word_labels,
+            preset_index(&WARN_WORDS_PRESETS, limits.warn_words),
+            sel!(handleLimitWarnWordsChanged:),
+        ),
+        (
+            "Reconnect after no reply for",
+            STALL_TIMEOUT_PRESETS
+                .iter()
+                .map(|secs| format!("{} s", secs))
+                .collect(),
+            STALL_TIMEOUT_PRESETS
+                .iter()
+                .position(|&secs| secs == stall_timeout_secs)
+                .map_or(-1, |i| i as isize),
+            sel!(handleStallTimeoutChanged:),
+        ),
+    ];
+
+    let row_height: CGFloat = 32.0;
+    let first_row_y: CGFloat = 244.0;
+    for (index, (title, labels, selected, action)) in rows.into_iter().enumerate() {
+        let y = first_row_y - row_height * index as CGFloat;
+        let label = create_path_label(
+            mtm,
+            NSRect::new(
+                NSPoint::new(PADDING, y + 4.0),
+                NSSize::new(row_label_width, 20.0),
+            ),
+            title,
+        );
+        let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+        let selector = create_segmented_control(
+            mtm,
+            NSRect::new(
+                NSPoint::new(selector_x, y),
+                NSSize::new(selector_width, 28.0),
+            ),
+            &labels,
+            selected,
+            delegate,
+            action,
+        );
+
+        // SAFETY: Adding valid subviews to a valid parent view
+        unsafe {
+            content_view.addSubview(&label);
+            content_view.addSubview(&selector);
+        }
+    }
+
+    let idle_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 120.0), NSSize::new(inner_width, 22.0)),
+        "Ask whether to keep recording when nothing is transcribed for 15 minutes and \
+         the Mac is idle",
+        limits.idle_reminder,
+        0,
+        delegate,
+        sel!(handleIdleReminderToggle:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&help_label);
+        content_view.addSubview(&idle_checkbox);
+    }
+}
+
+/// Segment index for a saved limit, or -1 if it isn't one of the presets
+fn preset_index(presets: &[Option<u32>], value: Option<u32>) -> isize {
+    presets
+        .iter()
+        .position(|&preset| preset == value)
+        .map_or(-1, |i| i as isize)
+}
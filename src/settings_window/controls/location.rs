@@ -6,6 +6,7 @@ use objc2_app_kit::{NSTextField, NSView};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
 
 use super::helpers::{create_path_label, create_section_label, create_small_button};
+use crate::localization::tr;
 use crate::settings_window::constants::PADDING;
 use crate::settings_window::delegate::SettingsActionDelegate;
 
@@ -28,9 +29,9 @@ pub(crate) fn add_location_controls(
     current_path: &str,
 ) -> Retained<NSTextField> {
     let config = LocationConfig {
-        section_title: "Transcript Location",
-        label_y: 180.0,
-        row_y: 150.0,
+        section_title: tr("settings.transcript_location"),
+        label_y: 210.0,
+        row_y: 180.0,
         choose_action: sel!(handleChooseLocation:),
         reset_action: sel!(handleResetLocation:),
     };
@@ -47,9 +48,9 @@ pub(crate) fn add_screenshot_location_controls(
     current_path: &str,
 ) -> Retained<NSTextField> {
     let config = LocationConfig {
-        section_title: "Screenshot Location",
-        label_y: 105.0,
-        row_y: 75.0,
+        section_title: tr("settings.screenshot_location"),
+        label_y: 135.0,
+        row_y: 105.0,
         choose_action: sel!(handleChooseScreenshotLocation:),
         reset_action: sel!(handleResetScreenshotLocation:),
     };
@@ -70,8 +71,8 @@ fn add_location_section(
 
     let label_height: CGFloat = 20.0;
     let row_height: CGFloat = 24.0;
-    let choose_button_width: CGFloat = 75.0;
-    let reset_button_width: CGFloat = 110.0;
+    let choose_button_width: CGFloat = 85.0;
+    let reset_button_width: CGFloat = 130.0;
     let button_gap: CGFloat = 8.0;
 
     // Calculate path label width (remaining space after buttons)
@@ -101,7 +102,7 @@ fn add_location_section(
     let choose_button = create_small_button(
         mtm,
         choose_button_frame,
-        "Choose...",
+        tr("settings.choose"),
         delegate,
         config.choose_action,
     );
@@ -115,7 +116,7 @@ fn add_location_section(
     let reset_button = create_small_button(
         mtm,
         reset_button_frame,
-        "Reset to Default",
+        tr("settings.reset_to_default"),
         delegate,
         config.reset_action,
     );
@@ -0,0 +1,118 @@
+//! Microphone test settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_small_button,
+};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Microphone test controls returned to caller for state management.
+pub(crate) struct MicrophoneControls {
+    pub(crate) level_label: Retained<NSTextField>,
+    pub(crate) status_label: Retained<NSTextField>,
+    pub(crate) transcript_label: Retained<NSTextField>,
+}
+
+/// Add microphone test controls to the content view.
+///
+/// Creates a section with:
+/// - Checkbox for also sending the test to the speech service
+/// - Test Microphone and Play Again buttons
+/// - Level meter while recording, then the clip's waveform
+/// - Verdict on the level, and the transcript when one was asked for
+pub(crate) fn add_microphone_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    transcribe: bool,
+) -> MicrophoneControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Test Microphone",
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 276.0), NSSize::new(inner_width, 36.0)),
+        "Records five seconds from the microphone, shows how loud it is and plays it back, \
+         so you can check your setup before an important meeting. The clip is never saved.",
+    );
+
+    let transcribe_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 246.0), NSSize::new(inner_width, 22.0)),
+        "Also send the test to the speech service and show the transcript",
+        transcribe,
+        0,
+        delegate,
+        sel!(handleMicTestTranscribeToggle:),
+    );
+
+    let button_width: CGFloat = 140.0;
+    let test_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 206.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Test Microphone",
+        delegate,
+        sel!(handleTestMicrophone:),
+    );
+
+    let play_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING + button_width + 10.0, 206.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Play Again",
+        delegate,
+        sel!(handlePlayMicTest:),
+    );
+
+    let level_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 172.0), NSSize::new(inner_width, 22.0)),
+        "",
+    );
+
+    let status_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 132.0), NSSize::new(inner_width, 34.0)),
+        "Press Test Microphone and speak as you would in a meeting.",
+    );
+
+    let transcript_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 30.0), NSSize::new(inner_width, 94.0)),
+        "",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&help_label);
+        content_view.addSubview(&transcribe_checkbox);
+        content_view.addSubview(&test_button);
+        content_view.addSubview(&play_button);
+        content_view.addSubview(&level_label);
+        content_view.addSubview(&status_label);
+        content_view.addSubview(&transcript_label);
+    }
+
+    MicrophoneControls {
+        level_label,
+        status_label,
+        transcript_label,
+    }
+}
@@ -3,19 +3,71 @@
 //! Contains functions for creating and laying out UI elements
 //! in the settings window sections.
 
+mod audio;
 mod azure;
 mod background;
+mod captions;
+mod debug_tab;
+mod export;
+mod feedback;
+mod google_drive;
 mod helpers;
+mod indicator;
+mod integrations;
+mod interface;
+mod issues;
+mod keywords;
+mod language_hotkey;
+mod limits;
 mod location;
+mod microphone;
+mod normalize;
+mod notes;
 mod openai;
+mod pdf;
+mod privacy;
+mod recording;
+mod screenshots;
+mod secret_field;
+mod stats;
+mod stop_hotkeys;
+mod storage;
+mod templates;
 mod transparency;
+mod workspaces;
 
+pub(crate) use audio::{add_audio_controls, AudioControls};
 pub(crate) use azure::{add_azure_controls, AzureControls};
 pub(crate) use background::add_background_controls;
+pub(crate) use captions::add_caption_controls;
+pub(crate) use debug_tab::add_debug_tab_controls;
+pub(crate) use export::{add_export_controls, ExportControls};
+pub(crate) use feedback::{add_auto_copy_controls, add_feedback_controls};
+pub(crate) use google_drive::{add_google_drive_controls, GoogleDriveControls};
 pub(crate) use helpers::{
-    create_section_label, create_segmented_control, create_separator, create_tab_item,
-    create_tab_view,
+    create_checkbox, create_section_label, create_segmented_control, create_separator,
+    create_tab_item, create_tab_view, lock_managed_control,
 };
+pub(crate) use indicator::add_indicator_controls;
+pub(crate) use integrations::{add_integrations_controls, IntegrationsControls, IntegrationsState};
+pub(crate) use interface::add_interface_language_controls;
+pub(crate) use issues::{add_issue_controls, IssueControls};
+pub(crate) use keywords::{add_keyword_controls, KeywordControls};
+pub(crate) use language_hotkey::add_language_hotkey_controls;
+pub(crate) use limits::add_limits_controls;
 pub(crate) use location::{add_location_controls, add_screenshot_location_controls};
+pub(crate) use microphone::{add_microphone_controls, MicrophoneControls};
+pub(crate) use normalize::{add_normalize_controls, NormalizeControls};
+pub(crate) use notes::{add_notes_controls, NotesControls};
 pub(crate) use openai::{add_openai_controls, OpenAIControls};
+pub(crate) use pdf::add_pdf_controls;
+pub(crate) use privacy::{add_privacy_controls, PrivacyControls, PrivacyState};
+pub(crate) use recording::{add_recording_controls, RecordingControls};
+pub(crate) use screenshots::add_screenshot_controls;
+pub(crate) use secret_field::{SecretField, AZURE_API_KEY_TAG, OPENAI_API_KEY_TAG};
+pub(crate) use stats::{add_stats_controls, StatsControls};
+pub(crate) use stop_hotkeys::add_stop_hotkey_controls;
+pub(crate) use storage::{add_storage_controls, StorageControls, StorageState};
+pub(crate) use templates::{add_template_controls, TemplateControls};
 pub(crate) use transparency::add_transparency_controls;
+pub(crate) use workspaces::{add_workspace_controls, WorkspaceControls};
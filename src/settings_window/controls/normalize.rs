@@ -0,0 +1,111 @@
+//! Spoken number, date and currency rewriting settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSButton, NSSegmentedControl, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+    create_separator,
+};
+use crate::preferences;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription::{NormalizeOption, Normalizer};
+
+/// Normalization controls returned to caller for state management.
+pub(crate) struct NormalizeControls {
+    pub(crate) language_selector: Retained<NSSegmentedControl>,
+    /// One checkbox per option, in `NormalizeOption::ALL` order
+    pub(crate) option_checkboxes: Vec<Retained<NSButton>>,
+}
+
+/// Add number, date and currency rewriting controls below the captions.
+///
+/// Creates a section with:
+/// - Language selector, starting at the transcription language if it has rules
+/// - A checkbox per rewrite, showing the selected language's options
+pub(crate) fn add_normalize_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+) -> NormalizeControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let separator = create_separator(mtm, 108.0, content_width);
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 80.0), NSSize::new(inner_width, 20.0)),
+        "Spoken Numbers and Dates",
+    );
+
+    let current = preferences::get_language_code();
+    let selected = Normalizer::LANGUAGES
+        .iter()
+        .position(|(code, _)| *code == current)
+        .unwrap_or(0);
+    let (language, _) = Normalizer::LANGUAGES[selected];
+    let options = preferences::get_normalize_options(language);
+
+    let labels: Vec<&str> = Normalizer::LANGUAGES.iter().map(|(_, l)| *l).collect();
+    let selector_width: CGFloat = 240.0;
+    let language_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 46.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &labels,
+        selected as isize,
+        delegate,
+        sel!(handleNormalizeLanguageChanged:),
+    );
+
+    let checkbox_width: CGFloat = 110.0;
+    let option_checkboxes: Vec<Retained<NSButton>> = NormalizeOption::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            create_checkbox(
+                mtm,
+                NSRect::new(
+                    NSPoint::new(
+                        PADDING + selector_width + 20.0 + i as CGFloat * checkbox_width,
+                        49.0,
+                    ),
+                    NSSize::new(checkbox_width, 22.0),
+                ),
+                option.label(),
+                options.enabled(*option),
+                i as isize,
+                delegate,
+                sel!(handleNormalizeOptionToggle:),
+            )
+        })
+        .collect();
+
+    let helper_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 8.0), NSSize::new(inner_width, 30.0)),
+        "Write them in digits as each line is transcribed, e.g. \"the twenty third of May\" becomes \"May 23\" and \"fifty dollars\" becomes \"$50\". Applies to recordings in the selected language.",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&separator);
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&language_selector);
+        for checkbox in &option_checkboxes {
+            content_view.addSubview(checkbox);
+        }
+        content_view.addSubview(&helper_label);
+    }
+
+    NormalizeControls {
+        language_selector,
+        option_checkboxes,
+    }
+}
@@ -0,0 +1,168 @@
+//! Meeting notes and output mode settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSTextView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_separator,
+    create_small_button, create_text_field,
+};
+use super::keywords::{create_helper_label, create_rules_editor};
+use crate::consent::{ConsentNotice, DEFAULT_TEXT};
+use crate::output_modes::MAX_CUSTOM_MODES;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Output mode controls returned to caller for state management.
+pub(crate) struct NotesControls {
+    pub(crate) consent_notice_field: Retained<NSTextField>,
+    pub(crate) output_modes_text_view: Retained<NSTextView>,
+    pub(crate) output_modes_status_label: Retained<NSTextField>,
+}
+
+/// Add meeting notes and output mode controls to the content view.
+///
+/// Creates a Meeting Notes section with a topic sections checkbox, saved
+/// immediately, and a description of what it adds, followed by the consent
+/// notice checkbox and its text, saved with a button. Below it, an Output
+/// Modes section lists the custom modes as editable text, saved with a button.
+pub(crate) fn add_notes_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    topic_sections: bool,
+    consent_notice: &ConsentNotice,
+    output_modes_text: &str,
+) -> NotesControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Meeting Notes",
+    );
+
+    let topics_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 292.0), NSSize::new(inner_width, 22.0)),
+        "Split meeting notes into topics",
+        topic_sections,
+        0,
+        delegate,
+        sel!(handleTopicSectionsToggle:),
+    );
+
+    let topics_help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 240.0), NSSize::new(inner_width, 46.0)),
+        "The transcript is divided into a section per topic, with a heading and \
+         the time it came up, plus a table of contents. Click an entry to jump to \
+         its section; Markdown exports link each entry to its heading.",
+    );
+
+    let checkbox_width: CGFloat = 190.0;
+    let notice_button_width: CGFloat = 70.0;
+    let notice_field_x = PADDING + checkbox_width + 10.0;
+    let notice_field_width = inner_width - checkbox_width - notice_button_width - 20.0;
+
+    let consent_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 208.0),
+            NSSize::new(checkbox_width, 24.0),
+        ),
+        "Start exported notes with",
+        consent_notice.enabled,
+        0,
+        delegate,
+        sel!(handleConsentNoticeToggle:),
+    );
+
+    let consent_notice_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(notice_field_x, 208.0),
+            NSSize::new(notice_field_width, 24.0),
+        ),
+        DEFAULT_TEXT,
+        &consent_notice.text,
+    );
+
+    let consent_save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(notice_field_x + notice_field_width + 10.0, 206.0),
+            NSSize::new(notice_button_width, 28.0),
+        ),
+        "Save",
+        delegate,
+        sel!(handleSaveConsentNotice:),
+    );
+
+    let separator = create_separator(mtm, 196.0, content_width);
+
+    let modes_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 170.0), NSSize::new(inner_width, 20.0)),
+        "Output Modes",
+    );
+
+    let modes_helper_text = format!(
+        "Up to {} modes of your own, each added to the stop menu and as a tab. Start each with \"## Name\", optionally followed by [plain] for plain text and [pdf] to save as PDF, then write its prompt on the lines below.",
+        MAX_CUSTOM_MODES
+    );
+    let modes_helper_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 132.0), NSSize::new(inner_width, 36.0)),
+        &modes_helper_text,
+    );
+
+    let (modes_scroll_view, output_modes_text_view) = create_rules_editor(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 64.0), NSSize::new(inner_width, 64.0)),
+        output_modes_text,
+    );
+
+    let output_modes_status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 44.0), NSSize::new(inner_width, 16.0)),
+        "",
+    );
+
+    let button_width: CGFloat = 120.0;
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - button_width) / 2.0, 10.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Save Modes",
+        delegate,
+        sel!(handleSaveOutputModes:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&topics_checkbox);
+        content_view.addSubview(&topics_help_label);
+        content_view.addSubview(&consent_checkbox);
+        content_view.addSubview(&consent_notice_field);
+        content_view.addSubview(&consent_save_button);
+        content_view.addSubview(&separator);
+        content_view.addSubview(&modes_label);
+        content_view.addSubview(&modes_helper_label);
+        content_view.addSubview(&modes_scroll_view);
+        content_view.addSubview(&output_modes_status_label);
+        content_view.addSubview(&save_button);
+    }
+
+    NotesControls {
+        consent_notice_field,
+        output_modes_text_view,
+        output_modes_status_label,
+    }
+}
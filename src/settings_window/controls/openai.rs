@@ -3,20 +3,22 @@
 //! Simplified version of Azure controls since OpenAI only requires an API key.
 
 use objc2::rc::Retained;
-use objc2::{msg_send, msg_send_id};
+use objc2::{msg_send_id, sel};
 use objc2_app_kit::NSTextField;
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
 
-use super::helpers::{create_section_label, create_small_button};
+use super::helpers::{create_section_label, create_segmented_control, create_small_button};
+use super::secret_field::{SecretField, OPENAI_API_KEY_TAG};
 use crate::keychain::OpenAICredentials;
+use crate::preferences;
 use crate::settings_window::constants::PADDING;
 use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription::TranscribeModel;
 
 /// OpenAI controls returned to caller for state management.
 pub(crate) struct OpenAIControls {
-    /// API key field. Note: Uses NSTextField since objc2-app-kit doesn't export NSSecureTextField.
-    /// The API key is stored securely in the macOS Keychain.
-    pub(crate) api_key_field: Retained<NSTextField>,
+    /// Masked API key field; the key itself is stored in the macOS Keychain
+    pub(crate) api_key_field: SecretField,
     pub(crate) status_label: Retained<NSTextField>,
 }
 
@@ -25,6 +27,7 @@ pub(crate) struct OpenAIControls {
 /// Creates a simple section with:
 /// - API Key field
 /// - Status label and save/clear buttons
+/// - Transcription model selector
 ///
 /// If `saved_credentials` is provided, the API key field will show "(stored in keychain)".
 pub(crate) fn add_openai_controls(
@@ -58,7 +61,7 @@ pub(crate) fn add_openai_controls(
     let key_field_y: CGFloat = 220.0;
 
     let key_label = create_field_label_at(mtm, field_x, key_label_y, field_width, "API Key");
-    let api_key_field = create_text_field(
+    let api_key_field = SecretField::new(
         mtm,
         NSRect::new(
             NSPoint::new(field_x, key_field_y),
@@ -69,6 +72,8 @@ pub(crate) fn add_openai_controls(
         } else {
             "sk-..."
         },
+        OPENAI_API_KEY_TAG,
+        delegate,
     );
 
     // Helper text
@@ -79,7 +84,7 @@ pub(crate) fn add_openai_controls(
         helper_y,
         content_width - PADDING * 2.0,
         label_height * 2.0,
-        "Get your API key from platform.openai.com. Uses gpt-4o-transcribe for transcription and gpt-5.2 for polishing.",
+        "Get your API key from platform.openai.com. Transcribes with the model chosen below and polishes with gpt-5.2.",
     );
 
     // Status label
@@ -127,16 +132,30 @@ pub(crate) fn add_openai_controls(
         objc2::sel!(handleClearOpenAICredentials:),
     );
 
+    // Transcription model
+    let model_label = create_field_label_at(mtm, field_x, 70.0, field_width, "Transcription Model");
+    let labels: Vec<&str> = TranscribeModel::ALL.iter().map(|m| m.label()).collect();
+    let model_selector = create_segmented_control(
+        mtm,
+        NSRect::new(NSPoint::new(field_x, 38.0), NSSize::new(480.0, 28.0)),
+        &labels,
+        preferences::get_openai_transcribe_model().to_index(),
+        delegate,
+        sel!(handleOpenAITranscribeModelChanged:),
+    );
+
     // Add all subviews
     unsafe {
         content_view.addSubview(&section_label);
         content_view.addSubview(&key_label);
-        content_view.addSubview(&api_key_field);
         content_view.addSubview(&helper_label);
         content_view.addSubview(&status_label);
         content_view.addSubview(&save_button);
         content_view.addSubview(&clear_button);
+        content_view.addSubview(&model_label);
+        content_view.addSubview(&model_selector);
     }
+    api_key_field.add_to(content_view);
 
     OpenAIControls {
         api_key_field,
@@ -171,38 +190,6 @@ fn create_field_label_at(
     label
 }
 
-/// Create an editable single-line text field with placeholder.
-fn create_text_field(
-    mtm: MainThreadMarker,
-    frame: NSRect,
-    placeholder: &str,
-) -> Retained<NSTextField> {
-    let field: Retained<NSTextField> =
-        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: frame] };
-
-    unsafe {
-        field.setEditable(true);
-        field.setSelectable(true);
-        field.setBordered(true);
-        field.setDrawsBackground(true);
-        let _: () = msg_send![&field, setPlaceholderString: &*NSString::from_str(placeholder)];
-
-        // Configure for single-line mode (no word wrap)
-        let cell: *mut objc2::runtime::AnyObject = msg_send![&field, cell];
-        if !cell.is_null() {
-            // NSLineBreakByTruncatingTail = 4
-            let _: () = msg_send![cell, setLineBreakMode: 4_usize];
-            let _: () = msg_send![cell, setUsesSingleLineMode: true];
-            let _: () = msg_send![cell, setScrollable: true];
-        }
-
-        let font = objc2_app_kit::NSFont::systemFontOfSize(12.0);
-        field.setFont(Some(&font));
-    }
-
-    field
-}
-
 /// Create a helper text label at a specific position.
 fn create_helper_label_at(
     mtm: MainThreadMarker,
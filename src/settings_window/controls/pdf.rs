@@ -0,0 +1,89 @@
+//! PDF page layout settings UI controls, at the bottom of the Storage tab.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_section_label, create_segmented_control, create_separator,
+};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription_window::{PdfMargins, PdfPageSize, PdfSettings};
+
+/// Add PDF page layout controls to the content view.
+///
+/// Creates a section with one row, all saved immediately:
+/// - Page size selector (A4, Letter)
+/// - Margin selector (Narrow, Normal, Wide)
+/// - Checkbox to head pages with the session title and number them
+pub(crate) fn add_pdf_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    settings: &PdfSettings,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let page_size_width: CGFloat = 130.0;
+    let margins_width: CGFloat = 220.0;
+    let gap: CGFloat = 10.0;
+
+    let separator = create_separator(mtm, 62.0, content_width);
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 38.0), NSSize::new(inner_width, 20.0)),
+        "PDF Pages",
+    );
+
+    let page_size_labels = PdfPageSize::ALL.map(PdfPageSize::label);
+    let page_size_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 6.0),
+            NSSize::new(page_size_width, 28.0),
+        ),
+        &page_size_labels,
+        settings.page_size.index(),
+        delegate,
+        sel!(handlePdfPageSizeChanged:),
+    );
+
+    let margins_x = PADDING + page_size_width + gap;
+    let margin_labels = PdfMargins::ALL.map(PdfMargins::label);
+    let margins_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(margins_x, 6.0),
+            NSSize::new(margins_width, 28.0),
+        ),
+        &margin_labels,
+        settings.margins.index(),
+        delegate,
+        sel!(handlePdfMarginsChanged:),
+    );
+
+    let checkbox_x = margins_x + margins_width + gap * 2.0;
+    let header_footer_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(
+            NSPoint::new(checkbox_x, 9.0),
+            NSSize::new(content_width - PADDING - checkbox_x, 22.0),
+        ),
+        "Session title and page numbers",
+        settings.header_footer,
+        0,
+        delegate,
+        sel!(handlePdfHeaderFooterToggle:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&separator);
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&page_size_selector);
+        content_view.addSubview(&margins_selector);
+        content_view.addSubview(&header_footer_checkbox);
+    }
+}
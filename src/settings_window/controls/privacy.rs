@@ -0,0 +1,256 @@
+//! Privacy settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+    create_separator, create_small_button, create_text_field, lock_managed_control,
+};
+use crate::managed;
+use crate::retention::{self, AGE_PRESETS, SIZE_PRESETS_MB};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Segment labels for `retention::AGE_PRESETS`
+const AGE_LABELS: [&str; 4] = ["Forever", "30 days", "90 days", "1 year"];
+
+/// Segment labels for `retention::SIZE_PRESETS_MB`
+const SIZE_LABELS: [&str; 4] = ["No limit", "1 GB", "5 GB", "10 GB"];
+
+/// Privacy controls returned to caller for state management.
+pub(crate) struct PrivacyControls {
+    pub(crate) screenshot_blocklist_field: Retained<NSTextField>,
+    pub(crate) retention_summary_label: Retained<NSTextField>,
+}
+
+/// Current privacy settings shown in the tab
+pub(crate) struct PrivacyState {
+    pub(crate) private_session_polish: bool,
+    /// Blocked apps, formatted for the text field
+    pub(crate) screenshot_blocklist: String,
+    pub(crate) encrypt_at_rest: bool,
+    pub(crate) retention_days: Option<u32>,
+    pub(crate) retention_max_mb: Option<u64>,
+}
+
+/// Add privacy controls to the content view.
+///
+/// Creates three sections:
+/// - Private Sessions: whether polishing is allowed during a private session,
+///   and the apps during which screenshots are refused
+/// - Encryption: checkbox to encrypt saved files and a button to open one
+/// - Retention: age limit and disk usage cap selectors, saved immediately,
+///   and a button to clean up now with a summary of reclaimed space
+pub(crate) fn add_privacy_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    state: &PrivacyState,
+) -> PrivacyControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    // Private Sessions section
+    let private_section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 335.0), NSSize::new(inner_width, 20.0)),
+        "Private Sessions",
+    );
+
+    let private_help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 303.0), NSSize::new(inner_width, 30.0)),
+        "Turn on Private Session in the menu bar to record without saving, screenshots, \
+         clipboard copies or captions.",
+    );
+
+    let private_polish_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 278.0), NSSize::new(inner_width, 22.0)),
+        "Allow AI polishing during private sessions",
+        state.private_session_polish,
+        0,
+        delegate,
+        sel!(handlePrivateSessionPolishToggle:),
+    );
+
+    let row_label_width: CGFloat = 150.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+    let save_button_width: CGFloat = 70.0;
+
+    let blocklist_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 250.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Block screenshots in",
+    );
+    let screenshot_blocklist_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 247.0),
+            NSSize::new(
+                content_width - PADDING - selector_x - save_button_width - 10.0,
+                24.0,
+            ),
+        ),
+        "App names or bundle IDs, separated by commas",
+        &state.screenshot_blocklist,
+    );
+    let blocklist_save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(content_width - PADDING - save_button_width, 245.0),
+            NSSize::new(save_button_width, 28.0),
+        ),
+        "Save",
+        delegate,
+        sel!(handleSaveScreenshotBlocklist:),
+    );
+
+    let private_separator = create_separator(mtm, 235.0, content_width);
+
+    // Encryption section
+    let encryption_section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 207.0), NSSize::new(inner_width, 20.0)),
+        "Encryption",
+    );
+
+    let open_button_width: CGFloat = 160.0;
+    let encrypt_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 180.0),
+            NSSize::new(inner_width - open_button_width - 10.0, 22.0),
+        ),
+        "Encrypt saved transcripts and screenshots",
+        state.encrypt_at_rest,
+        0,
+        delegate,
+        sel!(handleEncryptAtRestToggle:),
+    );
+
+    if managed::policy().encrypt_at_rest.is_some() {
+        lock_managed_control(&encrypt_checkbox);
+    }
+
+    let open_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(content_width - PADDING - open_button_width, 177.0),
+            NSSize::new(open_button_width, 28.0),
+        ),
+        "Open Encrypted File…",
+        delegate,
+        sel!(handleOpenEncryptedFile:),
+    );
+
+    let encryption_help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 146.0), NSSize::new(inner_width, 30.0)),
+        "Files are encrypted with AES-256-GCM using a key kept in your Keychain and \
+         saved with an extra .enc extension. Only Vissper on this Mac can open them.",
+    );
+
+    let encryption_separator = create_separator(mtm, 138.0, content_width);
+
+    // Retention section
+    let retention_section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 110.0), NSSize::new(inner_width, 20.0)),
+        "Retention",
+    );
+
+    let selector_width: CGFloat = 320.0;
+
+    let age_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 80.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Keep files for",
+    );
+    let age_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 76.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &AGE_LABELS,
+        retention::preset_index(&AGE_PRESETS, &state.retention_days),
+        delegate,
+        sel!(handleRetentionAgeChanged:),
+    );
+
+    let size_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 46.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Limit disk usage to",
+    );
+    let size_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 42.0),
+            NSSize::new(selector_width, 28.0),
+        ),
+        &SIZE_LABELS,
+        retention::preset_index(&SIZE_PRESETS_MB, &state.retention_max_mb),
+        delegate,
+        sel!(handleRetentionSizeChanged:),
+    );
+
+    let button_width: CGFloat = 120.0;
+    let clean_up_button = create_small_button(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 6.0), NSSize::new(button_width, 28.0)),
+        "Clean Up Now",
+        delegate,
+        sel!(handleCleanUpNow:),
+    );
+
+    let retention_summary_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING + button_width + 10.0, 10.0),
+            NSSize::new(inner_width - button_width - 10.0, 20.0),
+        ),
+        &retention::last_cleanup_description(),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&private_section_label);
+        content_view.addSubview(&private_help_label);
+        content_view.addSubview(&private_polish_checkbox);
+        content_view.addSubview(&blocklist_label);
+        content_view.addSubview(&screenshot_blocklist_field);
+        content_view.addSubview(&blocklist_save_button);
+        content_view.addSubview(&private_separator);
+        content_view.addSubview(&encryption_section_label);
+        content_view.addSubview(&encrypt_checkbox);
+        content_view.addSubview(&open_button);
+        content_view.addSubview(&encryption_help_label);
+        content_view.addSubview(&encryption_separator);
+        content_view.addSubview(&retention_section_label);
+        content_view.addSubview(&age_label);
+        content_view.addSubview(&age_selector);
+        content_view.addSubview(&size_label);
+        content_view.addSubview(&size_selector);
+        content_view.addSubview(&clean_up_button);
+        content_view.addSubview(&retention_summary_label);
+    }
+
+    PrivacyControls {
+        screenshot_blocklist_field,
+        retention_summary_label,
+    }
+}
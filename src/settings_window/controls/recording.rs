@@ -0,0 +1,110 @@
+//! Recording settings UI controls: wake word and screen lock.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_separator,
+    create_small_button, create_text_field,
+};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::wake_word::DEFAULT_PHRASE;
+
+/// Recording controls returned to caller for state management.
+pub(crate) struct RecordingControls {
+    pub(crate) wake_word_phrase_field: Retained<NSTextField>,
+}
+
+/// Add recording controls to the Audio tab, below the latency section.
+///
+/// Checkboxes are saved immediately; the wake phrase is saved with its button.
+pub(crate) fn add_recording_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    wake_word_enabled: bool,
+    wake_word_phrase: &str,
+    stop_on_screen_lock: bool,
+) -> RecordingControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let separator = create_separator(mtm, 145.0, content_width);
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 115.0), NSSize::new(inner_width, 20.0)),
+        "Recording",
+    );
+
+    let checkbox_width: CGFloat = 210.0;
+    let button_width: CGFloat = 70.0;
+    let field_x = PADDING + checkbox_width + 10.0;
+    let field_width = inner_width - checkbox_width - button_width - 20.0;
+
+    let checkbox = create_checkbox(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 83.0),
+            NSSize::new(checkbox_width, 24.0),
+        ),
+        "Start recording when I say",
+        wake_word_enabled,
+        0,
+        delegate,
+        sel!(handleWakeWordToggle:),
+    );
+
+    let phrase_field = create_text_field(
+        mtm,
+        NSRect::new(NSPoint::new(field_x, 83.0), NSSize::new(field_width, 24.0)),
+        DEFAULT_PHRASE,
+        wake_word_phrase,
+    );
+
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(field_x + field_width + 10.0, 81.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Save",
+        delegate,
+        sel!(handleSaveWakeWordPhrase:),
+    );
+
+    let lock_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 53.0), NSSize::new(inner_width, 24.0)),
+        "Stop recording when the screen locks",
+        stop_on_screen_lock,
+        0,
+        delegate,
+        sel!(handleStopOnScreenLockToggle:),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 10.0), NSSize::new(inner_width, 36.0)),
+        "The wake phrase is heard on this Mac only and needs Speech Recognition \
+         permission. Recording pauses while the Mac sleeps and reconnects on wake.",
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&separator);
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&checkbox);
+        content_view.addSubview(&phrase_field);
+        content_view.addSubview(&save_button);
+        content_view.addSubview(&lock_checkbox);
+        content_view.addSubview(&help_label);
+    }
+
+    RecordingControls {
+        wake_word_phrase_field: phrase_field,
+    }
+}
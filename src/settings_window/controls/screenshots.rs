@@ -0,0 +1,150 @@
+//! Screenshot format, resolution and capture settings UI controls.
+
+use objc2::sel;
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_segmented_control,
+    create_separator,
+};
+use super::keywords::create_helper_label;
+use crate::screenshot::{ScreenshotFormat, ScreenshotSettings, QUALITY_PRESETS};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Labels for the quality presets, in `QUALITY_PRESETS` order
+const QUALITY_LABELS: [&str; 4] = ["Small (50%)", "Medium (70%)", "High (85%)", "Best (95%)"];
+
+/// Add screenshot format controls to the content view.
+///
+/// Creates a section with, all saved immediately:
+/// - Format selector (PNG, JPEG, HEIC) and quality presets for JPEG and HEIC
+/// - Checkbox to save Retina captures at standard resolution
+/// - Checkbox to save large PNG captures as JPEG
+/// - Checkbox to leave Vissper's own windows out of captures
+pub(crate) fn add_screenshot_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    settings: &ScreenshotSettings,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let row_label_width: CGFloat = 110.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "File Format",
+    );
+
+    let format_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 290.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Save as",
+    );
+    let format_labels = ScreenshotFormat::ALL.map(ScreenshotFormat::label);
+    let format_selector = create_segmented_control(
+        mtm,
+        NSRect::new(NSPoint::new(selector_x, 286.0), NSSize::new(240.0, 28.0)),
+        &format_labels,
+        settings.format.index(),
+        delegate,
+        sel!(handleScreenshotFormatChanged:),
+    );
+
+    let quality_label = create_path_label(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 256.0),
+            NSSize::new(row_label_width, 20.0),
+        ),
+        "Quality",
+    );
+    let quality_selector = create_segmented_control(
+        mtm,
+        NSRect::new(
+            NSPoint::new(selector_x, 252.0),
+            NSSize::new(inner_width - row_label_width - 10.0, 28.0),
+        ),
+        &QUALITY_LABELS,
+        settings.quality_index(),
+        delegate,
+        sel!(handleScreenshotQualityChanged:),
+    );
+
+    let format_helper = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 212.0), NSSize::new(inner_width, 32.0)),
+        "JPEG and HEIC files are a fraction of the size of PNG. Quality applies to \
+         both. The clipboard always gets the full-quality capture.",
+    );
+
+    let separator = create_separator(mtm, 200.0, content_width);
+
+    let size_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 175.0), NSSize::new(inner_width, 20.0)),
+        "File Size",
+    );
+
+    let downscale_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 147.0), NSSize::new(inner_width, 22.0)),
+        "Save Retina screenshots at standard resolution (a quarter of the pixels)",
+        settings.downscale_retina,
+        0,
+        delegate,
+        sel!(handleScreenshotDownscaleToggle:),
+    );
+
+    let compress_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 121.0), NSSize::new(inner_width, 22.0)),
+        "Save PNG screenshots larger than 1 MB as JPEG",
+        settings.compress_large,
+        0,
+        delegate,
+        sel!(handleScreenshotCompressToggle:),
+    );
+
+    let capture_separator = create_separator(mtm, 105.0, content_width);
+
+    let capture_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 80.0), NSSize::new(inner_width, 20.0)),
+        "Capture",
+    );
+
+    let exclude_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 52.0), NSSize::new(inner_width, 22.0)),
+        "Leave Vissper's windows, such as the transcription window, out of screenshots",
+        settings.exclude_own_windows,
+        0,
+        delegate,
+        sel!(handleScreenshotExcludeOwnWindowsToggle:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&format_label);
+        content_view.addSubview(&format_selector);
+        content_view.addSubview(&quality_label);
+        content_view.addSubview(&quality_selector);
+        content_view.addSubview(&format_helper);
+        content_view.addSubview(&separator);
+        content_view.addSubview(&size_label);
+        content_view.addSubview(&downscale_checkbox);
+        content_view.addSubview(&compress_checkbox);
+        content_view.addSubview(&capture_separator);
+        content_view.addSubview(&capture_label);
+        content_view.addSubview(&exclude_checkbox);
+    }
+}
@@ -0,0 +1,173 @@
+//! Masked API key entry with a Show checkbox.
+//!
+//! NSSecureTextField draws bullets and refuses to copy its text, which keeps
+//! keys out of screen shares and recordings. It can't show the text either,
+//! so a plain field sits hidden in the same spot; the Show checkbox swaps the
+//! two and carries the typed text over.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{NSButton, NSSecureTextField, NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use zeroize::Zeroizing;
+
+use super::helpers::create_checkbox;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Width taken by the Show checkbox to the right of the field
+const REVEAL_WIDTH: CGFloat = 64.0;
+
+/// Tag of the Azure API key's Show checkbox
+pub(crate) const AZURE_API_KEY_TAG: isize = 0;
+
+/// Tag of the OpenAI API key's Show checkbox
+pub(crate) const OPENAI_API_KEY_TAG: isize = 1;
+
+/// A masked field, its plain twin and the checkbox switching between them
+pub(crate) struct SecretField {
+    secure: Retained<NSSecureTextField>,
+    plain: Retained<NSTextField>,
+    reveal: Retained<NSButton>,
+}
+
+impl SecretField {
+    /// Create the field in `frame`, with the Show checkbox at its right end.
+    ///
+    /// The checkbox sends `handleRevealSecret:` with `tag`.
+    pub(crate) fn new(
+        mtm: MainThreadMarker,
+        frame: NSRect,
+        placeholder: &str,
+        tag: isize,
+        delegate: &SettingsActionDelegate,
+    ) -> Self {
+        let field_frame = NSRect::new(
+            frame.origin,
+            NSSize::new(frame.size.width - REVEAL_WIDTH, frame.size.height),
+        );
+
+        // SAFETY: NSTextField allocation and configuration on the main thread
+        let (secure, plain) = unsafe {
+            let secure: Retained<NSSecureTextField> =
+                msg_send_id![mtm.alloc::<NSSecureTextField>(), initWithFrame: field_frame];
+            let plain: Retained<NSTextField> =
+                msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: field_frame];
+            for field in [&**secure, &*plain] {
+                configure(field, placeholder);
+            }
+            plain.setHidden(true);
+            (secure, plain)
+        };
+
+        let reveal = create_checkbox(
+            mtm,
+            NSRect::new(
+                NSPoint::new(
+                    frame.origin.x + frame.size.width - REVEAL_WIDTH + 8.0,
+                    frame.origin.y,
+                ),
+                NSSize::new(REVEAL_WIDTH - 8.0, frame.size.height),
+            ),
+            "Show",
+            false,
+            tag,
+            delegate,
+            objc2::sel!(handleRevealSecret:),
+        );
+
+        Self {
+            secure,
+            plain,
+            reveal,
+        }
+    }
+
+    /// Add the fields and the checkbox to `view`
+    pub(crate) fn add_to(&self, view: &NSView) {
+        // SAFETY: adding valid subviews on the main thread
+        unsafe {
+            view.addSubview(&self.secure);
+            view.addSubview(&self.plain);
+            view.addSubview(&self.reveal);
+        }
+    }
+
+    /// The text typed into whichever field is showing
+    pub(crate) fn value(&self) -> Zeroizing<String> {
+        // SAFETY: reading the value of valid text fields on the main thread
+        Zeroizing::new(unsafe { self.visible().stringValue() }.to_string())
+    }
+
+    /// Show the text in the clear, or mask it again
+    pub(crate) fn set_revealed(&self, revealed: bool) {
+        let (from, to) = if revealed {
+            (&**self.secure, &*self.plain)
+        } else {
+            (&*self.plain, &**self.secure)
+        };
+
+        // SAFETY: messages to valid controls on the main thread
+        unsafe {
+            to.setStringValue(&from.stringValue());
+            from.setStringValue(&NSString::from_str(""));
+            from.setHidden(true);
+            to.setHidden(false);
+            let _: () = msg_send![&self.reveal, setState: isize::from(revealed)];
+        }
+    }
+
+    /// Forget the typed text and mask the field, e.g. once the key is saved
+    pub(crate) fn scrub(&self) {
+        self.set_revealed(false);
+        // SAFETY: setting the value of a valid text field on the main thread
+        unsafe { self.secure.setStringValue(&NSString::from_str("")) };
+    }
+
+    /// Send the text editing messages of both fields to `delegate`
+    pub(crate) fn set_delegate(&self, delegate: &SettingsActionDelegate) {
+        for field in [&**self.secure, &*self.plain] {
+            // SAFETY: the delegate outlives the fields, both are kept by the window
+            unsafe {
+                let _: () = msg_send![field, setDelegate: delegate];
+            }
+        }
+    }
+
+    /// Change the placeholder of both fields
+    pub(crate) fn set_placeholder(&self, placeholder: &str) {
+        let placeholder = NSString::from_str(placeholder);
+        for field in [&**self.secure, &*self.plain] {
+            // SAFETY: setting the placeholder of a valid text field on the main thread
+            unsafe { field.setPlaceholderString(Some(&placeholder)) };
+        }
+    }
+
+    fn visible(&self) -> &NSTextField {
+        // SAFETY: reading the hidden state of a valid view on the main thread
+        if unsafe { self.plain.isHidden() } {
+            &self.secure
+        } else {
+            &self.plain
+        }
+    }
+}
+
+/// Make `field` an editable single-line field like the other settings fields
+///
+/// # Safety
+/// Must be called on the main thread.
+unsafe fn configure(field: &NSTextField, placeholder: &str) {
+    field.setEditable(true);
+    field.setSelectable(true);
+    field.setBordered(true);
+    field.setDrawsBackground(true);
+    field.setPlaceholderString(Some(&NSString::from_str(placeholder)));
+
+    let cell: *mut objc2::runtime::AnyObject = msg_send![field, cell];
+    if !cell.is_null() {
+        let _: () = msg_send![cell, setUsesSingleLineMode: true];
+        let _: () = msg_send![cell, setScrollable: true];
+    }
+
+    field.setFont(Some(&objc2_app_kit::NSFont::systemFontOfSize(12.0)));
+}
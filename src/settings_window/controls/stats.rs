@@ -0,0 +1,113 @@
+//! Local usage statistics settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_small_button,
+};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Stats controls returned to caller for state management.
+pub(crate) struct StatsControls {
+    pub(crate) summary_label: Retained<NSTextField>,
+    pub(crate) latency_label: Retained<NSTextField>,
+}
+
+/// Add usage statistics controls to the content view.
+///
+/// Creates a section with:
+/// - Opt-in checkbox, saved immediately
+/// - Summary of sessions, reconnects and error rates, plus speaking analytics
+/// - Measured partial transcript latency of the latest recording
+/// - Refresh and Reset buttons
+pub(crate) fn add_stats_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    enabled: bool,
+    summary: &str,
+    latency_summary: &str,
+) -> StatsControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Usage Statistics",
+    );
+
+    let checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 292.0), NSSize::new(inner_width, 22.0)),
+        "Record usage statistics on this Mac",
+        enabled,
+        0,
+        delegate,
+        sel!(handleMetricsToggle:),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 255.0), NSSize::new(inner_width, 32.0)),
+        "Counts sessions, recording time, reconnects and errors, and analyzes filler words \
+         and speaking pace. Stored next to your preferences and never sent anywhere.",
+    );
+
+    let summary_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 90.0), NSSize::new(inner_width, 145.0)),
+        summary,
+    );
+
+    // Not part of the opt-in statistics: measured live and never stored
+    let latency_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 60.0), NSSize::new(inner_width, 20.0)),
+        latency_summary,
+    );
+
+    let button_width: CGFloat = 120.0;
+    let buttons_x = (content_width - button_width * 2.0 - 10.0) / 2.0;
+    let refresh_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x, 25.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Refresh",
+        delegate,
+        sel!(handleRefreshStats:),
+    );
+
+    let reset_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(buttons_x + button_width + 10.0, 25.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Reset Statistics",
+        delegate,
+        sel!(handleResetStats:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&checkbox);
+        content_view.addSubview(&help_label);
+        content_view.addSubview(&summary_label);
+        content_view.addSubview(&latency_label);
+        content_view.addSubview(&refresh_button);
+        content_view.addSubview(&reset_button);
+    }
+
+    StatsControls {
+        summary_label,
+        latency_label,
+    }
+}
@@ -0,0 +1,91 @@
+//! Stop hotkey settings UI controls.
+
+use objc2::{msg_send, sel};
+use objc2_app_kit::NSView;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_path_label, create_section_label, create_segmented_control};
+use crate::hotkeys::{StopHotkey, StopHotkeys};
+use crate::localization::tr;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::transcription_window::TabType;
+
+/// Add the stop hotkey selectors to their own General tab section.
+///
+/// Creates a section with:
+/// - One selector per stop mode for the key combination stopping with it, or
+///   Off, tagged with the mode's tab
+pub(crate) fn add_stop_hotkey_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    hotkeys: StopHotkeys,
+) {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 122.0), NSSize::new(inner_width, 20.0)),
+        tr("settings.stop_hotkeys"),
+    );
+
+    let help_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 98.0), NSSize::new(inner_width, 20.0)),
+        tr("settings.stop_hotkeys_help"),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&help_label);
+    }
+
+    let row_label_width: CGFloat = 150.0;
+    let selector_x = PADDING + row_label_width + 10.0;
+    let selector_width: CGFloat = 300.0;
+    let row_height: CGFloat = 30.0;
+    let first_row_y: CGFloat = 64.0;
+    let labels: Vec<&str> = StopHotkey::ALL
+        .iter()
+        .map(|preset| preset.label())
+        .collect();
+
+    for (index, mode) in StopHotkeys::MODES.into_iter().enumerate() {
+        let y = first_row_y - row_height * index as CGFloat;
+        let title = match mode {
+            TabType::BasicPolish => tr("menu.stop_basic_polish"),
+            TabType::MeetingNotes => tr("menu.stop_meeting_notes"),
+            _ => tr("menu.stop_no_polish"),
+        };
+
+        let label = create_path_label(
+            mtm,
+            NSRect::new(
+                NSPoint::new(PADDING, y + 4.0),
+                NSSize::new(row_label_width, 20.0),
+            ),
+            title,
+        );
+        let selector = create_segmented_control(
+            mtm,
+            NSRect::new(
+                NSPoint::new(selector_x, y),
+                NSSize::new(selector_width, 28.0),
+            ),
+            &labels,
+            hotkeys.get(mode).index(),
+            delegate,
+            sel!(handleStopHotkeyChanged:),
+        );
+
+        // SAFETY: setTag is a standard NSControl call; adding valid subviews to a valid parent view
+        unsafe {
+            let _: () = msg_send![&selector, setTag: mode.to_index()];
+            content_view.addSubview(&label);
+            content_view.addSubview(&selector);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Storage settings UI controls (git archive and iCloud Drive).
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{
+    create_checkbox, create_path_label, create_section_label, create_separator,
+    create_small_button, create_text_field,
+};
+use super::keywords::create_helper_label;
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+
+/// Storage controls returned to caller for state management.
+pub(crate) struct StorageControls {
+    pub(crate) git_remote_field: Retained<NSTextField>,
+    pub(crate) git_status_label: Retained<NSTextField>,
+}
+
+/// Current storage settings shown in the tab
+pub(crate) struct StorageState {
+    pub(crate) git_archive: bool,
+    pub(crate) git_push: bool,
+    /// Remote URL, empty if none is set
+    pub(crate) git_remote: String,
+    pub(crate) store_in_icloud: bool,
+    /// Whether iCloud Drive is turned on for this Mac
+    pub(crate) icloud_available: bool,
+}
+
+/// Add storage controls to the content view.
+///
+/// Creates two sections:
+/// - Git Archive: checkboxes to commit each saved transcript and to push each
+///   commit, saved immediately, and the remote URL with a save button
+/// - iCloud Drive: checkbox to store transcripts there, saved immediately
+pub(crate) fn add_storage_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    state: &StorageState,
+) -> StorageControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+    let field_height: CGFloat = 22.0;
+    let button_height: CGFloat = 28.0;
+    let button_width: CGFloat = 120.0;
+
+    // Git archive section
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Git Archive",
+    );
+
+    let archive_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 292.0), NSSize::new(inner_width, 22.0)),
+        "Commit each saved transcript to a git repository in the transcripts folder",
+        state.git_archive,
+        0,
+        delegate,
+        sel!(handleGitArchiveToggle:),
+    );
+
+    let push_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 266.0), NSSize::new(inner_width, 22.0)),
+        "Push each commit to the remote below",
+        state.git_push,
+        0,
+        delegate,
+        sel!(handleGitArchivePushToggle:),
+    );
+
+    let remote_label = create_path_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 239.0), NSSize::new(inner_width, 16.0)),
+        "Remote URL (SSH or HTTPS, using your git credentials)",
+    );
+    let git_remote_field = create_text_field(
+        mtm,
+        NSRect::new(
+            NSPoint::new(PADDING, 216.0),
+            NSSize::new(inner_width - button_width - 10.0, field_height),
+        ),
+        "git@github.com:you/meeting-notes.git",
+        &state.git_remote,
+    );
+
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new(content_width - PADDING - button_width, 213.0),
+            NSSize::new(button_width, button_height),
+        ),
+        "Save Remote",
+        delegate,
+        sel!(handleSaveGitRemote:),
+    );
+
+    let git_status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 186.0), NSSize::new(inner_width, 24.0)),
+        "Requires git from the Xcode Command Line Tools. Transcripts saved outside the transcripts folder aren't committed.",
+    );
+
+    let separator = create_separator(mtm, 175.0, content_width);
+
+    // iCloud Drive section
+    let icloud_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 150.0), NSSize::new(inner_width, 20.0)),
+        "iCloud Drive",
+    );
+
+    let icloud_checkbox = create_checkbox(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 122.0), NSSize::new(inner_width, 22.0)),
+        "Store transcripts in iCloud Drive (Vissper/transcripts)",
+        state.store_in_icloud,
+        0,
+        delegate,
+        sel!(handleStoreInICloudToggle:),
+    );
+    if !state.icloud_available {
+        // SAFETY: disabling a valid checkbox on the main thread
+        unsafe { icloud_checkbox.setEnabled(false) };
+    }
+
+    let icloud_helper = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 70.0), NSSize::new(inner_width, 46.0)),
+        if state.icloud_available {
+            "Replaces the transcript location in General. Saves wait for iCloud's sync, and if iCloud holds another version of a file, the new one is saved next to it with a number instead of overwriting it."
+        } else {
+            "iCloud Drive is turned off on this Mac. Turn it on in System Settings → Apple Account → iCloud."
+        },
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&archive_checkbox);
+        content_view.addSubview(&push_checkbox);
+        content_view.addSubview(&remote_label);
+        content_view.addSubview(&git_remote_field);
+        content_view.addSubview(&save_button);
+        content_view.addSubview(&git_status_label);
+        content_view.addSubview(&separator);
+        content_view.addSubview(&icloud_label);
+        content_view.addSubview(&icloud_checkbox);
+        content_view.addSubview(&icloud_helper);
+    }
+
+    StorageControls {
+        git_remote_field,
+        git_status_label,
+    }
+}
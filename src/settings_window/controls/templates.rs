@@ -0,0 +1,86 @@
+//! Recording template settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSTextView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_section_label, create_small_button};
+use super::keywords::{create_helper_label, create_rules_editor};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::templates::MAX_TEMPLATES;
+
+/// Template controls returned to caller for state management.
+pub(crate) struct TemplateControls {
+    pub(crate) text_view: Retained<NSTextView>,
+    pub(crate) status_label: Retained<NSTextField>,
+}
+
+/// Add recording template controls to the content view.
+///
+/// Creates a Recording Templates section listing the templates as editable
+/// text, with a description of the setting lines, saved with a button.
+pub(crate) fn add_template_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    templates_text: &str,
+) -> TemplateControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Recording Templates",
+    );
+
+    let helper_text = format!(
+        "Up to {} templates, picked from the menu bar's Templates menu to start a recording. Start each with \"## Name\", then optionally \"language: no\", \"transcription: azure\" or \"openai\", \"mode: meeting notes\" (or \"basic polishing\", \"follow-up email\" or a custom mode's name), \"auto save: yes\" and \"save to: ~/Folder\". Control + Space stops the recording with the template's mode, and its result is saved without asking if set.",
+        MAX_TEMPLATES
+    );
+    let helper_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 230.0), NSSize::new(inner_width, 84.0)),
+        &helper_text,
+    );
+
+    let (scroll_view, text_view) = create_rules_editor(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 64.0), NSSize::new(inner_width, 160.0)),
+        templates_text,
+    );
+
+    let status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 44.0), NSSize::new(inner_width, 16.0)),
+        "",
+    );
+
+    let button_width: CGFloat = 140.0;
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - button_width) / 2.0, 10.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Save Templates",
+        delegate,
+        sel!(handleSaveTemplates:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&helper_label);
+        content_view.addSubview(&scroll_view);
+        content_view.addSubview(&status_label);
+        content_view.addSubview(&save_button);
+    }
+
+    TemplateControls {
+        text_view,
+        status_label,
+    }
+}
@@ -6,6 +6,7 @@ use objc2_app_kit::{NSSlider, NSTextField, NSView};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
 
 use super::helpers::{create_section_label, create_slider, create_value_label};
+use crate::localization::tr;
 use crate::settings_window::constants::PADDING;
 use crate::settings_window::delegate::SettingsActionDelegate;
 use crate::transcription_window::TranscriptionWindow;
@@ -24,18 +25,19 @@ pub(crate) fn add_transparency_controls(
     let label_height: CGFloat = 20.0;
     let slider_width: CGFloat = 300.0;
     let slider_height: CGFloat = 21.0;
+    let value_label_width: CGFloat = 50.0;
     let value_label_height: CGFloat = 16.0;
 
     // Section label at top of tab content
-    let label_y: CGFloat = 330.0;
+    let label_y: CGFloat = 340.0;
     let label_frame = NSRect::new(
         NSPoint::new(PADDING, label_y),
         NSSize::new(content_width - PADDING * 2.0, label_height),
     );
-    let label = create_section_label(mtm, label_frame, "Overlay Transparency");
+    let label = create_section_label(mtm, label_frame, tr("settings.overlay_transparency"));
 
     // Slider centered below label
-    let slider_y: CGFloat = 300.0;
+    let slider_y: CGFloat = 312.0;
     let slider_x = (content_width - slider_width) / 2.0;
     let slider_frame = NSRect::new(
         NSPoint::new(slider_x, slider_y),
@@ -55,11 +57,10 @@ pub(crate) fn add_transparency_controls(
         sel!(handleTransparencySlider:),
     );
 
-    // Value label below slider showing percentage
-    let value_y: CGFloat = 275.0;
+    // Value label beside slider showing percentage
     let value_frame = NSRect::new(
-        NSPoint::new(slider_x, value_y),
-        NSSize::new(slider_width, value_label_height),
+        NSPoint::new(slider_x + slider_width + 8.0, slider_y + 2.0),
+        NSSize::new(value_label_width, value_label_height),
     );
     let percentage = (current_transparency * 100.0).round() as i32;
     let value_label = create_value_label(mtm, value_frame, &format!("{}%", percentage));
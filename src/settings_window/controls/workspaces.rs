@@ -0,0 +1,86 @@
+//! Workspace settings UI controls.
+
+use objc2::rc::Retained;
+use objc2::sel;
+use objc2_app_kit::{NSTextField, NSTextView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+
+use super::helpers::{create_section_label, create_small_button};
+use super::keywords::{create_helper_label, create_rules_editor};
+use crate::settings_window::constants::PADDING;
+use crate::settings_window::delegate::SettingsActionDelegate;
+use crate::workspaces::MAX_WORKSPACES;
+
+/// Workspace controls returned to caller for state management.
+pub(crate) struct WorkspaceControls {
+    pub(crate) text_view: Retained<NSTextView>,
+    pub(crate) status_label: Retained<NSTextField>,
+}
+
+/// Add workspace controls to the content view.
+///
+/// Creates a Workspaces section listing the workspaces as editable text,
+/// with a description of the setting lines, saved with a button.
+pub(crate) fn add_workspace_controls(
+    mtm: MainThreadMarker,
+    content_view: &NSView,
+    delegate: &SettingsActionDelegate,
+    workspaces_text: &str,
+) -> WorkspaceControls {
+    let content_width = content_view.frame().size.width;
+    let inner_width = content_width - PADDING * 2.0;
+
+    let section_label = create_section_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 320.0), NSSize::new(inner_width, 20.0)),
+        "Workspaces",
+    );
+
+    let helper_text = format!(
+        "Up to {} workspaces besides Default, picked from the menu bar's Workspace menu. Start each with \"## Name\", then optionally \"transcripts: ~/Folder\", \"screenshots: ~/Folder\", \"transcription: azure\" or \"openai\", \"polishing: azure\" or \"openai\", \"keep days: 90\" and \"keep mb: 1024\". Other lines are added to every polishing prompt. Folders default to Documents/Vissper/Name, and files are kept forever unless set.",
+        MAX_WORKSPACES
+    );
+    let helper_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 230.0), NSSize::new(inner_width, 84.0)),
+        &helper_text,
+    );
+
+    let (scroll_view, text_view) = create_rules_editor(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 64.0), NSSize::new(inner_width, 160.0)),
+        workspaces_text,
+    );
+
+    let status_label = create_helper_label(
+        mtm,
+        NSRect::new(NSPoint::new(PADDING, 44.0), NSSize::new(inner_width, 16.0)),
+        "",
+    );
+
+    let button_width: CGFloat = 140.0;
+    let save_button = create_small_button(
+        mtm,
+        NSRect::new(
+            NSPoint::new((content_width - button_width) / 2.0, 10.0),
+            NSSize::new(button_width, 28.0),
+        ),
+        "Save Workspaces",
+        delegate,
+        sel!(handleSaveWorkspaces:),
+    );
+
+    // SAFETY: Adding valid subviews to a valid parent view
+    unsafe {
+        content_view.addSubview(&section_label);
+        content_view.addSubview(&helper_label);
+        content_view.addSubview(&scroll_view);
+        content_view.addSubview(&status_label);
+        content_view.addSubview(&save_button);
+    }
+
+    WorkspaceControls {
+        text_view,
+        status_label,
+    }
+}
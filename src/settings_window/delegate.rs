@@ -5,13 +5,12 @@
 
 use objc2::rc::Retained;
 use objc2::{declare_class, msg_send_id, mutability, ClassType, DeclaredClass};
-use objc2_app_kit::{NSSegmentedControl, NSSlider};
+use objc2_app_kit::{NSButton, NSSegmentedControl, NSSlider};
 use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol};
 use tracing::error;
 
 use super::SettingsWindow;
 use crate::preferences;
-use crate::transcription_window::TranscriptionWindow;
 
 // Delegate class for handling settings control actions
 declare_class!(
@@ -44,10 +43,9 @@ declare_class!(
                 let slider: &NSSlider = &*sender;
                 slider.doubleValue()
             };
-            TranscriptionWindow::set_transparency(value);
             SettingsWindow::update_transparency_label(value);
 
-            // Persist the preference
+            // Saving applies it to the overlay
             if let Err(e) = preferences::set_overlay_transparency(value) {
                 error!("Failed to save overlay transparency preference: {}", e);
             }
@@ -63,14 +61,21 @@ declare_class!(
             };
             // 0 = Dark, 1 = Light
             let is_dark = selected == 0;
-            TranscriptionWindow::set_dark_mode(is_dark);
 
-            // Persist the preference
+            // Saving applies it to the overlay
             if let Err(e) = preferences::set_is_dark_mode(is_dark) {
                 error!("Failed to save dark mode preference: {}", e);
             }
         }
 
+        /// Handle interface language segmented control selection
+        #[method(handleUiLanguageChanged:)]
+        fn handle_ui_language_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_ui_language(selected);
+        }
+
         #[method(handleChooseLocation:)]
         fn handle_choose_location(&self, _sender: *mut NSObject) {
             SettingsWindow::show_folder_picker();
@@ -115,6 +120,547 @@ declare_class!(
             SettingsWindow::clear_openai_credentials();
         }
 
+        /// Handle save keyword rules button click
+        #[method(handleSaveKeywordRules:)]
+        fn handle_save_keyword_rules(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_keyword_rules();
+        }
+
+        /// Handle the Show checkbox next to an API key field
+        #[method(handleRevealSecret:)]
+        fn handle_reveal_secret(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let (tag, revealed) = unsafe {
+                let checkbox: &NSButton = &*sender;
+                (checkbox.tag(), checkbox.state() == 1)
+            };
+            SettingsWindow::reveal_secret(tag, revealed);
+        }
+
+        /// Check the Azure connection fields, the only text fields this is
+        /// the delegate of, on every edit
+        #[method(controlTextDidChange:)]
+        fn control_text_did_change(&self, _notification: *mut NSObject) {
+            SettingsWindow::validate_azure_fields();
+        }
+
+        /// Handle sound cue and notification checkbox toggles
+        #[method(handleFeedbackOption:)]
+        fn handle_feedback_option(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let (tag, enabled) = unsafe {
+                let checkbox: &NSButton = &*sender;
+                (checkbox.tag(), checkbox.state() == 1)
+            };
+            SettingsWindow::set_feedback_option(tag, enabled);
+        }
+
+        /// Handle automation socket checkbox toggle
+        #[method(handleAutomationToggle:)]
+        fn handle_automation_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_automation_enabled(enabled);
+        }
+
+        /// Handle HTTP API checkbox toggle
+        #[method(handleHttpApiToggle:)]
+        fn handle_http_api_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_http_api_enabled(enabled);
+        }
+
+        /// Handle regenerate HTTP API token button click
+        #[method(handleRegenerateHttpApiToken:)]
+        fn handle_regenerate_http_api_token(&self, _sender: *mut NSObject) {
+            SettingsWindow::regenerate_http_api_token();
+        }
+
+        /// Handle issue tracker segmented control selection
+        #[method(handleIssueTrackerChanged:)]
+        fn handle_issue_tracker_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_issue_tracker(selected);
+        }
+
+        /// Handle Save Connection button click in the Issues tab
+        #[method(handleSaveIssueTracker:)]
+        fn handle_save_issue_tracker(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_issue_tracker();
+        }
+
+        /// Handle Clear Connection button click in the Issues tab
+        #[method(handleClearIssueTracker:)]
+        fn handle_clear_issue_tracker(&self, _sender: *mut NSObject) {
+            SettingsWindow::clear_issue_tracker();
+        }
+
+        /// Handle Notion export checkbox toggle
+        #[method(handleNotionExportToggle:)]
+        fn handle_notion_export_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_notion_export(enabled);
+        }
+
+        /// Handle Save Connection button click in the Export tab
+        #[method(handleSaveNotion:)]
+        fn handle_save_notion(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_notion();
+        }
+
+        /// Handle Clear Connection button click in the Export tab
+        #[method(handleClearNotion:)]
+        fn handle_clear_notion(&self, _sender: *mut NSObject) {
+            SettingsWindow::clear_notion();
+        }
+
+        /// Handle Google Drive upload checkbox toggle
+        #[method(handleGoogleDriveUploadToggle:)]
+        fn handle_google_drive_upload_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_google_drive_upload(enabled);
+        }
+
+        /// Handle Connect button click in the Export tab
+        #[method(handleConnectGoogleDrive:)]
+        fn handle_connect_google_drive(&self, _sender: *mut NSObject) {
+            SettingsWindow::connect_google_drive();
+        }
+
+        /// Handle Disconnect button click in the Export tab
+        #[method(handleDisconnectGoogleDrive:)]
+        fn handle_disconnect_google_drive(&self, _sender: *mut NSObject) {
+            SettingsWindow::disconnect_google_drive();
+        }
+
+        /// Handle git archive checkbox toggle
+        #[method(handleGitArchiveToggle:)]
+        fn handle_git_archive_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_git_archive(enabled);
+        }
+
+        /// Handle git archive push checkbox toggle
+        #[method(handleGitArchivePushToggle:)]
+        fn handle_git_archive_push_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_git_archive_push(enabled);
+        }
+
+        /// Handle Save Remote button click in the Storage tab
+        #[method(handleSaveGitRemote:)]
+        fn handle_save_git_remote(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_git_remote();
+        }
+
+        /// Handle iCloud Drive storage checkbox toggle
+        #[method(handleStoreInICloudToggle:)]
+        fn handle_store_in_icloud_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_store_in_icloud(enabled);
+        }
+
+        /// Handle PDF page size segmented control selection
+        #[method(handlePdfPageSizeChanged:)]
+        fn handle_pdf_page_size_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_pdf_page_size(selected);
+        }
+
+        /// Handle PDF margins segmented control selection
+        #[method(handlePdfMarginsChanged:)]
+        fn handle_pdf_margins_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_pdf_margins(selected);
+        }
+
+        /// Handle PDF session title and page numbers checkbox toggle
+        #[method(handlePdfHeaderFooterToggle:)]
+        fn handle_pdf_header_footer_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_pdf_header_footer(enabled);
+        }
+
+        /// Handle screenshot format segmented control selection
+        #[method(handleScreenshotFormatChanged:)]
+        fn handle_screenshot_format_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_screenshot_format(selected);
+        }
+
+        /// Handle screenshot quality segmented control selection
+        #[method(handleScreenshotQualityChanged:)]
+        fn handle_screenshot_quality_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_screenshot_quality(selected);
+        }
+
+        /// Handle standard resolution screenshots checkbox toggle
+        #[method(handleScreenshotDownscaleToggle:)]
+        fn handle_screenshot_downscale_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_screenshot_downscale(enabled);
+        }
+
+        /// Handle compress large screenshots checkbox toggle
+        #[method(handleScreenshotCompressToggle:)]
+        fn handle_screenshot_compress_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_screenshot_compress(enabled);
+        }
+
+        /// Handle leave Vissper's windows out of screenshots checkbox toggle
+        #[method(handleScreenshotExcludeOwnWindowsToggle:)]
+        fn handle_screenshot_exclude_own_windows_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_screenshot_exclude_own_windows(enabled);
+        }
+
+        /// Handle auto-copy segmented control selection for a stop mode
+        #[method(handleAutoCopyChanged:)]
+        fn handle_auto_copy_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let (tag, selected) = unsafe { ((*sender).tag(), (*sender).selectedSegment()) };
+            SettingsWindow::set_auto_copy(tag, selected);
+        }
+
+        /// Handle private session polishing checkbox toggle
+        #[method(handlePrivateSessionPolishToggle:)]
+        fn handle_private_session_polish_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let allowed = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_private_session_polish(allowed);
+        }
+
+        /// Handle encryption at rest checkbox toggle
+        #[method(handleEncryptAtRestToggle:)]
+        fn handle_encrypt_at_rest_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            if !SettingsWindow::set_encrypt_at_rest(enabled) {
+                // SAFETY: sender is still valid; revert to the saved state
+                unsafe { (*sender).setState(if enabled { 0 } else { 1 }) };
+            }
+        }
+
+        /// Handle open encrypted file button click
+        #[method(handleOpenEncryptedFile:)]
+        fn handle_open_encrypted_file(&self, _sender: *mut NSObject) {
+            SettingsWindow::open_encrypted_file();
+        }
+
+        /// Handle save screenshot blocklist button click
+        #[method(handleSaveScreenshotBlocklist:)]
+        fn handle_save_screenshot_blocklist(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_screenshot_blocklist();
+        }
+
+        /// Handle retention age segmented control selection
+        #[method(handleRetentionAgeChanged:)]
+        fn handle_retention_age_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_retention_age(selected);
+        }
+
+        /// Handle disk usage cap segmented control selection
+        #[method(handleRetentionSizeChanged:)]
+        fn handle_retention_size_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_retention_size(selected);
+        }
+
+        /// Handle session duration warning segmented control selection
+        #[method(handleLimitWarnAfterChanged:)]
+        fn handle_limit_warn_after_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_limit_warn_after(selected);
+        }
+
+        /// Handle session hard limit segmented control selection
+        #[method(handleLimitStopAfterChanged:)]
+        fn handle_limit_stop_after_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_limit_stop_after(selected);
+        }
+
+        /// Handle transcript size warning segmented control selection
+        #[method(handleLimitWarnWordsChanged:)]
+        fn handle_limit_warn_words_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_limit_warn_words(selected);
+        }
+
+        /// Handle stall timeout segmented control selection
+        #[method(handleStallTimeoutChanged:)]
+        fn handle_stall_timeout_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_stall_timeout(selected);
+        }
+
+        /// Handle quiet recording reminder checkbox toggle
+        #[method(handleIdleReminderToggle:)]
+        fn handle_idle_reminder_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_idle_reminder(enabled);
+        }
+
+        /// Handle language hotkey segmented control selection
+        #[method(handleLanguageHotkeyChanged:)]
+        fn handle_language_hotkey_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_language_hotkey(selected);
+        }
+
+        /// Handle stop hotkey segmented control selection for one mode
+        #[method(handleStopHotkeyChanged:)]
+        fn handle_stop_hotkey_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let (tag, selected) = unsafe { ((*sender).tag(), (*sender).selectedSegment()) };
+            SettingsWindow::set_stop_hotkey(tag, selected);
+        }
+
+        /// Handle recording indicator segmented control selection
+        #[method(handleRecordingIndicatorChanged:)]
+        fn handle_recording_indicator_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_recording_indicator(selected);
+        }
+
+        /// Handle recording banner checkbox toggle
+        #[method(handleRecordingBannerToggle:)]
+        fn handle_recording_banner_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_recording_banner(enabled);
+        }
+
+        /// Handle audio chunk duration segmented control selection
+        #[method(handleAudioChunkChanged:)]
+        fn handle_audio_chunk_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_audio_chunk(selected);
+        }
+
+        /// Handle audio buffer depth segmented control selection
+        #[method(handleAudioBufferChanged:)]
+        fn handle_audio_buffer_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe { (*sender).selectedSegment() };
+            SettingsWindow::set_audio_buffer(selected);
+        }
+
+        /// Handle save output modes button click
+        #[method(handleSaveOutputModes:)]
+        fn handle_save_output_modes(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_output_modes();
+        }
+
+        /// Handle save workspaces button click
+        #[method(handleSaveWorkspaces:)]
+        fn handle_save_workspaces(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_workspaces();
+        }
+
+        /// Handle save recording templates button click
+        #[method(handleSaveTemplates:)]
+        fn handle_save_templates(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_templates();
+        }
+
+        /// Handle topic sections checkbox toggle
+        #[method(handleTopicSectionsToggle:)]
+        fn handle_topic_sections_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_topic_sections(enabled);
+        }
+
+        /// Handle consent notice checkbox toggle
+        #[method(handleConsentNoticeToggle:)]
+        fn handle_consent_notice_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_consent_notice_enabled(enabled);
+        }
+
+        /// Handle save consent notice button click
+        #[method(handleSaveConsentNotice:)]
+        fn handle_save_consent_notice(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_consent_notice();
+        }
+
+        /// Handle connect ahead of time checkbox toggle
+        #[method(handlePrewarmConnectionToggle:)]
+        fn handle_prewarm_connection_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_prewarm_connection(enabled);
+        }
+
+        /// Handle low-bandwidth mode checkbox toggle
+        #[method(handleLowBandwidthModeToggle:)]
+        fn handle_low_bandwidth_mode_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_low_bandwidth_mode(enabled);
+        }
+
+        /// Handle noise reduction segmented control selection
+        #[method(handleNoiseReductionChanged:)]
+        fn handle_noise_reduction_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe {
+                let control: &NSSegmentedControl = &*sender;
+                control.selectedSegment()
+            };
+            SettingsWindow::set_noise_reduction(selected);
+        }
+
+        /// Handle wake word checkbox toggle
+        #[method(handleWakeWordToggle:)]
+        fn handle_wake_word_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_wake_word_enabled(enabled);
+        }
+
+        /// Handle save wake phrase button click
+        #[method(handleSaveWakeWordPhrase:)]
+        fn handle_save_wake_word_phrase(&self, _sender: *mut NSObject) {
+            SettingsWindow::save_wake_word_phrase();
+        }
+
+        /// Handle stop on screen lock checkbox toggle
+        #[method(handleStopOnScreenLockToggle:)]
+        fn handle_stop_on_screen_lock_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_stop_on_screen_lock(enabled);
+        }
+
+        /// Handle microphone test transcription checkbox toggle
+        #[method(handleMicTestTranscribeToggle:)]
+        fn handle_mic_test_transcribe_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_mic_test_transcribe(enabled);
+        }
+
+        /// Handle test microphone button click
+        #[method(handleTestMicrophone:)]
+        fn handle_test_microphone(&self, _sender: *mut NSObject) {
+            SettingsWindow::test_microphone();
+        }
+
+        /// Handle play microphone test again button click
+        #[method(handlePlayMicTest:)]
+        fn handle_play_mic_test(&self, _sender: *mut NSObject) {
+            SettingsWindow::play_mic_test();
+        }
+
+        /// Handle clean up now button click
+        #[method(handleCleanUpNow:)]
+        fn handle_clean_up_now(&self, _sender: *mut NSObject) {
+            SettingsWindow::clean_up_now();
+        }
+
+        /// Handle usage statistics checkbox toggle
+        #[method(handleMetricsToggle:)]
+        fn handle_metrics_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_metrics_enabled(enabled);
+        }
+
+        /// Handle Debug tab checkbox toggle
+        #[method(handleDebugTabToggle:)]
+        fn handle_debug_tab_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_debug_tab(enabled);
+        }
+
+        /// Handle refresh statistics button click
+        #[method(handleRefreshStats:)]
+        fn handle_refresh_stats(&self, _sender: *mut NSObject) {
+            SettingsWindow::refresh_stats();
+        }
+
+        /// Handle reset statistics button click
+        #[method(handleResetStats:)]
+        fn handle_reset_stats(&self, _sender: *mut NSObject) {
+            SettingsWindow::reset_stats();
+        }
+
+        /// Handle OpenAI transcription model segmented control selection
+        #[method(handleOpenAITranscribeModelChanged:)]
+        fn handle_openai_transcribe_model_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe {
+                let control: &NSSegmentedControl = &*sender;
+                control.selectedSegment()
+            };
+            SettingsWindow::set_openai_transcribe_model(selected);
+        }
+
+        /// Handle caption output segmented control selection
+        #[method(handleCaptionOutputChanged:)]
+        fn handle_caption_output_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe {
+                let control: &NSSegmentedControl = &*sender;
+                control.selectedSegment()
+            };
+            SettingsWindow::set_caption_output(selected);
+        }
+
+        /// Handle spoken number and date language segmented control selection
+        #[method(handleNormalizeLanguageChanged:)]
+        fn handle_normalize_language_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe {
+                let control: &NSSegmentedControl = &*sender;
+                control.selectedSegment()
+            };
+            SettingsWindow::set_normalize_language(selected);
+        }
+
+        /// Handle spoken number, date or currency checkbox toggle
+        #[method(handleNormalizeOptionToggle:)]
+        fn handle_normalize_option_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let (tag, enabled) = unsafe {
+                let checkbox: &NSButton = &*sender;
+                (checkbox.tag(), checkbox.state() == 1)
+            };
+            SettingsWindow::set_normalize_option(tag, enabled);
+        }
+
         /// Handle AI provider segmented control selection
         #[method(handleProviderChanged:)]
         fn handle_provider_changed(&self, sender: *mut NSSegmentedControl) {
@@ -126,6 +672,32 @@ declare_class!(
             // 0 = Azure OpenAI, 1 = OpenAI
             SettingsWindow::handle_provider_selection(selected);
         }
+
+        /// Handle polishing provider segmented control selection
+        #[method(handlePolishProviderChanged:)]
+        fn handle_polish_provider_changed(&self, sender: *mut NSSegmentedControl) {
+            // SAFETY: sender is a valid NSSegmentedControl passed by AppKit
+            let selected = unsafe {
+                let control: &NSSegmentedControl = &*sender;
+                control.selectedSegment()
+            };
+            // 0 = same as transcription, 1 = Azure OpenAI, 2 = OpenAI
+            SettingsWindow::handle_polish_provider_selection(selected);
+        }
+
+        /// Handle provider failover checkbox toggle
+        #[method(handleProviderFailoverToggle:)]
+        fn handle_provider_failover_toggle(&self, sender: *mut NSButton) {
+            // SAFETY: sender is a valid NSButton checkbox passed by AppKit
+            let enabled = unsafe { (*sender).state() == 1 };
+            SettingsWindow::set_provider_failover(enabled);
+        }
+
+        /// Handle the window closing via its close button (NSWindowDelegate)
+        #[method(windowWillClose:)]
+        fn window_will_close(&self, _notification: *mut NSObject) {
+            SettingsWindow::handle_window_closed();
+        }
     }
 
     unsafe impl NSObjectProtocol for SettingsActionDelegate {}
@@ -0,0 +1,243 @@
+//! Scrollable, resizable layout of the settings tabs
+//!
+//! Each tab shows a scroll view whose document is a vertical stack of
+//! sections. A section is a fixed-height view the control builders lay out
+//! with frames, measured from its bottom-left corner as before; the stack
+//! places the sections below each other with auto-layout constraints. When
+//! the window is smaller than a tab's sections, or a tab gains more settings
+//! than fit, the tab scrolls instead of cutting controls off.
+//!
+//! Sections are as wide as the tab, and never narrower than at the minimum
+//! window width. Controls spanning a section's width stretch with it and
+//! right-aligned ones keep to its right edge, so a wider window reflows them.
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType, DeclaredClass};
+use objc2_app_kit::{NSScrollView, NSStackView, NSTabViewItem, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSObjectProtocol, NSPoint, NSRect, NSSize};
+
+use super::constants::{PADDING, WINDOW_WIDTH};
+use super::controls;
+
+/// NSViewWidthSizable | NSViewHeightSizable
+const RESIZE_WITH_PARENT: u64 = 2 | 16;
+
+/// NSViewMinXMargin: keeps a view's distance to the right edge
+const KEEP_RIGHT_MARGIN: u64 = 1;
+
+/// NSViewWidthSizable
+const STRETCH_WIDTH: u64 = 2;
+
+/// NSLayoutPriorityDefaultHigh, yielding to the sections' minimum width
+const PRIORITY_HIGH: f32 = 750.0;
+
+/// NSUserInterfaceLayoutOrientationVertical
+const ORIENTATION_VERTICAL: isize = 1;
+
+/// NSLayoutAttributeLeading
+const ALIGN_LEADING: isize = 5;
+
+/// Space between the sections of a tab
+const SECTION_SPACING: CGFloat = 12.0;
+
+/// Narrowest width of a section, the tab's content width at the minimum
+/// window width
+const SECTION_WIDTH: CGFloat = WINDOW_WIDTH - 40.0;
+
+// Document view of a tab's scroll view. Flipped, so the sections start at
+// the top and extra height goes below them.
+declare_class!(
+    pub(super) struct TabDocumentView;
+
+    unsafe impl ClassType for TabDocumentView {
+        type Super = NSView;
+        type Mutability = mutability::MainThreadOnly;
+        const NAME: &'static str = "VissperSettingsTabDocumentView";
+    }
+
+    impl DeclaredClass for TabDocumentView {}
+
+    unsafe impl TabDocumentView {
+        #[method(isFlipped)]
+        fn is_flipped(&self) -> bool {
+            true
+        }
+    }
+
+    unsafe impl NSObjectProtocol for TabDocumentView {}
+);
+
+// A section of a tab. Controls added to it follow its width: those spanning
+// it between the paddings stretch, those ending at the right padding move.
+declare_class!(
+    struct SectionView;
+
+    unsafe impl ClassType for SectionView {
+        type Super = NSView;
+        type Mutability = mutability::MainThreadOnly;
+        const NAME: &'static str = "VissperSettingsSectionView";
+    }
+
+    impl DeclaredClass for SectionView {}
+
+    unsafe impl SectionView {
+        #[method(didAddSubview:)]
+        fn did_add_subview(&self, subview: &NSView) {
+            let frame = subview.frame();
+            let starts_left = frame.origin.x <= PADDING + 0.5;
+            let ends_right =
+                frame.origin.x + frame.size.width >= self.frame().size.width - PADDING - 0.5;
+            let mask = match (starts_left, ends_right) {
+                (true, true) => STRETCH_WIDTH,
+                (false, true) => KEEP_RIGHT_MARGIN,
+                _ => return,
+            };
+            // SAFETY: configuring a subview on the main thread
+            unsafe {
+                let _: () = msg_send![subview, setAutoresizingMask: mask];
+            }
+        }
+    }
+
+    unsafe impl NSObjectProtocol for SectionView {}
+);
+
+/// A settings tab whose sections scroll when they don't fit
+pub(super) struct SettingsTab {
+    pub(super) item: Retained<NSTabViewItem>,
+    document: Retained<TabDocumentView>,
+    stack: Retained<NSStackView>,
+}
+
+impl SettingsTab {
+    /// Create an empty tab with the given label
+    pub(super) fn new(mtm: MainThreadMarker, label: &str) -> Self {
+        let item = controls::create_tab_item(mtm, label);
+        let zero = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(0.0, 0.0));
+
+        // SAFETY: view allocation and configuration on the main thread; every
+        // view is retained by its superview or the tab item
+        let (document, stack) = unsafe {
+            let scroll_view: Retained<NSScrollView> =
+                msg_send_id![mtm.alloc::<NSScrollView>(), initWithFrame: zero];
+            scroll_view.setHasVerticalScroller(true);
+            scroll_view.setHasHorizontalScroller(false);
+            scroll_view.setAutohidesScrollers(true);
+            scroll_view.setDrawsBackground(false);
+            let _: () = msg_send![&scroll_view, setAutoresizingMask: RESIZE_WITH_PARENT];
+
+            let document: Retained<TabDocumentView> =
+                msg_send_id![mtm.alloc::<TabDocumentView>(), initWithFrame: zero];
+            let _: () = msg_send![&document, setTranslatesAutoresizingMaskIntoConstraints: false];
+            let _: () = msg_send![&scroll_view, setDocumentView: &*document];
+
+            let stack: Retained<NSStackView> =
+                msg_send_id![mtm.alloc::<NSStackView>(), initWithFrame: zero];
+            let _: () = msg_send![&stack, setOrientation: ORIENTATION_VERTICAL];
+            let _: () = msg_send![&stack, setAlignment: ALIGN_LEADING];
+            let _: () = msg_send![&stack, setSpacing: SECTION_SPACING];
+            let _: () = msg_send![&stack, setTranslatesAutoresizingMaskIntoConstraints: false];
+            document.addSubview(&stack);
+
+            // The document is as wide as the visible area and as tall as the stack
+            let clip_view = scroll_view.contentView();
+            equal(
+                msg_send![&document, leadingAnchor],
+                msg_send![&clip_view, leadingAnchor],
+            );
+            equal(
+                msg_send![&document, topAnchor],
+                msg_send![&clip_view, topAnchor],
+            );
+            equal(
+                msg_send![&document, widthAnchor],
+                msg_send![&clip_view, widthAnchor],
+            );
+            equal(
+                msg_send![&stack, leadingAnchor],
+                msg_send![&document, leadingAnchor],
+            );
+            equal(
+                msg_send![&stack, topAnchor],
+                msg_send![&document, topAnchor],
+            );
+            equal(
+                msg_send![&stack, bottomAnchor],
+                msg_send![&document, bottomAnchor],
+            );
+
+            item.setView(Some(&scroll_view));
+            (document, stack)
+        };
+
+        Self {
+            item,
+            document,
+            stack,
+        }
+    }
+
+    /// Add a section of the given height below the previous ones and return
+    /// the view to lay its controls out in
+    pub(super) fn add_section(&self, mtm: MainThreadMarker, height: CGFloat) -> Retained<NSView> {
+        let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(SECTION_WIDTH, height));
+
+        // SAFETY: view allocation and configuration on the main thread; the
+        // section is retained by the stack view
+        unsafe {
+            let section: Retained<SectionView> =
+                msg_send_id![mtm.alloc::<SectionView>(), initWithFrame: frame];
+            let _: () = msg_send![&section, setTranslatesAutoresizingMaskIntoConstraints: false];
+            self.stack.addArrangedSubview(&section);
+            at_least(msg_send![&section, widthAnchor], SECTION_WIDTH);
+            fix(msg_send![&section, heightAnchor], height);
+            prefer_equal(
+                msg_send![&section, widthAnchor],
+                msg_send![&self.document, widthAnchor],
+            );
+            Retained::into_super(section)
+        }
+    }
+}
+
+/// Activate a constraint making two layout anchors equal
+///
+/// # Safety
+/// Both must be anchors of the same kind, of views in one hierarchy, on the
+/// main thread.
+unsafe fn equal(anchor: *mut AnyObject, other: *mut AnyObject) {
+    let constraint: *mut AnyObject = msg_send![anchor, constraintEqualToAnchor: other];
+    let _: () = msg_send![constraint, setActive: true];
+}
+
+/// Activate a constraint making two layout anchors equal unless a required
+/// constraint conflicts
+///
+/// # Safety
+/// Both must be anchors of the same kind, of views in one hierarchy, on the
+/// main thread.
+unsafe fn prefer_equal(anchor: *mut AnyObject, other: *mut AnyObject) {
+    let constraint: *mut AnyObject = msg_send![anchor, constraintEqualToAnchor: other];
+    let _: () = msg_send![constraint, setPriority: PRIORITY_HIGH];
+    let _: () = msg_send![constraint, setActive: true];
+}
+
+/// Activate a constraint keeping a dimension anchor at `value` or more
+///
+/// # Safety
+/// `dimension` must be a width or height anchor, on the main thread.
+unsafe fn at_least(dimension: *mut AnyObject, value: CGFloat) {
+    let constraint: *mut AnyObject =
+        msg_send![dimension, constraintGreaterThanOrEqualToConstant: value];
+    let _: () = msg_send![constraint, setActive: true];
+}
+
+/// Activate a constraint fixing a dimension anchor to `value`
+///
+/// # Safety
+/// `dimension` must be a width or height anchor, on the main thread.
+unsafe fn fix(dimension: *mut AnyObject, value: CGFloat) {
+    let constraint: *mut AnyObject = msg_send![dimension, constraintEqualToConstant: value];
+    let _: () = msg_send![constraint, setActive: true];
+}
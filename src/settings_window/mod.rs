@@ -8,22 +8,28 @@ mod actions;
 mod controls;
 mod delegate;
 mod folder_picker;
+mod layout;
 mod path_utils;
 
 pub(crate) use delegate::SettingsActionDelegate;
 
-use objc2::msg_send_id;
+use layout::SettingsTab;
+
 use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{
-    NSApplication, NSBackingStoreType, NSScreen, NSSegmentedControl, NSTabView, NSTextField,
-    NSView, NSWindow, NSWindowStyleMask,
+    NSApplication, NSBackingStoreType, NSButton, NSPopUpButton, NSScreen, NSSegmentedControl,
+    NSTabView, NSTextField, NSTextView, NSView, NSWindow, NSWindowStyleMask,
 };
 use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
-use once_cell::sync::OnceCell;
-use std::sync::Mutex;
-use tracing::info;
+use std::cell::RefCell;
+use tracing::{error, info};
 
-use crate::keychain;
+use crate::localization::tr;
+use crate::{
+    app_context, audio, automation, encryption, keychain, keywords, metrics, output_modes,
+    preferences, screenshot_blocklist, storage, templates, transcription, workspaces,
+};
 
 /// Named constants for AppKit values and layout dimensions
 pub(crate) mod constants {
@@ -32,47 +38,73 @@ pub(crate) mod constants {
     /// NSBezelStyleRounded constant
     pub const NS_BEZEL_STYLE_ROUNDED: u64 = 1;
 
+    /// NSButtonTypeSwitch constant (checkbox)
+    pub const NS_BUTTON_TYPE_SWITCH: u64 = 3;
+
     /// NSModalResponseOK constant
     pub const NS_MODAL_RESPONSE_OK: isize = 1;
 
-    /// Window width in points (landscape)
-    pub const WINDOW_WIDTH: CGFloat = 550.0;
+    /// Initial and minimum window width in points (landscape)
+    pub const WINDOW_WIDTH: CGFloat = 740.0;
 
-    /// Window height in points (landscape)
+    /// Initial window height in points (landscape)
     pub const WINDOW_HEIGHT: CGFloat = 440.0;
 
+    /// Minimum window height; tabs scroll when their sections don't fit
+    pub const MIN_WINDOW_HEIGHT: CGFloat = 300.0;
+
     /// Standard padding for UI elements
     pub const PADDING: CGFloat = 20.0;
 
-    /// Tab content height (window height minus title bar and tab bar)
+    /// Height of a tab's first section (initial window height minus title
+    /// bar and tab bar)
     pub const TAB_CONTENT_HEIGHT: CGFloat = 370.0;
 }
 
 use constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
 
-/// Global state for the settings window
-static SETTINGS_WINDOW: OnceCell<Mutex<SettingsWindowInner>> = OnceCell::new();
+/// The settings window, once created (main thread only)
+fn settings_window() -> Option<&'static RefCell<SettingsWindowInner>> {
+    app_context::windows()?.settings.get()
+}
 
 /// Result from creating the settings window with all its controls.
 struct WindowCreationResult {
     window: Retained<NSWindow>,
     tab_view: Retained<NSTabView>,
     transparency_value_label: Retained<NSTextField>,
+    interface_language_label: Retained<NSTextField>,
     transcript_path_label: Retained<NSTextField>,
     screenshot_path_label: Retained<NSTextField>,
     provider_selector: Retained<NSSegmentedControl>,
     azure_controls: controls::AzureControls,
     openai_controls: controls::OpenAIControls,
+    keyword_controls: controls::KeywordControls,
+    notes_controls: controls::NotesControls,
+    normalize_controls: controls::NormalizeControls,
+    integrations_controls: controls::IntegrationsControls,
+    issue_controls: controls::IssueControls,
+    export_controls: controls::ExportControls,
+    google_drive_controls: controls::GoogleDriveControls,
+    storage_controls: controls::StorageControls,
+    workspace_controls: controls::WorkspaceControls,
+    template_controls: controls::TemplateControls,
+    privacy_controls: controls::PrivacyControls,
+    audio_controls: controls::AudioControls,
+    recording_controls: controls::RecordingControls,
+    microphone_controls: controls::MicrophoneControls,
+    stats_controls: controls::StatsControls,
 }
 
 /// Inner settings window state holding retained Objective-C references
-struct SettingsWindowInner {
+pub(crate) struct SettingsWindowInner {
     window: Retained<NSWindow>,
     #[allow(dead_code)]
     delegate: Retained<SettingsActionDelegate>,
     #[allow(dead_code)]
     tab_view: Retained<NSTabView>,
     transparency_value_label: Retained<NSTextField>,
+    interface_language_label: Retained<NSTextField>,
     transcript_path_label: Retained<NSTextField>,
     screenshot_path_label: Retained<NSTextField>,
     // Provider selector
@@ -82,18 +114,67 @@ struct SettingsWindowInner {
     azure_endpoint_field: Retained<NSTextField>,
     azure_stt_deployment_field: Retained<NSTextField>,
     azure_polish_deployment_field: Retained<NSTextField>,
-    azure_api_key_field: Retained<NSTextField>,
+    azure_api_key_field: controls::SecretField,
+    azure_realtime_version_field: Retained<NSTextField>,
+    azure_responses_version_field: Retained<NSTextField>,
+    azure_polish_route_popup: Retained<NSPopUpButton>,
+    azure_check_labels: [Retained<NSTextField>; 4],
+    azure_save_button: Retained<NSButton>,
     azure_status_label: Retained<NSTextField>,
     // OpenAI controls
-    openai_api_key_field: Retained<NSTextField>,
+    openai_api_key_field: controls::SecretField,
     openai_status_label: Retained<NSTextField>,
+    // Keyword highlighting controls
+    keyword_rules_text_view: Retained<NSTextView>,
+    keyword_status_label: Retained<NSTextField>,
+    // Meeting notes and output mode controls
+    consent_notice_field: Retained<NSTextField>,
+    output_modes_text_view: Retained<NSTextView>,
+    output_modes_status_label: Retained<NSTextField>,
+    // Spoken number and date controls
+    normalize_language_selector: Retained<NSSegmentedControl>,
+    normalize_option_checkboxes: Vec<Retained<NSButton>>,
+    // Integrations controls
+    http_api_token_label: Retained<NSTextField>,
+    // Issue tracker controls
+    issue_tracker_selector: Retained<NSSegmentedControl>,
+    issue_site_field: Retained<NSTextField>,
+    issue_email_field: Retained<NSTextField>,
+    issue_project_field: Retained<NSTextField>,
+    issue_token_field: Retained<NSTextField>,
+    issue_status_label: Retained<NSTextField>,
+    // Export controls
+    notion_token_field: Retained<NSTextField>,
+    notion_database_field: Retained<NSTextField>,
+    notion_status_label: Retained<NSTextField>,
+    google_drive_client_id_field: Retained<NSTextField>,
+    google_drive_client_secret_field: Retained<NSTextField>,
+    google_drive_folder_field: Retained<NSTextField>,
+    google_drive_status_label: Retained<NSTextField>,
+    // Storage controls
+    git_remote_field: Retained<NSTextField>,
+    git_status_label: Retained<NSTextField>,
+    // Workspace controls
+    workspaces_text_view: Retained<NSTextView>,
+    workspaces_status_label: Retained<NSTextField>,
+    // Recording template controls
+    templates_text_view: Retained<NSTextView>,
+    templates_status_label: Retained<NSTextField>,
+    // Privacy controls
+    screenshot_blocklist_field: Retained<NSTextField>,
+    retention_summary_label: Retained<NSTextField>,
+    // Audio controls
+    audio_latency_label: Retained<NSTextField>,
+    wake_word_phrase_field: Retained<NSTextField>,
+    // Microphone test controls
+    mic_test_level_label: Retained<NSTextField>,
+    mic_test_status_label: Retained<NSTextField>,
+    mic_test_transcript_label: Retained<NSTextField>,
+    // Stats controls
+    stats_summary_label: Retained<NSTextField>,
+    stats_latency_label: Retained<NSTextField>,
 }
 
-// SAFETY: SettingsWindowInner is only accessed from the main thread via
-// MainThreadMarker checks. The Retained types are Send when the underlying
-// types are MainThreadOnly (which they are for UI objects).
-unsafe impl Send for SettingsWindowInner {}
-
 /// Settings window manager.
 pub(crate) struct SettingsWindow;
 
@@ -115,12 +196,18 @@ impl SettingsWindow {
         #[allow(deprecated)]
         app.activateIgnoringOtherApps(true);
 
+        let Some(windows) = app_context::windows() else {
+            return;
+        };
+
         // Check if window already exists
-        if let Some(inner) = SETTINGS_WINDOW.get() {
-            if let Ok(inner) = inner.lock() {
+        if let Some(inner) = windows.settings.get() {
+            // Statistics may have changed while the window was closed
+            actions::refresh_stats();
+            if let Ok(inner) = inner.try_borrow() {
                 inner.window.makeKeyAndOrderFront(None);
-                return;
             }
+            return;
         }
 
         // Create delegate for control actions
@@ -129,12 +216,19 @@ impl SettingsWindow {
         // Create new window with UI
         let result = Self::create_window(mtm, &delegate);
 
-        // Store in global state
+        // The delegate also tidies up when the window is closed
+        // SAFETY: the delegate is retained in SettingsWindowInner alongside the window
+        unsafe {
+            let _: () = msg_send![&result.window, setDelegate: &*delegate];
+        }
+
+        // Store in the main-thread window registry
         let inner = SettingsWindowInner {
             window: result.window,
             delegate,
             tab_view: result.tab_view,
             transparency_value_label: result.transparency_value_label,
+            interface_language_label: result.interface_language_label,
             transcript_path_label: result.transcript_path_label,
             screenshot_path_label: result.screenshot_path_label,
             provider_selector: result.provider_selector,
@@ -142,18 +236,55 @@ impl SettingsWindow {
             azure_stt_deployment_field: result.azure_controls.stt_deployment_field,
             azure_polish_deployment_field: result.azure_controls.polish_deployment_field,
             azure_api_key_field: result.azure_controls.api_key_field,
+            azure_realtime_version_field: result.azure_controls.realtime_version_field,
+            azure_responses_version_field: result.azure_controls.responses_version_field,
+            azure_polish_route_popup: result.azure_controls.polish_route_popup,
+            azure_check_labels: result.azure_controls.check_labels,
+            azure_save_button: result.azure_controls.save_button,
             azure_status_label: result.azure_controls.status_label,
             openai_api_key_field: result.openai_controls.api_key_field,
             openai_status_label: result.openai_controls.status_label,
+            keyword_rules_text_view: result.keyword_controls.rules_text_view,
+            keyword_status_label: result.keyword_controls.status_label,
+            consent_notice_field: result.notes_controls.consent_notice_field,
+            output_modes_text_view: result.notes_controls.output_modes_text_view,
+            output_modes_status_label: result.notes_controls.output_modes_status_label,
+            normalize_language_selector: result.normalize_controls.language_selector,
+            normalize_option_checkboxes: result.normalize_controls.option_checkboxes,
+            http_api_token_label: result.integrations_controls.http_api_token_label,
+            issue_tracker_selector: result.issue_controls.tracker_selector,
+            issue_site_field: result.issue_controls.site_field,
+            issue_email_field: result.issue_controls.email_field,
+            issue_project_field: result.issue_controls.project_field,
+            issue_token_field: result.issue_controls.token_field,
+            issue_status_label: result.issue_controls.status_label,
+            notion_token_field: result.export_controls.notion_token_field,
+            notion_database_field: result.export_controls.notion_database_field,
+            notion_status_label: result.export_controls.notion_status_label,
+            google_drive_client_id_field: result.google_drive_controls.client_id_field,
+            google_drive_client_secret_field: result.google_drive_controls.client_secret_field,
+            google_drive_folder_field: result.google_drive_controls.folder_field,
+            google_drive_status_label: result.google_drive_controls.status_label,
+            git_remote_field: result.storage_controls.git_remote_field,
+            git_status_label: result.storage_controls.git_status_label,
+            workspaces_text_view: result.workspace_controls.text_view,
+            workspaces_status_label: result.workspace_controls.status_label,
+            templates_text_view: result.template_controls.text_view,
+            templates_status_label: result.template_controls.status_label,
+            screenshot_blocklist_field: result.privacy_controls.screenshot_blocklist_field,
+            retention_summary_label: result.privacy_controls.retention_summary_label,
+            audio_latency_label: result.audio_controls.latency_label,
+            wake_word_phrase_field: result.recording_controls.wake_word_phrase_field,
+            mic_test_level_label: result.microphone_controls.level_label,
+            mic_test_status_label: result.microphone_controls.status_label,
+            mic_test_transcript_label: result.microphone_controls.transcript_label,
+            stats_summary_label: result.stats_controls.summary_label,
+            stats_latency_label: result.stats_controls.latency_label,
         };
-        if SETTINGS_WINDOW.set(Mutex::new(inner)).is_err() {
-            // Window was created by another thread, show that one instead
-            if let Some(inner) = SETTINGS_WINDOW.get() {
-                if let Ok(inner) = inner.lock() {
-                    inner.window.makeKeyAndOrderFront(None);
-                }
-            }
-        }
+        windows.settings.set(RefCell::new(inner)).ok();
+
+        // Mark saved Azure settings that no longer pass the checks
+        actions::validate_azure_fields();
     }
 
     /// Create the settings window with all UI sections organized in tabs.
@@ -180,7 +311,8 @@ impl SettingsWindow {
         // Create standard macOS window with title bar
         let style_mask = NSWindowStyleMask::Titled
             | NSWindowStyleMask::Closable
-            | NSWindowStyleMask::Miniaturizable;
+            | NSWindowStyleMask::Miniaturizable
+            | NSWindowStyleMask::Resizable;
 
         // SAFETY: NSWindow initialization with valid parameters on main thread
         let window = unsafe {
@@ -193,9 +325,19 @@ impl SettingsWindow {
             )
         };
 
-        window.setTitle(&NSString::from_str("Vissper Settings"));
+        window.setTitle(&NSString::from_str(tr("settings.title")));
         unsafe { window.setReleasedWhenClosed(false) };
 
+        // SAFETY: size and autosave configuration on a valid NSWindow
+        unsafe {
+            window.setContentMinSize(NSSize::new(WINDOW_WIDTH, constants::MIN_WINDOW_HEIGHT));
+            // Restores the size and position the user left the window at
+            let _: bool = msg_send![
+                &window,
+                setFrameAutosaveName: &*NSString::from_str("VissperSettings")
+            ];
+        }
+
         // Create content view
         let content_frame = NSRect::new(
             NSPoint::new(0.0, 0.0),
@@ -212,35 +354,34 @@ impl SettingsWindow {
             NSSize::new(WINDOW_WIDTH - 20.0, WINDOW_HEIGHT - 20.0),
         );
         let tab_view = controls::create_tab_view(mtm, tab_frame);
+        // NSViewWidthSizable | NSViewHeightSizable, so the tabs follow the window
+        unsafe {
+            let _: () = msg_send![&tab_view, setAutoresizingMask: 18u64];
+        }
 
         // Create "General" tab
-        let general_tab = controls::create_tab_item(mtm, "General");
-
-        // Create content view for General tab
-        let general_content: Retained<NSView> = unsafe {
-            msg_send_id![mtm.alloc::<NSView>(), initWithFrame: NSRect::new(
-                NSPoint::new(0.0, 0.0),
-                NSSize::new(WINDOW_WIDTH - 40.0, constants::TAB_CONTENT_HEIGHT)
-            )]
-        };
+        let general_tab = SettingsTab::new(mtm, tr("settings.tab_general"));
+        let general_content = general_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
 
         // Add General tab controls
         let (_slider, transparency_value_label) =
             controls::add_transparency_controls(mtm, &general_content, delegate);
 
-        let sep1 = controls::create_separator(mtm, 265.0, WINDOW_WIDTH - 40.0);
+        let sep1 = controls::create_separator(mtm, 295.0, WINDOW_WIDTH - 40.0);
         unsafe { general_content.addSubview(&sep1) };
 
         let _segmented_control = controls::add_background_controls(mtm, &general_content, delegate);
+        let interface_language_label =
+            controls::add_interface_language_controls(mtm, &general_content, delegate);
 
-        let sep2 = controls::create_separator(mtm, 195.0, WINDOW_WIDTH - 40.0);
+        let sep2 = controls::create_separator(mtm, 225.0, WINDOW_WIDTH - 40.0);
         unsafe { general_content.addSubview(&sep2) };
 
         let transcript_path = path_utils::get_transcript_display_path();
         let transcript_path_label =
             controls::add_location_controls(mtm, &general_content, delegate, &transcript_path);
 
-        let sep3 = controls::create_separator(mtm, 125.0, WINDOW_WIDTH - 40.0);
+        let sep3 = controls::create_separator(mtm, 155.0, WINDOW_WIDTH - 40.0);
         unsafe { general_content.addSubview(&sep3) };
 
         let screenshot_path = path_utils::get_screenshot_display_path();
@@ -251,42 +392,40 @@ impl SettingsWindow {
             &screenshot_path,
         );
 
-        // Add provider selector at the bottom of General tab (below Screenshot Location which ends at y=75)
-        let sep4 = controls::create_separator(mtm, 55.0, WINDOW_WIDTH - 40.0);
+        // Add provider selectors at the bottom of General tab (below Screenshot Location which ends at y=105)
+        let sep4 = controls::create_separator(mtm, 85.0, WINDOW_WIDTH - 40.0);
         unsafe { general_content.addSubview(&sep4) };
 
         let provider_selector = actions::create_provider_selector(mtm, &general_content, delegate);
 
-        unsafe { general_tab.setView(Some(&general_content)) };
+        let hotkey_content = general_tab.add_section(mtm, 90.0);
+        controls::add_language_hotkey_controls(
+            mtm,
+            &hotkey_content,
+            delegate,
+            preferences::get_language_hotkey(),
+        );
+
+        let stop_hotkey_content = general_tab.add_section(mtm, 150.0);
+        controls::add_stop_hotkey_controls(
+            mtm,
+            &stop_hotkey_content,
+            delegate,
+            preferences::get_stop_hotkeys(),
+        );
 
         // Create "Azure" tab
-        let azure_tab = controls::create_tab_item(mtm, "Azure OpenAI");
-
-        // Create content view for Azure tab
-        let azure_content: Retained<NSView> = unsafe {
-            msg_send_id![mtm.alloc::<NSView>(), initWithFrame: NSRect::new(
-                NSPoint::new(0.0, 0.0),
-                NSSize::new(WINDOW_WIDTH - 40.0, constants::TAB_CONTENT_HEIGHT)
-            )]
-        };
+        let azure_tab = SettingsTab::new(mtm, "Azure OpenAI");
+        let azure_content = azure_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
 
         // Add Azure tab controls
         let azure_credentials = keychain::get_azure_credentials().ok();
         let azure_controls =
             controls::add_azure_controls(mtm, &azure_content, delegate, azure_credentials.as_ref());
 
-        unsafe { azure_tab.setView(Some(&azure_content)) };
-
         // Create "OpenAI" tab
-        let openai_tab = controls::create_tab_item(mtm, "OpenAI");
-
-        // Create content view for OpenAI tab
-        let openai_content: Retained<NSView> = unsafe {
-            msg_send_id![mtm.alloc::<NSView>(), initWithFrame: NSRect::new(
-                NSPoint::new(0.0, 0.0),
-                NSSize::new(WINDOW_WIDTH - 40.0, constants::TAB_CONTENT_HEIGHT)
-            )]
-        };
+        let openai_tab = SettingsTab::new(mtm, "OpenAI");
+        let openai_content = openai_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
 
         // Add OpenAI tab controls
         let openai_credentials = keychain::get_openai_credentials().ok();
@@ -297,13 +436,285 @@ impl SettingsWindow {
             openai_credentials.as_ref(),
         );
 
-        unsafe { openai_tab.setView(Some(&openai_content)) };
+        // Create "Highlights" tab
+        let keywords_tab = SettingsTab::new(mtm, tr("settings.tab_highlights"));
+        let keywords_content = keywords_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add keyword highlighting controls
+        let rules_text = keywords::format_rules(&keywords::active_rules());
+        let keyword_controls =
+            controls::add_keyword_controls(mtm, &keywords_content, delegate, &rules_text);
+
+        // Create "Notes" tab
+        let notes_tab = SettingsTab::new(mtm, tr("settings.tab_notes"));
+        let notes_content = notes_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add meeting notes and output mode controls
+        let modes_text = output_modes::format_modes(&preferences::get_custom_output_modes());
+        let notes_controls = controls::add_notes_controls(
+            mtm,
+            &notes_content,
+            delegate,
+            preferences::get_topic_sections(),
+            &preferences::get_consent_notice(),
+            &modes_text,
+        );
+
+        // Create "Notifications" tab
+        let feedback_tab = SettingsTab::new(mtm, tr("settings.tab_notifications"));
+        let feedback_content = feedback_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add sound cue and notification controls
+        let feedback_settings = preferences::get_feedback_settings();
+        controls::add_feedback_controls(mtm, &feedback_content, delegate, &feedback_settings);
+        controls::add_auto_copy_controls(
+            mtm,
+            &feedback_content,
+            delegate,
+            &preferences::get_auto_copy_settings(),
+        );
+
+        // Create "Integrations" tab
+        let integrations_tab = SettingsTab::new(mtm, tr("settings.tab_integrations"));
+        let integrations_content = integrations_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add automation socket and HTTP API controls
+        let http_api_token = automation::http_api_token().unwrap_or_else(|e| {
+            error!("Failed to load HTTP API token: {}", e);
+            String::new()
+        });
+        let integrations_state = controls::IntegrationsState {
+            socket_enabled: preferences::get_automation_socket_enabled(),
+            http_api_enabled: preferences::get_http_api_enabled(),
+            http_api_port: preferences::get_http_api_port(),
+            http_api_token,
+        };
+        let integrations_controls = controls::add_integrations_controls(
+            mtm,
+            &integrations_content,
+            delegate,
+            &integrations_state,
+        );
+
+        // Create "Issues" tab
+        let issues_tab = SettingsTab::new(mtm, tr("settings.tab_issues"));
+        let issues_content = issues_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add Jira / Linear connection controls
+        let issue_credentials = keychain::get_issue_tracker_credentials().ok();
+        let issue_controls = controls::add_issue_controls(
+            mtm,
+            &issues_content,
+            delegate,
+            issue_credentials.as_ref(),
+        );
+
+        // Create "Export" tab
+        let export_tab = SettingsTab::new(mtm, tr("settings.tab_export"));
+        let export_content = export_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add Notion export controls
+        let notion_credentials = keychain::get_notion_credentials().ok();
+        let export_controls = controls::add_export_controls(
+            mtm,
+            &export_content,
+            delegate,
+            preferences::get_notion_export(),
+            notion_credentials.as_ref(),
+        );
+
+        // Add Google Drive upload controls below
+        let google_drive_credentials = keychain::get_google_drive_credentials().ok();
+        let google_drive_controls = controls::add_google_drive_controls(
+            mtm,
+            &export_content,
+            delegate,
+            preferences::get_google_drive_upload(),
+            google_drive_credentials.as_ref(),
+        );
+
+        // Create "Storage" tab
+        let storage_tab = SettingsTab::new(mtm, tr("settings.tab_storage"));
+        let storage_content = storage_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add git archive controls
+        let storage_state = controls::StorageState {
+            git_archive: preferences::get_git_archive(),
+            git_push: preferences::get_git_archive_push(),
+            git_remote: preferences::get_git_archive_remote().unwrap_or_default(),
+            store_in_icloud: preferences::get_store_in_icloud(),
+            icloud_available: storage::icloud_drive_dir().is_some(),
+        };
+        let storage_controls =
+            controls::add_storage_controls(mtm, &storage_content, delegate, &storage_state);
+
+        // Add PDF page layout controls below
+        controls::add_pdf_controls(
+            mtm,
+            &storage_content,
+            delegate,
+            &preferences::get_pdf_settings(),
+        );
+
+        // Create "Workspaces" tab
+        let workspaces_tab = SettingsTab::new(mtm, tr("settings.tab_workspaces"));
+        let workspaces_content = workspaces_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add workspace controls
+        let workspaces_text = workspaces::format(&preferences::get_workspaces());
+        let workspace_controls =
+            controls::add_workspace_controls(mtm, &workspaces_content, delegate, &workspaces_text);
+
+        // Add recording template controls below the workspaces
+        let templates_content = workspaces_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+        let templates_text = templates::format(&preferences::get_templates());
+        let template_controls =
+            controls::add_template_controls(mtm, &templates_content, delegate, &templates_text);
+
+        // Create "Screenshots" tab
+        let screenshots_tab = SettingsTab::new(mtm, tr("settings.tab_screenshots"));
+        let screenshots_content = screenshots_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add screenshot format and size controls
+        controls::add_screenshot_controls(
+            mtm,
+            &screenshots_content,
+            delegate,
+            &preferences::get_screenshot_settings(),
+        );
+
+        // Create "Captions" tab
+        let captions_tab = SettingsTab::new(mtm, tr("settings.tab_captions"));
+        let captions_content = captions_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add live caption controls
+        controls::add_caption_controls(
+            mtm,
+            &captions_content,
+            delegate,
+            preferences::get_caption_output(),
+        );
+        let normalize_controls = controls::add_normalize_controls(mtm, &captions_content, delegate);
+
+        // Create "Privacy" tab
+        let privacy_tab = SettingsTab::new(mtm, tr("settings.tab_privacy"));
+        let privacy_content = privacy_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add private session, encryption and retention controls
+        let privacy_state = controls::PrivacyState {
+            private_session_polish: preferences::get_private_session_polish(),
+            screenshot_blocklist: screenshot_blocklist::format(
+                &preferences::get_screenshot_blocklist(),
+            ),
+            encrypt_at_rest: encryption::is_enabled(),
+            retention_days: preferences::get_retention_days(),
+            retention_max_mb: preferences::get_retention_max_mb(),
+        };
+        let privacy_controls =
+            controls::add_privacy_controls(mtm, &privacy_content, delegate, &privacy_state);
+
+        // Create "Audio" tab
+        let audio_tab = SettingsTab::new(mtm, tr("settings.tab_audio"));
+        let audio_content = audio_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add chunk duration and buffer depth controls
+        let audio_controls = controls::add_audio_controls(
+            mtm,
+            &audio_content,
+            delegate,
+            &audio::AudioTuning::from_preferences(),
+            preferences::get_prewarm_connection(),
+            preferences::get_low_bandwidth_mode(),
+            preferences::get_noise_reduction(),
+        );
+
+        // Add wake word and screen lock controls
+        let recording_controls = controls::add_recording_controls(
+            mtm,
+            &audio_content,
+            delegate,
+            preferences::get_wake_word_enabled(),
+            &preferences::get_wake_word_phrase(),
+            preferences::get_stop_on_screen_lock(),
+        );
+
+        // Create "Microphone" tab
+        let microphone_tab = SettingsTab::new(mtm, tr("settings.tab_microphone"));
+        let microphone_content = microphone_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add microphone test controls
+        let microphone_controls = controls::add_microphone_controls(
+            mtm,
+            &microphone_content,
+            delegate,
+            preferences::get_mic_test_transcribe(),
+        );
+
+        // Create "Session" tab
+        let limits_tab = SettingsTab::new(mtm, tr("settings.tab_session"));
+        let limits_content = limits_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add session duration, transcript size and stall limits
+        controls::add_limits_controls(
+            mtm,
+            &limits_content,
+            delegate,
+            &preferences::get_session_limits(),
+            preferences::get_stall_timeout_secs(),
+        );
+
+        // Add menu bar recording indicator and banner controls
+        controls::add_indicator_controls(
+            mtm,
+            &limits_content,
+            delegate,
+            preferences::get_recording_indicator(),
+            preferences::get_recording_banner(),
+        );
+
+        // Create "Stats" tab
+        let stats_tab = SettingsTab::new(mtm, tr("settings.tab_stats"));
+        let stats_content = stats_tab.add_section(mtm, constants::TAB_CONTENT_HEIGHT);
+
+        // Add usage statistics controls
+        let stats_controls = controls::add_stats_controls(
+            mtm,
+            &stats_content,
+            delegate,
+            metrics::is_enabled(),
+            &metrics::load().summary(),
+            &transcription::latency_summary(),
+        );
+
+        // Add the Debug tab opt-in below the statistics
+        let debug_content = stats_tab.add_section(mtm, 90.0);
+        controls::add_debug_tab_controls(
+            mtm,
+            &debug_content,
+            delegate,
+            preferences::get_debug_tab(),
+        );
 
         // Add tabs to tab view
         unsafe {
-            tab_view.addTabViewItem(&general_tab);
-            tab_view.addTabViewItem(&azure_tab);
-            tab_view.addTabViewItem(&openai_tab);
+            tab_view.addTabViewItem(&general_tab.item);
+            tab_view.addTabViewItem(&azure_tab.item);
+            tab_view.addTabViewItem(&openai_tab.item);
+            tab_view.addTabViewItem(&keywords_tab.item);
+            tab_view.addTabViewItem(&notes_tab.item);
+            tab_view.addTabViewItem(&feedback_tab.item);
+            tab_view.addTabViewItem(&integrations_tab.item);
+            tab_view.addTabViewItem(&issues_tab.item);
+            tab_view.addTabViewItem(&export_tab.item);
+            tab_view.addTabViewItem(&storage_tab.item);
+            tab_view.addTabViewItem(&workspaces_tab.item);
+            tab_view.addTabViewItem(&screenshots_tab.item);
+            tab_view.addTabViewItem(&captions_tab.item);
+            tab_view.addTabViewItem(&privacy_tab.item);
+            tab_view.addTabViewItem(&audio_tab.item);
+            tab_view.addTabViewItem(&microphone_tab.item);
+            tab_view.addTabViewItem(&limits_tab.item);
+            tab_view.addTabViewItem(&stats_tab.item);
         }
 
         // Add tab view to content view
@@ -318,18 +729,34 @@ impl SettingsWindow {
             window,
             tab_view,
             transparency_value_label,
+            interface_language_label,
             transcript_path_label,
             screenshot_path_label,
             provider_selector,
             azure_controls,
             openai_controls,
+            keyword_controls,
+            notes_controls,
+            normalize_controls,
+            integrations_controls,
+            issue_controls,
+            export_controls,
+            google_drive_controls,
+            storage_controls,
+            workspace_controls,
+            template_controls,
+            privacy_controls,
+            audio_controls,
+            recording_controls,
+            microphone_controls,
+            stats_controls,
         }
     }
 
     /// Update the transparency value label.
     pub(super) fn update_transparency_label(value: f64) {
-        if let Some(inner) = SETTINGS_WINDOW.get() {
-            if let Ok(inner) = inner.lock() {
+        if let Some(inner) = settings_window() {
+            if let Ok(inner) = inner.try_borrow() {
                 let percentage = (value * 100.0).round() as i32;
                 unsafe {
                     inner
@@ -365,6 +792,11 @@ impl SettingsWindow {
         actions::save_azure_credentials();
     }
 
+    /// Check the Azure fields as they are typed.
+    pub(super) fn validate_azure_fields() {
+        actions::validate_azure_fields();
+    }
+
     /// Clear Azure credentials from keychain.
     pub(super) fn clear_azure_credentials() {
         actions::clear_azure_credentials();
@@ -375,21 +807,403 @@ impl SettingsWindow {
         actions::save_openai_credentials();
     }
 
+    /// Show or mask the API key field the Show checkbox with `tag` belongs to.
+    pub(super) fn reveal_secret(tag: isize, revealed: bool) {
+        actions::reveal_secret(tag, revealed);
+    }
+
     /// Clear OpenAI credentials from keychain.
     pub(super) fn clear_openai_credentials() {
         actions::clear_openai_credentials();
     }
 
+    /// Save keyword highlighting rules from the rules list.
+    pub(super) fn save_keyword_rules() {
+        actions::save_keyword_rules();
+    }
+
+    /// Enable or disable a sound cue or notification option.
+    pub(super) fn set_feedback_option(tag: isize, enabled: bool) {
+        actions::set_feedback_option(tag, enabled);
+    }
+
+    /// Enable or disable the automation socket.
+    pub(super) fn set_automation_enabled(enabled: bool) {
+        actions::set_automation_enabled(enabled);
+    }
+
+    /// Enable or disable the local HTTP API.
+    pub(super) fn set_http_api_enabled(enabled: bool) {
+        actions::set_http_api_enabled(enabled);
+    }
+
+    /// Generate a new HTTP API token.
+    pub(super) fn regenerate_http_api_token() {
+        actions::regenerate_http_api_token();
+    }
+
+    /// Enable the fields the selected issue tracker needs.
+    pub(super) fn set_issue_tracker(selected_segment: isize) {
+        actions::set_issue_tracker(selected_segment);
+    }
+
+    /// Save the issue tracker connection from the UI fields to keychain.
+    pub(super) fn save_issue_tracker() {
+        actions::save_issue_tracker();
+    }
+
+    /// Clear the issue tracker connection from keychain.
+    pub(super) fn clear_issue_tracker() {
+        actions::clear_issue_tracker();
+    }
+
+    /// Turn exporting meeting notes to Notion on or off.
+    pub(super) fn set_notion_export(enabled: bool) {
+        actions::set_notion_export(enabled);
+    }
+
+    /// Save the Notion connection from the UI fields to keychain.
+    pub(super) fn save_notion() {
+        actions::save_notion();
+    }
+
+    /// Clear the Notion connection from keychain.
+    pub(super) fn clear_notion() {
+        actions::clear_notion();
+    }
+
+    /// Turn uploading saved transcripts to Google Drive on or off.
+    pub(super) fn set_google_drive_upload(enabled: bool) {
+        actions::set_google_drive_upload(enabled);
+    }
+
+    /// Connect Google Drive with the OAuth client and folder from the UI fields.
+    pub(super) fn connect_google_drive() {
+        actions::connect_google_drive();
+    }
+
+    /// Clear the Google Drive connection from keychain.
+    pub(super) fn disconnect_google_drive() {
+        actions::disconnect_google_drive();
+    }
+
+    /// Turn committing saved transcripts to git on or off.
+    pub(super) fn set_git_archive(enabled: bool) {
+        actions::set_git_archive(enabled);
+    }
+
+    /// Turn pushing git archive commits on or off.
+    pub(super) fn set_git_archive_push(enabled: bool) {
+        actions::set_git_archive_push(enabled);
+    }
+
+    /// Save the git archive remote URL from the settings field.
+    pub(super) fn save_git_remote() {
+        actions::save_git_remote();
+    }
+
+    /// Turn storing transcripts in iCloud Drive on or off.
+    pub(super) fn set_store_in_icloud(enabled: bool) {
+        actions::set_store_in_icloud(enabled);
+    }
+
+    /// Save the PDF page size for the selected segment.
+    pub(super) fn set_pdf_page_size(selected_segment: isize) {
+        actions::set_pdf_page_size(selected_segment);
+    }
+
+    /// Save the PDF margins for the selected segment.
+    pub(super) fn set_pdf_margins(selected_segment: isize) {
+        actions::set_pdf_margins(selected_segment);
+    }
+
+    /// Turn the PDF session title header and page number footer on or off.
+    pub(super) fn set_pdf_header_footer(enabled: bool) {
+        actions::set_pdf_header_footer(enabled);
+    }
+
+    /// Save the screenshot format for the selected segment.
+    pub(super) fn set_screenshot_format(selected_segment: isize) {
+        actions::set_screenshot_format(selected_segment);
+    }
+
+    /// Save the screenshot quality for the selected segment.
+    pub(super) fn set_screenshot_quality(selected_segment: isize) {
+        actions::set_screenshot_quality(selected_segment);
+    }
+
+    /// Turn saving Retina screenshots at standard resolution on or off.
+    pub(super) fn set_screenshot_downscale(enabled: bool) {
+        actions::set_screenshot_downscale(enabled);
+    }
+
+    /// Turn saving large PNG screenshots as JPEG on or off.
+    pub(super) fn set_screenshot_compress(enabled: bool) {
+        actions::set_screenshot_compress(enabled);
+    }
+
+    /// Turn leaving Vissper's own windows out of screenshots on or off.
+    pub(super) fn set_screenshot_exclude_own_windows(enabled: bool) {
+        actions::set_screenshot_exclude_own_windows(enabled);
+    }
+
+    /// Save the OpenAI transcription model for the selected segment.
+    pub(super) fn set_openai_transcribe_model(selected_segment: isize) {
+        actions::set_openai_transcribe_model(selected_segment);
+    }
+
+    /// Switch the live caption output.
+    pub(super) fn set_caption_output(selected_segment: isize) {
+        actions::set_caption_output(selected_segment);
+    }
+
+    /// Show the number and date rewrites saved for the selected language.
+    pub(super) fn set_normalize_language(selected_segment: isize) {
+        actions::set_normalize_language(selected_segment);
+    }
+
+    /// Turn a number, date or currency rewrite on or off for the selected language.
+    pub(super) fn set_normalize_option(tag: isize, enabled: bool) {
+        actions::set_normalize_option(tag, enabled);
+    }
+
+    /// Save the interface language for the selected segment.
+    pub(super) fn set_ui_language(selected_segment: isize) {
+        actions::set_ui_language(selected_segment);
+    }
+
+    /// Allow or disallow polishing during private sessions.
+    pub(super) fn set_private_session_polish(allowed: bool) {
+        actions::set_private_session_polish(allowed);
+    }
+
+    /// Enable or disable encryption at rest. Returns `false` if it failed.
+    pub(super) fn set_encrypt_at_rest(enabled: bool) -> bool {
+        actions::set_encrypt_at_rest(enabled)
+    }
+
+    /// Open a decrypted copy of an encrypted file.
+    pub(super) fn open_encrypted_file() {
+        actions::open_encrypted_file();
+    }
+
+    /// Change what is copied to the clipboard when a stop mode finishes.
+    pub(super) fn set_auto_copy(tag: isize, selected_segment: isize) {
+        actions::set_auto_copy(tag, selected_segment);
+    }
+
+    /// Save the apps during which screenshots are refused.
+    pub(super) fn save_screenshot_blocklist() {
+        actions::save_screenshot_blocklist();
+    }
+
+    /// Change how long transcripts and screenshots are kept.
+    pub(super) fn set_retention_age(selected_segment: isize) {
+        actions::set_retention_age(selected_segment);
+    }
+
+    /// Change the disk usage cap for transcripts and screenshots.
+    pub(super) fn set_retention_size(selected_segment: isize) {
+        actions::set_retention_size(selected_segment);
+    }
+
+    /// Apply the retention policy now.
+    pub(super) fn clean_up_now() {
+        actions::clean_up_now();
+    }
+
+    /// Change how much audio goes into each chunk sent for transcription.
+    pub(super) fn set_limit_warn_after(selected_segment: isize) {
+        actions::set_limit_warn_after(selected_segment);
+    }
+
+    pub(super) fn set_limit_stop_after(selected_segment: isize) {
+        actions::set_limit_stop_after(selected_segment);
+    }
+
+    pub(super) fn set_limit_warn_words(selected_segment: isize) {
+        actions::set_limit_warn_words(selected_segment);
+    }
+
+    pub(super) fn set_idle_reminder(enabled: bool) {
+        actions::set_idle_reminder(enabled);
+    }
+
+    /// Change how long audio is sent without a reply before reconnecting.
+    pub(super) fn set_stall_timeout(selected_segment: isize) {
+        actions::set_stall_timeout(selected_segment);
+    }
+
+    /// Change the key combination cycling the favorite languages.
+    pub(super) fn set_language_hotkey(selected_segment: isize) {
+        actions::set_language_hotkey(selected_segment);
+    }
+
+    /// Change the key combination stopping with the mode of `tag`.
+    pub(super) fn set_stop_hotkey(tag: isize, selected_segment: isize) {
+        actions::set_stop_hotkey(tag, selected_segment);
+    }
+
+    pub(super) fn set_recording_indicator(selected_segment: isize) {
+        actions::set_recording_indicator(selected_segment);
+    }
+
+    pub(super) fn set_recording_banner(enabled: bool) {
+        actions::set_recording_banner(enabled);
+    }
+
+    pub(super) fn set_audio_chunk(selected_segment: isize) {
+        actions::set_audio_chunk(selected_segment);
+    }
+
+    /// Change how much audio is buffered while the connection is slow.
+    pub(super) fn set_audio_buffer(selected_segment: isize) {
+        actions::set_audio_buffer(selected_segment);
+    }
+
+    /// Turn connecting when the menu bar menu opens on or off.
+    pub(super) fn set_prewarm_connection(enabled: bool) {
+        actions::set_prewarm_connection(enabled);
+    }
+
+    /// Turn low-bandwidth mode on or off.
+    pub(super) fn set_low_bandwidth_mode(enabled: bool) {
+        actions::set_low_bandwidth_mode(enabled);
+    }
+
+    /// Save the noise reduction mode for the selected segment.
+    pub(super) fn set_noise_reduction(selected_segment: isize) {
+        actions::set_noise_reduction(selected_segment);
+    }
+
+    /// Turn splitting meeting notes into topic sections on or off.
+    pub(super) fn set_topic_sections(enabled: bool) {
+        actions::set_topic_sections(enabled);
+    }
+
+    /// Turn the consent notice of exported meeting notes on or off.
+    pub(super) fn set_consent_notice_enabled(enabled: bool) {
+        actions::set_consent_notice_enabled(enabled);
+    }
+
+    /// Save the consent notice from its text field.
+    pub(super) fn save_consent_notice() {
+        actions::save_consent_notice();
+    }
+
+    /// Save the custom output modes from the modes list.
+    pub(super) fn save_output_modes() {
+        actions::save_output_modes();
+    }
+
+    /// Save the workspaces from the workspaces list.
+    pub(super) fn save_workspaces() {
+        actions::save_workspaces();
+    }
+
+    /// Save the recording templates from the templates list.
+    pub(super) fn save_templates() {
+        actions::save_templates();
+    }
+
+    /// Turn listening for the wake phrase on or off.
+    pub(super) fn set_wake_word_enabled(enabled: bool) {
+        actions::set_wake_word_enabled(enabled);
+    }
+
+    /// Save the wake phrase from its text field.
+    pub(super) fn save_wake_word_phrase() {
+        actions::save_wake_word_phrase();
+    }
+
+    /// Turn sending the microphone test to the speech service on or off.
+    pub(super) fn set_mic_test_transcribe(enabled: bool) {
+        actions::set_mic_test_transcribe(enabled);
+    }
+
+    /// Record, show and play back a microphone test.
+    pub(super) fn test_microphone() {
+        actions::test_microphone();
+    }
+
+    /// Play the last microphone test again.
+    pub(super) fn play_mic_test() {
+        actions::play_mic_test();
+    }
+
+    /// Turn stopping the recording on screen lock on or off.
+    pub(super) fn set_stop_on_screen_lock(enabled: bool) {
+        actions::set_stop_on_screen_lock(enabled);
+    }
+
+    /// Turn local usage statistics on or off.
+    pub(super) fn set_metrics_enabled(enabled: bool) {
+        actions::set_metrics_enabled(enabled);
+    }
+
+    /// Show or hide the Debug tab in the transcription window.
+    pub(super) fn set_debug_tab(enabled: bool) {
+        actions::set_debug_tab(enabled);
+    }
+
+    /// Reload the usage statistics summary.
+    pub(super) fn refresh_stats() {
+        actions::refresh_stats();
+    }
+
+    /// Delete recorded usage statistics.
+    pub(super) fn reset_stats() {
+        actions::reset_stats();
+    }
+
     /// Handle AI provider selection change.
     pub(super) fn handle_provider_selection(selected_segment: isize) {
         actions::handle_provider_selection(selected_segment);
     }
 
+    /// Handle polishing provider selection change.
+    pub(super) fn handle_polish_provider_selection(selected_segment: isize) {
+        actions::handle_polish_provider_selection(selected_segment);
+    }
+
+    /// Turn failing over to the other provider on or off.
+    pub(super) fn set_provider_failover(enabled: bool) {
+        actions::set_provider_failover(enabled);
+    }
+
+    /// Clean up after the window is closed.
+    ///
+    /// The window is kept for reuse, so end any editing and drop API keys that
+    /// were typed but never saved instead of leaving them in the hidden fields.
+    pub(super) fn handle_window_closed() {
+        let Some(inner) = settings_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Settings window already borrowed in handle_window_closed");
+            return;
+        };
+
+        // SAFETY: messages to the valid window and text fields on the main thread
+        unsafe {
+            let _: bool = msg_send![&inner.window, makeFirstResponder: std::ptr::null::<NSView>()];
+            let empty = NSString::from_str("");
+            inner.azure_api_key_field.scrub();
+            inner.openai_api_key_field.scrub();
+            inner.issue_token_field.setStringValue(&empty);
+            inner.notion_token_field.setStringValue(&empty);
+            inner
+                .google_drive_client_secret_field
+                .setStringValue(&empty);
+        }
+        info!("Settings window closed");
+    }
+
     /// Hide the settings window.
     #[allow(dead_code)]
     pub fn hide() {
-        if let Some(inner) = SETTINGS_WINDOW.get() {
-            if let Ok(inner) = inner.lock() {
+        if let Some(inner) = settings_window() {
+            if let Ok(inner) = inner.try_borrow() {
                 inner.window.orderOut(None);
                 info!("Settings window hidden");
             }
@@ -399,8 +1213,8 @@ impl SettingsWindow {
     /// Close the settings window.
     #[allow(dead_code)]
     pub fn close() {
-        if let Some(inner) = SETTINGS_WINDOW.get() {
-            if let Ok(inner) = inner.lock() {
+        if let Some(inner) = settings_window() {
+            if let Ok(inner) = inner.try_borrow() {
                 inner.window.close();
                 info!("Settings window closed");
             }
@@ -416,7 +1230,7 @@ mod tests {
     fn test_constants() {
         assert_eq!(constants::NS_BEZEL_STYLE_ROUNDED, 1);
         assert_eq!(constants::NS_MODAL_RESPONSE_OK, 1);
-        assert_eq!(constants::WINDOW_WIDTH, 550.0);
+        assert_eq!(constants::WINDOW_WIDTH, 740.0);
         assert_eq!(constants::WINDOW_HEIGHT, 440.0);
         assert_eq!(constants::PADDING, 20.0);
         assert_eq!(constants::TAB_CONTENT_HEIGHT, 370.0);
@@ -2,12 +2,19 @@
 
 use std::path::PathBuf;
 
-use crate::preferences;
+use crate::{preferences, storage};
 
 /// Format a path for display, replacing home directory with `~`.
+///
+/// Folders in iCloud Drive are shown as they appear in Finder.
 pub(crate) fn format_path_for_display(path: Option<&PathBuf>) -> String {
     match path {
         Some(p) => {
+            if let Some(icloud) = storage::icloud_drive_dir() {
+                if let Ok(stripped) = p.strip_prefix(&icloud) {
+                    return format!("iCloud Drive/{}", stripped.display());
+                }
+            }
             if let Some(home) = dirs::home_dir() {
                 if let Ok(stripped) = p.strip_prefix(&home) {
                     return format!("~/{}", stripped.display());
@@ -21,11 +28,7 @@ pub(crate) fn format_path_for_display(path: Option<&PathBuf>) -> String {
 
 /// Get the display path for the current transcript location.
 pub(crate) fn get_transcript_display_path() -> String {
-    format_path_for_display(
-        preferences::get_transcript_location()
-            .or_else(preferences::default_transcript_location)
-            .as_ref(),
-    )
+    format_path_for_display(storage::transcripts_dir().as_ref())
 }
 
 /// Get the display path for the current screenshot location.
@@ -0,0 +1,137 @@
+//! Graceful shutdown
+//!
+//! Quitting used to exit the process on the spot, dropping unsent audio and
+//! in-flight polish requests. Now Quit stops an active recording so its last
+//! audio reaches the speech service, saves the transcript to the transcripts
+//! folder, gives running polish jobs a moment to finish and only then stops
+//! the AppKit event loop. `main` unregisters the global hotkeys once the loop
+//! returns and shuts the Tokio runtime down, cancelling any remaining tasks.
+
+use chrono::Local;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use tracing::{error, info, warn};
+
+use crate::app_context::AppContext;
+use crate::menubar::MenuBar;
+use crate::provenance::{self, Provenance};
+use crate::transcription::SessionHandle;
+use crate::transcription_window::TabType;
+use crate::{encryption, git_archive, output_modes, private_mode, recording, storage};
+
+/// How long the speech service gets to finalize the last audio
+const RECORDING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long running polish jobs get before they are cancelled
+const POLISH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often shutdown checks whether the recording and polish jobs are done
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+static IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Whether the app is shutting down; new recordings are refused
+pub(crate) fn in_progress() -> bool {
+    IN_PROGRESS.load(Ordering::SeqCst)
+}
+
+/// Start shutting down. Must be called within the Tokio runtime.
+///
+/// Returns immediately; the event loop is stopped once cleanup has finished.
+/// Further calls while shutdown is running are ignored.
+pub(crate) fn begin(context: &AppContext) {
+    if IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        info!("Shutdown already in progress");
+        return;
+    }
+    info!("Shutting down...");
+
+    let context = context.clone();
+    tokio::spawn(async move {
+        if let Some(session) = recording::stop_for_shutdown(&context.recording_state) {
+            finish_recording(&context, session).await;
+        }
+        wait_for_polish_jobs().await;
+
+        dispatch::Queue::main().exec_async(MenuBar::stop);
+    });
+}
+
+/// Wait for the transcription task to close its connection, then save the transcript.
+async fn finish_recording(context: &AppContext, session: SessionHandle) {
+    let recording_ended = async {
+        while context
+            .recording_state
+            .lock()
+            .map(|state| state.is_some())
+            .unwrap_or(false)
+        {
+            sleep(POLL_INTERVAL).await;
+        }
+    };
+    if timeout(RECORDING_TIMEOUT, recording_ended).await.is_err() {
+        warn!("Speech service did not finish in time; saving the transcript so far");
+    }
+
+    let transcript = session.settled().await.full_transcript();
+    if transcript.trim().is_empty() {
+        return;
+    }
+    if private_mode::is_active() {
+        info!("Private session: transcript not saved on quit");
+        return;
+    }
+    save_transcript(&transcript);
+}
+
+/// Save the transcript of the recording that was active when quitting.
+fn save_transcript(transcript: &str) {
+    let dir = match storage::ensure_transcripts_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            error!("Failed to save transcript on quit: {}", e);
+            return;
+        }
+    };
+
+    // The raw transcript, whatever was last polished
+    let provenance = Provenance {
+        polish: None,
+        ..provenance::current()
+    };
+    let contents = format!("{}{}", provenance.front_matter(), transcript);
+    let name = storage::timestamped_name("transcript", "md");
+    match encryption::write_new_file(&dir, &name, contents.as_bytes()) {
+        Ok(path) => {
+            info!("Transcript saved on quit to {:?}", path);
+            git_archive::archive_transcript_on_quit(
+                &path,
+                &git_archive::CommitDetails {
+                    saved_at: Local::now(),
+                    mode: output_modes::title(TabType::Live),
+                    words: transcript.split_whitespace().count(),
+                    provenance,
+                },
+            );
+        }
+        Err(e) => error!("Failed to save transcript on quit: {}", e),
+    }
+}
+
+/// Wait for running polish jobs, giving up after [`POLISH_TIMEOUT`].
+async fn wait_for_polish_jobs() {
+    if recording::polish_jobs_idle() {
+        return;
+    }
+    info!("Waiting for polish jobs to finish");
+
+    let jobs_done = async {
+        while !recording::polish_jobs_idle() {
+            sleep(POLL_INTERVAL).await;
+        }
+    };
+    if timeout(POLISH_TIMEOUT, jobs_done).await.is_err() {
+        warn!("Polish jobs still running; cancelling them");
+    }
+}
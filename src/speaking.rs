@@ -0,0 +1,223 @@
+//! Speaking analytics for a finished transcript
+//!
+//! Counts filler words, words per minute and the longest uninterrupted
+//! stretch of speech, for presentation practice. The speech service commits a
+//! segment at each pause, so a committed segment is one stretch of speech.
+//! Results are kept with the usage statistics and shown in the Stats tab.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Filler words and phrases per transcription language, matched case-insensitively
+fn fillers_for(language: &str) -> &'static [&'static str] {
+    match language {
+        "no" => &["eh", "ehm", "altså", "liksom", "på en måte", "egentlig"],
+        "da" => &["øh", "øhm", "altså", "ligesom", "på en måde", "faktisk"],
+        "fi" => &["öö", "tota", "niinku", "siis", "tavallaan", "periaatteessa"],
+        "de" => &["äh", "ähm", "halt", "sozusagen", "quasi", "eigentlich"],
+        _ => &[
+            "um",
+            "uh",
+            "erm",
+            "like",
+            "you know",
+            "i mean",
+            "basically",
+            "actually",
+            "literally",
+        ],
+    }
+}
+
+/// Analytics for one recording session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct SpeakingStats {
+    pub(crate) words: u64,
+    pub(crate) duration_secs: u64,
+    /// Occurrences of each filler that was used at least once
+    pub(crate) fillers: BTreeMap<String, u64>,
+    /// Words in the longest segment spoken without a pause
+    pub(crate) longest_stretch_words: u64,
+}
+
+impl SpeakingStats {
+    /// Analyze the committed speech segments of a session.
+    ///
+    /// Screenshots, markers and notes are inserted as their own segments and
+    /// are never passed here, so every segment is speech.
+    pub(crate) fn analyze(segments: &[String], duration: Duration, language: &str) -> Self {
+        let fillers = fillers_for(language);
+        let mut stats = SpeakingStats {
+            duration_secs: duration.as_secs(),
+            ..Default::default()
+        };
+
+        for segment in segments {
+            let words = normalized_words(segment);
+            stats.words += words.len() as u64;
+            stats.longest_stretch_words = stats.longest_stretch_words.max(words.len() as u64);
+
+            for filler in fillers {
+                let count = count_phrase(&words, filler);
+                if count > 0 {
+                    *stats.fillers.entry(filler.to_string()).or_default() += count;
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Total number of filler words and phrases
+    pub(crate) fn filler_count(&self) -> u64 {
+        self.fillers.values().sum()
+    }
+
+    /// Average speaking rate over the whole session
+    pub(crate) fn words_per_minute(&self) -> u64 {
+        if self.duration_secs == 0 {
+            return 0;
+        }
+        self.words * 60 / self.duration_secs
+    }
+
+    /// Multi-line summary for the Stats tab
+    pub(crate) fn summary(&self) -> String {
+        if self.words == 0 {
+            return "Last session: no speech transcribed".to_string();
+        }
+
+        let mut fillers: Vec<(&String, &u64)> = self.fillers.iter().collect();
+        fillers.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let breakdown = fillers
+            .iter()
+            .take(5)
+            .map(|(filler, count)| format!("{} {}", filler, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let filler_line = if breakdown.is_empty() {
+            "Filler words: none".to_string()
+        } else {
+            format!(
+                "Filler words: {} ({:.1} per 100 words: {})",
+                self.filler_count(),
+                self.filler_count() as f64 * 100.0 / self.words as f64,
+                breakdown
+            )
+        };
+
+        // Time for the longest stretch at the session's average rate
+        let longest = match self.words_per_minute() {
+            0 => format!("{} words", self.longest_stretch_words),
+            wpm => format!(
+                "{} words (about {}s)",
+                self.longest_stretch_words,
+                self.longest_stretch_words * 60 / wpm
+            ),
+        };
+
+        format!(
+            "Last session: {} words, {} words per minute\n{}\nLongest stretch without a pause: {}",
+            self.words,
+            self.words_per_minute(),
+            filler_line,
+            longest,
+        )
+    }
+}
+
+/// Lowercased words with surrounding punctuation removed
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Occurrences of a (possibly multi-word) phrase in a word list
+fn count_phrase(words: &[String], phrase: &str) -> u64 {
+    let phrase: Vec<&str> = phrase.split_whitespace().collect();
+    if phrase.is_empty() || words.len() < phrase.len() {
+        return 0;
+    }
+    words
+        .windows(phrase.len())
+        .filter(|window| window.iter().zip(&phrase).all(|(w, p)| w == p))
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_counts_fillers_and_phrases() {
+        let stats = SpeakingStats::analyze(
+            &segments(&[
+                "Um, so I think, like, we should ship.",
+                "You know, UM, it's ready.",
+            ]),
+            Duration::from_secs(60),
+            "en",
+        );
+
+        assert_eq!(stats.fillers.get("um"), Some(&2));
+        assert_eq!(stats.fillers.get("like"), Some(&1));
+        assert_eq!(stats.fillers.get("you know"), Some(&1));
+        assert_eq!(stats.filler_count(), 4);
+    }
+
+    #[test]
+    fn test_fillers_follow_language() {
+        let stats = SpeakingStats::analyze(
+            &segments(&["Äh, das ist halt so. Um."]),
+            Duration::from_secs(30),
+            "de",
+        );
+
+        assert_eq!(stats.fillers.get("äh"), Some(&1));
+        assert_eq!(stats.fillers.get("halt"), Some(&1));
+        assert_eq!(stats.fillers.get("um"), None);
+    }
+
+    #[test]
+    fn test_rate_and_longest_stretch() {
+        let stats = SpeakingStats::analyze(
+            &segments(&["one two three", "one two three four five six", "one"]),
+            Duration::from_secs(30),
+            "en",
+        );
+
+        assert_eq!(stats.words, 10);
+        assert_eq!(stats.words_per_minute(), 20);
+        assert_eq!(stats.longest_stretch_words, 6);
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(
+            SpeakingStats::default().summary(),
+            "Last session: no speech transcribed"
+        );
+
+        let stats = SpeakingStats::analyze(
+            &segments(&["Um, hello everyone and welcome, um, to the new demo"]),
+            Duration::from_secs(6),
+            "en",
+        );
+        let summary = stats.summary();
+
+        assert!(summary.starts_with("Last session: 10 words, 100 words per minute\n"));
+        assert!(summary.contains("Filler words: 2 (20.0 per 100 words: um 2)"));
+        assert!(summary.contains("Longest stretch without a pause: 10 words (about 6s)"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Crash-safe writes and collision-free file names
+//!
+//! Files are written to a temporary file in the same folder, flushed to disk
+//! and then renamed over the target, so a crash or a full disk mid-write
+//! leaves either the old file or the new one, never a truncated mix. New
+//! files get a numbered name when one with the same name already exists.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Tells apart temporary files of writes running at the same time
+static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+
+/// Write `contents` to a temporary file next to `path`, then move it in place.
+pub(super) fn replace_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}-{}.vissper-tmp",
+        name,
+        std::process::id(),
+        NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = File::create(&temp_path).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()
+    });
+    written
+        .and_then(|()| fs::rename(&temp_path, path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&temp_path);
+        })
+}
+
+/// First of `name.ext`, `name 2.ext`, `name 3.ext`… that isn't `taken`.
+pub(super) fn free_path(path: &Path, taken: impl Fn(&Path) -> bool) -> PathBuf {
+    candidates(path)
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Claim the first of `name.ext`, `name 2.ext`… that isn't `taken` by creating it empty.
+///
+/// Each name is created exclusively, so writes racing for the same name end
+/// up with different files rather than one replacing the other.
+pub(super) fn reserve_path(path: &Path, taken: impl Fn(&Path) -> bool) -> io::Result<PathBuf> {
+    for candidate in candidates(path).filter(|candidate| !taken(candidate)) {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(_) => return Ok(candidate),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::ErrorKind::AlreadyExists.into())
+}
+
+/// `path` followed by its numbered variants
+fn candidates(path: &Path) -> impl Iterator<Item = PathBuf> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // Keep compound extensions like `.md.enc` together
+    let (stem, extension) = match name.find('.') {
+        Some(dot) if dot > 0 => name.split_at(dot),
+        _ => (name.as_str(), ""),
+    };
+    let (stem, extension) = (stem.to_string(), extension.to_string());
+
+    let numbered = path.to_path_buf();
+    iter::once(path.to_path_buf()).chain(
+        (2..).map(move |number| {
+            numbered.with_file_name(format!("{} {}{}", stem, number, extension))
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_path_numbers_taken_names() {
+        let path = Path::new("/notes/transcript.md.enc");
+        let taken = [
+            PathBuf::from("/notes/transcript.md.enc"),
+            PathBuf::from("/notes/transcript 2.md.enc"),
+        ];
+        assert_eq!(
+            free_path(path, |candidate| taken.iter().any(|t| t == candidate)),
+            PathBuf::from("/notes/transcript 3.md.enc")
+        );
+        assert_eq!(free_path(path, |_| false), path);
+    }
+
+    #[test]
+    fn test_replace_file_leaves_no_temporary_file() {
+        let dir = std::env::temp_dir().join(format!("vissper-atomic-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("transcript.md");
+
+        replace_file(&path, b"first").unwrap();
+        replace_file(&path, b"second").unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,174 @@
+//! iCloud Drive handling for the transcripts folder
+//!
+//! iCloud syncs files in the background, so writing to one directly can race
+//! with a sync and leave a half-written or corrupt file. Writes in iCloud
+//! Drive are coordinated with NSFileCoordinator and replace the file in one
+//! step. A file that iCloud holds with unresolved conflicting versions, or
+//! only as a placeholder that isn't downloaded yet, is never overwritten;
+//! the new contents are saved next to it under a numbered name.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use super::atomic::{free_path, replace_file};
+
+/// Where iCloud keeps synced folders, relative to the home folder
+const MOBILE_DOCUMENTS_DIR: &str = "Library/Mobile Documents";
+
+/// iCloud Drive's own folder inside [`MOBILE_DOCUMENTS_DIR`]
+const ICLOUD_DRIVE_CONTAINER: &str = "com~apple~CloudDocs";
+
+/// The iCloud Drive folder, if iCloud Drive is turned on
+pub(crate) fn icloud_drive_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+        .map(|home| home.join(MOBILE_DOCUMENTS_DIR).join(ICLOUD_DRIVE_CONTAINER))
+        .filter(|dir| dir.is_dir())
+}
+
+/// Whether `path` is synced by iCloud
+pub(crate) fn is_in_icloud(path: &Path) -> bool {
+    dirs::home_dir().is_some_and(|home| path.starts_with(home.join(MOBILE_DOCUMENTS_DIR)))
+}
+
+/// Write a file in iCloud Drive without racing iCloud's sync.
+///
+/// Returns the path written, which is numbered when `path` was in conflict.
+pub(super) fn write(path: &Path, contents: &[u8]) -> io::Result<PathBuf> {
+    let in_conflict = |path: &Path| {
+        placeholder_path(path).is_some_and(|placeholder| placeholder.exists())
+            || has_unresolved_conflicts(path)
+    };
+
+    let target = if in_conflict(path) {
+        let free = free_path(path, |candidate| {
+            candidate.exists() || in_conflict(candidate)
+        });
+        warn!(
+            "iCloud has another version of {:?}, saving as {:?}",
+            path.file_name().unwrap_or_default(),
+            free.file_name().unwrap_or_default()
+        );
+        free
+    } else {
+        path.to_path_buf()
+    };
+
+    coordinated_write(&target, contents)?;
+    Ok(target)
+}
+
+/// Placeholder iCloud keeps for a file that isn't downloaded: `.name.icloud`
+fn placeholder_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_string_lossy();
+    Some(path.with_file_name(format!(".{}.icloud", name)))
+}
+
+#[cfg(target_os = "macos")]
+mod coordination {
+    use block2::RcBlock;
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyClass, AnyObject};
+    use objc2::{msg_send, msg_send_id, ClassType};
+    use objc2_foundation::{NSString, NSURL};
+    use std::cell::RefCell;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    /// NSFileCoordinatorWritingForReplacing
+    const WRITING_FOR_REPLACING: usize = 1 << 3;
+
+    fn file_url(path: &Path) -> Retained<NSURL> {
+        let ns_path = NSString::from_str(&path.to_string_lossy());
+        // SAFETY: fileURLWithPath: returns a valid URL for any path string
+        unsafe { msg_send_id![NSURL::class(), fileURLWithPath: &*ns_path] }
+    }
+
+    /// Write through NSFileCoordinator so iCloud doesn't sync a partial file.
+    pub(super) fn coordinated_write(path: &Path, contents: &[u8]) -> io::Result<()> {
+        let Some(coordinator_class) = AnyClass::get("NSFileCoordinator") else {
+            return super::replace_file(path, contents);
+        };
+
+        let url = file_url(path);
+        let result: RefCell<Option<io::Result<()>>> = RefCell::new(None);
+        let accessor = RcBlock::new(|new_url: *mut NSURL| {
+            // Write where the coordinator says, falling back to the requested path
+            // SAFETY: new_url is a valid NSURL or nil
+            let target = unsafe { new_url.as_ref().and_then(|url| url.path()) }
+                .map(|path| PathBuf::from(path.to_string()))
+                .unwrap_or_else(|| path.to_path_buf());
+            *result.borrow_mut() = Some(super::replace_file(&target, contents));
+        });
+
+        // SAFETY: the coordinator is created and used on this thread, and the
+        // accessor block runs synchronously before coordinateWritingItemAtURL returns
+        unsafe {
+            let coordinator: Retained<AnyObject> = msg_send_id![coordinator_class, new];
+            let mut error: *mut AnyObject = std::ptr::null_mut();
+            let _: () = msg_send![
+                &coordinator,
+                coordinateWritingItemAtURL: &*url,
+                options: WRITING_FOR_REPLACING,
+                error: &mut error as *mut *mut AnyObject,
+                byAccessor: &*accessor
+            ];
+        }
+
+        result.into_inner().unwrap_or_else(|| {
+            Err(io::Error::other(
+                "iCloud file coordination refused the write",
+            ))
+        })
+    }
+
+    /// Whether iCloud has conflicting versions of `path` nobody has resolved
+    pub(super) fn has_unresolved_conflicts(path: &Path) -> bool {
+        if !path.exists() {
+            return false;
+        }
+        let Some(version_class) = AnyClass::get("NSFileVersion") else {
+            return false;
+        };
+
+        let url = file_url(path);
+        // SAFETY: unresolvedConflictVersionsOfItemAtURL: returns an NSArray or nil
+        unsafe {
+            let versions: *mut AnyObject =
+                msg_send![version_class, unresolvedConflictVersionsOfItemAtURL: &*url];
+            match versions.as_ref() {
+                Some(versions) => {
+                    let count: usize = msg_send![versions, count];
+                    count > 0
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+use coordination::{coordinated_write, has_unresolved_conflicts};
+
+#[cfg(not(target_os = "macos"))]
+fn coordinated_write(path: &Path, contents: &[u8]) -> io::Result<()> {
+    replace_file(path, contents)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn has_unresolved_conflicts(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_path() {
+        assert_eq!(
+            placeholder_path(Path::new("/notes/transcript.md")),
+            Some(PathBuf::from("/notes/.transcript.md.icloud"))
+        );
+    }
+}
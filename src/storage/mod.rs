@@ -0,0 +1,279 @@
+//! Local storage module for saving transcripts
+//!
+//! Handles saving transcripts to the user's Documents folder, iCloud Drive,
+//! or a custom location if configured in preferences. Files are replaced in
+//! one step so a crash mid-write can't leave a truncated transcript, and
+//! generated names are numbered rather than overwriting an earlier file.
+
+mod atomic;
+mod icloud;
+
+use crate::encryption;
+use crate::preferences;
+use crate::workspaces::Workspace;
+use chrono::Local;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+pub(crate) use icloud::icloud_drive_dir;
+
+/// Get the Vissper transcripts directory of the active workspace
+///
+/// Returns the Vissper folder in iCloud Drive if storing there is turned on,
+/// then the custom location from preferences if set,
+/// otherwise the default location in Documents.
+pub(crate) fn transcripts_dir() -> Option<PathBuf> {
+    transcripts_dir_in(preferences::get_active_workspace_settings().as_ref())
+}
+
+/// Get the transcripts directory of `workspace`, or of Default when None
+pub(crate) fn transcripts_dir_in(workspace: Option<&Workspace>) -> Option<PathBuf> {
+    if preferences::get_store_in_icloud() {
+        match icloud_drive_dir() {
+            Some(dir) => {
+                return Some(match workspace {
+                    Some(workspace) => dir.join(workspace.subfolder("transcripts")),
+                    None => dir.join("Vissper").join("transcripts"),
+                })
+            }
+            None => warn!("iCloud Drive is not available, using the local transcripts folder"),
+        }
+    }
+    // Check for custom location in preferences first
+    if let Some(custom) = preferences::get_transcript_location_in(workspace) {
+        return Some(custom);
+    }
+    // Fall back to default location
+    dirs::document_dir().map(|d| d.join("Vissper").join("transcripts"))
+}
+
+/// Ensure the transcripts directory exists
+pub(crate) fn ensure_transcripts_dir() -> Result<PathBuf, StorageError> {
+    let dir = transcripts_dir().ok_or(StorageError::NoDocumentsDir)?;
+
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| StorageError::CreateDirectory {
+            path: dir.clone(),
+            source: e,
+        })?;
+        info!("Created transcripts directory: {:?}", dir);
+    }
+
+    Ok(dir)
+}
+
+/// Write a transcript or export to `path`.
+///
+/// The file is replaced in one step, so readers never see it half-written.
+/// Files in iCloud Drive are written in coordination with iCloud's sync, and
+/// are saved under a numbered name if iCloud holds another version. Returns
+/// the path actually written.
+pub(crate) fn write_file(path: &Path, contents: &[u8]) -> Result<PathBuf, StorageError> {
+    let written = if icloud::is_in_icloud(path) {
+        icloud::write(path, contents)
+    } else {
+        atomic::replace_file(path, contents).map(|()| path.to_path_buf())
+    };
+    written.map_err(|e| StorageError::WriteFile {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// A path in `dir` for a new file named `name` that doesn't replace another.
+///
+/// Numbered as `name 2.md`, `name 3.md`… when `name`, or its encrypted copy,
+/// already exists, e.g. when saving twice within a second. Only a suggestion,
+/// since another save may take the name before it's written; use
+/// [`write_new_file`] to save under it.
+pub(crate) fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    atomic::free_path(&dir.join(name), is_taken)
+}
+
+/// Write a new file named `name` in `dir` without replacing another.
+///
+/// Numbered like [`unique_path`], but the name is claimed before writing, so
+/// saves racing for the same name get different files. Returns the path
+/// actually written.
+pub(crate) fn write_new_file(
+    dir: &Path,
+    name: &str,
+    contents: &[u8],
+) -> Result<PathBuf, StorageError> {
+    let path = dir.join(name);
+    let reserved = atomic::reserve_path(&path, is_taken).map_err(|e| StorageError::WriteFile {
+        path: path.clone(),
+        source: e,
+    })?;
+
+    let written = write_file(&reserved, contents);
+    // iCloud may have saved under another name, leaving the claimed one empty
+    if written.as_ref().ok() != Some(&reserved) {
+        let _ = fs::remove_file(&reserved);
+    }
+    written
+}
+
+/// Whether `path`, or its plaintext or encrypted copy, exists
+fn is_taken(path: &Path) -> bool {
+    let plaintext = encryption::plaintext_path(path);
+    plaintext.exists() || encryption::encrypted_path(&plaintext).exists()
+}
+
+/// Name of a transcript saved without asking, from the current time
+pub(crate) fn timestamped_name(prefix: &str, extension: &str) -> String {
+    format!(
+        "{}-{}.{}",
+        prefix,
+        Local::now().format("%Y-%m-%d-%H-%M-%S"),
+        extension
+    )
+}
+
+/// Save a transcript to a file
+///
+/// Returns the path to the saved file
+#[allow(dead_code)]
+pub(crate) fn save_transcript(transcript: &str) -> Result<PathBuf, StorageError> {
+    if transcript.trim().is_empty() {
+        return Err(StorageError::EmptyTranscript);
+    }
+
+    let dir = ensure_transcripts_dir()?;
+    let filepath = write_new_file(
+        &dir,
+        &timestamped_name("transcript", "md"),
+        transcript.as_bytes(),
+    )?;
+
+    info!("Saved transcript to: {:?}", filepath);
+    Ok(filepath)
+}
+
+/// Storage errors with contextual information
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StorageError {
+    #[error("Could not find Documents directory")]
+    NoDocumentsDir,
+
+    #[error("Transcript is empty")]
+    EmptyTranscript,
+
+    #[error("Failed to create directory {path}: {source}")]
+    CreateDirectory {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to write to file {path}: {source}")]
+    WriteFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl StorageError {
+    /// What went wrong and what to do about it, for an alert
+    pub(crate) fn user_message(&self) -> String {
+        let (path, source) = match self {
+            StorageError::NoDocumentsDir => {
+                return "Vissper could not find your Documents folder. Choose a transcript \
+                        location in Settings → Storage."
+                    .to_string()
+            }
+            StorageError::EmptyTranscript => return "There is no transcript to save.".to_string(),
+            StorageError::CreateDirectory { path, source } => (path, source),
+            StorageError::WriteFile { path, source } => (path, source),
+        };
+        let folder = path.parent().unwrap_or(path).display();
+        match source.kind() {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => format!(
+                "Vissper is not allowed to write to {}. Choose another folder, or give \
+                 Vissper access in System Settings → Privacy & Security → Files and Folders.",
+                folder
+            ),
+            io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => format!(
+                "There is not enough space to save in {}. Free up space and try again.",
+                folder
+            ),
+            io::ErrorKind::NotFound => format!(
+                "The folder {} no longer exists. It may be on a disconnected drive.",
+                folder
+            ),
+            _ => format!("The file could not be saved in {}: {}", folder, source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preferences;
+
+    #[test]
+    fn test_default_transcripts_dir() {
+        // Test the default location (not affected by user preferences)
+        let dir = preferences::default_transcript_location();
+        assert!(dir.is_some());
+        let path = dir.unwrap();
+        assert!(path.ends_with("Vissper/transcripts"));
+    }
+
+    #[test]
+    fn test_unique_path_skips_existing_files() {
+        let dir = std::env::temp_dir().join(format!("vissper-unique-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("transcript.md"), "").unwrap();
+        fs::write(dir.join("transcript 2.md.enc"), "").unwrap();
+
+        assert_eq!(
+            unique_path(&dir, "transcript.md"),
+            dir.join("transcript 3.md")
+        );
+        assert_eq!(unique_path(&dir, "notes.md"), dir.join("notes.md"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_saves_get_their_own_files() {
+        let dir =
+            std::env::temp_dir().join(format!("vissper-new-file-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let barrier = std::sync::Barrier::new(2);
+        let saved: Vec<PathBuf> = std::thread::scope(|scope| {
+            let saves: Vec<_> = [b"first", b"other"]
+                .into_iter()
+                .map(|contents| {
+                    let (dir, barrier) = (&dir, &barrier);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        write_new_file(dir, "transcript.md", contents).unwrap()
+                    })
+                })
+                .collect();
+            saves.into_iter().map(|save| save.join().unwrap()).collect()
+        });
+
+        assert_ne!(saved[0], saved[1]);
+        assert_eq!(fs::read(&saved[0]).unwrap(), b"first");
+        assert_eq!(fs::read(&saved[1]).unwrap(), b"other");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_user_message_explains_the_cause() {
+        let error = StorageError::WriteFile {
+            path: PathBuf::from("/Volumes/Backup/transcript.md"),
+            source: io::Error::from(io::ErrorKind::PermissionDenied),
+        };
+        assert!(error
+            .user_message()
+            .contains("not allowed to write to /Volumes/Backup"));
+    }
+}
@@ -0,0 +1,311 @@
+//! Practice mode that follows a script as it is read aloud
+//!
+//! After loading a script from the menu bar, the Live tab shows the script
+//! instead of the transcript, with the part already spoken dimmed and the
+//! next word highlighted. Each committed segment is aligned against the
+//! script with a short lookahead, so skipped words, ad-libs and small
+//! transcription errors don't lose the place.
+//!
+//! The transcript is still recorded as usual and appears again in the Live
+//! tab when the script is closed. The script is kept in memory only.
+
+use objc2::rc::Retained;
+use objc2_app_kit::NSOpenPanel;
+use objc2_foundation::{MainThreadMarker, NSArray, NSString};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing::{error, info};
+
+use crate::event_bus::AppEvent;
+use crate::settings_window::constants::NS_MODAL_RESPONSE_OK;
+use crate::transcription_window::TranscriptionWindow;
+
+/// How many script words ahead a spoken word may match
+const LOOKAHEAD: usize = 12;
+
+/// Lookahead for short words like "a" or "the", which match almost anywhere
+const SHORT_WORD_LOOKAHEAD: usize = 2;
+
+/// Words up to this many characters use the short lookahead
+const SHORT_WORD_LEN: usize = 3;
+
+static SCRIPT: Mutex<Option<Script>> = Mutex::new(None);
+
+/// A script word and where it ends in the script text
+#[derive(Debug, Clone)]
+struct ScriptWord {
+    normalized: String,
+    start: usize,
+    end: usize,
+}
+
+/// A loaded script and how far through it the speaker is
+#[derive(Debug, Clone)]
+pub(crate) struct Script {
+    text: String,
+    words: Vec<ScriptWord>,
+    /// Index of the next script word to be spoken
+    position: usize,
+}
+
+impl Script {
+    pub(crate) fn new(text: String) -> Self {
+        let mut words = Vec::new();
+        let mut offset = 0;
+        for word in text.split_whitespace() {
+            // split_whitespace yields subslices in order, so find from the last end
+            let start = offset + text[offset..].find(word).unwrap_or(0);
+            let end = start + word.len();
+            offset = end;
+            let normalized = normalize(word);
+            if !normalized.is_empty() {
+                words.push(ScriptWord {
+                    normalized,
+                    start,
+                    end,
+                });
+            }
+        }
+
+        Self {
+            text,
+            words,
+            position: 0,
+        }
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Byte offset in the script where the spoken part ends
+    pub(crate) fn spoken_len(&self) -> usize {
+        match self.position {
+            0 => 0,
+            n => self.words[n - 1].end,
+        }
+    }
+
+    /// Byte range of the next word to be spoken, if any
+    pub(crate) fn next_word(&self) -> Option<(usize, usize)> {
+        self.words.get(self.position).map(|w| (w.start, w.end))
+    }
+
+    /// Start over from the beginning of the script.
+    pub(crate) fn restart(&mut self) {
+        self.position = 0;
+    }
+
+    /// Move past the script words matched by a committed segment.
+    ///
+    /// Each spoken word is looked for a few words ahead of the current
+    /// position; words that don't match anything nearby are ignored.
+    pub(crate) fn advance(&mut self, spoken: &str) {
+        for word in spoken.split_whitespace().map(normalize) {
+            if word.is_empty() {
+                continue;
+            }
+            let lookahead = if word.chars().count() <= SHORT_WORD_LEN {
+                SHORT_WORD_LOOKAHEAD
+            } else {
+                LOOKAHEAD
+            };
+            let end = (self.position + lookahead).min(self.words.len());
+            if let Some(offset) = self.words[self.position..end]
+                .iter()
+                .position(|w| words_match(&w.normalized, &word))
+            {
+                self.position += offset + 1;
+            }
+        }
+    }
+}
+
+/// Whether a script is loaded
+pub(crate) fn is_active() -> bool {
+    SCRIPT.lock().map(|s| s.is_some()).unwrap_or(false)
+}
+
+/// Let the user pick a script file and start following it.
+///
+/// Must be called on the main thread.
+pub(crate) fn choose_script() {
+    let Some(path) = choose_script_file() else {
+        return;
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(text) => load(text),
+        Err(e) => error!("Failed to read script {}: {}", path.display(), e),
+    }
+}
+
+/// Start following `text` and show it in the overlay.
+pub(crate) fn load(text: String) {
+    let script = Script::new(text);
+    info!(words = script.words.len(), "Loaded practice script");
+    render(&script);
+    if let Ok(mut current) = SCRIPT.lock() {
+        *current = Some(script);
+    }
+    TranscriptionWindow::show();
+}
+
+/// Stop following the script and show the transcript again.
+pub(crate) fn clear() {
+    if let Ok(mut current) = SCRIPT.lock() {
+        *current = None;
+    }
+    TranscriptionWindow::clear_script();
+    info!("Closed practice script");
+}
+
+/// Follow the transcript as it is committed.
+pub(crate) fn handle_event(event: &AppEvent) {
+    let Ok(mut current) = SCRIPT.lock() else {
+        return;
+    };
+    let Some(script) = current.as_mut() else {
+        return;
+    };
+
+    match event {
+        AppEvent::RecordingStarted => script.restart(),
+        AppEvent::SegmentCommitted { text } => script.advance(text),
+        _ => return,
+    }
+    render(script);
+}
+
+fn render(script: &Script) {
+    TranscriptionWindow::show_script(script.text(), script.spoken_len(), script.next_word());
+}
+
+/// Lowercase a word and strip surrounding punctuation
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Whether a spoken word matches a script word, allowing small differences
+/// in longer words (transcription errors, inflections)
+fn words_match(script_word: &str, spoken: &str) -> bool {
+    if script_word == spoken {
+        return true;
+    }
+    let len = script_word.chars().count().min(spoken.chars().count());
+    len > SHORT_WORD_LEN && edit_distance(script_word, spoken) <= len / 4
+}
+
+/// Levenshtein distance between two words
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Show an open panel for plain text and Markdown scripts.
+fn choose_script_file() -> Option<PathBuf> {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Not on main thread, cannot show open panel");
+        return None;
+    };
+
+    // SAFETY: NSOpenPanel::openPanel is safe to call on main thread
+    let panel = unsafe { NSOpenPanel::openPanel(mtm) };
+
+    // SAFETY: These are standard NSOpenPanel configuration calls
+    unsafe {
+        panel.setCanChooseFiles(true);
+        panel.setCanChooseDirectories(false);
+        panel.setAllowsMultipleSelection(false);
+        panel.setMessage(Some(&NSString::from_str("Choose a script to practice")));
+        panel.setPrompt(Some(&NSString::from_str("Practice")));
+
+        let extensions: Retained<NSArray<NSString>> =
+            NSArray::from_id_slice(&[NSString::from_str("txt"), NSString::from_str("md")]);
+        #[allow(deprecated)]
+        panel.setAllowedFileTypes(Some(&extensions));
+    }
+
+    // SAFETY: runModal blocks until user dismisses the panel
+    let response = unsafe { panel.runModal() };
+    if response != NS_MODAL_RESPONSE_OK {
+        return None;
+    }
+
+    // SAFETY: URLs() returns a valid NSArray after successful modal
+    let urls = unsafe { panel.URLs() };
+    let url = urls.first()?;
+    // SAFETY: path() returns the file system path from a file URL
+    unsafe { url.path() }.map(|p| PathBuf::from(p.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = "Good morning, everyone. Today I want to talk about our roadmap \
+                          for the next quarter and the three projects we are prioritizing.";
+
+    fn spoken_prefix(script: &Script) -> &str {
+        &script.text()[..script.spoken_len()]
+    }
+
+    #[test]
+    fn test_follows_exact_reading() {
+        let mut script = Script::new(SCRIPT.to_string());
+        assert_eq!(script.spoken_len(), 0);
+
+        script.advance("Good morning everyone.");
+        assert_eq!(spoken_prefix(&script), "Good morning, everyone.");
+
+        script.advance("Today I want to talk about our roadmap");
+        assert!(spoken_prefix(&script).ends_with("our roadmap"));
+        assert_eq!(script.next_word().map(|(s, e)| &SCRIPT[s..e]), Some("for"));
+    }
+
+    #[test]
+    fn test_tolerates_skips_ad_libs_and_typos() {
+        let mut script = Script::new(SCRIPT.to_string());
+
+        // Skipped "Good", ad-libbed "uh, so", misrecognized "roadmap"
+        script.advance("morning everyone, uh, so today I want to talk about our road map");
+        assert!(spoken_prefix(&script).ends_with("talk about our"));
+
+        script.advance("roadmaps for the next quarter");
+        assert!(spoken_prefix(&script).ends_with("next quarter"));
+    }
+
+    #[test]
+    fn test_common_words_do_not_jump_ahead() {
+        let mut script = Script::new(SCRIPT.to_string());
+
+        // "the" appears later in the script but is too far ahead to match
+        script.advance("the");
+        assert_eq!(script.spoken_len(), 0);
+    }
+
+    #[test]
+    fn test_restart() {
+        let mut script = Script::new(SCRIPT.to_string());
+        script.advance("Good morning everyone");
+        script.restart();
+        assert_eq!(script.spoken_len(), 0);
+        assert_eq!(script.next_word().map(|(s, e)| &SCRIPT[s..e]), Some("Good"));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("roadmap", "roadmap"), 0);
+        assert_eq!(edit_distance("roadmap", "roadmaps"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
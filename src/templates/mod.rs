@@ -0,0 +1,206 @@
+//! Recording templates: one-click presets for common scenarios
+//!
+//! A template such as "Client call – Norwegian – meeting notes to Obsidian"
+//! combines a transcription language and provider, the output mode to stop
+//! with, and whether and where to save the result. Templates are edited as
+//! text in the Workspaces tab of Settings, like the workspaces, and picked
+//! from the menu bar's Templates submenu, which applies the language and
+//! provider and starts a recording.
+//!
+//! The mode and saving only apply to the recording the template started:
+//! stopping it with the recording hotkey stops with the template's mode, and
+//! its result is saved without asking if the template says so.
+
+mod session;
+
+pub(crate) use session::{apply, auto_save, recording_started, stop_mode};
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::languages::{self, LANGUAGES};
+use crate::output_modes;
+use crate::preferences::AiProvider;
+use crate::transcription_window::TabType;
+use crate::workspaces::{format_path, parse_path, parse_provider, provider_key};
+
+/// Most templates in the menu, to keep it short
+pub(crate) const MAX_TEMPLATES: usize = 10;
+
+/// A template and the settings it applies (None = leave as is)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct Template {
+    pub name: String,
+    /// Transcription language code, e.g. "no"
+    pub language: Option<String>,
+    /// Provider for transcription
+    pub ai_provider: Option<AiProvider>,
+    /// Output mode to stop with, as written (None = no polishing)
+    pub mode: Option<String>,
+    /// Save the result without asking once it is ready
+    pub auto_save: bool,
+    /// Folder the result is saved to (None = the transcripts folder)
+    pub destination: Option<PathBuf>,
+}
+
+impl Template {
+    /// Tab of the output mode the recording stops with, if the mode exists
+    pub(crate) fn mode_tab(&self) -> Option<TabType> {
+        let Some(mode) = self.mode.as_deref() else {
+            return Some(TabType::Live);
+        };
+        let mode = mode.to_lowercase();
+        match mode.as_str() {
+            "none" | "raw" | "no polishing" => Some(TabType::Live),
+            "polish" | "basic" | "basic polishing" => Some(TabType::BasicPolish),
+            "notes" | "meeting notes" => Some(TabType::MeetingNotes),
+            "email" | "follow-up email" => Some(TabType::FollowUpEmail),
+            _ => output_modes::custom_modes()
+                .iter()
+                .position(|custom| custom.name.to_lowercase() == mode)
+                .map(TabType::Custom),
+        }
+    }
+}
+
+/// Setting lines of a template in the list edited in Settings
+const LANGUAGE_KEY: &str = "language";
+const TRANSCRIPTION_KEY: &str = "transcription";
+const MODE_KEY: &str = "mode";
+const AUTO_SAVE_KEY: &str = "auto save";
+const SAVE_TO_KEY: &str = "save to";
+
+/// Parse the templates list as edited in Settings.
+///
+/// Each template starts with a `## Name` line, followed by lines such as
+/// `language: no`, `transcription: openai`, `mode: meeting notes`,
+/// `auto save: yes` or `save to: ~/Obsidian/Clients`; other lines are
+/// ignored. Templates without a name or named like an earlier one are
+/// dropped, and only the first `MAX_TEMPLATES` are kept.
+pub(crate) fn parse(text: &str) -> Vec<Template> {
+    let mut templates: Vec<Template> = Vec::new();
+
+    for line in text.lines() {
+        if let Some(name) = line.trim().strip_prefix("## ") {
+            templates.push(Template {
+                name: name.trim().to_string(),
+                ..Template::default()
+            });
+        } else if let Some(template) = templates.last_mut() {
+            apply_setting(template, line);
+        }
+    }
+
+    let mut valid: Vec<Template> = Vec::new();
+    for template in templates {
+        let name = template.name.to_lowercase();
+        if name.is_empty() || valid.iter().any(|other| other.name.to_lowercase() == name) {
+            continue;
+        }
+        valid.push(template);
+    }
+    valid.truncate(MAX_TEMPLATES);
+    valid
+}
+
+/// Apply a `key: value` setting line; other lines are ignored
+fn apply_setting(template: &mut Template, line: &str) {
+    let Some((key, value)) = line.split_once(':') else {
+        return;
+    };
+    let value = value.trim();
+    match key.trim().to_lowercase().as_str() {
+        LANGUAGE_KEY => template.language = parse_language(value),
+        TRANSCRIPTION_KEY => template.ai_provider = parse_provider(value),
+        MODE_KEY => template.mode = (!value.is_empty()).then(|| value.to_string()),
+        AUTO_SAVE_KEY => {
+            template.auto_save = matches!(value.to_lowercase().as_str(), "yes" | "on" | "true")
+        }
+        SAVE_TO_KEY => template.destination = parse_path(value),
+        _ => {}
+    }
+}
+
+/// Code of a language given by its code or its English or own name
+fn parse_language(value: &str) -> Option<String> {
+    let value = value.to_lowercase();
+    languages::find(&value)
+        .or_else(|| {
+            LANGUAGES.iter().find(|language| {
+                language.name.to_lowercase() == value
+                    || language.native_name.to_lowercase() == value
+            })
+        })
+        .map(|language| language.code.to_string())
+}
+
+/// Format templates for editing, separated by blank lines.
+pub(crate) fn format(templates: &[Template]) -> String {
+    templates
+        .iter()
+        .map(|template| {
+            let mut lines = vec![format!("## {}", template.name)];
+            if let Some(code) = &template.language {
+                lines.push(format!("{}: {}", LANGUAGE_KEY, code));
+            }
+            if let Some(provider) = template.ai_provider {
+                lines.push(format!("{}: {}", TRANSCRIPTION_KEY, provider_key(provider)));
+            }
+            if let Some(mode) = &template.mode {
+                lines.push(format!("{}: {}", MODE_KEY, mode));
+            }
+            if template.auto_save {
+                lines.push(format!("{}: yes", AUTO_SAVE_KEY));
+            }
+            if let Some(path) = &template.destination {
+                lines.push(format!("{}: {}", SAVE_TO_KEY, format_path(path)));
+            }
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST: &str = "## Client call – Norwegian – meeting notes to Obsidian
+language: Norwegian
+transcription: openai
+mode: Meeting notes
+auto save: yes
+save to: /Obsidian/Clients
+
+## Stand-up
+language: xx
+Ignored, templates have no prompt.
+
+## stand-up
+Dropped as a duplicate.";
+
+    #[test]
+    fn test_parse_reads_settings() {
+        let templates = parse(LIST);
+        assert_eq!(templates.len(), 2);
+
+        let client = &templates[0];
+        assert_eq!(client.language.as_deref(), Some("no"));
+        assert_eq!(client.ai_provider, Some(AiProvider::OpenAI));
+        assert_eq!(client.mode_tab(), Some(TabType::MeetingNotes));
+        assert!(client.auto_save);
+        assert_eq!(client.destination, Some(PathBuf::from("/Obsidian/Clients")));
+
+        let standup = &templates[1];
+        assert_eq!(standup.language, None);
+        assert_eq!(standup.mode_tab(), Some(TabType::Live));
+        assert!(!standup.auto_save);
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        let templates = parse(LIST);
+        assert_eq!(parse(&format(&templates)), templates);
+    }
+}
@@ -0,0 +1,134 @@
+//! The template of the recording in progress
+//!
+//! Picking a template arms it, and the next recording to start takes it
+//! over, so a recording started any other way runs without one.
+
+use chrono::Local;
+use std::fs;
+use std::sync::{Mutex, PoisonError};
+use tracing::{error, info, warn};
+
+use super::Template;
+use crate::preferences::{self, PreferencesError};
+use crate::transcription_window::TabType;
+use crate::{
+    attendees, consent, encryption, git_archive, google_drive, output_modes, private_mode,
+    provenance, storage, topics,
+};
+
+/// The template picked for the next recording
+static ARMED: Mutex<Option<Template>> = Mutex::new(None);
+
+/// The template of the current or last recording
+static ACTIVE: Mutex<Option<Template>> = Mutex::new(None);
+
+/// Apply a template's language and provider and arm it for the next recording
+pub(crate) fn apply(template: &Template) -> Result<(), PreferencesError> {
+    if let Some(code) = &template.language {
+        preferences::set_language_code(code)?;
+    }
+    if let Some(provider) = template.ai_provider {
+        preferences::set_ai_provider(provider)?;
+    }
+    *ARMED.lock().unwrap_or_else(PoisonError::into_inner) = Some(template.clone());
+    Ok(())
+}
+
+/// A recording started: it runs with the armed template, if any
+pub(crate) fn recording_started() {
+    let template = ARMED.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(template) = &template {
+        info!("Recording with template {}", template.name);
+    }
+    *ACTIVE.lock().unwrap_or_else(PoisonError::into_inner) = template;
+}
+
+fn active() -> Option<Template> {
+    ACTIVE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// The mode to stop the recording with when none is chosen: the template's,
+/// or no polishing
+pub(crate) fn stop_mode() -> TabType {
+    let Some(template) = active() else {
+        return TabType::Live;
+    };
+    template.mode_tab().unwrap_or_else(|| {
+        warn!(
+            "Template {} has an unknown mode, stopping without polishing",
+            template.name
+        );
+        TabType::Live
+    })
+}
+
+/// Save a recording's result from `tab` without asking, if its template
+/// says so
+///
+/// Saved as Markdown to the template's folder or the transcripts folder,
+/// and uploaded or archived like a transcript saved with the Save button.
+pub(crate) fn auto_save(tab: TabType, content: &str) {
+    let Some(template) = active().filter(|template| template.auto_save) else {
+        return;
+    };
+    if private_mode::is_active() {
+        info!("Private session: result not saved automatically");
+        return;
+    }
+    if content.trim().is_empty() {
+        return;
+    }
+
+    let dir = match template.destination {
+        Some(dir) => fs::create_dir_all(&dir)
+            .map(|()| dir)
+            .map_err(|e| e.to_string()),
+        None => storage::ensure_transcripts_dir().map_err(|e| e.to_string()),
+    };
+    let dir = match dir {
+        Ok(dir) => dir,
+        Err(e) => {
+            error!("Failed to save the result automatically: {}", e);
+            return;
+        }
+    };
+
+    let now = Local::now();
+    let mut provenance = provenance::current();
+    if tab == TabType::Live {
+        // The raw transcript, whatever was last polished
+        provenance.polish = None;
+    }
+    let contents = format!(
+        "{}{}",
+        provenance.front_matter(),
+        topics::link_contents(&consent::add_to_export(
+            tab,
+            &attendees::add_to_export(tab, content),
+            now,
+        ))
+    );
+    let name = storage::timestamped_name("transcript", "md");
+    match encryption::write_new_file(&dir, &name, contents.as_bytes()) {
+        Ok(saved_path) => {
+            info!("Saved result automatically to {:?}", saved_path);
+            google_drive::upload_saved_transcript(
+                &encryption::plaintext_path(&saved_path),
+                contents.into_bytes(),
+            );
+            git_archive::archive_transcript(
+                saved_path,
+                git_archive::CommitDetails {
+                    saved_at: now,
+                    mode: output_modes::title(tab),
+                    words: content.split_whitespace().count(),
+                    provenance,
+                },
+            );
+        }
+        Err(e) => error!("Failed to save the result automatically: {}", e),
+    }
+}
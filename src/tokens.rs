@@ -0,0 +1,279 @@
+//! Token count estimation for polish requests
+//!
+//! Provides a cheap, dependency-free approximation of how many tokens a
+//! transcript will consume, modelled on tiktoken's behaviour for the
+//! `o200k`/`cl100k` encodings: roughly four ASCII characters per token,
+//! fewer characters per token for accented Latin text, and about one token
+//! per CJK character.
+//!
+//! The estimate intentionally errs on the high side so that the pre-flight
+//! check warns before the provider rejects a request.
+
+use crate::preferences::AiProvider;
+
+/// Context window assumed for Azure deployments.
+///
+/// The deployment name does not tell us the underlying model, so use the
+/// window of the smallest model we expect users to deploy for polishing (gpt-4o).
+pub(crate) const AZURE_CONTEXT_WINDOW_TOKENS: usize = 128_000;
+
+/// Context window for the OpenAI polish model (gpt-5.2).
+pub(crate) const OPENAI_CONTEXT_WINDOW_TOKENS: usize = 400_000;
+
+/// Tokens reserved for the model's response.
+///
+/// Polishing returns text of roughly the same length as its input, so a
+/// generous share of the window is kept free for output.
+pub(crate) const OUTPUT_RESERVE_TOKENS: usize = 32_000;
+
+/// Tokens reserved for the system prompt and message framing.
+pub(crate) const PROMPT_OVERHEAD_TOKENS: usize = 1_500;
+
+/// Estimate the number of tokens in `text`.
+pub(crate) fn estimate_tokens(text: &str) -> usize {
+    let mut ascii_chars = 0usize;
+    let mut other_chars = 0usize;
+    let mut cjk_chars = 0usize;
+    let mut words = 0usize;
+    let mut in_word = false;
+
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            in_word = false;
+            continue;
+        }
+        if !in_word {
+            words += 1;
+            in_word = true;
+        }
+        if ch.is_ascii() {
+            ascii_chars += 1;
+        } else if is_cjk(ch) {
+            cjk_chars += 1;
+        } else {
+            other_chars += 1;
+        }
+    }
+
+    let by_chars = ascii_chars.div_ceil(4) + other_chars.div_ceil(2) + cjk_chars;
+    let by_words = (words * 4).div_ceil(3);
+    by_chars.max(by_words)
+}
+
+/// Check whether a character belongs to a CJK block (roughly one token each).
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x3040..=0x30FF   // Hiragana, Katakana
+            | 0x3400..=0x4DBF // CJK Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xAC00..=0xD7AF // Hangul Syllables
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+    )
+}
+
+/// Context window of the polish model used by the given provider.
+pub(crate) fn context_window(provider: AiProvider) -> usize {
+    match provider {
+        AiProvider::Azure => AZURE_CONTEXT_WINDOW_TOKENS,
+        AiProvider::OpenAI => OPENAI_CONTEXT_WINDOW_TOKENS,
+    }
+}
+
+/// Maximum number of transcript tokens that fit in a single polish request.
+pub(crate) fn transcript_budget(provider: AiProvider) -> usize {
+    context_window(provider).saturating_sub(OUTPUT_RESERVE_TOKENS + PROMPT_OVERHEAD_TOKENS)
+}
+
+/// Result of the pre-flight token check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Preflight {
+    /// The transcript fits in a single request.
+    Fits { estimated: usize },
+    /// The transcript is estimated to exceed the available budget.
+    ExceedsContext { estimated: usize, budget: usize },
+}
+
+/// Run the pre-flight check for a transcript against a token budget.
+pub(crate) fn preflight(text: &str, budget: usize) -> Preflight {
+    let estimated = estimate_tokens(text);
+    if estimated > budget {
+        Preflight::ExceedsContext { estimated, budget }
+    } else {
+        Preflight::Fits { estimated }
+    }
+}
+
+/// Truncate `text` so its estimated token count fits within `max_tokens`.
+///
+/// Cuts at a whitespace boundary and keeps the beginning of the transcript.
+pub(crate) fn truncate_to_tokens(text: &str, max_tokens: usize) -> &str {
+    if estimate_tokens(text) <= max_tokens {
+        return text;
+    }
+
+    let mut end = text.len();
+    while end > 0 {
+        // Shrink proportionally to the overshoot, then back up to whitespace
+        let estimated = estimate_tokens(&text[..end]).max(1);
+        if estimated <= max_tokens {
+            break;
+        }
+        let target = (end as u128 * max_tokens as u128 / estimated as u128) as usize;
+        let mut cut = target.min(end - 1);
+        while cut > 0 && !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        end = text[..cut].rfind(char::is_whitespace).unwrap_or(cut);
+    }
+
+    text[..end].trim_end()
+}
+
+/// Split `text` into chunks whose estimated token count fits within `max_tokens`.
+///
+/// Chunks break at sentence or line boundaries where possible, falling back
+/// to word boundaries for very long sentences. Concatenating the chunks
+/// reproduces the original text.
+pub(crate) fn split_into_chunks(text: &str, max_tokens: usize) -> Vec<String> {
+    let max_tokens = max_tokens.max(1);
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    // Sum of the pieces' estimates, never less than the estimate of `current`
+    let mut current_tokens = 0;
+
+    for unit in sentence_units(text) {
+        let pieces = if estimate_tokens(unit) > max_tokens {
+            unit.split_inclusive(char::is_whitespace)
+                .collect::<Vec<_>>()
+        } else {
+            vec![unit]
+        };
+
+        for piece in pieces {
+            let piece_tokens = estimate_tokens(piece);
+            if !current.is_empty() && current_tokens + piece_tokens > max_tokens {
+                chunks.push(std::mem::take(&mut current));
+                current_tokens = 0;
+            }
+            current.push_str(piece);
+            current_tokens += piece_tokens;
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// Split text after sentence-ending punctuation followed by whitespace, or after newlines.
+fn sentence_units(text: &str) -> Vec<&str> {
+    let mut units = Vec::new();
+    let mut start = 0;
+    let mut prev_terminal = false;
+
+    for (idx, ch) in text.char_indices() {
+        if ch == '\n' {
+            let end = idx + ch.len_utf8();
+            units.push(&text[start..end]);
+            start = end;
+            prev_terminal = false;
+            continue;
+        }
+        if prev_terminal && ch.is_whitespace() {
+            let end = idx + ch.len_utf8();
+            units.push(&text[start..end]);
+            start = end;
+        }
+        prev_terminal = matches!(ch, '.' | '!' | '?');
+    }
+
+    if start < text.len() {
+        units.push(&text[start..]);
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_tokens_empty() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("   \n "), 0);
+    }
+
+    #[test]
+    fn test_estimate_tokens_english() {
+        // tiktoken counts 10 tokens for this sentence
+        let estimate = estimate_tokens("The quick brown fox jumps over the lazy dog.");
+        assert!((10..=16).contains(&estimate), "estimate was {}", estimate);
+    }
+
+    #[test]
+    fn test_estimate_tokens_cjk_counts_per_character() {
+        assert!(estimate_tokens("会議の議事録") >= 6);
+    }
+
+    #[test]
+    fn test_preflight() {
+        assert_eq!(
+            preflight("hello world", 100),
+            Preflight::Fits { estimated: 3 }
+        );
+        assert!(matches!(
+            preflight(&"word ".repeat(1000), 100),
+            Preflight::ExceedsContext { budget: 100, .. }
+        ));
+    }
+
+    #[test]
+    fn test_transcript_budget_leaves_room_for_output() {
+        assert!(transcript_budget(AiProvider::Azure) < AZURE_CONTEXT_WINDOW_TOKENS);
+        assert!(transcript_budget(AiProvider::OpenAI) > transcript_budget(AiProvider::Azure));
+    }
+
+    #[test]
+    fn test_truncate_to_tokens() {
+        let text = "alpha beta gamma delta ".repeat(200);
+        let truncated = truncate_to_tokens(&text, 50);
+        assert!(estimate_tokens(truncated) <= 50);
+        assert!(text.starts_with(truncated));
+        assert!(!truncated.is_empty());
+
+        assert_eq!(truncate_to_tokens("short text", 50), "short text");
+    }
+
+    #[test]
+    fn test_split_into_chunks_preserves_text() {
+        let text = "First sentence here. Second one follows!\nThird line? Yes. ".repeat(50);
+        let chunks = split_into_chunks(&text, 40);
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), text);
+        for chunk in &chunks {
+            assert!(estimate_tokens(chunk) <= 40);
+        }
+    }
+
+    #[test]
+    fn test_split_into_chunks_long_sentence_falls_back_to_words() {
+        let text = "word ".repeat(300);
+        let chunks = split_into_chunks(&text, 30);
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), text);
+    }
+
+    #[test]
+    fn test_sentence_units_keep_screenshot_references_intact() {
+        let text = "Look here. ![Screenshot](screenshots/a.png) done.";
+        let units = sentence_units(text);
+        assert_eq!(
+            units,
+            vec!["Look here. ", "![Screenshot](screenshots/a.png) done."]
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Topic sections in meeting notes
+//!
+//! With "Split meeting notes into topics" turned on in Settings, the meeting
+//! notes prompt also asks for the polished transcript to be divided into
+//! topic sections headed `### [12:34] Title`, listed in a `## Contents`
+//! section above it. To date each topic, the transcript sent for polishing
+//! carries time cues like `[@12:34]` (time since the recording started);
+//! they are stripped wherever the transcript itself is shown.
+//!
+//! Contents entries link to their sections: in the Meeting Notes tab a click
+//! scrolls to the heading, and Markdown exports get anchor links.
+
+use std::time::Duration;
+
+use crate::preferences;
+use crate::transcription::TranscriptionSession;
+
+/// Heading of the table of contents section
+pub(crate) const CONTENTS_HEADING: &str = "Contents";
+
+/// Link prefix of contents entries in the transcription window
+pub(crate) const LINK_PREFIX: &str = "vissper-topic:";
+
+/// Minimum time between two time cues, to keep the prompt short
+const CUE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Instructions appended to the meeting notes prompt
+pub(crate) const PROMPT_INSTRUCTIONS: &str = r###"TOPIC SECTIONS: Divide the polished transcript under "## Transcript" into topic sections, one for each agenda item or subject discussed, in the order they came up. Start each topic section with a heading of the form `### [MM:SS] Topic title`, using the time cue nearest to where the topic starts. Time cues look like `[@12:34]` (or `[@1:02:34]` past the first hour) and give the time since the recording started; remove them from the output. If the transcript has no time cues, write the headings as `### Topic title` without a time.
+
+Directly before "## Transcript", add a "## Contents" section with one bullet point per topic heading, in order, repeating each heading's text exactly (for example `- [12:34] Budget review`)."###;
+
+/// Whether meeting notes are split into topics
+pub(crate) fn is_enabled() -> bool {
+    preferences::get_topic_sections()
+}
+
+/// Committed transcript with time cues before speech segments, at most one
+/// cue per `CUE_INTERVAL`
+#[cfg(test)]
+fn timed_transcript(session: &TranscriptionSession) -> String {
+    session.transcript_with_cues(time_cues(session))
+}
+
+/// Time cues before speech segments, for `transcript_with_cues`, at most one
+/// per `CUE_INTERVAL`
+pub(crate) fn time_cues(
+    session: &TranscriptionSession,
+) -> impl FnMut(usize) -> Option<String> + '_ {
+    let mut offsets = session.commit_offsets.iter().peekable();
+    let mut last_cue: Option<Duration> = None;
+
+    move |index| {
+        while offsets
+            .next_if(|(segment_index, _)| *segment_index < index)
+            .is_some()
+        {}
+        let offset = offsets
+            .next_if(|(segment_index, _)| *segment_index == index)
+            .map(|(_, offset)| *offset)?;
+        if last_cue.is_some_and(|last| offset < last + CUE_INTERVAL) {
+            return None;
+        }
+        last_cue = Some(offset);
+        Some(format!("[@{}]", format_offset(offset)))
+    }
+}
+
+/// `M:SS`, or `H:MM:SS` from the first hour on
+pub(crate) fn format_offset(offset: Duration) -> String {
+    let secs = offset.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// Remove time cues (and the space after each) from a transcript
+pub(crate) fn strip_time_cues(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("[@") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let cue_len = after
+            .find(']')
+            .filter(|&end| end > 0 && after[..end].chars().all(|c| c.is_ascii_digit() || c == ':'));
+        match cue_len {
+            Some(end) => {
+                let after_cue = &after[end + 1..];
+                rest = after_cue.strip_prefix(' ').unwrap_or(after_cue);
+            }
+            None => {
+                result.push_str("[@");
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Whether `line` is the table of contents heading
+fn is_contents_heading(line: &str) -> bool {
+    line.trim().strip_prefix("## ") == Some(CONTENTS_HEADING)
+}
+
+/// Whether `line` starts another section, ending the table of contents
+fn is_section_heading(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("# ") || line.starts_with("## ")
+}
+
+/// Contents entry of a bullet line inside the table of contents
+fn contents_entry(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    line.strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+}
+
+/// Markdown with each contents entry turned into a link to its heading,
+/// using the anchors Markdown viewers generate for headings
+pub(crate) fn link_contents(markdown: &str) -> String {
+    let mut in_contents = false;
+    let lines: Vec<String> = markdown
+        .lines()
+        .map(|line| {
+            if is_contents_heading(line) {
+                in_contents = true;
+            } else if is_section_heading(line) {
+                in_contents = false;
+            } else if let Some(entry) = contents_entry(line).filter(|_| in_contents) {
+                let text = entry.replace('[', "\\[").replace(']', "\\]");
+                return format!("- [{}](#{})", text, anchor(entry));
+            }
+            line.to_string()
+        })
+        .collect();
+
+    let mut linked = lines.join("\n");
+    if markdown.ends_with('\n') {
+        linked.push('\n');
+    }
+    linked
+}
+
+/// Heading anchor as generated by GitHub and most Markdown viewers
+fn anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Byte offset of the heading line for a contents entry in displayed text,
+/// where headings are shown without their `###` prefix
+pub(crate) fn heading_offset(text: &str, entry: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim() == entry {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timed_transcript_limits_cues() {
+        let session = TranscriptionSession {
+            committed_segments: vec![
+                "Welcome.".to_string(),
+                "\n\n=== MARKER 14:32 ===\n\n".to_string(),
+                "First item.".to_string(),
+                "Second item.".to_string(),
+            ],
+            commit_offsets: vec![
+                (0, Duration::from_secs(5)),
+                (2, Duration::from_secs(20)),
+                (3, Duration::from_secs(3725)),
+            ],
+            ..Default::default()
+        };
+
+        let timed = timed_transcript(&session);
+        assert_eq!(
+            timed,
+            "[@0:05] Welcome.\n\n=== MARKER 14:32 ===\n\nFirst item. [@1:02:05] Second item."
+        );
+        assert_eq!(strip_time_cues(&timed), session.full_transcript());
+    }
+
+    #[test]
+    fn test_strip_leaves_other_brackets() {
+        assert_eq!(
+            strip_time_cues("[@12:34] Hi [@you] [@] x"),
+            "Hi [@you] [@] x"
+        );
+        assert_eq!(strip_time_cues("no cues"), "no cues");
+    }
+
+    #[test]
+    fn test_link_contents() {
+        let notes = "## Summary\n- Not a topic\n\n## Contents\n- [0:05] Budget review\n- [12:34] Q3 hiring & plans\n\n## Transcript\n### [0:05] Budget review\n";
+        let linked = link_contents(notes);
+
+        assert!(linked.contains("- Not a topic\n"));
+        assert!(linked.contains("- [\\[0:05\\] Budget review](#005-budget-review)\n"));
+        assert!(linked.contains("- [\\[12:34\\] Q3 hiring & plans](#1234-q3-hiring--plans)\n"));
+        assert!(linked.ends_with("### [0:05] Budget review\n"));
+    }
+
+    #[test]
+    fn test_heading_offset_skips_contents_entry() {
+        let text = "Contents\n  •  [0:05] Budget review\nTranscript\n[0:05] Budget review\nWe…";
+        let offset = heading_offset(text, "[0:05] Budget review").unwrap();
+        assert!(text[offset..].starts_with("[0:05] Budget review\nWe"));
+        assert_eq!(heading_offset(text, "Missing"), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Reusable encoder for `input_audio_buffer.append` messages
+//!
+//! Audio is sent several times a second for as long as a session runs, so the
+//! PCM bytes and the JSON text are built in buffers kept for the whole
+//! connection instead of allocating a byte vector, a base64 string and a
+//! serialized message for every chunk.
+//!
+//! Audio goes out as 16-bit PCM, or as 8 kHz G.711 μ-law in low-bandwidth
+//! mode, which both realtime providers accept and which is a quarter to a
+//! sixth of the size.
+//!
+//! Compressed formats such as Opus would save more, but neither realtime API
+//! accepts them as `input_audio_format` (only `pcm16`, `g711_ulaw` and
+//! `g711_alaw`), so G.711 is the smallest encoding that can be sent. A new
+//! variant belongs in [`AudioEncoding`] once a provider takes one.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Message text before the base64 audio
+const PREFIX: &str = r#"{"type":"input_audio_buffer.append","audio":""#;
+
+/// Message text after the base64 audio
+const SUFFIX: &str = r#""}"#;
+
+/// Sample rate of G.711 audio
+const G711_SAMPLE_RATE: u32 = 8000;
+
+/// How audio samples are encoded for the provider
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AudioEncoding {
+    /// 16-bit little-endian PCM at the provider's sample rate
+    #[default]
+    Pcm16,
+    /// 8-bit G.711 μ-law at 8 kHz (low-bandwidth mode)
+    G711Ulaw,
+}
+
+impl AudioEncoding {
+    /// Encoding chosen in Settings
+    pub(crate) fn from_preferences() -> Self {
+        if crate::preferences::get_low_bandwidth_mode() {
+            Self::G711Ulaw
+        } else {
+            Self::Pcm16
+        }
+    }
+
+    /// Value of the session's `input_audio_format`
+    pub(crate) fn format_name(self) -> &'static str {
+        match self {
+            Self::Pcm16 => "pcm16",
+            Self::G711Ulaw => "g711_ulaw",
+        }
+    }
+
+    /// Sample rate to capture at, given the provider's PCM sample rate
+    pub(crate) fn sample_rate(self, pcm16_sample_rate: u32) -> u32 {
+        match self {
+            Self::Pcm16 => pcm16_sample_rate,
+            Self::G711Ulaw => G711_SAMPLE_RATE,
+        }
+    }
+}
+
+/// Builds append messages into buffers reused across chunks
+#[derive(Debug, Default)]
+pub(crate) struct AudioAppendEncoder {
+    encoding: AudioEncoding,
+    /// Encoded bytes of the current chunk
+    bytes: Vec<u8>,
+    /// JSON text of the current message
+    json: String,
+}
+
+impl AudioAppendEncoder {
+    pub(crate) fn new(encoding: AudioEncoding) -> Self {
+        Self {
+            encoding,
+            ..Self::default()
+        }
+    }
+
+    /// JSON append message for `samples`, valid until the next call.
+    ///
+    /// Matches the serde output of the providers' `InputAudioBufferAppend`
+    /// messages. Base64 never needs JSON escaping, so it is written straight
+    /// into the message.
+    pub(crate) fn encode(&mut self, samples: &[i16]) -> &str {
+        self.bytes.clear();
+        match self.encoding {
+            AudioEncoding::Pcm16 => {
+                self.bytes.reserve(samples.len() * 2);
+                for sample in samples {
+                    self.bytes.extend_from_slice(&sample.to_le_bytes());
+                }
+            }
+            AudioEncoding::G711Ulaw => {
+                self.bytes
+                    .extend(samples.iter().map(|&sample| ulaw(sample)));
+            }
+        }
+
+        self.json.clear();
+        self.json.push_str(PREFIX);
+        STANDARD.encode_string(&self.bytes, &mut self.json);
+        self.json.push_str(SUFFIX);
+        &self.json
+    }
+
+    /// WebSocket message for `samples`.
+    ///
+    /// The socket takes ownership of the text, so this is the only allocation
+    /// per chunk, sized exactly to the message.
+    pub(crate) fn message(&mut self, samples: &[i16]) -> Message {
+        Message::Text(self.encode(samples).to_owned())
+    }
+}
+
+/// G.711 μ-law code for a 16-bit sample
+fn ulaw(sample: i16) -> u8 {
+    const BIAS: i32 = 0x84;
+    const CLIP: i32 = 32635;
+
+    let sign = if sample < 0 { 0x80 } else { 0x00 };
+    let magnitude = (sample as i32).abs().min(CLIP) + BIAS;
+    // Segment: position of the highest set bit above bit 7
+    let exponent = (31 - magnitude.leading_zeros() as i32 - 7).clamp(0, 7);
+    let mantissa = (magnitude >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) as u8 | mantissa as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::azure_messages::AzureClientMessage;
+    use crate::transcription::openai_messages::OpenAIClientMessage;
+
+    fn base64_of(samples: &[i16]) -> String {
+        let bytes: Vec<u8> = samples.iter().flat_map(|&s| s.to_le_bytes()).collect();
+        STANDARD.encode(bytes)
+    }
+
+    #[test]
+    fn test_matches_serde_output() {
+        let samples = [0i16, 1, -1, i16::MAX, i16::MIN, 1234];
+        let mut encoder = AudioAppendEncoder::new(AudioEncoding::Pcm16);
+
+        let azure = serde_json::to_string(&AzureClientMessage::InputAudioBufferAppend {
+            audio: base64_of(&samples),
+        })
+        .unwrap();
+        let openai = serde_json::to_string(&OpenAIClientMessage::InputAudioBufferAppend {
+            audio: base64_of(&samples),
+        })
+        .unwrap();
+
+        assert_eq!(encoder.encode(&samples), azure);
+        assert_eq!(encoder.encode(&samples), openai);
+    }
+
+    #[test]
+    fn test_reuse_does_not_leak_previous_chunk() {
+        let mut encoder = AudioAppendEncoder::new(AudioEncoding::Pcm16);
+        encoder.encode(&[7i16; 1600]);
+        let capacity = encoder.json.capacity();
+
+        let short = encoder.encode(&[1, 2]).to_owned();
+        let expected = format!("{}{}{}", PREFIX, base64_of(&[1, 2]), SUFFIX);
+        assert_eq!(short, expected);
+        assert_eq!(encoder.json.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_ulaw_reference_values() {
+        // Values from the ITU-T G.711 tables
+        assert_eq!(ulaw(0), 0xFF);
+        assert_eq!(ulaw(-1), 0x7F);
+        assert_eq!(ulaw(i16::MAX), 0x80);
+        assert_eq!(ulaw(i16::MIN), 0x00);
+        assert_eq!(ulaw(1000), 0xCE);
+        assert_eq!(ulaw(-1000), 0x4E);
+    }
+
+    #[test]
+    fn test_ulaw_chunk_is_one_byte_per_sample() {
+        let mut encoder = AudioAppendEncoder::new(AudioEncoding::G711Ulaw);
+        encoder.encode(&[0i16; 800]);
+        assert_eq!(encoder.bytes.len(), 800);
+    }
+}
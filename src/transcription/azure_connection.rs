@@ -3,19 +3,25 @@
 //! Manages the WebSocket connection to Azure OpenAI for direct STT using GPT-4o Transcribe.
 //! Uses a different protocol than the ElevenLabs/VIPS proxy connections.
 
-use super::azure_messages::{
-    AzureClientMessage, AzureServerMessage, AzureSessionConfig, AZURE_API_VERSION,
-};
-use super::session::TranscriptionSession;
+use super::audio_append::{AudioAppendEncoder, AudioEncoding};
+use super::azure_messages::{AzureClientMessage, AzureServerMessage, AzureSessionConfig};
+use super::debug_log;
+use super::formatting::PauseDetector;
+use super::heartbeat::Heartbeat;
+use super::language_switch::LanguageUpdates;
+use super::latency;
+use super::noise_reduction::NoiseReduction;
+use super::session::{SessionCommand, SessionHandle};
+use super::session_info::notify_configured;
+use super::usage;
 use super::TranscriptEvent;
 use crate::audio::AudioChunk;
-use base64::Engine;
 use futures_util::{SinkExt, StreamExt};
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::mpsc;
 use tokio::time::interval;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, trace, warn};
@@ -37,7 +43,11 @@ pub(crate) struct AzureSendResult {
 }
 
 /// Build Azure WebSocket URL
-pub(crate) fn build_azure_ws_url(endpoint_url: &str, stt_deployment: &str) -> String {
+pub(crate) fn build_azure_ws_url(
+    endpoint_url: &str,
+    stt_deployment: &str,
+    api_version: &str,
+) -> String {
     // Remove trailing slash if present
     let endpoint = endpoint_url.trim_end_matches('/');
 
@@ -48,7 +58,7 @@ pub(crate) fn build_azure_ws_url(endpoint_url: &str, stt_deployment: &str) -> St
 
     format!(
         "{}/openai/realtime?api-version={}&deployment={}",
-        ws_endpoint, AZURE_API_VERSION, stt_deployment
+        ws_endpoint, api_version, stt_deployment
     )
 }
 
@@ -70,22 +80,37 @@ pub(crate) fn build_azure_ws_request(
         .map_err(|e| e.to_string())
 }
 
+/// Azure session.update message configuring transcription
+///
+/// Sent when connecting, and again when the language is switched.
+pub(crate) fn session_update(
+    model: &str,
+    language: Option<&str>,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+) -> Result<String, String> {
+    let session_config =
+        AzureSessionConfig::new(model, language, encoding.format_name(), noise_reduction);
+    let msg = AzureClientMessage::SessionUpdate {
+        session: session_config,
+    };
+    serde_json::to_string(&msg).map_err(|e| e.to_string())
+}
+
 /// Send Azure session initialization message
 pub(crate) async fn send_session_init<S>(
     ws_sink: &mut S,
     model: &str,
     language: Option<&str>,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
 ) -> Result<(), String>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
 {
-    let session_config = AzureSessionConfig::new(model, language);
-    let msg = AzureClientMessage::SessionUpdate {
-        session: session_config,
-    };
-
-    let json = serde_json::to_string(&msg).map_err(|e| e.to_string())?;
+    let json = session_update(model, language, encoding, noise_reduction)?;
     info!("Sending Azure session.update: {}", json);
+    debug_log::sent(&json);
 
     ws_sink
         .send(Message::Text(json))
@@ -99,15 +124,38 @@ pub(crate) fn spawn_azure_receive_task(
         + Unpin
         + Send
         + 'static,
-    session: Arc<Mutex<TranscriptionSession>>,
-    event_tx: broadcast::Sender<TranscriptEvent>,
+    session: SessionHandle,
     should_stop: Arc<AtomicBool>,
+    heartbeat: Heartbeat,
 ) -> tokio::task::JoinHandle<AzureReceiveResult> {
     tokio::spawn(async move {
         let mut connection_ok = true;
-        let quota_exceeded = false;
+        let mut quota_exceeded = false;
+        let mut pauses = PauseDetector::default();
+
+        loop {
+            let msg_result = tokio::select! {
+                msg_result = ws_stream.next() => match msg_result {
+                    Some(msg_result) => msg_result,
+                    None => break,
+                },
+                () = heartbeat.stalled() => {
+                    warn!(
+                        "No reply from Azure for {}s while sending audio, reconnecting",
+                        heartbeat.timeout_secs()
+                    );
+                    debug_log::connection(format!(
+                        "No reply for {}s while sending audio",
+                        heartbeat.timeout_secs()
+                    ));
+                    connection_ok = false;
+                    session.send(SessionCommand::PreservePartial { reason: "stalled" });
+                    session.notify(TranscriptEvent::ConnectionLost);
+                    break;
+                }
+            };
+            heartbeat.received();
 
-        while let Some(msg_result) = ws_stream.next().await {
             if should_stop.load(Ordering::SeqCst) {
                 break;
             }
@@ -115,6 +163,8 @@ pub(crate) fn spawn_azure_receive_task(
             match msg_result {
                 Ok(Message::Text(text)) => {
                     trace!("Azure message: {}", text);
+                    debug_log::received(&text);
+                    usage::received(text.len());
                     match serde_json::from_str::<AzureServerMessage>(&text) {
                         Ok(azure_msg) => {
                             // Check for errors
@@ -127,37 +177,60 @@ pub(crate) fn spawn_azure_receive_task(
                                     debug!("Azure buffer empty on stop (expected): {}", error_msg);
                                     continue;
                                 }
+                                // Retrying can't help once the account is out of credits
+                                if crate::retry::is_quota_exhausted(&text) {
+                                    error!("Azure quota exceeded: {}", error_msg);
+                                    quota_exceeded = true;
+                                    session.notify(TranscriptEvent::Error { message: error_msg });
+                                    break;
+                                }
                                 error!("Azure STT error: {}", error_msg);
-                                let _ =
-                                    event_tx.send(TranscriptEvent::Error { message: error_msg });
+                                session.notify(TranscriptEvent::Error { message: error_msg });
                                 continue;
                             }
 
                             // Convert Azure message to transcript event
                             if let Some((is_final, text)) = azure_msg.to_transcript_text() {
-                                update_azure_session_state(&session, is_final, &text);
-
-                                let event = if is_final {
+                                let command = if is_final {
                                     debug!("Azure committed transcript: {}", text);
-                                    TranscriptEvent::CommittedTranscript { text }
+                                    SessionCommand::Commit(text)
                                 } else {
                                     trace!("Azure partial transcript: {}", text);
-                                    TranscriptEvent::PartialTranscript { text }
+                                    SessionCommand::Partial(text)
                                 };
-                                let _ = event_tx.send(event);
+                                session.send(command);
                             }
 
                             // Log session events
                             match &azure_msg {
-                                AzureServerMessage::SessionCreated { .. } => {
+                                AzureServerMessage::SessionCreated { session: info } => {
                                     info!("Azure session created");
+                                    notify_configured(&session, info.as_ref());
                                 }
-                                AzureServerMessage::SessionUpdated { .. } => {
+                                AzureServerMessage::SessionUpdated { session: info } => {
                                     info!("Azure session updated");
+                                    notify_configured(&session, info.as_ref());
                                 }
                                 AzureServerMessage::InputAudioBufferCommitted => {
                                     debug!("Azure audio buffer committed");
                                 }
+                                AzureServerMessage::InputAudioBufferSpeechStarted {
+                                    audio_start_ms,
+                                } => {
+                                    debug!("Azure VAD: speech started");
+                                    if audio_start_ms.is_some_and(|ms| pauses.speech_started(ms)) {
+                                        session.send(SessionCommand::ParagraphBreak);
+                                    }
+                                }
+                                AzureServerMessage::InputAudioBufferSpeechStopped {
+                                    audio_end_ms,
+                                } => {
+                                    debug!("Azure VAD: speech stopped");
+                                    if let Some(audio_end_ms) = audio_end_ms {
+                                        latency::speech_stopped(*audio_end_ms);
+                                        pauses.speech_stopped(*audio_end_ms);
+                                    }
+                                }
                                 AzureServerMessage::ResponseCreated => {
                                     debug!("Azure response created");
                                 }
@@ -174,10 +247,13 @@ pub(crate) fn spawn_azure_receive_task(
                 }
                 Ok(Message::Close(_)) => {
                     info!("Azure WebSocket closed by server");
+                    debug_log::connection("Closed by the server");
                     connection_ok = false;
-                    preserve_azure_partial(&session, "connection close");
+                    session.send(SessionCommand::PreservePartial {
+                        reason: "connection close",
+                    });
                     if !quota_exceeded {
-                        let _ = event_tx.send(TranscriptEvent::ConnectionLost);
+                        session.notify(TranscriptEvent::ConnectionLost);
                     }
                     break;
                 }
@@ -189,9 +265,12 @@ pub(crate) fn spawn_azure_receive_task(
                 }
                 Err(e) => {
                     error!("Azure WebSocket receive error: {}", e);
+                    debug_log::connection(format!("Receive error: {}", e));
                     connection_ok = false;
-                    preserve_azure_partial(&session, "receive error");
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
+                    session.send(SessionCommand::PreservePartial {
+                        reason: "receive error",
+                    });
+                    session.notify(TranscriptEvent::ConnectionLost);
                     break;
                 }
                 _ => {}
@@ -211,13 +290,16 @@ pub(crate) fn spawn_azure_send_task<S>(
     mut audio_rx: mpsc::Receiver<AudioChunk>,
     mut connection_lost_rx: mpsc::Receiver<()>,
     should_stop: Arc<AtomicBool>,
+    encoding: AudioEncoding,
+    heartbeat: Heartbeat,
+    mut language_updates: LanguageUpdates,
 ) -> tokio::task::JoinHandle<AzureSendResult>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin + Send + 'static,
 {
     tokio::spawn(async move {
         info!("Azure send task started");
-        let base64_engine = base64::engine::general_purpose::STANDARD;
+        let mut encoder = AudioAppendEncoder::new(encoding);
         let mut pending_chunks: Vec<AudioChunk> = Vec::new();
         let mut sent_buffer: VecDeque<AudioChunk> = VecDeque::new();
         let max_buffer_secs = 30.0;
@@ -246,6 +328,15 @@ where
                     }
                     trace!("Sent Azure keepalive ping");
                 }
+                Some(update) = language_updates.next() => {
+                    info!("Sending Azure session.update: {}", update);
+                    debug_log::sent(&update);
+                    if ws_sink.send(Message::Text(update)).await.is_err() {
+                        warn!("Failed to send Azure language switch");
+                        connection_lost = true;
+                        break;
+                    }
+                }
                 chunk = audio_rx.recv() => {
                     if should_stop.load(Ordering::SeqCst) {
                         info!("Azure send task: should_stop flag set, sending commit");
@@ -275,10 +366,11 @@ where
                                     max_sample
                                 );
                             }
-                            match send_azure_audio_chunk(&mut ws_sink, &audio_chunk, &base64_engine).await {
+                            match send_azure_audio_chunk(&mut ws_sink, &audio_chunk, &mut encoder).await {
                                 Ok(()) => {
                                     sent_buffer.push_back(audio_chunk);
                                     trim_azure_sent_buffer(&mut sent_buffer, max_buffer_secs);
+                                    heartbeat.sent();
                                 }
                                 Err(_) => {
                                     error!("Failed to send Azure audio chunk");
@@ -287,6 +379,15 @@ where
                                     break;
                                 }
                             }
+                            // Ask a quiet server for a pong, so silence isn't taken for a stall
+                            if heartbeat.should_ping() {
+                                if ws_sink.send(Message::Ping(vec![])).await.is_err() {
+                                    warn!("Failed to send Azure heartbeat ping");
+                                    connection_lost = true;
+                                    break;
+                                }
+                                trace!("Sent Azure heartbeat ping");
+                            }
                         }
                         None => {
                             info!("Azure audio buffer channel closed after sending {} chunks", chunks_sent);
@@ -326,26 +427,16 @@ where
 async fn send_azure_audio_chunk<S>(
     ws_sink: &mut S,
     chunk: &AudioChunk,
-    base64_engine: &base64::engine::GeneralPurpose,
+    encoder: &mut AudioAppendEncoder,
 ) -> Result<(), ()>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
 {
-    // Convert samples to bytes (PCM16 little-endian)
-    let bytes: Vec<u8> = chunk
-        .samples
-        .iter()
-        .flat_map(|&s| s.to_le_bytes())
-        .collect();
-
-    let audio_base64 = base64_engine.encode(&bytes);
-    let msg = AzureClientMessage::InputAudioBufferAppend {
-        audio: audio_base64,
-    };
-
-    if let Ok(json) = serde_json::to_string(&msg) {
-        ws_sink.send(Message::Text(json)).await.map_err(|_| ())?;
-    }
+    let message = encoder.message(&chunk.samples);
+    let bytes = message.len();
+    ws_sink.send(message).await.map_err(|_| ())?;
+    latency::audio_sent(chunk);
+    usage::sent(bytes);
     Ok(())
 }
 
@@ -418,42 +509,11 @@ fn recover_azure_buffered_chunks(
     all_pending
 }
 
-/// Update session state based on Azure transcript
-fn update_azure_session_state(
-    session: &Arc<Mutex<TranscriptionSession>>,
-    is_final: bool,
-    text: &str,
-) {
-    if let Ok(mut sess) = session.lock() {
-        if is_final {
-            sess.committed_segments.push(text.to_string());
-            sess.partial_transcript = None;
-        } else {
-            sess.partial_transcript = Some(text.to_string());
-        }
-    }
-}
-
-/// Preserve any partial transcript as committed
-fn preserve_azure_partial(session: &Arc<Mutex<TranscriptionSession>>, reason: &str) {
-    if let Ok(mut sess) = session.lock() {
-        if let Some(partial) = sess.partial_transcript.take() {
-            if !partial.trim().is_empty() {
-                info!(
-                    "Preserving Azure partial transcript before {}: {} chars",
-                    reason,
-                    partial.len()
-                );
-                sess.committed_segments.push(partial);
-            }
-        }
-    }
-}
-
 /// Resend buffered Azure audio chunks after reconnection
 pub(crate) async fn resend_azure_buffered_chunks<S>(
     ws_sink: &mut S,
     pending_chunks: &mut Vec<AudioChunk>,
+    encoding: AudioEncoding,
 ) -> Result<(), ()>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
@@ -466,26 +526,17 @@ where
         "Resending {} Azure buffered audio chunks",
         pending_chunks.len()
     );
-    let base64_engine = base64::engine::general_purpose::STANDARD;
+    let mut encoder = AudioAppendEncoder::new(encoding);
 
     for chunk in pending_chunks.drain(..) {
-        let bytes: Vec<u8> = chunk
-            .samples
-            .iter()
-            .flat_map(|&s| s.to_le_bytes())
-            .collect();
-
-        let audio_base64 = base64_engine.encode(&bytes);
-        let msg = AzureClientMessage::InputAudioBufferAppend {
-            audio: audio_base64,
-        };
-
-        if let Ok(json) = serde_json::to_string(&msg) {
-            if ws_sink.send(Message::Text(json)).await.is_err() {
-                error!("Failed to resend Azure buffered audio chunk");
-                return Err(());
-            }
+        let message = encoder.message(&chunk.samples);
+        let bytes = message.len();
+        if ws_sink.send(message).await.is_err() {
+            error!("Failed to resend Azure buffered audio chunk");
+            return Err(());
         }
+        latency::audio_sent(&chunk);
+        usage::sent(bytes);
     }
 
     Ok(())
@@ -497,15 +548,23 @@ mod tests {
 
     #[test]
     fn test_build_azure_ws_url() {
-        let url = build_azure_ws_url("https://myresource.openai.azure.com", "gpt-4o-transcribe");
+        let url = build_azure_ws_url(
+            "https://myresource.openai.azure.com",
+            "gpt-4o-transcribe",
+            "2024-10-01-preview",
+        );
         assert!(url.starts_with("wss://"));
-        assert!(url.contains("api-version="));
+        assert!(url.contains("api-version=2024-10-01-preview"));
         assert!(url.contains("deployment=gpt-4o-transcribe"));
     }
 
     #[test]
     fn test_build_azure_ws_url_trailing_slash() {
-        let url = build_azure_ws_url("https://myresource.openai.azure.com/", "gpt-4o-transcribe");
+        let url = build_azure_ws_url(
+            "https://myresource.openai.azure.com/",
+            "gpt-4o-transcribe",
+            "2024-10-01-preview",
+        );
         assert!(!url.contains("//openai"));
     }
 }
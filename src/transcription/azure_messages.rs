@@ -4,8 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Azure API version for Realtime endpoint
-pub const AZURE_API_VERSION: &str = "2024-10-01-preview";
+use super::noise_reduction::NoiseReduction;
+use super::session_info::SessionInfo;
 
 /// Messages sent to Azure OpenAI Realtime API
 #[derive(Debug, Serialize)]
@@ -15,6 +15,10 @@ pub(crate) enum AzureClientMessage {
     #[serde(rename = "session.update")]
     SessionUpdate { session: AzureSessionConfig },
     /// Append audio data to the input buffer
+    ///
+    /// Sent via `AudioAppendEncoder`, which writes the same JSON without
+    /// per-chunk allocations; kept here as the reference format.
+    #[allow(dead_code)]
     #[serde(rename = "input_audio_buffer.append")]
     InputAudioBufferAppend { audio: String },
     /// Commit the audio buffer for processing
@@ -30,10 +34,21 @@ pub(crate) enum AzureClientMessage {
 pub(crate) struct AzureSessionConfig {
     /// Modalities to use (["text"] for transcription only)
     pub modalities: Vec<String>,
-    /// Input audio format (pcm16)
+    /// Input audio format ("pcm16", or "g711_ulaw" in low-bandwidth mode)
     pub input_audio_format: String,
     /// Transcription configuration
     pub input_audio_transcription: AzureTranscriptionConfig,
+    /// Noise reduction configuration (optional, API version 2025-04-01-preview and later)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_noise_reduction: Option<AzureNoiseReduction>,
+}
+
+/// Noise reduction configuration
+#[derive(Debug, Serialize)]
+pub(crate) struct AzureNoiseReduction {
+    /// Noise reduction type: "near_field" or "far_field"
+    #[serde(rename = "type")]
+    pub noise_type: String,
 }
 
 /// Transcription configuration
@@ -48,14 +63,26 @@ pub(crate) struct AzureTranscriptionConfig {
 
 impl AzureSessionConfig {
     /// Create a new session config for STT
-    pub fn new(model: &str, language: Option<&str>) -> Self {
+    ///
+    /// `noise_reduction` must already be limited to what the API version supports.
+    pub fn new(
+        model: &str,
+        language: Option<&str>,
+        input_audio_format: &str,
+        noise_reduction: NoiseReduction,
+    ) -> Self {
         Self {
             modalities: vec!["text".to_string()],
-            input_audio_format: "pcm16".to_string(),
+            input_audio_format: input_audio_format.to_string(),
             input_audio_transcription: AzureTranscriptionConfig {
                 model: model.to_string(),
                 language: language.map(String::from),
             },
+            input_audio_noise_reduction: noise_reduction.api_name().map(|noise_type| {
+                AzureNoiseReduction {
+                    noise_type: noise_type.to_string(),
+                }
+            }),
         }
     }
 }
@@ -66,16 +93,10 @@ impl AzureSessionConfig {
 pub(crate) enum AzureServerMessage {
     /// Session created
     #[serde(rename = "session.created")]
-    SessionCreated {
-        #[allow(dead_code)]
-        session: Option<AzureSessionInfo>,
-    },
+    SessionCreated { session: Option<SessionInfo> },
     /// Session updated
     #[serde(rename = "session.updated")]
-    SessionUpdated {
-        #[allow(dead_code)]
-        session: Option<AzureSessionInfo>,
-    },
+    SessionUpdated { session: Option<SessionInfo> },
     /// Partial transcription delta
     #[serde(rename = "conversation.item.input_audio_transcription.delta")]
     TranscriptionDelta { delta: Option<String> },
@@ -88,6 +109,18 @@ pub(crate) enum AzureServerMessage {
     /// Input audio buffer cleared
     #[serde(rename = "input_audio_buffer.cleared")]
     InputAudioBufferCleared,
+    /// Server VAD detected the start of speech
+    #[serde(rename = "input_audio_buffer.speech_started")]
+    InputAudioBufferSpeechStarted {
+        /// Where the speech started, in milliseconds of audio sent on this connection
+        audio_start_ms: Option<u64>,
+    },
+    /// Server VAD detected the end of speech
+    #[serde(rename = "input_audio_buffer.speech_stopped")]
+    InputAudioBufferSpeechStopped {
+        /// Where the speech ended, in milliseconds of audio sent on this connection
+        audio_end_ms: Option<u64>,
+    },
     /// Response started
     #[serde(rename = "response.created")]
     ResponseCreated,
@@ -102,15 +135,6 @@ pub(crate) enum AzureServerMessage {
     Other,
 }
 
-/// Azure session information
-#[derive(Debug, Deserialize)]
-pub(crate) struct AzureSessionInfo {
-    #[allow(dead_code)]
-    pub id: Option<String>,
-    #[allow(dead_code)]
-    pub model: Option<String>,
-}
-
 /// Azure response object
 #[derive(Debug, Deserialize)]
 pub(crate) struct AzureResponse {
@@ -194,12 +218,18 @@ mod tests {
     #[test]
     fn test_session_update_serialization() {
         let msg = AzureClientMessage::SessionUpdate {
-            session: AzureSessionConfig::new("gpt-4o-transcribe", Some("en")),
+            session: AzureSessionConfig::new(
+                "gpt-4o-transcribe",
+                Some("en"),
+                "pcm16",
+                NoiseReduction::Off,
+            ),
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("session.update"));
         assert!(json.contains("gpt-4o-transcribe"));
         assert!(json.contains("pcm16"));
+        assert!(!json.contains("input_audio_noise_reduction"));
     }
 
     #[test]
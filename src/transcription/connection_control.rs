@@ -0,0 +1,120 @@
+//! Pausing a session's connection from outside the connection loop
+//!
+//! While paused the connection loop drops its WebSocket and stays
+//! disconnected. Audio captured meanwhile keeps queueing in the internal
+//! buffer and is sent, together with the audio the service hadn't confirmed,
+//! once the loop reconnects on resume.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+/// Shared pause switch for one transcription session
+#[derive(Debug, Default)]
+pub struct ConnectionControl {
+    paused: AtomicBool,
+    changed: Notify,
+}
+
+impl ConnectionControl {
+    /// Drop the connection and stay disconnected until resumed
+    pub fn pause(&self) {
+        self.set_paused(true);
+    }
+
+    /// Reconnect if paused
+    pub fn resume(&self) {
+        self.set_paused(false);
+    }
+
+    /// Whether the connection is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    fn set_paused(&self, paused: bool) {
+        if self.paused.swap(paused, Ordering::SeqCst) != paused {
+            self.changed.notify_waiters();
+        }
+    }
+
+    /// Wait until the connection is in the given state
+    async fn wait_for(&self, paused: bool) {
+        loop {
+            // Created before checking, so a change in between still wakes it
+            let changed = self.changed.notified();
+            if self.is_paused() == paused {
+                return;
+            }
+            changed.await;
+        }
+    }
+
+    /// Wait until resumed; returns immediately when not paused
+    pub(super) async fn resumed(&self) {
+        self.wait_for(false).await;
+    }
+
+    /// Wait for a connection's receive task, or abort it when paused
+    ///
+    /// Returns `None` when the task failed or was aborted, so the caller
+    /// treats the connection as lost.
+    pub(super) async fn receive<T>(&self, mut task: JoinHandle<T>) -> Option<T> {
+        tokio::select! {
+            result = &mut task => result.ok(),
+            _ = self.wait_for(true) => {
+                info!("Connection paused, closing WebSocket");
+                task.abort();
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_pause_aborts_receive_task() {
+        let control = Arc::new(ConnectionControl::default());
+        let task = tokio::spawn(std::future::pending::<()>());
+
+        let pauser = control.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            pauser.pause();
+        });
+
+        assert_eq!(control.receive(task).await, None);
+        assert!(control.is_paused());
+    }
+
+    #[tokio::test]
+    async fn test_receive_returns_task_result() {
+        let control = ConnectionControl::default();
+        let task = tokio::spawn(async { 42 });
+        assert_eq!(control.receive(task).await, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_resumed_waits_while_paused() {
+        let control = Arc::new(ConnectionControl::default());
+        control.resumed().await;
+
+        control.pause();
+        let resumer = control.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            resumer.resume();
+        });
+
+        tokio::time::timeout(Duration::from_secs(1), control.resumed())
+            .await
+            .expect("resumed after resume()");
+        assert!(!control.is_paused());
+    }
+}
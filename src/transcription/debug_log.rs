@@ -0,0 +1,206 @@
+//! Event log behind the transcription window's Debug tab
+//!
+//! When the Debug tab is turned on in Settings, the connections record the
+//! provider's events as they arrive, the session updates they send and their
+//! lifecycle (connecting, reconnects, lost connections), each with the time
+//! it happened. Only the most recent entries are kept, in memory, so a user
+//! can copy them into a bug report.
+//!
+//! Transcript text and audio in the events are replaced by their length,
+//! so the log never reveals what was said.
+
+use chrono::{DateTime, Local};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::preferences;
+
+/// Entries kept before the oldest are dropped
+const MAX_ENTRIES: usize = 500;
+
+/// Fields of provider events whose values are speech or audio
+const REDACTED_FIELDS: [&str; 6] = ["audio", "delta", "logprobs", "prompt", "text", "transcript"];
+
+/// Where an entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// An event from the provider
+    Received,
+    /// A message sent to the provider (audio is never recorded)
+    Sent,
+    /// Connecting, reconnecting or losing the connection
+    Connection,
+}
+
+#[derive(Debug)]
+struct Entry {
+    time: DateTime<Local>,
+    direction: Direction,
+    text: String,
+}
+
+impl Entry {
+    fn line(&self) -> String {
+        let marker = match self.direction {
+            Direction::Received => "←",
+            Direction::Sent => "→",
+            Direction::Connection => "•",
+        };
+        format!(
+            "{} {} {}",
+            self.time.format("%H:%M:%S%.3f"),
+            marker,
+            self.text
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Log {
+    entries: VecDeque<Entry>,
+    /// Counts recorded entries, so the Debug tab knows when to redraw
+    revision: u64,
+}
+
+impl Log {
+    const fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            revision: 0,
+        }
+    }
+
+    fn push(&mut self, entry: Entry) {
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+        self.revision += 1;
+    }
+}
+
+static LOG: Mutex<Log> = Mutex::new(Log::new());
+
+fn record(direction: Direction, text: String) {
+    if !preferences::get_debug_tab() {
+        return;
+    }
+    let entry = Entry {
+        time: Local::now(),
+        direction,
+        text,
+    };
+    if let Ok(mut log) = LOG.lock() {
+        log.push(entry);
+    }
+}
+
+/// Number of entries recorded so far, which changes whenever one is added
+pub(crate) fn debug_log_revision() -> u64 {
+    LOG.lock().map(|log| log.revision).unwrap_or_default()
+}
+
+/// The kept entries, one per line, oldest first
+pub(crate) fn debug_log_text() -> String {
+    LOG.lock()
+        .map(|log| {
+            log.entries
+                .iter()
+                .map(Entry::line)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+/// An event received from the provider, as sent
+pub(super) fn received(message: &str) {
+    record(Direction::Received, redact(message));
+}
+
+/// A message sent to the provider
+pub(super) fn sent(message: &str) {
+    record(Direction::Sent, redact(message));
+}
+
+/// A change in the connection, e.g. "Reconnecting to OpenAI (attempt 2/5)"
+pub(super) fn connection(text: impl Into<String>) {
+    record(Direction::Connection, text.into());
+}
+
+/// `message` with speech and audio replaced by their length
+///
+/// Anything that isn't JSON is left out entirely.
+fn redact(message: &str) -> String {
+    match serde_json::from_str::<Value>(message) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes, not JSON>", message.len()),
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                if REDACTED_FIELDS.contains(&name.as_str()) {
+                    redact_field(field);
+                } else {
+                    redact_value(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+fn redact_field(field: &mut Value) {
+    *field = match field {
+        Value::Null => return,
+        Value::String(text) => Value::String(format!("<{} chars>", text.chars().count())),
+        _ => Value::String("<redacted>".to_string()),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_replaces_speech_with_its_length() {
+        let message = r#"{"type":"conversation.item.input_audio_transcription.completed","item_id":"item_1","transcript":"Hei på deg","logprobs":[{"token":"Hei"}],"error":null}"#;
+        assert_eq!(
+            redact(message),
+            r#"{"error":null,"item_id":"item_1","logprobs":"<redacted>","transcript":"<10 chars>","type":"conversation.item.input_audio_transcription.completed"}"#
+        );
+    }
+
+    #[test]
+    fn test_redact_nested_fields_and_keeps_errors() {
+        let message = r#"{"type":"error","error":{"message":"Invalid model"},"response":{"output":[{"content":[{"transcript":"secret"}]}]}}"#;
+        let redacted = redact(message);
+        assert!(redacted.contains(r#""message":"Invalid model""#));
+        assert!(redacted.contains(r#""transcript":"<6 chars>""#));
+        assert!(!redacted.contains("secret"));
+        assert_eq!(redact("not json"), "<8 bytes, not JSON>");
+    }
+
+    #[test]
+    fn test_log_keeps_the_most_recent_entries() {
+        let mut log = Log::new();
+        for i in 0..(MAX_ENTRIES + 3) {
+            log.push(Entry {
+                time: Local::now(),
+                direction: Direction::Connection,
+                text: i.to_string(),
+            });
+        }
+        assert_eq!(log.entries.len(), MAX_ENTRIES);
+        assert_eq!(log.entries.front().map(|e| e.text.as_str()), Some("3"));
+        assert_eq!(log.revision, MAX_ENTRIES as u64 + 3);
+    }
+}
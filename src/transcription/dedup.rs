@@ -0,0 +1,161 @@
+//! Removing text repeated after a reconnect
+//!
+//! After a reconnect or a failover, the audio buffered since the last commit
+//! is sent again, and the provider sometimes commits a sentence that is
+//! already in the transcript: in full, or as the start of a new segment that
+//! repeats the end of the last one (often a partial kept when the connection
+//! dropped). The first few commits after a reconnect are compared with the
+//! last few committed segments: a near copy of one of them is dropped, and
+//! words repeating the end of the last segment are cut from its start.
+//! Words are compared ignoring case and punctuation.
+
+/// Commits checked for repeats after each reconnect
+pub(super) const CHECKED_COMMITS: usize = 3;
+
+/// Recent segments a commit is compared with
+const RECENT_SEGMENTS: usize = 3;
+
+/// Share of words, in order, two segments must have in common to be the same
+const MIN_SIMILARITY: f64 = 0.8;
+
+/// Fewest repeated words cut from the start of a commit
+const MIN_OVERLAP_WORDS: usize = 3;
+
+/// `text` without what the `committed` segments already have
+///
+/// Returns an empty string when all of `text` is a repeat.
+pub(super) fn without_repeats(text: &str, committed: &[String]) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let normalized = normalize(&words);
+    if normalized.is_empty() {
+        return text.to_string();
+    }
+
+    // Screenshots, markers and notes are not speech
+    let recent: Vec<Vec<String>> = committed
+        .iter()
+        .rev()
+        .filter(|segment| !segment.starts_with("\n\n"))
+        .take(RECENT_SEGMENTS)
+        .map(|segment| normalize(&segment.split_whitespace().collect::<Vec<_>>()))
+        .collect();
+
+    if recent
+        .iter()
+        .any(|segment| similarity(segment, &normalized) >= MIN_SIMILARITY)
+    {
+        return String::new();
+    }
+
+    match recent.first().map(|last| overlap(last, &normalized)) {
+        Some(repeated) if repeated >= MIN_OVERLAP_WORDS => words[repeated..].join(" "),
+        _ => text.to_string(),
+    }
+}
+
+/// Words lowercased, without punctuation
+fn normalize(words: &[&str]) -> Vec<String> {
+    words
+        .iter()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        })
+        .collect()
+}
+
+/// Share of the longer segment's words the two have in common, in order
+fn similarity(a: &[String], b: &[String]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    common_subsequence_len(a, b) as f64 / longest as f64
+}
+
+/// Length of the longest common subsequence of `a` and `b`
+fn common_subsequence_len(a: &[String], b: &[String]) -> usize {
+    let mut previous = vec![0; b.len() + 1];
+    for word in a {
+        let mut current = vec![0; b.len() + 1];
+        for (j, other) in b.iter().enumerate() {
+            current[j + 1] = if word == other {
+                previous[j] + 1
+            } else {
+                current[j].max(previous[j + 1])
+            };
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Number of words at the start of `next` that repeat the end of `last`
+fn overlap(last: &[String], next: &[String]) -> usize {
+    (1..=last.len().min(next.len()))
+        .rev()
+        .find(|&n| last[last.len() - n..] == next[..n])
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::{SessionCommand, SessionHandle, TranscriptEvent};
+    use tokio::sync::broadcast;
+
+    fn committed(segments: &[&str]) -> Vec<String> {
+        segments.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_repeated_segment_is_dropped() {
+        let segments = committed(&[
+            "Let's start.",
+            "We should ship on Friday.",
+            "\n\n> Note: Bob joined\n\n",
+        ]);
+        assert_eq!(without_repeats("we should ship on friday", &segments), "");
+        assert_eq!(
+            without_repeats("We should ship on a Friday.", &segments),
+            ""
+        );
+        assert_eq!(
+            without_repeats("Marketing wants Monday.", &segments),
+            "Marketing wants Monday."
+        );
+    }
+
+    #[test]
+    fn test_overlap_with_last_segment_is_cut() {
+        let segments = committed(&["Good morning. We should ship on"]);
+        assert_eq!(
+            without_repeats("We should ship on Friday, right?", &segments),
+            "Friday, right?"
+        );
+        // Too short an overlap to tell from a natural repetition
+        assert_eq!(without_repeats("ship on time.", &segments), "ship on time.");
+    }
+
+    #[tokio::test]
+    async fn test_repeats_are_dropped_after_reconnect() {
+        let (event_tx, _events) = broadcast::channel(16);
+        let session = SessionHandle::spawn(event_tx);
+
+        session.send(SessionCommand::Commit("We ship on Friday.".to_string()));
+        session.send(SessionCommand::Commit("We ship on Friday.".to_string()));
+        session.notify(TranscriptEvent::Reconnected);
+        session.send(SessionCommand::Commit("We ship on Friday.".to_string()));
+        session.send(SessionCommand::Commit("Any questions?".to_string()));
+
+        let settled = session.settled().await;
+        assert_eq!(
+            settled.full_transcript(),
+            "We ship on Friday. We ship on Friday. Any questions?"
+        );
+        let indexes: Vec<usize> = settled.commit_offsets.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+    }
+}
@@ -11,4 +11,7 @@ pub enum TranscriptionError {
 
     #[error("Connection timeout - Azure did not respond within {WS_CONNECT_TIMEOUT_SECS} seconds")]
     ConnectionTimeout,
+
+    #[error("Invalid mock transcript fixture: {0}")]
+    InvalidFixture(String),
 }
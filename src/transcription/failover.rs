@@ -0,0 +1,140 @@
+//! Handing a recording over to another provider
+//!
+//! With failover on, captured audio reaches the provider through a relay.
+//! The relay keeps the audio captured since the last committed segment (up
+//! to 30 s), so when the provider gives up, that audio can be sent again to
+//! the next provider, converted to its sample rate, before live audio
+//! continues. Committed text is kept; a partial segment the first provider
+//! was still working on may be transcribed twice.
+
+use std::collections::VecDeque;
+use tokio::sync::{broadcast, mpsc};
+use tracing::{info, warn};
+
+use super::TranscriptEvent;
+use crate::audio::{self, AudioChunk};
+
+/// Audio kept for resending, in seconds
+const REPLAY_SECS: f64 = 30.0;
+
+/// Chunks the channel to each provider holds
+const PROVIDER_CHANNEL_CHUNKS: usize = 1000;
+
+/// Audio the relay sends to a new provider from now on
+struct Target {
+    audio_tx: mpsc::Sender<AudioChunk>,
+    sample_rate: u32,
+}
+
+/// Switches captured audio between providers
+pub(crate) struct AudioRelay {
+    switch_tx: mpsc::UnboundedSender<Target>,
+}
+
+impl AudioRelay {
+    /// Relay `audio_rx` to the first provider at `sample_rate`
+    ///
+    /// `events` are the recording's transcript events, which tell the relay
+    /// which audio has been transcribed. Returns the relay and the audio for
+    /// the first provider.
+    pub(crate) fn start(
+        audio_rx: mpsc::Receiver<AudioChunk>,
+        sample_rate: u32,
+        events: broadcast::Receiver<TranscriptEvent>,
+    ) -> (Self, mpsc::Receiver<AudioChunk>) {
+        let (switch_tx, switch_rx) = mpsc::unbounded_channel();
+        let (audio_tx, provider_rx) = mpsc::channel(PROVIDER_CHANNEL_CHUNKS);
+        let target = Target {
+            audio_tx,
+            sample_rate,
+        };
+        tokio::spawn(relay(audio_rx, target, switch_rx, events));
+        (Self { switch_tx }, provider_rx)
+    }
+
+    /// Send audio to a new provider at `sample_rate`, starting with the audio
+    /// that wasn't transcribed yet
+    ///
+    /// The previous provider's audio ends, so it finishes once it has sent
+    /// what it holds.
+    pub(crate) fn switch(&self, sample_rate: u32) -> mpsc::Receiver<AudioChunk> {
+        let (audio_tx, provider_rx) = mpsc::channel(PROVIDER_CHANNEL_CHUNKS);
+        let target = Target {
+            audio_tx,
+            sample_rate,
+        };
+        if self.switch_tx.send(target).is_err() {
+            // Capture already stopped; the new provider gets no audio
+            info!("Audio relay already finished");
+        }
+        provider_rx
+    }
+}
+
+/// Forward audio to the current target until capture stops
+async fn relay(
+    mut audio_rx: mpsc::Receiver<AudioChunk>,
+    mut target: Target,
+    mut switch_rx: mpsc::UnboundedReceiver<Target>,
+    mut events: broadcast::Receiver<TranscriptEvent>,
+) {
+    let mut recent: VecDeque<AudioChunk> = VecDeque::new();
+    // Cleared once the provider stops taking audio, until the next switch
+    let mut connected = true;
+    let mut tracking = true;
+
+    loop {
+        tokio::select! {
+            chunk = audio_rx.recv() => {
+                let Some(chunk) = chunk else {
+                    break;
+                };
+                if connected {
+                    let converted = audio::convert_chunk_rate(&chunk, target.sample_rate);
+                    connected = target.audio_tx.send(converted).await.is_ok();
+                }
+                remember(&mut recent, chunk);
+            }
+            Some(next) = switch_rx.recv() => {
+                info!(
+                    "Audio relay switching to {} Hz, resending {:.1}s",
+                    next.sample_rate,
+                    duration_secs(&recent)
+                );
+                target = next;
+                connected = true;
+                for chunk in &recent {
+                    let converted = audio::convert_chunk_rate(chunk, target.sample_rate);
+                    if target.audio_tx.send(converted).await.is_err() {
+                        warn!("New provider stopped taking audio during resend");
+                        connected = false;
+                        break;
+                    }
+                }
+            }
+            event = events.recv(), if tracking => match event {
+                // The audio so far has been transcribed
+                Ok(TranscriptEvent::CommittedTranscript { .. }) => recent.clear(),
+                // Keep relaying without knowing what was transcribed
+                Err(broadcast::error::RecvError::Closed) => tracking = false,
+                _ => {}
+            },
+        }
+    }
+    info!("Audio relay finished");
+}
+
+/// Keep `chunk` for resending, dropping audio older than the replay window
+fn remember(recent: &mut VecDeque<AudioChunk>, chunk: AudioChunk) {
+    recent.push_back(chunk);
+    while duration_secs(recent) > REPLAY_SECS && recent.len() > 1 {
+        recent.pop_front();
+    }
+}
+
+fn duration_secs(chunks: &VecDeque<AudioChunk>) -> f64 {
+    chunks
+        .iter()
+        .map(|c| c.samples.len() as f64 / c.sample_rate as f64)
+        .sum()
+}
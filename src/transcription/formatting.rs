@@ -0,0 +1,184 @@
+//! Turning committed segments into readable text
+//!
+//! The service commits whatever it heard between two pauses, so segments
+//! often end mid-sentence. Joining them, a segment that continues a sentence
+//! is appended as is, and one that starts a sentence is capitalized. A pause
+//! of [`PARAGRAPH_PAUSE_MS`] or more, as reported by the service's voice
+//! activity detection, starts a new paragraph, and the paragraph before it
+//! gets a full stop if it has none. Screenshots, markers and notes stay
+//! blocks of their own.
+
+/// Silence between utterances that starts a new paragraph, in milliseconds
+const PARAGRAPH_PAUSE_MS: u64 = 3000;
+
+/// Finds long pauses in the voice activity events of one connection
+#[derive(Debug, Default)]
+pub(super) struct PauseDetector {
+    /// Where the last utterance ended, in milliseconds of audio sent
+    speech_ended_ms: Option<u64>,
+}
+
+impl PauseDetector {
+    pub(super) fn speech_stopped(&mut self, audio_end_ms: u64) {
+        self.speech_ended_ms = Some(audio_end_ms);
+    }
+
+    /// Whether speech starting at `audio_start_ms` follows a long pause
+    pub(super) fn speech_started(&mut self, audio_start_ms: u64) -> bool {
+        self.speech_ended_ms
+            .take()
+            .is_some_and(|ended| audio_start_ms.saturating_sub(ended) >= PARAGRAPH_PAUSE_MS)
+    }
+}
+
+/// Join `segments` into sentences and paragraphs
+///
+/// `paragraph_starts` holds the indexes of segments that follow a long pause.
+/// Text from `cue` for a speech segment's index is put before it, followed
+/// by a space.
+pub(super) fn format_transcript(
+    segments: &[String],
+    paragraph_starts: &[usize],
+    mut cue: impl FnMut(usize) -> Option<String>,
+) -> String {
+    let mut text = String::new();
+    for (index, segment) in segments.iter().enumerate() {
+        // Inserted blocks bring their own line breaks
+        if segment.starts_with("\n\n") {
+            text.truncate(text.trim_end().len());
+            text.push_str(segment);
+            continue;
+        }
+
+        let words = tidy(segment);
+        if words.is_empty() {
+            continue;
+        }
+        let after_break = text.is_empty() || text.ends_with('\n');
+        if !after_break {
+            if paragraph_starts.contains(&index) {
+                end_sentence(&mut text);
+                text.push_str("\n\n");
+            } else {
+                text.push(' ');
+            }
+        }
+
+        let starts_sentence =
+            text.is_empty() || text.ends_with('\n') || ends_sentence(text.trim_end());
+        if let Some(cue) = cue(index) {
+            text.push_str(&cue);
+            text.push(' ');
+        }
+        if starts_sentence {
+            text.push_str(&capitalized(&words));
+        } else {
+            text.push_str(&words);
+        }
+    }
+    text
+}
+
+/// `segment` with single spaces, and none before punctuation
+fn tidy(segment: &str) -> String {
+    let mut words = segment.split_whitespace().collect::<Vec<_>>().join(" ");
+    for mark in [",", ".", "!", "?", ";", ":"] {
+        words = words.replace(&format!(" {mark}"), mark);
+    }
+    words
+}
+
+/// Whether `text` ends with a full stop, question or exclamation mark
+///
+/// A trailing ellipsis reads as a sentence trailing off, not ending.
+fn ends_sentence(text: &str) -> bool {
+    let text = text.trim_end_matches(['"', '\'', '”', '’', ')']);
+    !text.ends_with("...") && text.ends_with(['.', '!', '?'])
+}
+
+/// End `text` with a full stop unless it already ends a sentence
+fn end_sentence(text: &mut String) {
+    if text.ends_with(char::is_alphanumeric) {
+        text.push('.');
+    }
+}
+
+/// `words` with the first letter capitalized, after any opening quotes
+fn capitalized(words: &str) -> String {
+    let opening = words
+        .find(|c: char| !matches!(c, '"' | '\'' | '“' | '‘' | '('))
+        .unwrap_or(words.len());
+    let (quotes, rest) = words.split_at(opening);
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(first) if first.is_lowercase() => {
+            format!("{quotes}{}{}", first.to_uppercase(), chars.as_str())
+        }
+        _ => words.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_fragments_merge_into_sentences() {
+        let texts = segments(&["so we should", "ship on friday .", "marketing   agrees"]);
+        assert_eq!(
+            format_transcript(&texts, &[], |_| None),
+            "So we should ship on friday. Marketing agrees"
+        );
+    }
+
+    #[test]
+    fn test_long_pause_starts_a_paragraph() {
+        let texts = segments(&["That's the budget", "next, hiring.", "\"we need two\""]);
+        assert_eq!(
+            format_transcript(&texts, &[1], |_| None),
+            "That's the budget.\n\nNext, hiring. \"We need two\""
+        );
+    }
+
+    #[test]
+    fn test_inserted_blocks_stay_separate() {
+        let texts = segments(&["Look at this", "\n\n=== MARKER 14:32 ===\n\n", "chart..."]);
+        assert_eq!(
+            format_transcript(&texts, &[], |_| None),
+            "Look at this\n\n=== MARKER 14:32 ===\n\nChart..."
+        );
+    }
+
+    #[test]
+    fn test_cues_go_before_capitalized_segments() {
+        let texts = segments(&["welcome.", "first item"]);
+        let cue = |index: usize| (index == 1).then(|| "[@0:35]".to_string());
+        assert_eq!(
+            format_transcript(&texts, &[], cue),
+            "Welcome. [@0:35] First item"
+        );
+    }
+
+    #[test]
+    fn test_segments_after_finished_sentences_are_capitalized() {
+        let texts = segments(&["is that done?", "yes it is.", "good"]);
+        assert_eq!(
+            format_transcript(&texts, &[], |_| None),
+            "Is that done? Yes it is. Good"
+        );
+    }
+
+    #[test]
+    fn test_pause_detection() {
+        let mut pauses = PauseDetector::default();
+        assert!(!pauses.speech_started(500));
+        pauses.speech_stopped(2000);
+        assert!(!pauses.speech_started(2800));
+        pauses.speech_stopped(6000);
+        assert!(pauses.speech_started(9500));
+    }
+}
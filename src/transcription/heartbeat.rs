@@ -0,0 +1,158 @@
+//! Watchdog for connections that stay open but stop answering
+//!
+//! A half-open TCP connection looks healthy to the WebSocket: audio keeps
+//! being sent without an error, but no transcripts come back. The receive
+//! task notes every server message and the send task every audio chunk. Once
+//! audio has gone out for the stall timeout without any reply, the receive
+//! task reports the connection lost and the reconnect loop takes over.
+//!
+//! The server sends nothing while nobody speaks, so the send task also pings
+//! once the server has been quiet for a third of the timeout; a live server
+//! answers with a pong, which counts as a message.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// Stall timeouts offered in Settings, in seconds
+pub(crate) const STALL_TIMEOUT_PRESETS: [u32; 4] = [10, 20, 30, 60];
+
+/// Stall timeout used unless changed in Settings
+pub(crate) const DEFAULT_STALL_TIMEOUT_SECS: u32 = 20;
+
+/// How often the receive task checks for a stall
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Traffic on one connection, shared by its send and receive tasks
+#[derive(Debug, Clone)]
+pub(super) struct Heartbeat {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    started: Instant,
+    timeout_ms: u64,
+    /// Milliseconds since `started` of the last server message
+    last_received_ms: AtomicU64,
+    /// Milliseconds since `started` of the last audio chunk sent
+    last_sent_ms: AtomicU64,
+    /// Milliseconds since `started` of the last heartbeat ping
+    last_ping_ms: AtomicU64,
+}
+
+impl Heartbeat {
+    /// Start watching a connection that was just opened
+    pub(super) fn new(timeout: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                started: Instant::now(),
+                timeout_ms: timeout.as_millis() as u64,
+                last_received_ms: AtomicU64::new(0),
+                last_sent_ms: AtomicU64::new(0),
+                last_ping_ms: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Note a message from the server
+    pub(super) fn received(&self) {
+        self.inner
+            .last_received_ms
+            .store(self.now_ms(), Ordering::Relaxed);
+    }
+
+    /// Note an audio chunk sent to the server
+    pub(super) fn sent(&self) {
+        self.inner
+            .last_sent_ms
+            .store(self.now_ms(), Ordering::Relaxed);
+    }
+
+    /// Whether to ping the server now; counts as pinged when true
+    pub(super) fn should_ping(&self) -> bool {
+        let now_ms = self.now_ms();
+        if !self.should_ping_at(now_ms) {
+            return false;
+        }
+        self.inner.last_ping_ms.store(now_ms, Ordering::Relaxed);
+        true
+    }
+
+    /// Wait until audio has been sent for the timeout without a reply
+    pub(super) async fn stalled(&self) {
+        loop {
+            sleep(CHECK_INTERVAL).await;
+            if self.stalled_at(self.now_ms()) {
+                return;
+            }
+        }
+    }
+
+    /// Seconds the stall timeout lasts, for logging
+    pub(super) fn timeout_secs(&self) -> u64 {
+        self.inner.timeout_ms / 1000
+    }
+
+    fn should_ping_at(&self, now_ms: u64) -> bool {
+        let quiet_ms = self.inner.timeout_ms / 3;
+        let last_received = self.inner.last_received_ms.load(Ordering::Relaxed);
+        let last_ping = self.inner.last_ping_ms.load(Ordering::Relaxed);
+        now_ms.saturating_sub(last_received) >= quiet_ms
+            && now_ms.saturating_sub(last_ping) >= quiet_ms
+    }
+
+    fn stalled_at(&self, now_ms: u64) -> bool {
+        let last_received = self.inner.last_received_ms.load(Ordering::Relaxed);
+        let last_sent = self.inner.last_sent_ms.load(Ordering::Relaxed);
+        last_sent > last_received && now_ms.saturating_sub(last_received) >= self.inner.timeout_ms
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.inner.started.elapsed().as_millis() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heartbeat(received_ms: u64, sent_ms: u64) -> Heartbeat {
+        let heartbeat = Heartbeat::new(Duration::from_secs(20));
+        heartbeat
+            .inner
+            .last_received_ms
+            .store(received_ms, Ordering::Relaxed);
+        heartbeat
+            .inner
+            .last_sent_ms
+            .store(sent_ms, Ordering::Relaxed);
+        heartbeat
+    }
+
+    #[test]
+    fn test_stalled_only_while_sending_without_reply() {
+        // Audio went out after the last reply, for longer than the timeout
+        assert!(heartbeat(1_000, 25_000).stalled_at(26_000));
+        // Not long enough yet
+        assert!(!heartbeat(1_000, 15_000).stalled_at(20_000));
+        // Nothing was sent since the last reply, e.g. while paused
+        assert!(!heartbeat(30_000, 29_000).stalled_at(60_000));
+    }
+
+    #[test]
+    fn test_pings_after_a_third_of_the_timeout() {
+        let heartbeat = heartbeat(10_000, 10_000);
+        assert!(!heartbeat.should_ping_at(15_000));
+        assert!(heartbeat.should_ping_at(16_667));
+
+        // Not again until another third has passed
+        heartbeat
+            .inner
+            .last_ping_ms
+            .store(16_667, Ordering::Relaxed);
+        assert!(!heartbeat.should_ping_at(20_000));
+        assert!(heartbeat.should_ping_at(23_334));
+    }
+}
@@ -0,0 +1,115 @@
+//! Switching the transcription language during a session
+//!
+//! For meetings that move between languages, the language can change while
+//! a recording runs. The open connection is sent a session update with the
+//! new language, so speech from then on is transcribed in it, and a
+//! reconnect starts in the latest language. The session tags the segments
+//! committed after a switch, so polishing can tell the sections apart.
+
+use std::future;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use super::session::{SessionCommand, SessionHandle};
+
+/// Builds the session update message for a language, or none for automatic
+type SessionUpdate = Box<dyn Fn(Option<&str>) -> Result<String, String> + Send>;
+
+/// Shared language of one transcription session
+#[derive(Debug)]
+pub struct LanguageSwitch {
+    language: watch::Sender<String>,
+    session: SessionHandle,
+}
+
+impl LanguageSwitch {
+    /// Start transcribing in `language_code` (empty for automatic)
+    pub(super) fn new(language_code: String, session: SessionHandle) -> Self {
+        session.send(SessionCommand::SwitchLanguage(language_code.clone()));
+        Self {
+            language: watch::Sender::new(language_code),
+            session,
+        }
+    }
+
+    /// Transcribe in `code` from now on; false if it already is
+    pub fn switch(&self, code: &str) -> bool {
+        let switched = self.language.send_if_modified(|current| {
+            if current == code {
+                return false;
+            }
+            *current = code.to_string();
+            true
+        });
+        if switched {
+            self.session
+                .send(SessionCommand::SwitchLanguage(code.to_string()));
+        }
+        switched
+    }
+
+    /// Language code to connect with, None for automatic
+    pub(super) fn current(&self) -> Option<String> {
+        Some(self.language.borrow().clone()).filter(|code| !code.is_empty())
+    }
+
+    /// Session updates for one connection, built by `session_update`
+    pub(super) fn updates(
+        &self,
+        session_update: impl Fn(Option<&str>) -> Result<String, String> + Send + 'static,
+    ) -> LanguageUpdates {
+        LanguageUpdates {
+            language: self.language.subscribe(),
+            session_update: Box::new(session_update),
+        }
+    }
+}
+
+/// Language switches for a connection's send task
+pub(super) struct LanguageUpdates {
+    language: watch::Receiver<String>,
+    session_update: SessionUpdate,
+}
+
+impl LanguageUpdates {
+    /// Wait for the next switch and return the session update to send
+    ///
+    /// Never returns once the session is gone.
+    pub(super) async fn next(&mut self) -> Option<String> {
+        if self.language.changed().await.is_err() {
+            return future::pending().await;
+        }
+        let code = self.language.borrow_and_update().clone();
+        info!("Switching the transcription language to {:?}", code);
+        let language = Some(code.as_str()).filter(|code| !code.is_empty());
+        match (self.session_update)(language) {
+            Ok(update) => Some(update),
+            Err(e) => {
+                warn!("Failed to build the language session update: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::broadcast;
+
+    #[tokio::test]
+    async fn test_switch_sends_an_update_and_tags_the_session() {
+        let (event_tx, _) = broadcast::channel(16);
+        let session = SessionHandle::spawn(event_tx);
+        let switch = LanguageSwitch::new("en".to_string(), session.clone());
+        let mut updates = switch.updates(|language| Ok(format!("{:?}", language)));
+
+        assert!(!switch.switch("en"));
+        assert!(switch.switch("no"));
+        assert_eq!(updates.next().await.as_deref(), Some("Some(\"no\")"));
+        assert_eq!(switch.current().as_deref(), Some("no"));
+
+        let languages = session.settled().await.language_switches;
+        assert_eq!(languages, [(0, "no".to_string())]);
+    }
+}
@@ -0,0 +1,207 @@
+//! Latency from audio capture to the transcript on screen
+//!
+//! Every audio chunk carries the instant it was captured. As chunks are sent,
+//! their end position in the connection's audio stream is remembered, so when
+//! the service reports where an utterance ended (`speech_stopped`) the capture
+//! time of that audio is known. The first transcript text shown for the
+//! utterance, usually a partial, gives one latency sample.
+//!
+//! The Stats tab in Settings shows the median of the most recent samples along
+//! with the provider and chunk duration they were measured with. Nothing is
+//! stored; the figure covers the latest recording only.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::audio::AudioChunk;
+
+/// Samples kept for the rolling figure
+const MAX_SAMPLES: usize = 20;
+
+/// Sent audio remembered for looking up capture times, in milliseconds
+const TIMELINE_MS: f64 = 30_000.0;
+
+/// Capture times of sent audio and the latencies measured from them
+#[derive(Debug)]
+struct Tracker {
+    /// End of each sent chunk in the connection's audio stream (ms) and when it was captured
+    timeline: VecDeque<(f64, Instant)>,
+    /// Audio sent on the current connection, in milliseconds
+    sent_ms: f64,
+    /// Capture time of the end of the utterance waiting for its first text
+    pending: Option<Instant>,
+    samples: VecDeque<Duration>,
+    /// Provider and chunk duration of the recording being measured
+    label: String,
+}
+
+impl Tracker {
+    const fn new() -> Self {
+        Self {
+            timeline: VecDeque::new(),
+            sent_ms: 0.0,
+            pending: None,
+            samples: VecDeque::new(),
+            label: String::new(),
+        }
+    }
+
+    /// The service counts audio from zero on every connection
+    fn connection_started(&mut self) {
+        self.timeline.clear();
+        self.sent_ms = 0.0;
+        self.pending = None;
+    }
+
+    fn audio_sent(&mut self, duration_ms: f64, captured_at: Instant) {
+        self.sent_ms += duration_ms;
+        self.timeline.push_back((self.sent_ms, captured_at));
+        while self
+            .timeline
+            .front()
+            .is_some_and(|(end_ms, _)| self.sent_ms - end_ms > TIMELINE_MS)
+        {
+            self.timeline.pop_front();
+        }
+    }
+
+    /// Remember when the audio at `audio_end_ms` was captured
+    fn speech_stopped(&mut self, audio_end_ms: f64) {
+        self.pending = self
+            .timeline
+            .iter()
+            .find(|(end_ms, _)| *end_ms >= audio_end_ms)
+            .map(|(_, captured_at)| *captured_at);
+    }
+
+    fn transcript_shown(&mut self, now: Instant) {
+        let Some(captured_at) = self.pending.take() else {
+            return;
+        };
+        self.samples
+            .push_back(now.saturating_duration_since(captured_at));
+        if self.samples.len() > MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+    }
+
+    fn median(&self) -> Option<Duration> {
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort();
+        sorted.get(sorted.len() / 2).copied()
+    }
+
+    fn summary(&self) -> String {
+        let Some(median) = self.median() else {
+            return "Partial transcript latency: not measured yet".to_string();
+        };
+        format!(
+            "Partial transcript latency: {} ms median over the last {} utterances ({})",
+            median.as_millis(),
+            self.samples.len(),
+            self.label
+        )
+    }
+}
+
+static TRACKER: Mutex<Tracker> = Mutex::new(Tracker::new());
+
+fn with_tracker(f: impl FnOnce(&mut Tracker)) {
+    if let Ok(mut tracker) = TRACKER.lock() {
+        f(&mut tracker);
+    }
+}
+
+/// Start a new rolling figure for a recording, described by `label`
+/// (e.g. "OpenAI, 100 ms chunks")
+pub(crate) fn start_measuring(label: String) {
+    with_tracker(|tracker| {
+        *tracker = Tracker {
+            label,
+            ..Tracker::new()
+        }
+    });
+}
+
+/// Transcript text for the current utterance was handed to the overlay
+pub(crate) fn transcript_shown() {
+    let now = Instant::now();
+    with_tracker(|tracker| tracker.transcript_shown(now));
+}
+
+/// One-line description of the rolling latency for the Stats tab
+pub(crate) fn latency_summary() -> String {
+    TRACKER
+        .lock()
+        .map(|tracker| tracker.summary())
+        .unwrap_or_default()
+}
+
+pub(super) fn connection_started() {
+    with_tracker(Tracker::connection_started);
+}
+
+pub(super) fn audio_sent(chunk: &AudioChunk) {
+    let duration_ms = chunk.samples.len() as f64 * 1000.0 / chunk.sample_rate as f64;
+    with_tracker(|tracker| tracker.audio_sent(duration_ms, chunk.captured_at));
+}
+
+pub(super) fn speech_stopped(audio_end_ms: u64) {
+    with_tracker(|tracker| tracker.speech_stopped(audio_end_ms as f64));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_from_end_of_speech_to_first_text() {
+        let start = Instant::now();
+        let mut tracker = Tracker::new();
+        for i in 1..=10 {
+            tracker.audio_sent(100.0, start + Duration::from_millis(i * 100));
+        }
+
+        // Speech ended 450 ms into the stream, inside the chunk captured at 500 ms
+        tracker.speech_stopped(450.0);
+        tracker.transcript_shown(start + Duration::from_millis(1200));
+        // Later partials of the same utterance aren't counted again
+        tracker.transcript_shown(start + Duration::from_millis(1500));
+
+        assert_eq!(
+            tracker.samples.iter().copied().collect::<Vec<_>>(),
+            vec![Duration::from_millis(700)]
+        );
+    }
+
+    #[test]
+    fn test_reconnecting_restarts_the_stream_position() {
+        let start = Instant::now();
+        let mut tracker = Tracker::new();
+        tracker.audio_sent(100.0, start);
+        tracker.speech_stopped(50.0);
+        tracker.connection_started();
+
+        tracker.transcript_shown(start + Duration::from_secs(1));
+        assert!(tracker.samples.is_empty());
+
+        tracker.audio_sent(100.0, start + Duration::from_millis(100));
+        tracker.speech_stopped(50.0);
+        tracker.transcript_shown(start + Duration::from_millis(400));
+        assert_eq!(tracker.median(), Some(Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_keeps_the_most_recent_samples() {
+        let start = Instant::now();
+        let mut tracker = Tracker::new();
+        for i in 0..(MAX_SAMPLES as u64 + 5) {
+            tracker.audio_sent(100.0, start);
+            tracker.speech_stopped(tracker.sent_ms);
+            tracker.transcript_shown(start + Duration::from_millis(i));
+        }
+        assert_eq!(tracker.samples.len(), MAX_SAMPLES);
+        assert_eq!(tracker.samples.front(), Some(&Duration::from_millis(5)));
+    }
+}
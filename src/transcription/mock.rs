@@ -0,0 +1,245 @@
+//! Replay transcription provider for the demo session, development and tests
+//!
+//! The demo session replays the bundled `resources/demo/session.json`. Built
+//! with the `mock-provider` feature, and when `VISSPER_MOCK_TRANSCRIPT`
+//! points at a JSON fixture, recordings skip the microphone and the realtime
+//! API and replay the fixture instead, so the UI and recording flow can be
+//! exercised without credentials, network or audio hardware:
+//!
+//! ```sh
+//! VISSPER_MOCK_TRANSCRIPT=resources/fixtures/mock_session.json \
+//!     cargo run --features mock-provider
+//! ```
+//!
+//! A fixture is a list of steps, each waiting `delay_ms` before emitting one
+//! event:
+//!
+//! ```json
+//! [
+//!   { "delay_ms": 500, "type": "partial", "text": "Good morning" },
+//!   { "delay_ms": 300, "type": "committed", "text": "Good morning everyone." },
+//!   { "type": "connection_lost" },
+//!   { "type": "reconnecting", "attempt": 1 },
+//!   { "type": "reconnected" },
+//!   { "type": "error", "message": "Simulated server error" }
+//! ]
+//! ```
+
+use serde::Deserialize;
+#[cfg(feature = "mock-provider")]
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+use tracing::info;
+
+use super::{
+    SessionCommand, SessionHandle, TranscriptEvent, TranscriptionClient, TranscriptionError,
+};
+use crate::audio::AudioChunk;
+
+/// Environment variable holding the fixture path
+#[cfg(feature = "mock-provider")]
+pub const MOCK_TRANSCRIPT_ENV: &str = "VISSPER_MOCK_TRANSCRIPT";
+
+/// Event emitted by one fixture step
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum MockEvent {
+    Partial { text: String },
+    Committed { text: String },
+    Error { message: String },
+    ConnectionLost,
+    Reconnecting { attempt: u32 },
+    Reconnected,
+    ReconnectFailed,
+}
+
+impl From<MockEvent> for TranscriptEvent {
+    fn from(event: MockEvent) -> Self {
+        match event {
+            MockEvent::Partial { text } => TranscriptEvent::PartialTranscript { text },
+            MockEvent::Committed { text } => TranscriptEvent::CommittedTranscript { text },
+            MockEvent::Error { message } => TranscriptEvent::Error { message },
+            MockEvent::ConnectionLost => TranscriptEvent::ConnectionLost,
+            MockEvent::Reconnecting { attempt } => TranscriptEvent::Reconnecting { attempt },
+            MockEvent::Reconnected => TranscriptEvent::Reconnected,
+            MockEvent::ReconnectFailed => TranscriptEvent::ReconnectFailed,
+        }
+    }
+}
+
+/// One fixture step
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct MockStep {
+    #[serde(default)]
+    delay_ms: u64,
+    #[serde(flatten)]
+    event: MockEvent,
+}
+
+/// Sample meeting replayed by the demo session
+const DEMO_SESSION: &str = include_str!("../../resources/demo/session.json");
+
+/// Canned sequence of transcript events
+#[derive(Debug, Clone)]
+pub struct MockFixture {
+    steps: Vec<MockStep>,
+}
+
+impl MockFixture {
+    /// Parse a fixture from JSON
+    pub fn from_json(json: &str) -> Result<Self, TranscriptionError> {
+        serde_json::from_str(json)
+            .map(|steps| Self { steps })
+            .map_err(|e| TranscriptionError::InvalidFixture(e.to_string()))
+    }
+
+    /// The sample meeting replayed by the demo session
+    pub fn demo() -> Result<Self, TranscriptionError> {
+        Self::from_json(DEMO_SESSION)
+    }
+
+    /// How long replaying every step takes
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.steps.iter().map(|step| step.delay_ms).sum())
+    }
+
+    /// Read a fixture file
+    #[cfg(feature = "mock-provider")]
+    pub fn load(path: &Path) -> Result<Self, TranscriptionError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| TranscriptionError::InvalidFixture(format!("{:?}: {}", path, e)))?;
+        Self::from_json(&json)
+    }
+
+    /// The fixture named by `VISSPER_MOCK_TRANSCRIPT`, if set
+    #[cfg(feature = "mock-provider")]
+    pub fn from_env() -> Option<Result<Self, TranscriptionError>> {
+        let path = std::env::var_os(MOCK_TRANSCRIPT_ENV)?;
+        Some(Self::load(Path::new(&path)))
+    }
+}
+
+impl TranscriptionClient {
+    /// Replay a fixture as if it came from the realtime API.
+    ///
+    /// Audio chunks are drained and discarded. Like the real providers, this
+    /// returns once the audio channel closes, i.e. when recording stops.
+    pub async fn start_mock(
+        &self,
+        fixture: &MockFixture,
+        mut audio_rx: mpsc::Receiver<AudioChunk>,
+    ) -> Result<(), TranscriptionError> {
+        info!(steps = fixture.steps.len(), "Replaying mock transcription");
+
+        let replay = replay(fixture, self.session.clone(), self.should_stop.clone());
+        tokio::pin!(replay);
+        let mut replay_done = false;
+
+        loop {
+            tokio::select! {
+                _ = &mut replay, if !replay_done => {
+                    info!("Mock transcription replay finished");
+                    replay_done = true;
+                }
+                chunk = audio_rx.recv() => {
+                    if chunk.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        info!("Mock transcription session ended");
+        Ok(())
+    }
+}
+
+/// Emit each step after its delay, updating the session like the real receive tasks
+async fn replay(fixture: &MockFixture, session: SessionHandle, should_stop: Arc<AtomicBool>) {
+    for step in &fixture.steps {
+        sleep(Duration::from_millis(step.delay_ms)).await;
+        if should_stop.load(Ordering::SeqCst) {
+            return;
+        }
+        match step.event.clone() {
+            MockEvent::Partial { text } => session.send(SessionCommand::Partial(text)),
+            MockEvent::Committed { text } => session.send(SessionCommand::Commit(text)),
+            event => session.notify(event.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION_FIXTURE: &str = include_str!("../../resources/fixtures/mock_session.json");
+
+    #[test]
+    fn test_bundled_fixture_parses() {
+        let fixture = MockFixture::from_json(SESSION_FIXTURE).unwrap();
+        assert!(!fixture.steps.is_empty());
+        assert!(fixture
+            .steps
+            .iter()
+            .any(|step| step.event == MockEvent::Reconnected));
+    }
+
+    #[test]
+    fn test_demo_session_parses() {
+        let fixture = MockFixture::demo().unwrap();
+        assert!(fixture
+            .steps
+            .iter()
+            .any(|step| matches!(step.event, MockEvent::Committed { .. })));
+        assert!(fixture.duration() > Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_invalid_fixture_is_rejected() {
+        assert!(MockFixture::from_json(r#"[{"type": "shout", "text": "hi"}]"#).is_err());
+        assert!(MockFixture::from_json("{}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replay_drives_session_and_events() {
+        let fixture = MockFixture::from_json(
+            r#"[
+                {"delay_ms": 10, "type": "partial", "text": "Hello"},
+                {"delay_ms": 10, "type": "committed", "text": "Hello world."},
+                {"type": "connection_lost"},
+                {"type": "reconnecting", "attempt": 1},
+                {"type": "reconnected"},
+                {"delay_ms": 10, "type": "committed", "text": "Second line."}
+            ]"#,
+        )
+        .unwrap();
+        let client = TranscriptionClient::new_azure("en".to_string());
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = mpsc::channel(4);
+
+        let run = tokio::spawn(async move {
+            client.start_mock(&fixture, audio_rx).await.unwrap();
+            client.settled_session().await
+        });
+
+        let mut received = Vec::new();
+        while received.len() < 6 {
+            received.push(events.recv().await.unwrap());
+        }
+        drop(audio_tx);
+        let session = run.await.unwrap();
+
+        assert!(matches!(
+            received[0],
+            TranscriptEvent::PartialTranscript { ref text } if text == "Hello"
+        ));
+        assert!(matches!(received[4], TranscriptEvent::Reconnected));
+        assert_eq!(session.full_transcript(), "Hello world. Second line.");
+        assert_eq!(session.partial_transcript, None);
+    }
+}
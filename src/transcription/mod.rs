@@ -3,21 +3,55 @@
 //! Handles WebSocket connection to Azure OpenAI or OpenAI Realtime API
 //! for STT using GPT-4o Transcribe. Includes automatic reconnection on connection loss.
 
+mod audio_append;
 mod azure_connection;
 mod azure_messages;
+mod connection_control;
+mod debug_log;
+mod dedup;
 mod error;
+mod failover;
+mod formatting;
+mod heartbeat;
 mod helpers;
+mod language_switch;
+mod latency;
+mod mock;
+mod noise_reduction;
+mod normalize;
 mod openai_connection;
 mod openai_messages;
+mod prewarm;
+#[cfg(test)]
+mod realtime_harness;
 mod session;
+mod session_info;
+mod usage;
 
+pub use audio_append::AudioEncoding;
+pub use connection_control::ConnectionControl;
+pub(crate) use debug_log::{debug_log_revision, debug_log_text};
 pub use error::TranscriptionError;
-pub use session::TranscriptionSession;
+pub(crate) use failover::AudioRelay;
+pub(crate) use heartbeat::{DEFAULT_STALL_TIMEOUT_SECS, STALL_TIMEOUT_PRESETS};
+pub use language_switch::LanguageSwitch;
+pub(crate) use latency::{latency_summary, start_measuring, transcript_shown};
+pub use mock::MockFixture;
+#[cfg(feature = "mock-provider")]
+pub use mock::MOCK_TRANSCRIPT_ENV;
+pub(crate) use noise_reduction::NoiseReduction;
+pub(crate) use normalize::{NormalizeOption, NormalizeOptions, Normalizer};
+pub(crate) use openai_messages::TranscribeModel;
+pub(crate) use prewarm::{prewarm_azure, prewarm_openai};
+pub use session::{SessionCommand, SessionHandle, TranscriptionSession};
+pub use session_info::SessionInfo;
+pub(crate) use usage::{data_usage, start_counting};
 
 use crate::audio::AudioChunk;
 use futures_util::StreamExt;
+use heartbeat::Heartbeat;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{broadcast, mpsc};
 use tokio::time::{sleep, timeout};
@@ -41,6 +75,14 @@ pub enum TranscriptEvent {
     Reconnected,
     /// Failed to reconnect after max attempts
     ReconnectFailed,
+    /// A screenshot, marker or note was inserted into the transcript
+    SegmentInserted,
+    /// The provider gave up (quota exceeded or reconnecting failed)
+    ProviderFailed,
+    /// Transcription now runs on `provider` (e.g. "Azure")
+    ProviderActive { provider: String },
+    /// The provider created or updated the session with this configuration
+    SessionConfigured { info: SessionInfo },
 }
 
 /// Maximum number of reconnection attempts
@@ -51,10 +93,14 @@ const RECONNECT_DELAY_SECS: u64 = 2;
 
 /// Transcription client for managing Azure STT sessions
 pub struct TranscriptionClient {
-    language_code: String,
-    session: Arc<Mutex<TranscriptionSession>>,
+    language: Arc<LanguageSwitch>,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+    stall_timeout: Duration,
+    session: SessionHandle,
     event_tx: broadcast::Sender<TranscriptEvent>,
     should_stop: Arc<AtomicBool>,
+    control: Arc<ConnectionControl>,
 }
 
 impl TranscriptionClient {
@@ -64,11 +110,16 @@ impl TranscriptionClient {
     /// * `language_code` - Language code for transcription (e.g., "en", "no", "da", "fi", "de")
     pub fn new_azure(language_code: String) -> Self {
         let (event_tx, _) = broadcast::channel(100);
+        let session = SessionHandle::spawn(event_tx.clone());
         Self {
-            language_code,
-            session: Arc::new(Mutex::new(TranscriptionSession::default())),
+            language: Arc::new(LanguageSwitch::new(language_code, session.clone())),
+            encoding: AudioEncoding::default(),
+            noise_reduction: NoiseReduction::default(),
+            stall_timeout: Duration::from_secs(DEFAULT_STALL_TIMEOUT_SECS.into()),
+            session,
             event_tx,
             should_stop: Arc::new(AtomicBool::new(false)),
+            control: Arc::new(ConnectionControl::default()),
         }
     }
 
@@ -78,11 +129,59 @@ impl TranscriptionClient {
     /// * `language_code` - Language code for transcription (e.g., "en", "no", "da", "fi", "de")
     pub fn new_openai(language_code: String) -> Self {
         let (event_tx, _) = broadcast::channel(100);
+        let session = SessionHandle::spawn(event_tx.clone());
         Self {
-            language_code,
-            session: Arc::new(Mutex::new(TranscriptionSession::default())),
+            language: Arc::new(LanguageSwitch::new(language_code, session.clone())),
+            encoding: AudioEncoding::default(),
+            noise_reduction: NoiseReduction::default(),
+            stall_timeout: Duration::from_secs(DEFAULT_STALL_TIMEOUT_SECS.into()),
+            session,
             event_tx,
             should_stop: Arc::new(AtomicBool::new(false)),
+            control: Arc::new(ConnectionControl::default()),
+        }
+    }
+
+    /// Send audio in `encoding` instead of 16-bit PCM
+    ///
+    /// The audio chunks must be captured at the encoding's sample rate.
+    pub fn with_encoding(mut self, encoding: AudioEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Ask the provider for `noise_reduction` instead of near field
+    pub(crate) fn with_noise_reduction(mut self, noise_reduction: NoiseReduction) -> Self {
+        self.noise_reduction = noise_reduction;
+        self
+    }
+
+    /// Reconnect when audio is sent for `stall_timeout` without a reply
+    pub(crate) fn with_stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.stall_timeout = stall_timeout;
+        self
+    }
+
+    /// Write spoken numbers, dates and amounts in committed text in digits
+    pub(crate) fn with_normalizer(self, normalizer: Option<Normalizer>) -> Self {
+        self.session.send(SessionCommand::Normalize(normalizer));
+        self
+    }
+
+    /// Client for another provider that continues this client's recording
+    ///
+    /// Shares the transcript, subscribers and pause control, so a failover
+    /// adds to the same session.
+    pub fn continuing(&self) -> Self {
+        Self {
+            language: self.language.clone(),
+            encoding: self.encoding,
+            noise_reduction: self.noise_reduction,
+            stall_timeout: self.stall_timeout,
+            session: self.session.clone(),
+            event_tx: self.event_tx.clone(),
+            should_stop: self.should_stop.clone(),
+            control: self.control.clone(),
         }
     }
 
@@ -91,24 +190,26 @@ impl TranscriptionClient {
         self.event_tx.subscribe()
     }
 
-    /// Get the current session data
-    ///
-    /// Returns the session data or a default if the mutex is poisoned.
-    pub fn session(&self) -> TranscriptionSession {
-        match self.session.lock() {
-            Ok(session) => session.clone(),
-            Err(poisoned) => {
-                warn!("Session mutex was poisoned, recovering data");
-                poisoned.into_inner().clone()
-            }
-        }
+    /// Get the session data once all pending updates are applied
+    pub async fn settled_session(&self) -> TranscriptionSession {
+        self.session.settled().await
     }
 
-    /// Get a reference to the session Arc for sharing
-    pub fn session_arc(&self) -> Arc<Mutex<TranscriptionSession>> {
+    /// Get a handle to the session actor for sharing
+    pub fn session_handle(&self) -> SessionHandle {
         self.session.clone()
     }
 
+    /// Get a handle for switching the language during the session
+    pub fn language_switch(&self) -> Arc<LanguageSwitch> {
+        self.language.clone()
+    }
+
+    /// Get a handle for pausing and resuming the connection
+    pub fn connection_control(&self) -> Arc<ConnectionControl> {
+        self.control.clone()
+    }
+
     /// Start an Azure OpenAI Realtime transcription session
     ///
     /// Connects directly to Azure OpenAI Realtime API for STT using GPT-4o Transcribe.
@@ -116,28 +217,32 @@ impl TranscriptionClient {
     /// # Arguments
     /// * `endpoint_url` - Azure OpenAI endpoint URL (e.g., "https://myresource.openai.azure.com")
     /// * `stt_deployment` - Deployment name for STT (e.g., "gpt-4o-transcribe")
+    /// * `api_version` - Realtime API version (e.g., "2024-10-01-preview")
     /// * `api_key` - Azure API key
     /// * `audio_rx` - Receiver for audio chunks from the capture module
     pub async fn start_azure(
         &self,
         endpoint_url: &str,
         stt_deployment: &str,
+        api_version: &str,
         api_key: &str,
         mut audio_rx: mpsc::Receiver<AudioChunk>,
     ) -> Result<(), TranscriptionError> {
         use azure_connection::{
             build_azure_ws_request, build_azure_ws_url, resend_azure_buffered_chunks,
-            send_session_init, spawn_azure_receive_task, spawn_azure_send_task,
+            send_session_init, session_update, spawn_azure_receive_task, spawn_azure_send_task,
         };
 
         // Build Azure WebSocket URL
-        let ws_url = build_azure_ws_url(endpoint_url, stt_deployment);
+        let ws_url = build_azure_ws_url(endpoint_url, stt_deployment, api_version);
+        let noise_reduction = self.noise_reduction.for_azure(api_version);
+        let language_code = self.language.current().unwrap_or_default();
 
         info!(
             endpoint_url = %endpoint_url,
             stt_deployment = %stt_deployment,
             ws_url = %ws_url,
-            language_code = %self.language_code,
+            language_code = %language_code,
             "Connecting to Azure OpenAI Realtime for STT"
         );
 
@@ -148,13 +253,21 @@ impl TranscriptionClient {
             .ok_or_else(|| TranscriptionError::ConnectionError("Invalid URL: no host".to_string()))?
             .to_string();
 
+        let warm_key = prewarm::WarmKey::new(
+            &ws_url,
+            stt_deployment,
+            &language_code,
+            api_key,
+            self.encoding,
+            noise_reduction,
+        );
+
         // Create internal audio buffer channel for reconnection support
         let (audio_buffer_tx, mut audio_buffer_rx) = mpsc::channel::<AudioChunk>(1000);
 
         let session = self.session.clone();
-        let event_tx = self.event_tx.clone();
         let should_stop = self.should_stop.clone();
-        let language_code = self.language_code.clone();
+        let control = self.control.clone();
 
         // Forward audio from external channel to internal buffer
         let should_stop_forwarder = should_stop.clone();
@@ -193,6 +306,13 @@ impl TranscriptionClient {
                 break;
             }
 
+            // Stay disconnected while paused (e.g. while the Mac sleeps)
+            if control.is_paused() {
+                info!("Azure transcription paused, waiting to resume");
+                control.resumed().await;
+                reconnect_attempts = 0;
+            }
+
             // Handle reconnection logic
             if !is_first_connection {
                 reconnect_attempts += 1;
@@ -201,87 +321,125 @@ impl TranscriptionClient {
                         "Failed to reconnect to Azure after {} attempts",
                         MAX_RECONNECT_ATTEMPTS
                     );
-                    let _ = event_tx.send(TranscriptEvent::ReconnectFailed);
+                    debug_log::connection(format!(
+                        "Gave up after {} reconnect attempts",
+                        MAX_RECONNECT_ATTEMPTS
+                    ));
+                    session.notify(TranscriptEvent::ReconnectFailed);
+                    session.notify(TranscriptEvent::ProviderFailed);
                     break;
                 }
                 info!(
                     "Reconnecting to Azure STT (attempt {}/{})",
                     reconnect_attempts, MAX_RECONNECT_ATTEMPTS
                 );
-                let _ = event_tx.send(TranscriptEvent::Reconnecting {
+                debug_log::connection(format!(
+                    "Reconnecting to Azure (attempt {}/{})",
+                    reconnect_attempts, MAX_RECONNECT_ATTEMPTS
+                ));
+                session.notify(TranscriptEvent::Reconnecting {
                     attempt: reconnect_attempts,
                 });
                 sleep(Duration::from_secs(RECONNECT_DELAY_SECS)).await;
             } else {
                 info!("Connecting to Azure STT: {}", ws_url);
+                debug_log::connection("Connecting to Azure");
             }
 
-            // Build WebSocket request with Azure auth
-            let request = match build_azure_ws_request(&ws_url, &host, api_key) {
-                Ok(r) => r,
-                Err(e) => {
-                    error!("Failed to build Azure WebSocket request: {}", e);
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionError(e));
-                    }
-                    is_first_connection = false;
-                    continue;
-                }
+            // A connection prepared ahead of time is already connected and initialized
+            let warm = if is_first_connection {
+                prewarm::take(&warm_key).await
+            } else {
+                None
             };
+            let prewarmed = warm.is_some();
 
-            // Attempt connection with timeout
-            let ws_result = timeout(
-                Duration::from_secs(error::WS_CONNECT_TIMEOUT_SECS),
-                connect_async(request),
-            )
-            .await;
-
-            let ws_stream = match ws_result {
-                Ok(Ok((stream, _response))) => stream,
-                Ok(Err(e)) => {
-                    error!("Azure WebSocket connection failed: {}", e);
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionError(e.to_string()));
-                    }
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                    is_first_connection = false;
-                    continue;
+            let ws_stream = match warm {
+                Some(stream) => {
+                    info!("Using prepared Azure connection");
+                    debug_log::connection("Using the prepared connection");
+                    stream
                 }
-                Err(_) => {
-                    error!("Azure WebSocket connection timed out");
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionTimeout);
+                None => {
+                    // Build WebSocket request with Azure auth
+                    let request = match build_azure_ws_request(&ws_url, &host, api_key) {
+                        Ok(r) => r,
+                        Err(e) => {
+                            error!("Failed to build Azure WebSocket request: {}", e);
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionError(e));
+                            }
+                            is_first_connection = false;
+                            continue;
+                        }
+                    };
+
+                    // Attempt connection with timeout
+                    let ws_result = timeout(
+                        Duration::from_secs(error::WS_CONNECT_TIMEOUT_SECS),
+                        connect_async(request),
+                    )
+                    .await;
+
+                    match ws_result {
+                        Ok(Ok((stream, _response))) => stream,
+                        Ok(Err(e)) => {
+                            error!("Azure WebSocket connection failed: {}", e);
+                            debug_log::connection(format!("Connection failed: {}", e));
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionError(e.to_string()));
+                            }
+                            session.notify(TranscriptEvent::ConnectionLost);
+                            is_first_connection = false;
+                            continue;
+                        }
+                        Err(_) => {
+                            error!("Azure WebSocket connection timed out");
+                            debug_log::connection("Connection timed out");
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionTimeout);
+                            }
+                            session.notify(TranscriptEvent::ConnectionLost);
+                            is_first_connection = false;
+                            continue;
+                        }
                     }
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                    is_first_connection = false;
-                    continue;
                 }
             };
 
             info!("Connected to Azure OpenAI Realtime");
+            debug_log::connection("Connected");
 
             if !is_first_connection {
-                let _ = event_tx.send(TranscriptEvent::Reconnected);
+                session.notify(TranscriptEvent::Reconnected);
                 reconnect_attempts = 0;
             }
             is_first_connection = false;
 
+            latency::connection_started();
             let (mut ws_sink, ws_stream) = ws_stream.split();
 
-            // Send session initialization
-            let language = if self.language_code.is_empty() {
-                None
-            } else {
-                Some(language_code.as_str())
-            };
-            if let Err(e) = send_session_init(&mut ws_sink, stt_deployment, language).await {
-                error!("Failed to send Azure session init: {}", e);
-                let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                continue;
+            // Send session initialization, unless the prepared connection already
+            // did, in the language switched to last
+            let language = self.language.current();
+            if !prewarmed {
+                if let Err(e) = send_session_init(
+                    &mut ws_sink,
+                    stt_deployment,
+                    language.as_deref(),
+                    self.encoding,
+                    noise_reduction,
+                )
+                .await
+                {
+                    error!("Failed to send Azure session init: {}", e);
+                    session.notify(TranscriptEvent::ConnectionLost);
+                    continue;
+                }
             }
 
             // Resend buffered audio chunks
-            if resend_azure_buffered_chunks(&mut ws_sink, &mut pending_chunks)
+            if resend_azure_buffered_chunks(&mut ws_sink, &mut pending_chunks, self.encoding)
                 .await
                 .is_err()
             {
@@ -292,27 +450,38 @@ impl TranscriptionClient {
             let (connection_lost_tx, connection_lost_rx) = mpsc::channel::<()>(1);
 
             // Spawn receive and send tasks
+            let heartbeat = Heartbeat::new(self.stall_timeout);
             let recv_task = spawn_azure_receive_task(
                 ws_stream,
                 session.clone(),
-                event_tx.clone(),
                 should_stop.clone(),
+                heartbeat.clone(),
             );
 
+            let deployment = stt_deployment.to_string();
+            let encoding = self.encoding;
+            let language_updates = self.language.updates(move |language| {
+                session_update(&deployment, language, encoding, noise_reduction)
+            });
             let send_task = spawn_azure_send_task(
                 ws_sink,
                 audio_buffer_rx,
                 connection_lost_rx,
                 should_stop.clone(),
+                self.encoding,
+                heartbeat,
+                language_updates,
             );
 
             // Wait for receive task
-            let recv_result = recv_task
-                .await
-                .unwrap_or(azure_connection::AzureReceiveResult {
-                    connection_ok: false,
-                    quota_exceeded: false,
-                });
+            let recv_result =
+                control
+                    .receive(recv_task)
+                    .await
+                    .unwrap_or(azure_connection::AzureReceiveResult {
+                        connection_ok: false,
+                        quota_exceeded: false,
+                    });
 
             // Signal send task
             let _ = connection_lost_tx.send(()).await;
@@ -332,20 +501,25 @@ impl TranscriptionClient {
             // Check if we should stop
             if should_stop.load(Ordering::SeqCst) || send_result.stopped_by_user {
                 info!("Azure transcription session ended");
+                debug_log::connection("Session ended");
                 break;
             }
 
             if recv_result.quota_exceeded {
                 info!("Azure quota exceeded - stopping transcription");
+                debug_log::connection("Quota exceeded, stopping");
+                session.notify(TranscriptEvent::ProviderFailed);
                 break;
             }
 
             if recv_result.connection_ok {
                 info!("Azure connection closed normally");
+                debug_log::connection("Connection closed");
                 break;
             }
 
             warn!("Azure connection lost, will attempt to reconnect...");
+            debug_log::connection("Connection lost");
         }
 
         let _ = audio_forwarder.await;
@@ -354,37 +528,61 @@ impl TranscriptionClient {
 
     /// Start an OpenAI Realtime transcription session
     ///
-    /// Connects directly to OpenAI Realtime API for STT using gpt-4o-transcribe.
+    /// Connects directly to OpenAI Realtime API for STT.
     ///
     /// # Arguments
+    /// * `model` - Transcription model (e.g., gpt-4o-transcribe)
     /// * `api_key` - OpenAI API key
     /// * `audio_rx` - Receiver for audio chunks from the capture module
-    pub async fn start_openai(
+    pub(crate) async fn start_openai(
+        &self,
+        model: TranscribeModel,
+        api_key: &str,
+        audio_rx: mpsc::Receiver<AudioChunk>,
+    ) -> Result<(), TranscriptionError> {
+        let ws_url = openai_connection::build_openai_ws_url();
+        self.start_openai_at(&ws_url, model, api_key, audio_rx)
+            .await
+    }
+
+    /// Run an OpenAI Realtime transcription session against `ws_url`
+    ///
+    /// Split out from `start_openai` so tests can point it at a local server.
+    async fn start_openai_at(
         &self,
+        ws_url: &str,
+        model: TranscribeModel,
         api_key: &str,
         mut audio_rx: mpsc::Receiver<AudioChunk>,
     ) -> Result<(), TranscriptionError> {
         use openai_connection::{
-            build_openai_ws_request, build_openai_ws_url, resend_openai_buffered_chunks,
-            send_session_init, spawn_openai_receive_task, spawn_openai_send_task,
+            build_openai_ws_request, resend_openai_buffered_chunks, send_session_init,
+            session_update, spawn_openai_receive_task, spawn_openai_send_task,
         };
 
-        // Build OpenAI WebSocket URL
-        let ws_url = build_openai_ws_url();
-
+        let language_code = self.language.current().unwrap_or_default();
         info!(
             ws_url = %ws_url,
-            language_code = %self.language_code,
+            model = model.api_name(),
+            language_code = %language_code,
             "Connecting to OpenAI Realtime for STT"
         );
 
+        let warm_key = prewarm::WarmKey::new(
+            ws_url,
+            model.api_name(),
+            &language_code,
+            api_key,
+            self.encoding,
+            self.noise_reduction,
+        );
+
         // Create internal audio buffer channel for reconnection support
         let (audio_buffer_tx, mut audio_buffer_rx) = mpsc::channel::<AudioChunk>(1000);
 
         let session = self.session.clone();
-        let event_tx = self.event_tx.clone();
         let should_stop = self.should_stop.clone();
-        let language_code = self.language_code.clone();
+        let control = self.control.clone();
 
         // Forward audio from external channel to internal buffer
         let should_stop_forwarder = should_stop.clone();
@@ -426,6 +624,13 @@ impl TranscriptionClient {
                 break;
             }
 
+            // Stay disconnected while paused (e.g. while the Mac sleeps)
+            if control.is_paused() {
+                info!("OpenAI transcription paused, waiting to resume");
+                control.resumed().await;
+                reconnect_attempts = 0;
+            }
+
             // Handle reconnection logic
             if !is_first_connection {
                 reconnect_attempts += 1;
@@ -434,87 +639,125 @@ impl TranscriptionClient {
                         "Failed to reconnect to OpenAI after {} attempts",
                         MAX_RECONNECT_ATTEMPTS
                     );
-                    let _ = event_tx.send(TranscriptEvent::ReconnectFailed);
+                    debug_log::connection(format!(
+                        "Gave up after {} reconnect attempts",
+                        MAX_RECONNECT_ATTEMPTS
+                    ));
+                    session.notify(TranscriptEvent::ReconnectFailed);
+                    session.notify(TranscriptEvent::ProviderFailed);
                     break;
                 }
                 info!(
                     "Reconnecting to OpenAI STT (attempt {}/{})",
                     reconnect_attempts, MAX_RECONNECT_ATTEMPTS
                 );
-                let _ = event_tx.send(TranscriptEvent::Reconnecting {
+                debug_log::connection(format!(
+                    "Reconnecting to OpenAI (attempt {}/{})",
+                    reconnect_attempts, MAX_RECONNECT_ATTEMPTS
+                ));
+                session.notify(TranscriptEvent::Reconnecting {
                     attempt: reconnect_attempts,
                 });
                 sleep(Duration::from_secs(RECONNECT_DELAY_SECS)).await;
             } else {
                 info!("Connecting to OpenAI STT: {}", ws_url);
+                debug_log::connection("Connecting to OpenAI");
             }
 
-            // Build WebSocket request with OpenAI auth
-            let request = match build_openai_ws_request(&ws_url, api_key) {
-                Ok(r) => r,
-                Err(e) => {
-                    error!("Failed to build OpenAI WebSocket request: {}", e);
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionError(e));
-                    }
-                    is_first_connection = false;
-                    continue;
-                }
+            // A connection prepared ahead of time is already connected and initialized
+            let warm = if is_first_connection {
+                prewarm::take(&warm_key).await
+            } else {
+                None
             };
+            let prewarmed = warm.is_some();
 
-            // Attempt connection with timeout
-            let ws_result = timeout(
-                Duration::from_secs(error::WS_CONNECT_TIMEOUT_SECS),
-                connect_async(request),
-            )
-            .await;
-
-            let ws_stream = match ws_result {
-                Ok(Ok((stream, _response))) => stream,
-                Ok(Err(e)) => {
-                    error!("OpenAI WebSocket connection failed: {}", e);
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionError(e.to_string()));
-                    }
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                    is_first_connection = false;
-                    continue;
+            let ws_stream = match warm {
+                Some(stream) => {
+                    info!("Using prepared OpenAI connection");
+                    debug_log::connection("Using the prepared connection");
+                    stream
                 }
-                Err(_) => {
-                    error!("OpenAI WebSocket connection timed out");
-                    if is_first_connection {
-                        return Err(TranscriptionError::ConnectionTimeout);
+                None => {
+                    // Build WebSocket request with OpenAI auth
+                    let request = match build_openai_ws_request(ws_url, api_key) {
+                        Ok(r) => r,
+                        Err(e) => {
+                            error!("Failed to build OpenAI WebSocket request: {}", e);
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionError(e));
+                            }
+                            is_first_connection = false;
+                            continue;
+                        }
+                    };
+
+                    // Attempt connection with timeout
+                    let ws_result = timeout(
+                        Duration::from_secs(error::WS_CONNECT_TIMEOUT_SECS),
+                        connect_async(request),
+                    )
+                    .await;
+
+                    match ws_result {
+                        Ok(Ok((stream, _response))) => stream,
+                        Ok(Err(e)) => {
+                            error!("OpenAI WebSocket connection failed: {}", e);
+                            debug_log::connection(format!("Connection failed: {}", e));
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionError(e.to_string()));
+                            }
+                            session.notify(TranscriptEvent::ConnectionLost);
+                            is_first_connection = false;
+                            continue;
+                        }
+                        Err(_) => {
+                            error!("OpenAI WebSocket connection timed out");
+                            debug_log::connection("Connection timed out");
+                            if is_first_connection {
+                                return Err(TranscriptionError::ConnectionTimeout);
+                            }
+                            session.notify(TranscriptEvent::ConnectionLost);
+                            is_first_connection = false;
+                            continue;
+                        }
                     }
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                    is_first_connection = false;
-                    continue;
                 }
             };
 
             info!("Connected to OpenAI Realtime");
+            debug_log::connection("Connected");
 
             if !is_first_connection {
-                let _ = event_tx.send(TranscriptEvent::Reconnected);
+                session.notify(TranscriptEvent::Reconnected);
                 reconnect_attempts = 0;
             }
             is_first_connection = false;
 
+            latency::connection_started();
             let (mut ws_sink, ws_stream) = ws_stream.split();
 
-            // Send session initialization
-            let language = if self.language_code.is_empty() {
-                None
-            } else {
-                Some(language_code.as_str())
-            };
-            if let Err(e) = send_session_init(&mut ws_sink, language).await {
-                error!("Failed to send OpenAI session init: {}", e);
-                let _ = event_tx.send(TranscriptEvent::ConnectionLost);
-                continue;
+            // Send session initialization, unless the prepared connection already
+            // did, in the language switched to last
+            let language = self.language.current();
+            if !prewarmed {
+                if let Err(e) = send_session_init(
+                    &mut ws_sink,
+                    model,
+                    language.as_deref(),
+                    self.encoding,
+                    self.noise_reduction,
+                )
+                .await
+                {
+                    error!("Failed to send OpenAI session init: {}", e);
+                    session.notify(TranscriptEvent::ConnectionLost);
+                    continue;
+                }
             }
 
             // Resend buffered audio chunks
-            if resend_openai_buffered_chunks(&mut ws_sink, &mut pending_chunks)
+            if resend_openai_buffered_chunks(&mut ws_sink, &mut pending_chunks, self.encoding)
                 .await
                 .is_err()
             {
@@ -525,27 +768,35 @@ impl TranscriptionClient {
             let (connection_lost_tx, connection_lost_rx) = mpsc::channel::<()>(1);
 
             // Spawn receive and send tasks
+            let heartbeat = Heartbeat::new(self.stall_timeout);
             let recv_task = spawn_openai_receive_task(
                 ws_stream,
                 session.clone(),
-                event_tx.clone(),
                 should_stop.clone(),
+                heartbeat.clone(),
             );
 
+            let (encoding, noise_reduction) = (self.encoding, self.noise_reduction);
+            let language_updates = self.language.updates(move |language| {
+                session_update(model, language, encoding, noise_reduction)
+            });
             let send_task = spawn_openai_send_task(
                 ws_sink,
                 audio_buffer_rx,
                 connection_lost_rx,
                 should_stop.clone(),
+                self.encoding,
+                heartbeat,
+                language_updates,
             );
 
             // Wait for receive task
-            let recv_result = recv_task
-                .await
-                .unwrap_or(openai_connection::OpenAIReceiveResult {
+            let recv_result = control.receive(recv_task).await.unwrap_or(
+                openai_connection::OpenAIReceiveResult {
                     connection_ok: false,
                     quota_exceeded: false,
-                });
+                },
+            );
 
             // Signal send task
             let _ = connection_lost_tx.send(()).await;
@@ -565,20 +816,25 @@ impl TranscriptionClient {
             // Check if we should stop
             if should_stop.load(Ordering::SeqCst) || send_result.stopped_by_user {
                 info!("OpenAI transcription session ended");
+                debug_log::connection("Session ended");
                 break;
             }
 
             if recv_result.quota_exceeded {
                 info!("OpenAI quota exceeded - stopping transcription");
+                debug_log::connection("Quota exceeded, stopping");
+                session.notify(TranscriptEvent::ProviderFailed);
                 break;
             }
 
             if recv_result.connection_ok {
                 info!("OpenAI connection closed normally");
+                debug_log::connection("Connection closed");
                 break;
             }
 
             warn!("OpenAI connection lost, will attempt to reconnect...");
+            debug_log::connection("Connection lost");
         }
 
         let _ = audio_forwarder.await;
@@ -594,8 +850,6 @@ impl TranscriptionClient {
     /// Clear the session data
     #[allow(dead_code)]
     pub fn clear_session(&self) {
-        if let Ok(mut sess) = self.session.lock() {
-            *sess = TranscriptionSession::default();
-        }
+        self.session.send(SessionCommand::Clear);
     }
 }
@@ -0,0 +1,87 @@
+//! Noise reduction the provider applies before transcribing
+//!
+//! Near field suits a headset or laptop microphone, far field a conference
+//! room microphone across the table. Azure OpenAI accepts the same setting
+//! from realtime API version 2025-04-01-preview on; with older versions it is
+//! left out of the session config.
+
+use serde::{Deserialize, Serialize};
+
+/// First Azure realtime API version with input audio noise reduction
+const AZURE_MIN_API_VERSION: &str = "2025-04-01";
+
+/// Input audio noise reduction mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum NoiseReduction {
+    /// Close-talking microphone, such as a headset
+    #[default]
+    NearField,
+    /// Microphone at a distance, such as in a conference room
+    FarField,
+    /// No noise reduction
+    Off,
+}
+
+impl NoiseReduction {
+    /// All modes, in the order shown in Settings
+    pub(crate) const ALL: [NoiseReduction; 3] = [
+        NoiseReduction::NearField,
+        NoiseReduction::FarField,
+        NoiseReduction::Off,
+    ];
+
+    /// Segment label in Settings
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            NoiseReduction::NearField => "Headset",
+            NoiseReduction::FarField => "Conference Room",
+            NoiseReduction::Off => "Off",
+        }
+    }
+
+    /// Value of the session's `input_audio_noise_reduction.type`, if any
+    pub(crate) fn api_name(self) -> Option<&'static str> {
+        match self {
+            NoiseReduction::NearField => Some("near_field"),
+            NoiseReduction::FarField => Some("far_field"),
+            NoiseReduction::Off => None,
+        }
+    }
+
+    /// The mode to request from Azure with `api_version`, off if it's too old
+    pub(crate) fn for_azure(self, api_version: &str) -> Self {
+        let date = api_version.get(..AZURE_MIN_API_VERSION.len());
+        if date.is_some_and(|date| date >= AZURE_MIN_API_VERSION) {
+            self
+        } else {
+            NoiseReduction::Off
+        }
+    }
+
+    /// Look up a mode by its index in `ALL` (the selected segment)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    /// Index in `ALL`
+    pub(crate) fn to_index(self) -> isize {
+        Self::ALL.iter().position(|m| *m == self).unwrap_or(0) as isize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_azure_noise_reduction_needs_recent_api_version() {
+        let far = NoiseReduction::FarField;
+        assert_eq!(far.for_azure("2025-04-01-preview"), far);
+        assert_eq!(far.for_azure("2025-08-28"), far);
+        assert_eq!(far.for_azure("2024-10-01-preview"), NoiseReduction::Off);
+        assert_eq!(far.for_azure(""), NoiseReduction::Off);
+    }
+}
@@ -0,0 +1,285 @@
+//! Rules for English, Norwegian (bokmål) and German
+
+use super::rules::{Currency, Rules};
+
+pub(super) static ENGLISH: Rules = Rules {
+    units: [
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ],
+    tens: [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ],
+    scales: &[
+        ("hundred", 100),
+        ("thousand", 1_000),
+        ("million", 1_000_000),
+        ("billion", 1_000_000_000),
+    ],
+    aliases: &[],
+    articles: &["a"],
+    and_word: "and",
+    units_before_tens: false,
+    ordinal_units: [
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+    ],
+    ordinal_tens: ["twentieth", "thirtieth"],
+    ordinal_aliases: &[],
+    ordinal_endings: &[],
+    digit_ordinal_suffixes: &["st", "nd", "rd", "th"],
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    day_articles: &["the"],
+    month_connectors: &["of"],
+    day_first: false,
+    currencies: &[
+        Currency {
+            words: &["dollar", "dollars"],
+            subunits: &["cent", "cents"],
+            format: "${}",
+        },
+        Currency {
+            words: &["euro", "euros"],
+            subunits: &["cent", "cents"],
+            format: "€{}",
+        },
+    ],
+    group_separator: ",",
+    decimal_separator: ".",
+};
+
+pub(super) static NORWEGIAN: Rules = Rules {
+    units: [
+        "en", "to", "tre", "fire", "fem", "seks", "sju", "åtte", "ni", "ti", "elleve", "tolv",
+        "tretten", "fjorten", "femten", "seksten", "sytten", "atten", "nitten",
+    ],
+    tens: [
+        "tjue", "tretti", "førti", "femti", "seksti", "sytti", "åtti", "nitti",
+    ],
+    scales: &[
+        ("hundre", 100),
+        ("tusen", 1_000),
+        ("million", 1_000_000),
+        ("millioner", 1_000_000),
+        ("milliard", 1_000_000_000),
+        ("milliarder", 1_000_000_000),
+    ],
+    aliases: &[
+        ("ett", 1),
+        ("én", 1),
+        ("syv", 7),
+        ("tyve", 20),
+        ("tredve", 30),
+    ],
+    articles: &[],
+    and_word: "og",
+    units_before_tens: true,
+    ordinal_units: [
+        "første",
+        "andre",
+        "tredje",
+        "fjerde",
+        "femte",
+        "sjette",
+        "sjuende",
+        "åttende",
+        "niende",
+        "tiende",
+        "ellevte",
+        "tolvte",
+        "trettende",
+        "fjortende",
+        "femtende",
+        "sekstende",
+        "syttende",
+        "attende",
+        "nittende",
+    ],
+    ordinal_tens: ["tjuende", "trettiende"],
+    ordinal_aliases: &[("syvende", 7), ("tyvende", 20), ("tredevte", 30)],
+    ordinal_endings: &[],
+    digit_ordinal_suffixes: &[],
+    months: [
+        "januar",
+        "februar",
+        "mars",
+        "april",
+        "mai",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "desember",
+    ],
+    day_articles: &["den"],
+    month_connectors: &[],
+    day_first: true,
+    currencies: &[
+        Currency {
+            words: &["krone", "kroner"],
+            subunits: &["øre"],
+            format: "{} kr",
+        },
+        Currency {
+            words: &["euro"],
+            subunits: &["cent"],
+            format: "EUR {}",
+        },
+        Currency {
+            words: &["dollar"],
+            subunits: &["cent"],
+            format: "USD {}",
+        },
+    ],
+    group_separator: "\u{a0}",
+    decimal_separator: ",",
+};
+
+pub(super) static GERMAN: Rules = Rules {
+    units: [
+        "eins",
+        "zwei",
+        "drei",
+        "vier",
+        "fünf",
+        "sechs",
+        "sieben",
+        "acht",
+        "neun",
+        "zehn",
+        "elf",
+        "zwölf",
+        "dreizehn",
+        "vierzehn",
+        "fünfzehn",
+        "sechzehn",
+        "siebzehn",
+        "achtzehn",
+        "neunzehn",
+    ],
+    tens: [
+        "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+    ],
+    scales: &[
+        ("hundert", 100),
+        ("tausend", 1_000),
+        ("million", 1_000_000),
+        ("millionen", 1_000_000),
+        ("milliarde", 1_000_000_000),
+        ("milliarden", 1_000_000_000),
+    ],
+    aliases: &[("ein", 1), ("eine", 1), ("dreissig", 30)],
+    articles: &[],
+    and_word: "und",
+    units_before_tens: true,
+    ordinal_units: [
+        "erst",
+        "zweit",
+        "dritt",
+        "viert",
+        "fünft",
+        "sechst",
+        "siebt",
+        "acht",
+        "neunt",
+        "zehnt",
+        "elft",
+        "zwölft",
+        "dreizehnt",
+        "vierzehnt",
+        "fünfzehnt",
+        "sechzehnt",
+        "siebzehnt",
+        "achtzehnt",
+        "neunzehnt",
+    ],
+    ordinal_tens: ["zwanzigst", "dreißigst"],
+    ordinal_aliases: &[("siebent", 7), ("dreissigst", 30)],
+    ordinal_endings: &["en", "er", "em", "es", "e"],
+    digit_ordinal_suffixes: &[],
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    day_articles: &[],
+    month_connectors: &[],
+    day_first: true,
+    currencies: &[
+        Currency {
+            words: &["euro"],
+            subunits: &["cent"],
+            format: "{} €",
+        },
+        Currency {
+            words: &["dollar"],
+            subunits: &["cent"],
+            format: "{} $",
+        },
+        Currency {
+            words: &["franken"],
+            subunits: &["rappen"],
+            format: "{} CHF",
+        },
+    ],
+    group_separator: ".",
+    decimal_separator: ",",
+};
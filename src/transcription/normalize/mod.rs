@@ -0,0 +1,284 @@
+//! Writing spoken numbers, dates and amounts in digits
+//!
+//! Committed segments are rewritten locally with each language's rules, so
+//! "twenty third of May" reads "May 23" in the live transcript and in what is
+//! saved, without waiting for the polish model. Each kind of rewrite can be
+//! turned on per transcription language; there are rules for English,
+//! Norwegian and German. Partial text is shown as the service sends it.
+
+mod languages;
+mod numbers;
+mod patterns;
+mod rules;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use rules::Rules;
+
+/// A kind of rewrite that can be turned on in Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NormalizeOption {
+    Numbers,
+    Dates,
+    Currencies,
+}
+
+impl NormalizeOption {
+    /// All options, in the order shown in Settings
+    pub(crate) const ALL: [NormalizeOption; 3] = [
+        NormalizeOption::Numbers,
+        NormalizeOption::Dates,
+        NormalizeOption::Currencies,
+    ];
+
+    /// Checkbox label in Settings
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            NormalizeOption::Numbers => "Numbers",
+            NormalizeOption::Dates => "Dates",
+            NormalizeOption::Currencies => "Currencies",
+        }
+    }
+
+    /// Look up an option by its index in `ALL` (the checkbox tag)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+}
+
+/// Rewrites turned on for one language
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct NormalizeOptions {
+    pub(crate) numbers: bool,
+    pub(crate) dates: bool,
+    pub(crate) currencies: bool,
+}
+
+impl NormalizeOptions {
+    /// Whether `option` is turned on
+    pub(crate) fn enabled(&self, option: NormalizeOption) -> bool {
+        match option {
+            NormalizeOption::Numbers => self.numbers,
+            NormalizeOption::Dates => self.dates,
+            NormalizeOption::Currencies => self.currencies,
+        }
+    }
+
+    /// Turn `option` on or off
+    pub(crate) fn set(&mut self, option: NormalizeOption, enabled: bool) {
+        match option {
+            NormalizeOption::Numbers => self.numbers = enabled,
+            NormalizeOption::Dates => self.dates = enabled,
+            NormalizeOption::Currencies => self.currencies = enabled,
+        }
+    }
+}
+
+/// Rewrites committed text for one transcription language
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Normalizer {
+    rules: &'static Rules,
+    options: NormalizeOptions,
+}
+
+impl Normalizer {
+    /// Languages with rules, as (code, label) in the order shown in Settings
+    pub(crate) const LANGUAGES: [(&'static str, &'static str); 3] =
+        [("en", "English"), ("no", "Norsk"), ("de", "Deutsch")];
+
+    /// Normalizer for `language`, if it has rules and any option is on
+    pub(crate) fn new(language: &str, options: NormalizeOptions) -> Option<Self> {
+        if options == NormalizeOptions::default() {
+            return None;
+        }
+        let rules = rules::rules_for(language)?;
+        Some(Self { rules, options })
+    }
+
+    /// `text` with the turned-on rewrites applied
+    pub(crate) fn apply(&self, text: &str) -> String {
+        let tokens: Vec<Token> = text.split_whitespace().map(Token::new).collect();
+        let mut words = Vec::with_capacity(tokens.len());
+        let mut changed = false;
+        let mut index = 0;
+        while index < tokens.len() {
+            let rest = &tokens[index..];
+            match self.rewrite(rest) {
+                Some((replacement, used)) => {
+                    words.push(format!(
+                        "{}{}{}",
+                        rest[0].lead,
+                        replacement,
+                        rest[used - 1].trail
+                    ));
+                    changed = true;
+                    index += used;
+                }
+                None => {
+                    words.push(rest[0].to_string());
+                    index += 1;
+                }
+            }
+        }
+
+        if changed {
+            words.join(" ")
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// The first turned-on rewrite that matches the start of `tokens`
+    fn rewrite(&self, tokens: &[Token]) -> Option<(String, usize)> {
+        let rewrites: [(bool, Rewrite); 3] = [
+            (self.options.dates, patterns::date),
+            (self.options.currencies, patterns::amount),
+            (self.options.numbers, patterns::number),
+        ];
+        rewrites
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .find_map(|(_, rewrite)| rewrite(tokens, self.rules))
+    }
+}
+
+/// Replacement for the start of some tokens, and how many it replaces
+type Rewrite = fn(&[Token], &Rules) -> Option<(String, usize)>;
+
+/// A word with the punctuation around it
+#[derive(Debug)]
+struct Token<'a> {
+    lead: &'a str,
+    core: &'a str,
+    trail: &'a str,
+}
+
+impl<'a> Token<'a> {
+    fn new(word: &'a str) -> Self {
+        let Some(start) = word.find(char::is_alphanumeric) else {
+            return Self {
+                lead: word,
+                core: "",
+                trail: "",
+            };
+        };
+        let end = word
+            .char_indices()
+            .filter(|(_, c)| c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8())
+            .next_back()
+            .unwrap_or(word.len());
+        Self {
+            lead: &word[..start],
+            core: &word[start..end],
+            trail: &word[end..],
+        }
+    }
+
+    /// The word lowercased, without hyphens
+    fn word(&self) -> String {
+        self.core.to_lowercase().replace('-', "")
+    }
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.lead, self.core, self.trail)
+    }
+}
+
+/// Whether no punctuation separates `before` from `after`
+fn joined(before: &Token, after: &Token) -> bool {
+    before.trail.is_empty() && after.lead.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: NormalizeOptions = NormalizeOptions {
+        numbers: true,
+        dates: true,
+        currencies: true,
+    };
+
+    fn normalize(language: &str, text: &str) -> String {
+        Normalizer::new(language, ALL).unwrap().apply(text)
+    }
+
+    #[test]
+    fn test_english_dates_amounts_and_numbers() {
+        assert_eq!(
+            normalize("en", "It's due on the twenty third of May."),
+            "It's due on May 23."
+        );
+        assert_eq!(
+            normalize("en", "Ship it May 1st, twenty twenty six or later"),
+            "Ship it May 1, twenty twenty six or later"
+        );
+        assert_eq!(
+            normalize("en", "From the 3rd of March two thousand and twenty six"),
+            "From March 3, 2026"
+        );
+        assert_eq!(
+            normalize("en", "It costs twenty five dollars and fifty cents."),
+            "It costs $25.50."
+        );
+        assert_eq!(
+            normalize("en", "A hundred and twelve thousand people, not five"),
+            "112,000 people, not five"
+        );
+        // Ambiguous runs of numbers and small numbers stay words
+        assert_eq!(
+            normalize("en", "Call five five five, one of twenty-two."),
+            "Call five five five, one of 22."
+        );
+        assert_eq!(
+            normalize("en", "A twenty second break, nineteen ninety"),
+            "A twenty second break, nineteen ninety"
+        );
+    }
+
+    #[test]
+    fn test_norwegian_and_german() {
+        assert_eq!(
+            normalize("no", "Møtet er den tjuetredje mai tjue tjueseks"),
+            "Møtet er 23. mai 2026"
+        );
+        assert_eq!(
+            normalize("no", "Det koster to hundre og femti kroner og femti øre"),
+            "Det koster 250,50 kr"
+        );
+        assert_eq!(normalize("no", "Fem og tjue deltakere"), "25 deltakere");
+        assert_eq!(
+            normalize(
+                "de",
+                "Am dreiundzwanzigsten Mai zahlen wir fünfundzwanzigtausend Euro"
+            ),
+            "Am 23. Mai zahlen wir 25.000 €"
+        );
+        assert_eq!(
+            normalize("de", "Seit neunzehnhundertneunzig, nicht acht"),
+            "Seit 1990, nicht acht"
+        );
+    }
+
+    #[test]
+    fn test_only_enabled_rewrites_apply() {
+        let numbers = NormalizeOptions {
+            numbers: true,
+            ..Default::default()
+        };
+        let normalizer = Normalizer::new("en", numbers).unwrap();
+        assert_eq!(
+            normalizer.apply("the twenty third of May, fifty dollars"),
+            "the twenty third of May, 50 dollars"
+        );
+        assert!(Normalizer::new("en", NormalizeOptions::default()).is_none());
+        assert!(Normalizer::new("fi", ALL).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+//! Reading spelled-out numbers
+//!
+//! A word is split into number parts ("dreiundzwanzig" is three, and,
+//! twenty), and the parts of consecutive words are added up as long as they
+//! make a well-formed number: "twenty three" and "hundre og fem" are numbers,
+//! "nineteen ninety" is two.
+
+use std::cmp::Reverse;
+
+use super::rules::Rules;
+use super::{joined, Token};
+
+/// Part of a spelled-out number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Morpheme {
+    /// One to nineteen, or a multiple of ten up to ninety
+    Value(u64),
+    /// Hundred, thousand, million...
+    Scale(u64),
+    /// "and" between parts, as in "hundred and five" or "dreiundzwanzig"
+    And,
+    /// An ordinal ending the number, as in "third" or "dritte"
+    Ordinal(u64),
+}
+
+/// A number found at the start of some tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct NumberMatch {
+    pub(super) value: u64,
+    /// Tokens the number takes
+    pub(super) tokens: usize,
+    pub(super) ordinal: bool,
+    /// Written in digits already
+    pub(super) digits: bool,
+}
+
+/// Sum of the morphemes pushed so far
+#[derive(Debug, Default)]
+struct Number {
+    units_before_tens: bool,
+    /// Thousands, millions and up
+    total: u64,
+    /// Below a thousand
+    group: u64,
+    last: Option<Morpheme>,
+    ordinal: bool,
+}
+
+impl Number {
+    /// Add `morpheme`, or return false if it can't continue the number
+    fn push(&mut self, morpheme: Morpheme) -> bool {
+        if self.ordinal {
+            return false;
+        }
+        let after_and = self.last == Some(Morpheme::And);
+        let fits = match morpheme {
+            Morpheme::Value(value) | Morpheme::Ordinal(value) => self.fits(value, after_and),
+            Morpheme::Scale(scale) => {
+                !after_and && (self.group > 0 || self.last.is_none()) && self.fits_scale(scale)
+            }
+            Morpheme::And => match self.last {
+                Some(Morpheme::Scale(_)) => true,
+                Some(Morpheme::Value(value)) => self.units_before_tens && value < 10,
+                _ => false,
+            },
+        };
+        if !fits {
+            return false;
+        }
+
+        match morpheme {
+            Morpheme::Value(value) => self.group += value,
+            Morpheme::Ordinal(value) => {
+                self.group += value;
+                self.ordinal = true;
+            }
+            Morpheme::Scale(100) => self.group = self.group.max(1) * 100,
+            Morpheme::Scale(scale) => {
+                self.total += self.group.max(1) * scale;
+                self.group = 0;
+            }
+            Morpheme::And => {}
+        }
+        self.last = Some(morpheme);
+        true
+    }
+
+    /// Whether `value` fills an empty tens or units place
+    fn fits(&self, value: u64, after_and: bool) -> bool {
+        let rest = self.group % 100;
+        match value {
+            1..=9 => rest == 0 || (rest >= 20 && rest.is_multiple_of(10) && !after_and),
+            10..=19 => rest == 0,
+            // Tens after units only as in "dreiundzwanzig"
+            _ => rest == 0 || (rest < 10 && after_and),
+        }
+    }
+
+    /// Whether `scale` is smaller than any scale before it
+    fn fits_scale(&self, scale: u64) -> bool {
+        if scale == 100 {
+            self.group < 100
+        } else {
+            self.total.is_multiple_of(scale * 1000)
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.last.is_some_and(|last| last != Morpheme::And)
+    }
+
+    fn value(&self) -> u64 {
+        self.total + self.group
+    }
+}
+
+/// The longest number at the start of `tokens`
+pub(super) fn parse_number(tokens: &[Token], rules: &Rules) -> Option<NumberMatch> {
+    let first = tokens.first()?;
+    if first.core.starts_with(|c: char| c.is_ascii_digit()) {
+        return parse_digits(first.core, rules);
+    }
+
+    let mut number = Number {
+        units_before_tens: rules.units_before_tens,
+        ..Default::default()
+    };
+    // "a" is only a number in "a hundred", "a thousand"...
+    let mut article = false;
+    let mut found = None;
+    for (index, token) in tokens.iter().enumerate() {
+        if index > 0 && !joined(&tokens[index - 1], token) {
+            break;
+        }
+        let word = token.word();
+        if index == 0 && rules.articles.contains(&word.as_str()) {
+            article = true;
+            number.push(Morpheme::Value(1));
+            continue;
+        }
+        let Some(morphemes) = spelled(&word, rules) else {
+            break;
+        };
+        if article && index == 1 && !matches!(morphemes.first(), Some(Morpheme::Scale(_))) {
+            break;
+        }
+        if !morphemes.into_iter().all(|morpheme| number.push(morpheme)) {
+            break;
+        }
+        if number.is_complete() {
+            found = Some(NumberMatch {
+                value: number.value(),
+                tokens: index + 1,
+                ordinal: number.ordinal,
+                digits: false,
+            });
+        }
+    }
+    found
+}
+
+/// A number in digits, such as "2026" or "23rd"
+fn parse_digits(core: &str, rules: &Rules) -> Option<NumberMatch> {
+    let end = core
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(core.len());
+    let (digits, suffix) = core.split_at(end);
+    let ordinal = !suffix.is_empty();
+    if ordinal
+        && !rules
+            .digit_ordinal_suffixes
+            .contains(&suffix.to_lowercase().as_str())
+    {
+        return None;
+    }
+    Some(NumberMatch {
+        value: digits.parse().ok()?,
+        tokens: 1,
+        ordinal,
+        digits: true,
+    })
+}
+
+/// Number parts `word` is made of, if it is made of nothing else
+fn spelled(word: &str, rules: &Rules) -> Option<Vec<Morpheme>> {
+    if word.is_empty() {
+        return None;
+    }
+    decompose(word, rules).or_else(|| ordinal(word, rules))
+}
+
+/// Split `word` into cardinal parts, trying longer parts first
+fn decompose(word: &str, rules: &Rules) -> Option<Vec<Morpheme>> {
+    if word.is_empty() {
+        return Some(Vec::new());
+    }
+    let mut starts: Vec<(&str, Morpheme)> = rules
+        .cardinals()
+        .filter(|(part, _)| word.starts_with(part))
+        .collect();
+    starts.sort_by_key(|(part, _)| Reverse(part.len()));
+    starts.into_iter().find_map(|(part, morpheme)| {
+        let mut rest = decompose(&word[part.len()..], rules)?;
+        rest.insert(0, morpheme);
+        Some(rest)
+    })
+}
+
+/// Split an ordinal such as "twentythird" or "dreiundzwanzigsten"
+fn ordinal(word: &str, rules: &Rules) -> Option<Vec<Morpheme>> {
+    let stems: Vec<&str> = if rules.ordinal_endings.is_empty() {
+        vec![word]
+    } else {
+        rules
+            .ordinal_endings
+            .iter()
+            .filter_map(|ending| word.strip_suffix(ending))
+            .collect()
+    };
+    let mut ordinals: Vec<(&str, u64)> = rules.ordinals().collect();
+    ordinals.sort_by_key(|(part, _)| Reverse(part.len()));
+
+    stems.into_iter().find_map(|stem| {
+        ordinals.iter().find_map(|(part, value)| {
+            let mut morphemes = decompose(stem.strip_suffix(part)?, rules)?;
+            morphemes.push(Morpheme::Ordinal(*value));
+            Some(morphemes)
+        })
+    })
+}
+
+/// `value` in digits, grouped by thousands from 10 000 up
+pub(super) fn format_number(value: u64, rules: &Rules) -> String {
+    let digits = value.to_string();
+    if value < 10_000 {
+        return digits;
+    }
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push_str(rules.group_separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
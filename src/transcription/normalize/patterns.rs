@@ -0,0 +1,159 @@
+//! Dates, amounts and numbers found at the start of some tokens
+//!
+//! Each rewrite returns the text to write instead and the number of tokens
+//! it replaces.
+
+use super::numbers::{format_number, parse_number, NumberMatch};
+use super::rules::Rules;
+use super::{joined, Token};
+
+/// Rewrite "the twenty third of May" or "May 23rd" as "May 23"
+pub(super) fn date(tokens: &[Token], rules: &Rules) -> Option<(String, usize)> {
+    let (day, month, mut used) =
+        day_then_month(tokens, rules).or_else(|| month_then_day(tokens, rules))?;
+
+    let mut text = if rules.day_first {
+        format!("{day}. {month}")
+    } else {
+        format!("{month} {day}")
+    };
+    if tokens[used - 1].trail.is_empty() {
+        if let Some((year, year_tokens)) = year(&tokens[used..], rules) {
+            let separator = if rules.day_first { " " } else { ", " };
+            text.push_str(&format!("{separator}{year}"));
+            used += year_tokens;
+        }
+    }
+    Some((text, used))
+}
+
+/// Day, connector and month, as in "(the) twenty third (of) May"
+fn day_then_month(tokens: &[Token], rules: &Rules) -> Option<(u64, &'static str, usize)> {
+    let mut used = usize::from(is_one_of(tokens.first()?, rules.day_articles));
+    let day = day(tokens, used, rules)?;
+    used += day.tokens;
+    if is_one_of(tokens.get(used)?, rules.month_connectors)
+        && joined(&tokens[used - 1], &tokens[used])
+    {
+        used += 1;
+    }
+    let month = rules.month(&tokens.get(used)?.word())?;
+    joined(&tokens[used - 1], &tokens[used]).then_some((day.value, month, used + 1))
+}
+
+/// Month and day, as in "May (the) twenty third"
+fn month_then_day(tokens: &[Token], rules: &Rules) -> Option<(u64, &'static str, usize)> {
+    let month = rules.month(&tokens.first()?.word())?;
+    let mut used = 1;
+    if is_one_of(tokens.get(used)?, rules.day_articles) && joined(&tokens[0], &tokens[1]) {
+        used += 1;
+    }
+    let day = day(tokens, used, rules)?;
+    Some((day.value, month, used + day.tokens))
+}
+
+/// An ordinal from 1 to 31 at `tokens[start]`, joined to the token before
+fn day(tokens: &[Token], start: usize, rules: &Rules) -> Option<NumberMatch> {
+    if start > 0 && !joined(&tokens[start - 1], tokens.get(start)?) {
+        return None;
+    }
+    parse_number(&tokens[start..], rules).filter(|day| day.ordinal && (1..=31).contains(&day.value))
+}
+
+/// A year such as "two thousand twenty six" or "twenty twenty six"
+fn year(tokens: &[Token], rules: &Rules) -> Option<(u64, usize)> {
+    if tokens.is_empty() || !tokens[0].lead.is_empty() {
+        return None;
+    }
+    let first = parse_number(tokens, rules).filter(|n| !n.ordinal)?;
+    if (1000..3000).contains(&first.value) {
+        return Some((first.value, first.tokens));
+    }
+
+    // Said as two pairs of digits
+    if first.digits || !(10..30).contains(&first.value) {
+        return None;
+    }
+    let rest = &tokens[first.tokens..];
+    if !joined(&tokens[first.tokens - 1], rest.first()?) {
+        return None;
+    }
+    let second = parse_number(rest, rules)
+        .filter(|n| !n.ordinal && !n.digits && (10..100).contains(&n.value))?;
+    Some((
+        first.value * 100 + second.value,
+        first.tokens + second.tokens,
+    ))
+}
+
+/// Rewrite "twenty five dollars and fifty cents" as "$25.50"
+pub(super) fn amount(tokens: &[Token], rules: &Rules) -> Option<(String, usize)> {
+    let number = parse_number(tokens, rules).filter(|n| !n.ordinal)?;
+    let mut used = number.tokens;
+    let unit = tokens.get(used)?;
+    if !joined(&tokens[used - 1], unit) {
+        return None;
+    }
+    let word = unit.word();
+    let currency = rules
+        .currencies
+        .iter()
+        .find(|currency| currency.words.contains(&word.as_str()))?;
+    used += 1;
+
+    let mut amount = format_number(number.value, rules);
+    if let Some((cents, cents_tokens)) = cents(&tokens[used - 1..], currency.subunits, rules) {
+        amount.push_str(&format!("{}{:02}", rules.decimal_separator, cents));
+        used += cents_tokens;
+    }
+    Some((currency.format.replace("{}", &amount), used))
+}
+
+/// "and fifty cents" after the currency word `tokens[0]`
+fn cents(tokens: &[Token], subunits: &[&str], rules: &Rules) -> Option<(u64, usize)> {
+    let and = tokens.get(1)?;
+    if !joined(&tokens[0], and) || and.word() != rules.and_word {
+        return None;
+    }
+    let number =
+        parse_number(&tokens[2..], rules).filter(|n| !n.ordinal && (1..100).contains(&n.value))?;
+    let used = 2 + number.tokens;
+    let unit = tokens.get(used)?;
+    let is_subunit = subunits.contains(&unit.word().as_str());
+    (is_subunit && joined(&tokens[used - 1], unit)).then_some((number.value, used))
+}
+
+/// Rewrite a spelled-out number of ten or more in digits
+///
+/// Smaller numbers read better as words, and numbers that follow each other
+/// ("nineteen ninety", "five five five") are left alone, as it's not clear
+/// what they add up to.
+pub(super) fn number(tokens: &[Token], rules: &Rules) -> Option<(String, usize)> {
+    let number = parse_number(tokens, rules).filter(|n| !n.ordinal && !n.digits)?;
+    let mut used = number.tokens;
+    let mut followed = false;
+    while let Some(next) = tokens.get(used) {
+        if !joined(&tokens[used - 1], next) {
+            break;
+        }
+        let Some(next) = parse_number(&tokens[used..], rules).filter(|n| !n.digits) else {
+            break;
+        };
+        used += next.tokens;
+        followed = true;
+    }
+
+    if followed {
+        let words: Vec<&str> = tokens[..used].iter().map(|token| token.core).collect();
+        Some((words.join(" "), used))
+    } else if number.value >= 10 {
+        Some((format_number(number.value, rules), used))
+    } else {
+        None
+    }
+}
+
+/// Whether `token` is one of `words`, ignoring case
+fn is_one_of(token: &Token, words: &[&str]) -> bool {
+    words.contains(&token.word().as_str())
+}
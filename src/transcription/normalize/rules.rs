@@ -0,0 +1,99 @@
+//! How a language spells numbers, dates and amounts
+
+use super::languages;
+use super::numbers::Morpheme;
+
+/// How one language spells numbers, dates and amounts
+#[derive(Debug)]
+pub(super) struct Rules {
+    /// One to nineteen
+    pub(super) units: [&'static str; 19],
+    /// Twenty to ninety
+    pub(super) tens: [&'static str; 8],
+    pub(super) scales: &'static [(&'static str, u64)],
+    /// Other spellings of units and tens
+    pub(super) aliases: &'static [(&'static str, u64)],
+    /// Articles read as one before a scale, as in "a hundred"
+    pub(super) articles: &'static [&'static str],
+    pub(super) and_word: &'static str,
+    /// Whether units may come first, as in "fünfundzwanzig" or "fem og tjue"
+    pub(super) units_before_tens: bool,
+    /// Ordinals (or their stems) from first to nineteenth
+    pub(super) ordinal_units: [&'static str; 19],
+    /// Twentieth and thirtieth
+    pub(super) ordinal_tens: [&'static str; 2],
+    pub(super) ordinal_aliases: &'static [(&'static str, u64)],
+    /// Inflections after an ordinal ending, if it always has one
+    pub(super) ordinal_endings: &'static [&'static str],
+    /// Suffixes of ordinals in digits, such as the "rd" of "23rd"
+    pub(super) digit_ordinal_suffixes: &'static [&'static str],
+    /// Month names as written in a date
+    pub(super) months: [&'static str; 12],
+    /// Words before a spoken day that are dropped, such as "the"
+    pub(super) day_articles: &'static [&'static str],
+    /// Words between a spoken day and month that are dropped, such as "of"
+    pub(super) month_connectors: &'static [&'static str],
+    /// Whether dates are written "23. Mai" rather than "May 23"
+    pub(super) day_first: bool,
+    pub(super) currencies: &'static [Currency],
+    pub(super) group_separator: &'static str,
+    pub(super) decimal_separator: &'static str,
+}
+
+/// A currency and how amounts of it are written
+#[derive(Debug)]
+pub(super) struct Currency {
+    pub(super) words: &'static [&'static str],
+    /// Words for a hundredth of it, such as "cents"
+    pub(super) subunits: &'static [&'static str],
+    /// The amount with `{}` for the number
+    pub(super) format: &'static str,
+}
+
+impl Rules {
+    /// Every cardinal number part and its meaning
+    pub(super) fn cardinals(&self) -> impl Iterator<Item = (&'static str, Morpheme)> + '_ {
+        let units = (1..).zip(self.units).map(|(value, word)| (word, value));
+        let tens = (2..).zip(self.tens).map(|(tens, word)| (word, tens * 10));
+        units
+            .chain(tens)
+            .chain(self.aliases.iter().copied())
+            .map(|(word, value)| (word, Morpheme::Value(value)))
+            .chain(
+                self.scales
+                    .iter()
+                    .map(|&(word, scale)| (word, Morpheme::Scale(scale))),
+            )
+            .chain(std::iter::once((self.and_word, Morpheme::And)))
+    }
+
+    /// Every ordinal a number can end with, and its value
+    pub(super) fn ordinals(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        let units = (1..)
+            .zip(self.ordinal_units)
+            .map(|(value, word)| (word, value));
+        let tens = (2..)
+            .zip(self.ordinal_tens)
+            .map(|(tens, word)| (word, tens * 10));
+        units
+            .chain(tens)
+            .chain(self.ordinal_aliases.iter().copied())
+    }
+
+    /// Month name as written in a date, for a lowercase `word`
+    pub(super) fn month(&self, word: &str) -> Option<&'static str> {
+        self.months
+            .into_iter()
+            .find(|month| month.to_lowercase() == word)
+    }
+}
+
+/// Rules for a transcription language, if it has any
+pub(super) fn rules_for(language: &str) -> Option<&'static Rules> {
+    match language {
+        "en" => Some(&languages::ENGLISH),
+        "no" => Some(&languages::NORWEGIAN),
+        "de" => Some(&languages::GERMAN),
+        _ => None,
+    }
+}
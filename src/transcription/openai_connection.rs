@@ -3,19 +3,27 @@
 //! Manages the WebSocket connection to OpenAI for direct STT using GPT-4o Transcribe.
 //! Uses the transcription-specific endpoint with intent=transcription parameter.
 
+use super::audio_append::{AudioAppendEncoder, AudioEncoding};
+use super::debug_log;
+use super::formatting::PauseDetector;
+use super::heartbeat::Heartbeat;
+use super::language_switch::LanguageUpdates;
+use super::latency;
+use super::noise_reduction::NoiseReduction;
 use super::openai_messages::{
-    OpenAIClientMessage, OpenAIServerMessage, OpenAISessionConfig, OPENAI_TRANSCRIBE_MODEL,
+    OpenAIClientMessage, OpenAIServerMessage, OpenAISessionConfig, TranscribeModel,
 };
-use super::session::TranscriptionSession;
+use super::session::{SessionCommand, SessionHandle};
+use super::session_info::notify_configured;
+use super::usage;
 use super::TranscriptEvent;
 use crate::audio::AudioChunk;
-use base64::Engine;
 use futures_util::{SinkExt, StreamExt};
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::mpsc;
 use tokio::time::interval;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, trace, warn};
@@ -62,21 +70,41 @@ pub(crate) fn build_openai_ws_request(
         .map_err(|e| e.to_string())
 }
 
+/// OpenAI transcription_session.update message configuring transcription
+///
+/// Sent when connecting, and again when the language is switched.
+pub(crate) fn session_update(
+    model: TranscribeModel,
+    language: Option<&str>,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+) -> Result<String, String> {
+    let session_config = OpenAISessionConfig::new(
+        model.api_name(),
+        language,
+        encoding.format_name(),
+        noise_reduction,
+    );
+    let msg = OpenAIClientMessage::TranscriptionSessionUpdate {
+        session: session_config,
+    };
+    serde_json::to_string(&msg).map_err(|e| e.to_string())
+}
+
 /// Send OpenAI session initialization message (transcription mode)
 pub(crate) async fn send_session_init<S>(
     ws_sink: &mut S,
+    model: TranscribeModel,
     language: Option<&str>,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
 ) -> Result<(), String>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
 {
-    let session_config = OpenAISessionConfig::new(OPENAI_TRANSCRIBE_MODEL, language);
-    let msg = OpenAIClientMessage::TranscriptionSessionUpdate {
-        session: session_config,
-    };
-
-    let json = serde_json::to_string(&msg).map_err(|e| e.to_string())?;
+    let json = session_update(model, language, encoding, noise_reduction)?;
     info!("Sending OpenAI transcription_session.update: {}", json);
+    debug_log::sent(&json);
 
     ws_sink
         .send(Message::Text(json))
@@ -90,15 +118,38 @@ pub(crate) fn spawn_openai_receive_task(
         + Unpin
         + Send
         + 'static,
-    session: Arc<Mutex<TranscriptionSession>>,
-    event_tx: broadcast::Sender<TranscriptEvent>,
+    session: SessionHandle,
     should_stop: Arc<AtomicBool>,
+    heartbeat: Heartbeat,
 ) -> tokio::task::JoinHandle<OpenAIReceiveResult> {
     tokio::spawn(async move {
         let mut connection_ok = true;
-        let quota_exceeded = false;
+        let mut quota_exceeded = false;
+        let mut pauses = PauseDetector::default();
+
+        loop {
+            let msg_result = tokio::select! {
+                msg_result = ws_stream.next() => match msg_result {
+                    Some(msg_result) => msg_result,
+                    None => break,
+                },
+                () = heartbeat.stalled() => {
+                    warn!(
+                        "No reply from OpenAI for {}s while sending audio, reconnecting",
+                        heartbeat.timeout_secs()
+                    );
+                    debug_log::connection(format!(
+                        "No reply for {}s while sending audio",
+                        heartbeat.timeout_secs()
+                    ));
+                    connection_ok = false;
+                    session.send(SessionCommand::PreservePartial { reason: "stalled" });
+                    session.notify(TranscriptEvent::ConnectionLost);
+                    break;
+                }
+            };
+            heartbeat.received();
 
-        while let Some(msg_result) = ws_stream.next().await {
             if should_stop.load(Ordering::SeqCst) {
                 break;
             }
@@ -106,6 +157,8 @@ pub(crate) fn spawn_openai_receive_task(
             match msg_result {
                 Ok(Message::Text(text)) => {
                     trace!("OpenAI message: {}", text);
+                    debug_log::received(&text);
+                    usage::received(text.len());
                     match serde_json::from_str::<OpenAIServerMessage>(&text) {
                         Ok(openai_msg) => {
                             // Check for errors
@@ -117,48 +170,71 @@ pub(crate) fn spawn_openai_receive_task(
                                     debug!("OpenAI buffer empty on stop (expected): {}", error_msg);
                                     continue;
                                 }
+                                // Retrying can't help once the account is out of credits
+                                if crate::retry::is_quota_exhausted(&text) {
+                                    error!("OpenAI quota exceeded: {}", error_msg);
+                                    quota_exceeded = true;
+                                    session.notify(TranscriptEvent::Error { message: error_msg });
+                                    break;
+                                }
                                 error!("OpenAI STT error: {}", error_msg);
-                                let _ =
-                                    event_tx.send(TranscriptEvent::Error { message: error_msg });
+                                session.notify(TranscriptEvent::Error { message: error_msg });
                                 continue;
                             }
 
                             // Convert OpenAI message to transcript event
                             if let Some((is_final, text)) = openai_msg.to_transcript_text() {
-                                update_openai_session_state(&session, is_final, &text);
-
-                                let event = if is_final {
+                                let command = if is_final {
                                     debug!("OpenAI committed transcript: {}", text);
-                                    TranscriptEvent::CommittedTranscript { text }
+                                    SessionCommand::Commit(text)
                                 } else {
                                     trace!("OpenAI partial transcript: {}", text);
-                                    TranscriptEvent::PartialTranscript { text }
+                                    SessionCommand::Partial(text)
                                 };
-                                let _ = event_tx.send(event);
+                                session.send(command);
                             }
 
                             // Log session events
                             match &openai_msg {
-                                OpenAIServerMessage::SessionCreated { .. } => {
+                                OpenAIServerMessage::SessionCreated { session: info } => {
                                     info!("OpenAI session created");
+                                    notify_configured(&session, info.as_ref());
                                 }
-                                OpenAIServerMessage::SessionUpdated { .. } => {
+                                OpenAIServerMessage::SessionUpdated { session: info } => {
                                     info!("OpenAI session updated");
+                                    notify_configured(&session, info.as_ref());
                                 }
-                                OpenAIServerMessage::TranscriptionSessionCreated { .. } => {
+                                OpenAIServerMessage::TranscriptionSessionCreated {
+                                    session: info,
+                                } => {
                                     info!("OpenAI transcription session created");
+                                    notify_configured(&session, info.as_ref());
                                 }
-                                OpenAIServerMessage::TranscriptionSessionUpdated { .. } => {
+                                OpenAIServerMessage::TranscriptionSessionUpdated {
+                                    session: info,
+                                } => {
                                     info!("OpenAI transcription session updated");
+                                    notify_configured(&session, info.as_ref());
                                 }
                                 OpenAIServerMessage::InputAudioBufferCommitted => {
                                     debug!("OpenAI audio buffer committed");
                                 }
-                                OpenAIServerMessage::InputAudioBufferSpeechStarted => {
+                                OpenAIServerMessage::InputAudioBufferSpeechStarted {
+                                    audio_start_ms,
+                                } => {
                                     debug!("OpenAI VAD: speech started");
+                                    if audio_start_ms.is_some_and(|ms| pauses.speech_started(ms)) {
+                                        session.send(SessionCommand::ParagraphBreak);
+                                    }
                                 }
-                                OpenAIServerMessage::InputAudioBufferSpeechStopped => {
+                                OpenAIServerMessage::InputAudioBufferSpeechStopped {
+                                    audio_end_ms,
+                                } => {
                                     debug!("OpenAI VAD: speech stopped");
+                                    if let Some(audio_end_ms) = audio_end_ms {
+                                        latency::speech_stopped(*audio_end_ms);
+                                        pauses.speech_stopped(*audio_end_ms);
+                                    }
                                 }
                                 _ => {}
                             }
@@ -170,10 +246,13 @@ pub(crate) fn spawn_openai_receive_task(
                 }
                 Ok(Message::Close(_)) => {
                     info!("OpenAI WebSocket closed by server");
+                    debug_log::connection("Closed by the server");
                     connection_ok = false;
-                    preserve_openai_partial(&session, "connection close");
+                    session.send(SessionCommand::PreservePartial {
+                        reason: "connection close",
+                    });
                     if !quota_exceeded {
-                        let _ = event_tx.send(TranscriptEvent::ConnectionLost);
+                        session.notify(TranscriptEvent::ConnectionLost);
                     }
                     break;
                 }
@@ -185,9 +264,12 @@ pub(crate) fn spawn_openai_receive_task(
                 }
                 Err(e) => {
                     error!("OpenAI WebSocket receive error: {}", e);
+                    debug_log::connection(format!("Receive error: {}", e));
                     connection_ok = false;
-                    preserve_openai_partial(&session, "receive error");
-                    let _ = event_tx.send(TranscriptEvent::ConnectionLost);
+                    session.send(SessionCommand::PreservePartial {
+                        reason: "receive error",
+                    });
+                    session.notify(TranscriptEvent::ConnectionLost);
                     break;
                 }
                 _ => {}
@@ -207,13 +289,16 @@ pub(crate) fn spawn_openai_send_task<S>(
     mut audio_rx: mpsc::Receiver<AudioChunk>,
     mut connection_lost_rx: mpsc::Receiver<()>,
     should_stop: Arc<AtomicBool>,
+    encoding: AudioEncoding,
+    heartbeat: Heartbeat,
+    mut language_updates: LanguageUpdates,
 ) -> tokio::task::JoinHandle<OpenAISendResult>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin + Send + 'static,
 {
     tokio::spawn(async move {
         info!("OpenAI send task started");
-        let base64_engine = base64::engine::general_purpose::STANDARD;
+        let mut encoder = AudioAppendEncoder::new(encoding);
         let mut pending_chunks: Vec<AudioChunk> = Vec::new();
         let mut sent_buffer: VecDeque<AudioChunk> = VecDeque::new();
         let max_buffer_secs = 30.0;
@@ -242,6 +327,15 @@ where
                     }
                     trace!("Sent OpenAI keepalive ping");
                 }
+                Some(update) = language_updates.next() => {
+                    info!("Sending OpenAI transcription_session.update: {}", update);
+                    debug_log::sent(&update);
+                    if ws_sink.send(Message::Text(update)).await.is_err() {
+                        warn!("Failed to send OpenAI language switch");
+                        connection_lost = true;
+                        break;
+                    }
+                }
                 chunk = audio_rx.recv() => {
                     if should_stop.load(Ordering::SeqCst) {
                         info!("OpenAI send task: should_stop flag set, sending commit");
@@ -271,10 +365,11 @@ where
                                     max_sample
                                 );
                             }
-                            match send_openai_audio_chunk(&mut ws_sink, &audio_chunk, &base64_engine).await {
+                            match send_openai_audio_chunk(&mut ws_sink, &audio_chunk, &mut encoder).await {
                                 Ok(()) => {
                                     sent_buffer.push_back(audio_chunk);
                                     trim_openai_sent_buffer(&mut sent_buffer, max_buffer_secs);
+                                    heartbeat.sent();
                                 }
                                 Err(_) => {
                                     error!("Failed to send OpenAI audio chunk");
@@ -283,6 +378,15 @@ where
                                     break;
                                 }
                             }
+                            // Ask a quiet server for a pong, so silence isn't taken for a stall
+                            if heartbeat.should_ping() {
+                                if ws_sink.send(Message::Ping(vec![])).await.is_err() {
+                                    warn!("Failed to send OpenAI heartbeat ping");
+                                    connection_lost = true;
+                                    break;
+                                }
+                                trace!("Sent OpenAI heartbeat ping");
+                            }
                         }
                         None => {
                             info!("OpenAI audio buffer channel closed after sending {} chunks", chunks_sent);
@@ -322,26 +426,16 @@ where
 async fn send_openai_audio_chunk<S>(
     ws_sink: &mut S,
     chunk: &AudioChunk,
-    base64_engine: &base64::engine::GeneralPurpose,
+    encoder: &mut AudioAppendEncoder,
 ) -> Result<(), ()>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
 {
-    // Convert samples to bytes (PCM16 little-endian)
-    let bytes: Vec<u8> = chunk
-        .samples
-        .iter()
-        .flat_map(|&s| s.to_le_bytes())
-        .collect();
-
-    let audio_base64 = base64_engine.encode(&bytes);
-    let msg = OpenAIClientMessage::InputAudioBufferAppend {
-        audio: audio_base64,
-    };
-
-    if let Ok(json) = serde_json::to_string(&msg) {
-        ws_sink.send(Message::Text(json)).await.map_err(|_| ())?;
-    }
+    let message = encoder.message(&chunk.samples);
+    let bytes = message.len();
+    ws_sink.send(message).await.map_err(|_| ())?;
+    latency::audio_sent(chunk);
+    usage::sent(bytes);
     Ok(())
 }
 
@@ -406,42 +500,11 @@ fn recover_openai_buffered_chunks(
     all_pending
 }
 
-/// Update session state based on OpenAI transcript
-fn update_openai_session_state(
-    session: &Arc<Mutex<TranscriptionSession>>,
-    is_final: bool,
-    text: &str,
-) {
-    if let Ok(mut sess) = session.lock() {
-        if is_final {
-            sess.committed_segments.push(text.to_string());
-            sess.partial_transcript = None;
-        } else {
-            sess.partial_transcript = Some(text.to_string());
-        }
-    }
-}
-
-/// Preserve any partial transcript as committed
-fn preserve_openai_partial(session: &Arc<Mutex<TranscriptionSession>>, reason: &str) {
-    if let Ok(mut sess) = session.lock() {
-        if let Some(partial) = sess.partial_transcript.take() {
-            if !partial.trim().is_empty() {
-                info!(
-                    "Preserving OpenAI partial transcript before {}: {} chars",
-                    reason,
-                    partial.len()
-                );
-                sess.committed_segments.push(partial);
-            }
-        }
-    }
-}
-
 /// Resend buffered OpenAI audio chunks after reconnection
 pub(crate) async fn resend_openai_buffered_chunks<S>(
     ws_sink: &mut S,
     pending_chunks: &mut Vec<AudioChunk>,
+    encoding: AudioEncoding,
 ) -> Result<(), ()>
 where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
@@ -454,26 +517,17 @@ where
         "Resending {} OpenAI buffered audio chunks",
         pending_chunks.len()
     );
-    let base64_engine = base64::engine::general_purpose::STANDARD;
+    let mut encoder = AudioAppendEncoder::new(encoding);
 
     for chunk in pending_chunks.drain(..) {
-        let bytes: Vec<u8> = chunk
-            .samples
-            .iter()
-            .flat_map(|&s| s.to_le_bytes())
-            .collect();
-
-        let audio_base64 = base64_engine.encode(&bytes);
-        let msg = OpenAIClientMessage::InputAudioBufferAppend {
-            audio: audio_base64,
-        };
-
-        if let Ok(json) = serde_json::to_string(&msg) {
-            if ws_sink.send(Message::Text(json)).await.is_err() {
-                error!("Failed to resend OpenAI buffered audio chunk");
-                return Err(());
-            }
+        let message = encoder.message(&chunk.samples);
+        let bytes = message.len();
+        if ws_sink.send(message).await.is_err() {
+            error!("Failed to resend OpenAI buffered audio chunk");
+            return Err(());
         }
+        latency::audio_sent(&chunk);
+        usage::sent(bytes);
     }
 
     Ok(())
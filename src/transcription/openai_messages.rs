@@ -1,12 +1,65 @@
 //! OpenAI Realtime API message types for transcription
 //!
 //! Defines the message format for OpenAI Realtime WebSocket communication.
-//! Uses the transcription-specific session type with gpt-4o-transcribe model.
+//! Uses the transcription-specific session type with the model chosen in Settings.
 
 use serde::{Deserialize, Serialize};
 
+use super::noise_reduction::NoiseReduction;
+use super::session_info::SessionInfo;
+
 /// OpenAI Realtime transcription model
-pub const OPENAI_TRANSCRIBE_MODEL: &str = "gpt-4o-transcribe";
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum TranscribeModel {
+    #[default]
+    #[serde(rename = "gpt-4o-transcribe")]
+    Gpt4oTranscribe,
+    /// Cheaper, slightly less accurate
+    #[serde(rename = "gpt-4o-mini-transcribe")]
+    Gpt4oMiniTranscribe,
+    /// Transcribes each phrase once it ends, without live partial text
+    #[serde(rename = "whisper-1")]
+    Whisper1,
+}
+
+impl TranscribeModel {
+    /// All models, in the order shown in Settings
+    pub(crate) const ALL: [TranscribeModel; 3] = [
+        TranscribeModel::Gpt4oTranscribe,
+        TranscribeModel::Gpt4oMiniTranscribe,
+        TranscribeModel::Whisper1,
+    ];
+
+    /// Model name sent in the session config
+    pub(crate) fn api_name(self) -> &'static str {
+        match self {
+            TranscribeModel::Gpt4oTranscribe => "gpt-4o-transcribe",
+            TranscribeModel::Gpt4oMiniTranscribe => "gpt-4o-mini-transcribe",
+            TranscribeModel::Whisper1 => "whisper-1",
+        }
+    }
+
+    /// Segment label in Settings
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            TranscribeModel::Gpt4oTranscribe => "GPT-4o Transcribe",
+            TranscribeModel::Gpt4oMiniTranscribe => "GPT-4o mini (cheaper)",
+            TranscribeModel::Whisper1 => "Whisper (no live text)",
+        }
+    }
+
+    /// Look up a model by its index in `ALL` (the selected segment)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    /// Index in `ALL`
+    pub(crate) fn to_index(self) -> isize {
+        Self::ALL.iter().position(|m| *m == self).unwrap_or(0) as isize
+    }
+}
 
 /// Messages sent to OpenAI Realtime API
 #[derive(Debug, Serialize)]
@@ -16,6 +69,10 @@ pub(crate) enum OpenAIClientMessage {
     #[serde(rename = "transcription_session.update")]
     TranscriptionSessionUpdate { session: OpenAISessionConfig },
     /// Append audio data to the input buffer
+    ///
+    /// Sent via `AudioAppendEncoder`, which writes the same JSON without
+    /// per-chunk allocations; kept here as the reference format.
+    #[allow(dead_code)]
     #[serde(rename = "input_audio_buffer.append")]
     InputAudioBufferAppend { audio: String },
     /// Commit the audio buffer for processing
@@ -26,7 +83,7 @@ pub(crate) enum OpenAIClientMessage {
 /// Session configuration for OpenAI Realtime transcription API
 #[derive(Debug, Serialize)]
 pub(crate) struct OpenAISessionConfig {
-    /// Input audio format (pcm16)
+    /// Input audio format ("pcm16", or "g711_ulaw" in low-bandwidth mode)
     pub input_audio_format: String,
     /// Transcription configuration
     pub input_audio_transcription: OpenAITranscriptionConfig,
@@ -75,15 +132,22 @@ pub(crate) struct OpenAITurnDetection {
 
 impl OpenAISessionConfig {
     /// Create a new session config for transcription
-    pub fn new(model: &str, language: Option<&str>) -> Self {
+    pub fn new(
+        model: &str,
+        language: Option<&str>,
+        input_audio_format: &str,
+        noise_reduction: NoiseReduction,
+    ) -> Self {
         Self {
-            input_audio_format: "pcm16".to_string(),
+            input_audio_format: input_audio_format.to_string(),
             input_audio_transcription: OpenAITranscriptionConfig {
                 model: model.to_string(),
                 language: language.map(String::from),
             },
-            input_audio_noise_reduction: Some(OpenAINoiseReduction {
-                noise_type: "near_field".to_string(),
+            input_audio_noise_reduction: noise_reduction.api_name().map(|noise_type| {
+                OpenAINoiseReduction {
+                    noise_type: noise_type.to_string(),
+                }
             }),
             turn_detection: Some(OpenAITurnDetection {
                 detection_type: "server_vad".to_string(),
@@ -101,28 +165,16 @@ impl OpenAISessionConfig {
 pub(crate) enum OpenAIServerMessage {
     /// Session created
     #[serde(rename = "session.created")]
-    SessionCreated {
-        #[allow(dead_code)]
-        session: Option<OpenAISessionInfo>,
-    },
+    SessionCreated { session: Option<SessionInfo> },
     /// Session updated
     #[serde(rename = "session.updated")]
-    SessionUpdated {
-        #[allow(dead_code)]
-        session: Option<OpenAISessionInfo>,
-    },
+    SessionUpdated { session: Option<SessionInfo> },
     /// Transcription session updated (for transcription mode)
     #[serde(rename = "transcription_session.created")]
-    TranscriptionSessionCreated {
-        #[allow(dead_code)]
-        session: Option<OpenAISessionInfo>,
-    },
+    TranscriptionSessionCreated { session: Option<SessionInfo> },
     /// Transcription session updated
     #[serde(rename = "transcription_session.updated")]
-    TranscriptionSessionUpdated {
-        #[allow(dead_code)]
-        session: Option<OpenAISessionInfo>,
-    },
+    TranscriptionSessionUpdated { session: Option<SessionInfo> },
     /// Partial transcription delta
     #[serde(rename = "conversation.item.input_audio_transcription.delta")]
     TranscriptionDelta { delta: Option<String> },
@@ -137,10 +189,16 @@ pub(crate) enum OpenAIServerMessage {
     InputAudioBufferCleared,
     /// Input audio buffer speech started (VAD detected speech)
     #[serde(rename = "input_audio_buffer.speech_started")]
-    InputAudioBufferSpeechStarted,
+    InputAudioBufferSpeechStarted {
+        /// Where the speech started, in milliseconds of audio sent on this connection
+        audio_start_ms: Option<u64>,
+    },
     /// Input audio buffer speech stopped (VAD detected silence)
     #[serde(rename = "input_audio_buffer.speech_stopped")]
-    InputAudioBufferSpeechStopped,
+    InputAudioBufferSpeechStopped {
+        /// Where the speech ended, in milliseconds of audio sent on this connection
+        audio_end_ms: Option<u64>,
+    },
     /// Error message
     #[serde(rename = "error")]
     Error { error: Option<OpenAIError> },
@@ -149,15 +207,6 @@ pub(crate) enum OpenAIServerMessage {
     Other,
 }
 
-/// OpenAI session information
-#[derive(Debug, Deserialize)]
-pub(crate) struct OpenAISessionInfo {
-    #[allow(dead_code)]
-    pub id: Option<String>,
-    #[allow(dead_code)]
-    pub model: Option<String>,
-}
-
 /// OpenAI error details
 #[derive(Debug, Deserialize)]
 pub(crate) struct OpenAIError {
@@ -200,12 +249,22 @@ mod tests {
     #[test]
     fn test_transcription_session_update_serialization() {
         let msg = OpenAIClientMessage::TranscriptionSessionUpdate {
-            session: OpenAISessionConfig::new("gpt-4o-transcribe", Some("en")),
+            session: OpenAISessionConfig::new(
+                TranscribeModel::Gpt4oMiniTranscribe.api_name(),
+                Some("en"),
+                "pcm16",
+                NoiseReduction::FarField,
+            ),
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("transcription_session.update"));
-        assert!(json.contains("gpt-4o-transcribe"));
+        assert!(json.contains("gpt-4o-mini-transcribe"));
         assert!(json.contains("pcm16"));
+        assert!(json.contains(r#""input_audio_noise_reduction":{"type":"far_field"}"#));
+
+        let off = OpenAISessionConfig::new("whisper-1", None, "pcm16", NoiseReduction::Off);
+        let json = serde_json::to_string(&off).unwrap();
+        assert!(!json.contains("input_audio_noise_reduction"));
     }
 
     #[test]
@@ -259,4 +318,16 @@ mod tests {
         assert!(is_committed);
         assert_eq!(text, "Hello world");
     }
+
+    #[test]
+    fn test_transcribe_model_names() {
+        assert_eq!(TranscribeModel::default().api_name(), "gpt-4o-transcribe");
+        let json = serde_json::to_string(&TranscribeModel::Whisper1).unwrap();
+        assert_eq!(json, "\"whisper-1\"");
+        assert_eq!(
+            TranscribeModel::from_index(1),
+            Some(TranscribeModel::Gpt4oMiniTranscribe)
+        );
+        assert_eq!(TranscribeModel::from_index(3), None);
+    }
 }
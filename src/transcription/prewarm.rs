@@ -0,0 +1,421 @@
+//! Realtime connections prepared ahead of a recording
+//!
+//! Connecting and initializing a realtime session takes a moment. When a
+//! recording is about to start (the menu bar menu opens, or the prepare
+//! hotkey is pressed) the session can be set up in advance and kept alive
+//! with pings, so the recording starts transcribing right away.
+//!
+//! At most one prepared connection is kept. The next recording takes it over
+//! when it was made for the same endpoint, model, credentials, language and
+//! audio encoding; unused connections are closed after a few minutes.
+
+use futures_util::{SinkExt, StreamExt};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+use tokio::time::{interval, sleep, timeout};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tracing::{info, warn};
+
+use super::error::WS_CONNECT_TIMEOUT_SECS;
+use super::{
+    azure_connection, openai_connection, AudioEncoding, NoiseReduction, TranscribeModel,
+    TranscriptionError,
+};
+
+/// How often a prepared connection is pinged to keep it open
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long a prepared connection waits for a recording before closing
+const MAX_IDLE: Duration = Duration::from_secs(5 * 60);
+
+/// How long a recording waits for the prepared connection to be handed over
+const HANDOFF_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub(super) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// What a prepared connection was set up for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct WarmKey {
+    ws_url: String,
+    /// Transcription model, or the Azure deployment
+    model: String,
+    language: String,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+    /// Hash of the API key, to tell credentials apart without keeping them
+    credential: u64,
+}
+
+impl WarmKey {
+    pub(super) fn new(
+        ws_url: &str,
+        model: &str,
+        language_code: &str,
+        api_key: &str,
+        encoding: AudioEncoding,
+        noise_reduction: NoiseReduction,
+    ) -> Self {
+        let mut hasher = DefaultHasher::new();
+        api_key.hash(&mut hasher);
+        Self {
+            ws_url: ws_url.to_string(),
+            model: model.to_string(),
+            language: language_code.to_string(),
+            encoding,
+            noise_reduction,
+            credential: hasher.finish(),
+        }
+    }
+}
+
+/// The prepared connection, owned by its keep-alive task
+struct Warm {
+    key: WarmKey,
+    /// Asks the keep-alive task to hand the connection over
+    handoff: oneshot::Sender<oneshot::Sender<WsStream>>,
+}
+
+static WARM: Mutex<Option<Warm>> = Mutex::new(None);
+
+/// Set while a connection is being prepared, so repeated requests don't pile up
+static PREPARING: AtomicBool = AtomicBool::new(false);
+
+/// Prepare an Azure OpenAI Realtime session for the next recording
+///
+/// Does nothing if a matching connection is already prepared.
+pub(crate) async fn prewarm_azure(
+    endpoint_url: &str,
+    stt_deployment: &str,
+    api_version: &str,
+    api_key: &str,
+    language_code: &str,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+) -> Result<(), TranscriptionError> {
+    let ws_url = azure_connection::build_azure_ws_url(endpoint_url, stt_deployment, api_version);
+    let noise_reduction = noise_reduction.for_azure(api_version);
+    let key = WarmKey::new(
+        &ws_url,
+        stt_deployment,
+        language_code,
+        api_key,
+        encoding,
+        noise_reduction,
+    );
+    let Some(_preparing) = Preparing::start(&key) else {
+        return Ok(());
+    };
+
+    let parsed_url =
+        url::Url::parse(&ws_url).map_err(|e| TranscriptionError::ConnectionError(e.to_string()))?;
+    let host = parsed_url
+        .host_str()
+        .ok_or_else(|| TranscriptionError::ConnectionError("Invalid URL: no host".to_string()))?;
+    let request = azure_connection::build_azure_ws_request(&ws_url, host, api_key)
+        .map_err(TranscriptionError::ConnectionError)?;
+
+    let mut stream = connect(request).await?;
+    azure_connection::send_session_init(
+        &mut stream,
+        stt_deployment,
+        language(language_code),
+        encoding,
+        noise_reduction,
+    )
+    .await
+    .map_err(TranscriptionError::ConnectionError)?;
+
+    info!("Azure connection prepared");
+    store(key, stream);
+    Ok(())
+}
+
+/// Prepare an OpenAI Realtime session for the next recording
+///
+/// Does nothing if a matching connection is already prepared.
+pub(crate) async fn prewarm_openai(
+    model: TranscribeModel,
+    api_key: &str,
+    language_code: &str,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+) -> Result<(), TranscriptionError> {
+    let ws_url = openai_connection::build_openai_ws_url();
+    prewarm_openai_at(
+        &ws_url,
+        model,
+        api_key,
+        language_code,
+        encoding,
+        noise_reduction,
+    )
+    .await
+}
+
+/// Prepare an OpenAI Realtime session against `ws_url`
+async fn prewarm_openai_at(
+    ws_url: &str,
+    model: TranscribeModel,
+    api_key: &str,
+    language_code: &str,
+    encoding: AudioEncoding,
+    noise_reduction: NoiseReduction,
+) -> Result<(), TranscriptionError> {
+    let key = WarmKey::new(
+        ws_url,
+        model.api_name(),
+        language_code,
+        api_key,
+        encoding,
+        noise_reduction,
+    );
+    let Some(_preparing) = Preparing::start(&key) else {
+        return Ok(());
+    };
+
+    let request = openai_connection::build_openai_ws_request(ws_url, api_key)
+        .map_err(TranscriptionError::ConnectionError)?;
+
+    let mut stream = connect(request).await?;
+    openai_connection::send_session_init(
+        &mut stream,
+        model,
+        language(language_code),
+        encoding,
+        noise_reduction,
+    )
+    .await
+    .map_err(TranscriptionError::ConnectionError)?;
+
+    info!("OpenAI connection prepared");
+    store(key, stream);
+    Ok(())
+}
+
+/// Take over the prepared connection if it was set up for `key`
+///
+/// The session is already initialized. Returns `None` when nothing matching
+/// is prepared or the connection was lost meanwhile. A connection prepared
+/// for something else is left for the recording it was meant for.
+pub(super) async fn take(key: &WarmKey) -> Option<WsStream> {
+    let warm = {
+        let mut slot = WARM.lock().ok()?;
+        if slot.as_ref().map(|warm| &warm.key) != Some(key) {
+            return None;
+        }
+        slot.take()?
+    };
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    warm.handoff.send(reply_tx).ok()?;
+    timeout(HANDOFF_TIMEOUT, reply_rx).await.ok()?.ok()
+}
+
+/// Clears `PREPARING` when preparation ends, however it ends
+struct Preparing;
+
+impl Preparing {
+    /// Start preparing, unless `key` is already prepared or another preparation is running
+    fn start(key: &WarmKey) -> Option<Self> {
+        let already_warm = WARM.lock().is_ok_and(|slot| {
+            slot.as_ref()
+                .is_some_and(|warm| &warm.key == key && !warm.handoff.is_closed())
+        });
+        if already_warm || PREPARING.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        Some(Self)
+    }
+}
+
+impl Drop for Preparing {
+    fn drop(&mut self) {
+        PREPARING.store(false, Ordering::SeqCst);
+    }
+}
+
+fn language(language_code: &str) -> Option<&str> {
+    if language_code.is_empty() {
+        None
+    } else {
+        Some(language_code)
+    }
+}
+
+async fn connect(request: http::Request<()>) -> Result<WsStream, TranscriptionError> {
+    match timeout(
+        Duration::from_secs(WS_CONNECT_TIMEOUT_SECS),
+        connect_async(request),
+    )
+    .await
+    {
+        Ok(Ok((stream, _response))) => Ok(stream),
+        Ok(Err(e)) => Err(TranscriptionError::ConnectionError(e.to_string())),
+        Err(_) => Err(TranscriptionError::ConnectionTimeout),
+    }
+}
+
+/// Keep `stream` alive as the prepared connection, replacing any previous one
+fn store(key: WarmKey, stream: WsStream) {
+    let (handoff_tx, handoff_rx) = oneshot::channel();
+    tokio::spawn(keep_alive(stream, handoff_rx));
+    if let Ok(mut slot) = WARM.lock() {
+        // Dropping the previous handoff sender closes that connection
+        *slot = Some(Warm {
+            key,
+            handoff: handoff_tx,
+        });
+    }
+}
+
+/// Ping the connection until a recording takes it over, it is replaced, or it idles out
+async fn keep_alive(
+    mut stream: WsStream,
+    mut handoff: oneshot::Receiver<oneshot::Sender<WsStream>>,
+) {
+    let idle = sleep(MAX_IDLE);
+    tokio::pin!(idle);
+    let mut ping = interval(KEEPALIVE_INTERVAL);
+    ping.tick().await; // The first tick completes immediately
+
+    loop {
+        tokio::select! {
+            request = &mut handoff => {
+                match request {
+                    Ok(reply) => {
+                        let _ = reply.send(stream);
+                    }
+                    Err(_) => {
+                        info!("Prepared connection replaced, closing");
+                        let _ = stream.close(None).await;
+                    }
+                }
+                return;
+            }
+            _ = ping.tick() => {
+                if let Err(e) = stream.send(Message::Ping(Vec::new())).await {
+                    warn!("Prepared connection lost: {}", e);
+                    return;
+                }
+            }
+            message = stream.next() => match message {
+                // Session events and pongs aren't needed before recording starts
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    info!("Prepared connection closed by the server");
+                    return;
+                }
+                Some(Ok(_)) => {}
+            },
+            _ = &mut idle => {
+                info!("Prepared connection unused, closing");
+                let _ = stream.close(None).await;
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::realtime_harness::{
+        audio_with_chunk, events_until, MockRealtimeServer, ServerStep, SCENARIO_TIMEOUT,
+    };
+    use crate::transcription::{TranscriptEvent, TranscriptionClient};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_warm_key_tells_credentials_apart() {
+        let url = "wss://example.com/v1/realtime";
+        let model = "gpt-4o-transcribe";
+        let near = NoiseReduction::NearField;
+        let key = |language, api_key| {
+            WarmKey::new(url, model, language, api_key, AudioEncoding::Pcm16, near)
+        };
+        assert_eq!(key("en", "a"), key("en", "a"));
+        assert_ne!(key("en", "a"), key("en", "b"));
+        assert_ne!(key("en", "a"), key("no", "a"));
+        assert_ne!(
+            key("en", "a"),
+            WarmKey::new(url, model, "en", "a", AudioEncoding::G711Ulaw, near)
+        );
+        assert_ne!(
+            key("en", "a"),
+            WarmKey::new(url, "whisper-1", "en", "a", AudioEncoding::Pcm16, near)
+        );
+        assert_ne!(
+            key("en", "a"),
+            WarmKey::new(
+                url,
+                model,
+                "en",
+                "a",
+                AudioEncoding::Pcm16,
+                NoiseReduction::Off
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recording_uses_prepared_connection() {
+        let server = MockRealtimeServer::start(vec![vec![
+            ServerStep::WaitFor("transcription_session.update"),
+            ServerStep::WaitFor("input_audio_buffer.append"),
+            ServerStep::Send(json!({
+                "type": "conversation.item.input_audio_transcription.completed",
+                "transcript": "Ready when you are.",
+            })),
+        ]])
+        .await;
+        let url = server.openai_url();
+        prewarm_openai_at(
+            &url,
+            TranscribeModel::default(),
+            "test-key",
+            "en",
+            AudioEncoding::Pcm16,
+            NoiseReduction::default(),
+        )
+        .await
+        .unwrap();
+
+        let client = Arc::new(TranscriptionClient::new_openai("en".to_string()));
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = audio_with_chunk();
+        let run_client = client.clone();
+        let run = tokio::spawn(async move {
+            run_client
+                .start_openai_at(&url, TranscribeModel::default(), "test-key", audio_rx)
+                .await
+        });
+
+        events_until(&mut events, |e| {
+            matches!(e, TranscriptEvent::CommittedTranscript { .. })
+        })
+        .await;
+        drop(audio_tx);
+        timeout(SCENARIO_TIMEOUT, run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        // One connection, initialized once, before the recording started
+        let received = server.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0]
+                .iter()
+                .filter(|t| *t == "transcription_session.update")
+                .count(),
+            1
+        );
+    }
+}
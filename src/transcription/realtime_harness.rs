@@ -0,0 +1,433 @@
+//! Local WebSocket server for exercising the realtime connection loops
+//!
+//! Each accepted connection plays one script of [`ServerStep`]s, then keeps
+//! reading until the client closes. The type of every text message the client
+//! sends is recorded per connection so tests can check the protocol.
+
+use futures_util::{SinkExt, StreamExt};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use super::TranscriptEvent;
+use crate::audio::AudioChunk;
+
+/// Upper bound for any single wait in a scenario
+pub(super) const SCENARIO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What the server does next on a connection
+pub(super) enum ServerStep {
+    /// Read client messages until one of this type arrives
+    WaitFor(&'static str),
+    /// Send a JSON message
+    Send(serde_json::Value),
+    /// Send a close frame and end the connection
+    Close,
+}
+
+/// Realtime API stand-in listening on localhost
+pub(super) struct MockRealtimeServer {
+    addr: std::net::SocketAddr,
+    received: Arc<Mutex<Vec<Vec<String>>>>,
+}
+
+impl MockRealtimeServer {
+    /// Start serving one script per incoming connection.
+    ///
+    /// Once every script has been used, further connections are refused.
+    pub(super) async fn start(scripts: Vec<Vec<ServerStep>>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let received_clone = received.clone();
+        tokio::spawn(async move {
+            for script in scripts {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let Ok(ws) = tokio_tungstenite::accept_async(stream).await else {
+                    continue;
+                };
+                let index = {
+                    let mut received = received_clone.lock().unwrap();
+                    received.push(Vec::new());
+                    received.len() - 1
+                };
+                tokio::spawn(serve(ws, script, received_clone.clone(), index));
+            }
+        });
+
+        Self { addr, received }
+    }
+
+    /// Endpoint to pass to `start_azure`
+    pub(super) fn azure_endpoint(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// URL to pass to `start_openai_at`
+    pub(super) fn openai_url(&self) -> String {
+        format!("ws://{}/v1/realtime?intent=transcription", self.addr)
+    }
+
+    /// Message types received so far, one list per connection
+    pub(super) fn received(&self) -> Vec<Vec<String>> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+/// Play a script, then drain the connection until the client goes away
+async fn serve<S>(
+    mut ws: WebSocketStream<S>,
+    script: Vec<ServerStep>,
+    received: Arc<Mutex<Vec<Vec<String>>>>,
+    index: usize,
+) where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    for step in script {
+        match step {
+            ServerStep::WaitFor(expected) => loop {
+                match next_message_type(&mut ws).await {
+                    Some(Some(message_type)) => {
+                        received.lock().unwrap()[index].push(message_type.clone());
+                        if message_type == expected {
+                            break;
+                        }
+                    }
+                    Some(None) => {}
+                    None => return,
+                }
+            },
+            ServerStep::Send(json) => {
+                if ws.send(Message::Text(json.to_string())).await.is_err() {
+                    return;
+                }
+            }
+            ServerStep::Close => {
+                let _ = ws.close(None).await;
+                return;
+            }
+        }
+    }
+
+    while let Some(message) = next_message_type(&mut ws).await {
+        if let Some(message_type) = message {
+            received.lock().unwrap()[index].push(message_type);
+        }
+    }
+}
+
+/// The `type` of the next text message, `Some(None)` for other frames,
+/// or `None` once the connection is gone
+async fn next_message_type<S>(ws: &mut WebSocketStream<S>) -> Option<Option<String>>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    match ws.next().await? {
+        Ok(Message::Text(text)) => {
+            let value: serde_json::Value = serde_json::from_str(&text).ok()?;
+            Some(value["type"].as_str().map(str::to_string))
+        }
+        Ok(Message::Close(_)) | Err(_) => None,
+        Ok(_) => Some(None),
+    }
+}
+
+/// One 100ms chunk of quiet audio
+fn audio_chunk() -> AudioChunk {
+    AudioChunk {
+        samples: vec![0; 2400],
+        sample_rate: 24000,
+        captured_at: Instant::now(),
+    }
+}
+
+/// Receive events until one matches, returning everything seen so far
+pub(super) async fn events_until(
+    events: &mut broadcast::Receiver<TranscriptEvent>,
+    done: impl Fn(&TranscriptEvent) -> bool,
+) -> Vec<TranscriptEvent> {
+    let mut seen = Vec::new();
+    timeout(SCENARIO_TIMEOUT, async {
+        loop {
+            let event = events.recv().await.unwrap();
+            let finished = done(&event);
+            seen.push(event);
+            if finished {
+                break;
+            }
+        }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("timed out waiting for event, saw {:?}", seen));
+    seen
+}
+
+/// Audio channel with one chunk already queued
+pub(super) fn audio_with_chunk() -> (mpsc::Sender<AudioChunk>, mpsc::Receiver<AudioChunk>) {
+    let (tx, rx) = mpsc::channel(16);
+    tx.try_send(audio_chunk()).unwrap();
+    (tx, rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keychain::DEFAULT_REALTIME_API_VERSION;
+    use crate::transcription::{TranscribeModel, TranscriptionClient, TranscriptionError};
+    use serde_json::json;
+
+    fn delta(text: &str) -> ServerStep {
+        ServerStep::Send(json!({
+            "type": "conversation.item.input_audio_transcription.delta",
+            "delta": text,
+        }))
+    }
+
+    fn completed(text: &str) -> ServerStep {
+        ServerStep::Send(json!({
+            "type": "conversation.item.input_audio_transcription.completed",
+            "transcript": text,
+        }))
+    }
+
+    fn quota_error() -> ServerStep {
+        ServerStep::Send(json!({
+            "type": "error",
+            "error": {
+                "type": "insufficient_quota",
+                "code": "insufficient_quota",
+                "message": "You exceeded your current quota.",
+            },
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_azure_connect_and_transcribe() {
+        let server = MockRealtimeServer::start(vec![vec![
+            ServerStep::WaitFor("session.update"),
+            ServerStep::WaitFor("input_audio_buffer.append"),
+            delta("Hello"),
+            completed("Hello world."),
+        ]])
+        .await;
+        let client = Arc::new(TranscriptionClient::new_azure("en".to_string()));
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = audio_with_chunk();
+
+        let endpoint = server.azure_endpoint();
+        let run_client = client.clone();
+        let run = tokio::spawn(async move {
+            run_client
+                .start_azure(
+                    &endpoint,
+                    "gpt-4o-transcribe",
+                    DEFAULT_REALTIME_API_VERSION,
+                    "test-key",
+                    audio_rx,
+                )
+                .await
+        });
+
+        let seen = events_until(&mut events, |e| {
+            matches!(e, TranscriptEvent::CommittedTranscript { .. })
+        })
+        .await;
+        assert!(matches!(
+            &seen[0],
+            TranscriptEvent::PartialTranscript { text } if text == "Hello"
+        ));
+
+        drop(audio_tx);
+        timeout(SCENARIO_TIMEOUT, run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            client.settled_session().await.full_transcript(),
+            "Hello world."
+        );
+        let received = server.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0][0], "session.update");
+        assert!(received[0].ends_with(&[
+            "input_audio_buffer.commit".to_string(),
+            "response.create".to_string()
+        ]));
+    }
+
+    #[tokio::test]
+    async fn test_azure_reconnects_and_keeps_partial() {
+        let server = MockRealtimeServer::start(vec![
+            vec![
+                ServerStep::WaitFor("session.update"),
+                delta("Before the drop"),
+                ServerStep::Close,
+            ],
+            vec![
+                ServerStep::WaitFor("session.update"),
+                completed("After reconnecting."),
+            ],
+        ])
+        .await;
+        let client = Arc::new(TranscriptionClient::new_azure(String::new()));
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = audio_with_chunk();
+
+        let endpoint = server.azure_endpoint();
+        let run_client = client.clone();
+        let run = tokio::spawn(async move {
+            run_client
+                .start_azure(
+                    &endpoint,
+                    "gpt-4o-transcribe",
+                    DEFAULT_REALTIME_API_VERSION,
+                    "test-key",
+                    audio_rx,
+                )
+                .await
+        });
+
+        let seen = events_until(&mut events, |e| {
+            matches!(e, TranscriptEvent::CommittedTranscript { .. })
+        })
+        .await;
+        assert!(seen
+            .iter()
+            .any(|e| matches!(e, TranscriptEvent::ConnectionLost)));
+        assert!(seen
+            .iter()
+            .any(|e| matches!(e, TranscriptEvent::Reconnecting { attempt: 1 })));
+        assert!(seen
+            .iter()
+            .any(|e| matches!(e, TranscriptEvent::Reconnected)));
+
+        drop(audio_tx);
+        timeout(SCENARIO_TIMEOUT, run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            client.settled_session().await.full_transcript(),
+            "Before the drop After reconnecting."
+        );
+        assert_eq!(server.received().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_openai_connect_and_transcribe() {
+        let server = MockRealtimeServer::start(vec![vec![
+            ServerStep::WaitFor("transcription_session.update"),
+            ServerStep::WaitFor("input_audio_buffer.append"),
+            completed("Testing one two."),
+        ]])
+        .await;
+        let client = Arc::new(TranscriptionClient::new_openai("en".to_string()));
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = audio_with_chunk();
+
+        let url = server.openai_url();
+        let run_client = client.clone();
+        let run = tokio::spawn(async move {
+            run_client
+                .start_openai_at(&url, TranscribeModel::default(), "test-key", audio_rx)
+                .await
+        });
+
+        events_until(&mut events, |e| {
+            matches!(e, TranscriptEvent::CommittedTranscript { .. })
+        })
+        .await;
+        drop(audio_tx);
+        timeout(SCENARIO_TIMEOUT, run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            client.settled_session().await.full_transcript(),
+            "Testing one two."
+        );
+        let received = server.received();
+        assert_eq!(received[0][0], "transcription_session.update");
+        assert_eq!(
+            received[0].last().map(String::as_str),
+            Some("input_audio_buffer.commit")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openai_quota_stops_without_reconnecting() {
+        let server = MockRealtimeServer::start(vec![vec![
+            ServerStep::WaitFor("transcription_session.update"),
+            quota_error(),
+        ]])
+        .await;
+        let client = Arc::new(TranscriptionClient::new_openai(String::new()));
+        let mut events = client.subscribe();
+        let (audio_tx, audio_rx) = audio_with_chunk();
+
+        let url = server.openai_url();
+        let run_client = client.clone();
+        let run = tokio::spawn(async move {
+            run_client
+                .start_openai_at(&url, TranscribeModel::default(), "test-key", audio_rx)
+                .await
+        });
+
+        let seen = events_until(&mut events, |e| matches!(e, TranscriptEvent::Error { .. })).await;
+        assert!(matches!(
+            seen.last(),
+            Some(TranscriptEvent::Error { message }) if message.contains("quota")
+        ));
+
+        // The session ends by itself once recording stops; no reconnect is attempted
+        drop(audio_tx);
+        timeout(SCENARIO_TIMEOUT, run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(!std::iter::from_fn(|| events.try_recv().ok())
+            .any(|e| matches!(e, TranscriptEvent::Reconnecting { .. })));
+        assert_eq!(server.received().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_first_connection_failure_is_an_error() {
+        // Bind and release a port so nothing is listening on it
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = TranscriptionClient::new_azure("en".to_string());
+        let (_audio_tx, audio_rx) = mpsc::channel(1);
+
+        let result = client
+            .start_azure(
+                &format!("http://{}", addr),
+                "gpt-4o-transcribe",
+                DEFAULT_REALTIME_API_VERSION,
+                "test-key",
+                audio_rx,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(TranscriptionError::ConnectionError(_))
+        ));
+    }
+}
@@ -1,21 +1,78 @@
 //! Transcription session state management
+//!
+//! The session is owned by a single actor task. The receive tasks, the UI
+//! and the polishing path send it [`SessionCommand`]s and read the latest
+//! state from a watch snapshot, so nobody holds a lock the receive task waits
+//! on and there is no mutex to poison.
+//!
+//! The actor is also the only sender of [`TranscriptEvent`]s. An event is
+//! published after every command sent before it has been applied, so a
+//! listener that reads the snapshot when it sees an event always finds the
+//! change the event describes.
+
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tracing::info;
+
+use super::{dedup, formatting, Normalizer, TranscriptEvent};
+
+/// Outage after which the transcript notes that audio may be missing
+const GAP_MARKER_AFTER: Duration = Duration::from_secs(10);
 
 /// Accumulated transcription session data
 #[derive(Debug, Default, Clone)]
 pub struct TranscriptionSession {
     /// All committed transcript segments
     pub committed_segments: Vec<String>,
+    /// When each speech segment was committed, as (segment index, time since
+    /// the session started)
+    pub commit_offsets: Vec<(usize, Duration)>,
     /// Current partial transcript (if any)
     pub partial_transcript: Option<String>,
     /// Flag to indicate recording was manually stopped (not connection lost)
     /// Used to prevent ConnectionLost events from overwriting polished transcript
     pub manually_stopped: bool,
+    /// Commits still checked for text repeated after a reconnect
+    pub recheck_commits: usize,
+    /// Indexes of committed segments that start a paragraph
+    pub paragraph_starts: Vec<usize>,
+    /// Whether the next committed segment follows a long pause
+    pub paragraph_pending: bool,
+    /// Rewrites spoken numbers, dates and amounts in committed text
+    pub normalizer: Option<Normalizer>,
+    /// Language codes transcribed in, as (index of the first segment in the
+    /// language, code); the first is the language the session started in
+    pub language_switches: Vec<(usize, String)>,
+    /// When the connection was lost, and the wall-clock time (e.g. "14:02"),
+    /// until it is back
+    pub connection_lost_at: Option<(Instant, String)>,
 }
 
 impl TranscriptionSession {
-    /// Get the full transcript text
+    /// Get the full transcript text, in sentences and paragraphs
     pub fn full_transcript(&self) -> String {
-        self.committed_segments.join(" ")
+        self.transcript_with_cues(|_| None)
+    }
+
+    /// Full transcript text with `cue(index)` before the speech segment at `index`
+    pub fn transcript_with_cues(&self, cue: impl FnMut(usize) -> Option<String>) -> String {
+        formatting::format_transcript(&self.committed_segments, &self.paragraph_starts, cue)
+    }
+
+    /// `text` with spoken numbers, dates and amounts written in digits
+    fn normalized(&self, text: String) -> String {
+        match &self.normalizer {
+            Some(normalizer) => normalizer.apply(&text),
+            None => text,
+        }
+    }
+
+    /// Add spoken text, starting a paragraph if it follows a long pause
+    fn push_speech(&mut self, text: String) {
+        if std::mem::take(&mut self.paragraph_pending) {
+            self.paragraph_starts.push(self.committed_segments.len());
+        }
+        self.committed_segments.push(text);
     }
 
     /// Insert a screenshot reference at the current position in the transcript
@@ -30,4 +87,415 @@ impl TranscriptionSession {
         let markdown_ref = format!("\n\n![Screenshot]({})\n\n", relative_path);
         self.committed_segments.push(markdown_ref);
     }
+
+    /// Insert a timestamped marker at the current position in the transcript
+    ///
+    /// Markers flag important moments so they can be found later, and are
+    /// preserved by the polishing prompts.
+    ///
+    /// # Arguments
+    /// * `time` - The wall-clock time of the marker (e.g., "14:32")
+    pub fn insert_marker(&mut self, time: &str) {
+        let marker = format!("\n\n=== MARKER {} ===\n\n", time);
+        self.committed_segments.push(marker);
+    }
+
+    /// Insert a typed note at the current position in the transcript
+    ///
+    /// Used for context that isn't in the audio (e.g., "Bob joined late").
+    /// The note is rendered as a blockquote on a single line.
+    pub fn insert_note(&mut self, text: &str) {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let note = format!("\n\n> Note: {}\n\n", text);
+        self.committed_segments.push(note);
+    }
+
+    /// Insert a note that audio may be missing between two wall-clock times
+    ///
+    /// Audio captured while reconnecting is buffered and resent, but a long
+    /// outage can outlast the buffer, and the transcript would otherwise join
+    /// the text before and after it as if nothing happened.
+    pub fn insert_gap(&mut self, from: &str, to: &str) {
+        let gap = format!(
+            "\n\n[connection lost {}\u{2013}{}, audio may be missing]\n\n",
+            from, to
+        );
+        self.committed_segments.push(gap);
+    }
+
+    /// Tag segments committed from now on with `code`
+    ///
+    /// A switch nobody spoke after yet is replaced, so cycling through
+    /// languages leaves only the one settled on.
+    fn switch_language(&mut self, code: String) {
+        let index = self.committed_segments.len();
+        if self
+            .language_switches
+            .last()
+            .is_some_and(|(start, _)| *start == index)
+        {
+            self.language_switches.pop();
+        }
+        if self
+            .language_switches
+            .last()
+            .is_none_or(|(_, current)| *current != code)
+        {
+            self.language_switches.push((index, code));
+        }
+    }
+
+    /// Track a lost connection, and mark the gap once it is back if it was long
+    fn track_connection(&mut self, event: &TranscriptEvent) {
+        match event {
+            TranscriptEvent::ConnectionLost => {
+                if self.connection_lost_at.is_none() && !self.manually_stopped {
+                    self.connection_lost_at = Some((Instant::now(), wall_clock_time()));
+                }
+            }
+            TranscriptEvent::Reconnected | TranscriptEvent::ProviderActive { .. } => {
+                if let Some((since, from)) = self.connection_lost_at.take() {
+                    if since.elapsed() >= GAP_MARKER_AFTER {
+                        info!("Marking a {:?} gap in the transcript", since.elapsed());
+                        self.insert_gap(&from, &wall_clock_time());
+                    }
+                }
+            }
+            // Transcription has stopped, so a later provider starts afresh
+            TranscriptEvent::ReconnectFailed => self.connection_lost_at = None,
+            _ => {}
+        }
+    }
+
+    /// Apply a command, returning the event to publish once it's visible
+    fn apply(&mut self, command: SessionCommand) -> Option<TranscriptEvent> {
+        match command {
+            SessionCommand::Commit(text) => {
+                let mut text = self.normalized(text);
+                if self.recheck_commits > 0 {
+                    self.recheck_commits -= 1;
+                    text = dedup::without_repeats(&text, &self.committed_segments);
+                    if text.is_empty() {
+                        info!("Dropped a segment repeated after reconnecting");
+                    }
+                }
+                if !text.is_empty() {
+                    self.push_speech(text.clone());
+                }
+                self.partial_transcript = None;
+                Some(TranscriptEvent::CommittedTranscript { text })
+            }
+            SessionCommand::Partial(text) => {
+                self.partial_transcript = Some(text.clone());
+                Some(TranscriptEvent::PartialTranscript { text })
+            }
+            SessionCommand::PreservePartial { reason } => {
+                if let Some(partial) = self.partial_transcript.take() {
+                    if !partial.trim().is_empty() {
+                        info!(
+                            "Preserving partial transcript before {}: {} chars",
+                            reason,
+                            partial.len()
+                        );
+                        let partial = self.normalized(partial);
+                        self.push_speech(partial);
+                    }
+                }
+                None
+            }
+            SessionCommand::InsertScreenshot(relative_path) => {
+                self.insert_screenshot(&relative_path);
+                Some(TranscriptEvent::SegmentInserted)
+            }
+            SessionCommand::InsertMarker(time) => {
+                self.insert_marker(&time);
+                Some(TranscriptEvent::SegmentInserted)
+            }
+            SessionCommand::InsertNote(text) => {
+                self.insert_note(&text);
+                Some(TranscriptEvent::SegmentInserted)
+            }
+            SessionCommand::ParagraphBreak => {
+                self.paragraph_pending = !self.committed_segments.is_empty();
+                None
+            }
+            SessionCommand::MarkManuallyStopped => {
+                self.manually_stopped = true;
+                None
+            }
+            SessionCommand::Normalize(normalizer) => {
+                self.normalizer = normalizer;
+                None
+            }
+            SessionCommand::SwitchLanguage(code) => {
+                self.switch_language(code);
+                None
+            }
+            SessionCommand::Clear => {
+                let language = self.language_switches.pop();
+                *self = Self {
+                    normalizer: self.normalizer,
+                    language_switches: language
+                        .map(|(_, code)| vec![(0, code)])
+                        .unwrap_or_default(),
+                    ..Self::default()
+                };
+                None
+            }
+            SessionCommand::Notify(event) => {
+                self.track_connection(&event);
+                // Audio buffered before the switch is sent again
+                if matches!(
+                    event,
+                    TranscriptEvent::Reconnected | TranscriptEvent::ProviderActive { .. }
+                ) {
+                    self.recheck_commits = dedup::CHECKED_COMMITS;
+                }
+                Some(event)
+            }
+            SessionCommand::Flush(done) => {
+                let _ = done.send(());
+                None
+            }
+        }
+    }
+}
+
+/// Wall-clock time for markers in the transcript, e.g. "14:02"
+fn wall_clock_time() -> String {
+    chrono::Local::now().format("%H:%M").to_string()
+}
+
+/// Change requested of the session actor, applied in the order sent
+#[derive(Debug)]
+pub enum SessionCommand {
+    /// The service finalized a segment
+    Commit(String),
+    /// The service's current text for the segment in progress
+    Partial(String),
+    /// Keep the partial text as committed before the connection goes away
+    PreservePartial { reason: &'static str },
+    /// Reference a saved screenshot at the current position
+    InsertScreenshot(String),
+    /// Flag the current moment with a wall-clock time
+    InsertMarker(String),
+    /// Add a typed note at the current position
+    InsertNote(String),
+    /// The speaker paused long enough to start a new paragraph
+    ParagraphBreak,
+    /// The user stopped recording, so a lost connection is expected
+    MarkManuallyStopped,
+    /// Rewrite committed text from now on, or stop rewriting it
+    Normalize(Option<Normalizer>),
+    /// Tag segments committed from now on with a language code
+    SwitchLanguage(String),
+    /// Start over with an empty session, keeping how text is rewritten and
+    /// the language
+    Clear,
+    /// Publish an event after all earlier commands are applied
+    Notify(TranscriptEvent),
+    /// Signal once all earlier commands are applied
+    Flush(oneshot::Sender<()>),
+}
+
+/// Handle to a session actor, cheap to clone and share across tasks
+#[derive(Debug, Clone)]
+pub struct SessionHandle {
+    commands: mpsc::UnboundedSender<SessionCommand>,
+    snapshot: watch::Receiver<TranscriptionSession>,
+}
+
+impl SessionHandle {
+    /// Start a session actor on the current Tokio runtime
+    ///
+    /// The actor publishes transcript events on `event_tx` and exits once
+    /// every handle is dropped.
+    pub fn spawn(event_tx: broadcast::Sender<TranscriptEvent>) -> Self {
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let (state_tx, snapshot) = watch::channel(TranscriptionSession::default());
+        tokio::spawn(run_session_actor(command_rx, state_tx, event_tx));
+        Self { commands, snapshot }
+    }
+
+    /// Queue a change; never blocks
+    pub fn send(&self, command: SessionCommand) {
+        // The actor outlives every handle, so this only fails during shutdown
+        let _ = self.commands.send(command);
+    }
+
+    /// Publish an event once everything sent so far is applied
+    pub fn notify(&self, event: TranscriptEvent) {
+        self.send(SessionCommand::Notify(event));
+    }
+
+    /// Copy of the latest session state
+    pub fn snapshot(&self) -> TranscriptionSession {
+        self.snapshot.borrow().clone()
+    }
+
+    /// Session state once every command sent so far is applied
+    ///
+    /// Used when a provider finishes, so segments it committed just before
+    /// returning are part of the final transcript.
+    pub async fn settled(&self) -> TranscriptionSession {
+        let (done_tx, done_rx) = oneshot::channel();
+        self.send(SessionCommand::Flush(done_tx));
+        let _ = done_rx.await;
+        self.snapshot()
+    }
+
+    /// Committed transcript text of the latest state
+    pub fn full_transcript(&self) -> String {
+        self.snapshot.borrow().full_transcript()
+    }
+
+    /// Committed text and any partial text of the latest state
+    pub fn live_text(&self) -> (String, Option<String>) {
+        let session = self.snapshot.borrow();
+        (
+            session.full_transcript(),
+            session.partial_transcript.clone(),
+        )
+    }
+
+    /// Whether the user stopped the recording
+    pub fn manually_stopped(&self) -> bool {
+        self.snapshot.borrow().manually_stopped
+    }
+}
+
+/// Apply commands in order, updating the snapshot before publishing events
+async fn run_session_actor(
+    mut commands: mpsc::UnboundedReceiver<SessionCommand>,
+    state: watch::Sender<TranscriptionSession>,
+    event_tx: broadcast::Sender<TranscriptEvent>,
+) {
+    let started = Instant::now();
+    while let Some(command) = commands.recv().await {
+        let mut event = None;
+        state.send_if_modified(|session| {
+            let index = session.committed_segments.len();
+            let commits = matches!(command, SessionCommand::Commit(_));
+            let changes_state = !matches!(
+                command,
+                SessionCommand::Notify(_) | SessionCommand::Flush(_)
+            );
+            event = session.apply(command);
+            if commits && session.committed_segments.len() > index {
+                session.commit_offsets.push((index, started.elapsed()));
+            }
+            changes_state
+        });
+        if let Some(event) = event {
+            let _ = event_tx.send(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_events_follow_the_changes_they_describe() {
+        let (event_tx, mut events) = broadcast::channel(16);
+        let session = SessionHandle::spawn(event_tx);
+
+        session.send(SessionCommand::Partial("Hello".to_string()));
+        session.send(SessionCommand::Commit("Hello world.".to_string()));
+        session.send(SessionCommand::Partial("And".to_string()));
+        session.send(SessionCommand::PreservePartial {
+            reason: "connection close",
+        });
+        session.notify(TranscriptEvent::ConnectionLost);
+
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TranscriptEvent::PartialTranscript { ref text } if text == "Hello"
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TranscriptEvent::CommittedTranscript { ref text } if text == "Hello world."
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TranscriptEvent::PartialTranscript { .. }
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TranscriptEvent::ConnectionLost
+        ));
+        assert_eq!(session.live_text(), ("Hello world. And".to_string(), None));
+    }
+
+    #[tokio::test]
+    async fn test_inserts_and_clear() {
+        let (event_tx, mut events) = broadcast::channel(16);
+        let session = SessionHandle::spawn(event_tx);
+
+        session.send(SessionCommand::InsertNote("Bob   joined late".to_string()));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TranscriptEvent::SegmentInserted
+        ));
+        assert_eq!(session.full_transcript(), "\n\n> Note: Bob joined late\n\n");
+
+        session.send(SessionCommand::MarkManuallyStopped);
+        assert!(session.settled().await.manually_stopped);
+
+        session.send(SessionCommand::Clear);
+        let cleared = session.settled().await;
+        assert!(!cleared.manually_stopped);
+        assert!(cleared.committed_segments.is_empty());
+    }
+
+    #[test]
+    fn test_language_switches_tag_later_segments() {
+        let mut session = TranscriptionSession::default();
+        session.switch_language("en".to_string());
+        session.push_speech("Hello.".to_string());
+
+        session.switch_language("en".to_string());
+        session.switch_language("de".to_string());
+        // Cycled past before anything was said
+        session.switch_language("no".to_string());
+        session.push_speech("Hei.".to_string());
+        session.switch_language("da".to_string());
+        session.switch_language("no".to_string());
+
+        assert_eq!(
+            session.language_switches,
+            [(0, "en".to_string()), (1, "no".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_long_outage_is_marked_when_reconnected() {
+        let mut session = TranscriptionSession::default();
+        session.push_speech("Before.".to_string());
+
+        // A short reconnect leaves no trace
+        session.track_connection(&TranscriptEvent::ConnectionLost);
+        session.track_connection(&TranscriptEvent::Reconnected);
+        assert_eq!(session.committed_segments.len(), 1);
+
+        session.connection_lost_at = Instant::now()
+            .checked_sub(Duration::from_secs(30))
+            .map(|since| (since, "14:02".to_string()));
+        session.track_connection(&TranscriptEvent::Reconnected);
+        let gap = &session.committed_segments[1];
+        assert!(gap.starts_with("\n\n[connection lost 14:02\u{2013}"));
+        assert!(gap.ends_with(", audio may be missing]\n\n"));
+        assert!(session.connection_lost_at.is_none());
+    }
+
+    #[test]
+    fn test_failed_reconnect_forgets_the_outage() {
+        let mut session = TranscriptionSession::default();
+        session.track_connection(&TranscriptEvent::ConnectionLost);
+        assert!(session.connection_lost_at.is_some());
+
+        session.track_connection(&TranscriptEvent::ReconnectFailed);
+        assert!(session.connection_lost_at.is_none());
+    }
 }
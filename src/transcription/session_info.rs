@@ -0,0 +1,115 @@
+//! Session configuration the provider reports back
+//!
+//! `session.created` / `session.updated` (and OpenAI's `transcription_session.*`)
+//! echo the configuration the server actually applied, which can differ from
+//! what was asked for, e.g. a model the deployment doesn't offer. Both
+//! providers use the same shape, so one type parses either.
+
+use serde::Deserialize;
+
+use super::session::SessionHandle;
+use super::TranscriptEvent;
+
+/// Configuration applied by the provider to the running session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SessionInfo {
+    /// Realtime model of the session, absent for transcription-only sessions
+    pub model: Option<String>,
+    /// Format the server expects audio in, e.g. "pcm16"
+    pub input_audio_format: Option<String>,
+    pub input_audio_transcription: Option<TranscriptionSettings>,
+    pub turn_detection: Option<TurnDetection>,
+    pub input_audio_noise_reduction: Option<NoiseReductionSettings>,
+}
+
+/// Transcription part of the session configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct TranscriptionSettings {
+    pub model: Option<String>,
+    pub language: Option<String>,
+}
+
+/// Voice activity detection of the session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct TurnDetection {
+    /// e.g. "server_vad" or "semantic_vad"
+    #[serde(rename = "type")]
+    pub mode: Option<String>,
+}
+
+/// Noise reduction of the session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct NoiseReductionSettings {
+    /// e.g. "near_field" or "far_field"
+    #[serde(rename = "type")]
+    pub mode: Option<String>,
+}
+
+impl SessionInfo {
+    /// Model transcribing the audio, falling back to the session's model
+    pub fn transcription_model(&self) -> Option<&str> {
+        self.input_audio_transcription
+            .as_ref()
+            .and_then(|t| t.model.as_deref())
+            .or(self.model.as_deref())
+    }
+
+    /// Language the server transcribes in, if one was set
+    pub fn language(&self) -> Option<&str> {
+        self.input_audio_transcription
+            .as_ref()
+            .and_then(|t| t.language.as_deref())
+    }
+
+    /// Voice activity detection mode; None when turn detection is off
+    pub fn vad_mode(&self) -> Option<&str> {
+        self.turn_detection.as_ref().and_then(|t| t.mode.as_deref())
+    }
+
+    /// Noise reduction mode; None when it is off
+    pub fn noise_reduction(&self) -> Option<&str> {
+        self.input_audio_noise_reduction
+            .as_ref()
+            .and_then(|n| n.mode.as_deref())
+    }
+}
+
+/// Tell subscribers the configuration a session message reported, if any
+pub(super) fn notify_configured(session: &SessionHandle, info: Option<&SessionInfo>) {
+    if let Some(info) = info {
+        session.notify(TranscriptEvent::SessionConfigured { info: info.clone() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_transcription_session() {
+        let json = r#"{
+            "id": "sess_1",
+            "object": "realtime.transcription_session",
+            "input_audio_format": "g711_ulaw",
+            "input_audio_transcription": {"model": "gpt-4o-transcribe", "language": "nb", "prompt": ""},
+            "turn_detection": {"type": "server_vad", "threshold": 0.5, "silence_duration_ms": 200},
+            "input_audio_noise_reduction": null
+        }"#;
+        let info: SessionInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(info.transcription_model(), Some("gpt-4o-transcribe"));
+        assert_eq!(info.language(), Some("nb"));
+        assert_eq!(info.vad_mode(), Some("server_vad"));
+        assert_eq!(info.input_audio_format.as_deref(), Some("g711_ulaw"));
+        assert_eq!(info.noise_reduction(), None);
+    }
+
+    #[test]
+    fn test_falls_back_to_session_model() {
+        let json = r#"{"model": "gpt-4o-realtime-preview", "turn_detection": null}"#;
+        let info: SessionInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(info.transcription_model(), Some("gpt-4o-realtime-preview"));
+        assert_eq!(info.vad_mode(), None);
+    }
+}
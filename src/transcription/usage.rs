@@ -0,0 +1,84 @@
+//! Data sent and received by the current recording
+//!
+//! Counts the audio messages sent and the events received over the realtime
+//! connection, across reconnects, so users on a tethered phone or conference
+//! Wi-Fi can see what a recording costs. WebSocket and TLS framing add a few
+//! percent on top of these figures.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static SENT: AtomicU64 = AtomicU64::new(0);
+static RECEIVED: AtomicU64 = AtomicU64::new(0);
+
+/// Bytes exchanged with the provider since the recording started
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DataUsage {
+    pub(crate) sent: u64,
+    pub(crate) received: u64,
+}
+
+impl DataUsage {
+    /// Short figure for the transcription window footer, e.g. "↑ 1.2 MB ↓ 48 KB"
+    pub(crate) fn summary(&self) -> String {
+        format!(
+            "↑ {} ↓ {}",
+            format_bytes(self.sent),
+            format_bytes(self.received)
+        )
+    }
+}
+
+/// Start counting from zero for a new recording
+pub(crate) fn start_counting() {
+    SENT.store(0, Ordering::Relaxed);
+    RECEIVED.store(0, Ordering::Relaxed);
+}
+
+/// Bytes exchanged since [`start_counting`]
+pub(crate) fn data_usage() -> DataUsage {
+    DataUsage {
+        sent: SENT.load(Ordering::Relaxed),
+        received: RECEIVED.load(Ordering::Relaxed),
+    }
+}
+
+pub(super) fn sent(bytes: usize) {
+    SENT.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+pub(super) fn received(bytes: usize) {
+    RECEIVED.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// Byte count in B, KB or MB (decimal units, as Finder shows them)
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1000 {
+        format!("{} B", bytes)
+    } else if bytes < 1_000_000 {
+        format!("{} KB", bytes / 1000)
+    } else {
+        format!("{:.1} MB", bytes as f64 / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(999), "999 B");
+        assert_eq!(format_bytes(48_500), "48 KB");
+        assert_eq!(format_bytes(1_240_000), "1.2 MB");
+    }
+
+    #[test]
+    fn test_summary() {
+        let usage = DataUsage {
+            sent: 3_400_000,
+            received: 12_000,
+        };
+        assert_eq!(usage.summary(), "↑ 3.4 MB ↓ 12 KB");
+    }
+}
@@ -0,0 +1,126 @@
+//! VoiceOver announcements for the live transcript
+//!
+//! AppKit has no live regions, so newly committed transcript segments are
+//! read out with an announcement request instead. Announcements are only
+//! posted while VoiceOver is running.
+
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject, Bool};
+use objc2_foundation::NSString;
+
+/// NSAccessibilityAnnouncementRequestedNotification
+const ANNOUNCEMENT_REQUESTED_NOTIFICATION: &str = "AXAnnouncementRequested";
+
+/// NSAccessibilityAnnouncementKey
+const ANNOUNCEMENT_KEY: &str = "AXAnnouncementKey";
+
+/// NSAccessibilityPriorityKey
+const PRIORITY_KEY: &str = "AXPriorityKey";
+
+/// NSAccessibilityPriorityMedium: queued behind speech already in progress
+const PRIORITY_MEDIUM: isize = 50;
+
+extern "C" {
+    fn NSAccessibilityPostNotificationWithUserInfo(
+        element: *mut AnyObject,
+        notification: *const NSString,
+        user_info: *mut AnyObject,
+    );
+}
+
+/// Committed text that has not been announced yet.
+///
+/// Returns `None` when nothing new was committed, or when the transcript no
+/// longer starts with what was announced (a new session or a manual edit).
+pub(super) fn unannounced_text<'a>(committed: &'a str, announced: &str) -> Option<&'a str> {
+    committed
+        .strip_prefix(announced)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+/// Ask VoiceOver to read `text` without moving focus.
+pub(super) fn announce(text: &str) {
+    if !is_voice_over_enabled() {
+        return;
+    }
+
+    let (Some(app_class), Some(number_class), Some(dictionary_class)) = (
+        AnyClass::get("NSApplication"),
+        AnyClass::get("NSNumber"),
+        AnyClass::get("NSMutableDictionary"),
+    ) else {
+        return;
+    };
+
+    // SAFETY: class methods on AppKit/Foundation classes checked above; the
+    // dictionary and strings stay alive until the notification is posted
+    unsafe {
+        let app: *mut AnyObject = msg_send![app_class, sharedApplication];
+        if app.is_null() {
+            return;
+        }
+
+        let user_info: *mut AnyObject = msg_send![dictionary_class, dictionary];
+        if user_info.is_null() {
+            return;
+        }
+        let announcement = NSString::from_str(text);
+        let priority: *mut AnyObject = msg_send![number_class, numberWithInteger: PRIORITY_MEDIUM];
+        let _: () = msg_send![
+            user_info,
+            setObject: &*announcement,
+            forKey: &*NSString::from_str(ANNOUNCEMENT_KEY)
+        ];
+        let _: () = msg_send![
+            user_info,
+            setObject: priority,
+            forKey: &*NSString::from_str(PRIORITY_KEY)
+        ];
+
+        let notification = NSString::from_str(ANNOUNCEMENT_REQUESTED_NOTIFICATION);
+        NSAccessibilityPostNotificationWithUserInfo(app, &*notification, user_info);
+    }
+}
+
+/// Whether VoiceOver is currently running
+fn is_voice_over_enabled() -> bool {
+    let Some(workspace_class) = AnyClass::get("NSWorkspace") else {
+        return false;
+    };
+    // SAFETY: sharedWorkspace and isVoiceOverEnabled are safe to call on the main thread
+    unsafe {
+        let workspace: *mut AnyObject = msg_send![workspace_class, sharedWorkspace];
+        if workspace.is_null() {
+            return false;
+        }
+        let enabled: Bool = msg_send![workspace, isVoiceOverEnabled];
+        enabled.as_bool()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_segment_is_unannounced() {
+        assert_eq!(
+            unannounced_text("Hello there. How are you?", "Hello there."),
+            Some("How are you?")
+        );
+    }
+
+    #[test]
+    fn test_nothing_new_is_not_announced() {
+        assert_eq!(unannounced_text("Hello there.", "Hello there."), None);
+        assert_eq!(unannounced_text("Hello there.  ", "Hello there."), None);
+        assert_eq!(unannounced_text("", ""), None);
+    }
+
+    #[test]
+    fn test_rewritten_transcript_is_not_announced() {
+        assert_eq!(unannounced_text("Goodbye.", "Hello there."), None);
+        assert_eq!(unannounced_text("", "Hello there."), None);
+    }
+}
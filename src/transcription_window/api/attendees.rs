@@ -0,0 +1,50 @@
+//! Attendees of the recording, typed in the overlay
+//!
+//! The attendees button opens a small popover below the header with the
+//! session's attendees, separated by commas. Pressing Return saves the list,
+//! which is passed to the meeting notes and follow-up email prompts.
+
+use objc2_foundation::MainThreadMarker;
+use std::sync::atomic::Ordering;
+use tracing::{error, info};
+
+use super::popover::{close_popover, show_field_popover, FieldPopover};
+use crate::attendees;
+use crate::localization::tr;
+use crate::transcription_window::state::IS_RECORDING;
+
+/// Show the attendees popover anchored to the header's attendees button.
+///
+/// The field starts with the attendees entered so far. Does nothing when not
+/// recording. Must be called on the main thread.
+pub(crate) fn show_attendees_popover() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Attendees popover must be shown on the main thread");
+        return;
+    };
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        info!("Attendees ignored: not recording");
+        return;
+    }
+
+    let value = attendees::field_text();
+    show_field_popover(
+        mtm,
+        |inner| &inner.attendees_button,
+        FieldPopover {
+            placeholder: tr("overlay.attendees_placeholder"),
+            accessibility_label: tr("overlay.attendees_field"),
+            value: &value,
+            action: objc2::sel!(handleSubmitAttendees:),
+        },
+    );
+}
+
+/// Handle Return in the attendees popover's text field (called from delegate).
+///
+/// Closes the popover and replaces the session's attendees; an empty field
+/// clears them.
+pub(crate) fn handle_submit_attendees(text: String) {
+    close_popover();
+    attendees::set(&text);
+}
@@ -0,0 +1,149 @@
+//! Debug tab of the transcription window
+//!
+//! Turned on in Settings, the Debug tab is the last segment of the tab
+//! control. It lists the provider's events and connection changes recorded
+//! by the transcription debug log, with timestamps, and follows new entries
+//! while it is shown. Unlike the output mode tabs it can be opened during a
+//! recording, which is when the events matter.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2_foundation::NSString;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::{error, info};
+
+use super::dispatch_to_main;
+use super::tabs::switch_to_tab;
+use super::text::{
+    check_scroll_position_for_view, scroll_to_bottom_for_view, set_text_view_attributed_string,
+};
+use crate::localization::tr;
+use crate::transcription_window::components::set_debug_segment;
+use crate::transcription_window::markdown::create_attributed_string;
+use crate::transcription_window::state::{
+    debug_segment, transcription_window, TranscriptionWindowInner, IS_DARK_MODE,
+};
+use crate::{preferences, transcription};
+
+/// How often a shown Debug tab looks for new entries
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Counts openings of the Debug tab, so only the latest keeps refreshing
+static DEBUG_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Show the Debug tab in place of the active tab and keep it up to date.
+pub(super) fn show_debug_tab() {
+    info!("Debug tab shown");
+    let generation = DEBUG_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in show_debug_tab");
+            return;
+        };
+
+        // SAFETY: msg_send to valid NSSegmentedControl and NSScrollView
+        // objects, and setStringValue on a valid NSTextField
+        unsafe {
+            let _: () = msg_send![&inner.segmented_control, setSelectedSegment: debug_segment()];
+            for (_, scroll_view) in inner.scroll_views() {
+                let _: () = msg_send![scroll_view, setHidden: true];
+            }
+            let _: () = msg_send![&inner.debug_scroll_view, setHidden: false];
+            inner
+                .recording_type_label
+                .setStringValue(&NSString::from_str(tr("overlay.debug_log")));
+        }
+        inner.debug_shown = true;
+
+        render(&inner, true);
+        refresh_later(generation, transcription::debug_log_revision());
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Add or remove the Debug tab after it is turned on or off in Settings.
+///
+/// A shown Debug tab gives way to the tab it was opened from. Main thread only.
+pub(super) fn apply_debug_tab_setting() {
+    let shown = preferences::get_debug_tab();
+    let active_tab = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in apply_debug_tab_setting");
+            return;
+        };
+        set_debug_segment(&inner.segmented_control, shown);
+        (!shown && inner.debug_shown).then_some(inner.active_tab)
+    };
+
+    if let Some(tab) = active_tab {
+        switch_to_tab(tab);
+    }
+}
+
+/// Hide the Debug tab, if shown, so another tab can be shown.
+pub(super) fn hide_debug_tab(inner: &mut TranscriptionWindowInner) {
+    if !inner.debug_shown {
+        return;
+    }
+    inner.debug_shown = false;
+    // SAFETY: msg_send setHidden: to a valid NSScrollView
+    unsafe {
+        let _: () = msg_send![&inner.debug_scroll_view, setHidden: true];
+    }
+}
+
+/// Redraw the shown Debug tab once new entries are recorded, until it is
+/// hidden or opened again.
+fn refresh_later(generation: u64, shown_revision: u64) {
+    dispatch::Queue::main().exec_after(REFRESH_INTERVAL, move || {
+        if DEBUG_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            // Busy with another update; look again next time
+            refresh_later(generation, shown_revision);
+            return;
+        };
+        if !inner.debug_shown {
+            return;
+        }
+
+        let revision = transcription::debug_log_revision();
+        if revision != shown_revision {
+            // Follow new entries unless scrolled up to read older ones
+            let follow = check_scroll_position_for_view(&inner.debug_scroll_view);
+            render(&inner, follow);
+        }
+        refresh_later(generation, revision);
+    });
+}
+
+/// Show the debug log, newest entries at the bottom, scrolling to them if
+/// `scroll_to_end`.
+fn render(inner: &TranscriptionWindowInner, scroll_to_end: bool) {
+    let log = transcription::debug_log_text();
+    let text = if log.is_empty() {
+        tr("overlay.debug_empty")
+    } else {
+        &log
+    };
+
+    let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
+    let attr_string = create_attributed_string(text, is_dark, true);
+    set_text_view_attributed_string(&inner.debug_text_view, &attr_string);
+    if scroll_to_end {
+        scroll_to_bottom_for_view(&inner.debug_text_view);
+    }
+}
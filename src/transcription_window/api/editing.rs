@@ -0,0 +1,94 @@
+//! Manual corrections to the live transcript
+//!
+//! The Live tab becomes editable once recording stops. Edits replace the
+//! stored live transcript, so on-demand polishing, meeting notes, saving and
+//! copying all work from the corrected text.
+
+use objc2::msg_send;
+use objc2::runtime::AnyObject;
+use objc2_foundation::NSAttributedString;
+use std::sync::atomic::Ordering;
+use tracing::{error, info};
+
+use super::tab_content::get_live_transcript;
+use crate::transcription_window::markdown::attributed_string_to_markdown;
+use crate::transcription_window::state::{
+    pending_transcript_storage, transcription_window, TranscriptionWindowInner, SCRIPT_MODE,
+    WINDOW_CALLBACKS,
+};
+
+/// Allow or disallow editing the live transcript.
+///
+/// Must be called on the main thread.
+pub(super) fn set_live_editable(inner: &TranscriptionWindowInner, editable: bool) {
+    // A practice script in the Live tab is never editable
+    let editable = editable && !SCRIPT_MODE.load(Ordering::SeqCst);
+    // SAFETY: setEditable:/setAllowsUndo: are safe on a valid NSTextView
+    unsafe {
+        let _: () = msg_send![&inner.live_text_view, setEditable: editable];
+        let _: () = msg_send![&inner.live_text_view, setAllowsUndo: editable];
+    }
+}
+
+/// Handle a change to the live transcript text (called from the text view delegate).
+///
+/// Stores the edited text as the live transcript and discards generated tab
+/// content, so the next visit to the Polished, Meeting Notes or Email tab
+/// regenerates it from the corrected transcript.
+pub(crate) fn handle_live_text_changed() {
+    let edited = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in handle_live_text_changed");
+            return;
+        };
+
+        // SAFETY: textStorage is an NSMutableAttributedString owned by the text view
+        let edited = unsafe {
+            let text_storage: *mut AnyObject = msg_send![&inner.live_text_view, textStorage];
+            if text_storage.is_null() {
+                return;
+            }
+            let text_storage = &*(text_storage as *const NSAttributedString);
+            attributed_string_to_markdown(text_storage)
+        };
+
+        // Drop the display padding appended after the transcript
+        let edited = edited.trim_end().to_string();
+        if edited == inner.tab_content.live_transcript {
+            return;
+        }
+
+        inner.tab_content.live_transcript = edited.clone();
+        inner.tab_content.generated.clear();
+        edited
+    };
+
+    // The save button now saves the corrected transcript
+    if let Ok(mut stored_transcript) = pending_transcript_storage().write() {
+        *stored_transcript = if edited.trim().is_empty() {
+            None
+        } else {
+            Some(edited)
+        };
+    }
+}
+
+/// Handle the end of an editing session on the live transcript.
+///
+/// Hands the corrected transcript to the app (which copies it to the clipboard).
+pub(crate) fn handle_live_text_end_editing() {
+    let Some(transcript) = get_live_transcript() else {
+        return;
+    };
+    info!(
+        "Live transcript edited ({} chars), updating clipboard",
+        transcript.len()
+    );
+
+    if let Some(callbacks) = WINDOW_CALLBACKS.get() {
+        (callbacks.on_live_transcript_edited)(transcript);
+    }
+}
@@ -0,0 +1,66 @@
+//! Find bar support for the transcription window
+//!
+//! Each tab's text view uses the native AppKit find bar, which highlights all
+//! matches incrementally and shows itself above the tab's scroll view.
+
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::NSMenuItem;
+use objc2_foundation::MainThreadMarker;
+use tracing::{debug, error};
+
+use crate::transcription_window::state::transcription_window;
+
+/// Text finder actions (values of NSTextFinderAction)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FindAction {
+    /// Show the find bar (Cmd+F)
+    ShowFindInterface = 1,
+    /// Select the next match (Cmd+G)
+    NextMatch = 2,
+    /// Select the previous match (Cmd+Shift+G)
+    PreviousMatch = 3,
+}
+
+/// Perform a find action on the active tab's text view.
+///
+/// Must be called on the main thread (from the window's key handling).
+pub(crate) fn handle_find_action(action: FindAction) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Find action must be performed on the main thread");
+        return;
+    };
+
+    // Clone what we need and release the lock before calling into AppKit
+    let (window, text_view) = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in handle_find_action");
+            return;
+        };
+        let text_view = if inner.debug_shown {
+            &inner.debug_text_view
+        } else {
+            let Some((_, text_view)) = inner.views(inner.active_tab) else {
+                return;
+            };
+            text_view
+        };
+        (inner.window.clone(), text_view.clone())
+    };
+
+    debug!(?action, "Performing find action");
+
+    // performTextFinderAction: reads the action from the sender's tag
+    let sender: Retained<NSMenuItem> = unsafe { msg_send_id![mtm.alloc::<NSMenuItem>(), init] };
+
+    // SAFETY: messages to valid NSWindow, NSTextView and NSMenuItem objects on the main thread
+    unsafe {
+        let _: () = msg_send![&sender, setTag: action as isize];
+        window.makeKeyAndOrderFront(None);
+        let _: bool = msg_send![&window, makeFirstResponder: &*text_view];
+        let _: () = msg_send![&text_view, performTextFinderAction: &*sender];
+    }
+}
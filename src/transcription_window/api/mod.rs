@@ -3,9 +3,19 @@
 //! This module provides the public interface for controlling the transcription window,
 //! organized into submodules by functionality.
 
+mod attendees;
+mod debug;
+mod editing;
+mod find;
+mod notes;
+mod notice;
 mod pdf_writer;
+mod popover;
+mod print;
 mod recording;
 mod save;
+mod script;
+mod session_info;
 mod tab_content;
 mod tabs;
 mod text;
@@ -16,16 +26,29 @@ use objc2::msg_send;
 use objc2_foundation::NSOperationQueue;
 
 // Re-export all public functions from submodules
-pub(crate) use recording::{set_processing_state, set_recording_state, set_recording_type};
+pub(crate) use attendees::{handle_submit_attendees, show_attendees_popover};
+pub(crate) use editing::{handle_live_text_changed, handle_live_text_end_editing};
+pub(crate) use find::{handle_find_action, FindAction};
+pub(crate) use notes::{handle_submit_note, show_note_popover};
+pub(crate) use notice::handle_setting_changed;
+pub(crate) use pdf_writer::{PdfMargins, PdfPageSize, PdfSettings};
+pub(crate) use print::handle_print_action;
+pub(crate) use recording::{
+    handle_insert_marker_action, handle_stop_action, set_active_provider, set_data_usage,
+    set_input_warning, set_processing_message, set_processing_state, set_recording_state,
+    set_recording_type,
+};
 pub(crate) use save::{handle_save_file_action, hide_save_button, show_save_button};
+pub(crate) use script::{clear_script, show_script};
+pub(crate) use session_info::{set_session_info, show_session_info_popover};
 pub(crate) use tab_content::{
-    get_live_transcript, reset_tabs, set_meeting_notes_content, set_polished_content,
+    get_generated_content, get_live_transcript, reset_tabs, set_generated_content,
 };
 pub(crate) use tabs::{handle_tab_change, switch_to_tab};
 pub(crate) use text::{clear, update_live_text, update_text};
 pub(crate) use window::{
-    adjust_transparency, get_transparency, handle_hide_action, hide, is_dark_mode, set_dark_mode,
-    set_transparency,
+    adjust_transparency, get_transparency, handle_hide_action, handle_window_should_close,
+    handle_window_will_close, hide, is_dark_mode, is_visible, set_transparency,
 };
 
 /// Dispatch a block to the main queue for UI operations.
@@ -0,0 +1,55 @@
+//! Typed notes inserted into the transcript during recording
+//!
+//! The note button (or Control + Shift + N) opens a small popover below the
+//! header with a single-line text field. Pressing Return inserts the note
+//! inline at the current point of the transcript.
+
+use objc2_foundation::MainThreadMarker;
+use std::sync::atomic::Ordering;
+use tracing::{error, info};
+
+use super::popover::{close_popover, show_field_popover, FieldPopover};
+use crate::localization::tr;
+use crate::transcription_window::state::{IS_RECORDING, WINDOW_CALLBACKS};
+
+/// Show the note popover anchored to the header's note button.
+///
+/// Brings the transcription window forward if needed. Does nothing when not
+/// recording. Must be called on the main thread.
+pub(crate) fn show_note_popover() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Note popover must be shown on the main thread");
+        return;
+    };
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        info!("Note ignored: not recording");
+        return;
+    }
+
+    show_field_popover(
+        mtm,
+        |inner| &inner.note_button,
+        FieldPopover {
+            placeholder: tr("overlay.note_placeholder"),
+            accessibility_label: tr("overlay.note_field"),
+            value: "",
+            action: objc2::sel!(handleSubmitNote:),
+        },
+    );
+}
+
+/// Handle Return in the note popover's text field (called from delegate).
+///
+/// Closes the popover and hands non-empty notes to the app for insertion.
+pub(crate) fn handle_submit_note(text: String) {
+    close_popover();
+
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return;
+    }
+
+    if let Some(callbacks) = WINDOW_CALLBACKS.get() {
+        (callbacks.on_insert_note)(text);
+    }
+}
@@ -0,0 +1,83 @@
+//! Overlay reaction to settings changed while it is open
+//!
+//! Transparency and background are applied as soon as they are saved. Provider,
+//! model, audio and credentials are read when a recording connects, so a change
+//! during a recording keeps the running session as it is; a notice below the
+//! tabs says the change applies to the next recording. The language is
+//! switched in the running session, and the Debug tab comes and goes at once.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2_foundation::NSString;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::error;
+
+use super::debug::apply_debug_tab_setting;
+use super::dispatch_to_main;
+use super::window::{set_dark_mode, set_transparency};
+use crate::event_bus::Setting;
+use crate::localization::tr;
+use crate::preferences;
+use crate::transcription_window::state::{
+    transcription_window, CURRENT_TRANSPARENCY, IS_RECORDING,
+};
+
+/// How long a notice stays up
+const NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// Counts shown notices, so only the latest one hides itself
+static NOTICE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Apply a saved setting to the overlay, or tell the user when it takes effect
+pub(crate) fn handle_setting_changed(setting: Setting) {
+    match setting {
+        Setting::Transparency => {
+            let alpha = preferences::get_overlay_transparency();
+            CURRENT_TRANSPARENCY.store((alpha * 100.0).round() as u32, Ordering::SeqCst);
+            set_transparency(alpha);
+        }
+        Setting::DarkMode => set_dark_mode(preferences::get_is_dark_mode()),
+        Setting::DebugTab => apply_debug_tab_setting(),
+        _ if setting.applies_to_next_recording() && IS_RECORDING.load(Ordering::SeqCst) => {
+            show_notice(tr("overlay.applies_next_recording"));
+        }
+        _ => {}
+    }
+}
+
+/// Show `text` in the notice banner for a few seconds
+fn show_notice(text: &'static str) {
+    let generation = NOTICE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let block = RcBlock::new(move || {
+        set_notice(Some(text));
+    });
+    dispatch_to_main(&block);
+
+    dispatch::Queue::main().exec_after(NOTICE_DURATION, move || {
+        // A later notice restarted the timer
+        if NOTICE_GENERATION.load(Ordering::SeqCst) == generation {
+            set_notice(None);
+        }
+    });
+}
+
+/// Show the banner with `text`, or hide it with `None`. Main thread only.
+fn set_notice(text: Option<&str>) {
+    let Some(inner) = transcription_window() else {
+        return;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        error!("Transcription window already borrowed in set_notice");
+        return;
+    };
+
+    // SAFETY: setStringValue and setHidden are safe on valid NSTextField
+    unsafe {
+        if let Some(text) = text {
+            inner.notice_label.setStringValue(&NSString::from_str(text));
+        }
+        let _: () = msg_send![&inner.notice_label, setHidden: text.is_none()];
+    }
+}
@@ -0,0 +1,91 @@
+//! Page margins, header and footer of saved PDFs
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use genpdf::elements::Paragraph;
+use genpdf::error::Error;
+use genpdf::render::Area;
+use genpdf::style::{Color, Style, StyledString};
+use genpdf::{Alignment, Context, Element, Margins, Mm, Position};
+
+use super::layout::page_label;
+
+/// Font size of the header and footer (in points).
+const HEADER_FOOTER_SIZE: u8 = 9;
+
+/// Grey of the header and footer text.
+const HEADER_FOOTER_GREY: u8 = 110;
+
+/// Space between the header or footer and the content, in mm.
+const HEADER_FOOTER_GAP_MM: f64 = 6.0;
+
+/// Height kept free for the footer at the bottom of each page, in mm.
+const FOOTER_HEIGHT_MM: f64 = 5.0;
+
+/// Applies the margins to each page and, when a title is given, heads it
+/// with the title and numbers it in the footer.
+pub(super) struct PageDecorator {
+    margins: Margins,
+    title: Option<String>,
+    /// Page count of the finished document, if known from an earlier pass
+    total_pages: Option<usize>,
+    /// Pages decorated so far, shared so the caller can read the count
+    pages: Rc<Cell<usize>>,
+}
+
+impl PageDecorator {
+    pub(super) fn new(
+        margin_mm: f64,
+        title: Option<String>,
+        total_pages: Option<usize>,
+        pages: Rc<Cell<usize>>,
+    ) -> Self {
+        Self {
+            margins: Margins::all(Mm::from(margin_mm)),
+            title,
+            total_pages,
+            pages,
+        }
+    }
+}
+
+impl genpdf::PageDecorator for PageDecorator {
+    fn decorate_page<'a>(
+        &mut self,
+        context: &Context,
+        mut area: Area<'a>,
+        style: Style,
+    ) -> Result<Area<'a>, Error> {
+        let page = self.pages.get() + 1;
+        self.pages.set(page);
+        area.add_margins(self.margins);
+
+        let Some(title) = &self.title else {
+            return Ok(area);
+        };
+        let text_style = Style::new()
+            .with_font_size(HEADER_FOOTER_SIZE)
+            .with_color(Color::Greyscale(HEADER_FOOTER_GREY));
+
+        let mut header = Paragraph::new(StyledString::new(title.clone(), text_style));
+        let header_height = header.render(context, area.clone(), style)?.size.height;
+        area.add_offset(Position::new(
+            0,
+            header_height + Mm::from(HEADER_FOOTER_GAP_MM),
+        ));
+
+        let footer_top = area.size().height - Mm::from(FOOTER_HEIGHT_MM);
+        let mut footer_area = area.clone();
+        footer_area.add_offset(Position::new(0, footer_top));
+        let mut footer = Paragraph::new(StyledString::new(
+            page_label(page, self.total_pages),
+            text_style,
+        ))
+        .aligned(Alignment::Center);
+        footer.render(context, footer_area, style)?;
+
+        area.set_height(footer_top - Mm::from(HEADER_FOOTER_GAP_MM));
+        Ok(area)
+    }
+}
@@ -0,0 +1,56 @@
+//! System fonts for PDF generation
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use genpdf::fonts::{FontData, FontFamily};
+
+/// Load a font family for PDF generation.
+///
+/// Loads Arial fonts from macOS system font locations.
+pub(super) fn load_font_family() -> Result<FontFamily<FontData>> {
+    let font_dir = Path::new("/System/Library/Fonts/Supplemental");
+
+    // Load regular font
+    let regular_path = font_dir.join("Arial.ttf");
+    let regular = FontData::new(
+        std::fs::read(&regular_path)
+            .with_context(|| format!("Failed to read font: {}", regular_path.display()))?,
+        None,
+    )
+    .with_context(|| "Failed to parse Arial regular font")?;
+
+    // Load bold font
+    let bold_path = font_dir.join("Arial Bold.ttf");
+    let bold = FontData::new(
+        std::fs::read(&bold_path)
+            .with_context(|| format!("Failed to read font: {}", bold_path.display()))?,
+        None,
+    )
+    .with_context(|| "Failed to parse Arial bold font")?;
+
+    // Load italic font
+    let italic_path = font_dir.join("Arial Italic.ttf");
+    let italic = FontData::new(
+        std::fs::read(&italic_path)
+            .with_context(|| format!("Failed to read font: {}", italic_path.display()))?,
+        None,
+    )
+    .with_context(|| "Failed to parse Arial italic font")?;
+
+    // Load bold italic font
+    let bold_italic_path = font_dir.join("Arial Bold Italic.ttf");
+    let bold_italic = FontData::new(
+        std::fs::read(&bold_italic_path)
+            .with_context(|| format!("Failed to read font: {}", bold_italic_path.display()))?,
+        None,
+    )
+    .with_context(|| "Failed to parse Arial bold italic font")?;
+
+    Ok(FontFamily {
+        regular,
+        bold,
+        italic,
+        bold_italic,
+    })
+}
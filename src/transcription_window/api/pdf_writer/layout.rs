@@ -0,0 +1,241 @@
+//! Blocks a PDF is laid out from
+//!
+//! The markdown segments of a transcript are grouped into headings,
+//! paragraphs with their bold runs, bullet lists and gaps for blank lines.
+//! Keeping this apart from genpdf lets the layout be tested without fonts.
+
+use crate::transcription_window::markdown::{parse_markdown, MarkdownSegment};
+
+/// A run of text within a paragraph or list item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Span {
+    pub(super) text: String,
+    pub(super) bold: bool,
+}
+
+/// A block of the document, laid out top to bottom
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Block {
+    /// Heading of level 1 to 3
+    Heading { level: u8, text: String },
+    /// One line of text
+    Paragraph(Vec<Span>),
+    /// Consecutive bullet points, one item each
+    BulletList(Vec<Vec<Span>>),
+    /// Space left by a blank line
+    Gap,
+}
+
+/// Lay out markdown `content` as blocks
+pub(super) fn blocks(content: &str) -> Vec<Block> {
+    let mut builder = Builder::default();
+    for segment in parse_markdown(content) {
+        match segment {
+            MarkdownSegment::Header1(text) => builder.heading(1, text),
+            MarkdownSegment::Header2(text) => builder.heading(2, text),
+            MarkdownSegment::Header3(text) => builder.heading(3, text),
+            MarkdownSegment::BulletPoint(text) => builder.bullet(&text),
+            MarkdownSegment::Bold(text) => builder.span(text, true),
+            MarkdownSegment::Normal(text) if text == "\n" => builder.end_line(),
+            MarkdownSegment::Normal(text) => builder.span(text, false),
+            MarkdownSegment::Image { alt, path } => {
+                // Keep the reference as text; the image lives next to the transcript
+                builder.span(format!("![{}]({})", alt, path), false)
+            }
+        }
+    }
+    builder.finish()
+}
+
+/// Title for the page header: the first level 1 heading, or `fallback`
+pub(super) fn session_title(content: &str, fallback: &str) -> String {
+    content
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("# "))
+        .map(|title| title.replace("**", "").trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Footer text of page `page`, counted from 1
+pub(super) fn page_label(page: usize, total: Option<usize>) -> String {
+    match total {
+        Some(total) => format!("Page {} of {}", page, total),
+        None => format!("Page {}", page),
+    }
+}
+
+#[derive(Default)]
+struct Builder {
+    blocks: Vec<Block>,
+    /// Spans of the line being read
+    line: Vec<Span>,
+    /// Items of the bullet list being read
+    bullets: Vec<Vec<Span>>,
+    /// Whether the next line end closes a heading or bullet rather than
+    /// a line of text
+    line_taken: bool,
+}
+
+impl Builder {
+    fn heading(&mut self, level: u8, text: String) {
+        self.flush_bullets();
+        self.blocks.push(Block::Heading { level, text });
+        self.line_taken = true;
+    }
+
+    fn bullet(&mut self, text: &str) {
+        self.bullets.push(inline_spans(text));
+        self.line_taken = true;
+    }
+
+    fn span(&mut self, text: String, bold: bool) {
+        if !text.is_empty() {
+            self.line.push(Span { text, bold });
+        }
+    }
+
+    fn end_line(&mut self) {
+        if std::mem::take(&mut self.line_taken) {
+            return;
+        }
+        self.flush_bullets();
+        if self.line.iter().any(|span| !span.text.trim().is_empty()) {
+            let line = std::mem::take(&mut self.line);
+            self.blocks.push(Block::Paragraph(line));
+        } else {
+            self.line.clear();
+            // One gap per run of blank lines, and none where spacing is already given
+            if matches!(
+                self.blocks.last(),
+                Some(Block::Paragraph(_) | Block::BulletList(_))
+            ) {
+                self.blocks.push(Block::Gap);
+            }
+        }
+    }
+
+    fn flush_bullets(&mut self) {
+        if !self.bullets.is_empty() {
+            let items = std::mem::take(&mut self.bullets);
+            self.blocks.push(Block::BulletList(items));
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        if !self.line.is_empty() {
+            self.end_line();
+        }
+        self.flush_bullets();
+        if self.blocks.last() == Some(&Block::Gap) {
+            self.blocks.pop();
+        }
+        self.blocks
+    }
+}
+
+/// Spans of `text`, with `**bold**` runs marked
+fn inline_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut remaining = text;
+    let mut bold = false;
+    while let Some(marker) = remaining.find("**") {
+        // An unclosed marker is kept as text
+        if !bold && !remaining[marker + 2..].contains("**") {
+            break;
+        }
+        if marker > 0 {
+            spans.push(Span {
+                text: remaining[..marker].to_string(),
+                bold,
+            });
+        }
+        remaining = &remaining[marker + 2..];
+        bold = !bold;
+    }
+    if !remaining.is_empty() {
+        spans.push(Span {
+            text: remaining.to_string(),
+            bold,
+        });
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEETING_NOTES: &str = include_str!("../../../../resources/fixtures/pdf/meeting_notes.md");
+    const MEETING_NOTES_LAYOUT: &str =
+        include_str!("../../../../resources/fixtures/pdf/meeting_notes.layout");
+
+    /// One line per block, bold runs in `**`, for comparing with golden files
+    fn describe(blocks: &[Block]) -> String {
+        let spans = |spans: &[Span]| -> String {
+            spans
+                .iter()
+                .map(|span| {
+                    if span.bold {
+                        format!("**{}**", span.text)
+                    } else {
+                        span.text.clone()
+                    }
+                })
+                .collect()
+        };
+        let mut lines = Vec::new();
+        for block in blocks {
+            match block {
+                Block::Heading { level, text } => lines.push(format!("H{} {}", level, text)),
+                Block::Paragraph(line) => lines.push(format!("P {}", spans(line))),
+                Block::BulletList(items) => {
+                    lines.push("LIST".to_string());
+                    lines.extend(items.iter().map(|item| format!("  - {}", spans(item))));
+                }
+                Block::Gap => lines.push("GAP".to_string()),
+            }
+        }
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn test_meeting_notes_layout_matches_golden_file() {
+        assert_eq!(describe(&blocks(MEETING_NOTES)), MEETING_NOTES_LAYOUT);
+    }
+
+    #[test]
+    fn test_inline_spans() {
+        assert_eq!(
+            inline_spans("**Anna**: ship the beta"),
+            vec![
+                Span {
+                    text: "Anna".to_string(),
+                    bold: true
+                },
+                Span {
+                    text: ": ship the beta".to_string(),
+                    bold: false
+                },
+            ]
+        );
+        assert_eq!(
+            inline_spans("2 ** 3"),
+            vec![Span {
+                text: "2 ** 3".to_string(),
+                bold: false
+            }]
+        );
+    }
+
+    #[test]
+    fn test_session_title_and_page_label() {
+        assert_eq!(
+            session_title("Intro\n# **Weekly** sync\n# Other", "transcript"),
+            "Weekly sync"
+        );
+        assert_eq!(session_title("## Summary", "transcript"), "transcript");
+        assert_eq!(page_label(2, Some(5)), "Page 2 of 5");
+        assert_eq!(page_label(2, None), "Page 2");
+    }
+}
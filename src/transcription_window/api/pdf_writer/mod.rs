@@ -0,0 +1,175 @@
+//! PDF generation from markdown content.
+//!
+//! Uses genpdf to render markdown-formatted transcripts to PDF documents
+//! with proper styling (headings, bold, bullet lists), on the page size and
+//! margins chosen in Settings. Pages can be headed with the session title
+//! and numbered in the footer.
+
+mod decorator;
+mod fonts;
+mod layout;
+mod settings;
+
+pub(crate) use settings::{PdfMargins, PdfPageSize, PdfSettings};
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use genpdf::elements::{Break, PaddedElement, Paragraph, UnorderedList};
+use genpdf::fonts::{FontData, FontFamily};
+use genpdf::style::Style;
+use genpdf::{Document, Margins};
+use tracing::info;
+
+use decorator::PageDecorator;
+use layout::{Block, Span};
+
+/// Font sizes for PDF output (in points).
+const NORMAL_SIZE: u8 = 11;
+const H1_SIZE: u8 = 18;
+const H2_SIZE: u8 = 14;
+const H3_SIZE: u8 = 12;
+
+/// Space above headings of each level and below every block, in mm.
+const HEADING_SPACE_MM: [f64; 3] = [6.0, 4.0, 3.0];
+const BLOCK_SPACE_MM: f64 = 1.5;
+
+/// Render markdown-formatted content to PDF bytes.
+///
+/// Parses the markdown content and renders it with styled formatting:
+/// - Headers (H1/H2/H3) with appropriate sizes and spacing
+/// - Bold text preserved within its line
+/// - Bullet points as indented lists
+///
+/// With the header and footer turned on, each page is headed with the
+/// transcript's first level 1 heading, or `title` if it has none, and
+/// numbered "Page 1 of 3" at the bottom. The page count is found by
+/// rendering twice.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - No suitable font can be loaded from the system
+/// - The PDF cannot be rendered
+pub(crate) fn render_pdf(content: &str, title: &str, settings: &PdfSettings) -> Result<Vec<u8>> {
+    info!(
+        content_length = content.len(),
+        ?settings,
+        "Generating PDF transcript"
+    );
+
+    // Load font family from system fonts
+    let font_family = fonts::load_font_family()
+        .with_context(|| "Failed to load system font for PDF generation")?;
+
+    let blocks = layout::blocks(content);
+    let header_title = settings
+        .header_footer
+        .then(|| layout::session_title(content, title));
+
+    // Without page numbers one pass is enough; with them, the first pass counts the pages
+    let total_pages = match header_title {
+        Some(_) => {
+            let pages = Rc::new(Cell::new(0));
+            let doc = build_document(
+                &font_family,
+                &blocks,
+                settings,
+                PageDecorator::new(
+                    settings.margins.mm(),
+                    header_title.clone(),
+                    None,
+                    pages.clone(),
+                ),
+            );
+            doc.render(std::io::sink())
+                .context("Failed to render PDF")?;
+            Some(pages.get())
+        }
+        None => None,
+    };
+
+    let doc = build_document(
+        &font_family,
+        &blocks,
+        settings,
+        PageDecorator::new(
+            settings.margins.mm(),
+            header_title,
+            total_pages,
+            Rc::new(Cell::new(0)),
+        ),
+    );
+
+    // Render to memory so the caller decides how the file is written
+    let mut pdf = Vec::new();
+    doc.render(&mut pdf).context("Failed to render PDF")?;
+
+    info!(size = pdf.len(), pages = ?total_pages, "PDF transcript rendered");
+    Ok(pdf)
+}
+
+/// Create the document with `blocks` as its content
+fn build_document(
+    font_family: &FontFamily<FontData>,
+    blocks: &[Block],
+    settings: &PdfSettings,
+    decorator: PageDecorator,
+) -> Document {
+    let mut doc = Document::new(font_family.clone());
+    doc.set_title("Vissper Transcript");
+    doc.set_paper_size(settings.page_size.paper_size());
+    doc.set_font_size(NORMAL_SIZE);
+    doc.set_page_decorator(decorator);
+
+    for block in blocks {
+        match block {
+            Block::Heading { level, text } => {
+                let (size, space) = match level {
+                    1 => (H1_SIZE, HEADING_SPACE_MM[0]),
+                    2 => (H2_SIZE, HEADING_SPACE_MM[1]),
+                    _ => (H3_SIZE, HEADING_SPACE_MM[2]),
+                };
+                let mut heading = Paragraph::default();
+                heading.push_styled(text.clone(), Style::new().bold().with_font_size(size));
+                doc.push(PaddedElement::new(
+                    heading,
+                    Margins::trbl(space, 0.0, BLOCK_SPACE_MM, 0.0),
+                ));
+            }
+            Block::Paragraph(spans) => {
+                doc.push(PaddedElement::new(
+                    paragraph(spans),
+                    Margins::trbl(0.0, 0.0, BLOCK_SPACE_MM, 0.0),
+                ));
+            }
+            Block::BulletList(items) => {
+                let mut list = UnorderedList::with_bullet("\u{2022}");
+                for item in items {
+                    list.push(paragraph(item));
+                }
+                doc.push(PaddedElement::new(
+                    list,
+                    Margins::trbl(0.0, 0.0, BLOCK_SPACE_MM, 0.0),
+                ));
+            }
+            Block::Gap => doc.push(Break::new(0.5)),
+        }
+    }
+    doc
+}
+
+/// A paragraph of `spans`, bold where marked
+fn paragraph(spans: &[Span]) -> Paragraph {
+    let mut paragraph = Paragraph::default();
+    for span in spans {
+        let style = if span.bold {
+            Style::new().bold()
+        } else {
+            Style::new()
+        };
+        paragraph.push_styled(span.text.clone(), style);
+    }
+    paragraph
+}
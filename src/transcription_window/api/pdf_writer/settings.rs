@@ -0,0 +1,116 @@
+//! PDF page size, margin and header/footer settings
+
+use genpdf::PaperSize;
+use serde::{Deserialize, Serialize};
+
+/// Paper size of saved PDFs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PdfPageSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PdfPageSize {
+    /// All sizes, in the order shown in Settings
+    pub(crate) const ALL: [PdfPageSize; 2] = [PdfPageSize::A4, PdfPageSize::Letter];
+
+    /// Look up a size by its index in `ALL` (used as the segment index)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|size| *size == self)
+            .unwrap_or_default() as isize
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            PdfPageSize::A4 => "A4",
+            PdfPageSize::Letter => "Letter",
+        }
+    }
+
+    pub(super) fn paper_size(self) -> PaperSize {
+        match self {
+            PdfPageSize::A4 => PaperSize::A4,
+            PdfPageSize::Letter => PaperSize::Letter,
+        }
+    }
+}
+
+/// Page margins of saved PDFs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PdfMargins {
+    Narrow,
+    #[default]
+    Normal,
+    Wide,
+}
+
+impl PdfMargins {
+    /// All margins, in the order shown in Settings
+    pub(crate) const ALL: [PdfMargins; 3] =
+        [PdfMargins::Narrow, PdfMargins::Normal, PdfMargins::Wide];
+
+    /// Look up margins by their index in `ALL` (used as the segment index)
+    pub(crate) fn from_index(index: isize) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+    }
+
+    pub(crate) fn index(self) -> isize {
+        Self::ALL
+            .iter()
+            .position(|margins| *margins == self)
+            .unwrap_or_default() as isize
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            PdfMargins::Narrow => "Narrow",
+            PdfMargins::Normal => "Normal",
+            PdfMargins::Wide => "Wide",
+        }
+    }
+
+    /// Margin on every side, in mm
+    pub(super) fn mm(self) -> f64 {
+        match self {
+            PdfMargins::Narrow => 12.7,
+            PdfMargins::Normal => 20.0,
+            PdfMargins::Wide => 25.4,
+        }
+    }
+}
+
+/// Persisted PDF layout settings
+///
+/// Defaults to A4 with 20 mm margins, as before this was configurable, with
+/// the session title at the top of each page and page numbers at the bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct PdfSettings {
+    pub(crate) page_size: PdfPageSize,
+    pub(crate) margins: PdfMargins,
+    /// Session title in the header and page numbers in the footer
+    pub(crate) header_footer: bool,
+}
+
+impl Default for PdfSettings {
+    fn default() -> Self {
+        Self {
+            page_size: PdfPageSize::default(),
+            margins: PdfMargins::default(),
+            header_footer: true,
+        }
+    }
+}
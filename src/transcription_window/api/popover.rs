@@ -0,0 +1,220 @@
+//! Popovers anchored to the header's buttons
+//!
+//! The note and attendees buttons each open a small popover below the header
+//! with one text field. Pressing Return sends the field's action to the
+//! window delegate. The session info button opens one with read-only text.
+//! At most one popover is open at a time.
+
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, Sel};
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::{NSApplication, NSFont, NSTextField, NSView};
+use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+use std::cell::RefCell;
+use tracing::{error, warn};
+
+use crate::transcription_window::delegates::HoverButton;
+use crate::transcription_window::objc_utils;
+use crate::transcription_window::state::{transcription_window, TranscriptionWindowInner};
+
+/// Popover content size
+const POPOVER_WIDTH: f64 = 280.0;
+const POPOVER_HEIGHT: f64 = 44.0;
+
+/// Height of a line in a text popover
+const TEXT_LINE_HEIGHT: f64 = 17.0;
+
+/// NSPopoverBehaviorTransient: closes when the user clicks outside
+const POPOVER_BEHAVIOR_TRANSIENT: isize = 1;
+
+/// NSMinYEdge: attach the popover to the bottom edge of the button
+const POPOVER_EDGE_BELOW: usize = 1;
+
+thread_local! {
+    /// The open popover, kept so it can be closed on submit.
+    /// Only touched on the main thread.
+    static POPOVER: RefCell<Option<Retained<AnyObject>>> = const { RefCell::new(None) };
+}
+
+/// The text field shown in a popover
+pub(super) struct FieldPopover<'a> {
+    pub(super) placeholder: &'a str,
+    pub(super) accessibility_label: &'a str,
+    /// Text the field starts with
+    pub(super) value: &'a str,
+    /// Delegate action sent when Return is pressed
+    pub(super) action: Sel,
+}
+
+/// Show a popover with a text field below the header button `anchor` picks.
+///
+/// Brings the transcription window forward so the field can take typing.
+/// Must be called on the main thread.
+pub(super) fn show_field_popover(
+    mtm: MainThreadMarker,
+    anchor: impl FnOnce(&TranscriptionWindowInner) -> &Retained<HoverButton>,
+    field: FieldPopover<'_>,
+) {
+    // Clone what we need and release the lock before calling into AppKit
+    let (window, button, delegate) = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_field_popover");
+            return;
+        };
+        (
+            inner.window.clone(),
+            anchor(&inner).clone(),
+            inner.delegate.clone(),
+        )
+    };
+
+    let content_frame = NSRect::new(
+        NSPoint::new(0.0, 0.0),
+        NSSize::new(POPOVER_WIDTH, POPOVER_HEIGHT),
+    );
+    let content_view: Retained<NSView> =
+        unsafe { msg_send_id![mtm.alloc::<NSView>(), initWithFrame: content_frame] };
+
+    let field_frame = NSRect::new(
+        NSPoint::new(10.0, 10.0),
+        NSSize::new(POPOVER_WIDTH - 20.0, 24.0),
+    );
+    let text_field: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: field_frame] };
+
+    // SAFETY: messages to valid AppKit objects on the main thread
+    unsafe {
+        let placeholder = NSString::from_str(field.placeholder);
+        let _: () = msg_send![&text_field, setPlaceholderString: &*placeholder];
+        let accessibility_label = NSString::from_str(field.accessibility_label);
+        let _: () = msg_send![&text_field, setAccessibilityLabel: &*accessibility_label];
+        text_field.setStringValue(&NSString::from_str(field.value));
+        let _: () = msg_send![&text_field, setTarget: &*delegate];
+        let _: () = msg_send![&text_field, setAction: field.action];
+        content_view.addSubview(&text_field);
+
+        // The popover needs a key window to accept typing
+        let app = NSApplication::sharedApplication(mtm);
+        app.activateIgnoringOtherApps(true);
+        window.makeKeyAndOrderFront(None);
+    }
+
+    if !present_popover(&content_view, content_frame.size, &button) {
+        return;
+    }
+
+    // SAFETY: the field is in the popover's window on the main thread
+    unsafe {
+        let popover_window: *mut AnyObject = msg_send![&text_field, window];
+        if !popover_window.is_null() {
+            let _: bool = msg_send![popover_window, makeFirstResponder: &*text_field];
+        }
+    }
+}
+
+/// Show a popover with read-only, selectable text below the header button
+/// `anchor` picks. Must be called on the main thread.
+pub(super) fn show_text_popover(
+    mtm: MainThreadMarker,
+    anchor: impl FnOnce(&TranscriptionWindowInner) -> &Retained<HoverButton>,
+    text: &str,
+) {
+    let button = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_text_popover");
+            return;
+        };
+        anchor(&inner).clone()
+    };
+
+    let line_count = text.lines().count().max(1) as f64;
+    let text_height = line_count * TEXT_LINE_HEIGHT;
+    let content_frame = NSRect::new(
+        NSPoint::new(0.0, 0.0),
+        NSSize::new(POPOVER_WIDTH, text_height + 20.0),
+    );
+    let content_view: Retained<NSView> =
+        unsafe { msg_send_id![mtm.alloc::<NSView>(), initWithFrame: content_frame] };
+
+    let label_frame = NSRect::new(
+        NSPoint::new(10.0, 10.0),
+        NSSize::new(POPOVER_WIDTH - 20.0, text_height),
+    );
+    let label: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: label_frame] };
+
+    // SAFETY: messages to valid AppKit objects on the main thread
+    unsafe {
+        label.setEditable(false);
+        label.setSelectable(true);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+        label.setFont(Some(&NSFont::systemFontOfSize(12.0)));
+        label.setStringValue(&NSString::from_str(text));
+        content_view.addSubview(&label);
+    }
+
+    present_popover(&content_view, content_frame.size, &button);
+}
+
+/// Replace the open popover with one holding `content_view`, attached below
+/// `button`. Returns false when the popover could not be created.
+fn present_popover(content_view: &NSView, size: NSSize, button: &HoverButton) -> bool {
+    close_popover();
+
+    let Some(popover_class) = objc_utils::get_class_or_warn("NSPopover") else {
+        return false;
+    };
+    let Some(controller_class) = objc_utils::get_class_or_warn("NSViewController") else {
+        return false;
+    };
+
+    // SAFETY: messages to valid AppKit objects on the main thread; the popover
+    // and view controller classes were checked above
+    unsafe {
+        let controller: *mut AnyObject = msg_send![controller_class, alloc];
+        let controller: *mut AnyObject = msg_send![controller, init];
+        let Some(controller) = Retained::from_raw(controller) else {
+            warn!("Failed to create popover view controller");
+            return false;
+        };
+        let _: () = msg_send![&controller, setView: content_view];
+
+        let popover: *mut AnyObject = msg_send![popover_class, alloc];
+        let popover: *mut AnyObject = msg_send![popover, init];
+        let Some(popover) = Retained::from_raw(popover) else {
+            warn!("Failed to create popover");
+            return false;
+        };
+        let _: () = msg_send![&popover, setBehavior: POPOVER_BEHAVIOR_TRANSIENT];
+        let _: () = msg_send![&popover, setContentSize: size];
+        let _: () = msg_send![&popover, setContentViewController: &*controller];
+
+        let anchor: NSRect = msg_send![button, bounds];
+        let _: () = msg_send![
+            &popover,
+            showRelativeToRect: anchor,
+            ofView: button,
+            preferredEdge: POPOVER_EDGE_BELOW
+        ];
+
+        POPOVER.with(|cell| *cell.borrow_mut() = Some(popover));
+    }
+    true
+}
+
+/// Close the open popover, if any.
+pub(super) fn close_popover() {
+    if let Some(popover) = POPOVER.with(|cell| cell.borrow_mut().take()) {
+        // SAFETY: close is safe on a valid NSPopover on the main thread
+        unsafe {
+            let _: () = msg_send![&popover, close];
+        }
+    }
+}
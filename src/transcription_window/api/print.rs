@@ -0,0 +1,114 @@
+//! Printing the active tab
+//!
+//! The tab's content is rendered by the PDF writer, with the page size,
+//! margins, header and footer from Settings, and the PDF is printed through
+//! PDFKit's print operation. Printed pages match a saved PDF.
+
+use chrono::Local;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2::{msg_send, msg_send_id};
+use objc2_app_kit::NSApplication;
+use objc2_foundation::{MainThreadMarker, NSData, NSString};
+use tracing::{error, info};
+
+use super::pdf_writer;
+use crate::output_modes;
+use crate::transcription_window::state::{transcription_window, TabType};
+use crate::{attendees, consent, preferences, private_mode};
+
+#[link(name = "PDFKit", kind = "framework")]
+extern "C" {}
+
+/// kPDFPrintPageScaleDownToFit: shrink pages larger than the paper
+const PRINT_SCALE_DOWN_TO_FIT: isize = 2;
+
+/// Print the active tab's content (Cmd+P or the menu bar's Print…).
+///
+/// Shows the print panel; does nothing if the tab is empty or during a
+/// private session, since print jobs are spooled to disk.
+pub(crate) fn handle_print_action() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Print must be started on the main thread");
+        return;
+    };
+    if private_mode::is_active() {
+        info!("Print skipped: private session");
+        return;
+    }
+
+    let Some((tab, content)) = active_tab_content() else {
+        info!("Nothing to print");
+        return;
+    };
+    let title = format!(
+        "{} {}",
+        output_modes::title(tab),
+        Local::now().format("%Y-%m-%d")
+    );
+    let content =
+        consent::add_to_export(tab, &attendees::add_to_export(tab, &content), Local::now());
+
+    let pdf = match pdf_writer::render_pdf(&content, &title, &preferences::get_pdf_settings()) {
+        Ok(pdf) => pdf,
+        Err(e) => {
+            error!("Failed to render transcript for printing: {:#}", e);
+            return;
+        }
+    };
+    info!(?tab, size = pdf.len(), "Printing transcript");
+
+    let Some(document_class) = AnyClass::get("PDFDocument") else {
+        error!("PDFKit not available, cannot print");
+        return;
+    };
+    let data = NSData::with_bytes(&pdf);
+
+    // SAFETY: PDFKit and AppKit objects created and used on the main thread;
+    // printOperationForPrintInfo:... returns nil only for an unprintable document
+    unsafe {
+        let document: *mut AnyObject = msg_send![document_class, alloc];
+        let document: *mut AnyObject = msg_send![document, initWithData: &*data];
+        let Some(document) = Retained::from_raw(document) else {
+            error!("PDFKit could not read the rendered transcript");
+            return;
+        };
+        let Some(print_info_class) = AnyClass::get("NSPrintInfo") else {
+            return;
+        };
+        let print_info: Retained<AnyObject> = msg_send_id![print_info_class, sharedPrintInfo];
+        let operation: Option<Retained<AnyObject>> = msg_send_id![
+            &document,
+            printOperationForPrintInfo: &*print_info,
+            scalingMode: PRINT_SCALE_DOWN_TO_FIT,
+            autoRotate: true
+        ];
+        let Some(operation) = operation else {
+            error!("Could not create print operation");
+            return;
+        };
+        let _: () = msg_send![&operation, setJobTitle: &*NSString::from_str(&title)];
+
+        // A menu bar app has to come forward for the print panel to take focus
+        let app = NSApplication::sharedApplication(mtm);
+        #[allow(deprecated)]
+        app.activateIgnoringOtherApps(true);
+        let printed: bool = msg_send![&operation, runOperation];
+        info!(printed, "Print operation finished");
+    }
+}
+
+/// The active tab and its content, if it has any
+fn active_tab_content() -> Option<(TabType, String)> {
+    let inner = transcription_window()?;
+    let Ok(inner) = inner.try_borrow() else {
+        error!("Transcription window already borrowed in handle_print_action");
+        return None;
+    };
+    let tab = inner.active_tab;
+    let content = match tab {
+        TabType::Live => inner.tab_content.live_transcript.clone(),
+        _ => inner.tab_content.generated.get(&tab).cloned()?,
+    };
+    (!content.trim().is_empty()).then_some((tab, content))
+}
@@ -5,10 +5,14 @@ use objc2::msg_send;
 use objc2_app_kit::NSColor;
 use objc2_foundation::NSString;
 use std::sync::atomic::Ordering;
-use tracing::error;
+use tracing::{error, info};
 
 use super::dispatch_to_main;
-use crate::transcription_window::state::{IS_RECORDING, TRANSCRIPTION_WINDOW};
+use super::editing::set_live_editable;
+use crate::localization::tr;
+use crate::transcription_window::state::{
+    transcription_window, TabType, IS_RECORDING, WINDOW_CALLBACKS,
+};
 
 /// Set the recording state indicator.
 ///
@@ -19,16 +23,27 @@ pub(crate) fn set_recording_state(recording: bool) {
     IS_RECORDING.store(recording, Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_recording_state");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_recording_state");
             return;
         };
 
-        // SAFETY: msg_send calls to valid NSView and NSTextField objects
+        // The live transcript can be corrected once recording has stopped
+        set_live_editable(&inner, !recording);
+
+        // SAFETY: msg_send calls to valid NSView, NSTextField and NSButton objects
         unsafe {
+            // Markers, notes and attendees can only be added while recording
+            let _: () = msg_send![&inner.marker_button, setHidden: !recording];
+            let _: () = msg_send![&inner.note_button, setHidden: !recording];
+            let _: () = msg_send![&inner.attendees_button, setHidden: !recording];
+            for button in &inner.stop_buttons {
+                let _: () = msg_send![button, setHidden: !recording];
+            }
+
             if recording {
                 let red_color = NSColor::colorWithRed_green_blue_alpha(0.9, 0.2, 0.2, 1.0);
                 let _: () = msg_send![&inner.recording_indicator, setBackgroundColor: &*red_color];
@@ -37,7 +52,7 @@ pub(crate) fn set_recording_state(recording: bool) {
                 inner.recording_label.setTextColor(Some(&text_color));
                 inner
                     .recording_label
-                    .setStringValue(&NSString::from_str("Recording"));
+                    .setStringValue(&NSString::from_str(tr("overlay.recording")));
 
                 let _: () = msg_send![&inner.recording_indicator, setHidden: false];
                 let _: () = msg_send![&inner.recording_label, setHidden: false];
@@ -51,16 +66,105 @@ pub(crate) fn set_recording_state(recording: bool) {
     dispatch_to_main(&block);
 }
 
+/// Show the recording's data usage in the footer, e.g. "↑ 1.2 MB ↓ 48 KB"
+pub(crate) fn set_data_usage(summary: &str) {
+    let summary = summary.to_string();
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_data_usage");
+            return;
+        };
+
+        // SAFETY: setStringValue is safe on valid NSTextField
+        unsafe {
+            inner
+                .usage_label
+                .setStringValue(&NSString::from_str(&summary));
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Show a warning banner below the tabs, or hide it with `None`.
+///
+/// Used while the microphone is muted or its input volume is at zero.
+pub(crate) fn set_input_warning(warning: Option<String>) {
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_input_warning");
+            return;
+        };
+
+        // SAFETY: setStringValue and setHidden are safe on valid NSTextField
+        unsafe {
+            if let Some(warning) = &warning {
+                inner
+                    .input_warning_label
+                    .setStringValue(&NSString::from_str(warning));
+            }
+            let _: () = msg_send![&inner.input_warning_label, setHidden: warning.is_none()];
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Handle marker button click (called from delegate)
+pub(crate) fn handle_insert_marker_action() {
+    info!("Marker button clicked");
+    if let Some(callbacks) = WINDOW_CALLBACKS.get() {
+        (callbacks.on_insert_marker)();
+    }
+}
+
+/// Stop the recording with `tab`'s output mode (called from a header stop button)
+pub(crate) fn handle_stop_action(tab: TabType) {
+    info!(?tab, "Stop button clicked");
+    if let Some(callbacks) = WINDOW_CALLBACKS.get() {
+        (callbacks.on_stop_with_mode)(tab);
+    }
+}
+
 /// Set the recording type label in the header to "Transcription".
 ///
 /// Updates the header label to indicate live transcription mode.
 pub(crate) fn set_recording_type() {
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_recording_type");
+            return;
+        };
+
+        // SAFETY: setStringValue is safe on valid NSTextField
+        unsafe {
+            inner
+                .recording_type_label
+                .setStringValue(&NSString::from_str(tr("overlay.live_transcription")));
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Name the provider in the recording type label, e.g. "Live Transcription · OpenAI"
+pub(crate) fn set_active_provider(provider: &str) {
+    let label = format!("{} · {}", tr("overlay.live_transcription"), provider);
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_recording_type");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_active_provider");
             return;
         };
 
@@ -68,7 +172,7 @@ pub(crate) fn set_recording_type() {
         unsafe {
             inner
                 .recording_type_label
-                .setStringValue(&NSString::from_str("Live Transcription"));
+                .setStringValue(&NSString::from_str(&label));
         }
     });
 
@@ -80,34 +184,65 @@ pub(crate) fn set_recording_type() {
 /// When `processing` is true, shows an orange indicator with "Processing" text.
 /// When false, hides the indicator entirely.
 pub(crate) fn set_processing_state(processing: bool) {
+    if processing {
+        show_processing_indicator(tr("overlay.processing").to_string());
+    } else {
+        hide_status_indicator("set_processing_state");
+    }
+}
+
+/// Show the processing indicator with a custom status message.
+///
+/// Used for transient states during processing, e.g. "Rate limited, retrying in 20s…".
+pub(crate) fn set_processing_message(message: &str) {
+    show_processing_indicator(message.to_string());
+}
+
+/// Show the orange processing indicator with the given label text.
+fn show_processing_indicator(text: String) {
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_processing_state");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_processing_state");
             return;
         };
 
         // SAFETY: msg_send calls to valid NSView and NSTextField objects
         unsafe {
-            if processing {
-                let orange_color = NSColor::colorWithRed_green_blue_alpha(0.95, 0.6, 0.1, 1.0);
-                let _: () =
-                    msg_send![&inner.recording_indicator, setBackgroundColor: &*orange_color];
+            let orange_color = NSColor::colorWithRed_green_blue_alpha(0.95, 0.6, 0.1, 1.0);
+            let _: () = msg_send![&inner.recording_indicator, setBackgroundColor: &*orange_color];
 
-                let text_color = NSColor::colorWithRed_green_blue_alpha(0.95, 0.6, 0.1, 1.0);
-                inner.recording_label.setTextColor(Some(&text_color));
-                inner
-                    .recording_label
-                    .setStringValue(&NSString::from_str("Processing"));
+            let text_color = NSColor::colorWithRed_green_blue_alpha(0.95, 0.6, 0.1, 1.0);
+            inner.recording_label.setTextColor(Some(&text_color));
+            inner
+                .recording_label
+                .setStringValue(&NSString::from_str(&text));
 
-                let _: () = msg_send![&inner.recording_indicator, setHidden: false];
-                let _: () = msg_send![&inner.recording_label, setHidden: false];
-            } else {
-                let _: () = msg_send![&inner.recording_indicator, setHidden: true];
-                let _: () = msg_send![&inner.recording_label, setHidden: true];
-            }
+            let _: () = msg_send![&inner.recording_indicator, setHidden: false];
+            let _: () = msg_send![&inner.recording_label, setHidden: false];
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Hide the status indicator and its label.
+fn hide_status_indicator(caller: &'static str) {
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in {}", caller);
+            return;
+        };
+
+        // SAFETY: msg_send calls to valid NSView and NSTextField objects
+        unsafe {
+            let _: () = msg_send![&inner.recording_indicator, setHidden: true];
+            let _: () = msg_send![&inner.recording_label, setHidden: true];
         }
     });
 
@@ -1,7 +1,5 @@
 //! File save operations for the transcription window
 
-use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
@@ -9,14 +7,18 @@ use block2::RcBlock;
 use chrono::Local;
 use objc2::rc::Retained;
 use objc2::{msg_send, msg_send_id, ClassType};
-use objc2_app_kit::{NSPopUpButton, NSSavePanel, NSStackView, NSTextField};
+use objc2_app_kit::{NSAlert, NSAlertStyle, NSPopUpButton, NSSavePanel, NSStackView, NSTextField};
 use objc2_foundation::{CGRect, CGSize, MainThreadMarker, NSArray, NSPoint, NSString};
 use tracing::{error, info};
 
 use super::dispatch_to_main;
 use super::pdf_writer;
-use crate::storage;
-use crate::transcription_window::state::{pending_transcript_storage, TRANSCRIPTION_WINDOW};
+use crate::output_modes::{self, ExportFormat};
+use crate::transcription_window::state::{pending_transcript_storage, transcription_window};
+use crate::{
+    attendees, consent, encryption, git_archive, google_drive, preferences, private_mode,
+    provenance, storage, topics,
+};
 
 /// Modal response constant for OK button
 const NS_MODAL_RESPONSE_OK: isize = 1;
@@ -24,8 +26,13 @@ const NS_MODAL_RESPONSE_OK: isize = 1;
 /// Show the save button and store the transcript for later saving.
 ///
 /// The transcript is stored in global state and can be saved when
-/// the user clicks the save button.
+/// the user clicks the save button. Does nothing during a private session.
 pub(crate) fn show_save_button(transcript: String) {
+    if private_mode::is_active() {
+        info!("Save button not shown: private session");
+        return;
+    }
+
     info!(
         "Showing save button for transcript ({} chars)",
         transcript.len()
@@ -39,11 +46,11 @@ pub(crate) fn show_save_button(transcript: String) {
     }
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in show_save_button");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_save_button");
             return;
         };
 
@@ -66,11 +73,11 @@ pub(crate) fn hide_save_button() {
     }
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in hide_save_button");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in hide_save_button");
             return;
         };
 
@@ -116,14 +123,31 @@ pub(crate) fn handle_save_file_action() {
     // SAFETY: NSSavePanel::class() returns valid class, savePanel creates valid instance
     let panel: Retained<NSSavePanel> = unsafe { msg_send_id![NSSavePanel::class(), savePanel] };
 
-    // Create format popup button for accessory view
-    let format_popup = create_format_popup(mtm);
+    // Create format popup button for accessory view, preselecting the
+    // format suggested by the shown tab's output mode
+    let active_tab = transcription_window()
+        .and_then(|inner| inner.try_borrow().ok().map(|inner| inner.active_tab))
+        .unwrap_or_default();
+    let format_popup = create_format_popup(mtm, output_modes::export_format(active_tab));
+    let transcript = consent::add_to_export(
+        active_tab,
+        &attendees::add_to_export(active_tab, &transcript),
+        Local::now(),
+    );
 
     // SAFETY: All msg_send calls are to valid NSSavePanel methods
     unsafe {
-        // Generate default filename with timestamp (without extension - will be added based on format)
-        let timestamp = Local::now().format("%Y-%m-%d-%H-%M-%S");
-        let default_name = format!("transcript-{}", timestamp);
+        // Generate default filename with timestamp (without extension - will be added based on format),
+        // numbered if a transcript was already saved under it this second
+        let default_name = storage::timestamped_name("transcript", "md");
+        let default_name = match storage::transcripts_dir() {
+            Some(dir) => storage::unique_path(&dir, &default_name),
+            None => PathBuf::from(default_name),
+        };
+        let default_name = default_name
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
         panel.setNameFieldStringValue(&NSString::from_str(&default_name));
 
         // Set prompt and message
@@ -181,13 +205,24 @@ pub(crate) fn handle_save_file_action() {
 
                 // Write transcript to file (routes to PDF or text based on extension)
                 match write_transcript_to_path(&path, &transcript) {
-                    Ok(()) => {
-                        info!("Transcript saved to: {:?}", path);
+                    Ok((saved_path, contents)) => {
+                        info!("Transcript saved to: {:?}", saved_path);
                         // Hide the save button after successful save
                         hide_save_button();
+                        google_drive::upload_saved_transcript(&path, contents);
+                        git_archive::archive_transcript(
+                            saved_path,
+                            git_archive::CommitDetails {
+                                saved_at: Local::now(),
+                                mode: output_modes::title(active_tab),
+                                words: transcript.split_whitespace().count(),
+                                provenance: provenance::current(),
+                            },
+                        );
                     }
                     Err(e) => {
-                        error!("Failed to save transcript: {}", e);
+                        error!("Failed to save transcript: {:#}", e);
+                        show_save_error(mtm, &e);
                     }
                 }
             }
@@ -197,11 +232,32 @@ pub(crate) fn handle_save_file_action() {
     }
 }
 
+/// Tell the user why the transcript could not be saved.
+///
+/// The transcript stays in the window and the save button stays visible, so
+/// saving can be retried elsewhere.
+fn show_save_error(mtm: MainThreadMarker, error: &anyhow::Error) {
+    let message = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<storage::StorageError>())
+        .map(storage::StorageError::user_message)
+        .unwrap_or_else(|| format!("{:#}", error));
+
+    // SAFETY: NSAlert is created and run on the main thread (guaranteed by MainThreadMarker)
+    unsafe {
+        let alert = NSAlert::new(mtm);
+        alert.setAlertStyle(NSAlertStyle::Warning);
+        alert.setMessageText(&NSString::from_str("Could not save the transcript"));
+        alert.setInformativeText(&NSString::from_str(&message));
+        alert.runModal();
+    }
+}
+
 /// Create the format selection popup button.
 ///
 /// # Safety
 /// Must be called from the main thread.
-fn create_format_popup(mtm: MainThreadMarker) -> Retained<NSPopUpButton> {
+fn create_format_popup(mtm: MainThreadMarker, selected: ExportFormat) -> Retained<NSPopUpButton> {
     unsafe {
         let frame = CGRect::new(NSPoint::new(0.0, 0.0), CGSize::new(150.0, 25.0));
         let popup: Retained<NSPopUpButton> =
@@ -213,8 +269,12 @@ fn create_format_popup(mtm: MainThreadMarker) -> Retained<NSPopUpButton> {
         let _: () = msg_send![&popup, addItemWithTitle: &*md_title];
         let _: () = msg_send![&popup, addItemWithTitle: &*pdf_title];
 
-        // Select Markdown by default
-        let _: () = msg_send![&popup, selectItemAtIndex: 0_isize];
+        // Select the suggested format (Markdown unless the mode asks for PDF)
+        let index: isize = match selected {
+            ExportFormat::Markdown => 0,
+            ExportFormat::Pdf => 1,
+        };
+        let _: () = msg_send![&popup, selectItemAtIndex: index];
 
         popup
     }
@@ -265,24 +325,31 @@ fn create_format_accessory_view(
 
 /// Write transcript to file, choosing format based on file extension.
 ///
-/// Routes to PDF generation for `.pdf` files, or plain text for `.md`/`.txt`.
-fn write_transcript_to_path(path: &Path, transcript: &str) -> Result<()> {
+/// Routes to PDF generation for `.pdf` files, or plain text for `.md`/`.txt`
+/// with front-matter naming the providers, models and prompt that produced it.
+/// The file is encrypted when encryption at rest is enabled, so the returned
+/// path may have `.enc` appended. The unencrypted contents are returned too.
+fn write_transcript_to_path(path: &Path, transcript: &str) -> Result<(PathBuf, Vec<u8>)> {
     let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("md");
 
-    match extension.to_lowercase().as_str() {
-        "pdf" => pdf_writer::write_pdf(path, transcript),
-        _ => write_transcript_to_file(path, transcript)
-            .with_context(|| format!("Failed to write transcript to {}", path.display())),
-    }
-}
+    let contents = match extension.to_lowercase().as_str() {
+        "pdf" => {
+            let title = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("Transcript");
+            pdf_writer::render_pdf(transcript, title, &preferences::get_pdf_settings())?
+        }
+        // Contents entries of topic sections become anchor links
+        _ => format!(
+            "{}{}",
+            provenance::current().front_matter(),
+            topics::link_contents(transcript)
+        )
+        .into_bytes(),
+    };
 
-/// Write transcript content to a text file.
-fn write_transcript_to_file(path: &Path, transcript: &str) -> Result<()> {
-    let mut file = fs::File::create(path)
-        .with_context(|| format!("Failed to create file {}", path.display()))?;
-    file.write_all(transcript.as_bytes())
-        .with_context(|| format!("Failed to write to {}", path.display()))?;
-    file.flush()
-        .with_context(|| format!("Failed to flush {}", path.display()))?;
-    Ok(())
+    let saved_path = encryption::write_file(path, &contents)
+        .with_context(|| format!("Failed to write transcript to {}", path.display()))?;
+    Ok((saved_path, contents))
 }
@@ -0,0 +1,157 @@
+//! Practice script display in the Live tab
+//!
+//! While a script is shown, the Live tab belongs to it: transcript updates
+//! are stored but not drawn, and the text can't be edited. Closing the
+//! script draws the stored transcript again.
+
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_app_kit::{NSColor, NSFont};
+use objc2_foundation::{NSMutableAttributedString, NSRange, NSString};
+use std::sync::atomic::Ordering;
+use tracing::error;
+
+use super::dispatch_to_main;
+use super::editing::set_live_editable;
+use super::text::set_text_view_attributed_string;
+use crate::keywords;
+use crate::transcription_window::markdown::{
+    apply_keyword_highlights, create_attributed_string, utf16_len,
+};
+use crate::transcription_window::state::{
+    transcription_window, IS_DARK_MODE, IS_RECORDING, SCRIPT_MODE,
+};
+
+/// Background alpha for the next word to read
+const NEXT_WORD_HIGHLIGHT_ALPHA: f64 = 0.45;
+
+/// Show a practice script in the Live tab.
+///
+/// `spoken_len` is the byte length of the part already read, which is
+/// dimmed; `next_word` is the byte range of the word to read next.
+pub(crate) fn show_script(script: &str, spoken_len: usize, next_word: Option<(usize, usize)>) {
+    let script = script.to_string();
+
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_script");
+            return;
+        };
+
+        SCRIPT_MODE.store(true, Ordering::SeqCst);
+        set_live_editable(&inner, false);
+
+        let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
+        let attr_string = create_script_string(&script, spoken_len, next_word, is_dark);
+        set_text_view_attributed_string(&inner.live_text_view, &attr_string);
+
+        // Keep the next word in view
+        let position = next_word.map_or(spoken_len, |(start, _)| start);
+        let range = NSRange::new(utf16_len(&script[..position]), 0);
+        // SAFETY: the range lies within the text view's string
+        unsafe {
+            let _: () = msg_send![&inner.live_text_view, scrollRangeToVisible: range];
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Close the practice script and show the live transcript again.
+pub(crate) fn clear_script() {
+    let block = RcBlock::new(|| {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in clear_script");
+            return;
+        };
+
+        SCRIPT_MODE.store(false, Ordering::SeqCst);
+        set_live_editable(&inner, !IS_RECORDING.load(Ordering::SeqCst));
+
+        let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
+        let display_text = format!("{}\n\n\n\n\n\n", inner.tab_content.live_transcript);
+        let attr_string = create_attributed_string(&display_text, is_dark, true);
+        apply_keyword_highlights(&attr_string, &keywords::active_rules());
+        set_text_view_attributed_string(&inner.live_text_view, &attr_string);
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Script text in a larger reading font, dimmed up to `spoken_len` and
+/// with the next word highlighted.
+fn create_script_string(
+    script: &str,
+    spoken_len: usize,
+    next_word: Option<(usize, usize)>,
+    is_dark: bool,
+) -> Retained<NSMutableAttributedString> {
+    let padded = format!("{}\n\n\n\n\n\n", script);
+    let ns_string = NSString::from_str(&padded);
+    let full_range = NSRange::new(0, utf16_len(&padded));
+    let spoken_range = NSRange::new(0, utf16_len(&script[..spoken_len]));
+
+    let color_attr = NSString::from_str("NSColor");
+    let font_attr = NSString::from_str("NSFont");
+    let background_attr = NSString::from_str("NSBackgroundColor");
+
+    // SAFETY: attribute ranges lie within the attributed string
+    unsafe {
+        let attr_string: Retained<NSMutableAttributedString> = msg_send_id![
+            NSMutableAttributedString::alloc(),
+            initWithString: &*ns_string
+        ];
+
+        let font = NSFont::systemFontOfSize(18.0);
+        let (text_color, spoken_color) = if is_dark {
+            (
+                NSColor::whiteColor(),
+                NSColor::colorWithRed_green_blue_alpha(1.0, 1.0, 1.0, 0.4),
+            )
+        } else {
+            (
+                NSColor::blackColor(),
+                NSColor::colorWithRed_green_blue_alpha(0.0, 0.0, 0.0, 0.4),
+            )
+        };
+
+        let _: () = msg_send![
+            &attr_string,
+            addAttribute: &*font_attr,
+            value: &*font,
+            range: full_range
+        ];
+        let _: () = msg_send![
+            &attr_string,
+            addAttribute: &*color_attr,
+            value: &*text_color,
+            range: full_range
+        ];
+        let _: () = msg_send![
+            &attr_string,
+            addAttribute: &*color_attr,
+            value: &*spoken_color,
+            range: spoken_range
+        ];
+
+        if let Some((start, end)) = next_word {
+            let highlight =
+                NSColor::colorWithRed_green_blue_alpha(1.0, 0.8, 0.0, NEXT_WORD_HIGHLIGHT_ALPHA);
+            let _: () = msg_send![
+                &attr_string,
+                addAttribute: &*background_attr,
+                value: &*highlight,
+                range: NSRange::new(utf16_len(&script[..start]), utf16_len(&script[start..end]))
+            ];
+        }
+
+        attr_string
+    }
+}
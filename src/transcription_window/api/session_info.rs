@@ -0,0 +1,103 @@
+//! Configuration the provider applied, shown from the overlay's header
+//!
+//! Once the provider confirms the session, the info button appears in the
+//! header. It opens a popover with the transcription model, language, voice
+//! activity detection, audio format and noise reduction the server reported,
+//! so users can check what is actually in effect. The info is cleared when a
+//! new recording starts.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2_foundation::MainThreadMarker;
+use tracing::error;
+
+use super::dispatch_to_main;
+use super::popover::show_text_popover;
+use crate::localization::tr;
+use crate::transcription::SessionInfo;
+use crate::transcription_window::state::{transcription_window, TranscriptionWindowInner};
+
+/// Keep the session's configuration and show the info button
+pub(crate) fn set_session_info(info: SessionInfo) {
+    let block = RcBlock::new(move || {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in set_session_info");
+            return;
+        };
+
+        inner.session_info = Some(info.clone());
+        // SAFETY: setHidden is safe on a valid NSButton
+        unsafe {
+            let _: () = msg_send![&inner.info_button, setHidden: false];
+        }
+    });
+
+    dispatch_to_main(&block);
+}
+
+/// Forget the previous session's configuration and hide the info button.
+/// Called with the window already borrowed on the main thread.
+pub(super) fn clear_session_info(inner: &mut TranscriptionWindowInner) {
+    inner.session_info = None;
+    // SAFETY: setHidden is safe on a valid NSButton
+    unsafe {
+        let _: () = msg_send![&inner.info_button, setHidden: true];
+    }
+}
+
+/// Show the session info popover anchored to the header's info button.
+/// Must be called on the main thread.
+pub(crate) fn show_session_info_popover() {
+    let Some(mtm) = MainThreadMarker::new() else {
+        error!("Session info popover must be shown on the main thread");
+        return;
+    };
+
+    let text = {
+        let Some(inner) = transcription_window() else {
+            return;
+        };
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_session_info_popover");
+            return;
+        };
+        let Some(info) = &inner.session_info else {
+            return;
+        };
+        describe(info)
+    };
+
+    show_text_popover(mtm, |inner| &inner.info_button, &text);
+}
+
+/// One line per setting, e.g. "Model: gpt-4o-transcribe"
+fn describe(info: &SessionInfo) -> String {
+    let not_set = tr("overlay.session_not_set");
+    let off = tr("overlay.session_off");
+    [
+        (
+            tr("overlay.session_model"),
+            info.transcription_model().unwrap_or(not_set),
+        ),
+        (
+            tr("overlay.session_language"),
+            info.language().unwrap_or(not_set),
+        ),
+        (tr("overlay.session_vad"), info.vad_mode().unwrap_or(off)),
+        (
+            tr("overlay.session_format"),
+            info.input_audio_format.as_deref().unwrap_or(not_set),
+        ),
+        (
+            tr("overlay.session_noise"),
+            info.noise_reduction().unwrap_or(off),
+        ),
+    ]
+    .iter()
+    .map(|(label, value)| format!("{}: {}", label, value))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
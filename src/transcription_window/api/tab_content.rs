@@ -6,70 +6,46 @@ use objc2_foundation::NSString;
 use std::sync::atomic::Ordering;
 use tracing::error;
 
+use super::debug::hide_debug_tab;
 use super::dispatch_to_main;
+use super::session_info::clear_session_info;
 use super::text::set_text_view_attributed_string;
+use crate::localization::tr;
+use crate::output_modes;
 use crate::transcription_window::markdown::create_attributed_string;
-use crate::transcription_window::state::{TabType, IS_DARK_MODE, TRANSCRIPTION_WINDOW};
+use crate::transcription_window::state::{
+    transcription_window, TabType, IS_DARK_MODE, SCRIPT_MODE,
+};
 
-/// Set polished content (Tab 2).
+/// Set the generated content of an output mode's tab.
 ///
-/// Stores the content and updates the polished text view display.
-pub(crate) fn set_polished_content(content: &str) {
+/// Stores the content and updates the tab's text view display.
+pub(crate) fn set_generated_content(tab: TabType, content: &str) {
     let content = content.to_string();
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_polished_content");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in set_generated_content");
             return;
         };
 
-        // Store the polished content
-        inner.tab_content.polished_content = Some(content.clone());
+        // Store the generated content
+        inner.tab_content.generated.insert(tab, content.clone());
 
         // Add padding at the end
         let display_text = format!("{}\n\n\n\n\n\n", content);
 
-        // Create attributed string (proportional font for polished)
+        // Create attributed string (proportional font for generated content)
         let attr_string = create_attributed_string(&display_text, is_dark, false);
 
-        // Update polished text view
-        set_text_view_attributed_string(&inner.polished_text_view, &attr_string);
-    });
-
-    dispatch_to_main(&block);
-}
-
-/// Set meeting notes content (Tab 3).
-///
-/// Stores the content and updates the meeting notes text view display.
-pub(crate) fn set_meeting_notes_content(content: &str) {
-    let content = content.to_string();
-    let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
-
-    let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
-            return;
-        };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_meeting_notes_content");
-            return;
-        };
-
-        // Store the meeting notes content
-        inner.tab_content.meeting_notes_content = Some(content.clone());
-
-        // Add padding at the end
-        let display_text = format!("{}\n\n\n\n\n\n", content);
-
-        // Create attributed string (proportional font for meeting notes)
-        let attr_string = create_attributed_string(&display_text, is_dark, false);
-
-        // Update meeting notes text view
-        set_text_view_attributed_string(&inner.meeting_text_view, &attr_string);
+        // Update the tab's text view
+        if let Some((_, text_view)) = inner.views(tab) {
+            set_text_view_attributed_string(text_view, &attr_string);
+        }
     });
 
     dispatch_to_main(&block);
@@ -77,12 +53,12 @@ pub(crate) fn set_meeting_notes_content(content: &str) {
 
 /// Get the current raw transcript for on-demand polishing.
 ///
-/// Returns `None` if the window doesn't exist or the transcript is empty.
-#[allow(dead_code)]
+/// Returns `None` if the window doesn't exist, the transcript is empty, or
+/// when called off the main thread.
 pub(crate) fn get_live_transcript() -> Option<String> {
-    let inner = TRANSCRIPTION_WINDOW.get()?;
-    let Ok(inner) = inner.lock() else {
-        error!("Failed to acquire transcription window lock in get_live_transcript");
+    let inner = transcription_window()?;
+    let Ok(inner) = inner.try_borrow() else {
+        error!("Transcription window already borrowed in get_live_transcript");
         return None;
     };
 
@@ -94,6 +70,24 @@ pub(crate) fn get_live_transcript() -> Option<String> {
     }
 }
 
+/// Get the generated content of an output mode's tab.
+///
+/// Returns `None` if the window doesn't exist or the tab has nothing generated yet.
+pub(crate) fn get_generated_content(tab: TabType) -> Option<String> {
+    let inner = transcription_window()?;
+    let Ok(inner) = inner.try_borrow() else {
+        error!("Transcription window already borrowed in get_generated_content");
+        return None;
+    };
+
+    inner
+        .tab_content
+        .generated
+        .get(&tab)
+        .filter(|content| !content.is_empty())
+        .cloned()
+}
+
 /// Reset tab content when starting a new recording.
 ///
 /// Clears all tab content, resets to the Live tab, and shows
@@ -102,47 +96,47 @@ pub(crate) fn reset_tabs() {
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in reset_tabs");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in reset_tabs");
             return;
         };
 
         // Reset tab content
         inner.tab_content.live_transcript.clear();
-        inner.tab_content.polished_content = None;
-        inner.tab_content.meeting_notes_content = None;
+        inner.tab_content.generated.clear();
         inner.active_tab = TabType::Live;
 
-        // Reset live tab text
-        let live_attr = create_attributed_string("Listening...\n\n\n\n\n\n", is_dark, true);
-        set_text_view_attributed_string(&inner.live_text_view, &live_attr);
-
-        // Reset polished tab with placeholder
-        let polished_attr = create_attributed_string(
-            "Click to generate polished transcript...\n\n\n\n\n\n",
-            is_dark,
-            true,
-        );
-        set_text_view_attributed_string(&inner.polished_text_view, &polished_attr);
-
-        // Reset meeting notes tab with placeholder
-        let meeting_attr = create_attributed_string(
-            "Click to generate meeting notes...\n\n\n\n\n\n",
-            is_dark,
-            true,
-        );
-        set_text_view_attributed_string(&inner.meeting_text_view, &meeting_attr);
+        // Reset live tab text, unless a practice script is shown there
+        if !SCRIPT_MODE.load(Ordering::SeqCst) {
+            let live_attr = create_attributed_string(
+                &format!("{}\n\n\n\n\n\n", tr("overlay.listening")),
+                is_dark,
+                true,
+            );
+            set_text_view_attributed_string(&inner.live_text_view, &live_attr);
+        }
+
+        // Reset output mode tabs with their placeholders
+        for view in &inner.output_views {
+            let attr_string = create_attributed_string(
+                &format!("{}\n\n\n\n\n\n", output_modes::placeholder(view.tab)),
+                is_dark,
+                true,
+            );
+            set_text_view_attributed_string(&view.text_view, &attr_string);
+        }
 
         // Switch to live tab
+        hide_debug_tab(&mut inner);
         // SAFETY: msg_send to valid NSSegmentedControl and NSScrollView objects
         unsafe {
             let _: () = msg_send![&inner.segmented_control, setSelectedSegment: 0isize];
-            let _: () = msg_send![&inner.live_scroll_view, setHidden: false];
-            let _: () = msg_send![&inner.polished_scroll_view, setHidden: true];
-            let _: () = msg_send![&inner.meeting_scroll_view, setHidden: true];
+            for (tab, scroll_view) in inner.scroll_views() {
+                let _: () = msg_send![scroll_view, setHidden: tab != TabType::Live];
+            }
         }
 
         // Update header
@@ -150,8 +144,11 @@ pub(crate) fn reset_tabs() {
         unsafe {
             inner
                 .recording_type_label
-                .setStringValue(&NSString::from_str("Live Transcription"));
+                .setStringValue(&NSString::from_str(tr("overlay.live_transcription")));
         }
+
+        // The next session reports its own configuration
+        clear_session_info(&mut inner);
     });
 
     dispatch_to_main(&block);
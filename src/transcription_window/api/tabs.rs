@@ -6,12 +6,16 @@ use objc2_foundation::NSString;
 use std::sync::atomic::Ordering;
 use tracing::{error, info};
 
+use super::debug::{hide_debug_tab, show_debug_tab};
 use super::dispatch_to_main;
 use super::recording::set_processing_state;
 use super::text::set_text_view_attributed_string;
+use crate::localization::tr;
+use crate::output_modes;
 use crate::transcription_window::markdown::create_attributed_string;
 use crate::transcription_window::state::{
-    TabType, IS_DARK_MODE, IS_RECORDING, TRANSCRIPTION_WINDOW, WINDOW_CALLBACKS,
+    debug_segment, transcription_window, TabType, TranscriptionWindowInner, IS_DARK_MODE,
+    IS_RECORDING, WINDOW_CALLBACKS,
 };
 
 /// Handle tab change from segmented control.
@@ -21,13 +25,19 @@ use crate::transcription_window::state::{
 /// Otherwise, switches to the selected tab and triggers on-demand
 /// content generation if needed.
 pub(crate) fn handle_tab_change(selected_index: isize) {
+    // The Debug tab can be shown while recording and has nothing to generate
+    if selected_index == debug_segment() {
+        show_debug_tab();
+        return;
+    }
+
     let tab = TabType::from_index(selected_index);
     info!("Tab changed to: {:?}", tab);
 
     // Check if recording is active
     let is_recording = IS_RECORDING.load(Ordering::SeqCst);
 
-    // If recording and user clicks on polished or meeting notes tab, show message
+    // If recording and user clicks on any tab but Live, show message
     if is_recording && tab != TabType::Live {
         show_stop_recording_message(tab);
         return;
@@ -35,19 +45,15 @@ pub(crate) fn handle_tab_change(selected_index: isize) {
 
     // Check if we need to generate content on-demand
     let (needs_generation, transcript) = {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in handle_tab_change");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in handle_tab_change");
             return;
         };
 
-        let needs_gen = match tab {
-            TabType::Live => false,
-            TabType::BasicPolish => inner.tab_content.polished_content.is_none(),
-            TabType::MeetingNotes => inner.tab_content.meeting_notes_content.is_none(),
-        };
+        let needs_gen = tab != TabType::Live && !inner.tab_content.generated.contains_key(&tab);
 
         (needs_gen, inner.tab_content.live_transcript.clone())
     };
@@ -68,8 +74,8 @@ pub(crate) fn handle_tab_change(selected_index: isize) {
 
 /// Trigger on-demand generation for a specific tab.
 ///
-/// Shows a "Generating..." message and calls the appropriate callback
-/// to generate polished content or meeting notes.
+/// Shows a "Generating..." message and calls the callback that generates
+/// the tab's output mode.
 fn trigger_on_demand_generation(tab: TabType, transcript: String) {
     let Some(callbacks) = WINDOW_CALLBACKS.get() else {
         info!("No callbacks registered for on-demand generation");
@@ -81,45 +87,30 @@ fn trigger_on_demand_generation(tab: TabType, transcript: String) {
 
     // Update the tab with "Generating..." message
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
-    let message = match tab {
-        TabType::Live => return,
-        TabType::BasicPolish => "⏳ Generating polished transcript...\n\n\n\n\n\n",
-        TabType::MeetingNotes => "⏳ Generating meeting notes...\n\n\n\n\n\n",
+    let Some(message) = output_modes::generating_message(tab) else {
+        return;
     };
+    let message = format!("{}\n\n\n\n\n\n", message);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in trigger_on_demand_generation");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in trigger_on_demand_generation");
             return;
         };
 
-        let attr_string = create_attributed_string(message, is_dark, true);
-        let text_view = match tab {
-            TabType::Live => return,
-            TabType::BasicPolish => &inner.polished_text_view,
-            TabType::MeetingNotes => &inner.meeting_text_view,
-        };
-
-        set_text_view_attributed_string(text_view, &attr_string);
+        let attr_string = create_attributed_string(&message, is_dark, true);
+        if let Some((_, text_view)) = inner.views(tab) {
+            set_text_view_attributed_string(text_view, &attr_string);
+        }
     });
 
     dispatch_to_main(&block);
 
-    // Trigger the appropriate callback
-    match tab {
-        TabType::Live => {}
-        TabType::BasicPolish => {
-            info!("Triggering on-demand basic polishing");
-            (callbacks.on_request_basic_polish)(transcript);
-        }
-        TabType::MeetingNotes => {
-            info!("Triggering on-demand meeting notes generation");
-            (callbacks.on_request_meeting_notes)(transcript);
-        }
-    }
+    info!(?tab, "Triggering on-demand generation");
+    (callbacks.on_request_generation)(tab, transcript);
 }
 
 /// Switch to a specific tab.
@@ -128,11 +119,11 @@ fn trigger_on_demand_generation(tab: TabType, transcript: String) {
 /// scroll views, and updates the header label.
 pub(crate) fn switch_to_tab(tab: TabType) {
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in switch_to_tab");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in switch_to_tab");
             return;
         };
 
@@ -145,27 +136,15 @@ pub(crate) fn switch_to_tab(tab: TabType) {
             let _: () = msg_send![&inner.segmented_control, setSelectedSegment: tab.to_index()];
         }
 
-        // Show/hide scroll views based on active tab
-        // SAFETY: msg_send setHidden: to valid NSScrollView objects
-        unsafe {
-            let _: () = msg_send![&inner.live_scroll_view, setHidden: tab != TabType::Live];
-            let _: () =
-                msg_send![&inner.polished_scroll_view, setHidden: tab != TabType::BasicPolish];
-            let _: () =
-                msg_send![&inner.meeting_scroll_view, setHidden: tab != TabType::MeetingNotes];
-        }
+        show_tab_view(&mut inner, tab);
 
         // Update header label based on tab
-        let label_text = match tab {
-            TabType::Live => "Live Transcription",
-            TabType::BasicPolish => "Polished Transcript",
-            TabType::MeetingNotes => "Meeting Notes",
-        };
+        let label_text = output_modes::title(tab);
         // SAFETY: setStringValue is safe on valid NSTextField
         unsafe {
             inner
                 .recording_type_label
-                .setStringValue(&NSString::from_str(label_text));
+                .setStringValue(&NSString::from_str(&label_text));
         }
     });
 
@@ -175,14 +154,14 @@ pub(crate) fn switch_to_tab(tab: TabType) {
 /// Show "Stop recording first" message when user tries to access other tabs during recording.
 fn show_stop_recording_message(tab: TabType) {
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
-    let message = "⚠️ Stop recording first to generate content for this tab.\n\n\n\n\n\n";
+    let message = format!("{}\n\n\n\n\n\n", tr("overlay.stop_first"));
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in show_stop_recording_message");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in show_stop_recording_message");
             return;
         };
 
@@ -195,25 +174,13 @@ fn show_stop_recording_message(tab: TabType) {
             let _: () = msg_send![&inner.segmented_control, setSelectedSegment: tab.to_index()];
         }
 
-        // Show/hide scroll views
-        // SAFETY: msg_send setHidden: to valid NSScrollView objects
-        unsafe {
-            let _: () = msg_send![&inner.live_scroll_view, setHidden: tab != TabType::Live];
-            let _: () =
-                msg_send![&inner.polished_scroll_view, setHidden: tab != TabType::BasicPolish];
-            let _: () =
-                msg_send![&inner.meeting_scroll_view, setHidden: tab != TabType::MeetingNotes];
-        }
+        show_tab_view(&mut inner, tab);
 
         // Set the message in the appropriate text view
-        let attr_string = create_attributed_string(message, is_dark, true);
-        let text_view = match tab {
-            TabType::Live => &inner.live_text_view,
-            TabType::BasicPolish => &inner.polished_text_view,
-            TabType::MeetingNotes => &inner.meeting_text_view,
-        };
-
-        set_text_view_attributed_string(text_view, &attr_string);
+        let attr_string = create_attributed_string(&message, is_dark, true);
+        if let Some((_, text_view)) = inner.views(tab) {
+            set_text_view_attributed_string(text_view, &attr_string);
+        }
     });
 
     dispatch_to_main(&block);
@@ -222,30 +189,37 @@ fn show_stop_recording_message(tab: TabType) {
 /// Show prompt to generate content when tab has no content.
 fn show_generate_prompt(tab: TabType) {
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
-    let message = match tab {
-        TabType::Live => return, // Live tab doesn't need generation
-        TabType::BasicPolish => "📝 No polished content yet.\n\nThe transcript will be polished when you stop recording with 'Basic Polishing',\nor you can click here after recording to generate it.\n\n\n\n\n\n",
-        TabType::MeetingNotes => "📋 No meeting notes yet.\n\nMeeting notes will be generated when you stop recording with 'Meeting Notes',\nor you can click here after recording to generate them.\n\n\n\n\n\n",
+    // Live tab doesn't need generation
+    let Some(message) = output_modes::empty_message(tab) else {
+        return;
     };
+    let message = format!("{}\n\n\n\n\n\n", message);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in show_generate_prompt");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in show_generate_prompt");
             return;
         };
 
-        let attr_string = create_attributed_string(message, is_dark, true);
-        let text_view = match tab {
-            TabType::Live => &inner.live_text_view,
-            TabType::BasicPolish => &inner.polished_text_view,
-            TabType::MeetingNotes => &inner.meeting_text_view,
-        };
-
-        set_text_view_attributed_string(text_view, &attr_string);
+        let attr_string = create_attributed_string(&message, is_dark, true);
+        if let Some((_, text_view)) = inner.views(tab) {
+            set_text_view_attributed_string(text_view, &attr_string);
+        }
     });
 
     dispatch_to_main(&block);
 }
+
+/// Show the scroll view of `tab` and hide the others.
+fn show_tab_view(inner: &mut TranscriptionWindowInner, tab: TabType) {
+    hide_debug_tab(inner);
+    for (view_tab, scroll_view) in inner.scroll_views() {
+        // SAFETY: msg_send setHidden: to valid NSScrollView objects
+        unsafe {
+            let _: () = msg_send![scroll_view, setHidden: view_tab != tab];
+        }
+    }
+}
@@ -1,16 +1,24 @@
 //! Text display operations for the transcription window
 
 use block2::RcBlock;
-use objc2::msg_send;
+use objc2::rc::Retained;
 use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{NSScrollView, NSTextView};
-use objc2_foundation::{NSAttributedString, NSRange, NSRect};
+use objc2_foundation::{NSAttributedString, NSRange, NSRect, NSString};
 use std::sync::atomic::Ordering;
 use tracing::error;
 
 use super::dispatch_to_main;
-use crate::transcription_window::markdown::create_attributed_string;
-use crate::transcription_window::state::{TabType, IS_DARK_MODE, TRANSCRIPTION_WINDOW};
+use crate::keywords;
+use crate::localization::tr;
+use crate::transcription_window::accessibility;
+use crate::transcription_window::markdown::{
+    apply_keyword_highlights, create_attributed_string, utf16_len,
+};
+use crate::transcription_window::state::{
+    transcription_window, TabType, IS_DARK_MODE, SCRIPT_MODE,
+};
 
 /// Update the displayed transcription text with markdown rendering.
 ///
@@ -24,16 +32,17 @@ pub(crate) fn update_text(committed: &str, partial: Option<&str>, use_monospaced
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in update_text");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in update_text");
             return;
         };
 
         // Get the active tab first
         let active_tab = inner.active_tab;
+        let script_shown = active_tab == TabType::Live && SCRIPT_MODE.load(Ordering::SeqCst);
 
         // Build display text: committed text + partial (if any)
         let display_text = match partial.as_deref() {
@@ -52,39 +61,29 @@ pub(crate) fn update_text(committed: &str, partial: Option<&str>, use_monospaced
             inner.tab_content.live_transcript = display_text.clone();
         }
 
+        if script_shown {
+            return;
+        }
+
         // Add padding at the end
         let padded_text = format!("{}\n\n\n\n\n\n", display_text);
 
         // Create attributed string with markdown parsing
         let attr_string = create_attributed_string(&padded_text, is_dark, use_monospaced);
+        if active_tab == TabType::Live {
+            apply_keyword_highlights(&attr_string, &keywords::active_rules());
+        }
 
         // Determine which views to use and check scroll position
-        let should_scroll = match active_tab {
-            TabType::Live => check_scroll_position_for_view(&inner.live_scroll_view),
-            TabType::BasicPolish => check_scroll_position_for_view(&inner.polished_scroll_view),
-            TabType::MeetingNotes => check_scroll_position_for_view(&inner.meeting_scroll_view),
+        let Some((scroll_view, text_view)) = inner.views(active_tab) else {
+            return;
         };
+        let should_scroll = check_scroll_position_for_view(scroll_view);
 
         // Update the appropriate text view
-        match active_tab {
-            TabType::Live => {
-                set_text_view_attributed_string(&inner.live_text_view, &attr_string);
-                if should_scroll {
-                    scroll_to_bottom_for_view(&inner.live_text_view);
-                }
-            }
-            TabType::BasicPolish => {
-                set_text_view_attributed_string(&inner.polished_text_view, &attr_string);
-                if should_scroll {
-                    scroll_to_bottom_for_view(&inner.polished_text_view);
-                }
-            }
-            TabType::MeetingNotes => {
-                set_text_view_attributed_string(&inner.meeting_text_view, &attr_string);
-                if should_scroll {
-                    scroll_to_bottom_for_view(&inner.meeting_text_view);
-                }
-            }
+        set_text_view_attributed_string(text_view, &attr_string);
+        if should_scroll {
+            scroll_to_bottom_for_view(text_view);
         }
     });
 
@@ -99,18 +98,26 @@ pub(crate) fn clear() {
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in clear");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in clear");
             return;
         };
 
-        let attr_string = create_attributed_string("Listening...\n\n\n\n\n\n", is_dark, true);
-        set_text_view_attributed_string(&inner.live_text_view, &attr_string);
         // Clear stored content
         inner.tab_content.live_transcript.clear();
+        if SCRIPT_MODE.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let attr_string = create_attributed_string(
+            &format!("{}\n\n\n\n\n\n", tr("overlay.listening")),
+            is_dark,
+            true,
+        );
+        set_text_view_attributed_string(&inner.live_text_view, &attr_string);
     });
 
     dispatch_to_main(&block);
@@ -119,18 +126,20 @@ pub(crate) fn clear() {
 /// Update live transcript content (Tab 1) - used during recording.
 ///
 /// Combines committed and partial text, stores it in the tab content,
-/// and updates the display with auto-scroll behavior.
+/// updates the display with auto-scroll behavior and announces newly
+/// committed text to VoiceOver. A new partial only rewrites the text that
+/// changed; committed text redraws the whole view.
 pub(crate) fn update_live_text(committed: &str, partial: Option<&str>) {
     let committed = committed.to_string();
     let partial = partial.map(|s| s.to_string());
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(mut inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in update_live_text");
+        let Ok(mut inner) = inner.try_borrow_mut() else {
+            error!("Transcription window already borrowed in update_live_text");
             return;
         };
 
@@ -151,18 +160,39 @@ pub(crate) fn update_live_text(committed: &str, partial: Option<&str>) {
         // Store the raw transcript
         inner.tab_content.live_transcript = display_text.clone();
 
-        // Add padding at the end
-        let display_text = format!("{}\n\n\n\n\n\n", display_text);
+        // A practice script owns the live view; the transcript is drawn
+        // again when it is closed
+        if !SCRIPT_MODE.load(Ordering::SeqCst) {
+            // Add padding at the end
+            let display_text = format!("{}\n\n\n\n\n\n", display_text);
 
-        // Create attributed string with markdown parsing (monospaced for live)
-        let attr_string = create_attributed_string(&display_text, is_dark, true);
+            // Create attributed string with markdown parsing (monospaced for live)
+            let attr_string = create_attributed_string(&display_text, is_dark, true);
+            apply_keyword_highlights(&attr_string, &keywords::active_rules());
 
-        // Update live text view
-        set_text_view_attributed_string(&inner.live_text_view, &attr_string);
+            // Update live text view
+            if partial.as_deref().is_some_and(|p| !p.is_empty()) {
+                replace_changed_text(&inner.live_text_view, &attr_string);
+            } else {
+                set_text_view_attributed_string(&inner.live_text_view, &attr_string);
+            }
+
+            // Scroll to bottom if we're on the live tab and near bottom
+            if inner.active_tab == TabType::Live && should_scroll_to_bottom {
+                scroll_to_bottom_for_view(&inner.live_text_view);
+            }
+        }
+
+        // Read newly committed segments to VoiceOver users; partials are
+        // skipped since they are rewritten until committed
+        let announcement =
+            accessibility::unannounced_text(&committed, &inner.tab_content.announced_transcript)
+                .map(str::to_string);
+        inner.tab_content.announced_transcript = committed.clone();
+        drop(inner);
 
-        // Scroll to bottom if we're on the live tab and near bottom
-        if inner.active_tab == TabType::Live && should_scroll_to_bottom {
-            scroll_to_bottom_for_view(&inner.live_text_view);
+        if let Some(text) = announcement {
+            accessibility::announce(&text);
         }
     });
 
@@ -212,6 +242,49 @@ pub(super) fn scroll_to_bottom_for_view(text_view: &NSTextView) {
     }
 }
 
+/// Replace only the part of a text view's content that differs from `attr_string`.
+///
+/// Partials usually revise just their last few words. Leaving the text
+/// before them, and its layout, untouched keeps the overlay from flickering.
+fn replace_changed_text(text_view: &NSTextView, attr_string: &NSAttributedString) {
+    // SAFETY: msg_send to valid NSTextView's textStorage, with ranges
+    // within the shown and the new string
+    unsafe {
+        let text_storage: *mut AnyObject = msg_send![text_view, textStorage];
+        if text_storage.is_null() {
+            return;
+        }
+        let shown: Retained<NSString> = msg_send_id![text_storage, string];
+        let shown = shown.to_string();
+        let text = attr_string.string().to_string();
+
+        let start = utf16_len(common_prefix(&shown, &text));
+        let replaced = NSRange::new(start, utf16_len(&shown) - start);
+        let inserted = NSRange::new(start, utf16_len(&text) - start);
+        if replaced.length == 0 && inserted.length == 0 {
+            return;
+        }
+        let changed: Retained<NSAttributedString> =
+            msg_send_id![attr_string, attributedSubstringFromRange: inserted];
+        let _: () = msg_send![
+            text_storage,
+            replaceCharactersInRange: replaced,
+            withAttributedString: &*changed
+        ];
+    }
+}
+
+/// The longest start `a` and `b` have in common
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    &a[..len]
+}
+
 /// Set attributed string content on a text view.
 ///
 /// Updates the text storage of the given text view with the provided
@@ -232,3 +305,19 @@ pub(super) fn set_text_view_attributed_string(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_prefix_stops_at_first_difference() {
+        assert_eq!(
+            common_prefix("We ship on Fri", "We ship on Friday"),
+            "We ship on Fri"
+        );
+        assert_eq!(common_prefix("Weather", "Whether"), "W");
+        assert_eq!(common_prefix("Café noir", "Café au lait"), "Café ");
+        assert_eq!(common_prefix("", "Hello"), "");
+    }
+}
@@ -12,23 +12,37 @@ use tracing::{error, info, warn};
 use super::dispatch_to_main;
 use crate::transcription_window::objc_utils;
 use crate::transcription_window::state::{
-    CURRENT_TRANSPARENCY, IS_DARK_MODE, TRANSCRIPTION_WINDOW, WINDOW_CALLBACKS,
+    transcription_window, CURRENT_TRANSPARENCY, IS_DARK_MODE, IS_RECORDING, WINDOW_CALLBACKS,
 };
 
 /// Hide the transcription window.
 ///
 /// Removes the window from screen without destroying it.
 pub(crate) fn hide() {
-    if let Some(inner) = TRANSCRIPTION_WINDOW.get() {
-        if let Ok(inner) = inner.lock() {
+    if let Some(inner) = transcription_window() {
+        if let Ok(inner) = inner.try_borrow() {
             inner.window.orderOut(None);
             info!("Transcription window hidden");
         } else {
-            error!("Failed to acquire transcription window lock in hide");
+            error!("Transcription window already borrowed in hide");
         }
     }
 }
 
+/// Whether the transcription window is currently on screen.
+///
+/// Must be called on the main thread.
+pub(crate) fn is_visible() -> bool {
+    let Some(inner) = transcription_window() else {
+        return false;
+    };
+    let Ok(inner) = inner.try_borrow() else {
+        error!("Transcription window already borrowed in is_visible");
+        return false;
+    };
+    inner.window.isVisible()
+}
+
 /// Handle hide button click.
 ///
 /// Hides the window and invokes the on_hide callback.
@@ -40,6 +54,31 @@ pub(crate) fn handle_hide_action() {
     }
 }
 
+/// Handle a request to close the window (Cmd+W or `performClose:`).
+///
+/// While recording, the close is turned into a hide so the recording UI
+/// stays in sync when the window is shown again. Returns whether AppKit may
+/// go ahead and close the window.
+pub(crate) fn handle_window_should_close() -> bool {
+    if IS_RECORDING.load(Ordering::SeqCst) {
+        info!("Close requested while recording: hiding transcription window instead");
+        handle_hide_action();
+        return false;
+    }
+    true
+}
+
+/// Handle the window closing.
+///
+/// The window is not released on close and is reused by the next `show`, so
+/// closing is reported like the hide button.
+pub(crate) fn handle_window_will_close() {
+    info!("Transcription window closed");
+    if let Some(callbacks) = WINDOW_CALLBACKS.get() {
+        (callbacks.on_hide)();
+    }
+}
+
 /// Set background transparency.
 ///
 /// # Arguments
@@ -53,11 +92,11 @@ pub(crate) fn set_transparency(alpha: f64) {
     let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_transparency");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_transparency");
             return;
         };
 
@@ -112,11 +151,11 @@ pub(crate) fn set_dark_mode(is_dark: bool) {
     IS_DARK_MODE.store(is_dark, Ordering::SeqCst);
 
     let block = RcBlock::new(move || {
-        let Some(inner) = TRANSCRIPTION_WINDOW.get() else {
+        let Some(inner) = transcription_window() else {
             return;
         };
-        let Ok(inner) = inner.lock() else {
-            error!("Failed to acquire transcription window lock in set_dark_mode");
+        let Ok(inner) = inner.try_borrow() else {
+            error!("Transcription window already borrowed in set_dark_mode");
             return;
         };
 
@@ -152,12 +191,8 @@ pub(crate) fn set_dark_mode(is_dark: bool) {
                 NSColor::blackColor()
             };
 
-            // Update all three text views
-            for text_view in [
-                &inner.live_text_view,
-                &inner.polished_text_view,
-                &inner.meeting_text_view,
-            ] {
+            // Update the text views of all tabs
+            for text_view in inner.text_views() {
                 let text_storage: *mut AnyObject = msg_send![text_view, textStorage];
                 if !text_storage.is_null() {
                     let length: usize = msg_send![text_storage, length];
@@ -201,22 +236,35 @@ pub(crate) fn set_dark_mode(is_dark: bool) {
             }
             // Also update the content tint color for the SF Symbol icon
             let _: () = msg_send![&inner.save_button, setContentTintColor: &*save_button_color];
+            inner.usage_label.setTextColor(Some(&save_button_color));
 
-            // Update close button text color
-            let close_button_color = if is_dark {
+            // Update header button text colors
+            let header_button_color = if is_dark {
                 NSColor::colorWithRed_green_blue_alpha(0.55, 0.55, 0.55, 1.0)
             } else {
                 NSColor::colorWithRed_green_blue_alpha(0.35, 0.35, 0.35, 1.0)
             };
-            let attr_title: *mut AnyObject = msg_send![&inner.hide_button, attributedTitle];
-            if !attr_title.is_null() {
+            for button in [
+                &inner.hide_button,
+                &inner.marker_button,
+                &inner.note_button,
+                &inner.attendees_button,
+                &inner.info_button,
+            ]
+            .into_iter()
+            .chain(&inner.stop_buttons)
+            {
+                let attr_title: *mut AnyObject = msg_send![button, attributedTitle];
+                if attr_title.is_null() {
+                    continue;
+                }
                 let mutable_attr: Retained<AnyObject> = msg_send_id![attr_title, mutableCopy];
                 let length: usize = msg_send![&mutable_attr, length];
                 if length > 0 {
                     let range = NSRange::new(0, length);
                     let color_key = NSString::from_str("NSColor");
-                    let _: () = msg_send![&mutable_attr, addAttribute: &*color_key value: &*close_button_color range: range];
-                    let _: () = msg_send![&inner.hide_button, setAttributedTitle: &*mutable_attr];
+                    let _: () = msg_send![&mutable_attr, addAttribute: &*color_key value: &*header_button_color range: range];
+                    let _: () = msg_send![button, setAttributedTitle: &*mutable_attr];
                 }
             }
         }
@@ -1,27 +1,43 @@
-//! Header view component with recording type label and hide button
+//! Header view component with recording type label, stop buttons, session info, attendees,
+//! note, marker and hide buttons
 
 use objc2::rc::Retained;
-use objc2::runtime::AnyObject;
+use objc2::runtime::{AnyObject, Sel};
 use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{NSColor, NSFont, NSTextField, NSView};
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRange, NSRect, NSSize, NSString};
 use std::sync::atomic::Ordering;
 
+use super::stop_buttons::create_stop_buttons;
+use crate::localization::tr;
 use crate::transcription_window::delegates::{HoverButton, WindowActionDelegate};
 use crate::transcription_window::state::IS_DARK_MODE;
 
-/// Create the header view with recording type label and hide button
+/// Views created for the window header
+pub(in crate::transcription_window) struct HeaderViews {
+    pub header_view: Retained<NSView>,
+    pub hide_button: Retained<HoverButton>,
+    pub marker_button: Retained<HoverButton>,
+    pub note_button: Retained<HoverButton>,
+    pub attendees_button: Retained<HoverButton>,
+    pub info_button: Retained<HoverButton>,
+    pub stop_buttons: Vec<Retained<HoverButton>>,
+    pub recording_type_label: Retained<NSTextField>,
+}
+
+/// Create the header view with recording type label, session info, attendees, note, marker
+/// and hide buttons
+///
+/// The stop, attendees, note and marker buttons start hidden and are only shown while
+/// recording.
+/// The session info button is shown once the provider has confirmed the session.
 pub(in crate::transcription_window) fn create_header(
     mtm: MainThreadMarker,
     window_width: CGFloat,
     window_height: CGFloat,
     header_height: CGFloat,
     delegate: &WindowActionDelegate,
-) -> (
-    Retained<NSView>,
-    Retained<HoverButton>,
-    Retained<NSTextField>,
-) {
+) -> HeaderViews {
     // Header frame at top of window
     let header_frame = NSRect::new(
         NSPoint::new(0.0, window_height - header_height),
@@ -37,13 +53,19 @@ pub(in crate::transcription_window) fn create_header(
         // Autoresizing: width sizable (2) | min Y margin (8) = 10
         // This ensures the header stretches horizontally and stays at the top
         let _: () = msg_send![&header_view, setAutoresizingMask: 10u64];
+
+        // Accessibility: expose the header as a named group for VoiceOver
+        let role = NSString::from_str("AXGroup");
+        let _: () = msg_send![&header_view, setAccessibilityRole: &*role];
+        let accessibility_label = NSString::from_str(tr("overlay.header"));
+        let _: () = msg_send![&header_view, setAccessibilityLabel: &*accessibility_label];
     }
 
     // Create recording type label on the left side
     let label_margin: CGFloat = 12.0;
     let label_frame = NSRect::new(
         NSPoint::new(label_margin, (header_height - 16.0) / 2.0),
-        NSSize::new(window_width - 60.0, 16.0),
+        NSSize::new(window_width - 180.0, 16.0),
     );
 
     let recording_type_label: Retained<NSTextField> =
@@ -72,14 +94,13 @@ pub(in crate::transcription_window) fn create_header(
         let _: () = msg_send![&recording_type_label, setFont: &*font];
 
         // Default label text
-        recording_type_label.setStringValue(&NSString::from_str("Live Transcription"));
+        recording_type_label.setStringValue(&NSString::from_str(tr("overlay.live_transcription")));
 
         // Autoresizing: width sizable (2) to stretch with header
         let _: () = msg_send![&recording_type_label, setAutoresizingMask: 2u64];
     }
 
-    // Create hide button with SF Symbol (xmark) on the right side
-    // Uses HoverImageButton for tint color change on hover
+    // Create hide button on the right side
     let button_size: CGFloat = 28.0; // Increased from 20px for better touch target
     let button_margin: CGFloat = 6.0;
     let button_frame = NSRect::new(
@@ -90,20 +111,180 @@ pub(in crate::transcription_window) fn create_header(
         NSSize::new(button_size, button_size),
     );
 
-    let hide_button = HoverButton::new(mtm, button_frame);
+    // Use text "X" for close button (more reliable than SF Symbols)
+    let hide_button = create_header_button(
+        mtm,
+        button_frame,
+        "\u{2715}",
+        16.0,
+        is_dark,
+        delegate,
+        objc2::sel!(handleHide:),
+        tr("overlay.close"),
+    );
+
+    // Create marker button to the left of the hide button
+    let marker_frame = NSRect::new(
+        NSPoint::new(
+            window_width - button_size * 2.0 - button_margin,
+            (header_height - button_size) / 2.0,
+        ),
+        NSSize::new(button_size, button_size),
+    );
+
+    let marker_button = create_header_button(
+        mtm,
+        marker_frame,
+        "\u{2691}",
+        14.0,
+        is_dark,
+        delegate,
+        objc2::sel!(handleInsertMarker:),
+        "Insert marker into transcript",
+    );
 
     unsafe {
-        // Use text "X" for close button (more reliable than SF Symbols)
-        let title = NSString::from_str("\u{2715}");
-        let _: () = msg_send![&hide_button, setTitle: &*title];
+        let tooltip = NSString::from_str(tr("overlay.insert_marker"));
+        let _: () = msg_send![&marker_button, setToolTip: &*tooltip];
+        let _: () = msg_send![&marker_button, setHidden: true];
+    }
+
+    // Create note button to the left of the marker button
+    let note_frame = NSRect::new(
+        NSPoint::new(
+            window_width - button_size * 3.0 - button_margin,
+            (header_height - button_size) / 2.0,
+        ),
+        NSSize::new(button_size, button_size),
+    );
+
+    let note_button = create_header_button(
+        mtm,
+        note_frame,
+        "\u{270E}",
+        15.0,
+        is_dark,
+        delegate,
+        objc2::sel!(handleShowNotePopover:),
+        "Add note to transcript",
+    );
+
+    unsafe {
+        let tooltip = NSString::from_str(tr("overlay.add_note"));
+        let _: () = msg_send![&note_button, setToolTip: &*tooltip];
+        let _: () = msg_send![&note_button, setHidden: true];
+    }
+
+    // Create attendees button to the left of the note button
+    let attendees_frame = NSRect::new(
+        NSPoint::new(
+            window_width - button_size * 4.0 - button_margin,
+            (header_height - button_size) / 2.0,
+        ),
+        NSSize::new(button_size, button_size),
+    );
+
+    let attendees_button = create_header_button(
+        mtm,
+        attendees_frame,
+        "@",
+        14.0,
+        is_dark,
+        delegate,
+        objc2::sel!(handleShowAttendeesPopover:),
+        tr("overlay.attendees_field"),
+    );
+
+    unsafe {
+        let tooltip = NSString::from_str(tr("overlay.attendees"));
+        let _: () = msg_send![&attendees_button, setToolTip: &*tooltip];
+        let _: () = msg_send![&attendees_button, setHidden: true];
+    }
+
+    // Create session info button to the left of the attendees button
+    let info_frame = NSRect::new(
+        NSPoint::new(
+            window_width - button_size * 5.0 - button_margin,
+            (header_height - button_size) / 2.0,
+        ),
+        NSSize::new(button_size, button_size),
+    );
+
+    let info_button = create_header_button(
+        mtm,
+        info_frame,
+        "\u{24D8}",
+        14.0,
+        is_dark,
+        delegate,
+        objc2::sel!(handleShowSessionInfo:),
+        tr("overlay.session_info"),
+    );
+
+    unsafe {
+        let tooltip = NSString::from_str(tr("overlay.session_info"));
+        let _: () = msg_send![&info_button, setToolTip: &*tooltip];
+        let _: () = msg_send![&info_button, setHidden: true];
+    }
+
+    // Create stop buttons to the left of the session info button
+    let stop_buttons = create_stop_buttons(
+        mtm,
+        window_width - button_size * 5.0 - button_margin,
+        header_height,
+        is_dark,
+        delegate,
+    );
+
+    // Add views to header
+    unsafe {
+        header_view.addSubview(&recording_type_label);
+        for button in &stop_buttons {
+            header_view.addSubview(button);
+        }
+        header_view.addSubview(&info_button);
+        header_view.addSubview(&attendees_button);
+        header_view.addSubview(&note_button);
+        header_view.addSubview(&marker_button);
+        header_view.addSubview(&hide_button);
+    }
+
+    HeaderViews {
+        header_view,
+        hide_button,
+        marker_button,
+        note_button,
+        attendees_button,
+        info_button,
+        stop_buttons,
+        recording_type_label,
+    }
+}
+
+/// Create a borderless text button for the header, anchored to the right edge
+#[allow(clippy::too_many_arguments)]
+pub(super) fn create_header_button(
+    mtm: MainThreadMarker,
+    frame: NSRect,
+    title: &str,
+    font_size: CGFloat,
+    is_dark: bool,
+    delegate: &WindowActionDelegate,
+    action: Sel,
+    accessibility_label: &str,
+) -> Retained<HoverButton> {
+    let button = HoverButton::new(mtm, frame);
+
+    unsafe {
+        let title = NSString::from_str(title);
+        let _: () = msg_send![&button, setTitle: &*title];
 
         // Style as borderless
-        let _: () = msg_send![&hide_button, setBezelStyle: 0u64]; // NSBezelStyleInline
-        let _: () = msg_send![&hide_button, setBordered: false];
+        let _: () = msg_send![&button, setBezelStyle: 0u64]; // NSBezelStyleInline
+        let _: () = msg_send![&button, setBordered: false];
 
-        // Set font size for the X symbol
-        let font = NSFont::systemFontOfSize(16.0);
-        let _: () = msg_send![&hide_button, setFont: &*font];
+        let font = NSFont::systemFontOfSize(font_size);
+        let _: () = msg_send![&button, setFont: &*font];
 
         // Set initial muted gray text color based on dark mode (matches HoverButton's mouseExited color)
         let button_color = if is_dark {
@@ -111,7 +292,7 @@ pub(in crate::transcription_window) fn create_header(
         } else {
             NSColor::colorWithRed_green_blue_alpha(0.35, 0.35, 0.35, 1.0)
         };
-        let attr_title: *mut AnyObject = msg_send![&hide_button, attributedTitle];
+        let attr_title: *mut AnyObject = msg_send![&button, attributedTitle];
         if !attr_title.is_null() {
             let mutable_attr: Retained<AnyObject> = msg_send_id![attr_title, mutableCopy];
             let length: usize = msg_send![&mutable_attr, length];
@@ -119,27 +300,21 @@ pub(in crate::transcription_window) fn create_header(
                 let range = NSRange::new(0, length);
                 let color_key = NSString::from_str("NSColor");
                 let _: () = msg_send![&mutable_attr, addAttribute: &*color_key value: &*button_color range: range];
-                let _: () = msg_send![&hide_button, setAttributedTitle: &*mutable_attr];
+                let _: () = msg_send![&button, setAttributedTitle: &*mutable_attr];
             }
         }
 
         // Autoresizing: min X margin (1) to stay anchored to right edge
-        let _: () = msg_send![&hide_button, setAutoresizingMask: 1u64];
+        let _: () = msg_send![&button, setAutoresizingMask: 1u64];
 
         // Set action with delegate as target
-        let _: () = msg_send![&hide_button, setTarget: delegate];
-        let _: () = msg_send![&hide_button, setAction: objc2::sel!(handleHide:)];
+        let _: () = msg_send![&button, setTarget: delegate];
+        let _: () = msg_send![&button, setAction: action];
 
         // Accessibility: label for VoiceOver
-        let accessibility_label = NSString::from_str("Close transcription window");
-        let _: () = msg_send![&hide_button, setAccessibilityLabel: &*accessibility_label];
-    }
-
-    // Add views to header
-    unsafe {
-        header_view.addSubview(&recording_type_label);
-        header_view.addSubview(&hide_button);
+        let accessibility_label = NSString::from_str(accessibility_label);
+        let _: () = msg_send![&button, setAccessibilityLabel: &*accessibility_label];
     }
 
-    (header_view, hide_button, recording_type_label)
+    button
 }
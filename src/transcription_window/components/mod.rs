@@ -3,9 +3,10 @@
 //! This module re-exports component creation functions from submodules.
 
 mod header;
+mod stop_buttons;
 mod tab_control;
 mod text_view;
 
-pub(in crate::transcription_window) use header::create_header;
-pub(in crate::transcription_window) use tab_control::create_tab_control;
+pub(in crate::transcription_window) use header::{create_header, HeaderViews};
+pub(in crate::transcription_window) use tab_control::{create_tab_control, set_debug_segment};
 pub(in crate::transcription_window) use text_view::create_scrollable_text_view;
@@ -0,0 +1,86 @@
+//! Stop buttons in the header, one per stop mode
+//!
+//! While recording, the header offers the same stops as the menu bar and the
+//! hotkeys: no polishing, basic polishing and meeting notes. Each button's
+//! tag is the segment index of the tab it stops with.
+
+use objc2::msg_send;
+use objc2::rc::Retained;
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
+
+use super::header::create_header_button;
+use crate::localization::tr;
+use crate::transcription_window::delegates::{HoverButton, WindowActionDelegate};
+use crate::transcription_window::state::TabType;
+
+/// Size of each stop button
+const BUTTON_WIDTH: CGFloat = 76.0;
+const BUTTON_HEIGHT: CGFloat = 24.0;
+
+/// Space between the stop buttons
+const BUTTON_SPACING: CGFloat = 2.0;
+
+/// Stop modes offered in the header, with their title and tooltip keys
+const STOPS: [(TabType, &str, &str); 3] = [
+    (
+        TabType::Live,
+        "overlay.stop_no_polish",
+        "overlay.stop_no_polish_tooltip",
+    ),
+    (
+        TabType::BasicPolish,
+        "overlay.stop_polish",
+        "overlay.stop_polish_tooltip",
+    ),
+    (
+        TabType::MeetingNotes,
+        "overlay.stop_meeting_notes",
+        "overlay.stop_meeting_notes_tooltip",
+    ),
+];
+
+/// Create the stop buttons, ending at `right_edge` of the header
+///
+/// The buttons start hidden and are only shown while recording.
+pub(in crate::transcription_window) fn create_stop_buttons(
+    mtm: MainThreadMarker,
+    right_edge: CGFloat,
+    header_height: CGFloat,
+    is_dark: bool,
+    delegate: &WindowActionDelegate,
+) -> Vec<Retained<HoverButton>> {
+    let first_x = right_edge - (BUTTON_WIDTH + BUTTON_SPACING) * STOPS.len() as CGFloat;
+
+    STOPS
+        .iter()
+        .enumerate()
+        .map(|(index, &(tab, title, tooltip))| {
+            let frame = NSRect::new(
+                NSPoint::new(
+                    first_x + (BUTTON_WIDTH + BUTTON_SPACING) * index as CGFloat,
+                    (header_height - BUTTON_HEIGHT) / 2.0,
+                ),
+                NSSize::new(BUTTON_WIDTH, BUTTON_HEIGHT),
+            );
+            let button = create_header_button(
+                mtm,
+                frame,
+                tr(title),
+                11.0,
+                is_dark,
+                delegate,
+                objc2::sel!(handleStopWithMode:),
+                tr(tooltip),
+            );
+
+            // SAFETY: msg_send calls to a valid NSButton on the main thread
+            unsafe {
+                let _: () = msg_send![&button, setTag: tab.to_index()];
+                let tooltip = NSString::from_str(tr(tooltip));
+                let _: () = msg_send![&button, setToolTip: &*tooltip];
+                let _: () = msg_send![&button, setHidden: true];
+            }
+            button
+        })
+        .collect()
+}
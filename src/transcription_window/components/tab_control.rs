@@ -7,8 +7,11 @@ use objc2_app_kit::NSView;
 use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
 use tracing::warn;
 
+use crate::localization::tr;
 use crate::transcription_window::delegates::WindowActionDelegate;
 use crate::transcription_window::objc_utils;
+use crate::transcription_window::state::{debug_segment, TabType};
+use crate::{output_modes, preferences};
 
 /// Create the segmented control for tab switching
 ///
@@ -49,21 +52,22 @@ pub(in crate::transcription_window) fn create_tab_control(
     };
 
     unsafe {
-        // Set segment count
-        let _: () = msg_send![&segmented_control, setSegmentCount: 3isize];
-
-        // Set segment labels
-        let live_label = NSString::from_str("Live");
-        let polished_label = NSString::from_str("Polished");
-        let meeting_label = NSString::from_str("Meeting Notes");
-        let _: () = msg_send![&segmented_control, setLabel: &*live_label forSegment: 0isize];
-        let _: () = msg_send![&segmented_control, setLabel: &*polished_label forSegment: 1isize];
-        let _: () = msg_send![&segmented_control, setLabel: &*meeting_label forSegment: 2isize];
-
-        // Set segment widths (0.0 = auto-size based on content)
-        let _: () = msg_send![&segmented_control, setWidth: 0.0f64 forSegment: 0isize];
-        let _: () = msg_send![&segmented_control, setWidth: 0.0f64 forSegment: 1isize];
-        let _: () = msg_send![&segmented_control, setWidth: 0.0f64 forSegment: 2isize];
+        // One segment per tab: Live, then each output mode
+        let tabs: Vec<TabType> = std::iter::once(TabType::Live)
+            .chain(output_modes::polish_tabs())
+            .collect();
+        let _: () = msg_send![&segmented_control, setSegmentCount: tabs.len() as isize];
+
+        for tab in tabs {
+            let label = NSString::from_str(&output_modes::tab_label(tab));
+            let index = tab.to_index();
+            let _: () = msg_send![&segmented_control, setLabel: &*label forSegment: index];
+            // Width 0.0 = auto-size based on content
+            let _: () = msg_send![&segmented_control, setWidth: 0.0f64 forSegment: index];
+        }
+
+        // The Debug tab, when turned on in Settings, comes last
+        set_debug_segment(&segmented_control, preferences::get_debug_tab());
 
         // Style as capsule/rounded (NSSegmentStyleCapsule = 5)
         let _: () = msg_send![&segmented_control, setSegmentStyle: 5isize];
@@ -79,9 +83,24 @@ pub(in crate::transcription_window) fn create_tab_control(
         let _: () = msg_send![&segmented_control, setAutoresizingMask: 10u64];
 
         // Accessibility: label for VoiceOver
-        let accessibility_label = NSString::from_str("Transcription view selector");
+        let accessibility_label = NSString::from_str(tr("overlay.view_selector"));
         let _: () = msg_send![&segmented_control, setAccessibilityLabel: &*accessibility_label];
     }
 
     Some(segmented_control)
 }
+
+/// Add the Debug tab's segment after the other tabs, or remove it
+pub(in crate::transcription_window) fn set_debug_segment(segmented_control: &NSView, shown: bool) {
+    let index = debug_segment();
+    // SAFETY: msg_send to a valid NSSegmentedControl stored as NSView, with
+    // the debug segment index within the new segment count
+    unsafe {
+        let _: () = msg_send![segmented_control, setSegmentCount: index + shown as isize];
+        if shown {
+            let label = NSString::from_str(tr("overlay.tab_debug"));
+            let _: () = msg_send![segmented_control, setLabel: &*label forSegment: index];
+            let _: () = msg_send![segmented_control, setWidth: 0.0f64 forSegment: index];
+        }
+    }
+}
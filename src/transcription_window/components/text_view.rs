@@ -13,6 +13,7 @@ use std::sync::atomic::Ordering;
 use crate::transcription_window::state::IS_DARK_MODE;
 
 /// Create a scrollable text view for displaying transcription text
+#[allow(clippy::too_many_arguments)]
 pub(in crate::transcription_window) fn create_scrollable_text_view(
     mtm: MainThreadMarker,
     width: CGFloat,
@@ -20,6 +21,7 @@ pub(in crate::transcription_window) fn create_scrollable_text_view(
     footer_height: CGFloat,
     padding: CGFloat,
     initial_text: &str,
+    accessibility_label: &str,
     visible: bool,
 ) -> (Retained<NSScrollView>, Retained<NSTextView>) {
     // Position scroll view between tab control and footer
@@ -63,6 +65,14 @@ pub(in crate::transcription_window) fn create_scrollable_text_view(
         text_view.setEditable(false);
         text_view.setSelectable(true);
 
+        // Accessibility: name the text area after its tab for VoiceOver
+        let accessibility_label = NSString::from_str(accessibility_label);
+        let _: () = msg_send![&text_view, setAccessibilityLabel: &*accessibility_label];
+
+        // Native find bar (Cmd+F) with all matches highlighted as you type
+        let _: () = msg_send![&text_view, setUsesFindBar: true];
+        let _: () = msg_send![&text_view, setIncrementalSearchingEnabled: true];
+
         // Transparent background
         text_view.setDrawsBackground(false);
 
@@ -1,4 +1,5 @@
-//! UI control creation functions for recording indicator and saved file button
+//! UI control creation functions for recording indicator, saved file button,
+//! data usage label, input warning and settings notice
 
 use objc2::rc::Retained;
 use objc2::runtime::AnyObject;
@@ -11,6 +12,7 @@ use tracing::warn;
 use super::delegates::{HoverButton, WindowActionDelegate};
 use super::objc_utils;
 use super::state::IS_DARK_MODE;
+use crate::localization::tr;
 
 /// Create the recording indicator (SF Symbol + "Recording" text) at center bottom
 pub(super) fn create_recording_indicator(
@@ -33,7 +35,7 @@ pub(super) fn create_recording_indicator(
     unsafe {
         // Create SF Symbol image for recording indicator
         let symbol_name = NSString::from_str("record.circle.fill");
-        let accessibility_desc = NSString::from_str("Recording");
+        let accessibility_desc = NSString::from_str(tr("overlay.recording"));
 
         // Use NSImage imageWithSystemSymbolName:accessibilityDescription:
         let image: Option<Retained<NSImage>> = msg_send_id![
@@ -83,7 +85,7 @@ pub(super) fn create_recording_indicator(
         let _: () = msg_send![&icon_view, setAutoresizingMask: 37u64];
 
         // Accessibility: label for VoiceOver
-        let accessibility_label = NSString::from_str("Recording in progress");
+        let accessibility_label = NSString::from_str(tr("overlay.recording_in_progress"));
         let _: () = msg_send![&icon_view, setAccessibilityLabel: &*accessibility_label];
     }
 
@@ -104,7 +106,7 @@ pub(super) fn create_recording_indicator(
         label.setSelectable(false);
         label.setBordered(false);
         label.setDrawsBackground(false);
-        label.setStringValue(&NSString::from_str("Recording"));
+        label.setStringValue(&NSString::from_str(tr("overlay.recording")));
 
         // Red text to match icon
         let red_color = NSColor::colorWithRed_green_blue_alpha(0.9, 0.3, 0.3, 1.0);
@@ -140,6 +142,131 @@ pub(super) fn create_recording_indicator(
     (icon_as_view, label)
 }
 
+/// Create the data usage label at bottom right, empty until a recording reports usage
+pub(super) fn create_usage_label(
+    mtm: MainThreadMarker,
+    window_width: CGFloat,
+    padding: CGFloat,
+) -> Retained<NSTextField> {
+    let label_width: CGFloat = 140.0;
+    let label_frame = NSRect::new(
+        NSPoint::new(window_width - padding - label_width, 14.0),
+        NSSize::new(label_width, 16.0),
+    );
+
+    let label: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: label_frame] };
+
+    unsafe {
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(false);
+
+        // Muted like the other footer controls
+        let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
+        let muted_color = if is_dark {
+            NSColor::colorWithRed_green_blue_alpha(0.55, 0.55, 0.55, 1.0)
+        } else {
+            NSColor::colorWithRed_green_blue_alpha(0.35, 0.35, 0.35, 1.0)
+        };
+        label.setTextColor(Some(&muted_color));
+
+        // Digits keep their width as the figures change
+        let font: Retained<NSFont> = msg_send_id![
+            NSFont::class(),
+            monospacedDigitSystemFontOfSize: 11.0,
+            weight: 0.0
+        ];
+        label.setFont(Some(&font));
+        label.setAlignment(NSTextAlignment::Right);
+
+        // Autoresizing: min X margin (1) | max Y margin (32) keeps it bottom right
+        let _: () = msg_send![&label, setAutoresizingMask: 33u64];
+
+        let tooltip = NSString::from_str(tr("overlay.data_usage"));
+        let _: () = msg_send![&label, setToolTip: &*tooltip];
+    }
+
+    label
+}
+
+/// Create the input warning banner (hidden) just below the tab control.
+/// Shown while the microphone is muted or its volume is at zero.
+pub(super) fn create_input_warning_label(
+    mtm: MainThreadMarker,
+    window_width: CGFloat,
+    window_height: CGFloat,
+    top_offset: CGFloat,
+    padding: CGFloat,
+) -> Retained<NSTextField> {
+    // Same red as the recording indicator
+    let red_color = unsafe { NSColor::colorWithRed_green_blue_alpha(0.9, 0.2, 0.2, 1.0) };
+    create_banner_label(
+        mtm,
+        window_width,
+        window_height - top_offset,
+        padding,
+        &red_color,
+    )
+}
+
+/// Create the settings notice banner (hidden) below the input warning.
+/// Shown for a few seconds when a setting changed during a recording.
+pub(super) fn create_notice_label(
+    mtm: MainThreadMarker,
+    window_width: CGFloat,
+    window_height: CGFloat,
+    top_offset: CGFloat,
+    padding: CGFloat,
+) -> Retained<NSTextField> {
+    let gray_color = unsafe { NSColor::colorWithRed_green_blue_alpha(0.35, 0.35, 0.4, 0.95) };
+    create_banner_label(
+        mtm,
+        window_width,
+        window_height - top_offset - BANNER_HEIGHT - 4.0,
+        padding,
+        &gray_color,
+    )
+}
+
+/// Height of the banners below the tab control
+const BANNER_HEIGHT: CGFloat = 22.0;
+
+/// Create a hidden full-width banner whose top edge is 8pt below `top`
+fn create_banner_label(
+    mtm: MainThreadMarker,
+    window_width: CGFloat,
+    top: CGFloat,
+    padding: CGFloat,
+    color: &NSColor,
+) -> Retained<NSTextField> {
+    let label_frame = NSRect::new(
+        NSPoint::new(padding, top - BANNER_HEIGHT - 8.0),
+        NSSize::new(window_width - padding * 2.0, BANNER_HEIGHT),
+    );
+
+    let label: Retained<NSTextField> =
+        unsafe { msg_send_id![mtm.alloc::<NSTextField>(), initWithFrame: label_frame] };
+
+    unsafe {
+        label.setEditable(false);
+        label.setSelectable(false);
+        label.setBordered(false);
+        label.setDrawsBackground(true);
+        label.setBackgroundColor(Some(color));
+        label.setTextColor(Some(&NSColor::whiteColor()));
+        label.setFont(Some(&NSFont::boldSystemFontOfSize(12.0)));
+        label.setAlignment(NSTextAlignment::Center);
+
+        // Autoresizing: width sizable (2) | min Y margin (8) keeps it under the tabs
+        let _: () = msg_send![&label, setAutoresizingMask: 10u64];
+        let _: () = msg_send![&label, setHidden: true];
+    }
+
+    label
+}
+
 /// Create the "Save" button at center bottom with SF Symbol icon
 /// This button is shown after recording stops, allowing users to save the transcript
 pub(super) fn create_save_button(
@@ -161,7 +288,7 @@ pub(super) fn create_save_button(
     unsafe {
         // Create SF Symbol for save action (square.and.arrow.down)
         let symbol_name = NSString::from_str("square.and.arrow.down");
-        let accessibility_desc = NSString::from_str("Save transcript");
+        let accessibility_desc = NSString::from_str(tr("overlay.save_transcript"));
 
         let image: Option<Retained<NSImage>> = msg_send_id![
             NSImage::class(),
@@ -176,7 +303,7 @@ pub(super) fn create_save_button(
         }
 
         // Set button title
-        let title = NSString::from_str("Save");
+        let title = NSString::from_str(tr("overlay.save"));
         let _: () = msg_send![&button, setTitle: &*title];
 
         // Style as borderless/plain
@@ -220,7 +347,7 @@ pub(super) fn create_save_button(
         let _: () = msg_send![&button, setAction: sel!(handleSaveFile:)];
 
         // Accessibility: label for VoiceOver
-        let accessibility_label = NSString::from_str("Save transcript to file");
+        let accessibility_label = NSString::from_str(tr("overlay.save_transcript"));
         let _: () = msg_send![&button, setAccessibilityLabel: &*accessibility_label];
     }
 
@@ -1,12 +1,15 @@
 //! Objective-C delegate classes for window event handling
 
 use objc2::rc::Retained;
-use objc2::runtime::AnyObject;
+use objc2::runtime::{AnyObject, Bool};
 use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType, DeclaredClass};
-use objc2_app_kit::{NSButton, NSColor, NSView};
+use objc2_app_kit::{NSButton, NSColor, NSView, NSWindow};
 use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSRange, NSRect, NSString};
 
-use crate::transcription_window::TranscriptionWindow;
+use crate::transcription_window::api::FindAction;
+use crate::transcription_window::thumbnails;
+use crate::transcription_window::topic_links;
+use crate::transcription_window::{TabType, TranscriptionWindow};
 
 // Delegate class for handling button actions
 declare_class!(
@@ -26,6 +29,53 @@ declare_class!(
             TranscriptionWindow::handle_hide_action();
         }
 
+        #[method(handleInsertMarker:)]
+        fn handle_insert_marker(&self, _sender: *mut NSObject) {
+            TranscriptionWindow::handle_insert_marker_action();
+        }
+
+        #[method(handleStopWithMode:)]
+        fn handle_stop_with_mode(&self, sender: *mut NSObject) {
+            // The button's tag is the segment index of the tab to stop with
+            let tag: isize = unsafe { msg_send![sender, tag] };
+            TranscriptionWindow::handle_stop_action(TabType::from_index(tag));
+        }
+
+        #[method(handleShowNotePopover:)]
+        fn handle_show_note_popover(&self, _sender: *mut NSObject) {
+            TranscriptionWindow::show_note_popover();
+        }
+
+        #[method(handleSubmitNote:)]
+        fn handle_submit_note(&self, sender: *mut NSObject) {
+            // Read the note from the popover's text field
+            let text = unsafe {
+                let value: *mut NSString = msg_send![sender, stringValue];
+                value.as_ref().map(|s| s.to_string()).unwrap_or_default()
+            };
+            TranscriptionWindow::handle_submit_note(text);
+        }
+
+        #[method(handleShowAttendeesPopover:)]
+        fn handle_show_attendees_popover(&self, _sender: *mut NSObject) {
+            TranscriptionWindow::show_attendees_popover();
+        }
+
+        #[method(handleShowSessionInfo:)]
+        fn handle_show_session_info(&self, _sender: *mut NSObject) {
+            TranscriptionWindow::show_session_info_popover();
+        }
+
+        #[method(handleSubmitAttendees:)]
+        fn handle_submit_attendees(&self, sender: *mut NSObject) {
+            // Read the names from the popover's text field
+            let text = unsafe {
+                let value: *mut NSString = msg_send![sender, stringValue];
+                value.as_ref().map(|s| s.to_string()).unwrap_or_default()
+            };
+            TranscriptionWindow::handle_submit_attendees(text);
+        }
+
         #[method(handleLessTransparent:)]
         fn handle_less_transparent(&self, _sender: *mut NSObject) {
             TranscriptionWindow::adjust_transparency(-0.1);
@@ -41,12 +91,58 @@ declare_class!(
             TranscriptionWindow::handle_save_file_action();
         }
 
+        #[method(handleToggleGallery:)]
+        fn handle_toggle_gallery(&self, _sender: *mut NSObject) {
+            TranscriptionWindow::handle_toggle_gallery_action();
+        }
+
         #[method(handleTabChange:)]
         fn handle_tab_change(&self, sender: *mut NSObject) {
             // Get selected segment index from the segmented control
             let selected_index: isize = unsafe { msg_send![sender, selectedSegment] };
             TranscriptionWindow::handle_tab_change_action(selected_index);
         }
+
+        // NSTextView delegate methods for the editable live transcript
+        #[method(textDidChange:)]
+        fn text_did_change(&self, _notification: *mut NSObject) {
+            TranscriptionWindow::handle_live_text_changed();
+        }
+
+        #[method(textDidEndEditing:)]
+        fn text_did_end_editing(&self, _notification: *mut NSObject) {
+            TranscriptionWindow::handle_live_text_end_editing();
+        }
+
+        // NSWindowDelegate methods for the overlay window
+        #[method(windowShouldClose:)]
+        fn window_should_close(&self, _sender: *mut NSObject) -> Bool {
+            Bool::new(TranscriptionWindow::handle_window_should_close())
+        }
+
+        #[method(windowWillClose:)]
+        fn window_will_close(&self, _notification: *mut NSObject) {
+            TranscriptionWindow::handle_window_will_close();
+        }
+
+        // Encrypted screenshots can't be opened directly by the default viewer,
+        // and topic contents entries scroll within the text view
+        #[method(textView:clickedOnLink:atIndex:)]
+        fn text_view_clicked_on_link(
+            &self,
+            text_view: *mut NSObject,
+            link: *mut AnyObject,
+            _char_index: usize,
+        ) -> Bool {
+            // SAFETY: AppKit passes the valid text view and link object (NSURL or NSString)
+            let (text_view, link) = unsafe { (text_view.as_ref(), link.as_ref()) };
+            let handled = text_view.zip(link).is_some_and(|(text_view, link)| {
+                thumbnails::open_encrypted_link(link)
+                    // SAFETY: text_view is the text view the link was clicked in
+                    || unsafe { topic_links::scroll_to_topic(text_view, link) }
+            });
+            Bool::new(handled)
+        }
     }
 
     unsafe impl NSObjectProtocol for WindowActionDelegate {}
@@ -59,6 +155,89 @@ impl WindowActionDelegate {
     }
 }
 
+// Borderless overlay window that can become key (for the find bar) and handles
+// the find and print keyboard shortcuts, since the app has no Edit or File
+// menu to route them
+declare_class!(
+    pub struct OverlayWindow;
+
+    unsafe impl ClassType for OverlayWindow {
+        type Super = NSWindow;
+        type Mutability = mutability::MainThreadOnly;
+        const NAME: &'static str = "VissperOverlayWindow";
+    }
+
+    impl DeclaredClass for OverlayWindow {}
+
+    unsafe impl OverlayWindow {
+        #[method(canBecomeKeyWindow)]
+        fn can_become_key_window(&self) -> bool {
+            true
+        }
+
+        #[method(performKeyEquivalent:)]
+        fn perform_key_equivalent(&self, event: *mut AnyObject) -> Bool {
+            if let Some(action) = unsafe { find_action_for_event(event) } {
+                TranscriptionWindow::handle_find_action(action);
+                return Bool::YES;
+            }
+            if unsafe { is_print_shortcut(event) } {
+                TranscriptionWindow::handle_print_action();
+                return Bool::YES;
+            }
+            unsafe { msg_send![super(self), performKeyEquivalent: event] }
+        }
+    }
+
+    unsafe impl NSObjectProtocol for OverlayWindow {}
+);
+
+/// Command key modifier flag (NSEventModifierFlagCommand)
+const COMMAND_KEY_MASK: usize = 1 << 20;
+
+/// Shift key modifier flag (NSEventModifierFlagShift)
+const SHIFT_KEY_MASK: usize = 1 << 17;
+
+/// Map Cmd+F, Cmd+G and Cmd+Shift+G to find actions.
+///
+/// # Safety
+/// `event` must be null or a valid NSEvent of a key type.
+unsafe fn find_action_for_event(event: *mut AnyObject) -> Option<FindAction> {
+    if event.is_null() {
+        return None;
+    }
+    let flags: usize = msg_send![event, modifierFlags];
+    if flags & COMMAND_KEY_MASK == 0 {
+        return None;
+    }
+    let characters: Option<Retained<NSString>> = msg_send_id![event, charactersIgnoringModifiers];
+    let characters = characters?.to_string().to_lowercase();
+    let shift = flags & SHIFT_KEY_MASK != 0;
+
+    match characters.as_str() {
+        "f" if !shift => Some(FindAction::ShowFindInterface),
+        "g" if shift => Some(FindAction::PreviousMatch),
+        "g" => Some(FindAction::NextMatch),
+        _ => None,
+    }
+}
+
+/// Whether `event` is Cmd+P.
+///
+/// # Safety
+/// `event` must be null or a valid NSEvent of a key type.
+unsafe fn is_print_shortcut(event: *mut AnyObject) -> bool {
+    if event.is_null() {
+        return false;
+    }
+    let flags: usize = msg_send![event, modifierFlags];
+    if flags & COMMAND_KEY_MASK == 0 || flags & SHIFT_KEY_MASK != 0 {
+        return false;
+    }
+    let characters: Option<Retained<NSString>> = msg_send_id![event, charactersIgnoringModifiers];
+    characters.is_some_and(|characters| characters.to_string().eq_ignore_ascii_case("p"))
+}
+
 // Custom content view class for tracking mouse enter/exit
 declare_class!(
     pub struct TrackingContentView;
@@ -0,0 +1,299 @@
+//! Screenshot gallery strip
+//!
+//! A collapsible row of thumbnails above the footer of the transcription
+//! window, showing every screenshot taken since the current recording
+//! started. Clicking a thumbnail opens the screenshot in the default viewer
+//! and dragging it drops the file into Finder or another app. The footer
+//! button with the number of screenshots shows and hides the strip.
+
+mod thumbnail;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, sel, ClassType};
+use objc2_app_kit::{NSColor, NSFont, NSImage, NSScrollView, NSView};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRange, NSRect, NSSize, NSString};
+use std::cell::RefCell;
+use std::sync::atomic::Ordering;
+use tracing::error;
+
+use super::delegates::{HoverButton, WindowActionDelegate};
+use super::state::{transcription_window, TranscriptionWindowInner, IS_DARK_MODE};
+use super::{objc_utils, thumbnails};
+use crate::localization::tr;
+use thumbnail::GalleryThumbnail;
+
+/// Height of the strip, including room for the scroller
+const STRIP_HEIGHT: CGFloat = 72.0;
+
+/// Space between the strip and the text above it
+const STRIP_SPACING: CGFloat = 6.0;
+
+/// Thumbnail height in points
+const THUMBNAIL_HEIGHT: CGFloat = 56.0;
+
+/// Maximum thumbnail width in points
+const THUMBNAIL_MAX_WIDTH: CGFloat = 100.0;
+
+/// Space between thumbnails
+const THUMBNAIL_SPACING: CGFloat = 8.0;
+
+thread_local! {
+    /// Screenshot references taken this session, oldest first (main thread only)
+    static SCREENSHOTS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Views of the gallery strip and its footer button
+pub(super) struct Gallery {
+    toggle_button: Retained<HoverButton>,
+    scroll_view: Retained<NSScrollView>,
+    document_view: Retained<NSView>,
+    /// Whether the user wants the strip shown when there are screenshots
+    expanded: bool,
+    /// Whether the strip is on screen, with the text views moved up for it
+    shown: bool,
+}
+
+/// Create the gallery strip and its toggle button, both hidden until a screenshot is taken
+pub(super) fn create_gallery(
+    mtm: MainThreadMarker,
+    window_width: CGFloat,
+    footer_height: CGFloat,
+    padding: CGFloat,
+    delegate: &WindowActionDelegate,
+) -> Gallery {
+    let strip_frame = NSRect::new(
+        NSPoint::new(padding, footer_height),
+        NSSize::new(window_width - padding * 2.0, STRIP_HEIGHT),
+    );
+    let scroll_view: Retained<NSScrollView> =
+        unsafe { msg_send_id![mtm.alloc::<NSScrollView>(), initWithFrame: strip_frame] };
+    let document_frame = NSRect::new(NSPoint::new(0.0, 0.0), strip_frame.size);
+    let document_view: Retained<NSView> =
+        unsafe { msg_send_id![mtm.alloc::<NSView>(), initWithFrame: document_frame] };
+
+    unsafe {
+        scroll_view.setHasVerticalScroller(false);
+        scroll_view.setHasHorizontalScroller(true);
+        let _: () = msg_send![&scroll_view, setAutohidesScrollers: true];
+        scroll_view.setDrawsBackground(false);
+        let _: () = msg_send![&scroll_view, setBorderType: 0u64]; // NSNoBorder
+        scroll_view.setDocumentView(Some(&document_view));
+
+        // Autoresizing: width sizable (2) | max Y margin (32) keeps it above the footer
+        let _: () = msg_send![&scroll_view, setAutoresizingMask: 34u64];
+        let _: () = msg_send![&scroll_view, setHidden: true];
+
+        let label = NSString::from_str(tr("overlay.screenshots"));
+        let _: () = msg_send![&scroll_view, setAccessibilityLabel: &*label];
+    }
+
+    let toggle_button = create_toggle_button(mtm, padding, delegate);
+
+    Gallery {
+        toggle_button,
+        scroll_view,
+        document_view,
+        expanded: true,
+        shown: false,
+    }
+}
+
+/// Footer button (bottom left) showing the screenshot count
+fn create_toggle_button(
+    mtm: MainThreadMarker,
+    padding: CGFloat,
+    delegate: &WindowActionDelegate,
+) -> Retained<HoverButton> {
+    let frame = NSRect::new(NSPoint::new(padding, 13.0), NSSize::new(60.0, 24.0));
+    let button = HoverButton::new(mtm, frame);
+
+    unsafe {
+        let symbol_name = NSString::from_str("photo.on.rectangle");
+        let accessibility_desc = NSString::from_str(tr("overlay.screenshots"));
+        let image: Option<Retained<NSImage>> = msg_send_id![
+            NSImage::class(),
+            imageWithSystemSymbolName: &*symbol_name,
+            accessibilityDescription: &*accessibility_desc
+        ];
+        if let Some(image) = image {
+            let _: () = msg_send![&button, setImage: &*image];
+            let _: () = msg_send![&button, setImagePosition: 2usize]; // NSImageLeft
+        }
+
+        let _: () = msg_send![&button, setBezelStyle: 0u64]; // NSBezelStyleInline
+        let _: () = msg_send![&button, setBordered: false];
+        let font = NSFont::systemFontOfSize(12.0);
+        let _: () = msg_send![&button, setFont: &*font];
+        let _: () = msg_send![&button, setHidden: true];
+
+        // Autoresizing: max X margin (4) | max Y margin (32) keeps it bottom left
+        let _: () = msg_send![&button, setAutoresizingMask: 36u64];
+
+        let _: () = msg_send![&button, setTarget: delegate];
+        let _: () = msg_send![&button, setAction: sel!(handleToggleGallery:)];
+
+        let tooltip = NSString::from_str(tr("overlay.toggle_screenshots"));
+        let _: () = msg_send![&button, setToolTip: &*tooltip];
+        let _: () = msg_send![&button, setAccessibilityLabel: &*tooltip];
+    }
+
+    button
+}
+
+impl Gallery {
+    /// Add the strip and its button to the window's content view
+    pub(super) fn add_to(&self, content_view: &NSView) {
+        unsafe {
+            content_view.addSubview(&self.scroll_view);
+            content_view.addSubview(&self.toggle_button);
+        }
+    }
+}
+
+/// Add a screenshot taken this session to the strip (main thread only)
+pub(super) fn add_screenshot(reference: String) {
+    SCREENSHOTS.with(|screenshots| screenshots.borrow_mut().push(reference));
+    reload_window();
+}
+
+/// Forget this session's screenshots, when a new recording starts (main thread only)
+pub(super) fn clear() {
+    SCREENSHOTS.with(|screenshots| screenshots.borrow_mut().clear());
+    reload_window();
+}
+
+/// Show or hide the strip (called from the toggle button)
+pub(super) fn toggle() {
+    let Some(inner) = transcription_window() else {
+        return;
+    };
+    let Ok(mut inner) = inner.try_borrow_mut() else {
+        error!("Transcription window already borrowed in gallery toggle");
+        return;
+    };
+    inner.gallery.expanded = !inner.gallery.expanded;
+    update_visibility(&mut inner);
+}
+
+fn reload_window() {
+    let Some(inner) = transcription_window() else {
+        return;
+    };
+    let Ok(mut inner) = inner.try_borrow_mut() else {
+        error!("Transcription window already borrowed in gallery reload");
+        return;
+    };
+    reload(&mut inner);
+}
+
+/// Rebuild the thumbnails from this session's screenshots
+pub(super) fn reload(inner: &mut TranscriptionWindowInner) {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let gallery = &inner.gallery;
+
+    // SAFETY: +array returns an empty NSArray, which replaces the old thumbnails
+    if let Some(array_class) = objc_utils::get_class_or_warn("NSArray") {
+        unsafe {
+            let empty: *mut AnyObject = msg_send![array_class, array];
+            let _: () = msg_send![&gallery.document_view, setSubviews: empty];
+        }
+    }
+
+    let mut x = 0.0;
+    let count = SCREENSHOTS.with(|screenshots| {
+        let screenshots = screenshots.borrow();
+        for reference in screenshots.iter() {
+            let Some((path, image)) = thumbnails::load_image(mtm, reference) else {
+                continue;
+            };
+            let size: NSSize = unsafe { msg_send![&image, size] };
+            if size.width <= 0.0 || size.height <= 0.0 {
+                continue;
+            }
+            let width = (size.width * THUMBNAIL_HEIGHT / size.height).min(THUMBNAIL_MAX_WIDTH);
+            let frame = NSRect::new(
+                NSPoint::new(x, STRIP_HEIGHT - THUMBNAIL_HEIGHT - 2.0),
+                NSSize::new(width, THUMBNAIL_HEIGHT),
+            );
+            let thumbnail = GalleryThumbnail::new(mtm, frame, path, &image);
+            unsafe { gallery.document_view.addSubview(&thumbnail) };
+            x += width + THUMBNAIL_SPACING;
+        }
+        screenshots.len()
+    });
+
+    unsafe {
+        let visible_size: NSSize = msg_send![&gallery.scroll_view, contentSize];
+        let visible_width = visible_size.width;
+        let document_size = NSSize::new(x.max(visible_width), STRIP_HEIGHT);
+        let _: () = msg_send![&gallery.document_view, setFrameSize: document_size];
+
+        // Scroll to the newest screenshot
+        let newest = NSPoint::new((document_size.width - visible_width).max(0.0), 0.0);
+        let _: () = msg_send![&gallery.document_view, scrollPoint: newest];
+    }
+
+    set_count(&gallery.toggle_button, count);
+    update_visibility(inner);
+}
+
+/// Show the strip when expanded and there are screenshots, moving the text views up for it
+fn update_visibility(inner: &mut TranscriptionWindowInner) {
+    let has_screenshots = SCREENSHOTS.with(|screenshots| !screenshots.borrow().is_empty());
+    let shown = inner.gallery.expanded && has_screenshots;
+
+    unsafe {
+        let _: () = msg_send![&inner.gallery.toggle_button, setHidden: !has_screenshots];
+    }
+    if shown == inner.gallery.shown {
+        return;
+    }
+
+    let offset = if shown {
+        STRIP_HEIGHT + STRIP_SPACING
+    } else {
+        -(STRIP_HEIGHT + STRIP_SPACING)
+    };
+    let tab_views = inner.scroll_views().map(|(_, scroll_view)| scroll_view);
+    for scroll_view in tab_views.chain([&inner.debug_scroll_view]) {
+        let mut frame = scroll_view.frame();
+        frame.origin.y += offset;
+        frame.size.height -= offset;
+        unsafe { scroll_view.setFrame(frame) };
+    }
+    unsafe {
+        let _: () = msg_send![&inner.gallery.scroll_view, setHidden: !shown];
+    }
+    inner.gallery.shown = shown;
+}
+
+/// Show the number of screenshots on the toggle button, in the footer's muted color
+fn set_count(button: &HoverButton, count: usize) {
+    let is_dark = IS_DARK_MODE.load(Ordering::SeqCst);
+    let muted_color = if is_dark {
+        NSColor::colorWithRed_green_blue_alpha(0.55, 0.55, 0.55, 1.0)
+    } else {
+        NSColor::colorWithRed_green_blue_alpha(0.35, 0.35, 0.35, 1.0)
+    };
+
+    unsafe {
+        let title = NSString::from_str(&count.to_string());
+        let _: () = msg_send![button, setTitle: &*title];
+        let _: () = msg_send![button, setContentTintColor: &*muted_color];
+
+        let attr_title: *mut AnyObject = msg_send![button, attributedTitle];
+        if !attr_title.is_null() {
+            let mutable_attr: Retained<AnyObject> = msg_send_id![attr_title, mutableCopy];
+            let length: usize = msg_send![&mutable_attr, length];
+            if length > 0 {
+                let range = NSRange::new(0, length);
+                let color_key = NSString::from_str("NSColor");
+                let _: () = msg_send![&mutable_attr, addAttribute: &*color_key value: &*muted_color range: range];
+                let _: () = msg_send![button, setAttributedTitle: &*mutable_attr];
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Gallery thumbnail that opens its screenshot on click and can be dragged out
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType, DeclaredClass};
+use objc2_app_kit::{NSImage, NSImageView};
+use objc2_foundation::{MainThreadMarker, NSObjectProtocol, NSRect, NSString, NSURL};
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use tracing::error;
+
+use crate::encryption;
+use crate::transcription_window::objc_utils;
+
+/// NSDragOperationCopy: dropping the thumbnail copies the file
+const DRAG_OPERATION_COPY: usize = 1;
+
+/// State of a gallery thumbnail
+pub struct ThumbnailIvars {
+    /// The screenshot file, which may be encrypted
+    path: PathBuf,
+    /// Set once the current mouse press turned into a drag
+    dragging: Cell<bool>,
+}
+
+declare_class!(
+    pub struct GalleryThumbnail;
+
+    unsafe impl ClassType for GalleryThumbnail {
+        type Super = NSImageView;
+        type Mutability = mutability::MainThreadOnly;
+        const NAME: &'static str = "VissperGalleryThumbnail";
+    }
+
+    impl DeclaredClass for GalleryThumbnail {
+        type Ivars = ThumbnailIvars;
+    }
+
+    unsafe impl GalleryThumbnail {
+        // The window is movable by its background; thumbnails are dragged instead
+        #[method(mouseDownCanMoveWindow)]
+        fn mouse_down_can_move_window(&self) -> bool {
+            false
+        }
+
+        #[method(mouseDown:)]
+        fn mouse_down(&self, _event: *mut AnyObject) {
+            self.ivars().dragging.set(false);
+        }
+
+        #[method(mouseDragged:)]
+        fn mouse_dragged(&self, event: *mut AnyObject) {
+            if !self.ivars().dragging.replace(true) {
+                // SAFETY: AppKit passes the valid drag event
+                unsafe { self.begin_drag(event) };
+            }
+        }
+
+        #[method(mouseUp:)]
+        fn mouse_up(&self, _event: *mut AnyObject) {
+            if self.ivars().dragging.get() {
+                return;
+            }
+            if let Some(path) = readable_path(&self.ivars().path) {
+                if let Err(e) = open::that(&path) {
+                    error!("Failed to open screenshot: {}", e);
+                }
+            }
+        }
+
+        // NSDraggingSource
+        #[method(draggingSession:sourceOperationMaskForDraggingContext:)]
+        fn source_operation_mask(&self, _session: *mut AnyObject, _context: isize) -> usize {
+            DRAG_OPERATION_COPY
+        }
+    }
+
+    unsafe impl NSObjectProtocol for GalleryThumbnail {}
+);
+
+impl GalleryThumbnail {
+    pub fn new(
+        mtm: MainThreadMarker,
+        frame: NSRect,
+        path: PathBuf,
+        image: &NSImage,
+    ) -> Retained<Self> {
+        let this = mtm.alloc::<Self>().set_ivars(ThumbnailIvars {
+            path,
+            dragging: Cell::new(false),
+        });
+        let thumbnail: Retained<Self> = unsafe { msg_send_id![super(this), initWithFrame: frame] };
+
+        unsafe {
+            thumbnail.setImage(Some(image));
+            // NSImageScaleProportionallyUpOrDown
+            let _: () = msg_send![&thumbnail, setImageScaling: 3usize];
+
+            if let Some(name) = thumbnail.ivars().path.file_name() {
+                let name = NSString::from_str(&name.to_string_lossy());
+                let _: () = msg_send![&thumbnail, setToolTip: &*name];
+                let _: () = msg_send![&thumbnail, setAccessibilityLabel: &*name];
+            }
+        }
+        thumbnail
+    }
+
+    /// Start dragging the screenshot file out of the window.
+    ///
+    /// # Safety
+    /// `event` must be the mouse event that started the drag.
+    unsafe fn begin_drag(&self, event: *mut AnyObject) {
+        let Some(item_class) = objc_utils::get_class_or_warn("NSDraggingItem") else {
+            return;
+        };
+        let Some(array_class) = objc_utils::get_class_or_warn("NSArray") else {
+            return;
+        };
+        let Some(path) = readable_path(&self.ivars().path) else {
+            return;
+        };
+
+        let ns_path = NSString::from_str(&path.to_string_lossy());
+        let url: Retained<NSURL> = msg_send_id![NSURL::class(), fileURLWithPath: &*ns_path];
+        let item: *mut AnyObject = msg_send![item_class, alloc];
+        let item: *mut AnyObject = msg_send![item, initWithPasteboardWriter: &*url];
+        let Some(item) = Retained::from_raw(item) else {
+            return;
+        };
+
+        // Drag the thumbnail image itself
+        let bounds: NSRect = msg_send![self, bounds];
+        let image: *mut AnyObject = msg_send![self, image];
+        let _: () = msg_send![&item, setDraggingFrame: bounds, contents: image];
+
+        let items: *mut AnyObject = msg_send![array_class, arrayWithObject: &*item];
+        let _: *mut AnyObject = msg_send![
+            self,
+            beginDraggingSessionWithItems: items,
+            event: event,
+            source: self
+        ];
+    }
+}
+
+/// A path other apps can read.
+///
+/// Encrypted screenshots are decrypted to a private temporary copy.
+fn readable_path(path: &Path) -> Option<PathBuf> {
+    if !encryption::is_encrypted_path(path) {
+        return Some(path.to_path_buf());
+    }
+    encryption::decrypt_to_temp(path)
+        .inspect_err(|e| error!("Failed to decrypt screenshot {:?}: {}", path, e))
+        .ok()
+}
@@ -6,18 +6,23 @@
 use objc2::rc::Retained;
 use objc2::{msg_send, msg_send_id, ClassType};
 use objc2_app_kit::{NSColor, NSFont};
-use objc2_foundation::{NSMutableAttributedString, NSRange, NSString};
+use objc2_foundation::{NSAttributedString, NSMutableAttributedString, NSRange, NSString};
+
+use super::thumbnails;
+use crate::keywords::{self, KeywordRule};
+use crate::topics;
 
 /// Simple markdown segment types for parsing markdown text.
 ///
 /// Used for both UI rendering (NSAttributedString) and PDF export.
 pub(crate) enum MarkdownSegment {
-    Header1(String),     // # header
-    Header2(String),     // ## header
-    Header3(String),     // ### header
-    BulletPoint(String), // - item or * item
-    Bold(String),        // **text**
-    Normal(String),      // regular text
+    Header1(String),                     // # header
+    Header2(String),                     // ## header
+    Header3(String),                     // ### header
+    BulletPoint(String),                 // - item or * item
+    Bold(String),                        // **text**
+    Normal(String),                      // regular text
+    Image { alt: String, path: String }, // ![alt](path)
 }
 
 /// Parse text into markdown segments (simple line-based parsing).
@@ -55,10 +60,45 @@ pub(crate) fn parse_markdown(text: &str) -> Vec<MarkdownSegment> {
     segments
 }
 
-/// Parse inline bold formatting within a line
+/// Parse inline image references and bold formatting within a line
 fn parse_inline_formatting(text: &str, segments: &mut Vec<MarkdownSegment>) {
     let mut remaining = text;
 
+    while let Some((start, end, alt, path)) = find_image_reference(remaining) {
+        parse_bold_formatting(&remaining[..start], segments);
+        segments.push(MarkdownSegment::Image {
+            alt: alt.to_string(),
+            path: path.to_string(),
+        });
+        remaining = &remaining[end..];
+    }
+
+    parse_bold_formatting(remaining, segments);
+}
+
+/// Find the first `![alt](path)` reference in `text`.
+///
+/// Returns the byte range of the whole reference along with its alt text and path.
+fn find_image_reference(text: &str) -> Option<(usize, usize, &str, &str)> {
+    let start = text.find("![")?;
+    let after_bang = &text[start + 2..];
+    let alt_end = after_bang.find("](")?;
+    let after_alt = &after_bang[alt_end + 2..];
+    let path_end = after_alt.find(')')?;
+
+    let path = after_alt[..path_end].trim();
+    if path.is_empty() {
+        return None;
+    }
+
+    let end = start + 2 + alt_end + 2 + path_end + 1;
+    Some((start, end, &after_bang[..alt_end], path))
+}
+
+/// Parse inline bold formatting within a piece of text
+fn parse_bold_formatting(text: &str, segments: &mut Vec<MarkdownSegment>) {
+    let mut remaining = text;
+
     while !remaining.is_empty() {
         if let Some(start) = remaining.find("**") {
             if start > 0 {
@@ -130,17 +170,46 @@ pub(super) fn create_attributed_string(
     let color_attr = NSString::from_str("NSColor");
     let font_attr = NSString::from_str("NSFont");
 
+    // Entries of a topic table of contents link to their sections
+    let mut in_contents = false;
+
     for segment in segments {
+        match &segment {
+            MarkdownSegment::Header1(_) => in_contents = false,
+            MarkdownSegment::Header2(s) => in_contents = s.trim() == topics::CONTENTS_HEADING,
+            _ => {}
+        }
+
         let (text_content, font) = match &segment {
             MarkdownSegment::Header1(s) => (s.as_str(), &*h1_font),
             MarkdownSegment::Header2(s) => (s.as_str(), &*h2_font),
             MarkdownSegment::Header3(s) => (s.as_str(), &*h3_font),
             MarkdownSegment::BulletPoint(s) => {
-                append_bullet_point(&result, s, &text_color, &regular_font);
+                let link = in_contents.then(|| format!("{}{}", topics::LINK_PREFIX, s.trim()));
+                append_bullet_point(&result, s, &text_color, &regular_font, link.as_deref());
                 continue;
             }
             MarkdownSegment::Bold(s) => (s.as_str(), &*bold_font),
             MarkdownSegment::Normal(s) => (s.as_str(), &*regular_font),
+            MarkdownSegment::Image { alt, path } => {
+                if let Some(thumbnail) = thumbnails::create_thumbnail_string(alt, path) {
+                    unsafe {
+                        let _: () = msg_send![&result, appendAttributedString: &*thumbnail];
+                    }
+                } else {
+                    // Image not available yet (or unreadable) - show the reference itself
+                    let reference = format!("![{}]({})", alt, path);
+                    append_styled_text(
+                        &result,
+                        &reference,
+                        &regular_font,
+                        &text_color,
+                        &color_attr,
+                        &font_attr,
+                    );
+                }
+                continue;
+            }
         };
 
         append_styled_text(
@@ -156,12 +225,102 @@ pub(super) fn create_attributed_string(
     result
 }
 
-/// Append a bullet point segment with proper styling
+/// Background alpha for keyword highlights, readable on dark and light backgrounds
+const KEYWORD_HIGHLIGHT_ALPHA: f64 = 0.4;
+
+/// Highlight keyword matches in an attributed string with each rule's color.
+pub(super) fn apply_keyword_highlights(
+    attr_string: &NSMutableAttributedString,
+    rules: &[KeywordRule],
+) {
+    if rules.is_empty() {
+        return;
+    }
+
+    // SAFETY: string is safe on a valid NSAttributedString
+    let text: Retained<NSString> = unsafe { msg_send_id![attr_string, string] };
+    let text = text.to_string();
+    let matches = keywords::find_matches(&text, rules);
+    if matches.is_empty() {
+        return;
+    }
+
+    let background_attr = NSString::from_str("NSBackgroundColor");
+    let mut utf16_offset = 0usize;
+    let mut byte_offset = 0usize;
+
+    for (start, end, color) in matches {
+        // Matches are sorted, so UTF-16 offsets can be computed incrementally
+        utf16_offset += utf16_len(&text[byte_offset..start]);
+        let length = utf16_len(&text[start..end]);
+        byte_offset = start;
+
+        let (r, g, b) = color.rgb();
+        // SAFETY: the range lies within the attributed string
+        unsafe {
+            let ns_color = NSColor::colorWithRed_green_blue_alpha(r, g, b, KEYWORD_HIGHLIGHT_ALPHA);
+            let _: () = msg_send![
+                attr_string,
+                addAttribute: &*background_attr,
+                value: &*ns_color,
+                range: NSRange::new(utf16_offset, length)
+            ];
+        }
+    }
+}
+
+/// Length of a string in UTF-16 code units (NSString indexing)
+pub(super) fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+/// Convert the contents of an edited text view back to markdown source.
+///
+/// Screenshot thumbnails are replaced by the references they were rendered
+/// from. Other formatting is returned as displayed, which is lossless for the
+/// live transcript since it only contains plain text and screenshot references.
+pub(super) fn attributed_string_to_markdown(attr_string: &NSAttributedString) -> String {
+    // SAFETY: string is safe on a valid NSAttributedString
+    let text: Retained<NSString> = unsafe { msg_send_id![attr_string, string] };
+    let text = text.to_string();
+    let source_attr = NSString::from_str(thumbnails::MARKDOWN_SOURCE_ATTRIBUTE);
+
+    let mut markdown = String::with_capacity(text.len());
+    let mut utf16_index = 0usize;
+    for ch in text.chars() {
+        if ch == ATTACHMENT_CHARACTER {
+            // SAFETY: utf16_index is within the string, and a null range pointer is allowed
+            let source: Option<Retained<NSString>> = unsafe {
+                msg_send_id![
+                    attr_string,
+                    attribute: &*source_attr,
+                    atIndex: utf16_index,
+                    effectiveRange: std::ptr::null_mut::<NSRange>()
+                ]
+            };
+            if let Some(source) = source {
+                markdown.push_str(&source.to_string());
+            }
+        } else {
+            markdown.push(ch);
+        }
+        utf16_index += ch.len_utf16();
+    }
+
+    markdown
+}
+
+/// Placeholder character AppKit uses for text attachments
+const ATTACHMENT_CHARACTER: char = '\u{FFFC}';
+
+/// Append a bullet point segment with proper styling, linking its text to
+/// `link` if given
 fn append_bullet_point(
     result: &NSMutableAttributedString,
     text: &str,
     text_color: &NSColor,
     font: &NSFont,
+    link: Option<&str>,
 ) {
     let bullet_text = format!("  •  {}", text);
     let ns_str = NSString::from_str(&bullet_text);
@@ -175,6 +334,16 @@ fn append_bullet_point(
         let _: () =
             msg_send![&segment_attr, addAttribute: &*color_attr, value: text_color, range: range];
         let _: () = msg_send![&segment_attr, addAttribute: &*font_attr, value: font, range: range];
+        if let Some(link) = link {
+            let link_attr = NSString::from_str("NSLink");
+            let link_range = NSRange::new(len - utf16_len(text), utf16_len(text));
+            let _: () = msg_send![
+                &segment_attr,
+                addAttribute: &*link_attr,
+                value: &*NSString::from_str(link),
+                range: link_range
+            ];
+        }
         let _: () = msg_send![result, appendAttributedString: &*segment_attr];
     }
 }
@@ -204,3 +373,34 @@ fn append_styled_text(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_screenshot_reference_line() {
+        let segments = parse_markdown("![Screenshot](screenshots/shot.png)");
+        assert!(matches!(
+            &segments[0],
+            MarkdownSegment::Image { alt, path }
+                if alt == "Screenshot" && path == "screenshots/shot.png"
+        ));
+    }
+
+    #[test]
+    fn test_parse_inline_image_between_text() {
+        let segments = parse_markdown("see **this** ![A](a.png) and more");
+        assert!(matches!(&segments[0], MarkdownSegment::Normal(s) if s == "see "));
+        assert!(matches!(&segments[1], MarkdownSegment::Bold(s) if s == "this"));
+        assert!(matches!(&segments[2], MarkdownSegment::Normal(s) if s == " "));
+        assert!(matches!(&segments[3], MarkdownSegment::Image { path, .. } if path == "a.png"));
+        assert!(matches!(&segments[4], MarkdownSegment::Normal(s) if s == " and more"));
+    }
+
+    #[test]
+    fn test_unterminated_image_reference_stays_text() {
+        let segments = parse_markdown("broken ![A](a.png");
+        assert!(matches!(&segments[0], MarkdownSegment::Normal(s) if s == "broken ![A](a.png"));
+    }
+}
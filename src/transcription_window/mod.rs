@@ -3,25 +3,34 @@
 //! This module provides a transparent, borderless overlay window for displaying
 //! real-time transcription text at the bottom center of the screen.
 
+mod accessibility;
 mod api;
 mod components;
 mod controls;
 mod delegates;
+mod gallery;
 mod markdown;
 mod objc_utils;
 mod state;
+mod thumbnails;
+mod topic_links;
 mod window;
 
 use block2::RcBlock;
 use objc2::msg_send;
 use objc2_foundation::{MainThreadMarker, NSOperationQueue};
-use std::sync::Mutex;
+use std::cell::RefCell;
 use tracing::info;
 
+use crate::app_context;
+use crate::event_bus::AppEvent;
+use crate::transcription::SessionInfo;
+
 // Re-export for crate use
-pub(crate) use state::{TabType, WindowCallbacks};
+pub(crate) use api::{PdfMargins, PdfPageSize, PdfSettings};
+pub(crate) use state::{TabType, TranscriptionWindowInner, WindowCallbacks};
 
-use state::{TRANSCRIPTION_WINDOW, WINDOW_CALLBACKS};
+use state::WINDOW_CALLBACKS;
 
 /// Transcription window manager
 pub(crate) struct TranscriptionWindow;
@@ -66,27 +75,24 @@ impl TranscriptionWindow {
 
     /// Internal: show window on main thread (requires MainThreadMarker)
     fn show_on_main_thread(mtm: MainThreadMarker) {
+        let Some(windows) = app_context::windows() else {
+            return;
+        };
+
         // Check if window already exists
-        if let Some(inner) = TRANSCRIPTION_WINDOW.get() {
-            if let Ok(inner) = inner.lock() {
+        if let Some(inner) = windows.transcription.get() {
+            if let Ok(inner) = inner.try_borrow() {
                 // Window exists, just show it
                 inner.window.makeKeyAndOrderFront(None);
-                return;
             }
+            return;
         }
 
         // Create new window with all UI elements
         let inner = window::create_window(mtm);
 
-        // Store in global state
-        if TRANSCRIPTION_WINDOW.set(Mutex::new(inner)).is_err() {
-            // Window was created by another thread, show that one instead
-            if let Some(inner) = TRANSCRIPTION_WINDOW.get() {
-                if let Ok(inner) = inner.lock() {
-                    inner.window.makeKeyAndOrderFront(None);
-                }
-            }
-        }
+        // Store in the main-thread window registry
+        windows.transcription.set(RefCell::new(inner)).ok();
     }
 
     /// Update the displayed transcription text (for active tab)
@@ -101,6 +107,11 @@ impl TranscriptionWindow {
         api::hide();
     }
 
+    /// Whether the window is currently on screen (must be called on the main thread)
+    pub(crate) fn is_visible() -> bool {
+        api::is_visible()
+    }
+
     /// Clear the transcription text
     #[allow(dead_code)]
     pub(crate) fn clear() {
@@ -117,11 +128,31 @@ impl TranscriptionWindow {
         api::set_recording_type();
     }
 
+    /// Name the provider transcribing the recording in the header
+    pub(crate) fn set_active_provider(provider: &str) {
+        api::set_active_provider(provider);
+    }
+
     /// Set the processing state (shows indicator with "Processing" text)
     pub(crate) fn set_processing_state(processing: bool) {
         api::set_processing_state(processing);
     }
 
+    /// Show a custom status message in the processing indicator
+    pub(crate) fn set_processing_message(message: &str) {
+        api::set_processing_message(message);
+    }
+
+    /// Show the recording's data usage in the footer
+    pub(crate) fn set_data_usage(summary: &str) {
+        api::set_data_usage(summary);
+    }
+
+    /// Show a warning below the tabs, e.g. a muted microphone; `None` hides it
+    pub(crate) fn set_input_warning(warning: Option<&str>) {
+        api::set_input_warning(warning.map(str::to_string));
+    }
+
     /// Set window transparency (0.0 = fully transparent, 1.0 = fully opaque)
     #[allow(dead_code)]
     pub(crate) fn set_transparency(alpha: f64) {
@@ -133,6 +164,56 @@ impl TranscriptionWindow {
         api::handle_hide_action();
     }
 
+    /// Handle a close request; returns whether the window may close (called from delegate)
+    pub(crate) fn handle_window_should_close() -> bool {
+        api::handle_window_should_close()
+    }
+
+    /// Handle the window closing (called from delegate)
+    pub(crate) fn handle_window_will_close() {
+        api::handle_window_will_close();
+    }
+
+    /// Handle marker button click (called from delegate)
+    pub(crate) fn handle_insert_marker_action() {
+        api::handle_insert_marker_action();
+    }
+
+    /// Handle a click on a header stop button (called from delegate)
+    pub(crate) fn handle_stop_action(tab: TabType) {
+        api::handle_stop_action(tab);
+    }
+
+    /// Show the note popover below the header (only while recording)
+    pub(crate) fn show_note_popover() {
+        api::show_note_popover();
+    }
+
+    /// Handle a submitted note from the note popover (called from delegate)
+    pub(crate) fn handle_submit_note(text: String) {
+        api::handle_submit_note(text);
+    }
+
+    /// Show the attendees popover below the header (only while recording)
+    pub(crate) fn show_attendees_popover() {
+        api::show_attendees_popover();
+    }
+
+    /// Handle submitted attendees from the attendees popover (called from delegate)
+    pub(crate) fn handle_submit_attendees(text: String) {
+        api::handle_submit_attendees(text);
+    }
+
+    /// Keep the configuration the provider applied and show the info button
+    pub(crate) fn set_session_info(info: SessionInfo) {
+        api::set_session_info(info);
+    }
+
+    /// Show the session info popover below the header
+    pub(crate) fn show_session_info_popover() {
+        api::show_session_info_popover();
+    }
+
     /// Adjust transparency by delta (positive = more opaque, negative = more transparent)
     pub(crate) fn adjust_transparency(delta: f64) {
         api::adjust_transparency(delta);
@@ -148,11 +229,6 @@ impl TranscriptionWindow {
         api::is_dark_mode()
     }
 
-    /// Set dark or light mode for the window background
-    pub(crate) fn set_dark_mode(is_dark: bool) {
-        api::set_dark_mode(is_dark);
-    }
-
     /// Show the save button and store transcript for later saving
     pub(crate) fn show_save_button(transcript: String) {
         api::show_save_button(transcript);
@@ -168,6 +244,48 @@ impl TranscriptionWindow {
         api::handle_save_file_action();
     }
 
+    /// Handle a find shortcut (Cmd+F, Cmd+G, Cmd+Shift+G) from the overlay window
+    pub(crate) fn handle_find_action(action: api::FindAction) {
+        api::handle_find_action(action);
+    }
+
+    /// Print the active tab (Cmd+P in the overlay window or the menu bar's Print…)
+    pub(crate) fn handle_print_action() {
+        api::handle_print_action();
+    }
+
+    /// Handle an edit to the live transcript (called from the text view delegate)
+    pub(crate) fn handle_live_text_changed() {
+        api::handle_live_text_changed();
+    }
+
+    /// Handle the end of editing the live transcript (called from the text view delegate)
+    pub(crate) fn handle_live_text_end_editing() {
+        api::handle_live_text_end_editing();
+    }
+
+    /// Handle gallery toggle button click (called from delegate)
+    pub(crate) fn handle_toggle_gallery_action() {
+        gallery::toggle();
+    }
+
+    /// Keep the screenshot gallery strip in step with the session and apply
+    /// settings saved while the window exists.
+    ///
+    /// A new recording starts with an empty strip; every captured screenshot
+    /// is added to it.
+    pub(crate) fn handle_event(event: &AppEvent) {
+        let event = event.clone();
+        dispatch::Queue::main().exec_async(move || match event {
+            AppEvent::RecordingStarted => gallery::clear(),
+            AppEvent::ScreenshotCaptured { filename } => {
+                gallery::add_screenshot(format!("screenshots/{}", filename))
+            }
+            AppEvent::SettingChanged { setting } => api::handle_setting_changed(setting),
+            _ => {}
+        });
+    }
+
     /// Handle tab change from segmented control (called from delegate)
     pub(crate) fn handle_tab_change_action(selected_index: isize) {
         api::handle_tab_change(selected_index);
@@ -178,27 +296,36 @@ impl TranscriptionWindow {
         api::switch_to_tab(tab);
     }
 
+    /// Show a practice script in the Live tab, dimmed up to `spoken_len`
+    pub(crate) fn show_script(script: &str, spoken_len: usize, next_word: Option<(usize, usize)>) {
+        api::show_script(script, spoken_len, next_word);
+    }
+
+    /// Close the practice script and show the live transcript again
+    pub(crate) fn clear_script() {
+        api::clear_script();
+    }
+
     /// Update live transcript content (Tab 1)
     pub(crate) fn update_live_text(committed: &str, partial: Option<&str>) {
         api::update_live_text(committed, partial);
     }
 
-    /// Set polished content (Tab 2)
-    pub(crate) fn set_polished_content(content: &str) {
-        api::set_polished_content(content);
-    }
-
-    /// Set meeting notes content (Tab 3)
-    pub(crate) fn set_meeting_notes_content(content: &str) {
-        api::set_meeting_notes_content(content);
+    /// Set the generated content of an output mode's tab
+    pub(crate) fn set_generated_content(tab: TabType, content: &str) {
+        api::set_generated_content(tab, content);
     }
 
     /// Get the current raw transcript for on-demand polishing
-    #[allow(dead_code)]
     pub(crate) fn get_live_transcript() -> Option<String> {
         api::get_live_transcript()
     }
 
+    /// Get the generated content of an output mode's tab
+    pub(crate) fn get_generated_content(tab: TabType) -> Option<String> {
+        api::get_generated_content(tab)
+    }
+
     /// Reset tab content (called when starting a new recording)
     pub(crate) fn reset_tabs() {
         api::reset_tabs();
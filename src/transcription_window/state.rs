@@ -3,20 +3,29 @@
 use objc2::rc::Retained;
 use objc2_app_kit::{NSScrollView, NSTextField, NSTextView, NSView, NSWindow};
 use once_cell::sync::OnceCell;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::{Arc, Mutex, RwLock};
+use std::sync::{Arc, RwLock};
 use tracing::info;
 
 use super::delegates::{HoverButton, WindowActionDelegate};
-use crate::preferences;
+use super::gallery::Gallery;
+use crate::transcription::SessionInfo;
+use crate::{app_context, output_modes, preferences};
 
 /// Tab types for the transcription window
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub(crate) enum TabType {
     #[default]
     Live,
     BasicPolish,
     MeetingNotes,
+    FollowUpEmail,
+    /// A custom output mode, by its position in the registry
+    Custom(usize),
 }
 
 impl TabType {
@@ -26,6 +35,10 @@ impl TabType {
             0 => TabType::Live,
             1 => TabType::BasicPolish,
             2 => TabType::MeetingNotes,
+            3 => TabType::FollowUpEmail,
+            n if n > 3 && output_modes::custom_mode(n as usize - 4).is_some() => {
+                TabType::Custom(n as usize - 4)
+            }
             _ => TabType::Live,
         }
     }
@@ -36,23 +49,34 @@ impl TabType {
             TabType::Live => 0,
             TabType::BasicPolish => 1,
             TabType::MeetingNotes => 2,
+            TabType::FollowUpEmail => 3,
+            TabType::Custom(index) => 4 + index as isize,
         }
     }
 }
 
+/// Segment index of the Debug tab, after the Live and output mode tabs
+///
+/// The segment only exists while the Debug tab is turned on in Settings.
+pub(super) fn debug_segment() -> isize {
+    1 + output_modes::polish_tabs().len() as isize
+}
+
 /// Content storage for each tab
 #[derive(Debug, Clone, Default)]
 pub(super) struct TabContent {
     /// Raw live transcript (always preserved)
     pub live_transcript: String,
-    /// Basic polished content (None if not yet generated)
-    pub polished_content: Option<String>,
-    /// Meeting notes content (None if not yet generated)
-    pub meeting_notes_content: Option<String>,
+    /// Generated content of each output mode tab (missing if not yet generated)
+    pub generated: HashMap<TabType, String>,
+    /// Committed live text already read out to VoiceOver
+    pub announced_transcript: String,
 }
 
-/// Global state for the transcription window
-pub(super) static TRANSCRIPTION_WINDOW: OnceCell<Mutex<TranscriptionWindowInner>> = OnceCell::new();
+/// The transcription window, once created (main thread only)
+pub(super) fn transcription_window() -> Option<&'static RefCell<TranscriptionWindowInner>> {
+    app_context::windows()?.transcription.get()
+}
 
 /// Global callbacks for window actions
 pub(super) static WINDOW_CALLBACKS: OnceCell<WindowCallbacks> = OnceCell::new();
@@ -66,6 +90,9 @@ pub(super) static IS_DARK_MODE: AtomicBool = AtomicBool::new(true);
 /// Global state for recording status (true = actively recording)
 pub(super) static IS_RECORDING: AtomicBool = AtomicBool::new(false);
 
+/// Whether the Live tab shows a practice script instead of the transcript
+pub(super) static SCRIPT_MODE: AtomicBool = AtomicBool::new(false);
+
 /// Global state for pending transcript (to be saved when user clicks Save button)
 pub(super) static PENDING_TRANSCRIPT: OnceCell<RwLock<Option<String>>> = OnceCell::new();
 
@@ -77,43 +104,106 @@ pub(super) fn pending_transcript_storage() -> &'static RwLock<Option<String>> {
 /// Callbacks for window actions
 pub(crate) struct WindowCallbacks {
     pub(crate) on_hide: Arc<dyn Fn() + Send + Sync>,
-    /// Callback to request basic polishing on-demand (takes raw transcript)
-    pub(crate) on_request_basic_polish: Arc<dyn Fn(String) + Send + Sync>,
-    /// Callback to request meeting notes on-demand (takes raw transcript)
-    pub(crate) on_request_meeting_notes: Arc<dyn Fn(String) + Send + Sync>,
+    /// Callback to generate an output mode tab's content on-demand (takes the
+    /// tab and raw transcript)
+    pub(crate) on_request_generation: Arc<dyn Fn(TabType, String) + Send + Sync>,
+    /// Callback when the user finishes correcting the live transcript (takes edited transcript)
+    pub(crate) on_live_transcript_edited: Arc<dyn Fn(String) + Send + Sync>,
+    /// Callback to insert a timestamped marker into the recording transcript
+    pub(crate) on_insert_marker: Arc<dyn Fn() + Send + Sync>,
+    /// Callback to insert a typed note into the recording transcript (takes note text)
+    pub(crate) on_insert_note: Arc<dyn Fn(String) + Send + Sync>,
+    /// Callback to stop the recording from a header stop button (takes the
+    /// tab whose output mode to stop with)
+    pub(crate) on_stop_with_mode: Arc<dyn Fn(TabType) + Send + Sync>,
 }
 
 /// Inner transcription window state
 #[allow(dead_code)]
-pub(super) struct TranscriptionWindowInner {
-    pub window: Retained<NSWindow>,
+pub(crate) struct TranscriptionWindowInner {
+    pub(super) window: Retained<NSWindow>,
     // Tab control (NSSegmentedControl stored as NSView since objc2_app_kit doesn't export it)
-    pub segmented_control: Retained<NSView>,
-    pub active_tab: TabType,
-    pub tab_content: TabContent,
+    pub(super) segmented_control: Retained<NSView>,
+    pub(super) active_tab: TabType,
+    pub(super) tab_content: TabContent,
     // Tab 1: Live transcription
-    pub live_scroll_view: Retained<NSScrollView>,
-    pub live_text_view: Retained<NSTextView>,
-    // Tab 2: Basic polishing
-    pub polished_scroll_view: Retained<NSScrollView>,
-    pub polished_text_view: Retained<NSTextView>,
-    // Tab 3: Meeting notes
-    pub meeting_scroll_view: Retained<NSScrollView>,
-    pub meeting_text_view: Retained<NSTextView>,
+    pub(super) live_scroll_view: Retained<NSScrollView>,
+    pub(super) live_text_view: Retained<NSTextView>,
+    // Remaining tabs: one per output mode, in tab order
+    pub(super) output_views: Vec<OutputView>,
+    // Debug tab: provider events and connection changes (opt-in in Settings)
+    pub(super) debug_scroll_view: Retained<NSScrollView>,
+    pub(super) debug_text_view: Retained<NSTextView>,
+    // Whether the Debug tab is shown in place of the active tab
+    pub(super) debug_shown: bool,
     // Header elements
-    pub header_view: Retained<NSView>,
-    pub hide_button: Retained<HoverButton>,
-    pub recording_type_label: Retained<NSTextField>,
+    pub(super) header_view: Retained<NSView>,
+    pub(super) hide_button: Retained<HoverButton>,
+    // Marker, note and attendees buttons (shown only while recording)
+    pub(super) marker_button: Retained<HoverButton>,
+    pub(super) note_button: Retained<HoverButton>,
+    pub(super) attendees_button: Retained<HoverButton>,
+    // Session info button (shown once the provider confirms the session)
+    pub(super) info_button: Retained<HoverButton>,
+    // Stop buttons, one per stop mode (shown only while recording)
+    pub(super) stop_buttons: Vec<Retained<HoverButton>>,
+    pub(super) recording_type_label: Retained<NSTextField>,
     // Recording indicator (center bottom)
-    pub recording_indicator: Retained<NSView>,
-    pub recording_label: Retained<NSTextField>,
+    pub(super) recording_indicator: Retained<NSView>,
+    pub(super) recording_label: Retained<NSTextField>,
     // Save button (center bottom, shown after recording to allow manual save)
-    pub save_button: Retained<HoverButton>,
+    pub(super) save_button: Retained<HoverButton>,
+    // Data usage of the recording (bottom right)
+    pub(super) usage_label: Retained<NSTextField>,
+    // Muted microphone warning (below the tab control, hidden unless muted)
+    pub(super) input_warning_label: Retained<NSTextField>,
+    // Settings notice (below the input warning, hidden unless a setting just changed)
+    pub(super) notice_label: Retained<NSTextField>,
+    // Configuration the provider applied to the current session
+    pub(super) session_info: Option<SessionInfo>,
+    // Screenshot gallery strip (above the footer, shown once a screenshot is taken)
+    pub(super) gallery: Gallery,
     // Delegate (kept alive)
-    pub delegate: Retained<WindowActionDelegate>,
+    pub(super) delegate: Retained<WindowActionDelegate>,
+}
+
+/// Text view of an output mode's tab
+pub(super) struct OutputView {
+    pub(super) tab: TabType,
+    pub(super) scroll_view: Retained<NSScrollView>,
+    pub(super) text_view: Retained<NSTextView>,
 }
 
-unsafe impl Send for TranscriptionWindowInner {}
+impl TranscriptionWindowInner {
+    /// Scroll and text view showing a tab
+    pub(super) fn views(
+        &self,
+        tab: TabType,
+    ) -> Option<(&Retained<NSScrollView>, &Retained<NSTextView>)> {
+        if tab == TabType::Live {
+            return Some((&self.live_scroll_view, &self.live_text_view));
+        }
+        self.output_views
+            .iter()
+            .find(|view| view.tab == tab)
+            .map(|view| (&view.scroll_view, &view.text_view))
+    }
+
+    /// Text views of all tabs, in tab order
+    pub(super) fn text_views(&self) -> impl Iterator<Item = &Retained<NSTextView>> {
+        std::iter::once(&self.live_text_view)
+            .chain(self.output_views.iter().map(|view| &view.text_view))
+    }
+
+    /// Scroll views of all tabs with their tab, in tab order
+    pub(super) fn scroll_views(&self) -> impl Iterator<Item = (TabType, &Retained<NSScrollView>)> {
+        std::iter::once((TabType::Live, &self.live_scroll_view)).chain(
+            self.output_views
+                .iter()
+                .map(|view| (view.tab, &view.scroll_view)),
+        )
+    }
+}
 
 /// Load appearance preferences from persistent storage and apply them to global state.
 ///
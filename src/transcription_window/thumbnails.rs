@@ -0,0 +1,226 @@
+//! Inline screenshot thumbnails
+//!
+//! Renders `![Screenshot](screenshots/...)` references as scaled-down images
+//! in the transcript text views. Each thumbnail carries a link to the
+//! full-size file, so clicking it opens the screenshot in the default viewer.
+//! Encrypted screenshots (`.png.enc`) are decrypted in memory for display and
+//! to a private temporary copy when clicked.
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_app_kit::NSImage;
+use objc2_foundation::{
+    MainThreadMarker, NSAttributedString, NSData, NSMutableAttributedString, NSPoint, NSRange,
+    NSRect, NSSize, NSString, NSURL,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{error, warn};
+
+use super::objc_utils;
+use crate::{encryption, preferences};
+
+/// Maximum thumbnail width in points
+const THUMBNAIL_MAX_WIDTH: f64 = 320.0;
+
+/// Maximum thumbnail height in points
+const THUMBNAIL_MAX_HEIGHT: f64 = 180.0;
+
+/// Number of decoded images kept before the cache is cleared
+const IMAGE_CACHE_LIMIT: usize = 64;
+
+/// Prefix used for screenshot references inserted during recording
+const SCREENSHOTS_PREFIX: &str = "screenshots/";
+
+/// Attribute holding the markdown a thumbnail was rendered from.
+///
+/// Lets edited text be converted back to markdown without losing screenshot references.
+pub(super) const MARKDOWN_SOURCE_ATTRIBUTE: &str = "VissperMarkdownSource";
+
+thread_local! {
+    /// Decoded images keyed by markdown reference.
+    ///
+    /// The live view is re-rendered on every partial transcript, so images
+    /// are loaded from disk once and reused. Only touched on the main thread.
+    static IMAGE_CACHE: RefCell<HashMap<String, (PathBuf, Retained<NSImage>)>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Resolve a screenshot reference from the transcript to a file on disk.
+///
+/// References are either absolute paths or `screenshots/<file>` relative to
+/// the configured screenshot location.
+pub(super) fn resolve_screenshot_path(reference: &str) -> Option<PathBuf> {
+    let path = Path::new(reference);
+    if path.is_absolute() {
+        return Some(path.to_path_buf());
+    }
+
+    let file_name = reference.strip_prefix(SCREENSHOTS_PREFIX)?;
+    let screenshots_dir =
+        preferences::get_screenshot_location().or_else(preferences::default_screenshot_location)?;
+    Some(screenshots_dir.join(file_name))
+}
+
+/// Scale an image size down to fit the thumbnail bounds, preserving aspect ratio.
+fn thumbnail_size(width: f64, height: f64) -> (f64, f64) {
+    if width <= 0.0 || height <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let scale = (THUMBNAIL_MAX_WIDTH / width)
+        .min(THUMBNAIL_MAX_HEIGHT / height)
+        .min(1.0);
+    (width * scale, height * scale)
+}
+
+/// Load (or fetch from cache) the image for a screenshot reference.
+pub(super) fn load_image(
+    mtm: MainThreadMarker,
+    reference: &str,
+) -> Option<(PathBuf, Retained<NSImage>)> {
+    if let Some(cached) = IMAGE_CACHE.with(|cache| cache.borrow().get(reference).cloned()) {
+        return Some(cached);
+    }
+
+    let mut path = resolve_screenshot_path(reference)?;
+    let image = if path.exists() {
+        let ns_path = NSString::from_str(&path.to_string_lossy());
+        // SAFETY: initWithContentsOfFile: returns nil for unreadable files, which maps to None
+        unsafe { msg_send_id![mtm.alloc::<NSImage>(), initWithContentsOfFile: &*ns_path] }
+    } else {
+        // Screenshots taken with encryption at rest keep their `.png` reference
+        path = encryption::encrypted_path(&path);
+        if !path.exists() {
+            return None;
+        }
+        load_encrypted_image(mtm, &path)
+    };
+    let Some(image) = image else {
+        warn!("Failed to load screenshot thumbnail: {:?}", path);
+        return None;
+    };
+
+    IMAGE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= IMAGE_CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(reference.to_string(), (path.clone(), image.clone()));
+    });
+
+    Some((path, image))
+}
+
+/// Decrypt an encrypted screenshot in memory and decode it.
+fn load_encrypted_image(mtm: MainThreadMarker, path: &Path) -> Option<Retained<NSImage>> {
+    let bytes = match encryption::read_file(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!("Failed to decrypt screenshot {:?}: {}", path, e);
+            return None;
+        }
+    };
+    let data = NSData::with_bytes(&bytes);
+    // SAFETY: initWithData: returns nil for undecodable data, which maps to None
+    unsafe { msg_send_id![mtm.alloc::<NSImage>(), initWithData: &*data] }
+}
+
+/// Open a clicked thumbnail link that points at an encrypted screenshot.
+///
+/// The screenshot is decrypted to a private temporary copy, which is opened
+/// in the default viewer. Returns `false` for other links so the text view
+/// handles them as usual.
+pub(super) fn open_encrypted_link(link: &AnyObject) -> bool {
+    // SAFETY: isKindOfClass: and path are valid on any object / NSURL
+    let path: Option<Retained<NSString>> = unsafe {
+        let is_url: bool = msg_send![link, isKindOfClass: NSURL::class()];
+        if !is_url {
+            return false;
+        }
+        msg_send_id![link, path]
+    };
+    let Some(path) = path.map(|p| PathBuf::from(p.to_string())) else {
+        return false;
+    };
+    if !encryption::is_encrypted_path(&path) {
+        return false;
+    }
+
+    match encryption::decrypt_to_temp(&path) {
+        Ok(decrypted) => {
+            if let Err(e) = open::that(&decrypted) {
+                error!("Failed to open decrypted screenshot: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to decrypt screenshot {:?}: {}", path, e),
+    }
+    true
+}
+
+/// Build an attributed string holding a clickable thumbnail for a screenshot reference.
+///
+/// Returns `None` when not on the main thread or the image cannot be loaded,
+/// in which case the caller falls back to showing the reference as text.
+pub(super) fn create_thumbnail_string(
+    alt: &str,
+    reference: &str,
+) -> Option<Retained<NSAttributedString>> {
+    let mtm = MainThreadMarker::new()?;
+    let (path, image) = load_image(mtm, reference)?;
+    let attachment_class = objc_utils::get_class_or_warn("NSTextAttachment")?;
+
+    // SAFETY: NSTextAttachment, NSAttributedString and NSURL messages with valid arguments
+    unsafe {
+        let image_size: NSSize = msg_send![&image, size];
+        let (width, height) = thumbnail_size(image_size.width, image_size.height);
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+
+        let attachment: *mut AnyObject = msg_send![attachment_class, alloc];
+        let attachment: *mut AnyObject = msg_send![attachment, init];
+        if attachment.is_null() {
+            return None;
+        }
+        let _: () = msg_send![attachment, setImage: &*image];
+        let bounds = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(width, height));
+        let _: () = msg_send![attachment, setBounds: bounds];
+
+        let attachment_string: Retained<NSAttributedString> = msg_send_id![
+            NSAttributedString::class(),
+            attributedStringWithAttachment: attachment
+        ];
+        // The attributed string retains the attachment
+        let _: () = msg_send![attachment, release];
+
+        let result: Retained<NSMutableAttributedString> = msg_send_id![
+            NSMutableAttributedString::alloc(),
+            initWithAttributedString: &*attachment_string
+        ];
+
+        // Link the thumbnail to the full image so a click opens it
+        let ns_path = NSString::from_str(&path.to_string_lossy());
+        let url: Retained<NSURL> = msg_send_id![NSURL::class(), fileURLWithPath: &*ns_path];
+        let len: usize = msg_send![&result, length];
+        let link_attr = NSString::from_str("NSLink");
+        let _: () = msg_send![
+            &result,
+            addAttribute: &*link_attr,
+            value: &*url,
+            range: NSRange::new(0, len)
+        ];
+
+        let source_attr = NSString::from_str(MARKDOWN_SOURCE_ATTRIBUTE);
+        let source = NSString::from_str(&format!("![{}]({})", alt, reference));
+        let _: () = msg_send![
+            &result,
+            addAttribute: &*source_attr,
+            value: &*source,
+            range: NSRange::new(0, len)
+        ];
+
+        Some(Retained::into_super(result))
+    }
+}
@@ -0,0 +1,36 @@
+//! Clickable table of contents in meeting notes split into topics
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id, ClassType};
+use objc2_foundation::{NSRange, NSString};
+
+use super::markdown::utf16_len;
+use crate::topics;
+
+/// Scroll a clicked contents entry's section into view.
+///
+/// Returns `false` for other links so they are handled as usual.
+///
+/// # Safety
+/// `text_view` must be the NSTextView the link was clicked in.
+pub(super) unsafe fn scroll_to_topic(text_view: &AnyObject, link: &AnyObject) -> bool {
+    let is_string: bool = msg_send![link, isKindOfClass: NSString::class()];
+    if !is_string {
+        return false;
+    }
+    let link = &*(link as *const AnyObject as *const NSString);
+    let link = link.to_string();
+    let Some(entry) = link.strip_prefix(topics::LINK_PREFIX) else {
+        return false;
+    };
+
+    let text: Retained<NSString> = msg_send_id![text_view, string];
+    let text = text.to_string();
+    if let Some(offset) = topics::heading_offset(&text, entry) {
+        let range = NSRange::new(utf16_len(&text[..offset]), utf16_len(entry));
+        let _: () = msg_send![text_view, scrollRangeToVisible: range];
+        let _: () = msg_send![text_view, showFindIndicatorForRange: range];
+    }
+    true
+}
@@ -1,18 +1,27 @@
 //! Window creation and orchestration
 
-use objc2::msg_send;
+use objc2::rc::Retained;
 use objc2::runtime::AnyObject;
+use objc2::{msg_send, msg_send_id};
 use objc2_app_kit::{NSBackingStoreType, NSColor, NSScreen, NSWindow, NSWindowStyleMask};
-use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize};
+use objc2_foundation::{CGFloat, MainThreadMarker, NSPoint, NSRect, NSSize, NSString};
 use std::sync::atomic::Ordering;
 use tracing::info;
 
-use super::components::{create_header, create_scrollable_text_view, create_tab_control};
-use super::controls::{create_recording_indicator, create_save_button};
-use super::delegates::{TrackingContentView, WindowActionDelegate};
+use super::components::{
+    create_header, create_scrollable_text_view, create_tab_control, HeaderViews,
+};
+use super::controls::{
+    create_input_warning_label, create_notice_label, create_recording_indicator,
+    create_save_button, create_usage_label,
+};
+use super::delegates::{OverlayWindow, TrackingContentView, WindowActionDelegate};
+use super::gallery::{self, create_gallery};
 use super::state::{
-    TabContent, TabType, TranscriptionWindowInner, CURRENT_TRANSPARENCY, IS_DARK_MODE,
+    OutputView, TabContent, TabType, TranscriptionWindowInner, CURRENT_TRANSPARENCY, IS_DARK_MODE,
 };
+use crate::localization::tr;
+use crate::output_modes;
 
 /// Create the transparent window with all UI elements
 pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
@@ -47,15 +56,16 @@ pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
         NSSize::new(window_width, window_height),
     );
 
-    // Create borderless window
-    let window = unsafe {
-        NSWindow::initWithContentRect_styleMask_backing_defer(
-            mtm.alloc(),
-            frame,
-            NSWindowStyleMask::Borderless | NSWindowStyleMask::Resizable,
-            NSBackingStoreType::NSBackingStoreBuffered,
-            false,
-        )
+    // Create borderless window (subclass so it can become key for the find bar)
+    let window: Retained<NSWindow> = unsafe {
+        let window: Retained<OverlayWindow> = msg_send_id![
+            mtm.alloc::<OverlayWindow>(),
+            initWithContentRect: frame,
+            styleMask: NSWindowStyleMask::Borderless | NSWindowStyleMask::Resizable,
+            backing: NSBackingStoreType::NSBackingStoreBuffered,
+            defer: false
+        ];
+        Retained::into_super(window)
     };
 
     // Mark as released when closed = false for proper memory management
@@ -123,9 +133,17 @@ pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
 
     window.setContentView(Some(&tracking_content_view));
 
-    // Create header view with recording type label and hide button
-    let (header_view, hide_button, recording_type_label) =
-        create_header(mtm, window_width, window_height, header_height, &delegate);
+    // Create header view with recording type label and header buttons
+    let HeaderViews {
+        header_view,
+        hide_button,
+        marker_button,
+        note_button,
+        attendees_button,
+        info_button,
+        stop_buttons,
+        recording_type_label,
+    } = create_header(mtm, window_width, window_height, header_height, &delegate);
 
     // Create tab control (segmented control)
     // NSSegmentedControl is a core macOS class - if it fails, the UI is fundamentally broken
@@ -142,7 +160,7 @@ pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
     // Calculate content height for text views (below header and tab control, above footer)
     let content_height = window_height - header_height - tab_height - footer_height - 8.0; // 8.0 for spacing
 
-    // Create three text views for each tab
+    // Create a text view for each tab
     // Tab 1: Live transcription (visible by default)
     let (live_scroll_view, live_text_view) = create_scrollable_text_view(
         mtm,
@@ -150,31 +168,62 @@ pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
         content_height,
         footer_height,
         padding,
-        "Listening...",
+        tr("overlay.listening"),
+        tr("overlay.live_transcription"),
         true,
     );
 
-    // Tab 2: Polished transcript (hidden by default)
-    let (polished_scroll_view, polished_text_view) = create_scrollable_text_view(
+    // Remaining tabs: one per output mode (hidden by default)
+    let output_views: Vec<OutputView> = output_modes::polish_tabs()
+        .into_iter()
+        .map(|tab| {
+            let (scroll_view, text_view) = create_scrollable_text_view(
+                mtm,
+                window_width,
+                content_height,
+                footer_height,
+                padding,
+                output_modes::placeholder(tab),
+                &output_modes::title(tab),
+                false,
+            );
+            OutputView {
+                tab,
+                scroll_view,
+                text_view,
+            }
+        })
+        .collect();
+
+    // Debug tab: the transcription debug log (hidden until its segment is picked)
+    let (debug_scroll_view, debug_text_view) = create_scrollable_text_view(
         mtm,
         window_width,
         content_height,
         footer_height,
         padding,
-        "Click to generate polished transcript...",
+        tr("overlay.debug_empty"),
+        tr("overlay.debug_log"),
         false,
     );
 
-    // Tab 3: Meeting notes (hidden by default)
-    let (meeting_scroll_view, meeting_text_view) = create_scrollable_text_view(
-        mtm,
-        window_width,
-        content_height,
-        footer_height,
-        padding,
-        "Click to generate meeting notes...",
-        false,
-    );
+    // Track manual corrections to the live transcript, and open encrypted
+    // screenshot thumbnails in every tab
+    unsafe {
+        let _: () = msg_send![&live_text_view, setDelegate: &*delegate];
+        for view in &output_views {
+            let _: () = msg_send![&view.text_view, setDelegate: &*delegate];
+        }
+
+        // Turn a close into a hide while recording (the delegate is kept
+        // alive in TranscriptionWindowInner for as long as the window)
+        let _: () = msg_send![&window, setDelegate: &*delegate];
+
+        // Accessibility: borderless windows have no visible title, so give
+        // VoiceOver one to announce
+        let accessibility_title = NSString::from_str(tr("overlay.window"));
+        let _: () = msg_send![&window, setAccessibilityTitle: &*accessibility_title];
+    }
 
     // Create recording indicator (center bottom)
     let (recording_indicator, recording_label) = create_recording_indicator(mtm, window_width);
@@ -182,40 +231,84 @@ pub(super) fn create_window(mtm: MainThreadMarker) -> TranscriptionWindowInner {
     // Create save button (center bottom, shown after recording to allow manual save)
     let save_button = create_save_button(mtm, window_width, &delegate);
 
+    // Create data usage label (bottom right)
+    let usage_label = create_usage_label(mtm, window_width, padding);
+
+    // Create the muted microphone warning (below the tab control, hidden)
+    let input_warning_label = create_input_warning_label(
+        mtm,
+        window_width,
+        window_height,
+        header_height + tab_height,
+        padding,
+    );
+
+    // Create the settings notice (below the input warning, hidden)
+    let notice_label = create_notice_label(
+        mtm,
+        window_width,
+        window_height,
+        header_height + tab_height,
+        padding,
+    );
+
+    // Create screenshot gallery strip (above the footer) and its toggle (bottom left)
+    let gallery = create_gallery(mtm, window_width, footer_height, padding, &delegate);
+
     // Add all views to the tracking content view
     unsafe {
         tracking_content_view.addSubview(&header_view);
         tracking_content_view.addSubview(&segmented_control);
         tracking_content_view.addSubview(&live_scroll_view);
-        tracking_content_view.addSubview(&polished_scroll_view);
-        tracking_content_view.addSubview(&meeting_scroll_view);
+        for view in &output_views {
+            tracking_content_view.addSubview(&view.scroll_view);
+        }
+        tracking_content_view.addSubview(&debug_scroll_view);
         tracking_content_view.addSubview(&recording_indicator);
         tracking_content_view.addSubview(&recording_label);
         tracking_content_view.addSubview(&save_button);
+        tracking_content_view.addSubview(&usage_label);
+        tracking_content_view.addSubview(&input_warning_label);
+        tracking_content_view.addSubview(&notice_label);
     }
+    gallery.add_to(&tracking_content_view);
 
     // Show the window - use makeKeyAndOrderFront to ensure visibility
     window.makeKeyAndOrderFront(None);
 
     info!("Transcription window created and shown");
 
-    TranscriptionWindowInner {
+    let mut inner = TranscriptionWindowInner {
         window,
         segmented_control,
         active_tab: TabType::Live,
         tab_content: TabContent::default(),
         live_scroll_view,
         live_text_view,
-        polished_scroll_view,
-        polished_text_view,
-        meeting_scroll_view,
-        meeting_text_view,
+        output_views,
+        debug_scroll_view,
+        debug_text_view,
+        debug_shown: false,
         header_view,
         hide_button,
+        marker_button,
+        note_button,
+        attendees_button,
+        info_button,
+        stop_buttons,
         recording_type_label,
         recording_indicator,
         recording_label,
         save_button,
+        usage_label,
+        input_warning_label,
+        notice_label,
+        session_info: None,
+        gallery,
         delegate,
-    }
+    };
+
+    // Show screenshots taken before the window was opened
+    gallery::reload(&mut inner);
+    inner
 }
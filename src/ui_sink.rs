@@ -0,0 +1,146 @@
+//! Presentation boundary for recording and polishing
+//!
+//! Recording, transcription events and polishing report progress through a
+//! [`UiSink`] instead of calling the transcription window and menu bar
+//! directly. The app passes an [`AppKitUi`] in through its callback config;
+//! headless runs and tests can use [`NoopUi`] or their own implementation.
+
+use std::sync::Arc;
+
+use crate::localization::tr;
+use crate::menubar;
+use crate::transcription::SessionInfo;
+use crate::transcription_window::{TabType, TranscriptionWindow};
+
+/// Shared handle to the active presenter
+pub(crate) type SharedUi = Arc<dyn UiSink>;
+
+/// Everything recording and polishing show to the user
+pub(crate) trait UiSink: Send + Sync {
+    /// Prepare the window for a new recording and show it
+    fn recording_started(&self);
+
+    /// Show the live transcript, with the partial text still being recognized
+    fn update_live_text(&self, committed: &str, partial: Option<&str>);
+
+    /// Reflect whether audio is being recorded
+    fn set_recording(&self, recording: bool);
+
+    /// Reflect whether a polish job is running
+    fn set_processing(&self, processing: bool);
+
+    /// Replace the processing indicator text, e.g. while rate limited
+    fn set_processing_message(&self, message: &str);
+
+    /// Show how much data the recording has sent and received
+    fn show_data_usage(&self, summary: &str);
+
+    /// Show which provider is transcribing, after a failover
+    fn show_active_provider(&self, provider: &str);
+
+    /// Warn that the microphone will record silence; `None` clears the warning
+    fn show_input_warning(&self, warning: Option<&str>);
+
+    /// Show the configuration the provider applied to the session
+    fn show_session_info(&self, info: &SessionInfo);
+
+    /// Show a message that stops recording from starting
+    fn show_error(&self, message: &str);
+
+    /// Put polished output in a tab
+    fn set_tab_content(&self, tab: TabType, content: &str);
+
+    /// Bring a tab to the front
+    fn switch_to_tab(&self, tab: TabType);
+
+    /// Offer to save a finished transcript
+    fn show_save_button(&self, transcript: String);
+}
+
+/// The transcription window and menu bar
+pub(crate) struct AppKitUi;
+
+impl UiSink for AppKitUi {
+    fn recording_started(&self) {
+        TranscriptionWindow::show();
+        TranscriptionWindow::reset_tabs();
+        TranscriptionWindow::set_recording_state(true);
+        TranscriptionWindow::set_recording_type();
+        TranscriptionWindow::update_live_text("", Some(tr("overlay.listening")));
+        TranscriptionWindow::hide_save_button();
+    }
+
+    fn update_live_text(&self, committed: &str, partial: Option<&str>) {
+        TranscriptionWindow::update_live_text(committed, partial);
+    }
+
+    fn set_recording(&self, recording: bool) {
+        menubar::MenuBar::set_recording(recording);
+        TranscriptionWindow::set_recording_state(recording);
+    }
+
+    fn set_processing(&self, processing: bool) {
+        menubar::MenuBar::set_processing(processing);
+        TranscriptionWindow::set_processing_state(processing);
+    }
+
+    fn set_processing_message(&self, message: &str) {
+        TranscriptionWindow::set_processing_message(message);
+    }
+
+    fn show_data_usage(&self, summary: &str) {
+        TranscriptionWindow::set_data_usage(summary);
+    }
+
+    fn show_active_provider(&self, provider: &str) {
+        TranscriptionWindow::set_active_provider(provider);
+    }
+
+    fn show_input_warning(&self, warning: Option<&str>) {
+        TranscriptionWindow::set_input_warning(warning);
+    }
+
+    fn show_session_info(&self, info: &SessionInfo) {
+        TranscriptionWindow::set_session_info(info.clone());
+    }
+
+    fn show_error(&self, message: &str) {
+        TranscriptionWindow::show();
+        TranscriptionWindow::update_live_text(message, None);
+    }
+
+    fn set_tab_content(&self, tab: TabType, content: &str) {
+        match tab {
+            TabType::Live => TranscriptionWindow::update_live_text(content, None),
+            _ => TranscriptionWindow::set_generated_content(tab, content),
+        }
+    }
+
+    fn switch_to_tab(&self, tab: TabType) {
+        TranscriptionWindow::switch_to_tab(tab);
+    }
+
+    fn show_save_button(&self, transcript: String) {
+        TranscriptionWindow::show_save_button(transcript);
+    }
+}
+
+/// Presenter that discards everything
+#[allow(dead_code)] // For headless runs and tests
+pub(crate) struct NoopUi;
+
+impl UiSink for NoopUi {
+    fn recording_started(&self) {}
+    fn update_live_text(&self, _committed: &str, _partial: Option<&str>) {}
+    fn set_recording(&self, _recording: bool) {}
+    fn set_processing(&self, _processing: bool) {}
+    fn set_processing_message(&self, _message: &str) {}
+    fn show_data_usage(&self, _summary: &str) {}
+    fn show_active_provider(&self, _provider: &str) {}
+    fn show_input_warning(&self, _warning: Option<&str>) {}
+    fn show_session_info(&self, _info: &SessionInfo) {}
+    fn show_error(&self, _message: &str) {}
+    fn set_tab_content(&self, _tab: TabType, _content: &str) {}
+    fn switch_to_tab(&self, _tab: TabType) {}
+    fn show_save_button(&self, _transcript: String) {}
+}
@@ -9,6 +9,8 @@ use std::cmp::Ordering;
 use std::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
+use crate::event_bus::{self, AppEvent};
+
 /// Global version checker configuration
 static VERSION_CHECK_URL: OnceCell<String> = OnceCell::new();
 
@@ -206,7 +208,9 @@ async fn perform_check_and_update() {
                     "Cached update available: {} -> {}",
                     current_version, cached_version
                 );
-                crate::menubar::MenuBar::show_update_available(&cached_version);
+                event_bus::publish(AppEvent::UpdateAvailable {
+                    version: cached_version,
+                });
 
                 // Check if we have the download URL cached
                 if crate::preferences::get_latest_download_url().is_none() {
@@ -241,8 +245,9 @@ async fn perform_check_and_update() {
                 "Update detected! Showing menu item for version {}",
                 version_info.version
             );
-            crate::menubar::MenuBar::show_update_available(&version_info.version);
-            info!("Menu item update requested");
+            event_bus::publish(AppEvent::UpdateAvailable {
+                version: version_info.version,
+            });
         }
         Ok(None) => {
             // Network check skipped or no update found
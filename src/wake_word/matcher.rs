@@ -0,0 +1,83 @@
+//! Matching the wake phrase in recognized speech
+
+use crate::teleprompter::edit_distance;
+
+/// Words up to this many characters must be recognized exactly
+const SHORT_WORD_LEN: usize = 3;
+
+/// The configured wake phrase, split into normalized words
+#[derive(Debug, Clone)]
+pub(super) struct PhraseMatcher {
+    words: Vec<String>,
+}
+
+impl PhraseMatcher {
+    /// Returns `None` for a phrase without any words.
+    pub(super) fn new(phrase: &str) -> Option<Self> {
+        let words = normalized_words(phrase);
+        (!words.is_empty()).then_some(Self { words })
+    }
+
+    /// Whether the phrase was said anywhere in `heard`, in order and without
+    /// other words in between
+    pub(super) fn matches(&self, heard: &str) -> bool {
+        let heard = normalized_words(heard);
+        heard.windows(self.words.len()).any(|window| {
+            window
+                .iter()
+                .zip(&self.words)
+                .all(|(heard, expected)| words_match(expected, heard))
+        })
+    }
+}
+
+/// Lowercased words with surrounding punctuation removed
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Whether a recognized word matches a phrase word, allowing a small
+/// recognition error in longer words (names are often misspelled)
+fn words_match(expected: &str, heard: &str) -> bool {
+    if expected == heard {
+        return true;
+    }
+    let len = expected.chars().count().min(heard.chars().count());
+    len > SHORT_WORD_LEN && edit_distance(expected, heard) <= len / 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_phrase_in_speech() {
+        let matcher = PhraseMatcher::new("Hey Vissper").unwrap();
+
+        assert!(matcher.matches("hey vissper"));
+        assert!(matcher.matches("Okay, so... Hey, Vissper! Start the notes"));
+        assert!(matcher.matches("hey visper"));
+        assert!(!matcher.matches("hey there"));
+        assert!(!matcher.matches("vissper hey"));
+        assert!(!matcher.matches("hey you vissper"));
+    }
+
+    #[test]
+    fn test_short_words_must_match_exactly() {
+        let matcher = PhraseMatcher::new("go now").unwrap();
+        assert!(matcher.matches("let's go now"));
+        assert!(!matcher.matches("so now"));
+    }
+
+    #[test]
+    fn test_empty_phrase() {
+        assert!(PhraseMatcher::new("").is_none());
+        assert!(PhraseMatcher::new(" !? ").is_none());
+    }
+}
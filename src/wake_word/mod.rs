@@ -0,0 +1,217 @@
+//! Hands-free recording with a wake phrase
+//!
+//! When enabled in the Audio tab of Settings, Vissper listens to the
+//! microphone while it isn't recording and starts a recording when it hears
+//! the configured phrase (by default "Hey Vissper"). Listening uses on-device
+//! speech recognition only, so no audio leaves the Mac before the recording
+//! starts, and it pauses while recording so the session has the microphone
+//! to itself.
+//!
+//! The recognizer runs on its own thread, fed by a regular audio capture.
+//! Recognition tasks are restarted regularly because the Speech framework
+//! limits how long a single task may run.
+
+mod matcher;
+mod recognizer;
+
+use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+use crate::app_context::AppContext;
+use crate::audio::{self, AudioTuning};
+use crate::event_bus::AppEvent;
+use crate::preferences;
+use crate::recording;
+
+use matcher::PhraseMatcher;
+use recognizer::{Recognizer, AUTHORIZATION_AUTHORIZED, AUTHORIZATION_NOT_DETERMINED};
+
+/// Phrase used until one is configured
+pub(crate) const DEFAULT_PHRASE: &str = "Hey Vissper";
+
+/// Sample rate of the audio fed to the recognizer
+const SAMPLE_RATE: u32 = 16_000;
+
+/// How long one recognition task runs before it is replaced
+const TASK_DURATION: Duration = Duration::from_secs(50);
+
+/// What a detected wake phrase acts on
+static CONTEXT: OnceCell<(AppContext, tokio::runtime::Handle)> = OnceCell::new();
+
+/// Stop flag of the running listener thread, if any
+static LISTENER: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+/// The phrase the running listener is waiting for
+static PHRASE: Mutex<Option<PhraseMatcher>> = Mutex::new(None);
+
+/// Start listening if the wake word is enabled.
+pub(crate) fn init(context: AppContext, runtime: tokio::runtime::Handle) {
+    if CONTEXT.set((context, runtime)).is_err() {
+        warn!("Wake word already initialized");
+        return;
+    }
+    if preferences::get_wake_word_enabled() {
+        start_listening();
+    }
+}
+
+/// Turn the wake word on or off and persist the choice.
+pub(crate) fn set_enabled(enabled: bool) -> Result<(), preferences::PreferencesError> {
+    preferences::set_wake_word_enabled(enabled)?;
+    if enabled {
+        start_listening();
+    } else {
+        stop_listening();
+    }
+    Ok(())
+}
+
+/// Change the wake phrase and persist it.
+pub(crate) fn set_phrase(phrase: &str) -> Result<(), preferences::PreferencesError> {
+    preferences::set_wake_word_phrase(phrase)?;
+    if let Ok(mut current) = PHRASE.lock() {
+        *current = PhraseMatcher::new(phrase);
+    }
+    Ok(())
+}
+
+/// Pause listening while recording and resume afterwards.
+pub(crate) fn handle_event(event: &AppEvent) {
+    match event {
+        AppEvent::RecordingStarted => stop_listening(),
+        AppEvent::RecordingStopped if preferences::get_wake_word_enabled() => start_listening(),
+        _ => {}
+    }
+}
+
+/// Start the listener thread unless it is running or a recording is active.
+fn start_listening() {
+    let Some((context, _)) = CONTEXT.get() else {
+        return;
+    };
+    if context.app_state.is_recording.load(Ordering::SeqCst) {
+        return;
+    }
+
+    match recognizer::authorization_status() {
+        AUTHORIZATION_AUTHORIZED => {}
+        AUTHORIZATION_NOT_DETERMINED => {
+            recognizer::request_authorization(start_listening);
+            return;
+        }
+        _ => {
+            warn!("Speech recognition permission denied, wake word is off");
+            return;
+        }
+    }
+
+    let Some(matcher) = PhraseMatcher::new(&preferences::get_wake_word_phrase()) else {
+        warn!("Wake phrase is empty, wake word is off");
+        return;
+    };
+    if let Ok(mut current) = PHRASE.lock() {
+        *current = Some(matcher);
+    }
+
+    let Ok(mut listener) = LISTENER.lock() else {
+        return;
+    };
+    if listener.is_some() {
+        return;
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    *listener = Some(stop.clone());
+    std::thread::spawn(move || listen(preferences::get_language_code(), stop));
+    info!("Listening for the wake phrase");
+}
+
+/// Signal the listener thread to stop; it exits after the next audio chunk.
+fn stop_listening() {
+    if let Ok(mut listener) = LISTENER.lock() {
+        if let Some(stop) = listener.take() {
+            stop.store(true, Ordering::SeqCst);
+            info!("Stopped listening for the wake phrase");
+        }
+    }
+}
+
+/// Listener thread: feed microphone audio to the recognizer until stopped.
+fn listen(language: String, stop: Arc<AtomicBool>) {
+    let (mut capture, mut chunks) =
+        match audio::start_capture_with_sample_rate(SAMPLE_RATE, AudioTuning::from_preferences()) {
+            Ok(capture) => capture,
+            Err(e) => {
+                error!("Wake word could not open the microphone: {}", e);
+                clear_listener(&stop);
+                return;
+            }
+        };
+
+    let Some(mut recognizer) = Recognizer::new(&language, SAMPLE_RATE) else {
+        capture.stop();
+        clear_listener(&stop);
+        return;
+    };
+    recognizer.start(on_heard);
+    let mut task_started = Instant::now();
+
+    while !stop.load(Ordering::SeqCst) {
+        let Some(chunk) = chunks.blocking_recv() else {
+            break;
+        };
+        objc2::rc::autoreleasepool(|_| {
+            recognizer.append(&chunk.samples);
+            if task_started.elapsed() >= TASK_DURATION {
+                recognizer.stop();
+                recognizer.start(on_heard);
+                task_started = Instant::now();
+            }
+        });
+    }
+
+    recognizer.stop();
+    capture.stop();
+    clear_listener(&stop);
+}
+
+/// Forget the listener if it is still the registered one (it may have been
+/// replaced after being told to stop).
+fn clear_listener(stop: &Arc<AtomicBool>) {
+    if let Ok(mut listener) = LISTENER.lock() {
+        if listener
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, stop))
+        {
+            *listener = None;
+        }
+    }
+}
+
+/// Check recognized speech for the wake phrase (called on the main queue).
+fn on_heard(text: &str) {
+    let heard = PHRASE
+        .lock()
+        .map(|phrase| phrase.as_ref().is_some_and(|p| p.matches(text)))
+        .unwrap_or(false);
+    if !heard {
+        return;
+    }
+
+    info!("Wake phrase heard, starting recording");
+    stop_listening();
+
+    let Some((context, runtime)) = CONTEXT.get() else {
+        return;
+    };
+    if context.app_state.is_recording.load(Ordering::SeqCst) {
+        return;
+    }
+    let recording_state = context.recording_state.clone();
+    let ui = context.ui.clone();
+    runtime.spawn(async move {
+        recording::start_recording(recording_state, ui, true);
+    });
+}
@@ -0,0 +1,210 @@
+//! On-device speech recognition through the Speech framework
+//!
+//! Recognition is required to run on this Mac (`requiresOnDeviceRecognition`),
+//! so listening for the wake phrase never sends audio anywhere. Classes are
+//! looked up at runtime, like the UserNotifications classes in `feedback`.
+
+use block2::RcBlock;
+use objc2::msg_send;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject, Bool};
+use objc2_foundation::NSString;
+use tracing::{error, info, warn};
+
+#[link(name = "Speech", kind = "framework")]
+extern "C" {}
+
+#[link(name = "AVFoundation", kind = "framework")]
+extern "C" {}
+
+/// SFSpeechRecognizerAuthorizationStatusNotDetermined
+pub(super) const AUTHORIZATION_NOT_DETERMINED: isize = 0;
+
+/// SFSpeechRecognizerAuthorizationStatusAuthorized
+pub(super) const AUTHORIZATION_AUTHORIZED: isize = 3;
+
+/// Current speech recognition permission
+pub(super) fn authorization_status() -> isize {
+    let Some(class) = AnyClass::get("SFSpeechRecognizer") else {
+        return -1;
+    };
+    // SAFETY: authorizationStatus is a class method without arguments
+    unsafe { msg_send![class, authorizationStatus] }
+}
+
+/// Ask for speech recognition permission and call `on_authorized` if granted.
+pub(super) fn request_authorization(on_authorized: fn()) {
+    let Some(class) = AnyClass::get("SFSpeechRecognizer") else {
+        error!("Speech framework not available");
+        return;
+    };
+    let handler = RcBlock::new(move |status: isize| {
+        if status == AUTHORIZATION_AUTHORIZED {
+            info!("Speech recognition permission granted");
+            on_authorized();
+        } else {
+            warn!("Speech recognition permission denied");
+        }
+    });
+    // SAFETY: valid class and completion block
+    unsafe {
+        let _: () = msg_send![class, requestAuthorization: &*handler];
+    }
+}
+
+/// A speech recognizer fed with 16-bit mono audio
+///
+/// Created and fed on the listener thread; results are delivered on the
+/// main queue.
+pub(super) struct Recognizer {
+    recognizer: Retained<AnyObject>,
+    format: Retained<AnyObject>,
+    request: Option<Retained<AnyObject>>,
+    task: Option<Retained<AnyObject>>,
+}
+
+impl Recognizer {
+    /// Create a recognizer for `language` (e.g. "en"), or `None` if the
+    /// language can't be recognized on this Mac.
+    pub(super) fn new(language: &str, sample_rate: u32) -> Option<Self> {
+        let (recognizer_class, format_class, locale_class) = (
+            AnyClass::get("SFSpeechRecognizer")?,
+            AnyClass::get("AVAudioFormat")?,
+            AnyClass::get("NSLocale")?,
+        );
+
+        // SAFETY: messages to valid Speech and AVFoundation classes; alloc/init
+        // return +1 objects owned by Retained
+        unsafe {
+            let locale: *mut AnyObject = msg_send![
+                locale_class,
+                localeWithLocaleIdentifier: &*NSString::from_str(language)
+            ];
+            let recognizer: *mut AnyObject = msg_send![recognizer_class, alloc];
+            let recognizer: *mut AnyObject = msg_send![recognizer, initWithLocale: locale];
+            let Some(recognizer) = Retained::from_raw(recognizer) else {
+                warn!("Speech recognition does not support language {}", language);
+                return None;
+            };
+
+            let on_device: Bool = msg_send![&recognizer, supportsOnDeviceRecognition];
+            if !on_device.as_bool() {
+                warn!(
+                    "On-device speech recognition is not available for {}",
+                    language
+                );
+                return None;
+            }
+
+            let format: *mut AnyObject = msg_send![format_class, alloc];
+            let format: *mut AnyObject = msg_send![
+                format,
+                initStandardFormatWithSampleRate: sample_rate as f64,
+                channels: 1_u32
+            ];
+            let format = Retained::from_raw(format)?;
+
+            Some(Self {
+                recognizer,
+                format,
+                request: None,
+                task: None,
+            })
+        }
+    }
+
+    /// Start a recognition task that reports the text heard so far to `on_text`.
+    pub(super) fn start(&mut self, on_text: fn(&str)) {
+        let Some(request_class) = AnyClass::get("SFSpeechAudioBufferRecognitionRequest") else {
+            return;
+        };
+
+        let handler = RcBlock::new(move |result: *mut AnyObject, _error: *mut AnyObject| {
+            if result.is_null() {
+                return;
+            }
+            // SAFETY: result is a valid SFSpeechRecognitionResult
+            unsafe {
+                let transcription: *mut AnyObject = msg_send![result, bestTranscription];
+                let text: *mut NSString = msg_send![transcription, formattedString];
+                if let Some(text) = text.as_ref() {
+                    on_text(&text.to_string());
+                }
+            }
+        });
+
+        // SAFETY: messages to a valid request class and recognizer; the task
+        // keeps its own reference to the handler block
+        unsafe {
+            let request: *mut AnyObject = msg_send![request_class, new];
+            let Some(request) = Retained::from_raw(request) else {
+                error!("Failed to create speech recognition request");
+                return;
+            };
+            let _: () = msg_send![&request, setRequiresOnDeviceRecognition: true];
+            let _: () = msg_send![&request, setShouldReportPartialResults: true];
+
+            let task: *mut AnyObject = msg_send![
+                &self.recognizer,
+                recognitionTaskWithRequest: &*request,
+                resultHandler: &*handler
+            ];
+            self.task = Retained::retain(task);
+            self.request = Some(request);
+        }
+    }
+
+    /// Feed captured audio to the running task.
+    pub(super) fn append(&self, samples: &[i16]) {
+        let (Some(request), Some(buffer_class)) =
+            (&self.request, AnyClass::get("AVAudioPCMBuffer"))
+        else {
+            return;
+        };
+        let frames = samples.len() as u32;
+
+        // SAFETY: the buffer is created with capacity for `frames` float samples
+        // in one channel, written, then handed to the request
+        unsafe {
+            let buffer: *mut AnyObject = msg_send![buffer_class, alloc];
+            let buffer: *mut AnyObject = msg_send![
+                buffer,
+                initWithPCMFormat: &*self.format,
+                frameCapacity: frames
+            ];
+            let Some(buffer) = Retained::from_raw(buffer) else {
+                return;
+            };
+
+            let channels: *const *mut f32 = msg_send![&buffer, floatChannelData];
+            if channels.is_null() || (*channels).is_null() {
+                return;
+            }
+            let data = std::slice::from_raw_parts_mut(*channels, samples.len());
+            for (out, sample) in data.iter_mut().zip(samples) {
+                *out = f32::from(*sample) / 32768.0;
+            }
+            let _: () = msg_send![&buffer, setFrameLength: frames];
+            let _: () = msg_send![&**request, appendAudioPCMBuffer: &*buffer];
+        }
+    }
+
+    /// Finish the running task, if any.
+    pub(super) fn stop(&mut self) {
+        // SAFETY: endAudio and cancel are safe on valid request and task objects
+        unsafe {
+            if let Some(request) = self.request.take() {
+                let _: () = msg_send![&request, endAudio];
+            }
+            if let Some(task) = self.task.take() {
+                let _: () = msg_send![&task, cancel];
+            }
+        }
+    }
+}
+
+impl Drop for Recognizer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,306 @@
+//! Workspaces separating work, personal and client data
+//!
+//! A workspace such as "Work", "Personal" or "Client X" has its own
+//! transcript and screenshot folders, transcription and polishing providers,
+//! instructions added to every polishing prompt, and retention policy. The
+//! workspaces are edited as text in the Workspaces tab of Settings and one
+//! is picked from the menu bar's Workspace submenu.
+//!
+//! The active workspace is resolved in `preferences`, so everything that
+//! saves or polishes follows it without knowing about workspaces, and the
+//! folder, provider and retention controls in Settings change the active
+//! workspace. A provider a workspace leaves out is the Default workspace's.
+//! Its folders default to `Documents/Vissper/<name>` so each workspace's
+//! files stay apart, and its files are kept forever unless it has a
+//! retention policy of its own.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::preferences::{self, AiProvider, PreferencesError};
+
+/// Name of the workspace made of the settings outside any workspace
+pub(crate) const DEFAULT_NAME: &str = "Default";
+
+/// Most workspaces besides Default
+pub(crate) const MAX_WORKSPACES: usize = 8;
+
+/// A workspace and the settings it overrides (None = as in Default)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Workspace {
+    pub name: String,
+    /// Transcript folder (None = `Documents/Vissper/<name>/transcripts`)
+    pub transcript_location: Option<PathBuf>,
+    /// Screenshot folder (None = `Documents/Vissper/<name>/screenshots`)
+    pub screenshot_location: Option<PathBuf>,
+    /// Provider for transcription
+    pub ai_provider: Option<AiProvider>,
+    /// Provider for polishing
+    pub polish_provider: Option<AiProvider>,
+    /// Instructions added to every polishing prompt
+    pub prompt: Option<String>,
+    /// Delete files older than this many days (None = keep forever)
+    pub retention_days: Option<u32>,
+    /// Cap on the workspace's disk usage in megabytes (None = no cap)
+    pub retention_max_mb: Option<u64>,
+}
+
+impl Workspace {
+    /// Folder for the workspace's files of `kind` when none is set
+    pub(crate) fn default_dir(&self, kind: &str) -> Option<PathBuf> {
+        dirs::document_dir().map(|d| d.join(self.subfolder(kind)))
+    }
+
+    /// `Vissper/<name>/<kind>`, the workspace's part of Documents or iCloud Drive
+    pub(crate) fn subfolder(&self, kind: &str) -> PathBuf {
+        Path::new("Vissper")
+            .join(folder_name(&self.name))
+            .join(kind)
+    }
+}
+
+/// Name of the active workspace
+pub(crate) fn active_name() -> String {
+    preferences::get_active_workspace().unwrap_or_else(|| DEFAULT_NAME.to_string())
+}
+
+/// Names of all workspaces, Default first
+pub(crate) fn names() -> Vec<String> {
+    std::iter::once(DEFAULT_NAME.to_string())
+        .chain(preferences::get_workspaces().into_iter().map(|w| w.name))
+        .collect()
+}
+
+/// Make the workspace named `name` active; Default or an unknown name
+/// switches back to Default.
+pub(crate) fn switch_to(name: &str) -> Result<(), PreferencesError> {
+    let known = preferences::get_workspaces()
+        .into_iter()
+        .any(|workspace| workspace.name == name);
+    preferences::set_active_workspace(known.then(|| name.to_string()))
+}
+
+/// `prompt` with the active workspace's instructions added
+pub(crate) fn add_to_prompt(prompt: String) -> String {
+    match preferences::get_workspace_prompt() {
+        Some(instructions) => format!(
+            "{}\n\nAdditional instructions for this workspace:\n{}",
+            prompt, instructions
+        ),
+        None => prompt,
+    }
+}
+
+/// Folder name for a workspace, without characters paths can't hold
+fn folder_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if matches!(c, '/' | ':' | '\\') {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect::<String>()
+        .trim_start_matches('.')
+        .to_string()
+}
+
+/// Setting lines of a workspace in the list edited in Settings
+const TRANSCRIPTS_KEY: &str = "transcripts";
+const SCREENSHOTS_KEY: &str = "screenshots";
+const TRANSCRIPTION_KEY: &str = "transcription";
+const POLISHING_KEY: &str = "polishing";
+const KEEP_DAYS_KEY: &str = "keep days";
+const KEEP_MB_KEY: &str = "keep mb";
+
+/// Parse the workspaces list as edited in Settings.
+///
+/// Each workspace starts with a `## Name` line. Lines such as
+/// `transcripts: ~/Clients/X` or `polishing: openai` set its folders,
+/// providers and retention; any other lines are instructions for polishing.
+/// Workspaces without a name, named Default or named like an earlier one are
+/// dropped, and only the first `MAX_WORKSPACES` are kept.
+pub(crate) fn parse(text: &str) -> Vec<Workspace> {
+    let mut workspaces: Vec<Workspace> = Vec::new();
+    let mut current: Option<(Workspace, Vec<&str>)> = None;
+
+    for line in text.lines() {
+        if let Some(name) = line.trim().strip_prefix("## ") {
+            workspaces.extend(current.take().map(finish));
+            let workspace = Workspace {
+                name: name.trim().to_string(),
+                ..Workspace::default()
+            };
+            current = Some((workspace, Vec::new()));
+        } else if let Some((workspace, prompt)) = current.as_mut() {
+            if !apply_setting(workspace, line) {
+                prompt.push(line);
+            }
+        }
+    }
+    workspaces.extend(current.map(finish));
+
+    let mut valid: Vec<Workspace> = Vec::new();
+    for workspace in workspaces {
+        let name = workspace.name.to_lowercase();
+        if name.is_empty()
+            || name == DEFAULT_NAME.to_lowercase()
+            || valid.iter().any(|other| other.name.to_lowercase() == name)
+        {
+            continue;
+        }
+        valid.push(workspace);
+    }
+    valid.truncate(MAX_WORKSPACES);
+    valid
+}
+
+/// The workspace with its prompt lines joined, None when empty
+fn finish((mut workspace, prompt): (Workspace, Vec<&str>)) -> Workspace {
+    let prompt = prompt.join("\n").trim().to_string();
+    workspace.prompt = (!prompt.is_empty()).then_some(prompt);
+    workspace
+}
+
+/// Apply a `key: value` setting line, returning false if it isn't one
+fn apply_setting(workspace: &mut Workspace, line: &str) -> bool {
+    let Some((key, value)) = line.split_once(':') else {
+        return false;
+    };
+    let value = value.trim();
+    match key.trim().to_lowercase().as_str() {
+        TRANSCRIPTS_KEY => workspace.transcript_location = parse_path(value),
+        SCREENSHOTS_KEY => workspace.screenshot_location = parse_path(value),
+        TRANSCRIPTION_KEY => workspace.ai_provider = parse_provider(value),
+        POLISHING_KEY => workspace.polish_provider = parse_provider(value),
+        KEEP_DAYS_KEY => workspace.retention_days = value.parse().ok(),
+        KEEP_MB_KEY => workspace.retention_max_mb = value.parse().ok(),
+        _ => return false,
+    }
+    true
+}
+
+/// A folder, with `~` for the home folder
+pub(crate) fn parse_path(value: &str) -> Option<PathBuf> {
+    match value.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None if value.is_empty() => None,
+        None => Some(PathBuf::from(value)),
+    }
+}
+
+pub(crate) fn parse_provider(value: &str) -> Option<AiProvider> {
+    match value.to_lowercase().as_str() {
+        "azure" | "azure openai" => Some(AiProvider::Azure),
+        "openai" => Some(AiProvider::OpenAI),
+        _ => None,
+    }
+}
+
+/// Format workspaces for editing, separated by blank lines.
+pub(crate) fn format(workspaces: &[Workspace]) -> String {
+    workspaces
+        .iter()
+        .map(|workspace| {
+            let mut lines = vec![format!("## {}", workspace.name)];
+            if let Some(path) = &workspace.transcript_location {
+                lines.push(format!("{}: {}", TRANSCRIPTS_KEY, format_path(path)));
+            }
+            if let Some(path) = &workspace.screenshot_location {
+                lines.push(format!("{}: {}", SCREENSHOTS_KEY, format_path(path)));
+            }
+            if let Some(provider) = workspace.ai_provider {
+                lines.push(format!("{}: {}", TRANSCRIPTION_KEY, provider_key(provider)));
+            }
+            if let Some(provider) = workspace.polish_provider {
+                lines.push(format!("{}: {}", POLISHING_KEY, provider_key(provider)));
+            }
+            if let Some(days) = workspace.retention_days {
+                lines.push(format!("{}: {}", KEEP_DAYS_KEY, days));
+            }
+            if let Some(mb) = workspace.retention_max_mb {
+                lines.push(format!("{}: {}", KEEP_MB_KEY, mb));
+            }
+            lines.extend(workspace.prompt.clone());
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// A folder with the home folder shortened to `~`
+pub(crate) fn format_path(path: &Path) -> String {
+    match dirs::home_dir().and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf)) {
+        Some(rest) => format!("~/{}", rest.display()),
+        None => path.display().to_string(),
+    }
+}
+
+pub(crate) fn provider_key(provider: AiProvider) -> &'static str {
+    match provider {
+        AiProvider::Azure => "azure",
+        AiProvider::OpenAI => "openai",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST: &str = "## Client X
+transcripts: /Clients/X/Transcripts
+polishing: openai
+keep days: 90
+Use the client's product names: Nimbus and Stratus.
+
+## Personal
+transcription: Azure
+
+## default
+Dropped, Default holds the settings outside any workspace.
+
+## Personal
+Dropped as a duplicate.";
+
+    #[test]
+    fn test_parse_reads_settings_and_prompt() {
+        let workspaces = parse(LIST);
+        assert_eq!(workspaces.len(), 2);
+
+        let client = &workspaces[0];
+        assert_eq!(client.name, "Client X");
+        assert_eq!(
+            client.transcript_location,
+            Some(PathBuf::from("/Clients/X/Transcripts"))
+        );
+        assert_eq!(client.polish_provider, Some(AiProvider::OpenAI));
+        assert_eq!(client.retention_days, Some(90));
+        assert_eq!(
+            client.prompt.as_deref(),
+            Some("Use the client's product names: Nimbus and Stratus.")
+        );
+
+        let personal = &workspaces[1];
+        assert_eq!(personal.ai_provider, Some(AiProvider::Azure));
+        assert_eq!(personal.prompt, None);
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        let workspaces = parse(LIST);
+        assert_eq!(parse(&format(&workspaces)), workspaces);
+    }
+
+    #[test]
+    fn test_subfolder_is_per_workspace() {
+        let workspace = Workspace {
+            name: "Client/X".to_string(),
+            ..Workspace::default()
+        };
+        assert_eq!(
+            workspace.subfolder("transcripts"),
+            PathBuf::from("Vissper/Client-X/transcripts")
+        );
+    }
+}